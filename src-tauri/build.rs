@@ -1,3 +1,13 @@
 fn main() {
+    // Lets tests that shell out to git skip when it isn't installed.
+    println!("cargo:rustc-check-cfg=cfg(git_available)");
+    let git_available = std::process::Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success());
+    if git_available {
+        println!("cargo:rustc-cfg=git_available");
+    }
+
     tauri_build::build()
 }
@@ -404,6 +404,10 @@ mod tests {
                     meeting_link: Some("https://meet.example.com/sync".to_string()),
                     status: "confirmed".to_string(),
                     color_id: Some("1".to_string()),
+                    category: None,
+                    is_recurring: false,
+                    recurring_event_id: None,
+                    recurrence: Vec::new(),
                 },
                 GoogleCalendarEvent {
                     id: "evt-2".to_string(),
@@ -416,6 +420,10 @@ mod tests {
                     meeting_link: None,
                     status: "confirmed".to_string(),
                     color_id: Some("2".to_string()),
+                    category: None,
+                    is_recurring: false,
+                    recurring_event_id: None,
+                    recurrence: Vec::new(),
                 },
                 GoogleCalendarEvent {
                     id: "evt-3".to_string(),
@@ -428,6 +436,10 @@ mod tests {
                     meeting_link: Some("https://meet.example.com/retro".to_string()),
                     status: "cancelled".to_string(),
                     color_id: Some("3".to_string()),
+                    category: None,
+                    is_recurring: false,
+                    recurring_event_id: None,
+                    recurrence: Vec::new(),
                 },
             ],
             last_updated: Utc::now(),
@@ -493,6 +505,10 @@ mod tests {
             meeting_link: None,
             status: "confirmed".to_string(),
             color_id: Some("4".to_string()),
+            category: None,
+            is_recurring: false,
+            recurring_event_id: None,
+            recurrence: Vec::new(),
         });
 
         let response = google_calendar_list_events_from_cache(
@@ -34,15 +34,18 @@ use crate::backend::mcp_workspace_markdown::{
     resolve_project_readme_in_directory, ActionBuildInput, HabitBuildInput, HorizonBuildInput,
     ProjectBuildInput,
 };
+use crate::commands::file_locks::with_file_lock;
 #[cfg(test)]
 use crate::commands::filesystem::MarkdownFile;
-use crate::commands::filesystem::{create_directory, list_markdown_files, read_file, save_file};
-use crate::commands::gtd_habits::update_habit_status;
+use crate::commands::filesystem::{
+    create_directory, list_markdown_files, read_file, save_file, save_file_contents,
+};
+use crate::commands::gtd_habits::apply_habit_status;
 use crate::commands::gtd_habits_domain::{
     apply_status_marker, format_history_entry, format_history_time, insert_history_entry,
     parse_habit_state, parse_history_rows_strict, parse_history_timestamp, HabitStatus,
 };
-use crate::commands::gtd_projects::{rename_gtd_action, rename_gtd_project};
+use crate::commands::gtd_projects::{rename_action_file, rename_project_folder};
 use crate::commands::gtd_relationships::{find_habits_referencing, find_reverse_relationships};
 use crate::commands::search::{search_files, SearchFilters};
 use crate::commands::utils::sanitize_markdown_file_stem;
@@ -833,6 +836,9 @@ impl GtdWorkspaceService {
             use_regex: false,
             include_file_names: true,
             max_results: offset.saturating_add(limit).clamp(1, MAX_SEARCH_LIMIT),
+            use_query_syntax: false,
+            custom_field: None,
+            follow_symlinks: false,
         };
         let response = search_files(request.query, self.workspace_root(), filters).await?;
         let matches = response
@@ -1505,13 +1511,13 @@ impl GtdWorkspaceService {
                 }
                 ChangeOperation::RenameProject {
                     old_path, new_name, ..
-                } => rename_gtd_project(old_path.clone(), new_name.clone()).map(|_| ()),
+                } => rename_project_folder(old_path, new_name).map(|_| ()),
                 ChangeOperation::RenameAction {
                     old_path, new_name, ..
-                } => rename_gtd_action(old_path.clone(), new_name.clone()).map(|_| ()),
+                } => rename_action_file(old_path, new_name).map(|_| ()),
                 ChangeOperation::UpdateHabitStatus {
                     path, new_status, ..
-                } => update_habit_status(path.clone(), new_status.clone()).map(|_| ()),
+                } => apply_habit_status(path, new_status, None).map(|_| ()),
                 ChangeOperation::WriteHabitHistoryEntry {
                     path,
                     entry,
@@ -1778,11 +1784,10 @@ impl GtdWorkspaceService {
     }
 
     fn cache_paths(&self) -> Result<CachePaths, String> {
-        let cache_dir = app_cache_dir()?;
         let mut hasher = Sha256::new();
         hasher.update(self.workspace_root().as_bytes());
         let workspace_hash = encode_hex(hasher.finalize());
-        let root = cache_dir.join(CONTEXT_CACHE_DIR).join(workspace_hash);
+        let root = context_cache_root()?.join(workspace_hash);
         Ok(CachePaths {
             manifest: root.join("manifest.json"),
             json: root.join("gtd-context.json"),
@@ -2288,17 +2293,19 @@ fn apply_habit_history_entry(
     entry: &str,
     new_status: Option<&str>,
 ) -> Result<(), String> {
-    let content = read_file(path.to_string())?;
-    let updated = if let Some(status) = new_status {
-        let parsed = parse_habit_state(&content)?;
-        let next_status = HabitStatus::from_input(status)?;
-        let with_status = apply_status_marker(&content, next_status, parsed.status_format);
-        insert_history_entry(&with_status, entry)?
-    } else {
-        insert_history_entry(&content, entry)?
-    };
+    with_file_lock(Path::new(path), || {
+        let content = read_file(path.to_string())?;
+        let updated = if let Some(status) = new_status {
+            let parsed = parse_habit_state(&content)?;
+            let next_status = HabitStatus::from_input(status)?;
+            let with_status = apply_status_marker(&content, next_status, parsed.status_format);
+            insert_history_entry(&with_status, entry)?
+        } else {
+            insert_history_entry(&content, entry)?
+        };
 
-    save_file(path.to_string(), updated).map(|_| ())
+        save_file_contents(Path::new(path), &updated)
+    })
 }
 
 fn normalize_replacement_history_rows(
@@ -2472,6 +2479,11 @@ fn current_os_kind() -> OsKind {
     }
 }
 
+/// Directory holding the cached MCP context packs for every workspace.
+pub(crate) fn context_cache_root() -> Result<PathBuf, String> {
+    app_cache_dir().map(|dir| dir.join(CONTEXT_CACHE_DIR))
+}
+
 fn app_cache_dir() -> Result<PathBuf, String> {
     let local_appdata = std::env::var("LOCALAPPDATA").ok();
     let appdata = std::env::var("APPDATA").ok();
@@ -2651,6 +2663,8 @@ mod tests {
                 size: 10,
                 last_modified: 10,
                 extension: ".md".to_string(),
+                is_writable: true,
+                custom_fields: BTreeMap::new(),
             }],
         );
         let after = build_fingerprint(
@@ -2662,6 +2676,8 @@ mod tests {
                 size: 10,
                 last_modified: 11,
                 extension: ".md".to_string(),
+                is_writable: true,
+                custom_fields: BTreeMap::new(),
             }],
         );
         assert_ne!(before.aggregate_digest, after.aggregate_digest);
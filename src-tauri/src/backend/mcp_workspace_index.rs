@@ -631,6 +631,7 @@ mod tests {
     };
     use crate::commands::filesystem::MarkdownFile;
     use crate::test_utils::write_test_file;
+    use std::collections::BTreeMap;
     use std::path::Path;
 
     #[test]
@@ -650,6 +651,8 @@ mod tests {
                 size: 1,
                 last_modified: 20,
                 extension: ".md".to_string(),
+                is_writable: true,
+                custom_fields: BTreeMap::new(),
             },
             MarkdownFile {
                 id: "a".to_string(),
@@ -658,6 +661,8 @@ mod tests {
                 size: 1,
                 last_modified: 10,
                 extension: ".md".to_string(),
+                is_writable: true,
+                custom_fields: BTreeMap::new(),
             },
         ];
 
@@ -680,6 +685,8 @@ mod tests {
                     size: 1,
                     last_modified: 12,
                     extension: ".md".to_string(),
+                    is_writable: true,
+                    custom_fields: BTreeMap::new(),
                 },
                 MarkdownFile {
                     id: "2".to_string(),
@@ -688,6 +695,8 @@ mod tests {
                     size: 1,
                     last_modified: 3,
                     extension: ".md".to_string(),
+                    is_writable: true,
+                    custom_fields: BTreeMap::new(),
                 },
             ],
         );
@@ -701,6 +710,8 @@ mod tests {
                     size: 1,
                     last_modified: 2,
                     extension: ".md".to_string(),
+                    is_writable: true,
+                    custom_fields: BTreeMap::new(),
                 },
                 MarkdownFile {
                     id: "4".to_string(),
@@ -709,6 +720,8 @@ mod tests {
                     size: 1,
                     last_modified: 3,
                     extension: ".md".to_string(),
+                    is_writable: true,
+                    custom_fields: BTreeMap::new(),
                 },
             ],
         );
@@ -736,6 +749,8 @@ mod tests {
                 size: 1,
                 last_modified: 10,
                 extension: ".markdown".to_string(),
+                is_writable: true,
+                custom_fields: BTreeMap::new(),
             }],
         )?;
 
@@ -767,6 +782,8 @@ mod tests {
                 size: 1,
                 last_modified: 10,
                 extension: ".markdown".to_string(),
+                is_writable: true,
+                custom_fields: BTreeMap::new(),
             }],
         )?;
 
@@ -163,7 +163,8 @@ pub(super) fn build_project_markdown(input: ProjectBuildInput) -> Result<String,
     let mut content = generate_project_readme_with_refs(ProjectReadmeParams {
         name: &input.title,
         description: &input.description,
-        due_date: input.due_date.clone(),
+        due_date: input.due_date.as_deref(),
+        focus_date: None,
         status: &input.status,
         areas_refs: &areas_refs,
         goals_refs: &goals_refs,
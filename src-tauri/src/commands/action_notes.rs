@@ -0,0 +1,200 @@
+//! Read and append to the `## Notes` section of action files.
+
+use chrono::Local;
+use std::path::Path;
+
+use super::file_locks::with_file_lock;
+use super::filesystem::{read_file, save_file_contents};
+use super::sections::insert_before_created_footer;
+
+fn is_notes_heading(line: &str) -> bool {
+    line.trim()
+        .strip_prefix("## ")
+        .is_some_and(|title| title.trim().eq_ignore_ascii_case("notes"))
+}
+
+fn is_section_boundary(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("# ") || trimmed.starts_with("## ")
+}
+
+fn is_rule(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= 3 && trimmed.chars().all(|ch| ch == '-')
+}
+
+/// Line range of the Notes body: from after the heading up to, not
+/// including, the next heading and any horizontal rule just before it.
+fn notes_body_range(lines: &[&str]) -> Option<(usize, usize)> {
+    let heading = lines.iter().position(|line| is_notes_heading(line))?;
+    let start = heading + 1;
+    let mut end = lines[start..]
+        .iter()
+        .position(|line| is_section_boundary(line))
+        .map_or(lines.len(), |offset| start + offset);
+    while end > start && (lines[end - 1].trim().is_empty() || is_rule(lines[end - 1])) {
+        end -= 1;
+    }
+    Some((start, end))
+}
+
+/// Notes section text, trimmed; empty when the section is missing.
+pub(crate) fn extract_action_notes(content: &str) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    notes_body_range(&lines)
+        .map(|(start, end)| lines[start..end].join("\n").trim().to_string())
+        .unwrap_or_default()
+}
+
+/// Append `bullet` to the Notes section, creating the section before the
+/// `---` / `## Created` footer (or at the end) when it is missing.
+fn append_to_notes(content: &str, bullet: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let Some((_, end)) = notes_body_range(&lines) else {
+        return insert_before_created_footer(content, &format!("## Notes\n{}", bullet));
+    };
+    lines.insert(end, bullet);
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+/// Render a note as a single bullet line that cannot open a GTD field.
+fn format_note_bullet(note: &str) -> Result<String, String> {
+    let text = note.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.is_empty() {
+        return Err("Note cannot be empty".to_string());
+    }
+    Ok(format!(
+        "- {} _{}_",
+        text.replace("[!", "[\\!"),
+        Local::now().format("%Y-%m-%d %H:%M")
+    ))
+}
+
+/// Read the Notes section of an action
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+///
+/// # Returns
+///
+/// Text between `## Notes` and the next heading, or an empty string when the
+/// action has no notes section
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const notes = await invoke<string>('get_action_notes', {
+///   actionPath: '/path/to/space/Projects/Website/Write copy.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_action_notes(action_path: String) -> Result<String, String> {
+    log::info!("Reading action notes: {}", action_path);
+    Ok(extract_action_notes(&read_file(action_path)?))
+}
+
+/// Append a timestamped note to an action
+///
+/// Adds `- {note} _{YYYY-MM-DD HH:MM}_` to the end of the Notes section,
+/// creating the section if needed. Line breaks in `note` are collapsed.
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+/// * `note` - Note text
+///
+/// # Returns
+///
+/// Nothing on success, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('append_action_note', {
+///   actionPath: '/path/to/space/Projects/Website/Write copy.md',
+///   note: 'Waiting on brand guidelines',
+/// });
+/// ```
+#[tauri::command]
+pub fn append_action_note(action_path: String, note: String) -> Result<(), String> {
+    log::info!("Appending note to action: {}", action_path);
+
+    let bullet = format_note_bullet(&note)?;
+    let path = Path::new(&action_path);
+    with_file_lock(path, || {
+        let content = read_file(action_path.clone())?;
+        save_file_contents(path, &append_to_notes(&content, &bullet))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::fields::{get_field, FieldKind};
+    use crate::commands::seed_data::generate_action_template;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+    use std::fs;
+
+    #[test]
+    fn notes_are_read_and_appended_inside_the_section() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let action = workspace
+            .path()
+            .join("Projects/Alpha Project/Call vendor.md");
+        let template = generate_action_template(
+            "Call vendor",
+            "in-progress",
+            None,
+            None,
+            "small",
+            None,
+            Some("Ask about pricing".to_string()),
+            None,
+        );
+        write_test_file(&action, &template)?;
+        let action_path = action.to_string_lossy().to_string();
+
+        assert_eq!(get_action_notes(action_path.clone())?, "Ask about pricing");
+
+        append_action_note(
+            action_path.clone(),
+            "Left voicemail\n[!singleselect:status:completed]".to_string(),
+        )?;
+        let notes = get_action_notes(action_path.clone())?;
+        let lines: Vec<&str> = notes.lines().collect();
+        assert_eq!(lines[0], "Ask about pricing");
+        assert!(lines[1].starts_with("- Left voicemail [\\!singleselect:status:completed] _"));
+
+        let content = fs::read_to_string(&action).map_err(|e| e.to_string())?;
+        assert_eq!(
+            get_field(&content, FieldKind::SingleSelect, "status").as_deref(),
+            Some("in-progress")
+        );
+        assert!(get_field(&content, FieldKind::Datetime, "created_date_time").is_some());
+        assert!(content.contains("_\n\n---\n## Created"));
+        assert!(append_action_note(action_path, "  ".to_string()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_notes_section_is_created_before_the_footer() {
+        let content = "# Task\n\n## Status\n[!singleselect:status:in-progress]\n\n---\n## Created\n[!datetime:created_date_time:2026-01-01]\n";
+        assert_eq!(extract_action_notes(content), "");
+
+        let updated = append_to_notes(content, "- first");
+        assert_eq!(extract_action_notes(&updated), "- first");
+        assert!(updated.contains("## Notes\n- first\n\n---\n## Created"));
+
+        let bare = append_to_notes("# Task", "- first");
+        assert_eq!(bare, "# Task\n\n## Notes\n- first\n");
+    }
+}
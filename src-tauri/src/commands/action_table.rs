@@ -0,0 +1,264 @@
+//! Generated action table inside project READMEs.
+//!
+//! The table lives between `<!-- gtd:actions:start -->` and
+//! `<!-- gtd:actions:end -->` so READMEs stay readable when exported or
+//! printed. Only the delimited block is ever rewritten.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::gtd_projects::{resolve_project_readme_path, write_string_atomically};
+use super::project_velocity::ACTION_STATUS_REGEX;
+use super::sections::{list_sections, section_body_start};
+use super::space_config::{action_tables_enabled, ensure_file_editable};
+use super::utils::{is_markdown_path, is_readme_path};
+
+pub(crate) const ACTION_TABLE_START: &str = "<!-- gtd:actions:start -->";
+pub(crate) const ACTION_TABLE_END: &str = "<!-- gtd:actions:end -->";
+
+pub(crate) static ACTION_DUE_DATE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!datetime:due_date:([^\]]*)\]").expect("Invalid action due date regex pattern")
+});
+
+pub(crate) struct ActionRow {
+    pub name: String,
+    pub status: String,
+    /// Due date (YYYY-MM-DD), empty when unset
+    pub due: String,
+}
+
+/// A folder directly under `Projects` that has a README.
+fn is_project_directory(dir: &Path) -> bool {
+    let parent_is_projects = dir
+        .parent()
+        .and_then(|parent| parent.file_name())
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.eq_ignore_ascii_case("Projects"));
+    parent_is_projects && resolve_project_readme_path(dir).is_some()
+}
+
+fn escape_table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Action rows for every action file in `project_dir`, sorted by name.
+pub(crate) fn collect_action_rows(project_dir: &Path) -> Result<Vec<ActionRow>, String> {
+    let entries = fs::read_dir(project_dir)
+        .map_err(|e| format!("Failed to read project directory: {}", e))?;
+
+    let mut rows: Vec<ActionRow> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file() && !is_readme_path(path) && is_markdown_path(path))
+        .map(|path| {
+            let content = fs::read_to_string(&path).unwrap_or_default();
+            let capture = |regex: &Regex| {
+                regex
+                    .captures(&content)
+                    .and_then(|captures| captures.get(1))
+                    .map(|value| value.as_str().trim().to_string())
+                    .unwrap_or_default()
+            };
+            let due = capture(&ACTION_DUE_DATE_REGEX);
+            ActionRow {
+                name: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+                status: capture(&ACTION_STATUS_REGEX),
+                due: due.chars().take(10).collect(),
+            }
+        })
+        .collect();
+
+    rows.sort_by_key(|row| row.name.to_lowercase());
+    Ok(rows)
+}
+
+fn render_action_table(rows: &[ActionRow]) -> String {
+    let mut lines = vec![ACTION_TABLE_START.to_string()];
+    if rows.is_empty() {
+        lines.push("*No actions yet*".to_string());
+    } else {
+        lines.push("| Action | Status | Due |".to_string());
+        lines.push("|--------|--------|-----|".to_string());
+        for row in rows {
+            lines.push(format!(
+                "| {} | {} | {} |",
+                escape_table_cell(&row.name),
+                escape_table_cell(&row.status),
+                escape_table_cell(&row.due)
+            ));
+        }
+    }
+    lines.push(ACTION_TABLE_END.to_string());
+    lines.join("\n")
+}
+
+/// Replace the delimited block, or insert it ahead of the created footer.
+fn apply_action_table(content: &str, block: &str) -> String {
+    if let Some(start) = content.find(ACTION_TABLE_START) {
+        if let Some(end_offset) = content[start..].find(ACTION_TABLE_END) {
+            let end = start + end_offset + ACTION_TABLE_END.len();
+            return format!("{}{}{}", &content[..start], block, &content[end..]);
+        }
+    }
+
+    let lines: Vec<&str> = content.lines().collect();
+    let footer_index = lines
+        .iter()
+        .position(|line| line.contains("[!datetime:created_date_time:"))
+        .map(|index| {
+            // Keep the "## Created" heading attached to its marker.
+            list_sections(content)
+                .into_iter()
+                .find(|section| {
+                    let body_start = section_body_start(&lines, section);
+                    body_start <= index
+                        && index < section.end_line
+                        && lines[body_start..index]
+                            .iter()
+                            .all(|line| line.trim().is_empty())
+                })
+                .map_or(index, |section| section.start_line)
+        });
+
+    let Some(footer_index) = footer_index else {
+        return format!("{}\n\n{}\n", content.trim_end(), block);
+    };
+
+    let mut new_lines: Vec<&str> = lines[..footer_index].to_vec();
+    while new_lines.last().is_some_and(|line| line.trim().is_empty()) {
+        new_lines.pop();
+    }
+    let mut updated = new_lines.join("\n");
+    if !updated.is_empty() {
+        updated.push_str("\n\n");
+    }
+    updated.push_str(block);
+    updated.push_str("\n\n");
+    updated.push_str(&lines[footer_index..].join("\n"));
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+    updated
+}
+
+/// Regenerate the action table in a project's README.
+///
+/// Returns whether the README changed.
+pub(crate) fn sync_action_table_in(project_dir: &Path) -> Result<bool, String> {
+    let readme_path = resolve_project_readme_path(project_dir)
+        .ok_or_else(|| "Project README not found".to_string())?;
+    let content =
+        fs::read_to_string(&readme_path).map_err(|e| format!("Failed to read README: {}", e))?;
+
+    let block = render_action_table(&collect_action_rows(project_dir)?);
+    let updated = apply_action_table(&content, &block);
+    if updated == content {
+        return Ok(false);
+    }
+
+    ensure_file_editable(&readme_path)?;
+    write_string_atomically(&readme_path, &updated)?;
+    Ok(true)
+}
+
+/// Refresh the parent project's table after an action file changed.
+///
+/// No-op for READMEs, files outside projects, or spaces that turned the
+/// table off. Failures are logged so the original operation still succeeds.
+pub(crate) fn sync_action_table_after_change(changed_path: &Path) {
+    if is_readme_path(changed_path) {
+        return;
+    }
+    let Some(project_dir) = changed_path.parent() else {
+        return;
+    };
+    if !is_project_directory(project_dir) || !action_tables_enabled(project_dir) {
+        return;
+    }
+
+    if let Err(error) = sync_action_table_in(project_dir) {
+        log::warn!(
+            "Failed to sync action table for {}: {}",
+            project_dir.display(),
+            error
+        );
+    }
+}
+
+/// Regenerate the action table in a project README
+///
+/// Lists every action in the project folder (name, status, due date) inside
+/// the `<!-- gtd:actions:start -->` / `<!-- gtd:actions:end -->` block. When
+/// the markers are missing the block is inserted before the `## Created`
+/// footer. Content outside the markers is never modified.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project directory
+///
+/// # Returns
+///
+/// Whether the README changed, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('sync_project_action_table', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+/// });
+/// ```
+#[tauri::command]
+pub fn sync_project_action_table(project_path: String) -> Result<bool, String> {
+    log::info!("Syncing action table for project: {}", project_path);
+
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+
+    sync_action_table_in(project_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn sync_inserts_before_created_footer_then_updates_in_place() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        let readme = project.join("README.md");
+        write_test_file(
+            &readme,
+            "# Alpha\n\n## Desired Outcome\nShip it\n\n## Created\n[!datetime:created_date_time:2026-03-20T10:00:00Z]\n\n## Actions\n[!actions-list]\n",
+        )?;
+        write_test_file(
+            project.join("Draft.md"),
+            "# Draft\n\n## Status\n[!singleselect:status:in-progress]\n\n## Due Date\n[!datetime:due_date:2026-04-01]\n",
+        )?;
+
+        assert!(sync_action_table_in(&project)?);
+        let content = fs::read_to_string(&readme).map_err(|e| e.to_string())?;
+        assert_eq!(
+            content,
+            "# Alpha\n\n## Desired Outcome\nShip it\n\n<!-- gtd:actions:start -->\n| Action | Status | Due |\n|--------|--------|-----|\n| Draft | in-progress | 2026-04-01 |\n<!-- gtd:actions:end -->\n\n## Created\n[!datetime:created_date_time:2026-03-20T10:00:00Z]\n\n## Actions\n[!actions-list]\n"
+        );
+
+        fs::remove_file(project.join("Draft.md")).map_err(|e| e.to_string())?;
+        sync_action_table_after_change(&project.join("Draft.md"));
+        let content = fs::read_to_string(&readme).map_err(|e| e.to_string())?;
+        assert!(content.contains("Ship it\n\n<!-- gtd:actions:start -->\n*No actions yet*\n<!-- gtd:actions:end -->\n\n## Created"));
+        assert!(!sync_action_table_in(&project)?);
+
+        Ok(())
+    }
+}
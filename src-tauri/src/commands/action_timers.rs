@@ -0,0 +1,508 @@
+//! Per-action time tracking.
+//!
+//! At most one timer runs at a time; it lives in the `action-timer.json`
+//! store so it survives restarts. Stopping a timer appends a row to the
+//! action's `## Time Log` table.
+
+use chrono::{DateTime, Local, NaiveTime};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::AppHandle;
+use tauri_plugin_store::StoreExt;
+
+use super::file_locks::with_file_lock;
+use super::filesystem::{list_project_actions, read_file, save_file_contents};
+use super::sections::insert_before_created_footer;
+
+const TIMER_STORE_FILE: &str = "action-timer.json";
+const RUNNING_TIMER_KEY: &str = "running_timer";
+const TIME_LOG_HEADING: &str = "## Time Log";
+const TIME_LOG_HEADER: &str = "| Date | Start | End | Duration | Note |";
+const TIME_LOG_SEPARATOR: &str = "|------|-------|-----|----------|------|";
+
+/// The timer currently running
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunningTimer {
+    pub action_path: String,
+    /// RFC 3339 start time
+    pub started_at: String,
+}
+
+/// Result of stopping a timer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoppedTimer {
+    pub action_path: String,
+    pub started_at: String,
+    pub ended_at: String,
+    pub duration_minutes: u64,
+    /// False when the action file no longer exists and nothing was written
+    pub logged: bool,
+}
+
+/// Time logged on one action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionTimeTotal {
+    pub action_path: String,
+    pub action_name: String,
+    pub total_minutes: u64,
+    /// Rows in the Time Log table
+    pub entry_count: usize,
+}
+
+/// Time logged across a project's actions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTimeTotals {
+    pub total_minutes: u64,
+    /// Actions with at least one Time Log row, most time first
+    pub actions: Vec<ActionTimeTotal>,
+}
+
+fn format_duration(minutes: u64) -> String {
+    format!("{}:{:02}", minutes / 60, minutes % 60)
+}
+
+fn parse_duration(value: &str) -> Option<u64> {
+    let (hours, minutes) = value.trim().split_once(':')?;
+    let minutes: u64 = minutes.parse().ok()?;
+    (minutes < 60).then_some(hours.parse::<u64>().ok()? * 60 + minutes)
+}
+
+/// One Time Log row; the note is flattened so it cannot break the table.
+pub(crate) fn format_time_log_row(
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    minutes: u64,
+    note: &str,
+) -> String {
+    let note = note
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace('|', "\\|");
+    format!(
+        "| {} | {} | {} | {} | {} |",
+        start.format("%Y-%m-%d"),
+        start.format("%H:%M"),
+        end.format("%H:%M"),
+        format_duration(minutes),
+        note
+    )
+}
+
+fn is_time_log_row(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.starts_with('|')
+        && trimmed != TIME_LOG_HEADER
+        && !trimmed
+            .trim_matches(|ch| ch == '|' || ch == '-' || ch == ' ')
+            .is_empty()
+}
+
+/// Line indexes of the Time Log heading and its last table line, if present.
+fn time_log_bounds(lines: &[&str]) -> Option<(usize, Option<usize>)> {
+    let heading = lines
+        .iter()
+        .position(|line| line.trim().eq_ignore_ascii_case(TIME_LOG_HEADING))?;
+    let last_table_line = lines[heading + 1..]
+        .iter()
+        .take_while(|line| !line.trim_start().starts_with("## "))
+        .enumerate()
+        .filter(|(_, line)| line.trim_start().starts_with('|'))
+        .map(|(offset, _)| heading + 1 + offset)
+        .last();
+    Some((heading, last_table_line))
+}
+
+/// Append `row` to the Time Log table, creating the section (before the
+/// `---` / `## Created` footer, like other action sections) when missing.
+pub(crate) fn insert_time_log_row(content: &str, row: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    match time_log_bounds(&lines) {
+        Some((_, Some(last))) => lines.insert(last + 1, row),
+        Some((heading, None)) => {
+            lines.splice(
+                heading + 1..heading + 1,
+                ["", TIME_LOG_HEADER, TIME_LOG_SEPARATOR, row],
+            );
+        }
+        None => {
+            let section = [
+                TIME_LOG_HEADING,
+                "",
+                TIME_LOG_HEADER,
+                TIME_LOG_SEPARATOR,
+                row,
+            ];
+            return insert_before_created_footer(content, &section.join("\n"));
+        }
+    }
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+/// Total minutes and row count from a Time Log table.
+pub(crate) fn parse_time_log(content: &str) -> (u64, usize) {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some((heading, Some(last))) = time_log_bounds(&lines) else {
+        return (0, 0);
+    };
+
+    lines[heading + 1..=last]
+        .iter()
+        .filter(|line| is_time_log_row(line))
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.trim().trim_matches('|').split('|').collect();
+            cells.get(3).and_then(|cell| parse_duration(cell))
+        })
+        .fold((0, 0), |(total, count), minutes| {
+            (total + minutes, count + 1)
+        })
+}
+
+fn action_time_total(path: &Path, content: &str) -> ActionTimeTotal {
+    let (total_minutes, entry_count) = parse_time_log(content);
+    ActionTimeTotal {
+        action_path: path.to_string_lossy().to_string(),
+        action_name: path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+        total_minutes,
+        entry_count,
+    }
+}
+
+fn load_running_timer(app: &AppHandle) -> Result<Option<RunningTimer>, String> {
+    let store = app
+        .store(TIMER_STORE_FILE)
+        .map_err(|e| format!("Failed to open timer store: {}", e))?;
+    Ok(store
+        .get(RUNNING_TIMER_KEY)
+        .and_then(|value| serde_json::from_value(value).ok()))
+}
+
+fn save_running_timer(app: &AppHandle, timer: Option<&RunningTimer>) -> Result<(), String> {
+    let store = app
+        .store(TIMER_STORE_FILE)
+        .map_err(|e| format!("Failed to open timer store: {}", e))?;
+    match timer {
+        Some(timer) => store.set(
+            RUNNING_TIMER_KEY,
+            serde_json::to_value(timer).map_err(|e| e.to_string())?,
+        ),
+        None => {
+            store.delete(RUNNING_TIMER_KEY);
+        }
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save timer store: {}", e))
+}
+
+/// Point the running timer at its action's new path after a folder move.
+///
+/// `remap` returns the new path, or `None` when the action did not move.
+/// Returns whether the stored timer changed.
+pub(crate) fn remap_running_timer(
+    app: &AppHandle,
+    remap: impl Fn(&str) -> Option<String>,
+) -> Result<bool, String> {
+    let Some(mut timer) = load_running_timer(app)? else {
+        return Ok(false);
+    };
+    let Some(new_path) = remap(&timer.action_path) else {
+        return Ok(false);
+    };
+    timer.action_path = new_path;
+    save_running_timer(app, Some(&timer))?;
+    Ok(true)
+}
+
+/// Start timing an action
+///
+/// Only one timer runs at a time; starting while another action's timer is
+/// running fails. Starting the already-running action returns its timer.
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+///
+/// # Returns
+///
+/// The running timer or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const timer = await invoke<RunningTimer>('start_action_timer', {
+///   actionPath: '/path/to/space/Projects/Website/Write copy.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn start_action_timer(app: AppHandle, action_path: String) -> Result<RunningTimer, String> {
+    log::info!("Starting timer for action: {}", action_path);
+
+    if !Path::new(&action_path).is_file() {
+        return Err(format!("Action file does not exist: {}", action_path));
+    }
+    if let Some(running) = load_running_timer(&app)? {
+        if running.action_path == action_path {
+            return Ok(running);
+        }
+        return Err(format!(
+            "A timer is already running for {}; stop it first",
+            running.action_path
+        ));
+    }
+
+    let timer = RunningTimer {
+        action_path,
+        started_at: Local::now().to_rfc3339(),
+    };
+    save_running_timer(&app, Some(&timer))?;
+    Ok(timer)
+}
+
+/// Get the running timer, if any
+///
+/// # Returns
+///
+/// The running timer, or `null` when none is running
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const timer = await invoke<RunningTimer | null>('get_running_action_timer');
+/// ```
+#[tauri::command]
+pub fn get_running_action_timer(app: AppHandle) -> Result<Option<RunningTimer>, String> {
+    load_running_timer(&app)
+}
+
+/// Stop an action's timer and log the time
+///
+/// Appends `| date | start | end | H:MM | note |` to the action's
+/// `## Time Log` table. If the action file was deleted meanwhile, the timer
+/// is still cleared and the elapsed time returned; the orphaned entry is
+/// written to the application log instead.
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+/// * `note` - Optional note for the log row
+///
+/// # Returns
+///
+/// The stopped timer with its duration, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const stopped = await invoke<StoppedTimer>('stop_action_timer', {
+///   actionPath: '/path/to/space/Projects/Website/Write copy.md',
+///   note: 'First draft',
+/// });
+/// ```
+#[tauri::command]
+pub fn stop_action_timer(
+    app: AppHandle,
+    action_path: String,
+    note: Option<String>,
+) -> Result<StoppedTimer, String> {
+    log::info!("Stopping timer for action: {}", action_path);
+
+    let running = load_running_timer(&app)?
+        .filter(|running| running.action_path == action_path)
+        .ok_or_else(|| format!("No timer is running for {}", action_path))?;
+    let start = DateTime::parse_from_rfc3339(&running.started_at)
+        .map_err(|e| format!("Invalid timer start time: {}", e))?
+        .with_timezone(&Local);
+    let end = Local::now();
+    let minutes = (end - start).num_minutes().max(0) as u64;
+    let row = format_time_log_row(start, end, minutes, note.as_deref().unwrap_or_default());
+
+    let path = Path::new(&action_path);
+    let logged = if path.is_file() {
+        with_file_lock(path, || {
+            let content = read_file(action_path.clone())?;
+            save_file_contents(path, &insert_time_log_row(&content, &row))
+        })?;
+        true
+    } else {
+        log::warn!(
+            "Timer stopped for deleted action {}; orphaned time log entry: {}",
+            action_path,
+            row
+        );
+        false
+    };
+    save_running_timer(&app, None)?;
+
+    Ok(StoppedTimer {
+        action_path,
+        started_at: running.started_at,
+        ended_at: end.to_rfc3339(),
+        duration_minutes: minutes,
+        logged,
+    })
+}
+
+/// Total time logged on an action
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+///
+/// # Returns
+///
+/// Minutes and row count from the action's Time Log table
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const total = await invoke<ActionTimeTotal>('get_action_time_total', {
+///   actionPath: '/path/to/space/Projects/Website/Write copy.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_action_time_total(action_path: String) -> Result<ActionTimeTotal, String> {
+    let content = read_file(action_path.clone())?;
+    Ok(action_time_total(Path::new(&action_path), &content))
+}
+
+/// Time logged across every action in a project
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder
+///
+/// # Returns
+///
+/// Project total plus per-action totals for actions with logged time
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const totals = await invoke<ProjectTimeTotals>('get_project_time_totals', {
+///   projectPath: '/path/to/space/Projects/Website',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_project_time_totals(project_path: String) -> Result<ProjectTimeTotals, String> {
+    log::info!("Totaling logged time in project: {}", project_path);
+
+    let mut actions: Vec<ActionTimeTotal> = list_project_actions(project_path)?
+        .into_iter()
+        .filter_map(|file| {
+            let content = read_file(file.path.clone()).ok()?;
+            Some(action_time_total(Path::new(&file.path), &content))
+        })
+        .filter(|total| total.entry_count > 0)
+        .collect();
+    actions.sort_by(|a, b| {
+        b.total_minutes
+            .cmp(&a.total_minutes)
+            .then_with(|| a.action_name.cmp(&b.action_name))
+    });
+
+    Ok(ProjectTimeTotals {
+        total_minutes: actions.iter().map(|action| action.total_minutes).sum(),
+        actions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::seed_data::generate_action_template;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Local> {
+        Local
+            .with_ymd_and_hms(2026, 3, 2, hour, minute, 0)
+            .single()
+            .expect("valid local time")
+    }
+
+    #[test]
+    fn time_log_rows_are_appended_and_totaled() {
+        let template = generate_action_template(
+            "Write copy",
+            "in-progress",
+            None,
+            None,
+            "small",
+            None,
+            None,
+            None,
+        );
+
+        let first = format_time_log_row(at(9, 0), at(10, 30), 90, "Draft | outline\nv1");
+        assert_eq!(
+            first,
+            "| 2026-03-02 | 09:00 | 10:30 | 1:30 | Draft \\| outline v1 |"
+        );
+        let content = insert_time_log_row(&template, &first);
+        assert!(content.contains(&format!(
+            "{}\n\n{}\n{}\n{}\n\n---\n## Created",
+            TIME_LOG_HEADING, TIME_LOG_HEADER, TIME_LOG_SEPARATOR, first
+        )));
+
+        let second = format_time_log_row(at(14, 0), at(14, 20), 20, "");
+        let content = insert_time_log_row(&content, &second);
+        assert!(content.contains(&format!("{}\n{}\n", first, second)));
+        assert_eq!(parse_time_log(&content), (110, 2));
+        assert_eq!(parse_time_log(&template), (0, 0));
+    }
+
+    #[test]
+    fn project_totals_sum_logged_actions() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Timed Project");
+        let table = format!(
+            "{}\n\n{}\n{}\n",
+            TIME_LOG_HEADING, TIME_LOG_HEADER, TIME_LOG_SEPARATOR
+        );
+        write_test_file(project.join("README.md"), "# Timed Project\n")?;
+        write_test_file(
+            project.join("Design.md"),
+            &format!(
+                "# Design\n\n{}| 2026-03-02 | 09:00 | 11:15 | 2:15 | |\n| 2026-03-03 | 09:00 | 09:45 | 0:45 | review |\n",
+                table
+            ),
+        )?;
+        write_test_file(
+            project.join("Build.md"),
+            &format!(
+                "# Build\n\n{}| 2026-03-04 | 13:00 | 14:00 | 1:00 | |\n",
+                table
+            ),
+        )?;
+        write_test_file(project.join("Untimed.md"), "# Untimed\n")?;
+
+        let totals = get_project_time_totals(project.to_string_lossy().to_string())?;
+        assert_eq!(totals.total_minutes, 240);
+        let summary: Vec<(&str, u64, usize)> = totals
+            .actions
+            .iter()
+            .map(|a| (a.action_name.as_str(), a.total_minutes, a.entry_count))
+            .collect();
+        assert_eq!(summary, vec![("Design", 180, 2), ("Build", 60, 1)]);
+
+        Ok(())
+    }
+}
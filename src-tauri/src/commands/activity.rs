@@ -0,0 +1,483 @@
+//! Workspace activity feed built from file metadata.
+
+use chrono::{Datelike, Duration, Local, NaiveDate, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Component, Path};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::field_vocabulary::field_vocabulary_or_default;
+use super::filesystem::{list_project_actions, scan_directory_recursive, MarkdownFile};
+use super::gtd_habits_domain::{parse_history_rows, HabitStatus};
+use super::gtd_projects::{list_gtd_projects, resolve_project_readme_path};
+use super::project_velocity::{completion_time, compute_project_velocity};
+
+/// Files modified within this many seconds of creation count as "created".
+const CREATED_WINDOW_SECS: u64 = 5;
+
+/// Longest completion trend returned, in weeks
+const MAX_TREND_WEEKS: u32 = 520;
+
+/// A recent change to a markdown file in the GTD space
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ActivityEvent {
+    /// Full path of the changed file
+    pub file_path: String,
+    /// File name without path
+    pub file_name: String,
+    /// Time of the change (Unix timestamp, seconds)
+    pub event_time: u64,
+    /// Either "created" or "modified"
+    pub event_type: String,
+    /// Top-level GTD section containing the file ("Projects", "Habits", ...)
+    pub section: String,
+}
+
+/// Actions last modified on one local calendar day
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct DailyActionCount {
+    /// Local date (YYYY-MM-DD)
+    pub date: String,
+    pub count: u32,
+}
+
+/// Completions in one Monday-to-Sunday week
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct WeeklyCount {
+    /// Monday of the week (YYYY-MM-DD)
+    pub week_start: String,
+    pub actions_completed: u32,
+    pub habits_completed: u32,
+    pub projects_closed: u32,
+}
+
+fn system_time_to_secs(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+fn section_for_path(space_root: &Path, file_path: &Path) -> String {
+    let relative = match file_path.strip_prefix(space_root) {
+        Ok(relative) => relative,
+        Err(_) => return String::new(),
+    };
+
+    let mut components = relative.components();
+    match (components.next(), components.next()) {
+        (Some(Component::Normal(section)), Some(_)) => section.to_string_lossy().to_string(),
+        _ => "Root".to_string(),
+    }
+}
+
+fn activity_event_for_file(space_root: &Path, file: MarkdownFile) -> ActivityEvent {
+    let path = Path::new(&file.path);
+    // Not every filesystem records creation time; treat those files as modified.
+    let created_at = fs::metadata(path)
+        .and_then(|metadata| metadata.created())
+        .ok()
+        .and_then(system_time_to_secs);
+    let event_type = match created_at {
+        Some(created) if file.last_modified.abs_diff(created) <= CREATED_WINDOW_SECS => "created",
+        _ => "modified",
+    };
+
+    ActivityEvent {
+        section: section_for_path(space_root, path),
+        file_path: file.path,
+        file_name: file.name,
+        event_time: file.last_modified,
+        event_type: event_type.to_string(),
+    }
+}
+
+/// Get the most recent file activity across a GTD space
+///
+/// Scans every markdown file in the space and reports its latest change,
+/// newest first. A file whose modification time is within a few seconds of
+/// its creation time is reported as `created`, otherwise as `modified`.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `limit` - Maximum number of events to return
+///
+/// # Returns
+///
+/// Activity events sorted by `event_time` descending, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const feed = await invoke<ActivityEvent[]>('get_workspace_activity_feed', {
+///   spacePath: '/path/to/space',
+///   limit: 50,
+/// });
+/// ```
+#[tauri::command]
+pub fn get_workspace_activity_feed(
+    space_path: String,
+    limit: usize,
+) -> Result<Vec<ActivityEvent>, String> {
+    log::info!(
+        "Building activity feed for: {} (limit {})",
+        space_path,
+        limit
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Invalid directory path".to_string());
+    }
+
+    let mut files = Vec::new();
+    scan_directory_recursive(space_root, &mut files)?;
+
+    let mut events: Vec<ActivityEvent> = files
+        .into_iter()
+        .map(|file| activity_event_for_file(space_root, file))
+        .collect();
+    events.sort_by(|a, b| {
+        b.event_time
+            .cmp(&a.event_time)
+            .then_with(|| a.file_path.cmp(&b.file_path))
+    });
+    events.truncate(limit);
+
+    Ok(events)
+}
+
+/// One entry per day of `year`, counting the timestamps that fall on it in
+/// local time.
+fn tally_daily_counts(
+    year: i32,
+    timestamps: impl IntoIterator<Item = u64>,
+) -> Result<Vec<DailyActionCount>, String> {
+    let first_day =
+        NaiveDate::from_ymd_opt(year, 1, 1).ok_or_else(|| format!("Invalid year: {}", year))?;
+    let days_in_year = NaiveDate::from_ymd_opt(year, 12, 31)
+        .ok_or_else(|| format!("Invalid year: {}", year))?
+        .ordinal() as usize;
+
+    let mut counts = vec![0u32; days_in_year];
+    for timestamp in timestamps {
+        let Some(modified) = Local.timestamp_opt(timestamp as i64, 0).single() else {
+            continue;
+        };
+        let date = modified.date_naive();
+        if date.year() == year {
+            counts[date.ordinal0() as usize] += 1;
+        }
+    }
+
+    Ok(first_day
+        .iter_days()
+        .zip(counts)
+        .map(|(date, count)| DailyActionCount {
+            date: date.format("%Y-%m-%d").to_string(),
+            count,
+        })
+        .collect())
+}
+
+/// Count actions modified on each day of a year
+///
+/// Every action file across all projects is dated by its last modification
+/// time in local time. Powers the contribution-graph style activity heatmap.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `year` - Calendar year to report
+///
+/// # Returns
+///
+/// One entry per day of the year (365 or 366) in date order, with zero
+/// counts for quiet days, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const days = await invoke<DailyActionCount[]>('get_daily_action_counts', {
+///   spacePath: '/path/to/space',
+///   year: 2026,
+/// });
+/// ```
+#[tauri::command]
+pub fn get_daily_action_counts(
+    space_path: String,
+    year: i32,
+) -> Result<Vec<DailyActionCount>, String> {
+    log::info!(
+        "Counting daily action changes for {} in: {}",
+        year,
+        space_path
+    );
+
+    if !Path::new(&space_path).is_dir() {
+        return Err("Invalid directory path".to_string());
+    }
+
+    let mut timestamps = Vec::new();
+    for project in list_gtd_projects(space_path)? {
+        match list_project_actions(project.path.clone()) {
+            Ok(actions) => timestamps.extend(actions.iter().map(|action| action.last_modified)),
+            Err(error) => log::warn!("Skipping actions in {}: {}", project.path, error),
+        }
+    }
+    tally_daily_counts(year, timestamps)
+}
+
+/// Completion times gathered for the weekly trend
+#[derive(Debug, Default)]
+struct CompletionTimes {
+    actions: Vec<NaiveDateTime>,
+    habits: Vec<NaiveDateTime>,
+    projects: Vec<NaiveDateTime>,
+}
+
+/// `weeks` entries ending with the week containing `today`, oldest first.
+fn tally_weekly_counts(today: NaiveDate, weeks: u32, times: &CompletionTimes) -> Vec<WeeklyCount> {
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    let first_monday = this_monday - Duration::weeks(weeks.saturating_sub(1) as i64);
+    let mut counts: Vec<WeeklyCount> = (0..weeks)
+        .map(|week| WeeklyCount {
+            week_start: (first_monday + Duration::weeks(week as i64))
+                .format("%Y-%m-%d")
+                .to_string(),
+            actions_completed: 0,
+            habits_completed: 0,
+            projects_closed: 0,
+        })
+        .collect();
+
+    let week_index = |time: &NaiveDateTime| {
+        let days = (time.date() - first_monday).num_days();
+        (days >= 0)
+            .then_some((days / 7) as usize)
+            .filter(|index| *index < weeks as usize)
+    };
+    for index in times.actions.iter().filter_map(week_index) {
+        counts[index].actions_completed += 1;
+    }
+    for index in times.habits.iter().filter_map(week_index) {
+        counts[index].habits_completed += 1;
+    }
+    for index in times.projects.iter().filter_map(week_index) {
+        counts[index].projects_closed += 1;
+    }
+    counts
+}
+
+/// Completion times of actions, habit check-offs, and closed projects.
+fn collect_completion_times(space_root: &Path) -> Result<CompletionTimes, String> {
+    let mut times = CompletionTimes::default();
+
+    for project in list_gtd_projects(space_root.to_string_lossy().to_string())? {
+        let project_dir = Path::new(&project.path);
+        match compute_project_velocity(project_dir) {
+            Ok(velocity) => times.actions.extend(velocity.completed_at),
+            Err(error) => log::warn!("Skipping actions in {}: {}", project.path, error),
+        }
+
+        let vocabulary = field_vocabulary_or_default(project_dir);
+        let closed = matches!(
+            vocabulary
+                .builtin_status(&project.status)
+                .to_ascii_lowercase()
+                .as_str(),
+            "completed" | "complete" | "done"
+        );
+        if !closed {
+            continue;
+        }
+        let closed_at = resolve_project_readme_path(project_dir).and_then(|readme| {
+            let content = fs::read_to_string(&readme).ok()?;
+            completion_time(&content, &readme)
+        });
+        times.projects.extend(closed_at);
+    }
+
+    // Archived history files hold older rows, so they are counted too
+    let habits_dir = space_root.join("Habits");
+    if habits_dir.is_dir() {
+        let mut files = Vec::new();
+        scan_directory_recursive(&habits_dir, &mut files)?;
+        for file in files {
+            let Ok(content) = fs::read_to_string(&file.path) else {
+                continue;
+            };
+            times.habits.extend(
+                parse_history_rows(&content)
+                    .into_iter()
+                    .filter(|row| row.status == HabitStatus::Completed.history_label())
+                    .map(|row| row.timestamp),
+            );
+        }
+    }
+
+    Ok(times)
+}
+
+/// Count completions per week for a trend sparkline
+///
+/// Actions and projects count in the week their `completed_date` marker
+/// names or, without one, the week their file was last modified, since the
+/// status marker carries no timestamp. Habits count each `Complete` row in
+/// their history.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `weeks` - Number of weeks to report, ending with the current week
+///
+/// # Returns
+///
+/// One entry per week in chronological order, oldest first, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const trend = await invoke<WeeklyCount[]>('get_weekly_completion_trend', {
+///   spacePath: '/path/to/space',
+///   weeks: 12,
+/// });
+/// ```
+#[tauri::command]
+pub fn get_weekly_completion_trend(
+    space_path: String,
+    weeks: u32,
+) -> Result<Vec<WeeklyCount>, String> {
+    log::info!(
+        "Building {}-week completion trend for: {}",
+        weeks,
+        space_path
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Invalid directory path".to_string());
+    }
+
+    let times = collect_completion_times(space_root)?;
+    Ok(tally_weekly_counts(
+        Local::now().date_naive(),
+        weeks.min(MAX_TREND_WEEKS),
+        &times,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn activity_feed_reports_sections_and_respects_limit() -> Result<(), String> {
+        let temp_dir = seed_test_workspace()?;
+        let space_path = temp_dir.path().to_string_lossy().to_string();
+
+        let events = get_workspace_activity_feed(space_path.clone(), 10)?;
+        assert_eq!(events.len(), 2);
+        let sections: Vec<&str> = events.iter().map(|e| e.section.as_str()).collect();
+        assert!(sections.contains(&"Projects"));
+        assert!(sections.contains(&"Goals"));
+        assert!(events
+            .iter()
+            .all(|e| e.event_type == "created" || e.event_type == "modified"));
+        assert!(events
+            .windows(2)
+            .all(|w| w[0].event_time >= w[1].event_time));
+
+        assert_eq!(get_workspace_activity_feed(space_path, 1)?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn daily_counts_cover_every_day_of_the_year() -> Result<(), String> {
+        let at_noon = |y, m, d| {
+            Local
+                .with_ymd_and_hms(y, m, d, 12, 0, 0)
+                .single()
+                .map(|time| time.timestamp() as u64)
+                .ok_or("ambiguous local time")
+        };
+        let days = tally_daily_counts(
+            2024,
+            [
+                at_noon(2024, 2, 29)?,
+                at_noon(2024, 2, 29)?,
+                at_noon(2024, 12, 31)?,
+                at_noon(2025, 1, 1)?,
+            ],
+        )?;
+        assert_eq!(days.len(), 366);
+        assert_eq!(days[0].date, "2024-01-01");
+        assert_eq!(
+            days[59],
+            DailyActionCount {
+                date: "2024-02-29".to_string(),
+                count: 2,
+            }
+        );
+        assert_eq!(days[365].count, 1);
+        assert_eq!(days.iter().map(|day| day.count).sum::<u32>(), 3);
+        assert_eq!(tally_daily_counts(2026, [])?.len(), 365);
+
+        let temp_dir = seed_test_workspace()?;
+        let project = temp_dir.path().join("Projects/Alpha Project");
+        write_test_file(project.join("Draft.md"), "# Draft\n")?;
+        write_test_file(project.join("Review.md"), "# Review\n")?;
+        let today = Local::now().date_naive();
+        let counts =
+            get_daily_action_counts(temp_dir.path().to_string_lossy().to_string(), today.year())?;
+        assert_eq!(counts[today.ordinal0() as usize].count, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn weekly_trend_buckets_completions_by_monday() -> Result<(), String> {
+        let at = |y, m, d| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .and_then(|date| date.and_hms_opt(9, 30, 0))
+                .ok_or("invalid test date")
+        };
+        let times = CompletionTimes {
+            actions: vec![at(2026, 3, 2)?, at(2026, 3, 8)?, at(2026, 1, 1)?],
+            habits: vec![at(2026, 3, 9)?],
+            projects: vec![at(2026, 2, 23)?],
+        };
+        let today = NaiveDate::from_ymd_opt(2026, 3, 11).ok_or("invalid test date")?;
+        let weeks = tally_weekly_counts(today, 3, &times);
+        let starts: Vec<&str> = weeks.iter().map(|week| week.week_start.as_str()).collect();
+        assert_eq!(starts, vec!["2026-02-23", "2026-03-02", "2026-03-09"]);
+        assert_eq!(weeks[0].projects_closed, 1);
+        assert_eq!(weeks[1].actions_completed, 2);
+        assert_eq!(weeks[2].habits_completed, 1);
+        assert!(tally_weekly_counts(today, 0, &times).is_empty());
+
+        let temp_dir = seed_test_workspace()?;
+        let root = temp_dir.path();
+        write_test_file(
+            root.join("Projects/Alpha Project/Done.md"),
+            "# Done\n\n[!singleselect:status:completed]\n",
+        )?;
+        let today = Local::now().format("%Y-%m-%d");
+        write_test_file(
+            root.join("Habits/Stretch.md"),
+            &format!(
+                "# Stretch\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n| {} | 8:00 AM | Complete | Manual | Changed from To Do to Complete |\n",
+                today
+            ),
+        )?;
+        let trend = get_weekly_completion_trend(root.to_string_lossy().to_string(), 12)?;
+        assert_eq!(trend.len(), 12);
+        assert_eq!(trend[11].actions_completed, 1);
+        assert_eq!(trend[11].habits_completed, 1);
+        Ok(())
+    }
+}
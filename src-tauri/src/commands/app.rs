@@ -1,7 +1,16 @@
 //! Lightweight app-level Tauri commands.
 
 use serde::{Deserialize, Serialize};
-use tauri::AppHandle;
+use std::sync::Mutex;
+use std::time::Instant;
+use tauri::{AppHandle, Manager};
+
+use crate::mcp_settings::settings_store_path;
+
+lazy_static::lazy_static! {
+    static ref APP_START_TIME: Instant = Instant::now();
+    static ref LAST_COMMAND_ERROR: Mutex<Option<String>> = Mutex::new(None);
+}
 
 /// Response structure for permission check command
 #[derive(Debug, Serialize, Deserialize)]
@@ -14,6 +23,77 @@ pub struct PermissionStatus {
     pub can_open_dialogs: bool,
 }
 
+/// Runtime diagnostics returned by `get_app_health`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppHealth {
+    /// Whether a file watcher task is currently active
+    pub file_watcher_running: bool,
+    /// Whether the Google Calendar manager has been created this session
+    pub google_calendar_manager_initialized: bool,
+    /// Whether the settings store could be opened
+    pub settings_store_accessible: bool,
+    /// Resolved application data directory
+    pub app_data_dir: String,
+    /// Application version string
+    pub app_version: String,
+    /// Seconds elapsed since the app started
+    pub uptime_seconds: u64,
+    /// Most recent error returned by a tracked command
+    pub last_error: Option<String>,
+}
+
+/// Pin the app start time used for uptime reporting.
+pub(crate) fn mark_app_started() {
+    lazy_static::initialize(&APP_START_TIME);
+}
+
+/// Remember the most recent command error for `get_app_health`.
+pub(crate) fn record_command_error(command: &str, error: &str) {
+    let entry = format!(
+        "[{}] {}: {}",
+        chrono::Utc::now().to_rfc3339(),
+        command,
+        error
+    );
+    match LAST_COMMAND_ERROR.lock() {
+        Ok(mut last_error) => *last_error = Some(entry),
+        Err(poisoned) => *poisoned.into_inner() = Some(entry),
+    }
+}
+
+/// Pass a command result through, recording it if it is an error.
+pub(crate) fn track_command_result<T>(
+    command: &str,
+    result: Result<T, String>,
+) -> Result<T, String> {
+    if let Err(error) = &result {
+        record_command_error(command, error);
+    }
+    result
+}
+
+fn last_command_error() -> Option<String> {
+    match LAST_COMMAND_ERROR.lock() {
+        Ok(last_error) => last_error.clone(),
+        Err(poisoned) => poisoned.into_inner().clone(),
+    }
+}
+
+fn settings_store_accessible(app: &AppHandle) -> bool {
+    let store_path = settings_store_path();
+    if tauri_plugin_store::StoreExt::get_store(app, store_path.clone()).is_some() {
+        return true;
+    }
+
+    match tauri_plugin_store::StoreBuilder::new(app, store_path).build() {
+        Ok(_) => true,
+        Err(e) => {
+            log::warn!("Settings store is not accessible: {}", e);
+            false
+        }
+    }
+}
+
 /// Simple ping command to test frontend-backend communication
 ///
 /// This command serves as a basic connectivity test between the React
@@ -113,3 +193,50 @@ pub fn check_permissions() -> Result<PermissionStatus, String> {
     log::info!("Permission check requested; desktop permission checks are not implemented yet");
     Err("Permission checks are not implemented for desktop yet".to_string())
 }
+
+/// Collect runtime diagnostics for support and debugging
+///
+/// Reports whether background services are running, whether the settings
+/// store can be opened, where app data lives, and the last error recorded
+/// by a tracked command. Safe to call at any time; it never initializes
+/// services that are not already running.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for accessing app metadata and paths
+///
+/// # Returns
+///
+/// `AppHealth` snapshot or error message
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const health = await invoke<AppHealth>('get_app_health');
+/// console.log(`Uptime: ${health.uptime_seconds}s`, health.last_error);
+/// ```
+#[tauri::command]
+pub async fn get_app_health(app: AppHandle) -> Result<AppHealth, String> {
+    log::info!("App health requested");
+
+    let app_data_dir = match app.path().app_data_dir() {
+        Ok(dir) => dir.to_string_lossy().to_string(),
+        Err(e) => {
+            record_command_error("get_app_health", &e.to_string());
+            String::new()
+        }
+    };
+
+    Ok(AppHealth {
+        file_watcher_running: super::watcher::is_file_watcher_running().await,
+        google_calendar_manager_initialized:
+            super::google_calendar_commands::is_google_calendar_manager_initialized().await,
+        settings_store_accessible: settings_store_accessible(&app),
+        app_data_dir,
+        app_version: app.package_info().version.to_string(),
+        uptime_seconds: APP_START_TIME.elapsed().as_secs(),
+        last_error: last_command_error(),
+    })
+}
@@ -0,0 +1,308 @@
+//! App data location and cache maintenance commands.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+use walkdir::WalkDir;
+
+use super::space_config::SPACE_CONFIG_DIR;
+use crate::backend::mcp_workspace::context_cache_root;
+use crate::google_calendar::cache::google_calendar_cache_path;
+use crate::google_calendar::token_manager::TokenManager;
+use crate::mcp_settings::settings_store_path;
+
+/// Locations of the files and folders the app keeps outside the GTD space
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AppPaths {
+    /// Application data directory
+    pub app_data_dir: String,
+    /// Application config directory
+    pub app_config_dir: String,
+    /// Application cache directory
+    pub app_cache_dir: String,
+    /// Application log directory
+    pub app_log_dir: String,
+    /// Settings store file (settings.json)
+    pub settings_file: String,
+    /// Google Calendar OAuth token store
+    pub google_calendar_token_file: String,
+    /// Cached Google Calendar events
+    pub google_calendar_cache_file: String,
+    /// MCP context pack cache
+    pub content_cache_dir: String,
+    /// Internal `.gtdspace` folders for the configured spaces
+    pub space_internal_dirs: Vec<String>,
+}
+
+/// Cache categories that `clear_app_caches` can wipe
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AppCacheKind {
+    /// MCP context packs
+    Content,
+    /// Search index; search reads the space directly, so there is none on
+    /// disk and clearing it reports not available
+    SearchIndex,
+    /// Unsaved drafts; drafts live in the editor's tabs, so there is nothing
+    /// on disk and clearing them reports not available
+    Drafts,
+    /// Cached Google Calendar events
+    GoogleEvents,
+}
+
+/// Outcome of clearing one cache category
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClearedCache {
+    /// Cache category that was cleared
+    pub kind: AppCacheKind,
+    /// False when the app keeps no such cache on disk
+    pub available: bool,
+    /// Path that was removed, when the cache is available
+    pub path: Option<String>,
+    /// Bytes freed on disk
+    pub bytes_freed: u64,
+}
+
+/// Result of `clear_app_caches`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ClearAppCachesResult {
+    /// Per-category results
+    pub cleared: Vec<ClearedCache>,
+    /// Total bytes freed across all categories
+    pub total_bytes_freed: u64,
+}
+
+fn path_string(path: &Path) -> String {
+    path.to_string_lossy().to_string()
+}
+
+fn app_data_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))
+}
+
+fn app_cache_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_cache_dir()
+        .map_err(|e| format!("Failed to resolve app cache directory: {}", e))
+}
+
+/// Where a cache category lives on disk, or `None` when the app keeps none.
+fn cache_path_for_kind(kind: AppCacheKind) -> Result<Option<PathBuf>, String> {
+    match kind {
+        AppCacheKind::Content => context_cache_root().map(Some),
+        AppCacheKind::GoogleEvents => google_calendar_cache_path().map(Some),
+        AppCacheKind::SearchIndex | AppCacheKind::Drafts => Ok(None),
+    }
+}
+
+fn size_on_disk(path: &Path) -> u64 {
+    if path.is_file() {
+        return fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+    }
+
+    WalkDir::new(path)
+        .follow_links(false)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| entry.metadata().ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+fn remove_cache_path(path: &Path) -> Result<u64, String> {
+    if !path.exists() {
+        return Ok(0);
+    }
+
+    let bytes = size_on_disk(path);
+    let result = if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+    result.map_err(|e| format!("Failed to clear cache {}: {}", path.display(), e))?;
+
+    Ok(bytes)
+}
+
+/// Get the locations of settings, tokens, logs, and caches
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for resolving app directories
+///
+/// # Returns
+///
+/// `AppPaths` with every known location, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const paths = await invoke<AppPaths>('get_app_paths');
+/// console.log(paths.settings_file);
+/// ```
+#[tauri::command]
+pub async fn get_app_paths(app: AppHandle) -> Result<AppPaths, String> {
+    let data_dir = app_data_dir(&app)?;
+    let resolver = app.path();
+    let config_dir = resolver
+        .app_config_dir()
+        .map_err(|e| format!("Failed to resolve app config directory: {}", e))?;
+    let log_dir = resolver
+        .app_log_dir()
+        .map_err(|e| format!("Failed to resolve app log directory: {}", e))?;
+
+    let mut space_internal_dirs = Vec::new();
+    if let Ok(settings) = super::settings::load_settings(app.clone()).await {
+        for space in [settings.default_space_path, settings.last_folder]
+            .into_iter()
+            .flatten()
+        {
+            let internal_dir = path_string(&Path::new(&space).join(SPACE_CONFIG_DIR));
+            if !space_internal_dirs.contains(&internal_dir) {
+                space_internal_dirs.push(internal_dir);
+            }
+        }
+    }
+
+    Ok(AppPaths {
+        app_data_dir: path_string(&data_dir),
+        app_config_dir: path_string(&config_dir),
+        app_cache_dir: path_string(&app_cache_dir(&app)?),
+        app_log_dir: path_string(&log_dir),
+        settings_file: path_string(&data_dir.join(settings_store_path())),
+        google_calendar_token_file: path_string(&TokenManager::token_file_path(&data_dir)),
+        google_calendar_cache_file: path_string(&google_calendar_cache_path()?),
+        content_cache_dir: path_string(&context_cache_root()?),
+        space_internal_dirs,
+    })
+}
+
+/// Open the app data directory in the system file explorer
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for resolving the directory
+///
+/// # Returns
+///
+/// Success message or error details
+#[tauri::command]
+pub fn open_app_data_dir(app: AppHandle) -> Result<String, String> {
+    let data_dir = app_data_dir(&app)?;
+    fs::create_dir_all(&data_dir)
+        .map_err(|e| format!("Failed to create app data directory: {}", e))?;
+    super::dialogs::open_folder_in_explorer(path_string(&data_dir))
+}
+
+/// Delete selected app caches
+///
+/// Settings are never touched here; use `reset_settings` for that. The app
+/// keeps no search index or drafts on disk, so those kinds come back with
+/// `available: false`.
+///
+/// # Arguments
+///
+/// * `kinds` - Cache categories to clear (`content`, `search_index`, `drafts`, `google_events`)
+///
+/// # Returns
+///
+/// Bytes freed per category and in total, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke('clear_app_caches', { kinds: ['content', 'google_events'] });
+/// console.log(`Freed ${result.total_bytes_freed} bytes`);
+/// ```
+#[tauri::command]
+pub fn clear_app_caches(kinds: Vec<AppCacheKind>) -> Result<ClearAppCachesResult, String> {
+    log::info!("Clearing app caches: {:?}", kinds);
+
+    let mut cleared = Vec::new();
+    for kind in kinds {
+        if cleared
+            .iter()
+            .any(|entry: &ClearedCache| entry.kind == kind)
+        {
+            continue;
+        }
+        let Some(path) = cache_path_for_kind(kind)? else {
+            log::info!("No {:?} cache is kept on disk; nothing to clear", kind);
+            cleared.push(ClearedCache {
+                kind,
+                available: false,
+                path: None,
+                bytes_freed: 0,
+            });
+            continue;
+        };
+        let bytes_freed = remove_cache_path(&path)?;
+        cleared.push(ClearedCache {
+            kind,
+            available: true,
+            path: Some(path_string(&path)),
+            bytes_freed,
+        });
+    }
+
+    let total_bytes_freed = cleared.iter().map(|entry| entry.bytes_freed).sum();
+    log::info!("Cleared app caches, freed {} bytes", total_bytes_freed);
+    Ok(ClearAppCachesResult {
+        cleared,
+        total_bytes_freed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::write_test_file;
+
+    #[test]
+    fn remove_cache_path_reports_bytes_for_directories_and_files() -> Result<(), String> {
+        let temp_dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let cache_dir = temp_dir.path().join("cache");
+        write_test_file(cache_dir.join("a.json"), "12345")?;
+        write_test_file(cache_dir.join("nested/b.json"), "123")?;
+        let cache_file = temp_dir.path().join("events.json");
+        write_test_file(&cache_file, "1234567")?;
+
+        assert_eq!(remove_cache_path(&cache_dir)?, 8);
+        assert!(!cache_dir.exists());
+        assert_eq!(remove_cache_path(&cache_file)?, 7);
+        assert_eq!(remove_cache_path(&cache_file)?, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn caches_the_app_does_not_keep_report_not_available() -> Result<(), String> {
+        let result = clear_app_caches(vec![
+            AppCacheKind::SearchIndex,
+            AppCacheKind::Drafts,
+            AppCacheKind::SearchIndex,
+        ])?;
+        let outline: Vec<(AppCacheKind, bool, Option<String>)> = result
+            .cleared
+            .into_iter()
+            .map(|entry| (entry.kind, entry.available, entry.path))
+            .collect();
+        assert_eq!(
+            outline,
+            vec![
+                (AppCacheKind::SearchIndex, false, None),
+                (AppCacheKind::Drafts, false, None),
+            ]
+        );
+        assert_eq!(result.total_bytes_freed, 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,161 @@
+//! Standards documents stored in an area of focus's `Standards/` folder.
+//!
+//! A standard describes what excellence looks like for the area, where it
+//! stands today, and the projects that close the gap.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use super::file_meta::record_file_change;
+use super::filesystem::{list_markdown_files, MarkdownFile};
+use super::seed_data::generate_area_standard_template;
+use super::space_config::ensure_space_writable;
+use super::utils::sanitize_markdown_file_stem;
+
+/// Folder inside an area holding standard documents
+const STANDARDS_DIR: &str = "Standards";
+
+/// Create a standard document inside an area of focus
+///
+/// # Arguments
+///
+/// * `area_dir_path` - Full path to the area's folder
+/// * `standard_name` - Name of the standard
+///
+/// # Returns
+///
+/// Path to the created standard file or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const path = await invoke<string>('create_area_standard', {
+///   areaDirPath: '/path/to/space/Areas of Focus/Health',
+///   standardName: 'Sleep',
+/// });
+/// ```
+#[tauri::command]
+pub fn create_area_standard(
+    area_dir_path: String,
+    standard_name: String,
+) -> Result<String, String> {
+    log::info!(
+        "Creating standard '{}' in area: {}",
+        standard_name,
+        area_dir_path
+    );
+
+    let area_dir = Path::new(&area_dir_path);
+    ensure_space_writable(area_dir)?;
+    if !area_dir.is_dir() {
+        return Err("Area directory does not exist".to_string());
+    }
+
+    let name = standard_name.trim();
+    if name.is_empty() {
+        return Err("Standard name cannot be empty".to_string());
+    }
+
+    let standards_dir = area_dir.join(STANDARDS_DIR);
+    fs::create_dir_all(&standards_dir)
+        .map_err(|e| format!("Failed to create Standards folder: {}", e))?;
+    let standard_path = standards_dir.join(format!("{}.md", sanitize_markdown_file_stem(name)));
+
+    let mut file = match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&standard_path)
+    {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(format!("Standard '{}' already exists", name));
+        }
+        Err(e) => return Err(format!("Failed to create standard file: {}", e)),
+    };
+    if let Err(e) = file.write_all(generate_area_standard_template(name).as_bytes()) {
+        drop(file);
+        let _ = fs::remove_file(&standard_path);
+        return Err(format!("Failed to create standard file: {}", e));
+    }
+
+    record_file_change(&standard_path);
+    Ok(standard_path.to_string_lossy().to_string())
+}
+
+/// List an area of focus's standard documents
+///
+/// # Arguments
+///
+/// * `area_dir_path` - Full path to the area's folder
+///
+/// # Returns
+///
+/// Standard files sorted by path (empty when the area has no `Standards`
+/// folder) or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const standards = await invoke<MarkdownFile[]>('list_area_standards', {
+///   areaDirPath: '/path/to/space/Areas of Focus/Health',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_area_standards(area_dir_path: String) -> Result<Vec<MarkdownFile>, String> {
+    log::info!("Listing standards in area: {}", area_dir_path);
+
+    let area_dir = Path::new(&area_dir_path);
+    if !area_dir.is_dir() {
+        return Err("Area directory does not exist".to_string());
+    }
+
+    let standards_dir = area_dir.join(STANDARDS_DIR);
+    if !standards_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    list_markdown_files(standards_dir.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::seed_test_workspace;
+
+    #[test]
+    fn standards_are_created_and_listed() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let area = workspace.path().join("Areas of Focus/Health");
+        fs::create_dir_all(&area).map_err(|e| e.to_string())?;
+        let area_path = area.to_string_lossy().to_string();
+        assert!(list_area_standards(area_path.clone())?.is_empty());
+
+        let path = create_area_standard(area_path.clone(), "Sleep".to_string())?;
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        assert!(content.starts_with("# Sleep Standard\n"));
+        for heading in [
+            "## What Excellence Looks Like",
+            "## Current Reality",
+            "## Gap Analysis",
+            "## Improvement Actions\n[!projects-references:]",
+        ] {
+            assert!(content.contains(heading), "missing {}", heading);
+        }
+
+        create_area_standard(area_path.clone(), "Fitness".to_string())?;
+        assert!(create_area_standard(area_path.clone(), "Sleep".to_string()).is_err());
+        assert!(create_area_standard(area_path.clone(), " ".to_string()).is_err());
+
+        let names: Vec<String> = list_area_standards(area_path)?
+            .into_iter()
+            .map(|file| file.name)
+            .collect();
+        assert_eq!(names, vec!["Fitness.md", "Sleep.md"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+//! Cheap counts for sidebar and dock badges.
+//!
+//! These commands run on every refresh, so they avoid reading whole trees:
+//! the inbox count only lists one directory and never opens a file, and the
+//! overdue count reads the metadata index.
+
+use chrono::{Local, NaiveDateTime};
+use std::fs;
+use std::path::Path;
+
+use super::due_summary::summarize_due;
+use super::file_meta::indexed_file_meta;
+
+fn count_inbox_items(inbox_dir: &Path) -> Result<u32, String> {
+    let entries = match fs::read_dir(inbox_dir) {
+        Ok(entries) => entries,
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(0),
+        Err(error) => return Err(format!("Failed to read Inbox directory: {}", error)),
+    };
+
+    let mut count = 0;
+    for entry in entries.flatten() {
+        let is_file = entry.file_type().is_ok_and(|file_type| file_type.is_file());
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if is_file && !name.starts_with('.') && name.to_ascii_lowercase().ends_with(".md") {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+fn count_overdue(space_root: &Path, now: NaiveDateTime) -> Result<u32, String> {
+    let (projects, actions) = summarize_due(space_root, indexed_file_meta(space_root)?, now, 0, 0);
+    Ok(projects.overdue.count + actions.overdue.count)
+}
+
+/// Count unprocessed inbox items for a badge
+///
+/// Counts the `.md` files directly inside `Inbox` without reading them.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// Number of inbox items (0 when the space has no `Inbox` folder) or error
+/// details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const count = await invoke<number>('get_inbox_count', { spacePath: '/path/to/space' });
+/// ```
+#[tauri::command]
+pub fn get_inbox_count(space_path: String) -> Result<u32, String> {
+    count_inbox_items(&Path::new(&space_path).join("Inbox"))
+}
+
+/// Count overdue actions and projects for a badge
+///
+/// Matches the overdue buckets of `get_due_summary`: completed and cancelled
+/// items are never overdue, and a date without a time is due at the end of
+/// that day. Reads the metadata index, so repeated calls are cheap.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// Number of overdue items (0 when the space has no `Projects` folder) or
+/// error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const count = await invoke<number>('get_overdue_count', { spacePath: '/path/to/space' });
+/// ```
+#[tauri::command]
+pub fn get_overdue_count(space_path: String) -> Result<u32, String> {
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    count_overdue(space_root, Local::now().naive_local())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn badge_counts_cover_inbox_files_and_overdue_items() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let space_path = workspace.path().to_string_lossy().to_string();
+        assert_eq!(get_inbox_count(space_path.clone())?, 0);
+
+        let inbox = workspace.path().join("Inbox");
+        write_test_file(inbox.join("Call plumber.md"), "Call plumber\n")?;
+        write_test_file(inbox.join("Receipt.MD"), "Receipt\n")?;
+        write_test_file(inbox.join("scan.pdf"), "")?;
+        write_test_file(inbox.join(".draft.md"), "")?;
+        write_test_file(inbox.join("Later/Nested.md"), "")?;
+        assert_eq!(get_inbox_count(space_path)?, 2);
+
+        let project = workspace.path().join("Projects/Alpha Project");
+        write_test_file(
+            project.join("Late.md"),
+            "# Late\n\n[!singleselect:status:in-progress]\n[!datetime:due_date:2026-03-01]\n",
+        )?;
+        write_test_file(
+            project.join("Done.md"),
+            "# Done\n\n[!singleselect:status:completed]\n[!datetime:due_date:2026-03-01]\n",
+        )?;
+        let now = chrono::NaiveDate::from_ymd_opt(2026, 4, 5)
+            .and_then(|date| date.and_hms_opt(9, 0, 0))
+            .ok_or("invalid date")?;
+        assert_eq!(count_overdue(workspace.path(), now)?, 2);
+        assert!(get_overdue_count(
+            workspace
+                .path()
+                .join("Missing")
+                .to_string_lossy()
+                .to_string()
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
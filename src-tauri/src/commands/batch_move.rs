@@ -0,0 +1,277 @@
+//! Moving many actions from one project to another in a single call.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use super::filesystem::move_file;
+use super::gtd_projects::validate_projects_child_directory;
+use super::pre_op_backups::backup_before_bulk_operation;
+use super::space_config::ensure_space_writable;
+use super::utils::{is_markdown_path, is_readme_path};
+
+/// Outcome of `batch_move_actions`, one entry per requested path
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BatchMoveResult {
+    /// `(old_path, new_path)` for every action moved
+    pub moved: Vec<(String, String)>,
+    /// `(path, reason)` for paths that were not actions of the source project
+    pub skipped: Vec<(String, String)>,
+    /// `(path, error)` for moves that were attempted and failed
+    pub failed: Vec<(String, String)>,
+}
+
+/// `dir/name`, or `dir/stem-2.ext`, `dir/stem-3.ext`, ... when taken.
+fn available_destination(dir: &Path, file_name: &str, reserved: &HashSet<PathBuf>) -> PathBuf {
+    let taken = |candidate: &Path| candidate.exists() || reserved.contains(candidate);
+    let candidate = dir.join(file_name);
+    if !taken(&candidate) {
+        return candidate;
+    }
+
+    let file_path = Path::new(file_name);
+    let stem = file_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    let extension = file_path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    let mut suffix = 2;
+    loop {
+        let candidate = dir.join(format!("{}-{}{}", stem, suffix, extension));
+        if !taken(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+/// Check each path and move it with `move_one`, collecting the outcomes.
+/// `source_dir` must be canonical; paths handed to `move_one` keep the
+/// caller's spelling so path remaps match what the frontend has open.
+fn move_actions_with<F>(
+    source_dir: &Path,
+    dest_dir: &Path,
+    action_paths: &[String],
+    mut move_one: F,
+) -> BatchMoveResult
+where
+    F: FnMut(&Path, &Path) -> Result<(), String>,
+{
+    let mut result = BatchMoveResult::default();
+    let mut seen = HashSet::new();
+    let mut reserved = HashSet::new();
+
+    // Resolve every path before moving anything, so a repeated path is
+    // recognized even after its first occurrence has moved
+    let resolved: Vec<(&String, Option<PathBuf>)> = action_paths
+        .iter()
+        .map(|action_path| (action_path, fs::canonicalize(action_path).ok()))
+        .collect();
+
+    for (action_path, canonical) in resolved {
+        let Some(canonical) = canonical else {
+            result.failed.push((
+                action_path.clone(),
+                "Action file does not exist".to_string(),
+            ));
+            continue;
+        };
+        if !seen.insert(canonical.clone()) {
+            result
+                .skipped
+                .push((action_path.clone(), "Listed more than once".to_string()));
+            continue;
+        }
+        if canonical.parent() != Some(source_dir) {
+            result.skipped.push((
+                action_path.clone(),
+                "Not an action of the source project".to_string(),
+            ));
+            continue;
+        }
+        if !canonical.is_file() || !is_markdown_path(&canonical) || is_readme_path(&canonical) {
+            result
+                .skipped
+                .push((action_path.clone(), "Not an action file".to_string()));
+            continue;
+        }
+
+        let file_name = canonical
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let dest = available_destination(dest_dir, &file_name, &reserved);
+        match move_one(Path::new(action_path), &dest) {
+            Ok(()) => {
+                reserved.insert(dest.clone());
+                result
+                    .moved
+                    .push((action_path.clone(), dest.to_string_lossy().to_string()));
+            }
+            Err(error) => result.failed.push((action_path.clone(), error)),
+        }
+    }
+    result
+}
+
+/// Move several actions from one project to another
+///
+/// Each action is moved like `move_file`, so action tables and open tabs
+/// follow it. Paths outside the source project are skipped. A name already
+/// taken in the destination gets a numeric suffix (`Call Sam-2.md`). Moves
+/// of more actions than the space's backup threshold are backed up to
+/// `.gtdspace/pre-op-backups` first.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for path remap events
+/// * `source_project_path` - Project folder the actions are in
+/// * `dest_project_path` - Project folder to move them to
+/// * `action_paths` - Full paths to the actions to move
+/// * `skip_backup` - Skip the automatic pre-operation backup
+///
+/// # Returns
+///
+/// Moved, skipped, and failed paths, or error details when either project
+/// is invalid
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke<BatchMoveResult>('batch_move_actions', {
+///   sourceProjectPath: '/path/to/space/Projects/Website',
+///   destProjectPath: '/path/to/space/Projects/Launch',
+///   actionPaths: ['/path/to/space/Projects/Website/Write copy.md'],
+/// });
+/// ```
+#[tauri::command]
+pub fn batch_move_actions(
+    app: AppHandle,
+    source_project_path: String,
+    dest_project_path: String,
+    action_paths: Vec<String>,
+    skip_backup: Option<bool>,
+) -> Result<BatchMoveResult, String> {
+    log::info!(
+        "Moving {} actions from {} to {}",
+        action_paths.len(),
+        source_project_path,
+        dest_project_path
+    );
+
+    let source = Path::new(&source_project_path);
+    let dest = Path::new(&dest_project_path);
+    ensure_space_writable(source)?;
+    ensure_space_writable(dest)?;
+    for project in [source, dest] {
+        if !project.is_dir() {
+            return Err(format!(
+                "Project directory does not exist: {}",
+                project.display()
+            ));
+        }
+        validate_projects_child_directory(project)?;
+    }
+
+    let source_dir =
+        fs::canonicalize(source).map_err(|e| format!("Failed to resolve source project: {}", e))?;
+    let dest_dir = fs::canonicalize(dest)
+        .map_err(|e| format!("Failed to resolve destination project: {}", e))?;
+    if source_dir == dest_dir {
+        return Err("Source and destination projects are the same".to_string());
+    }
+
+    let space_root = source_dir
+        .parent()
+        .and_then(Path::parent)
+        .ok_or_else(|| "Cannot determine the space folder".to_string())?;
+    let files: Vec<PathBuf> = action_paths
+        .iter()
+        .filter_map(|path| fs::canonicalize(path).ok())
+        .collect();
+    backup_before_bulk_operation(
+        space_root,
+        "batch_move_actions",
+        &uuid::Uuid::new_v4().to_string(),
+        &files,
+        skip_backup.unwrap_or(false),
+    )?;
+
+    let result = move_actions_with(&source_dir, dest, &action_paths, |from, to| {
+        move_file(
+            app.clone(),
+            from.to_string_lossy().to_string(),
+            to.to_string_lossy().to_string(),
+        )
+        .map(|_| ())
+    });
+
+    log::info!(
+        "Batch move finished: {} moved, {} skipped, {} failed",
+        result.moved.len(),
+        result.skipped.len(),
+        result.failed.len()
+    );
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn actions_move_with_suffixes_and_foreign_paths_are_skipped() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let projects = workspace.path().join("Projects");
+        let source = projects.join("Alpha Project");
+        let dest = projects.join("Beta");
+        write_test_file(source.join("Call Sam.md"), "# Call Sam\n")?;
+        write_test_file(source.join("Draft.md"), "# Draft\n")?;
+        write_test_file(dest.join("README.md"), "# Beta\n")?;
+        write_test_file(dest.join("Call Sam.md"), "# Call Sam\n")?;
+        write_test_file(projects.join("Gamma/Other.md"), "# Other\n")?;
+
+        let path = |path: PathBuf| path.to_string_lossy().to_string();
+        let requested = vec![
+            path(source.join("Call Sam.md")),
+            path(source.join("Draft.md")),
+            path(source.join("Draft.md")),
+            path(source.join("README.md")),
+            path(projects.join("Gamma/Other.md")),
+            path(source.join("Missing.md")),
+        ];
+        let source_dir = fs::canonicalize(&source).map_err(|e| e.to_string())?;
+        let result = move_actions_with(&source_dir, &dest, &requested, |from, to| {
+            fs::rename(from, to).map_err(|e| e.to_string())
+        });
+
+        let new_names: Vec<String> = result
+            .moved
+            .iter()
+            .map(|(_, new_path)| {
+                Path::new(new_path)
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect();
+        assert_eq!(new_names, vec!["Call Sam-2.md", "Draft.md"]);
+        assert!(dest.join("Call Sam.md").is_file() && dest.join("Call Sam-2.md").is_file());
+        assert_eq!(result.skipped.len(), 3);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, requested[5]);
+
+        Ok(())
+    }
+}
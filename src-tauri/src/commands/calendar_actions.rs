@@ -0,0 +1,456 @@
+//! Follow-up actions created from Google Calendar events.
+//!
+//! Events are read from the persisted calendar cache so conversion works
+//! offline. Each converted action carries a `[!gcal:event_id:...]`
+//! back-reference, which is how an event is recognized as already processed.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::file_locks::with_file_lock;
+use super::filesystem::{save_file_contents, scan_directory_recursive};
+use super::gtd_projects::create_gtd_action;
+use super::space_config::ensure_space_writable;
+use crate::google_calendar::{load_google_calendar_cache, GoogleCalendarEvent};
+
+/// Matches the back-reference written by this module and the older
+/// `[!calendar-event-id:...]` link used by manually linked actions.
+static EVENT_REFERENCE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!(?:gcal:event_id|calendar-event-id):\s*([^\]\s]+)\s*\]")
+        .expect("Invalid event reference regex pattern")
+});
+
+/// Matches only the back-reference written by this module
+static GCAL_LINK_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!gcal:event_id:[^\]]*\]").expect("Invalid gcal link regex pattern")
+});
+
+/// Calendar event an action links to, from either link form.
+pub(crate) fn linked_event_id(content: &str) -> Option<String> {
+    EVENT_REFERENCE_REGEX
+        .captures(content)
+        .map(|captures| captures[1].to_string())
+}
+
+/// Outcome of removing calendar back-references from one file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventLinkCleanup {
+    pub path: String,
+    /// Back-references removed from the file
+    pub links_removed: u32,
+    /// Why the file could not be rewritten, if it could not
+    pub error: Option<String>,
+}
+
+/// Reason an event could not be converted
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventConversionErrorKind {
+    /// An action already references the event
+    AlreadyExists,
+}
+
+/// Structured error returned (JSON-encoded) when an event was already converted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventConversionError {
+    pub kind: EventConversionErrorKind,
+    /// Event that was being converted
+    pub event_id: String,
+    /// Action that already references the event
+    pub existing_action_path: String,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+/// Map every referenced event id to the first file referencing it.
+fn referenced_event_ids(space_root: &Path) -> Result<HashMap<String, String>, String> {
+    let mut files = Vec::new();
+    scan_directory_recursive(space_root, &mut files)?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut references = HashMap::new();
+    for file in files {
+        let Ok(content) = fs::read_to_string(&file.path) else {
+            continue;
+        };
+        for captures in EVENT_REFERENCE_REGEX.captures_iter(&content) {
+            references
+                .entry(captures[1].to_string())
+                .or_insert_with(|| file.path.clone());
+        }
+    }
+    Ok(references)
+}
+
+/// `content` without `[!gcal:event_id:...]` links, dropping lines that held
+/// nothing else, plus the number of links removed.
+fn strip_gcal_links(content: &str) -> (String, u32) {
+    let mut removed = 0;
+    let mut stripped = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let count = GCAL_LINK_REGEX.find_iter(line).count() as u32;
+        if count == 0 {
+            stripped.push_str(line);
+            continue;
+        }
+        removed += count;
+        let rest = GCAL_LINK_REGEX.replace_all(line, "");
+        if !rest.trim().is_empty() {
+            stripped.push_str(&rest);
+        }
+    }
+    (stripped, removed)
+}
+
+/// Remove `[!gcal:event_id:...]` links from every file in the space.
+///
+/// Each changed file is rewritten through the shared save path while its
+/// lock is held; a file that fails, including one tagged `[!locked:true]`,
+/// is reported and the rest are still processed.
+pub(crate) fn remove_event_links(space_root: &Path) -> Result<Vec<EventLinkCleanup>, String> {
+    ensure_space_writable(space_root)?;
+    let mut files = Vec::new();
+    scan_directory_recursive(space_root, &mut files)?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut cleaned = Vec::new();
+    for file in files {
+        let path = Path::new(&file.path);
+        let outcome = with_file_lock(path, || {
+            let content = fs::read_to_string(path).ok()?;
+            let (stripped, links_removed) = strip_gcal_links(&content);
+            (links_removed > 0).then(|| (links_removed, save_file_contents(path, &stripped)))
+        });
+        let Some((links_removed, result)) = outcome else {
+            continue;
+        };
+        let error = result.err();
+        if let Some(error) = &error {
+            log::warn!(
+                "Failed to remove calendar links from {}: {}",
+                file.path,
+                error
+            );
+        }
+        cleaned.push(EventLinkCleanup {
+            path: file.path,
+            links_removed,
+            error,
+        });
+    }
+    Ok(cleaned)
+}
+
+fn cached_events() -> Result<Vec<GoogleCalendarEvent>, String> {
+    Ok(load_google_calendar_cache()?
+        .map(|cache| cache.events)
+        .unwrap_or_default())
+}
+
+/// Parse an event start or `since` bound; all-day dates count from midnight UTC.
+pub(crate) fn parse_event_time(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Utc));
+    }
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|datetime| datetime.and_utc())
+}
+
+fn event_notes(event: &GoogleCalendarEvent) -> String {
+    let when = match (event.start.as_deref(), event.end.as_deref()) {
+        (Some(start), Some(end)) => format!("{} – {}", start, end),
+        (Some(start), None) => start.to_string(),
+        _ => "Unknown".to_string(),
+    };
+
+    let mut lines = vec![format!("**When:** {}", when)];
+    if !event.attendees.is_empty() {
+        lines.push(format!("**Attendees:** {}", event.attendees.join(", ")));
+    }
+    if let Some(link) = event.meeting_link.as_deref() {
+        lines.push(format!("**Meeting link:** {}", link));
+    }
+    lines.push(String::new());
+    lines.push(format!("[!gcal:event_id:{}]", event.id));
+    lines.join("\n")
+}
+
+fn create_action_from_cached_event(
+    space_root: &Path,
+    project_path: String,
+    event_id: &str,
+    template: Option<&str>,
+    events: &[GoogleCalendarEvent],
+) -> Result<String, String> {
+    let event = events
+        .iter()
+        .find(|event| event.id == event_id)
+        .ok_or_else(|| format!("Calendar event '{}' not found in cache", event_id))?;
+
+    if let Some(existing) = referenced_event_ids(space_root)?.remove(event_id) {
+        return Err(serde_json::to_string(&EventConversionError {
+            kind: EventConversionErrorKind::AlreadyExists,
+            event_id: event_id.to_string(),
+            message: format!("Event already converted to action {}", existing),
+            existing_action_path: existing,
+        })
+        .unwrap_or_else(|_| "Event already converted".to_string()));
+    }
+
+    let action_name = template
+        .filter(|template| !template.trim().is_empty())
+        .unwrap_or("{title}")
+        .replace("{title}", &event.summary);
+
+    create_gtd_action(
+        project_path,
+        action_name,
+        "in-progress".to_string(),
+        None,
+        None,
+        "medium".to_string(),
+        None,
+        Some(event_notes(event)),
+        None,
+        None,
+    )
+}
+
+fn unprocessed_events(
+    space_root: &Path,
+    since: Option<DateTime<Utc>>,
+    events: Vec<GoogleCalendarEvent>,
+) -> Result<Vec<GoogleCalendarEvent>, String> {
+    let references = referenced_event_ids(space_root)?;
+    let mut pending: Vec<(Option<DateTime<Utc>>, GoogleCalendarEvent)> = events
+        .into_iter()
+        .filter(|event| !references.contains_key(&event.id))
+        .map(|event| (event.start.as_deref().and_then(parse_event_time), event))
+        .filter(|(start, _)| match (since, start) {
+            (Some(since), Some(start)) => *start >= since,
+            (Some(_), None) => false,
+            (None, _) => true,
+        })
+        .collect();
+
+    pending.sort_by_key(|(start, _)| *start);
+    Ok(pending.into_iter().map(|(_, event)| event).collect())
+}
+
+/// Create a follow-up action from a calendar event
+///
+/// Looks the event up in the persisted calendar cache, so it works offline.
+/// The action uses the normal action template with the event's time,
+/// attendees, and meeting link in Notes plus a `[!gcal:event_id:...]`
+/// back-reference. Converting an event that an action already references is
+/// rejected with a JSON-encoded `EventConversionError` naming that action.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space (searched for existing conversions)
+/// * `project_path` - Project directory to create the action in
+/// * `event_id` - Google Calendar event id
+/// * `template` - Action name template; `{title}` becomes the event title
+///
+/// # Returns
+///
+/// Path of the new action, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const actionPath = await invoke<string>('create_action_from_event', {
+///   spacePath: '/path/to/space',
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+///   eventId: 'abc123',
+///   template: 'Follow up: {title}',
+/// });
+/// ```
+#[tauri::command]
+pub fn create_action_from_event(
+    space_path: String,
+    project_path: String,
+    event_id: String,
+    template: Option<String>,
+) -> Result<String, String> {
+    log::info!(
+        "Creating action from calendar event {} in {}",
+        event_id,
+        project_path
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    create_action_from_cached_event(
+        space_root,
+        project_path,
+        event_id.trim(),
+        template.as_deref(),
+        &cached_events()?,
+    )
+}
+
+/// List cached calendar events that no action references yet
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `since` - Only include events starting at or after this date/time (optional)
+///
+/// # Returns
+///
+/// Unprocessed events ordered by start time, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const events = await invoke<GoogleCalendarEvent[]>('list_unprocessed_events', {
+///   spacePath: '/path/to/space',
+///   since: '2026-04-01',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_unprocessed_events(
+    space_path: String,
+    since: Option<String>,
+) -> Result<Vec<GoogleCalendarEvent>, String> {
+    log::info!("Listing unprocessed calendar events for: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let since = match since.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+        Some(value) => {
+            Some(parse_event_time(value).ok_or_else(|| format!("Invalid since '{}'", value))?)
+        }
+        None => None,
+    };
+
+    unprocessed_events(space_root, since, cached_events()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn event(id: &str, summary: &str, start: &str) -> GoogleCalendarEvent {
+        GoogleCalendarEvent {
+            id: id.to_string(),
+            summary: summary.to_string(),
+            description: None,
+            start: Some(start.to_string()),
+            end: Some("2026-04-02T10:00:00Z".to_string()),
+            location: None,
+            attendees: vec!["ana@example.com".to_string(), "bo@example.com".to_string()],
+            meeting_link: Some("https://meet.example.com/abc".to_string()),
+            status: "confirmed".to_string(),
+            color_id: None,
+            category: None,
+            is_recurring: false,
+            recurring_event_id: None,
+            recurrence: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn converting_an_event_twice_points_at_the_existing_action() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let events = vec![
+            event("evt-1", "Kickoff", "2026-04-02T09:00:00Z"),
+            event("evt-2", "Retro", "2026-04-01"),
+        ];
+
+        let action_path = create_action_from_cached_event(
+            root,
+            project.to_string_lossy().to_string(),
+            "evt-1",
+            Some("Follow up: {title}"),
+            &events,
+        )?;
+        assert!(Path::new(&action_path).starts_with(&project));
+        let content = fs::read_to_string(&action_path).map_err(|e| e.to_string())?;
+        assert!(content.contains("**Attendees:** ana@example.com, bo@example.com"));
+        assert!(content.contains("**Meeting link:** https://meet.example.com/abc"));
+        assert!(content.contains("[!gcal:event_id:evt-1]"));
+
+        let error = create_action_from_cached_event(
+            root,
+            project.to_string_lossy().to_string(),
+            "evt-1",
+            None,
+            &events,
+        )
+        .expect_err("second conversion should be rejected");
+        let error: EventConversionError =
+            serde_json::from_str(&error).map_err(|e| e.to_string())?;
+        assert_eq!(error.kind, EventConversionErrorKind::AlreadyExists);
+        assert_eq!(error.existing_action_path, action_path);
+
+        let pending = unprocessed_events(root, None, events.clone())?;
+        assert_eq!(
+            pending.iter().map(|e| e.id.as_str()).collect::<Vec<_>>(),
+            vec!["evt-2"]
+        );
+        let since = parse_event_time("2026-04-02");
+        assert!(unprocessed_events(root, since, events)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn removing_event_links_keeps_manual_links_and_other_text() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let action = root.join("Projects/Alpha Project/Kickoff.md");
+        write_test_file(
+            &action,
+            "# Kickoff\n\n[!gcal:event_id:evt-1]\nSee [!gcal:event_id:evt-2] notes\n[!calendar-event-id:evt-3]\n",
+        )?;
+
+        let cleaned = remove_event_links(root)?;
+        assert_eq!(cleaned.len(), 1);
+        assert_eq!(cleaned[0].links_removed, 2);
+        assert!(cleaned[0].error.is_none());
+        assert_eq!(
+            fs::read_to_string(&action).map_err(|e| e.to_string())?,
+            "# Kickoff\n\nSee  notes\n[!calendar-event-id:evt-3]\n"
+        );
+        assert!(remove_event_links(root)?.is_empty());
+
+        let locked = root.join("Projects/Alpha Project/Retro.md");
+        let locked_content = "# Retro\n[!locked:true]\n\n[!gcal:event_id:evt-4]\n";
+        write_test_file(&locked, locked_content)?;
+        let cleaned = remove_event_links(root)?;
+        assert_eq!(cleaned.len(), 1);
+        assert!(cleaned[0]
+            .error
+            .as_deref()
+            .is_some_and(|error| error.contains("\"locked\"")));
+        assert_eq!(
+            fs::read_to_string(&locked).map_err(|e| e.to_string())?,
+            locked_content
+        );
+
+        Ok(())
+    }
+}
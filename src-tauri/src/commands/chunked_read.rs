@@ -0,0 +1,331 @@
+//! Streaming file reads for large documents.
+//!
+//! `read_file` sends the whole file in one IPC message, which blocks the UI for
+//! multi-megabyte documents. A chunked read returns a session id at once and
+//! streams the content as `file-chunk` events. Every chunk ends on a UTF-8
+//! character boundary, so the frontend can append chunks as they arrive.
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tauri::{AppHandle, Emitter};
+
+use super::folder_encryption::read_encrypted_markdown;
+use crate::backend::encode_hex;
+
+/// Event carrying one chunk of a chunked read
+pub(crate) const FILE_CHUNK_EVENT: &str = "file-chunk";
+/// Event emitted once a chunked read finishes or fails
+pub(crate) const FILE_READ_COMPLETE_EVENT: &str = "file-read-complete";
+
+/// Chunk size used when the caller does not pass one
+const DEFAULT_CHUNK_SIZE: usize = 256 * 1024;
+/// Smallest accepted chunk size; a chunk must fit any UTF-8 character
+const MIN_CHUNK_SIZE: usize = 4 * 1024;
+/// Largest accepted chunk size
+const MAX_CHUNK_SIZE: usize = 4 * 1024 * 1024;
+
+/// Cancellation flags for reads still in progress, by session id
+static READ_SESSIONS: Lazy<Mutex<HashMap<String, Arc<AtomicBool>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Payload for [`FILE_CHUNK_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileChunk {
+    pub session_id: String,
+    pub path: String,
+    /// Zero-based position of this chunk
+    pub index: u32,
+    pub content: String,
+}
+
+/// Payload for [`FILE_READ_COMPLETE_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileReadComplete {
+    pub session_id: String,
+    pub path: String,
+    pub chunk_count: u32,
+    pub total_bytes: u64,
+    /// Hex SHA-256 of the full content; empty when the read failed
+    pub checksum: String,
+    /// Why the read stopped early, if it did
+    pub error: Option<String>,
+}
+
+/// Totals of a finished stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct StreamSummary {
+    chunk_count: u32,
+    total_bytes: u64,
+    checksum: String,
+}
+
+/// Length of the longest valid UTF-8 prefix of `bytes`, leaving an incomplete
+/// trailing character for the next chunk.
+fn utf8_prefix_len(bytes: &[u8], at_eof: bool) -> Result<usize, String> {
+    match std::str::from_utf8(bytes) {
+        Ok(_) => Ok(bytes.len()),
+        Err(error) if error.error_len().is_none() && !at_eof => Ok(error.valid_up_to()),
+        Err(_) => Err("File is not valid UTF-8".to_string()),
+    }
+}
+
+/// Read `reader` to the end, passing UTF-8 chunks of at most `chunk_size`
+/// bytes to `on_chunk`. Returns `Ok(None)` when `cancelled` was set.
+fn stream_chunks(
+    mut reader: impl Read,
+    chunk_size: usize,
+    cancelled: &AtomicBool,
+    mut on_chunk: impl FnMut(u32, String),
+) -> Result<Option<StreamSummary>, String> {
+    let mut hasher = Sha256::new();
+    let mut pending: Vec<u8> = Vec::with_capacity(chunk_size);
+    let mut buffer = vec![0u8; chunk_size];
+    let mut chunk_count = 0u32;
+    let mut total_bytes = 0u64;
+    let mut at_eof = false;
+
+    while !at_eof || !pending.is_empty() {
+        if cancelled.load(Ordering::Relaxed) {
+            return Ok(None);
+        }
+        if !at_eof {
+            let wanted = chunk_size.saturating_sub(pending.len()).max(1);
+            let bytes_read = match reader.read(&mut buffer[..wanted]) {
+                Ok(bytes_read) => bytes_read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(format!("Failed to read file: {}", e)),
+            };
+            at_eof = bytes_read == 0;
+            pending.extend_from_slice(&buffer[..bytes_read]);
+            if pending.len() < chunk_size && !at_eof {
+                continue;
+            }
+        }
+
+        let split = utf8_prefix_len(&pending, at_eof)?;
+        if split == 0 {
+            continue;
+        }
+        let rest = pending.split_off(split);
+        hasher.update(&pending);
+        total_bytes += pending.len() as u64;
+        let chunk = String::from_utf8(std::mem::replace(&mut pending, rest))
+            .map_err(|_| "File is not valid UTF-8".to_string())?;
+        on_chunk(chunk_count, chunk);
+        chunk_count += 1;
+    }
+
+    Ok(Some(StreamSummary {
+        chunk_count,
+        total_bytes,
+        checksum: encode_hex(hasher.finalize()),
+    }))
+}
+
+/// Start reading a file as a stream of chunk events
+///
+/// Returns a session id straight away, then emits `file-chunk` events in
+/// order and a final `file-read-complete` event with a SHA-256 checksum of
+/// the content. Register the listeners before invoking and match events on
+/// `session_id`. A cancelled read emits nothing further. Use `read_file` for
+/// small files.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `path` - Full path to the file to read
+/// * `chunk_size` - Bytes per chunk, clamped to 4 KiB–4 MiB (defaults to 256 KiB)
+///
+/// # Returns
+///
+/// The read session id, or error details when the file cannot be opened
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+/// import { listen } from '@tauri-apps/api/event';
+///
+/// const parts: string[] = [];
+/// const unlisten = await listen<FileChunk>('file-chunk', ({ payload }) => {
+///   parts[payload.index] = payload.content;
+/// });
+/// const sessionId = await invoke<string>('read_file_chunked', {
+///   path: '/path/to/space/Cabinet/Handbook.md',
+///   chunkSize: 262144,
+/// });
+/// ```
+#[tauri::command]
+pub async fn read_file_chunked(
+    app: AppHandle,
+    path: String,
+    chunk_size: Option<usize>,
+) -> Result<String, String> {
+    log::info!("Starting chunked read: {}", path);
+
+    let file_path = Path::new(&path);
+    let reader: Box<dyn Read + Send> = match read_encrypted_markdown(file_path)? {
+        Some(content) => Box::new(io::Cursor::new(content.into_bytes())),
+        None => {
+            if !file_path.is_file() {
+                return Err(format!("File does not exist: {}", path));
+            }
+            Box::new(File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?)
+        }
+    };
+    let chunk_size = chunk_size
+        .unwrap_or(DEFAULT_CHUNK_SIZE)
+        .clamp(MIN_CHUNK_SIZE, MAX_CHUNK_SIZE);
+
+    let session_id = uuid::Uuid::new_v4().to_string();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    READ_SESSIONS
+        .lock()
+        .map_err(|_| "Read session registry is poisoned".to_string())?
+        .insert(session_id.clone(), cancelled.clone());
+
+    let session = session_id.clone();
+    tokio::task::spawn_blocking(move || {
+        let result = stream_chunks(reader, chunk_size, &cancelled, |index, content| {
+            let chunk = FileChunk {
+                session_id: session.clone(),
+                path: path.clone(),
+                index,
+                content,
+            };
+            if let Err(e) = app.emit(FILE_CHUNK_EVENT, &chunk) {
+                log::error!("Failed to emit file chunk: {}", e);
+            }
+        });
+        if let Ok(mut sessions) = READ_SESSIONS.lock() {
+            sessions.remove(&session);
+        }
+
+        let complete = match result {
+            Ok(None) => {
+                log::info!("Chunked read cancelled: {}", path);
+                return;
+            }
+            Ok(Some(summary)) => FileReadComplete {
+                session_id: session,
+                path,
+                chunk_count: summary.chunk_count,
+                total_bytes: summary.total_bytes,
+                checksum: summary.checksum,
+                error: None,
+            },
+            Err(error) => {
+                log::error!("Chunked read of {} failed: {}", path, error);
+                FileReadComplete {
+                    session_id: session,
+                    path,
+                    chunk_count: 0,
+                    total_bytes: 0,
+                    checksum: String::new(),
+                    error: Some(error),
+                }
+            }
+        };
+        if let Err(e) = app.emit(FILE_READ_COMPLETE_EVENT, &complete) {
+            log::error!("Failed to emit file read completion: {}", e);
+        }
+    });
+
+    Ok(session_id)
+}
+
+/// Stop a chunked read
+///
+/// # Arguments
+///
+/// * `session_id` - Id returned by `read_file_chunked`
+///
+/// # Returns
+///
+/// Whether the read was still running
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<boolean>('cancel_file_read', { sessionId });
+/// ```
+#[tauri::command]
+pub fn cancel_file_read(session_id: String) -> Result<bool, String> {
+    log::info!("Cancelling chunked read: {}", session_id);
+
+    let sessions = READ_SESSIONS
+        .lock()
+        .map_err(|_| "Read session registry is poisoned".to_string())?;
+    Ok(match sessions.get(&session_id) {
+        Some(cancelled) => {
+            cancelled.store(true, Ordering::Relaxed);
+            true
+        }
+        None => false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reader that hands out at most `step` bytes per call
+    struct Trickle<'a> {
+        bytes: &'a [u8],
+        step: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let count = self.step.min(buf.len()).min(self.bytes.len());
+            buf[..count].copy_from_slice(&self.bytes[..count]);
+            self.bytes = &self.bytes[count..];
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn chunks_split_on_character_boundaries() -> Result<(), String> {
+        let text = "héllo wörld 🚀 ".repeat(40);
+        let mut chunks = Vec::new();
+        let summary = stream_chunks(
+            Trickle {
+                bytes: text.as_bytes(),
+                step: 7,
+            },
+            16,
+            &AtomicBool::new(false),
+            |index, chunk| chunks.push((index, chunk)),
+        )?
+        .ok_or("read was not cancelled")?;
+
+        assert!(chunks.iter().all(|(_, chunk)| chunk.len() <= 16));
+        assert!(chunks
+            .iter()
+            .enumerate()
+            .all(|(position, (index, _))| *index as usize == position));
+        let joined: String = chunks.into_iter().map(|(_, chunk)| chunk).collect();
+        assert_eq!(joined, text);
+        assert_eq!(summary.total_bytes, text.len() as u64);
+        assert_eq!(
+            summary.checksum,
+            encode_hex(Sha256::digest(text.as_bytes()))
+        );
+
+        let invalid = stream_chunks(&b"ok \xff"[..], 16, &AtomicBool::new(false), |_, _| {});
+        assert!(invalid.is_err());
+        let cancelled = stream_chunks(text.as_bytes(), 16, &AtomicBool::new(true), |_, _| {})?;
+        assert!(cancelled.is_none());
+
+        Ok(())
+    }
+}
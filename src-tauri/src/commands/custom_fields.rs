@@ -0,0 +1,626 @@
+//! Custom action fields declared per space.
+//!
+//! A space can declare extra action fields such as `client` or `ticket-id` in
+//! `.gtdspace/space.json`. Values are stored as `[!kind:key:value]` markers in
+//! a `## Custom` section of the action file and are checked against the
+//! declarations whenever they are written.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+use super::fields::{update_file_content, FieldKind};
+use super::folder_encryption::stored_markdown_path;
+use super::metadata::is_valid_datetime;
+use super::space_config::{find_configured_space_root, load_space_config, save_space_config};
+
+/// Heading of the action section that holds custom field markers
+const CUSTOM_SECTION_HEADING: &str = "## Custom";
+
+/// Keys owned by the built-in action and project fields
+const RESERVED_KEYS: [&str; 10] = [
+    "status",
+    "project-status",
+    "effort",
+    "due_date",
+    "focus_date",
+    "contexts",
+    "created_date_time",
+    "completed_date_time",
+    "completed_date",
+    "waiting_since",
+];
+
+static CUSTOM_MARKER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!(singleselect|text|datetime):([^:\]\r\n]+):([^\]\r\n]*)\]")
+        .expect("Invalid custom field marker regex pattern")
+});
+
+/// A custom action field declared in the space config
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CustomFieldDefinition {
+    /// Marker key, e.g. `client`
+    pub key: String,
+    /// `singleselect`, `text`, or `datetime`
+    pub kind: FieldKind,
+    /// Values a single-select field accepts; empty accepts any value
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_values: Vec<String>,
+    /// Display label; the frontend falls back to `key`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// Reason a custom field value was rejected
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CustomFieldErrorKind {
+    /// The space does not declare the field
+    UnknownField,
+    /// The value does not fit the field's kind or allowed values
+    InvalidValue,
+}
+
+/// Structured error returned (JSON-encoded) when a custom field is rejected
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomFieldError {
+    pub kind: CustomFieldErrorKind,
+    pub key: String,
+    pub value: String,
+    /// Accepted values for single-select fields
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_values: Vec<String>,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl CustomFieldError {
+    fn into_command_error(self) -> String {
+        serde_json::to_string(&self).unwrap_or(self.message)
+    }
+}
+
+/// A validated custom field value, ready to be written
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CustomFieldValue {
+    pub kind: FieldKind,
+    pub key: String,
+    /// Blank clears the field
+    pub value: String,
+}
+
+/// A custom field marker found in an action's `## Custom` section
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CustomFieldMarker {
+    /// Index of the line holding the marker
+    pub line_number: usize,
+    pub key: String,
+    pub value: String,
+    /// Byte range of the whole marker within its line
+    marker_range: (usize, usize),
+    /// Byte range of the value within its line
+    pub value_range: (usize, usize),
+}
+
+fn validate_definitions(fields: &[CustomFieldDefinition]) -> Result<(), String> {
+    for (index, field) in fields.iter().enumerate() {
+        let key = field.key.as_str();
+        if key.is_empty()
+            || !key
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-' || ch == '_')
+        {
+            return Err(format!("Invalid custom field key '{}'", key));
+        }
+        if RESERVED_KEYS.contains(&key) {
+            return Err(format!(
+                "Custom field key '{}' is used by a built-in field",
+                key
+            ));
+        }
+        if fields[..index].iter().any(|other| other.key == key) {
+            return Err(format!("Duplicate custom field key '{}'", key));
+        }
+        match field.kind {
+            FieldKind::SingleSelect => {}
+            FieldKind::Text | FieldKind::Datetime if field.allowed_values.is_empty() => {}
+            FieldKind::Text | FieldKind::Datetime => {
+                return Err(format!(
+                    "Custom field '{}' lists allowed values, which only singleselect fields support",
+                    key
+                ));
+            }
+            FieldKind::MultiSelect | FieldKind::Checkbox => {
+                return Err(format!(
+                    "Custom field '{}' must be singleselect, text, or datetime",
+                    key
+                ));
+            }
+        }
+        for value in &field.allowed_values {
+            if value.is_empty() || value != value.trim() || value.contains([']', '\r', '\n']) {
+                return Err(format!(
+                    "Invalid allowed value '{}' for custom field '{}'",
+                    value, key
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Custom fields declared for the space containing `path`; none when the
+/// space has no config.
+pub(crate) fn custom_fields_for(path: &Path) -> Result<Vec<CustomFieldDefinition>, String> {
+    let Some(space_root) = find_configured_space_root(path) else {
+        return Ok(Vec::new());
+    };
+    Ok(load_space_config(&space_root)?.custom_fields)
+}
+
+/// Check `values` against the declared fields, returning them in key order.
+pub(crate) fn validate_custom_fields(
+    definitions: &[CustomFieldDefinition],
+    values: &HashMap<String, String>,
+) -> Result<Vec<CustomFieldValue>, String> {
+    let mut entries: Vec<(&String, &String)> = values.iter().collect();
+    entries.sort();
+
+    entries
+        .into_iter()
+        .map(|(key, value)| {
+            let value = value.trim();
+            let reject = |kind, allowed_values: Vec<String>, message: String| {
+                CustomFieldError {
+                    kind,
+                    key: key.clone(),
+                    value: value.to_string(),
+                    allowed_values,
+                    message,
+                }
+                .into_command_error()
+            };
+
+            let Some(definition) = definitions.iter().find(|field| field.key == *key) else {
+                return Err(reject(
+                    CustomFieldErrorKind::UnknownField,
+                    Vec::new(),
+                    format!("The space does not declare a custom field '{}'", key),
+                ));
+            };
+            let allowed = &definition.allowed_values;
+            let message = if value.contains([']', '\r', '\n']) {
+                Some(format!("Invalid {} value '{}'", key, value))
+            } else if value.is_empty() {
+                None
+            } else {
+                match definition.kind {
+                    FieldKind::SingleSelect
+                        if !allowed.is_empty() && !allowed.iter().any(|entry| entry == value) =>
+                    {
+                        Some(format!(
+                            "Invalid {} '{}'. Must be one of: {}",
+                            key,
+                            value,
+                            allowed.join(", ")
+                        ))
+                    }
+                    FieldKind::Datetime if !is_valid_datetime(value) => Some(format!(
+                        "Invalid {} '{}'. Must be a date or date-time",
+                        key, value
+                    )),
+                    _ => None,
+                }
+            };
+            if let Some(message) = message {
+                return Err(reject(
+                    CustomFieldErrorKind::InvalidValue,
+                    allowed.clone(),
+                    message,
+                ));
+            }
+
+            Ok(CustomFieldValue {
+                kind: definition.kind,
+                key: key.clone(),
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Line range of the `## Custom` section body, if the section exists.
+fn custom_section<S: AsRef<str>>(lines: &[S]) -> Option<(usize, usize)> {
+    let heading = lines
+        .iter()
+        .position(|line| line.as_ref().trim_end() == CUSTOM_SECTION_HEADING)?;
+    let end = lines[heading + 1..]
+        .iter()
+        .position(|line| {
+            let trimmed = line.as_ref().trim();
+            trimmed.starts_with('#') || trimmed == "---"
+        })
+        .map_or(lines.len(), |offset| heading + 1 + offset);
+    Some((heading + 1, end))
+}
+
+/// Custom field markers in the `## Custom` section, first occurrence of each
+/// key only.
+fn custom_markers<S: AsRef<str>>(lines: &[S]) -> Vec<CustomFieldMarker> {
+    let Some((start, end)) = custom_section(lines) else {
+        return Vec::new();
+    };
+
+    let mut markers: Vec<CustomFieldMarker> = Vec::new();
+    for (line_number, line) in lines.iter().enumerate().take(end).skip(start) {
+        for captures in CUSTOM_MARKER_REGEX.captures_iter(line.as_ref()) {
+            let key = captures[2].trim();
+            if markers.iter().any(|marker| marker.key == key) {
+                continue;
+            }
+            let (Some(marker), Some(value)) = (captures.get(0), captures.get(3)) else {
+                continue;
+            };
+            markers.push(CustomFieldMarker {
+                line_number,
+                key: key.to_string(),
+                value: value.as_str().trim().to_string(),
+                marker_range: (marker.start(), marker.end()),
+                value_range: (value.start(), value.end()),
+            });
+        }
+    }
+    markers
+}
+
+/// Custom field values stored in an action, keyed by field key.
+pub(crate) fn parse_custom_fields(content: &str) -> BTreeMap<String, String> {
+    let lines: Vec<&str> = content.lines().collect();
+    custom_markers(&lines)
+        .into_iter()
+        .filter(|marker| !marker.value.is_empty())
+        .map(|marker| (marker.key, marker.value))
+        .collect()
+}
+
+/// The marker for custom field `key`, if the action sets it.
+pub(crate) fn find_custom_field(content: &str, key: &str) -> Option<CustomFieldMarker> {
+    let lines: Vec<&str> = content.lines().collect();
+    custom_markers(&lines)
+        .into_iter()
+        .find(|marker| marker.key == key && !marker.value.is_empty())
+}
+
+/// Write custom field markers into `content`.
+///
+/// Existing markers are rewritten in place and blank values remove them.
+/// New markers go at the end of the `## Custom` section, which is created
+/// before `## Notes` when the action does not have one yet.
+pub(crate) fn write_custom_fields(content: &str, values: &[CustomFieldValue]) -> String {
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    for field in values {
+        let marker = format!(
+            "[!{}:{}:{}]",
+            field.kind.marker_name(),
+            field.key,
+            field.value
+        );
+        let existing = custom_markers(&lines)
+            .into_iter()
+            .find(|existing| existing.key == field.key);
+
+        match existing {
+            Some(existing) if field.value.is_empty() => {
+                let line = &mut lines[existing.line_number];
+                line.replace_range(existing.marker_range.0..existing.marker_range.1, "");
+                if line.trim().is_empty() {
+                    lines.remove(existing.line_number);
+                }
+            }
+            Some(existing) => {
+                lines[existing.line_number]
+                    .replace_range(existing.marker_range.0..existing.marker_range.1, &marker);
+            }
+            None if field.value.is_empty() => {}
+            None => match custom_section(&lines) {
+                Some((start, end)) => {
+                    let insert_at = (start..end)
+                        .rev()
+                        .find(|index| !lines[*index].trim().is_empty())
+                        .map_or(start, |index| index + 1);
+                    lines.insert(insert_at, marker);
+                }
+                None => {
+                    let insert_at = lines
+                        .iter()
+                        .position(|line| line.trim_end() == "## Notes")
+                        .or_else(|| lines.iter().position(|line| line.trim() == "---"))
+                        .unwrap_or(lines.len());
+                    let mut section = vec![CUSTOM_SECTION_HEADING.to_string(), marker];
+                    if insert_at < lines.len() {
+                        section.push(String::new());
+                    } else if lines.last().is_some_and(|line| !line.trim().is_empty()) {
+                        section.insert(0, String::new());
+                    }
+                    lines.splice(insert_at..insert_at, section);
+                }
+            },
+        }
+    }
+
+    let mut output = lines.join("\n");
+    if content.ends_with('\n') {
+        output.push('\n');
+    }
+    output
+}
+
+/// Load the custom action fields declared in a space
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// Declared fields in config order (empty when none are declared), or error
+/// details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const fields = await invoke<CustomFieldDefinition[]>('get_custom_field_definitions', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_custom_field_definitions(
+    space_path: String,
+) -> Result<Vec<CustomFieldDefinition>, String> {
+    Ok(load_space_config(Path::new(&space_path))?.custom_fields)
+}
+
+/// Replace the custom action fields declared in a space
+///
+/// Keys may use letters, digits, `-` and `_`, and must not clash with a
+/// built-in field such as `status`. Values already stored in actions are
+/// left alone when a field is removed or changed.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `fields` - Every custom field the space should declare
+///
+/// # Returns
+///
+/// The declared fields or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<CustomFieldDefinition[]>('set_custom_field_definitions', {
+///   spacePath: '/path/to/space',
+///   fields: [
+///     { key: 'client', kind: 'singleselect', allowed_values: ['Acme', 'Globex'] },
+///     { key: 'ticket-id', kind: 'text', label: 'Ticket' },
+///   ],
+/// });
+/// ```
+#[tauri::command]
+pub fn set_custom_field_definitions(
+    space_path: String,
+    fields: Vec<CustomFieldDefinition>,
+) -> Result<Vec<CustomFieldDefinition>, String> {
+    log::info!(
+        "Setting {} custom field(s) for space: {}",
+        fields.len(),
+        space_path
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    validate_definitions(&fields)?;
+
+    let mut config = load_space_config(space_root)?;
+    config.custom_fields = fields;
+    save_space_config(space_root, &config)?;
+
+    Ok(config.custom_fields)
+}
+
+/// Set custom field values on an action
+///
+/// Values are checked against the space's declared fields before anything is
+/// written; a blank value removes the field. Rejected values return a
+/// JSON-encoded `CustomFieldError`.
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+/// * `custom_fields` - Field key to new value
+///
+/// # Returns
+///
+/// Every custom field stored in the action afterwards, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<Record<string, string>>('set_action_custom_fields', {
+///   actionPath: '/path/to/space/Projects/Website Redesign/Draft copy.md',
+///   customFields: { client: 'Acme', 'ticket-id': 'WEB-42' },
+/// });
+/// ```
+#[tauri::command]
+pub fn set_action_custom_fields(
+    action_path: String,
+    custom_fields: HashMap<String, String>,
+) -> Result<BTreeMap<String, String>, String> {
+    log::info!(
+        "Setting {} custom field(s) on: {}",
+        custom_fields.len(),
+        action_path
+    );
+
+    let file_path = Path::new(&action_path);
+    if !stored_markdown_path(file_path).is_file() {
+        return Err("File does not exist".to_string());
+    }
+
+    let values = validate_custom_fields(&custom_fields_for(file_path)?, &custom_fields)?;
+    let mut stored = BTreeMap::new();
+    update_file_content(file_path, |content| {
+        let updated = write_custom_fields(content, &values);
+        stored = parse_custom_fields(&updated);
+        Ok(updated)
+    })?;
+
+    Ok(stored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::filesystem::list_project_actions;
+    use crate::commands::gtd_projects::create_gtd_action;
+    use crate::commands::metadata::get_action_metadata;
+    use crate::test_utils::seed_test_workspace;
+    use std::fs;
+
+    fn field(key: &str, kind: FieldKind, allowed_values: &[&str]) -> CustomFieldDefinition {
+        CustomFieldDefinition {
+            key: key.to_string(),
+            kind,
+            allowed_values: allowed_values.iter().map(|v| v.to_string()).collect(),
+            label: None,
+        }
+    }
+
+    fn values(entries: &[(&str, &str)]) -> HashMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn declared_custom_fields_are_validated_and_round_trip() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let space_path = workspace.path().to_string_lossy().to_string();
+        let project = workspace.path().join("Projects/Alpha Project");
+        let project_path = project.to_string_lossy().to_string();
+
+        assert!(set_custom_field_definitions(
+            space_path.clone(),
+            vec![field("status", FieldKind::Text, &[])]
+        )
+        .is_err());
+        assert!(set_custom_field_definitions(
+            space_path.clone(),
+            vec![field("tags", FieldKind::MultiSelect, &[])]
+        )
+        .is_err());
+        set_custom_field_definitions(
+            space_path.clone(),
+            vec![
+                field("client", FieldKind::SingleSelect, &["Acme", "Globex"]),
+                field("ticket-id", FieldKind::Text, &[]),
+                field("signed", FieldKind::Datetime, &[]),
+            ],
+        )?;
+        assert_eq!(get_custom_field_definitions(space_path)?.len(), 3);
+
+        let create = |name: &str, custom: &[(&str, &str)]| {
+            create_gtd_action(
+                project_path.clone(),
+                name.to_string(),
+                "in-progress".to_string(),
+                None,
+                None,
+                "small".to_string(),
+                None,
+                None,
+                None,
+                Some(values(custom)),
+            )
+        };
+        let rejected = create("Bad", &[("client", "Initech")]).unwrap_err();
+        let error: CustomFieldError = serde_json::from_str(&rejected).map_err(|e| e.to_string())?;
+        assert_eq!(error.kind, CustomFieldErrorKind::InvalidValue);
+        assert_eq!(error.allowed_values, vec!["Acme", "Globex"]);
+        let unknown = create("Bad", &[("owner", "me")]).unwrap_err();
+        assert!(unknown.contains("\"kind\":\"unknown-field\""));
+        assert!(!project.join("Bad.md").exists());
+
+        let action_path = create("Invoice", &[("client", "Acme"), ("ticket-id", "FIN-7")])?;
+        let content = fs::read_to_string(&action_path).map_err(|e| e.to_string())?;
+        assert!(content.contains(
+            "## Custom\n[!singleselect:client:Acme]\n[!text:ticket-id:FIN-7]\n\n## Notes"
+        ));
+
+        assert!(
+            set_action_custom_fields(action_path.clone(), values(&[("signed", "soon")])).is_err()
+        );
+        let stored = set_action_custom_fields(
+            action_path.clone(),
+            values(&[
+                ("client", "Globex"),
+                ("ticket-id", ""),
+                ("signed", "2026-05-01"),
+            ]),
+        )?;
+        assert_eq!(
+            stored.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("client".to_string(), "Globex".to_string()),
+                ("signed".to_string(), "2026-05-01".to_string()),
+            ]
+        );
+        let content = fs::read_to_string(&action_path).map_err(|e| e.to_string())?;
+        assert!(content.contains(
+            "## Custom\n[!singleselect:client:Globex]\n[!datetime:signed:2026-05-01]\n\n"
+        ));
+
+        let metadata = get_action_metadata(action_path.clone())?;
+        assert_eq!(
+            metadata.custom_fields.get("client").map(String::as_str),
+            Some("Globex")
+        );
+        let listed = list_project_actions(project_path)?;
+        let invoice = listed
+            .iter()
+            .find(|file| file.path == action_path)
+            .ok_or("Invoice action missing from listing")?;
+        assert_eq!(invoice.custom_fields.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn custom_section_is_appended_when_notes_are_missing() {
+        let values = vec![CustomFieldValue {
+            kind: FieldKind::Text,
+            key: "client".to_string(),
+            value: "Acme".to_string(),
+        }];
+        assert_eq!(
+            write_custom_fields("# Action\n\n## Status\n[!singleselect:status:waiting]\n", &values),
+            "# Action\n\n## Status\n[!singleselect:status:waiting]\n\n## Custom\n[!text:client:Acme]\n"
+        );
+        let marker = find_custom_field("## Custom\n[!text:client:Acme]\n", "client");
+        assert_eq!(
+            marker.map(|m| (m.line_number, m.value_range)),
+            Some((1, (14, 18)))
+        );
+    }
+}
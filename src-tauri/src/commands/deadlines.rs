@@ -0,0 +1,270 @@
+//! Upcoming deadline report for the dashboard.
+//!
+//! Every project README and action file under `Projects` with a
+//! `[!datetime:due_date:...]` marker is bucketed relative to today. Completed
+//! and cancelled items never raise an alert.
+
+use chrono::{Local, NaiveDate};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::action_table::ACTION_DUE_DATE_REGEX;
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary};
+use super::utils::{is_markdown_path, is_readme_path};
+
+/// Largest accepted `days_ahead`
+const MAX_DAYS_AHEAD: u32 = 365;
+/// Items due within this many days land in `due_this_week`
+const WEEK_DAYS: i64 = 7;
+
+static ITEM_STATUS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\[!singleselect:(?:project-)?status:([^\]]*)\]")
+        .expect("Invalid item status regex pattern")
+});
+
+/// An action or project with a due date
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeadlineItem {
+    /// Action file stem or project folder name
+    pub name: String,
+    /// Full path to the action file or project README
+    pub path: String,
+    /// `"action"` or `"project"`
+    pub item_type: String,
+    /// Due date (YYYY-MM-DD)
+    pub due_date: String,
+    /// Negative when overdue
+    pub days_until_due: i64,
+}
+
+/// Deadlines grouped by urgency
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeadlineReport {
+    pub overdue: Vec<DeadlineItem>,
+    pub due_today: Vec<DeadlineItem>,
+    /// Due within the next seven days
+    pub due_this_week: Vec<DeadlineItem>,
+    /// Due after this week but within `days_ahead`
+    pub due_later: Vec<DeadlineItem>,
+}
+
+/// Build an item from a file's markers, skipping finished or undated ones.
+fn deadline_item(
+    path: &Path,
+    name: String,
+    item_type: &str,
+    today: NaiveDate,
+    vocabulary: &FieldVocabulary,
+) -> Option<DeadlineItem> {
+    let content = fs::read_to_string(path).ok()?;
+
+    let status = ITEM_STATUS_REGEX
+        .captures(&content)
+        .and_then(|captures| captures.get(1))
+        .map(|value| value.as_str().trim())
+        .unwrap_or_default();
+    if matches!(
+        vocabulary
+            .builtin_status(status)
+            .to_ascii_lowercase()
+            .as_str(),
+        "completed" | "complete" | "done" | "cancelled" | "canceled"
+    ) {
+        return None;
+    }
+
+    let raw_due = ACTION_DUE_DATE_REGEX
+        .captures(&content)
+        .and_then(|captures| captures.get(1))?
+        .as_str()
+        .trim();
+    let due = NaiveDate::parse_from_str(raw_due.get(..10)?, "%Y-%m-%d").ok()?;
+
+    Some(DeadlineItem {
+        name,
+        path: path.to_string_lossy().to_string(),
+        item_type: item_type.to_string(),
+        due_date: due.format("%Y-%m-%d").to_string(),
+        days_until_due: (due - today).num_days(),
+    })
+}
+
+pub(crate) fn collect_deadlines(projects_dir: &Path, today: NaiveDate) -> Vec<DeadlineItem> {
+    let Ok(projects) = fs::read_dir(projects_dir) else {
+        return Vec::new();
+    };
+
+    let vocabulary = field_vocabulary_or_default(projects_dir);
+    let mut items = Vec::new();
+    for project in projects.flatten().map(|entry| entry.path()) {
+        if !project.is_dir() {
+            continue;
+        }
+        let project_name = project
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+        let Ok(entries) = fs::read_dir(&project) else {
+            continue;
+        };
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            if !path.is_file() || !is_markdown_path(&path) {
+                continue;
+            }
+            let item = if is_readme_path(&path) {
+                deadline_item(&path, project_name.clone(), "project", today, &vocabulary)
+            } else {
+                let name = path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+                deadline_item(&path, name, "action", today, &vocabulary)
+            };
+            items.extend(item);
+        }
+    }
+    items
+}
+
+fn build_report(mut items: Vec<DeadlineItem>, days_ahead: u32) -> DeadlineReport {
+    items.sort_by(|a, b| {
+        a.days_until_due
+            .cmp(&b.days_until_due)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+
+    let mut report = DeadlineReport::default();
+    for item in items {
+        match item.days_until_due {
+            days if days < 0 => report.overdue.push(item),
+            0 => report.due_today.push(item),
+            days if days > i64::from(days_ahead) => {}
+            days if days <= WEEK_DAYS => report.due_this_week.push(item),
+            _ => report.due_later.push(item),
+        }
+    }
+    report
+}
+
+/// Report actions and projects that are overdue or due soon
+///
+/// Scans every project README and action file under `Projects`. Overdue items
+/// are always reported; upcoming ones only when due within `days_ahead` days.
+/// Completed and cancelled items are skipped.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `days_ahead` - How far ahead to look, in days (at most 365)
+///
+/// # Returns
+///
+/// `DeadlineReport` with each bucket sorted by due date, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke<DeadlineReport>('check_upcoming_deadlines', {
+///   spacePath: '/path/to/space',
+///   daysAhead: 14,
+/// });
+/// ```
+#[tauri::command]
+pub fn check_upcoming_deadlines(
+    space_path: String,
+    days_ahead: u32,
+) -> Result<DeadlineReport, String> {
+    log::info!(
+        "Checking deadlines within {} days in: {}",
+        days_ahead,
+        space_path
+    );
+
+    if days_ahead > MAX_DAYS_AHEAD {
+        return Err(format!(
+            "days_ahead must be at most {} (got {})",
+            MAX_DAYS_AHEAD, days_ahead
+        ));
+    }
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let today = Local::now().date_naive();
+    let items = collect_deadlines(&space_root.join("Projects"), today);
+    Ok(build_report(items, days_ahead))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(due: &str, status: &str) -> String {
+        format!(
+            "# Action\n\n## Status\n[!singleselect:status:{}]\n\n## Due Date\n[!datetime:due_date:{}]\n",
+            status, due
+        )
+    }
+
+    #[test]
+    fn deadlines_are_bucketed_relative_to_today() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        write_test_file(
+            project.join("Late.md"),
+            &action("2026-03-30", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Today.md"),
+            &action("2026-04-05T17:00", "waiting"),
+        )?;
+        write_test_file(
+            project.join("Soon.md"),
+            &action("2026-04-12", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Later.md"),
+            &action("2026-04-20", "in-progress"),
+        )?;
+        write_test_file(project.join("Far.md"), &action("2026-06-01", "in-progress"))?;
+        write_test_file(project.join("Done.md"), &action("2026-03-01", "completed"))?;
+        write_test_file(project.join("Undated.md"), &action("", "in-progress"))?;
+
+        let today = NaiveDate::from_ymd_opt(2026, 4, 5).expect("valid date");
+        let report = build_report(
+            collect_deadlines(&workspace.path().join("Projects"), today),
+            30,
+        );
+
+        let names = |items: &[DeadlineItem]| {
+            items
+                .iter()
+                .map(|item| item.name.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(names(&report.overdue), vec!["Late", "Alpha Project"]);
+        assert_eq!(report.overdue[1].item_type, "project");
+        assert_eq!(report.overdue[1].days_until_due, -4);
+        assert_eq!(names(&report.due_today), vec!["Today"]);
+        assert_eq!(report.due_today[0].due_date, "2026-04-05");
+        assert_eq!(names(&report.due_this_week), vec!["Soon"]);
+        assert_eq!(names(&report.due_later), vec!["Later"]);
+
+        assert!(
+            check_upcoming_deadlines(workspace.path().to_string_lossy().to_string(), 366).is_err()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Impact checks run before destructive deletes.
+//!
+//! `delete_file` and `delete_folder` refuse to remove a path that other
+//! files still reference unless the caller acknowledges the impact.
+
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use super::gtd_relationships::{list_all_references, reference_key};
+
+/// A reference field pointing at the path being deleted
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct IncomingReference {
+    /// File containing the reference field
+    pub source_file: String,
+    /// Field tag, e.g. `goals-references`
+    pub reference_type: String,
+}
+
+/// What deleting a file or folder would affect
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeleteImpact {
+    pub path: String,
+    /// Files outside the deleted path (other than habits) that reference it
+    pub incoming_references: Vec<IncomingReference>,
+    /// Habits that reference it
+    pub habits: Vec<IncomingReference>,
+    /// Files inside the folder; 0 for files
+    pub contained_files: usize,
+}
+
+impl DeleteImpact {
+    /// Whether anything outside the deleted path would be left dangling.
+    ///
+    /// Contained files alone do not count; folder deletes are already
+    /// confirmed by the frontend.
+    pub fn has_impact(&self) -> bool {
+        !self.incoming_references.is_empty() || !self.habits.is_empty()
+    }
+}
+
+/// Reason a delete was refused
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ImpactWarningKind {
+    /// Other files reference the path; retry with `acknowledgeImpact: true`
+    /// after showing the impact
+    UnacknowledgedImpact,
+}
+
+/// Structured error returned (JSON-encoded) instead of deleting
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactWarning {
+    pub kind: ImpactWarningKind,
+    /// Human-readable explanation
+    pub message: String,
+    pub impact: DeleteImpact,
+}
+
+fn compute_delete_impact(path: &Path, space_root: &Path) -> Result<DeleteImpact, String> {
+    let space_path = space_root.to_string_lossy().to_string();
+    let target_key = reference_key(space_root, &path.to_string_lossy());
+    let habits_key = reference_key(space_root, "Habits");
+    let within = |key: &str, root: &str| {
+        key == root
+            || key
+                .strip_prefix(root)
+                .is_some_and(|rest| rest.starts_with('/'))
+    };
+
+    let mut impact = DeleteImpact {
+        path: path.to_string_lossy().to_string(),
+        ..DeleteImpact::default()
+    };
+    for entry in list_all_references(space_path)? {
+        let source_key = reference_key(space_root, &entry.source_file);
+        if within(&source_key, &target_key) {
+            continue;
+        }
+        let points_at_target = entry
+            .referenced_paths
+            .iter()
+            .any(|reference| within(&reference_key(space_root, reference), &target_key));
+        if !points_at_target {
+            continue;
+        }
+
+        let incoming = IncomingReference {
+            source_file: entry.source_file,
+            reference_type: entry.reference_type,
+        };
+        if within(&source_key, &habits_key) {
+            impact.habits.push(incoming);
+        } else {
+            impact.incoming_references.push(incoming);
+        }
+    }
+
+    if path.is_dir() {
+        impact.contained_files = WalkDir::new(path)
+            .follow_links(false)
+            .into_iter()
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_type().is_file())
+            .count();
+    }
+    Ok(impact)
+}
+
+/// Closest ancestor of `path` that looks like a GTD space (has `Projects/`).
+fn find_gtd_space_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|ancestor| ancestor.join("Projects").is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// Refuse a delete with impact when the caller asked to be warned by
+/// passing `acknowledge_impact: Some(false)`. Callers that omit the flag
+/// delete without the check.
+pub(crate) fn ensure_delete_acknowledged(
+    path: &Path,
+    acknowledge_impact: Option<bool>,
+) -> Result<(), String> {
+    if acknowledge_impact != Some(false) || !path.exists() {
+        return Ok(());
+    }
+    let Some(space_root) = find_gtd_space_root(path) else {
+        return Ok(());
+    };
+
+    let impact = compute_delete_impact(path, &space_root)?;
+    if !impact.has_impact() {
+        return Ok(());
+    }
+    let message = format!(
+        "{} is referenced by {} file(s) and {} habit(s)",
+        path.display(),
+        impact.incoming_references.len(),
+        impact.habits.len()
+    );
+    log::info!("Blocked delete pending acknowledgement: {}", message);
+    Err(serde_json::to_string(&ImpactWarning {
+        kind: ImpactWarningKind::UnacknowledgedImpact,
+        message: message.clone(),
+        impact,
+    })
+    .unwrap_or(message))
+}
+
+/// Describe what deleting a file or folder would affect
+///
+/// # Arguments
+///
+/// * `path` - File or folder about to be deleted
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Incoming references, referencing habits, and contained file counts
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const impact = await invoke<DeleteImpact>('get_delete_impact', {
+///   path: '/path/to/space/Goals/Fitness.md',
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_delete_impact(path: String, space_path: String) -> Result<DeleteImpact, String> {
+    log::info!("Computing delete impact for: {}", path);
+
+    let target = Path::new(&path);
+    if !target.exists() {
+        return Err(format!("Path does not exist: {}", path));
+    }
+    compute_delete_impact(target, Path::new(&space_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::filesystem::{delete_file, delete_folder};
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn referenced_deletes_require_acknowledgement() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let goal = root.join("Goals/Fitness.md");
+        write_test_file(&goal, "# Fitness\n")?;
+        write_test_file(
+            root.join("Projects/Gym Plan/README.md"),
+            "# Gym Plan\n\n[!goals-references:%5B%22Goals%2FFitness.md%22%5D]\n",
+        )?;
+        write_test_file(
+            root.join("Habits/Stretch.md"),
+            &format!("# Stretch\n\n[!goals-references:{}]\n", goal.display()),
+        )?;
+        write_test_file(
+            root.join("Cabinet/Gym Notes.md"),
+            "# Notes\n\n[!references:Projects/Gym Plan]\n",
+        )?;
+
+        let impact = get_delete_impact(
+            goal.to_string_lossy().to_string(),
+            root.to_string_lossy().to_string(),
+        )?;
+        assert_eq!(impact.incoming_references.len(), 1);
+        assert!(impact.incoming_references[0]
+            .source_file
+            .ends_with("Gym Plan/README.md"));
+        assert_eq!(impact.habits.len(), 1);
+
+        let goal_path = goal.to_string_lossy().to_string();
+        let error = delete_file(goal_path.clone(), Some(false), None)
+            .expect_err("delete should be refused");
+        let warning: ImpactWarning =
+            serde_json::from_str(&error).expect("warning should be structured");
+        assert_eq!(warning.kind, ImpactWarningKind::UnacknowledgedImpact);
+        assert!(goal.exists());
+        assert!(delete_file(goal_path, Some(true), None)?.success);
+        assert!(!goal.exists());
+
+        // The project's own README reference does not count against the folder
+        let project = root.join("Projects/Gym Plan");
+        let project_path = project.to_string_lossy().to_string();
+        let impact = get_delete_impact(project_path.clone(), root.to_string_lossy().to_string())?;
+        assert_eq!(impact.incoming_references.len(), 1);
+        assert_eq!(impact.contained_files, 1);
+        assert!(delete_folder(project_path.clone(), Some(false), None).is_err());
+        assert!(delete_folder(project_path, Some(true), None)?.success);
+
+        // Impact-free deletes go through without acknowledgement
+        let notes = root.join("Cabinet/Gym Notes.md");
+        assert!(delete_file(notes.to_string_lossy().to_string(), Some(false), None)?.success);
+
+        // Callers that do not pass the flag are not checked
+        let strength = root.join("Goals/Strength.md");
+        write_test_file(&strength, "# Strength\n")?;
+        write_test_file(
+            root.join("Habits/Lift.md"),
+            &format!("# Lift\n\n[!goals-references:{}]\n", strength.display()),
+        )?;
+        assert!(delete_file(strength.to_string_lossy().to_string(), None, None)?.success);
+        assert!(!strength.exists());
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 //! Native dialog and file explorer commands.
 
+use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Command;
 use tauri::AppHandle;
 use tauri_plugin_dialog::DialogExt;
@@ -75,6 +76,98 @@ fn resolve_file_location_target(path: &str) -> Result<PathBuf, String> {
     ))
 }
 
+/// An installed editor that files can be opened with
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ExternalEditor {
+    /// Display name for the menu
+    pub name: String,
+    /// Value to pass as `app` to `open_file_in_external_app`
+    pub app: String,
+}
+
+/// Editors probed by `get_external_editors`: display name and launcher.
+/// On macOS the launcher is the application bundle name.
+#[cfg(target_os = "macos")]
+const EXTERNAL_EDITOR_CANDIDATES: &[(&str, &str)] = &[
+    ("VS Code", "Visual Studio Code"),
+    ("VSCodium", "VSCodium"),
+    ("Typora", "Typora"),
+    ("Obsidian", "Obsidian"),
+    ("Sublime Text", "Sublime Text"),
+    ("Zed", "Zed"),
+    ("BBEdit", "BBEdit"),
+    ("TextEdit", "TextEdit"),
+];
+
+#[cfg(target_os = "windows")]
+const EXTERNAL_EDITOR_CANDIDATES: &[(&str, &str)] = &[
+    ("VS Code", "code"),
+    ("VSCodium", "codium"),
+    ("Typora", "typora"),
+    ("Sublime Text", "subl"),
+    ("Notepad++", "notepad++"),
+    ("Notepad", "notepad"),
+];
+
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const EXTERNAL_EDITOR_CANDIDATES: &[(&str, &str)] = &[
+    ("VS Code", "code"),
+    ("VSCodium", "codium"),
+    ("Typora", "typora"),
+    ("Obsidian", "obsidian"),
+    ("Sublime Text", "subl"),
+    ("Zed", "zed"),
+    ("Gedit", "gedit"),
+    ("Kate", "kate"),
+];
+
+fn is_editor_installed(launcher: &str) -> bool {
+    if cfg!(target_os = "macos") {
+        let bundle = format!("{}.app", launcher);
+        let mut roots = vec![
+            PathBuf::from("/Applications"),
+            PathBuf::from("/System/Applications"),
+        ];
+        if let Some(home) = std::env::var_os("HOME") {
+            roots.push(Path::new(&home).join("Applications"));
+        }
+        return roots.iter().any(|root| root.join(&bundle).is_dir());
+    }
+
+    let extensions: &[&str] = if cfg!(target_os = "windows") {
+        &[".exe", ".cmd", ".bat"]
+    } else {
+        &[""]
+    };
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| {
+            extensions
+                .iter()
+                .any(|ext| dir.join(format!("{}{}", launcher, ext)).is_file())
+        })
+    })
+}
+
+/// Resolve `path` to an existing file inside one of `spaces`.
+fn resolve_space_file(path: &str, spaces: &[String]) -> Result<PathBuf, String> {
+    let resolved = resolve_existing_path(path)?;
+    if !resolved.is_file() {
+        return Err(format!("Path is not a file: {}", redact_path(path)));
+    }
+
+    let in_known_space = spaces
+        .iter()
+        .filter_map(|space| fs::canonicalize(space).ok())
+        .any(|space| resolved.starts_with(space));
+    if !in_known_space {
+        return Err(format!(
+            "File is not inside a known GTD space: {}",
+            redact_path(path)
+        ));
+    }
+    Ok(resolved)
+}
+
 /// Open folder selection dialog and return selected path
 ///
 /// Uses Tauri's dialog API to present a native folder selection dialog
@@ -300,9 +393,103 @@ pub fn open_file_location(file_path: String) -> Result<String, String> {
     }
 }
 
+/// Open a file in an external application
+///
+/// Uses the platform default handler (`open`, `xdg-open`, or `start`) when
+/// `app` is omitted, otherwise launches `app` with the file as an argument.
+/// The file must exist inside the default space, the last opened folder, or
+/// a registered space.
+///
+/// # Arguments
+///
+/// * `app_handle` - Tauri application handle for reading the known spaces
+/// * `path` - Path to the file to open
+/// * `app` - Application to launch, e.g. an `app` value from
+///   `get_external_editors`
+///
+/// # Returns
+///
+/// Success message, or the OS error if the application could not be started
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('open_file_in_external_app', {
+///   path: '/path/to/space/Projects/Website/README.md',
+///   app: 'code',
+/// });
+/// ```
+#[tauri::command]
+pub async fn open_file_in_external_app(
+    app_handle: AppHandle,
+    path: String,
+    app: Option<String>,
+) -> Result<String, String> {
+    log::info!("Opening file in external app: {}", redact_path(&path));
+
+    let settings = super::settings::load_settings(app_handle).await?;
+    let spaces: Vec<String> = [settings.default_space_path, settings.last_folder]
+        .into_iter()
+        .flatten()
+        .chain(settings.known_spaces)
+        .collect();
+    let file_path = resolve_space_file(&path, &spaces)?;
+
+    let app = app
+        .map(|app| app.trim().to_string())
+        .filter(|app| !app.is_empty());
+    let result = match &app {
+        Some(app) => open::with_detached(&file_path, app),
+        None => open::that_detached(&file_path),
+    };
+    let launcher = app.as_deref().unwrap_or("the default application");
+    result.map_err(|e| {
+        log::error!("Failed to open file with {}: {}", launcher, e);
+        format!(
+            "Failed to open {} with {}: {}",
+            redact_path(&path),
+            launcher,
+            e
+        )
+    })?;
+
+    log::info!("Opened file with {}", launcher);
+    Ok(format!("Opened {} with {}", redact_path(&path), launcher))
+}
+
+/// List common external editors installed on this machine
+///
+/// Probes a short per-platform list: application bundles on macOS and
+/// launchers on `PATH` elsewhere.
+///
+/// # Returns
+///
+/// Installed editors in menu order
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const editors = await invoke<ExternalEditor[]>('get_external_editors');
+/// ```
+#[tauri::command]
+pub fn get_external_editors() -> Vec<ExternalEditor> {
+    EXTERNAL_EDITOR_CANDIDATES
+        .iter()
+        .filter(|(_, launcher)| is_editor_installed(launcher))
+        .map(|(name, launcher)| ExternalEditor {
+            name: name.to_string(),
+            app: launcher.to_string(),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{resolve_existing_path, resolve_file_location_target};
+    use super::{resolve_existing_path, resolve_file_location_target, resolve_space_file};
     use std::fs;
 
     #[test]
@@ -349,4 +536,28 @@ mod tests {
 
         assert_eq!(resolved, canonical_project_dir);
     }
+
+    #[test]
+    fn resolve_space_file_rejects_files_outside_known_spaces() {
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        let space = temp_dir.path().join("Space");
+        let outside = temp_dir.path().join("notes.md");
+        let readme = space.join("Projects").join("Alpha").join("README.md");
+        fs::create_dir_all(readme.parent().expect("parent")).expect("create project dir");
+        fs::write(&readme, "# Alpha").expect("write readme");
+        fs::write(&outside, "# Notes").expect("write outside file");
+        let spaces = vec![space.to_string_lossy().to_string()];
+
+        let resolved = resolve_space_file(readme.to_str().expect("utf-8 path"), &spaces)
+            .expect("resolve space file");
+        assert_eq!(resolved, fs::canonicalize(&readme).expect("canonicalize"));
+
+        let error = resolve_space_file(outside.to_str().expect("utf-8 path"), &spaces)
+            .expect_err("outside file");
+        assert!(error.contains("not inside a known GTD space"));
+
+        let error = resolve_space_file(space.to_str().expect("utf-8 path"), &spaces)
+            .expect_err("directory");
+        assert!(error.contains("not a file"));
+    }
 }
@@ -0,0 +1,293 @@
+//! Due-date buckets for the dashboard.
+//!
+//! Reads due dates from the metadata index rather than parsing every file, so
+//! a warm call only stats the indexed files. Completed and cancelled items
+//! are left out: nothing is escalated once it is done.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::field_vocabulary::field_vocabulary_or_default;
+use super::file_meta::{indexed_file_meta, FileMeta};
+
+/// Items listed per bucket when the caller does not pass a limit
+const DEFAULT_ITEMS_PER_BUCKET: usize = 5;
+
+/// A project or action in a due bucket
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueItem {
+    /// Project folder or action file name, without extension
+    pub name: String,
+    /// Full path to the README or action file
+    pub path: String,
+    /// Due date as written in the file, if any
+    pub due_date: Option<String>,
+    pub status: Option<String>,
+}
+
+/// Count and most urgent items of one bucket
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DueBucket {
+    pub count: u32,
+    /// Earliest due first; at most the requested number of items
+    pub items: Vec<DueItem>,
+}
+
+/// Open items grouped by when they are due
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DueBuckets {
+    pub overdue: DueBucket,
+    pub due_today: DueBucket,
+    /// Due after today and within the horizon
+    pub due_this_week: DueBucket,
+    /// Due after the horizon
+    pub due_later: DueBucket,
+    pub no_due_date: DueBucket,
+}
+
+/// Result of `get_due_summary`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueSummary {
+    /// RFC 3339 time the summary was computed
+    pub computed_at: String,
+    pub horizon_days: u32,
+    pub projects: DueBuckets,
+    pub actions: DueBuckets,
+}
+
+/// Local due instant; date-only values count as due at the end of that day.
+fn due_instant(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Local).naive_local());
+    }
+    if let Some(datetime) = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    {
+        return Some(datetime);
+    }
+    NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d")
+        .ok()?
+        .and_hms_opt(23, 59, 59)
+}
+
+fn is_closed(status: &str) -> bool {
+    matches!(
+        status.to_ascii_lowercase().as_str(),
+        "completed" | "complete" | "done" | "cancelled" | "canceled"
+    )
+}
+
+fn item_name(relative: &str, kind: &str) -> String {
+    let mut segments = relative.rsplit('/');
+    let file = segments.next().unwrap_or_default();
+    if kind == "project" {
+        return segments.next().unwrap_or(file).to_string();
+    }
+    file.rsplit_once('.')
+        .map_or(file, |(stem, _)| stem)
+        .to_string()
+}
+
+/// Sort each bucket's items by due date and keep the first `limit`.
+fn finish_buckets(
+    buckets: &mut DueBuckets,
+    mut pending: [Vec<(Option<NaiveDateTime>, DueItem)>; 5],
+    limit: usize,
+) {
+    let targets = [
+        &mut buckets.overdue,
+        &mut buckets.due_today,
+        &mut buckets.due_this_week,
+        &mut buckets.due_later,
+        &mut buckets.no_due_date,
+    ];
+    for (bucket, items) in targets.into_iter().zip(pending.iter_mut()) {
+        items.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+        bucket.count = items.len() as u32;
+        bucket.items = items.drain(..).take(limit).map(|(_, item)| item).collect();
+    }
+}
+
+/// Bucket open projects and actions by due date relative to `now`.
+pub(crate) fn summarize_due(
+    space_root: &Path,
+    entries: impl IntoIterator<Item = (String, FileMeta)>,
+    now: NaiveDateTime,
+    horizon_days: u32,
+    limit: usize,
+) -> (DueBuckets, DueBuckets) {
+    let vocabulary = field_vocabulary_or_default(space_root);
+    let today = now.date();
+    let horizon_end = today + Duration::days(horizon_days as i64);
+
+    let mut projects: [Vec<_>; 5] = Default::default();
+    let mut actions: [Vec<_>; 5] = Default::default();
+    for (relative, meta) in entries {
+        let pending = match meta.kind.as_str() {
+            "project" => &mut projects,
+            "action" => &mut actions,
+            _ => continue,
+        };
+        if meta
+            .status
+            .as_deref()
+            .is_some_and(|status| is_closed(vocabulary.builtin_status(status)))
+        {
+            continue;
+        }
+
+        let due = meta.due_date.as_deref().and_then(due_instant);
+        let bucket = match due {
+            None => 4,
+            Some(due) if due < now => 0,
+            Some(due) if due.date() == today => 1,
+            Some(due) if due.date() <= horizon_end => 2,
+            Some(_) => 3,
+        };
+        pending[bucket].push((
+            due,
+            DueItem {
+                name: item_name(&relative, &meta.kind),
+                path: space_root.join(&relative).to_string_lossy().to_string(),
+                due_date: meta.due_date,
+                status: meta.status,
+            },
+        ));
+    }
+
+    let mut project_buckets = DueBuckets::default();
+    let mut action_buckets = DueBuckets::default();
+    finish_buckets(&mut project_buckets, projects, limit);
+    finish_buckets(&mut action_buckets, actions, limit);
+    (project_buckets, action_buckets)
+}
+
+/// Summarize which projects and actions are overdue or coming due
+///
+/// Open projects (README `due_date`) and actions (action `due_date`) are
+/// counted into overdue, due today, due within `horizon_days`, due later, and
+/// no due date, in local time. A date without a time is due at the end of
+/// that day. Completed and cancelled items are skipped. Reads the metadata
+/// index, so repeated calls are cheap.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `horizon_days` - Days after today that count as "this week"
+/// * `limit` - Items listed per bucket (defaults to 5)
+///
+/// # Returns
+///
+/// Bucket counts and the earliest-due items per bucket, with the time the
+/// summary was computed, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const summary = await invoke<DueSummary>('get_due_summary', {
+///   spacePath: '/path/to/space',
+///   horizonDays: 7,
+/// });
+/// ```
+#[tauri::command]
+pub fn get_due_summary(
+    space_path: String,
+    horizon_days: u32,
+    limit: Option<usize>,
+) -> Result<DueSummary, String> {
+    log::debug!("Building due summary for: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let now = Local::now();
+    let (projects, actions) = summarize_due(
+        space_root,
+        indexed_file_meta(space_root)?,
+        now.naive_local(),
+        horizon_days,
+        limit.unwrap_or(DEFAULT_ITEMS_PER_BUCKET),
+    );
+    Ok(DueSummary {
+        computed_at: now.to_rfc3339(),
+        horizon_days,
+        projects,
+        actions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn due_summary_buckets_open_items_in_local_time() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let action = |status: &str, due: &str| {
+            format!(
+                "# Action\n\n[!singleselect:status:{}]\n[!datetime:due_date:{}]\n",
+                status, due
+            )
+        };
+        write_test_file(
+            project.join("Late.md"),
+            &action("in-progress", "2026-03-09"),
+        )?;
+        write_test_file(project.join("Done.md"), &action("completed", "2026-03-01"))?;
+        write_test_file(project.join("Tonight.md"), &action("waiting", "2026-03-10"))?;
+        write_test_file(
+            project.join("Morning.md"),
+            &action("in-progress", "2026-03-10T08:00"),
+        )?;
+        write_test_file(
+            project.join("Friday.md"),
+            &action("in-progress", "2026-03-13"),
+        )?;
+        write_test_file(
+            project.join("Later.md"),
+            &action("in-progress", "2026-04-01"),
+        )?;
+        write_test_file(
+            project.join("Someday.md"),
+            "# Someday\n\n[!singleselect:status:in-progress]\n",
+        )?;
+
+        let now = NaiveDate::from_ymd_opt(2026, 3, 10)
+            .and_then(|date| date.and_hms_opt(12, 0, 0))
+            .ok_or("invalid test time")?;
+        let (projects, actions) = summarize_due(root, indexed_file_meta(root)?, now, 7, 1);
+        let names = |bucket: &DueBucket| -> Vec<String> {
+            bucket.items.iter().map(|item| item.name.clone()).collect()
+        };
+        assert_eq!(actions.overdue.count, 2);
+        assert_eq!(names(&actions.overdue), vec!["Late"]);
+        assert_eq!(names(&actions.due_today), vec!["Tonight"]);
+        assert_eq!(names(&actions.due_this_week), vec!["Friday"]);
+        assert_eq!(names(&actions.due_later), vec!["Later"]);
+        assert!(names(&actions.no_due_date).contains(&"Someday".to_string()));
+        assert_eq!(names(&projects.due_later), vec!["Alpha Project"]);
+
+        let summary = get_due_summary(root.to_string_lossy().to_string(), 7, None)?;
+        assert!(DateTime::parse_from_rfc3339(&summary.computed_at).is_ok());
+        let total = |buckets: &DueBuckets| {
+            buckets.overdue.count
+                + buckets.due_today.count
+                + buckets.due_this_week.count
+                + buckets.due_later.count
+                + buckets.no_due_date.count
+        };
+        assert_eq!(total(&summary.projects), 1);
+        assert_eq!(total(&summary.actions), total(&actions));
+        Ok(())
+    }
+}
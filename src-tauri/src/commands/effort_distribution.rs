@@ -0,0 +1,173 @@
+//! How a project's remaining work is spread across effort levels.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::field_vocabulary::field_vocabulary_or_default;
+use super::fields::{get_field, FieldKind};
+use super::filesystem::list_project_actions;
+use super::focus::effort_points;
+
+/// Built-in efforts, smallest first
+const EFFORT_LEVELS: [&str; 4] = ["small", "medium", "large", "extra-large"];
+
+/// Open actions per effort level in one project
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EffortDistribution {
+    pub small: u32,
+    pub medium: u32,
+    pub large: u32,
+    pub extra_large: u32,
+    /// Actions with no effort or one outside the vocabulary
+    pub unknown: u32,
+    /// Sum of effort points (small=1, medium=2, large=3, extra-large=5);
+    /// unknown efforts score as medium, as in `get_focus_blocks`
+    pub total_effort_points: u32,
+    /// Effort level closest to the mean points per action, or `none` when
+    /// the project has no open actions
+    pub average_effort_label: String,
+}
+
+impl EffortDistribution {
+    fn add(&mut self, effort: &str) {
+        match effort {
+            "small" => self.small += 1,
+            "medium" => self.medium += 1,
+            "large" => self.large += 1,
+            "extra-large" => self.extra_large += 1,
+            _ => self.unknown += 1,
+        }
+        self.total_effort_points += effort_points(effort);
+    }
+
+    fn action_count(&self) -> u32 {
+        self.small + self.medium + self.large + self.extra_large + self.unknown
+    }
+}
+
+/// Effort level whose points are nearest `mean`, preferring the smaller on a tie.
+fn closest_effort_label(mean: f64) -> &'static str {
+    EFFORT_LEVELS
+        .iter()
+        .copied()
+        .min_by(|a, b| {
+            let distance = |effort: &str| (effort_points(effort) as f64 - mean).abs();
+            distance(a).total_cmp(&distance(b))
+        })
+        .unwrap_or("medium")
+}
+
+/// Get the effort distribution of a project's remaining actions
+///
+/// Completed and cancelled actions are left out, so the numbers describe the
+/// work still to do. Custom efforts count as the built-in effort they map to.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder
+///
+/// # Returns
+///
+/// Action counts per effort level with total points and the average level,
+/// or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const distribution = await invoke<EffortDistribution>('get_action_effort_distribution', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_action_effort_distribution(project_path: String) -> Result<EffortDistribution, String> {
+    log::info!("Computing effort distribution for: {}", project_path);
+
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+    let vocabulary = field_vocabulary_or_default(project_dir);
+
+    let mut distribution = EffortDistribution::default();
+    for action in list_project_actions(project_path.clone())? {
+        let Ok(content) = fs::read_to_string(&action.path) else {
+            continue;
+        };
+        let status = get_field(&content, FieldKind::SingleSelect, "status").unwrap_or_default();
+        if matches!(
+            vocabulary.builtin_status(&status),
+            "completed" | "cancelled"
+        ) {
+            continue;
+        }
+        let effort = get_field(&content, FieldKind::SingleSelect, "effort").unwrap_or_default();
+        distribution.add(&vocabulary.builtin_effort(&effort).to_ascii_lowercase());
+    }
+
+    let count = distribution.action_count();
+    distribution.average_effort_label = if count == 0 {
+        "none".to_string()
+    } else {
+        closest_effort_label(distribution.total_effort_points as f64 / count as f64).to_string()
+    };
+    Ok(distribution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(status: &str, effort: Option<&str>) -> String {
+        let effort = effort
+            .map(|effort| format!("[!singleselect:effort:{}]\n", effort))
+            .unwrap_or_default();
+        format!("# Action\n\n[!singleselect:status:{}]\n{}", status, effort)
+    }
+
+    #[test]
+    fn distribution_counts_open_actions_by_effort() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        let project_path = project.to_string_lossy().to_string();
+
+        let empty = get_action_effort_distribution(project_path.clone())?;
+        assert_eq!(empty.total_effort_points, 0);
+        assert_eq!(empty.average_effort_label, "none");
+
+        write_test_file(project.join("A.md"), &action("in-progress", Some("small")))?;
+        write_test_file(project.join("B.md"), &action("waiting", Some("large")))?;
+        write_test_file(
+            project.join("C.md"),
+            &action("in-progress", Some("extra-large")),
+        )?;
+        write_test_file(project.join("D.md"), &action("in-progress", None))?;
+        write_test_file(
+            project.join("E.md"),
+            &action("completed", Some("extra-large")),
+        )?;
+        write_test_file(project.join("F.md"), &action("cancelled", Some("large")))?;
+
+        let distribution = get_action_effort_distribution(project_path)?;
+        assert_eq!(
+            (
+                distribution.small,
+                distribution.medium,
+                distribution.large,
+                distribution.extra_large,
+                distribution.unknown,
+            ),
+            (1, 0, 1, 1, 1)
+        );
+        assert_eq!(distribution.total_effort_points, 11);
+        assert_eq!(distribution.average_effort_label, "large");
+
+        assert_eq!(closest_effort_label(1.5), "small");
+        assert_eq!(closest_effort_label(4.2), "extra-large");
+
+        Ok(())
+    }
+}
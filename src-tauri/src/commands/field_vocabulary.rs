@@ -0,0 +1,374 @@
+//! Allowed status and effort values for a space.
+//!
+//! A space can replace the built-in values in `.gtdspace/space.json`. Every
+//! custom value names the built-in value it behaves like (`blocked` acts like
+//! `waiting`, `xs` like `small`), so completion stats, focus points, and the
+//! stuck-project check keep working on custom values.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::space_config::{
+    find_configured_space_root, load_space_config, save_space_config, SpaceConfig,
+};
+
+/// Statuses every space understands; custom statuses map onto these
+pub(crate) const BUILTIN_STATUSES: [&str; 4] = ["in-progress", "waiting", "completed", "cancelled"];
+/// Efforts every space understands; custom efforts map onto these
+pub(crate) const BUILTIN_EFFORTS: [&str; 4] = ["small", "medium", "large", "extra-large"];
+
+/// A status or effort value allowed in a space
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct VocabularyValue {
+    /// Value stored in the `[!singleselect:...]` marker
+    pub value: String,
+    /// Built-in value with the same meaning, e.g. `waiting` for `blocked`
+    pub maps_to: String,
+    /// Picker label; the frontend falls back to `value`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// Status and effort values allowed in a space
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FieldVocabulary {
+    /// Allowed action and project statuses, in picker order
+    pub statuses: Vec<VocabularyValue>,
+    /// Allowed efforts, in picker order
+    pub efforts: Vec<VocabularyValue>,
+    /// Whether any values come from the space config
+    pub is_custom: bool,
+}
+
+fn builtin_values(values: &[&str]) -> Vec<VocabularyValue> {
+    values
+        .iter()
+        .map(|value| VocabularyValue {
+            value: value.to_string(),
+            maps_to: value.to_string(),
+            label: None,
+        })
+        .collect()
+}
+
+impl Default for FieldVocabulary {
+    fn default() -> Self {
+        Self {
+            statuses: builtin_values(&BUILTIN_STATUSES),
+            efforts: builtin_values(&BUILTIN_EFFORTS),
+            is_custom: false,
+        }
+    }
+}
+
+fn find_value<'a>(values: &'a [VocabularyValue], value: &str) -> Option<&'a VocabularyValue> {
+    values.iter().find(|entry| entry.value == value)
+}
+
+fn value_list(values: &[VocabularyValue]) -> String {
+    values
+        .iter()
+        .map(|entry| entry.value.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+impl FieldVocabulary {
+    /// Accept a status from this vocabulary, or explain which are allowed.
+    pub(crate) fn validate_status(&self, status: &str) -> Result<String, String> {
+        match find_value(&self.statuses, status.trim()) {
+            Some(entry) => Ok(entry.value.clone()),
+            None => Err(format!(
+                "Invalid status '{}'. Must be one of: {}",
+                status,
+                value_list(&self.statuses)
+            )),
+        }
+    }
+
+    /// Accept an effort from this vocabulary, or explain which are allowed.
+    pub(crate) fn validate_effort(&self, effort: &str) -> Result<String, String> {
+        match find_value(&self.efforts, effort.trim()) {
+            Some(entry) => Ok(entry.value.clone()),
+            None => Err(format!(
+                "Invalid effort '{}'. Must be one of: {}",
+                effort,
+                value_list(&self.efforts)
+            )),
+        }
+    }
+
+    /// Status for new items: the first one that behaves like `in-progress`.
+    pub(crate) fn default_status(&self) -> String {
+        self.statuses
+            .iter()
+            .find(|entry| entry.maps_to == "in-progress")
+            .or_else(|| self.statuses.first())
+            .map(|entry| entry.value.clone())
+            .unwrap_or_else(|| "in-progress".to_string())
+    }
+
+    fn allows_effort(&self, effort: &str) -> bool {
+        find_value(&self.efforts, effort).is_some()
+    }
+
+    /// Effort for loosely spelled input such as `Extra Large`, falling back to
+    /// the vocabulary's medium effort when nothing matches.
+    pub(crate) fn normalize_effort(&self, effort: &str) -> String {
+        let trimmed = effort.trim();
+        if self.allows_effort(trimmed) {
+            return trimmed.to_string();
+        }
+        let mut loose = trimmed.to_lowercase().replace([' ', '_'], "-");
+        if loose == "extralarge" {
+            loose = "extra-large".to_string();
+        }
+        if self.allows_effort(&loose) {
+            return loose;
+        }
+
+        let fallback = self
+            .efforts
+            .iter()
+            .find(|entry| entry.maps_to == "medium")
+            .or_else(|| self.efforts.first())
+            .map(|entry| entry.value.clone())
+            .unwrap_or_else(|| "medium".to_string());
+        log::warn!(
+            "Unknown effort value '{}', defaulting to '{}'",
+            effort,
+            fallback
+        );
+        fallback
+    }
+
+    /// Built-in status a stored status behaves like; unknown values pass
+    /// through unchanged.
+    pub(crate) fn builtin_status<'a>(&'a self, status: &'a str) -> &'a str {
+        find_value(&self.statuses, status).map_or(status, |entry| entry.maps_to.as_str())
+    }
+
+    /// Stored status for a built-in status: the first value that maps to it.
+    pub(crate) fn status_for(&self, builtin: &str) -> String {
+        self.statuses
+            .iter()
+            .find(|entry| entry.maps_to == builtin)
+            .map_or_else(|| builtin.to_string(), |entry| entry.value.clone())
+    }
+
+    /// Built-in effort a stored effort behaves like; unknown values pass
+    /// through unchanged.
+    pub(crate) fn builtin_effort<'a>(&'a self, effort: &'a str) -> &'a str {
+        find_value(&self.efforts, effort).map_or(effort, |entry| entry.maps_to.as_str())
+    }
+}
+
+fn validate_values(
+    field: &str,
+    values: &[VocabularyValue],
+    builtins: &[&str],
+) -> Result<(), String> {
+    if values.is_empty() {
+        return Err(format!("The {} list must not be empty", field));
+    }
+    for (index, entry) in values.iter().enumerate() {
+        let value = entry.value.as_str();
+        if value.is_empty() || value != value.trim() || value.contains([']', ':', ',', '\r', '\n'])
+        {
+            return Err(format!("Invalid {} value '{}'", field, value));
+        }
+        if values[..index].iter().any(|other| other.value == value) {
+            return Err(format!("Duplicate {} value '{}'", field, value));
+        }
+        if !builtins.contains(&entry.maps_to.as_str()) {
+            return Err(format!(
+                "{} value '{}' maps to '{}', which must be one of: {}",
+                field,
+                value,
+                entry.maps_to,
+                builtins.join(", ")
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Vocabulary described by a space config, validating any custom values.
+pub(crate) fn vocabulary_from_config(config: &SpaceConfig) -> Result<FieldVocabulary, String> {
+    let mut vocabulary = FieldVocabulary::default();
+    if let Some(statuses) = &config.status_values {
+        validate_values("status", statuses, &BUILTIN_STATUSES)?;
+        vocabulary.statuses = statuses.clone();
+        vocabulary.is_custom = true;
+    }
+    if let Some(efforts) = &config.effort_values {
+        validate_values("effort", efforts, &BUILTIN_EFFORTS)?;
+        vocabulary.efforts = efforts.clone();
+        vocabulary.is_custom = true;
+    }
+    Ok(vocabulary)
+}
+
+/// Vocabulary for the space containing `path`; built-in values when the
+/// space has no config.
+pub(crate) fn field_vocabulary_for(path: &Path) -> Result<FieldVocabulary, String> {
+    let Some(space_root) = find_configured_space_root(path) else {
+        return Ok(FieldVocabulary::default());
+    };
+    vocabulary_from_config(&load_space_config(&space_root)?)
+}
+
+/// Like [`field_vocabulary_for`], but falls back to the built-in values when
+/// the config cannot be used, for readers that must not fail.
+pub(crate) fn field_vocabulary_or_default(path: &Path) -> FieldVocabulary {
+    field_vocabulary_for(path).unwrap_or_else(|error| {
+        log::warn!(
+            "Using built-in field values for {}: {}",
+            path.display(),
+            error
+        );
+        FieldVocabulary::default()
+    })
+}
+
+/// Load the status and effort values allowed in a space
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// The space's vocabulary (built-in values when none is configured), or
+/// error details when the configured values are invalid
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const vocabulary = await invoke<FieldVocabulary>('get_field_vocabulary', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_field_vocabulary(space_path: String) -> Result<FieldVocabulary, String> {
+    vocabulary_from_config(&load_space_config(Path::new(&space_path))?)
+}
+
+/// Replace the status and effort values allowed in a space
+///
+/// Every value must map to a built-in value. Pass `null` for a list to go
+/// back to the built-in values. Files already using removed values are left
+/// alone; their metadata reports them as unrecognized.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `statuses` - Allowed statuses, or `null` for the built-in ones
+/// * `efforts` - Allowed efforts, or `null` for the built-in ones
+///
+/// # Returns
+///
+/// The updated vocabulary or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<FieldVocabulary>('set_field_vocabulary', {
+///   spacePath: '/path/to/space',
+///   statuses: [
+///     { value: 'in-progress', maps_to: 'in-progress' },
+///     { value: 'blocked', maps_to: 'waiting', label: 'Blocked' },
+///     { value: 'completed', maps_to: 'completed' },
+///   ],
+///   efforts: null,
+/// });
+/// ```
+#[tauri::command]
+pub fn set_field_vocabulary(
+    space_path: String,
+    statuses: Option<Vec<VocabularyValue>>,
+    efforts: Option<Vec<VocabularyValue>>,
+) -> Result<FieldVocabulary, String> {
+    log::info!("Setting field vocabulary for space: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let mut config = load_space_config(space_root)?;
+    config.status_values = statuses;
+    config.effort_values = efforts;
+    let vocabulary = vocabulary_from_config(&config)?;
+    save_space_config(space_root, &config)?;
+
+    Ok(vocabulary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::seed_test_workspace;
+
+    fn value(value: &str, maps_to: &str) -> VocabularyValue {
+        VocabularyValue {
+            value: value.to_string(),
+            maps_to: maps_to.to_string(),
+            label: None,
+        }
+    }
+
+    #[test]
+    fn custom_values_replace_and_map_onto_builtins() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let space_path = workspace.path().to_string_lossy().to_string();
+
+        let builtin = get_field_vocabulary(space_path.clone())?;
+        assert!(!builtin.is_custom);
+        assert_eq!(builtin.normalize_effort("Extra Large"), "extra-large");
+
+        let vocabulary = set_field_vocabulary(
+            space_path.clone(),
+            Some(vec![
+                value("in-progress", "in-progress"),
+                value("blocked", "waiting"),
+                value("done", "completed"),
+            ]),
+            Some(vec![value("xs", "small"), value("m", "medium")]),
+        )?;
+        assert!(vocabulary.is_custom);
+        assert_eq!(
+            field_vocabulary_for(&workspace.path().join("Projects"))?,
+            vocabulary
+        );
+
+        assert_eq!(vocabulary.validate_status("blocked")?, "blocked");
+        assert!(vocabulary
+            .validate_status("waiting")
+            .is_err_and(|error| error.contains("in-progress, blocked, done")));
+        assert_eq!(vocabulary.builtin_status("done"), "completed");
+        assert_eq!(vocabulary.builtin_status("waiting"), "waiting");
+        assert_eq!(vocabulary.builtin_effort("xs"), "small");
+        assert_eq!(vocabulary.normalize_effort("large"), "m");
+
+        assert!(set_field_vocabulary(
+            space_path.clone(),
+            Some(vec![value("blocked", "stuck")]),
+            None,
+        )
+        .is_err());
+        assert!(set_field_vocabulary(
+            space_path.clone(),
+            Some(vec![value("a", "waiting"), value("a", "completed")]),
+            None,
+        )
+        .is_err());
+        assert_eq!(get_field_vocabulary(space_path)?, vocabulary);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,364 @@
+//! Shared parsing and in-place updates for `[!kind:key:value]` field markers.
+//!
+//! Only the value inside the first matching marker is ever rewritten, so the
+//! surrounding whitespace and the marker's section stay exactly where they were.
+
+use chrono::Local;
+use once_cell::sync::Lazy;
+use regex::{NoExpand, Regex};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::field_vocabulary::field_vocabulary_for;
+use super::filesystem::{read_file, save_file_contents};
+use super::folder_encryption::stored_markdown_path;
+use super::space_config::ensure_file_editable;
+use super::waiting_for::track_waiting_since;
+
+static FIELD_MARKER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!(singleselect|multiselect|checkbox|datetime|text):([^:\]\r\n]+):([^\]\r\n]*)\]")
+        .expect("Invalid field marker regex pattern")
+});
+
+/// Marker kinds the field parser understands
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldKind {
+    SingleSelect,
+    /// Comma-separated list of values
+    MultiSelect,
+    /// `true` or `false`
+    Checkbox,
+    Datetime,
+    /// Free text, used by custom fields
+    Text,
+}
+
+impl FieldKind {
+    pub(crate) fn marker_name(self) -> &'static str {
+        match self {
+            FieldKind::SingleSelect => "singleselect",
+            FieldKind::MultiSelect => "multiselect",
+            FieldKind::Checkbox => "checkbox",
+            FieldKind::Datetime => "datetime",
+            FieldKind::Text => "text",
+        }
+    }
+
+    fn from_marker_name(name: &str) -> Option<Self> {
+        match name {
+            "singleselect" => Some(FieldKind::SingleSelect),
+            "multiselect" => Some(FieldKind::MultiSelect),
+            "checkbox" => Some(FieldKind::Checkbox),
+            "datetime" => Some(FieldKind::Datetime),
+            "text" => Some(FieldKind::Text),
+            _ => None,
+        }
+    }
+
+    /// Canonical stored form of `value`, or an error if it cannot be stored.
+    fn normalize(self, key: &str, value: &str) -> Result<String, String> {
+        if value.contains([']', '\r', '\n']) {
+            return Err(format!("Invalid {} value '{}'", key, value));
+        }
+
+        match self {
+            FieldKind::SingleSelect | FieldKind::Datetime | FieldKind::Text => {
+                Ok(value.trim().to_string())
+            }
+            FieldKind::MultiSelect => Ok(split_multiselect(value).join(",")),
+            FieldKind::Checkbox => match value.trim().to_ascii_lowercase().as_str() {
+                "true" => Ok("true".to_string()),
+                "false" => Ok("false".to_string()),
+                other => Err(format!("Invalid {} checkbox value '{}'", key, other)),
+            },
+        }
+    }
+}
+
+/// A field marker found in a file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileField {
+    pub kind: FieldKind,
+    pub key: String,
+    /// Stored value; multiselect values are comma-separated
+    pub value: String,
+}
+
+/// Split a multiselect value into its trimmed, non-empty entries.
+pub(crate) fn split_multiselect(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .map(|entry| entry.trim().to_string())
+        .filter(|entry| !entry.is_empty())
+        .collect()
+}
+
+fn marker_regex(kind: FieldKind, key: &str) -> Result<Regex, String> {
+    Regex::new(&format!(
+        r"\[!{}:{}:([^\]\r\n]*)\]",
+        kind.marker_name(),
+        regex::escape(key)
+    ))
+    .map_err(|e| format!("Invalid marker pattern: {}", e))
+}
+
+/// Value of the first `[!kind:key:...]` marker, trimmed.
+pub(crate) fn get_field(content: &str, kind: FieldKind, key: &str) -> Option<String> {
+    let captures = marker_regex(kind, key).ok()?.captures(content)?;
+    Some(captures[1].trim().to_string())
+}
+
+/// Replace the value of the first `[!kind:key:...]` marker.
+///
+/// Multiselect values are normalized to a trimmed comma-separated list and
+/// checkbox values must be `true` or `false`. A blank value clears the field.
+pub(crate) fn set_field(
+    content: &str,
+    kind: FieldKind,
+    key: &str,
+    value: &str,
+) -> Result<String, String> {
+    let value = kind.normalize(key, value)?;
+    let marker = marker_regex(kind, key)?;
+    if !marker.is_match(content) {
+        return Err(format!("File has no {} field", key));
+    }
+
+    Ok(marker
+        .replacen(
+            content,
+            1,
+            NoExpand(&format!("[!{}:{}:{}]", kind.marker_name(), key, value)),
+        )
+        .into_owned())
+}
+
+/// Every field marker in `content`, first occurrence of each kind/key only.
+pub(crate) fn parse_fields(content: &str) -> Vec<FileField> {
+    let mut fields: Vec<FileField> = Vec::new();
+    for captures in FIELD_MARKER_REGEX.captures_iter(content) {
+        let Some(kind) = FieldKind::from_marker_name(&captures[1]) else {
+            continue;
+        };
+        let key = captures[2].trim();
+        if fields
+            .iter()
+            .any(|field| field.kind == kind && field.key == key)
+        {
+            continue;
+        }
+        fields.push(FileField {
+            kind,
+            key: key.to_string(),
+            value: captures[3].trim().to_string(),
+        });
+    }
+    fields
+}
+
+/// Rewrite a single field in place, leaving the rest of the file alone.
+pub(crate) fn update_file_field(
+    path: &Path,
+    kind: FieldKind,
+    key: &str,
+    value: &str,
+) -> Result<(), String> {
+    update_file_content(path, |content| set_field(content, kind, key, value))
+}
+
+/// Apply `update` to a file's content, writing only when it changed.
+pub(crate) fn update_file_content(
+    path: &Path,
+    update: impl FnOnce(&str) -> Result<String, String>,
+) -> Result<(), String> {
+    ensure_file_editable(path)?;
+
+    let content = read_file(path.to_string_lossy().to_string())?;
+    let updated = update(&content)?;
+    if updated != content {
+        save_file_contents(path, &updated)?;
+    }
+    Ok(())
+}
+
+/// Read every field marker in a markdown file
+///
+/// # Arguments
+///
+/// * `path` - Full path to the markdown file
+///
+/// # Returns
+///
+/// Fields in file order, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const fields = await invoke<FileField[]>('get_file_fields', {
+///   path: '/path/to/space/Projects/Website Redesign/Draft copy.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_file_fields(path: String) -> Result<Vec<FileField>, String> {
+    log::info!("Reading fields from: {}", path);
+
+    Ok(parse_fields(&read_file(path)?))
+}
+
+/// Set one field marker in a markdown file
+///
+/// # Arguments
+///
+/// * `path` - Full path to the markdown file
+/// * `kind` - `singleselect`, `multiselect`, `checkbox`, `datetime`, or `text`
+/// * `key` - Field key, e.g. `status` or `contexts`
+/// * `value` - New value; comma-separated for multiselect, blank to clear.
+///   Status and effort values must be in the space's field vocabulary.
+///   Changing an action's status to or from waiting also sets or clears its
+///   `waiting_since` date
+///
+/// # Returns
+///
+/// Ok on success, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_file_field', {
+///   path: '/path/to/space/Projects/Website Redesign/Draft copy.md',
+///   kind: 'multiselect',
+///   key: 'contexts',
+///   value: 'computer,office',
+/// });
+/// ```
+#[tauri::command]
+pub fn set_file_field(
+    path: String,
+    kind: FieldKind,
+    key: String,
+    value: String,
+) -> Result<(), String> {
+    log::info!("Setting {} field {} in: {}", kind.marker_name(), key, path);
+
+    let file_path = Path::new(&path);
+    if !stored_markdown_path(file_path).is_file() {
+        return Err("File does not exist".to_string());
+    }
+
+    // Status and effort must come from the space's vocabulary
+    let value = match (kind, key.as_str()) {
+        (FieldKind::SingleSelect, "status") => {
+            let vocabulary = field_vocabulary_for(file_path)?;
+            let value = if value.is_empty() {
+                value
+            } else {
+                vocabulary.validate_status(&value)?
+            };
+            let is_waiting = |status: &str| vocabulary.builtin_status(status) == "waiting";
+            return update_file_content(file_path, |content| {
+                let was_waiting =
+                    get_field(content, kind, &key).is_some_and(|old| is_waiting(&old));
+                let updated = set_field(content, kind, &key, &value)?;
+                track_waiting_since(
+                    &updated,
+                    was_waiting,
+                    is_waiting(&value),
+                    Local::now().naive_local(),
+                )
+            });
+        }
+        (FieldKind::SingleSelect, "project-status") if !value.is_empty() => {
+            field_vocabulary_for(file_path)?.validate_status(&value)?
+        }
+        (FieldKind::SingleSelect, "effort") if !value.is_empty() => {
+            field_vocabulary_for(file_path)?.validate_effort(&value)?
+        }
+        _ => value,
+    };
+    update_file_field(file_path, kind, &key, &value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::seed_data::{
+        generate_action_template, generate_project_readme, generate_weekly_review_habit,
+    };
+
+    fn seed_templates() -> Vec<String> {
+        vec![
+            generate_action_template(
+                "Draft copy",
+                "in-progress",
+                Some("2026-04-05T09:00".to_string()),
+                Some("2026-04-10".to_string()),
+                "medium",
+                Some(vec!["computer".to_string(), "office".to_string()]),
+                None,
+                None,
+            ),
+            generate_project_readme("Launch", "Ship it", Some("2026-05-01"), "in-progress"),
+            generate_weekly_review_habit(),
+        ]
+    }
+
+    #[test]
+    fn seed_template_fields_round_trip_unchanged() -> Result<(), String> {
+        for template in seed_templates() {
+            let fields = parse_fields(&template);
+            assert!(!fields.is_empty());
+
+            let mut rewritten = template.clone();
+            for field in &fields {
+                assert_eq!(
+                    get_field(&rewritten, field.kind, &field.key).as_deref(),
+                    Some(field.value.as_str())
+                );
+                rewritten = set_field(&rewritten, field.kind, &field.key, &field.value)?;
+            }
+            assert_eq!(rewritten, template);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn set_field_updates_each_kind_in_place() -> Result<(), String> {
+        let action = &seed_templates()[0];
+        assert_eq!(
+            get_field(action, FieldKind::MultiSelect, "contexts").as_deref(),
+            Some("computer,office")
+        );
+
+        let updated = set_field(
+            action,
+            FieldKind::MultiSelect,
+            "contexts",
+            " home, phone ,, ",
+        )?;
+        assert_eq!(
+            split_multiselect(&get_field(&updated, FieldKind::MultiSelect, "contexts").unwrap()),
+            vec!["home", "phone"]
+        );
+        let updated = set_field(&updated, FieldKind::SingleSelect, "status", "waiting")?;
+        let updated = set_field(&updated, FieldKind::Datetime, "due_date", "")?;
+        assert!(updated.contains("## Status\n[!singleselect:status:waiting]\n"));
+        assert!(updated.contains("## Due Date\n[!datetime:due_date:]\n"));
+        assert!(updated.contains("[!multiselect:contexts:home,phone]"));
+
+        let habit = &seed_templates()[2];
+        let checked = set_field(habit, FieldKind::Checkbox, "habit-status", "TRUE")?;
+        assert_eq!(
+            get_field(&checked, FieldKind::Checkbox, "habit-status").as_deref(),
+            Some("true")
+        );
+        assert!(set_field(habit, FieldKind::Checkbox, "habit-status", "maybe").is_err());
+        assert!(set_field(habit, FieldKind::MultiSelect, "contexts", "a]").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,328 @@
+//! Line diff between two versions of a file.
+//!
+//! Used when the watcher reports a change to a file that is open in the
+//! editor, so the user can review the external edit before accepting it.
+//! The diff is a Myers shortest edit script over lines, after trimming the
+//! common prefix and suffix; changes too large to search cheaply become one
+//! removed and one added run. Hunks are runs of one kind. Line numbers are 1-based and inclusive: `removed` and
+//! `context` hunks count lines in the old version, `added` hunks count lines
+//! in the new version.
+
+use serde::{Deserialize, Serialize};
+
+/// A run of consecutive lines with the same change kind
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DiffHunk {
+    /// `added`, `removed`, or `context`
+    pub kind: String,
+    /// First line of the run
+    pub start_line: usize,
+    /// Last line of the run
+    pub end_line: usize,
+    pub lines: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+impl DiffOp {
+    fn kind(self) -> &'static str {
+        match self {
+            DiffOp::Equal => "context",
+            DiffOp::Delete => "removed",
+            DiffOp::Insert => "added",
+        }
+    }
+}
+
+/// Edit distance past which the Myers search gives up; the saved frontier
+/// grows with its square.
+const MAX_EDIT_DISTANCE: isize = 1000;
+
+/// Edit script from `old` to `new`, in order.
+///
+/// The common prefix and suffix are matched up front. When the rest differs
+/// by more than [`MAX_EDIT_DISTANCE`] edits it is reported as one removed run
+/// followed by one added run.
+fn myers_ops(old: &[&str], new: &[&str]) -> Vec<DiffOp> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let old_changed = &old[prefix..old.len() - suffix];
+    let new_changed = &new[prefix..new.len() - suffix];
+
+    let mut ops = vec![DiffOp::Equal; prefix];
+    match shortest_edit_script(old_changed, new_changed) {
+        Some(changed) => ops.extend(changed),
+        None => {
+            ops.extend(std::iter::repeat_n(DiffOp::Delete, old_changed.len()));
+            ops.extend(std::iter::repeat_n(DiffOp::Insert, new_changed.len()));
+        }
+    }
+    ops.extend(std::iter::repeat_n(DiffOp::Equal, suffix));
+    ops
+}
+
+/// Myers shortest edit script, or `None` when it needs more than
+/// [`MAX_EDIT_DISTANCE`] edits.
+fn shortest_edit_script(old: &[&str], new: &[&str]) -> Option<Vec<DiffOp>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    if max == 0 {
+        return Some(Vec::new());
+    }
+
+    // `v[k + offset]` is the furthest x reached on diagonal k = x - y.
+    let offset = max;
+    let mut v = vec![0isize; 2 * max as usize + 2];
+    let at = |k: isize| (k + offset) as usize;
+    // Step d only reads diagonals -d..=d of the previous frontier, so that
+    // window is all the backtrack needs.
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    'search: for d in 0..=max.min(MAX_EDIT_DISTANCE) {
+        trace.push(v[at(-d)..=at(d)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+        if d == MAX_EDIT_DISTANCE {
+            return None;
+        }
+    }
+
+    // Walk the trace back from the end; each step is one edit plus a snake.
+    let mut ops = Vec::with_capacity(max as usize);
+    let (mut x, mut y) = (n, m);
+    for (d, window) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let v = |k: isize| window[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && v(k - 1) < v(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d > 0 { v(prev_k) } else { 0 };
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            ops.push(DiffOp::Equal);
+            x -= 1;
+            y -= 1;
+        }
+        if d > 0 {
+            ops.push(if x == prev_x {
+                DiffOp::Insert
+            } else {
+                DiffOp::Delete
+            });
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    ops.reverse();
+    Some(ops)
+}
+
+/// Group the edit script into runs of one kind.
+fn diff_hunks(old_content: &str, new_content: &str) -> Vec<DiffHunk> {
+    let old: Vec<&str> = old_content.lines().collect();
+    let new: Vec<&str> = new_content.lines().collect();
+
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    let (mut old_index, mut new_index) = (0usize, 0usize);
+    let mut last_op = None;
+    for op in myers_ops(&old, &new) {
+        let (line, text) = match op {
+            DiffOp::Equal | DiffOp::Delete => (old_index + 1, old[old_index]),
+            DiffOp::Insert => (new_index + 1, new[new_index]),
+        };
+        match hunks.last_mut() {
+            Some(hunk) if last_op == Some(op) => {
+                hunk.end_line = line;
+                hunk.lines.push(text.to_string());
+            }
+            _ => hunks.push(DiffHunk {
+                kind: op.kind().to_string(),
+                start_line: line,
+                end_line: line,
+                lines: vec![text.to_string()],
+            }),
+        }
+        if op != DiffOp::Insert {
+            old_index += 1;
+        }
+        if op != DiffOp::Delete {
+            new_index += 1;
+        }
+        last_op = Some(op);
+    }
+    hunks
+}
+
+/// Compare two versions of a file line by line
+///
+/// Returns every line of both versions grouped into `context`, `removed`,
+/// and `added` runs, in reading order, so the frontend can lay out a split
+/// view. `removed` and `context` line numbers refer to the old version and
+/// `added` line numbers to the new one; both are 1-based and inclusive.
+///
+/// # Arguments
+///
+/// * `old_content` - Content currently in the editor
+/// * `new_content` - Content now on disk
+///
+/// # Returns
+///
+/// Hunks in order, empty when both versions are empty, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const hunks = await invoke<DiffHunk[]>('diff_file_versions', {
+///   oldContent: editorContent,
+///   newContent: await invoke<string>('read_file', { path }),
+/// });
+/// const changed = hunks.some((hunk) => hunk.kind !== 'context');
+/// ```
+#[tauri::command]
+pub fn diff_file_versions(
+    old_content: String,
+    new_content: String,
+) -> Result<Vec<DiffHunk>, String> {
+    Ok(diff_hunks(&old_content, &new_content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(hunks: &[DiffHunk]) -> Vec<(&str, usize, usize, String)> {
+        hunks
+            .iter()
+            .map(|hunk| {
+                (
+                    hunk.kind.as_str(),
+                    hunk.start_line,
+                    hunk.end_line,
+                    hunk.lines.join("|"),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn identical_and_empty_versions() -> Result<(), String> {
+        assert!(diff_file_versions(String::new(), String::new())?.is_empty());
+        assert_eq!(
+            summary(&diff_file_versions("a\nb\n".into(), "a\r\nb".into())?),
+            vec![("context", 1, 2, "a|b".to_string())]
+        );
+        assert_eq!(
+            summary(&diff_file_versions(String::new(), "a\nb".into())?),
+            vec![("added", 1, 2, "a|b".to_string())]
+        );
+        assert_eq!(
+            summary(&diff_file_versions("a\nb".into(), String::new())?),
+            vec![("removed", 1, 2, "a|b".to_string())]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn edits_are_grouped_with_old_and_new_line_numbers() -> Result<(), String> {
+        let old = "# Task\nstatus: todo\nnotes\nend";
+        let new = "# Task\nstatus: done\nnotes\nmore\nnotes\nend";
+        assert_eq!(
+            summary(&diff_file_versions(old.into(), new.into())?),
+            vec![
+                ("context", 1, 1, "# Task".to_string()),
+                ("removed", 2, 2, "status: todo".to_string()),
+                ("added", 2, 4, "status: done|notes|more".to_string()),
+                ("context", 3, 4, "notes|end".to_string()),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fully_rewritten_files_fall_back_to_one_hunk_pair() -> Result<(), String> {
+        let lines = |prefix: &str| {
+            (1..=10_000)
+                .map(|index| format!("{} line {}", prefix, index))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let old = format!("# Doc\n{}\nend", lines("old"));
+        let new = format!("# Doc\n{}\nend", lines("new"));
+
+        let hunks = diff_file_versions(old, new)?;
+        let outline: Vec<(&str, usize, usize)> = hunks
+            .iter()
+            .map(|hunk| (hunk.kind.as_str(), hunk.start_line, hunk.end_line))
+            .collect();
+        assert_eq!(
+            outline,
+            vec![
+                ("context", 1, 1),
+                ("removed", 2, 10_001),
+                ("added", 2, 10_001),
+                ("context", 10_002, 10_002),
+            ]
+        );
+        assert_eq!(hunks[2].lines[0], "new line 1");
+        Ok(())
+    }
+
+    #[test]
+    fn edit_script_is_minimal() {
+        let old = ["a", "b", "c", "a", "b", "b", "a"];
+        let new = ["c", "b", "a", "b", "a", "c"];
+        let ops = myers_ops(&old, &new);
+        let edits = ops.iter().filter(|op| **op != DiffOp::Equal).count();
+        assert_eq!(edits, 5);
+
+        let replayed: Vec<&str> = {
+            let (mut i, mut j, mut out) = (0, 0, Vec::new());
+            for op in ops {
+                match op {
+                    DiffOp::Equal => {
+                        assert_eq!(old[i], new[j]);
+                        out.push(old[i]);
+                        i += 1;
+                        j += 1;
+                    }
+                    DiffOp::Delete => i += 1,
+                    DiffOp::Insert => {
+                        out.push(new[j]);
+                        j += 1;
+                    }
+                }
+            }
+            out
+        };
+        assert_eq!(replayed, new);
+    }
+}
@@ -0,0 +1,279 @@
+//! Emoji or named icons for project READMEs and horizon files.
+//!
+//! An icon is stored as an `[!icon:...]` marker under the title, or read from
+//! an `icon:` key in YAML frontmatter for imported files.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use std::path::Path;
+
+use super::file_locks::with_file_lock;
+use super::filesystem::{read_file, save_file_contents};
+use super::frontmatter::split_frontmatter;
+
+static ICON_MARKER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[!icon:([^\]\r\n]*)\]").expect("Invalid icon marker regex"));
+
+static FRONTMATTER_ICON_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r#"(?m)^icon:[ \t]*['"]?([^'"\r\n]*?)['"]?[ \t]*\r?$"#)
+        .expect("Invalid frontmatter icon regex")
+});
+
+/// Named icons, e.g. `rocket` or `book-open`
+static NAMED_ICON_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^[a-z0-9][a-z0-9-]{0,39}$").expect("Invalid named icon regex"));
+
+/// Characters that extend the previous character into one grapheme:
+/// joiners, variation selectors, skin tones, keycaps, tags, combining marks.
+fn is_grapheme_extender(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{200D}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{20E3}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{0300}'..='\u{036F}'
+    )
+}
+
+fn is_regional_indicator(ch: char) -> bool {
+    ('\u{1F1E6}'..='\u{1F1FF}').contains(&ch)
+}
+
+/// Whether `value` is one user-perceived character, such as an emoji with
+/// modifiers, a ZWJ sequence, or a flag.
+fn is_single_grapheme(value: &str) -> bool {
+    let chars: Vec<char> = value.chars().collect();
+    let Some(&first) = chars.first() else {
+        return false;
+    };
+    if first.is_control() || first.is_whitespace() || is_grapheme_extender(first) {
+        return false;
+    }
+    if chars.len() == 2 && is_regional_indicator(first) && is_regional_indicator(chars[1]) {
+        return true;
+    }
+
+    let mut index = 1;
+    while index < chars.len() {
+        match chars[index] {
+            // A joiner pulls the following character into the cluster
+            '\u{200D}' if index + 1 < chars.len() => index += 2,
+            ch if is_grapheme_extender(ch) => index += 1,
+            _ => return false,
+        }
+    }
+    true
+}
+
+/// Trim and validate an icon: one grapheme (emoji) or a short
+/// lowercase-kebab icon name.
+pub(crate) fn validate_icon(icon: &str) -> Result<String, String> {
+    let icon = icon.trim();
+    if is_single_grapheme(icon) || NAMED_ICON_REGEX.is_match(icon) {
+        Ok(icon.to_string())
+    } else {
+        Err(format!(
+            "Invalid icon '{}': use a single emoji or an icon name like 'book-open'",
+            icon
+        ))
+    }
+}
+
+/// Whether `icon` is a named icon rather than an emoji.
+pub(crate) fn is_named_icon(icon: &str) -> bool {
+    NAMED_ICON_REGEX.is_match(icon)
+}
+
+/// Icon from the `[!icon:...]` marker, else the frontmatter `icon:` key.
+pub(crate) fn extract_icon(content: &str) -> Option<String> {
+    let from_marker = ICON_MARKER_REGEX
+        .captures(content)
+        .map(|captures| captures[1].trim().to_string());
+    let from_frontmatter = || {
+        let (yaml, _) = split_frontmatter(content)?;
+        FRONTMATTER_ICON_REGEX
+            .captures(yaml)
+            .map(|captures| captures[1].trim().to_string())
+    };
+    from_marker
+        .or_else(from_frontmatter)
+        .filter(|icon| !icon.is_empty())
+}
+
+/// Read a file's icon, ignoring unreadable files.
+pub(crate) fn read_file_icon(path: &Path) -> Option<String> {
+    read_file(path.to_string_lossy().to_string())
+        .ok()
+        .and_then(|content| extract_icon(&content))
+}
+
+fn set_frontmatter_icon(content: &str, icon: Option<&str>) -> Option<String> {
+    let (yaml, _) = split_frontmatter(content)?;
+    let captures = FRONTMATTER_ICON_REGEX.captures(yaml)?;
+    let line = captures.get(0)?;
+    let yaml_start = content.find(yaml)?;
+    let (start, mut end) = (yaml_start + line.start(), yaml_start + line.end());
+
+    let replacement = match icon {
+        Some(icon) => format!("icon: \"{}\"", icon),
+        None => {
+            // Drop the whole line, including its newline
+            if content[end..].starts_with('\n') {
+                end += 1;
+            }
+            String::new()
+        }
+    };
+    Some(format!(
+        "{}{}{}",
+        &content[..start],
+        replacement,
+        &content[end..]
+    ))
+}
+
+/// Insert, replace, or (with `None`) remove the icon, leaving other content
+/// untouched. New markers go on the line after the `# ` title.
+pub(crate) fn set_icon_in_content(content: &str, icon: Option<&str>) -> String {
+    if let Some(marker) = ICON_MARKER_REGEX.find(content) {
+        let (start, mut end) = (marker.start(), marker.end());
+        let replacement = match icon {
+            Some(icon) => format!("[!icon:{}]", icon),
+            None => {
+                let line_start = content[..start].rfind('\n').map_or(0, |i| i + 1);
+                let line_end = content[end..].find('\n').map_or(content.len(), |i| end + i);
+                if content[line_start..line_end].trim() == marker.as_str() {
+                    // The marker is alone on its line; drop the line
+                    end = (line_end + 1).min(content.len());
+                    return format!("{}{}", &content[..line_start], &content[end..]);
+                }
+                String::new()
+            }
+        };
+        return format!("{}{}{}", &content[..start], replacement, &content[end..]);
+    }
+    if let Some(updated) = set_frontmatter_icon(content, icon) {
+        return updated;
+    }
+    let Some(icon) = icon else {
+        return content.to_string();
+    };
+
+    let marker = format!("[!icon:{}]\n", icon);
+    let body_start = split_frontmatter(content).map_or(0, |(_, body)| content.len() - body.len());
+    let mut offset = body_start;
+    for line in content[body_start..].split_inclusive('\n') {
+        if line.trim_start().starts_with("# ") {
+            let insert_at = offset + line.len();
+            let separator = if line.ends_with('\n') { "" } else { "\n" };
+            return format!(
+                "{}{}{}{}",
+                &content[..insert_at],
+                separator,
+                marker,
+                &content[insert_at..]
+            );
+        }
+        offset += line.len();
+    }
+    format!(
+        "{}{}{}",
+        &content[..body_start],
+        marker,
+        &content[body_start..]
+    )
+}
+
+/// Set or clear the icon of a project README or horizon file
+///
+/// Updates an existing `[!icon:...]` marker or frontmatter `icon:` key in
+/// place; otherwise adds a marker under the title.
+///
+/// # Arguments
+///
+/// * `path` - Full path to the markdown file
+/// * `icon` - A single emoji or an icon name like `book-open`; empty clears it
+///
+/// # Returns
+///
+/// The stored icon (`null` when cleared), or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_file_icon', {
+///   path: '/path/to/space/Projects/Website/README.md',
+///   icon: '🚀',
+/// });
+/// ```
+#[tauri::command]
+pub fn set_file_icon(path: String, icon: String) -> Result<Option<String>, String> {
+    log::info!("Setting icon for: {}", path);
+
+    let icon = if icon.trim().is_empty() {
+        None
+    } else {
+        Some(validate_icon(&icon)?)
+    };
+    let file_path = Path::new(&path);
+    with_file_lock(file_path, || {
+        let content = read_file(path.clone())?;
+        let updated = set_icon_in_content(&content, icon.as_deref());
+        if updated != content {
+            save_file_contents(file_path, &updated)?;
+        }
+        Ok(icon.clone())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn icons_validate_as_one_grapheme_or_a_short_name() {
+        for valid in ["🚀", "👩🏽‍💻", "🇯🇵", "❤️", "book-open", " rocket "] {
+            assert!(validate_icon(valid).is_ok(), "{} should be valid", valid);
+        }
+        for invalid in ["🚀🚀", "ab cd", "Rocket", "", &"x".repeat(41)] {
+            assert!(
+                validate_icon(invalid).is_err(),
+                "{} should be invalid",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn icon_markers_are_inserted_updated_and_removed_in_place() {
+        let readme = "# Website\n\n## Status\n[!singleselect:project-status:in-progress]\n";
+        let with_icon = set_icon_in_content(readme, Some("🚀"));
+        assert_eq!(
+            with_icon,
+            "# Website\n[!icon:🚀]\n\n## Status\n[!singleselect:project-status:in-progress]\n"
+        );
+        assert_eq!(extract_icon(&with_icon).as_deref(), Some("🚀"));
+
+        let updated = set_icon_in_content(&with_icon, Some("globe"));
+        assert_eq!(extract_icon(&updated).as_deref(), Some("globe"));
+        assert_eq!(set_icon_in_content(&updated, None), readme);
+
+        let imported = "---\ntitle: Health\nicon: '💪'\n---\n# Health\n";
+        assert_eq!(extract_icon(imported).as_deref(), Some("💪"));
+        assert_eq!(
+            set_icon_in_content(imported, Some("🏃")),
+            "---\ntitle: Health\nicon: \"🏃\"\n---\n# Health\n"
+        );
+        assert_eq!(
+            set_icon_in_content(imported, None),
+            "---\ntitle: Health\n---\n# Health\n"
+        );
+
+        let untitled = set_icon_in_content("Just notes\n", Some("star"));
+        assert_eq!(untitled, "[!icon:star]\nJust notes\n");
+    }
+}
@@ -0,0 +1,62 @@
+//! Per-file locks for read-modify-write cycles.
+//!
+//! Habit files are rewritten by the reset timer, status toggles, pruning, and
+//! plain saves. Holding the file's lock across the read and the write keeps
+//! one of those from silently discarding another's changes. Entries are
+//! dropped again once nobody holds or waits on them.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+type FileLock = Arc<Mutex<()>>;
+
+static FILE_LOCKS: Lazy<Mutex<HashMap<PathBuf, FileLock>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn registry() -> std::sync::MutexGuard<'static, HashMap<PathBuf, FileLock>> {
+    FILE_LOCKS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Canonical key for `path`, resolving the parent so files that do not exist
+/// yet still share a key with later canonical lookups.
+fn lock_key(path: &Path) -> PathBuf {
+    if let Ok(canonical) = fs::canonicalize(path) {
+        return canonical;
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(parent), Some(name)) => fs::canonicalize(parent)
+            .map(|parent| parent.join(name))
+            .unwrap_or_else(|_| path.to_path_buf()),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// Run `f` while holding the lock for `path`.
+///
+/// Locks are not reentrant: `f` must not take the lock for the same file again.
+pub(crate) fn with_file_lock<T>(path: &Path, f: impl FnOnce() -> T) -> T {
+    let key = lock_key(path);
+    let lock = registry().entry(key.clone()).or_default().clone();
+
+    let result = {
+        let _guard = lock.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f()
+    };
+
+    // Only the registry and this call still hold the lock: nobody is waiting.
+    let mut locks = registry();
+    if Arc::strong_count(&lock) == 2 {
+        locks.remove(&key);
+    }
+    result
+}
+
+#[cfg(test)]
+pub(crate) fn is_lock_tracked(path: &Path) -> bool {
+    registry().contains_key(&lock_key(path))
+}
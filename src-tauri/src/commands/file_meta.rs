@@ -0,0 +1,746 @@
+//! Persistent metadata index for fast created/modified queries.
+//!
+//! `.gtdspace/meta.json` maps every markdown file to its created time,
+//! modification time, kind, status, and due date. The first query builds the index;
+//! create, save, and watcher hooks keep an existing index current, and every
+//! query re-parses entries whose file vanished or whose mtime moved. Folders
+//! modified since the last scan are re-listed so files created outside the
+//! app are indexed too.
+//! Encrypted files are never indexed so their metadata stays off disk.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime, TimeZone};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tempfile::NamedTempFile;
+
+use super::fields::{get_field, FieldKind};
+use super::filesystem::scan_directory_recursive;
+use super::space_config::{ensure_space_writable, SPACE_CONFIG_DIR};
+use super::space_walk::{walk_directory, WalkOptions};
+use super::utils::is_markdown_path;
+
+/// Index file name inside [`SPACE_CONFIG_DIR`]
+const META_INDEX_FILE: &str = "meta.json";
+/// Bumped whenever the stored entry shape changes; older indexes are rebuilt
+const META_INDEX_VERSION: u32 = 2;
+/// Page size when the filter sets no limit
+const DEFAULT_PAGE_SIZE: usize = 100;
+
+static CREATED_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!datetime:created_date_time:([^\]\r\n]*)\]")
+        .expect("Invalid created date regex pattern")
+});
+
+static STATUS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!(?:singleselect|checkbox):(?:[a-z]+-)?status:([^\]\r\n]*)\]")
+        .expect("Invalid status regex pattern")
+});
+
+/// Serializes read-modify-write cycles on index files
+static META_INDEX_LOCK: Lazy<Mutex<()>> = Lazy::new(|| Mutex::new(()));
+
+/// Indexed metadata for one markdown file
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FileMeta {
+    /// `created_date_time` marker, else filesystem creation time (Unix seconds)
+    pub created: Option<u64>,
+    /// Last modification time (Unix seconds)
+    pub modified: u64,
+    /// project, action, habit, area, goal, vision, purpose, someday, cabinet, or other
+    pub kind: String,
+    /// Lowercased status marker value, if the file has one
+    pub status: Option<String>,
+    /// Raw `due_date` marker value, if the file has one
+    pub due_date: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct MetaIndex {
+    version: u32,
+    /// When the space was last scanned for unindexed files (Unix seconds)
+    #[serde(default)]
+    scanned_at: u64,
+    /// Keyed by `/`-separated path relative to the space root
+    entries: BTreeMap<String, FileMeta>,
+}
+
+/// Predicates for `query_files_meta`; every set field must match
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FileMetaFilter {
+    /// Created at or after this date/time (RFC 3339 or YYYY-MM-DD, local time)
+    #[serde(default)]
+    pub created_after: Option<String>,
+    /// Created strictly before this date/time
+    #[serde(default)]
+    pub created_before: Option<String>,
+    /// Modified at or after this date/time
+    #[serde(default)]
+    pub modified_after: Option<String>,
+    /// Modified strictly before this date/time
+    #[serde(default)]
+    pub modified_before: Option<String>,
+    /// Allowed kinds; empty allows all
+    #[serde(default)]
+    pub kinds: Vec<String>,
+    /// Allowed statuses (case-insensitive); empty allows all
+    #[serde(default)]
+    pub statuses: Vec<String>,
+    /// Matches to skip
+    #[serde(default)]
+    pub offset: usize,
+    /// Page size (defaults to 100)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// A query match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileMetaItem {
+    /// Full path to the file
+    pub path: String,
+    #[serde(flatten)]
+    pub meta: FileMeta,
+}
+
+/// One page of query matches, newest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileMetaPage {
+    pub items: Vec<FileMetaItem>,
+    /// Matches across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub has_more: bool,
+}
+
+fn meta_index_path(space_root: &Path) -> PathBuf {
+    space_root.join(SPACE_CONFIG_DIR).join(META_INDEX_FILE)
+}
+
+fn lock_index() -> std::sync::MutexGuard<'static, ()> {
+    META_INDEX_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn system_time_to_secs(time: SystemTime) -> Option<u64> {
+    time.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/// Parse a marker or filter date/time; values without an offset are local time.
+pub(crate) fn parse_timestamp(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return u64::try_from(datetime.timestamp()).ok();
+    }
+
+    let naive = ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M:%S"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+        .or_else(|| {
+            NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d")
+                .ok()?
+                .and_hms_opt(0, 0, 0)
+        })?;
+    let local = Local.from_local_datetime(&naive).earliest()?;
+    u64::try_from(local.timestamp()).ok()
+}
+
+fn relative_key(space_root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(space_root).ok()?;
+    let mut segments = Vec::new();
+    for component in relative.components() {
+        match component {
+            Component::Normal(segment) => segments.push(segment.to_string_lossy().to_string()),
+            _ => return None,
+        }
+    }
+    if segments.is_empty() || segments[0].starts_with('.') {
+        return None;
+    }
+    Some(segments.join("/"))
+}
+
+fn file_kind(relative: &str) -> &'static str {
+    let mut segments = relative.split('/');
+    let section = segments.next().unwrap_or_default();
+    let rest: Vec<&str> = segments.collect();
+    let is_readme = rest.last().is_some_and(|name| {
+        let stem = name.rsplit_once('.').map_or(*name, |(stem, _)| stem);
+        stem.eq_ignore_ascii_case("README")
+    });
+
+    match section {
+        "Projects" if rest.len() == 2 && is_readme => "project",
+        "Projects" if rest.len() == 2 => "action",
+        "Habits" => "habit",
+        "Areas of Focus" => "area",
+        "Goals" => "goal",
+        "Vision" => "vision",
+        "Purpose & Principles" => "purpose",
+        "Someday Maybe" => "someday",
+        "Cabinet" => "cabinet",
+        _ => "other",
+    }
+}
+
+/// `created_date_time` marker, else filesystem creation time.
+fn parse_created(content: &str, metadata: &fs::Metadata) -> Option<u64> {
+    CREATED_REGEX
+        .captures(content)
+        .and_then(|captures| parse_timestamp(&captures[1]))
+        .or_else(|| metadata.created().ok().and_then(system_time_to_secs))
+}
+
+fn read_file_meta(path: &Path, relative: &str) -> Option<FileMeta> {
+    let metadata = fs::metadata(path)
+        .ok()
+        .filter(|metadata| metadata.is_file())?;
+    let content = fs::read_to_string(path).ok()?;
+
+    let created = parse_created(&content, &metadata);
+    let status = STATUS_REGEX
+        .captures(&content)
+        .map(|captures| captures[1].trim().to_ascii_lowercase())
+        .filter(|status| !status.is_empty());
+    let due_date =
+        get_field(&content, FieldKind::Datetime, "due_date").filter(|due| !due.is_empty());
+
+    Some(FileMeta {
+        created,
+        modified: metadata
+            .modified()
+            .ok()
+            .and_then(system_time_to_secs)
+            .unwrap_or_default(),
+        kind: file_kind(relative).to_string(),
+        status,
+        due_date,
+    })
+}
+
+fn current_mtime(path: &Path) -> Option<u64> {
+    fs::metadata(path)
+        .ok()
+        .filter(|metadata| metadata.is_file())
+        .and_then(|metadata| metadata.modified().ok())
+        .and_then(system_time_to_secs)
+}
+
+fn load_index(space_root: &Path) -> Option<MetaIndex> {
+    let raw = fs::read_to_string(meta_index_path(space_root)).ok()?;
+    match serde_json::from_str::<MetaIndex>(&raw) {
+        Ok(index) if index.version == META_INDEX_VERSION => Some(index),
+        Ok(_) => None,
+        Err(error) => {
+            log::warn!("Rebuilding unreadable metadata index: {}", error);
+            None
+        }
+    }
+}
+
+/// Persist the index atomically; read-only spaces keep it in memory only.
+fn save_index(space_root: &Path, index: &MetaIndex) -> Result<(), String> {
+    if ensure_space_writable(space_root).is_err() {
+        return Ok(());
+    }
+
+    let index_dir = space_root.join(SPACE_CONFIG_DIR);
+    fs::create_dir_all(&index_dir)
+        .map_err(|e| format!("Failed to create metadata index directory: {}", e))?;
+    let serialized = serde_json::to_string(index)
+        .map_err(|e| format!("Failed to serialize metadata index: {}", e))?;
+
+    let mut temp_file = NamedTempFile::new_in(&index_dir)
+        .map_err(|e| format!("Failed to create temporary metadata index: {}", e))?;
+    temp_file
+        .write_all(serialized.as_bytes())
+        .map_err(|e| format!("Failed to write metadata index: {}", e))?;
+    temp_file
+        .persist(meta_index_path(space_root))
+        .map_err(|e| format!("Failed to persist metadata index: {}", e.error))?;
+    Ok(())
+}
+
+/// Index every markdown file under `dir` into `index`.
+fn index_directory(space_root: &Path, dir: &Path, index: &mut MetaIndex) -> Result<(), String> {
+    let mut files = Vec::new();
+    scan_directory_recursive(dir, &mut files)?;
+    for file in files {
+        let path = Path::new(&file.path);
+        let Some(key) = relative_key(space_root, path) else {
+            continue;
+        };
+        if let Some(meta) = read_file_meta(path, &key) {
+            index.entries.insert(key, meta);
+        }
+    }
+    Ok(())
+}
+
+/// Index markdown files in folders modified since the last scan.
+///
+/// Creating a file bumps its folder's mtime, so only those folders are listed.
+fn index_new_files(space_root: &Path, index: &mut MetaIndex) -> bool {
+    let scan_started = system_time_to_secs(SystemTime::now()).unwrap_or_default();
+    let scanned_at = index.scanned_at;
+    let is_stale = |metadata: &fs::Metadata| {
+        metadata
+            .modified()
+            .ok()
+            .and_then(system_time_to_secs)
+            .is_some_and(|mtime| mtime >= scanned_at)
+    };
+
+    let mut stale_dirs = Vec::new();
+    if fs::metadata(space_root).is_ok_and(|metadata| is_stale(&metadata)) {
+        stale_dirs.push(space_root.to_path_buf());
+    }
+    let options = WalkOptions {
+        skip_hidden_dirs: true,
+        ..WalkOptions::default()
+    };
+    let walked = walk_directory(space_root, options, |entry| {
+        if entry.metadata.is_dir() && is_stale(entry.metadata) {
+            stale_dirs.push(entry.path.to_path_buf());
+        }
+        Ok(true)
+    });
+    if let Err(error) = walked {
+        log::warn!("Failed to scan for unindexed files: {}", error);
+        return false;
+    }
+    if stale_dirs.is_empty() {
+        return false;
+    }
+
+    for dir in stale_dirs {
+        let Ok(children) = fs::read_dir(&dir) else {
+            continue;
+        };
+        for path in children.flatten().map(|child| child.path()) {
+            if !is_markdown_path(&path) {
+                continue;
+            }
+            let Some(key) = relative_key(space_root, &path) else {
+                continue;
+            };
+            if index.entries.contains_key(&key) {
+                continue;
+            }
+            if let Some(meta) = read_file_meta(&path, &key) {
+                index.entries.insert(key, meta);
+            }
+        }
+    }
+    index.scanned_at = scan_started;
+    true
+}
+
+/// Drop entries whose file is gone, re-parse those whose mtime moved, and
+/// add files created since the last scan.
+fn heal_index(space_root: &Path, index: &mut MetaIndex) -> bool {
+    let mut changed = false;
+    index.entries.retain(|key, meta| {
+        let path = space_root.join(key);
+        match current_mtime(&path) {
+            None => {
+                changed = true;
+                false
+            }
+            Some(mtime) if mtime != meta.modified => {
+                changed = true;
+                match read_file_meta(&path, key) {
+                    Some(fresh) => {
+                        *meta = fresh;
+                        true
+                    }
+                    None => false,
+                }
+            }
+            Some(_) => true,
+        }
+    });
+    changed |= index_new_files(space_root, index);
+    changed
+}
+
+fn find_indexed_space_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|ancestor| meta_index_path(ancestor).is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Created times for `paths` under `dir`, in order.
+///
+/// Uses the metadata index when the space has one and the entry is current;
+/// other files are parsed directly. The index is never built or saved here.
+pub(crate) fn created_times(dir: &Path, paths: &[&Path]) -> Vec<Option<u64>> {
+    let indexed = find_indexed_space_root(dir).and_then(|root| {
+        let _guard = lock_index();
+        load_index(&root).map(|index| (root, index))
+    });
+
+    paths
+        .iter()
+        .map(|path| {
+            let cached = indexed.as_ref().and_then(|(root, index)| {
+                let meta = index.entries.get(&relative_key(root, path)?)?;
+                (current_mtime(path) == Some(meta.modified)).then_some(meta.created)
+            });
+            cached.unwrap_or_else(|| {
+                let metadata = fs::metadata(path).ok()?;
+                let content = fs::read_to_string(path).unwrap_or_default();
+                parse_created(&content, &metadata)
+            })
+        })
+        .collect()
+}
+
+/// Update an existing index after `path` was created, saved, moved, or deleted.
+///
+/// Spaces that have never been queried have no index and are left alone.
+pub(crate) fn record_file_change(path: &Path) {
+    let Some(space_root) = find_indexed_space_root(path) else {
+        return;
+    };
+    let Some(key) = relative_key(&space_root, path) else {
+        return;
+    };
+
+    let _guard = lock_index();
+    let Some(mut index) = load_index(&space_root) else {
+        return;
+    };
+
+    if path.is_dir() {
+        if let Err(error) = index_directory(&space_root, path, &mut index) {
+            log::warn!("Failed to index {}: {}", path.display(), error);
+        }
+    } else if is_markdown_path(path) {
+        match read_file_meta(path, &key) {
+            Some(meta) => {
+                index.entries.insert(key, meta);
+            }
+            None => {
+                index.entries.remove(&key);
+            }
+        }
+    } else {
+        // A removed or renamed folder; forget everything that was inside it.
+        let prefix = format!("{}/", key);
+        index
+            .entries
+            .retain(|entry, _| !entry.starts_with(&prefix) || space_root.join(entry).is_file());
+    }
+
+    if let Err(error) = save_index(&space_root, &index) {
+        log::warn!("Failed to update metadata index: {}", error);
+    }
+}
+
+fn parse_bound(value: Option<&str>, name: &str) -> Result<Option<u64>, String> {
+    match value.map(str::trim).filter(|value| !value.is_empty()) {
+        Some(value) => parse_timestamp(value)
+            .map(Some)
+            .ok_or_else(|| format!("Invalid {} '{}'", name, value)),
+        None => Ok(None),
+    }
+}
+
+fn in_range(value: Option<u64>, after: Option<u64>, before: Option<u64>) -> bool {
+    if after.is_none() && before.is_none() {
+        return true;
+    }
+    value.is_some_and(|value| {
+        after.is_none_or(|after| value >= after) && before.is_none_or(|before| value < before)
+    })
+}
+
+/// Load the saved index, building it on first use and healing entries for
+/// files that changed since it was written.
+fn current_index(space_root: &Path) -> Result<MetaIndex, String> {
+    let _guard = lock_index();
+    let (mut index, mut changed) = match load_index(space_root) {
+        Some(index) => (index, false),
+        None => {
+            let mut index = MetaIndex {
+                version: META_INDEX_VERSION,
+                scanned_at: system_time_to_secs(SystemTime::now()).unwrap_or_default(),
+                entries: BTreeMap::new(),
+            };
+            index_directory(space_root, space_root, &mut index)?;
+            (index, true)
+        }
+    };
+    changed |= heal_index(space_root, &mut index);
+    if changed {
+        save_index(space_root, &index)?;
+    }
+    Ok(index)
+}
+
+/// Current metadata for every indexed file, keyed by `/`-separated path
+/// relative to the space root. Builds or heals the index as queries do.
+pub(crate) fn indexed_file_meta(space_root: &Path) -> Result<BTreeMap<String, FileMeta>, String> {
+    current_index(space_root).map(|index| index.entries)
+}
+
+/// Bring the saved index up to date so the first query doesn't pay for it.
+pub(crate) fn warm_meta_index(space_root: &Path) -> Result<(), String> {
+    current_index(space_root).map(|_| ())
+}
+
+fn query_index(space_root: &Path, filter: &FileMetaFilter) -> Result<FileMetaPage, String> {
+    let created_after = parse_bound(filter.created_after.as_deref(), "created_after")?;
+    let created_before = parse_bound(filter.created_before.as_deref(), "created_before")?;
+    let modified_after = parse_bound(filter.modified_after.as_deref(), "modified_after")?;
+    let modified_before = parse_bound(filter.modified_before.as_deref(), "modified_before")?;
+    let kinds: Vec<String> = filter
+        .kinds
+        .iter()
+        .map(|kind| kind.trim().to_ascii_lowercase())
+        .collect();
+    let statuses: Vec<String> = filter
+        .statuses
+        .iter()
+        .map(|status| status.trim().to_ascii_lowercase())
+        .collect();
+
+    let index = current_index(space_root)?;
+
+    let mut matches: Vec<FileMetaItem> = index
+        .entries
+        .into_iter()
+        .filter(|(_, meta)| {
+            in_range(meta.created, created_after, created_before)
+                && in_range(Some(meta.modified), modified_after, modified_before)
+                && (kinds.is_empty() || kinds.contains(&meta.kind))
+                && (statuses.is_empty()
+                    || meta
+                        .status
+                        .as_ref()
+                        .is_some_and(|status| statuses.contains(status)))
+        })
+        .map(|(key, meta)| FileMetaItem {
+            path: space_root.join(key).to_string_lossy().to_string(),
+            meta,
+        })
+        .collect();
+    matches.sort_by(|a, b| {
+        b.meta
+            .created
+            .cmp(&a.meta.created)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    let total = matches.len();
+    let limit = filter.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    let items: Vec<FileMetaItem> = matches
+        .into_iter()
+        .skip(filter.offset)
+        .take(limit)
+        .collect();
+    Ok(FileMetaPage {
+        has_more: filter.offset + items.len() < total,
+        items,
+        total,
+        offset: filter.offset,
+    })
+}
+
+/// Query indexed file metadata by created/modified range, kind, and status
+///
+/// Builds `.gtdspace/meta.json` on first use. Entries whose file is missing
+/// or whose modification time changed are re-parsed before filtering, so
+/// results stay correct even when the watcher missed a change.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `filter` - Range, kind, and status predicates plus `offset`/`limit` (optional)
+///
+/// # Returns
+///
+/// A page of matches sorted by created time (newest first), or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const page = await invoke<FileMetaPage>('query_files_meta', {
+///   spacePath: '/path/to/space',
+///   filter: { createdAfter: '2026-04-06', kinds: ['action', 'project'], limit: 20 },
+/// });
+/// ```
+#[tauri::command]
+pub fn query_files_meta(
+    space_path: String,
+    filter: Option<FileMetaFilter>,
+) -> Result<FileMetaPage, String> {
+    log::info!("Querying file metadata in: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    query_index(space_root, &filter.unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(created: &str, status: &str) -> String {
+        format!(
+            "# Action\n\n## Status\n[!singleselect:status:{}]\n\n## Created\n[!datetime:created_date_time:{}]\n",
+            status, created
+        )
+    }
+
+    fn names(page: &FileMetaPage) -> Vec<String> {
+        page.items
+            .iter()
+            .map(|item| {
+                Path::new(&item.path)
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn query_filters_paginates_and_self_heals() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        write_test_file(
+            project.join("Old.md"),
+            &action("2026-03-01T09:00:00Z", "completed"),
+        )?;
+        write_test_file(
+            project.join("New.md"),
+            &action("2026-04-07T09:00:00Z", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Newer.md"),
+            &action("2026-04-08T09:00:00Z", "waiting"),
+        )?;
+
+        let this_week = FileMetaFilter {
+            created_after: Some("2026-04-06T00:00:00Z".to_string()),
+            kinds: vec!["action".to_string()],
+            limit: Some(1),
+            ..Default::default()
+        };
+        let page = query_index(root, &this_week)?;
+        assert!(meta_index_path(root).is_file());
+        assert_eq!(page.total, 2);
+        assert!(page.has_more);
+        assert_eq!(names(&page), vec!["Newer.md"]);
+        let next = query_index(
+            root,
+            &FileMetaFilter {
+                offset: 1,
+                ..this_week.clone()
+            },
+        )?;
+        assert_eq!(names(&next), vec!["New.md"]);
+        assert!(!next.has_more);
+
+        let projects = query_index(
+            root,
+            &FileMetaFilter {
+                kinds: vec!["project".to_string()],
+                statuses: vec!["In-Progress".to_string()],
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(names(&projects), vec!["README.md"]);
+
+        // Changes made behind the index's back are picked up on the next query.
+        fs::remove_file(project.join("Newer.md")).map_err(|e| e.to_string())?;
+        let mut index = load_index(root).expect("index exists");
+        let stale = index
+            .entries
+            .get_mut("Projects/Alpha Project/New.md")
+            .expect("entry exists");
+        stale.modified = 0;
+        stale.status = None;
+        save_index(root, &index)?;
+
+        let healed = query_index(
+            root,
+            &FileMetaFilter {
+                statuses: vec!["in-progress".to_string()],
+                kinds: vec!["action".to_string()],
+                ..Default::default()
+            },
+        )?;
+        assert_eq!(names(&healed), vec!["New.md"]);
+        assert!(!load_index(root)
+            .expect("index exists")
+            .entries
+            .contains_key("Projects/Alpha Project/Newer.md"));
+
+        // Hooks keep an existing index current.
+        write_test_file(project.join("Hooked.md"), &action("2026-04-09", "waiting"))?;
+        record_file_change(&project.join("Hooked.md"));
+        assert!(load_index(root)
+            .expect("index exists")
+            .entries
+            .contains_key("Projects/Alpha Project/Hooked.md"));
+
+        assert!(query_index(
+            root,
+            &FileMetaFilter {
+                created_before: Some("next week".to_string()),
+                ..Default::default()
+            }
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn files_created_outside_the_app_are_indexed() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let waiting = FileMetaFilter {
+            statuses: vec!["waiting".to_string()],
+            kinds: vec!["action".to_string()],
+            ..Default::default()
+        };
+        assert!(query_index(root, &waiting)?.items.is_empty());
+
+        // Dropped in by another tool; no hook tells the index about it.
+        write_test_file(
+            project.join("Dropped.md"),
+            &action("2026-04-09T09:00:00Z", "waiting"),
+        )?;
+        let page = query_index(root, &waiting)?;
+        assert_eq!(names(&page), vec!["Dropped.md"]);
+        assert!(load_index(root)
+            .expect("index exists")
+            .entries
+            .contains_key("Projects/Alpha Project/Dropped.md"));
+
+        Ok(())
+    }
+}
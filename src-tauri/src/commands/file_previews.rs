@@ -0,0 +1,198 @@
+//! One-line content previews for quick-open and the sidebar.
+//!
+//! A preview is the first line of body text: front matter, headings, blank
+//! lines, and `[!...]` field markers are skipped. Previews are cached by path
+//! and modification time; the file watcher drops entries as files change.
+//! Encrypted files are previewed but never cached.
+
+use once_cell::sync::Lazy;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use super::folder_encryption::read_encrypted_markdown;
+
+/// Preview length when the caller does not pass one
+pub(crate) const DEFAULT_PREVIEW_LEN: usize = 160;
+
+struct CachedPreview {
+    modified: SystemTime,
+    /// Untruncated preview line
+    preview: String,
+}
+
+static PREVIEW_CACHE: Lazy<Mutex<HashMap<PathBuf, CachedPreview>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+fn lock_cache() -> std::sync::MutexGuard<'static, HashMap<PathBuf, CachedPreview>> {
+    PREVIEW_CACHE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Drop cached previews for `changed_path` and anything beneath it.
+pub(crate) fn invalidate_preview(changed_path: &Path) {
+    lock_cache().retain(|path, _| !path.starts_with(changed_path));
+}
+
+/// Whether a line holds only `[!...]` field markers
+fn is_marker_line(line: &str) -> bool {
+    let mut rest = line;
+    while let Some(marker) = rest.strip_prefix("[!") {
+        let Some(end) = marker.find(']') else {
+            return false;
+        };
+        rest = marker[end + 1..].trim_start();
+    }
+    rest.is_empty() && line.starts_with("[!")
+}
+
+/// First line of body text in a markdown document, trimmed
+fn extract_preview(content: &str) -> String {
+    let mut lines = content.lines().map(str::trim).peekable();
+    if lines.peek() == Some(&"---") {
+        lines.next();
+        for line in lines.by_ref() {
+            if line == "---" || line == "..." {
+                break;
+            }
+        }
+    }
+
+    lines
+        .find(|line| !line.is_empty() && !line.starts_with('#') && !is_marker_line(line))
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Cut a preview to at most `max_len` characters
+pub(crate) fn truncate_preview(preview: &str, max_len: usize) -> String {
+    match preview.char_indices().nth(max_len) {
+        Some((end, _)) => preview[..end].trim_end().to_string(),
+        None => preview.to_string(),
+    }
+}
+
+/// Untruncated preview for one file, served from cache while its mtime holds.
+/// Unreadable files preview as an empty string.
+pub(crate) fn file_preview(path: &Path) -> String {
+    match read_encrypted_markdown(path) {
+        Ok(Some(content)) => return extract_preview(&content),
+        Ok(None) => {}
+        Err(error) => {
+            log::debug!("No preview for encrypted {}: {}", path.display(), error);
+            return String::new();
+        }
+    }
+
+    let Ok(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()) else {
+        return String::new();
+    };
+    if let Some(cached) = lock_cache().get(path) {
+        if cached.modified == modified {
+            return cached.preview.clone();
+        }
+    }
+
+    let preview = match fs::read_to_string(path) {
+        Ok(content) => extract_preview(&content),
+        Err(error) => {
+            log::debug!("No preview for {}: {}", path.display(), error);
+            return String::new();
+        }
+    };
+    lock_cache().insert(
+        path.to_path_buf(),
+        CachedPreview {
+            modified,
+            preview: preview.clone(),
+        },
+    );
+    preview
+}
+
+/// Get one-line content previews for a batch of files
+///
+/// Each preview is the first line that is not front matter, a heading, blank,
+/// or only `[!...]` markers. Files that cannot be read get an empty string
+/// instead of failing the batch.
+///
+/// # Arguments
+///
+/// * `paths` - Full paths of the files to preview
+/// * `max_len` - Maximum preview length in characters (defaults to 160)
+///
+/// # Returns
+///
+/// Preview text keyed by the paths as passed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const previews = await invoke<Record<string, string>>('get_file_previews', {
+///   paths: ['/path/to/space/Goals/Fitness.md'],
+///   maxLen: 120,
+/// });
+/// ```
+#[tauri::command]
+pub async fn get_file_previews(
+    paths: Vec<String>,
+    max_len: Option<usize>,
+) -> Result<HashMap<String, String>, String> {
+    let max_len = max_len.unwrap_or(DEFAULT_PREVIEW_LEN);
+    tokio::task::spawn_blocking(move || {
+        paths
+            .into_iter()
+            .map(|path| {
+                let preview = truncate_preview(&file_preview(Path::new(&path)), max_len);
+                (path, preview)
+            })
+            .collect()
+    })
+    .await
+    .map_err(|e| format!("Failed to build file previews: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[tokio::test]
+    async fn previews_skip_structure_and_follow_edits() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let goal = root.join("Goals/Fitness.md");
+        write_test_file(
+            &goal,
+            "---\ntitle: Fitness\n---\n# Fitness\n\n[!singleselect:goal-status:in-progress] [!datetime:created_date_time:2025-01-01]\n\n## Description\n  Run a half marathon before the end of the year.  \n",
+        )?;
+        let missing = root.join("Goals/Missing.md");
+        let paths = vec![
+            goal.to_string_lossy().to_string(),
+            missing.to_string_lossy().to_string(),
+        ];
+
+        let previews = get_file_previews(paths.clone(), None).await?;
+        assert_eq!(
+            previews[&paths[0]],
+            "Run a half marathon before the end of the year."
+        );
+        assert_eq!(previews[&paths[1]], "");
+
+        let short = get_file_previews(paths.clone(), Some(8)).await?;
+        assert_eq!(short[&paths[0]], "Run a ha");
+
+        write_test_file(&goal, "# Fitness\n\nSwim twice a week.\n")?;
+        invalidate_preview(&goal);
+        let previews = get_file_previews(paths.clone(), None).await?;
+        assert_eq!(previews[&paths[0]], "Swim twice a week.");
+
+        assert!(!is_marker_line("[!note] Remember the race entry"));
+        Ok(())
+    }
+}
@@ -1,11 +1,25 @@
 //! File system commands and shared file operation payloads.
 
+use super::action_table::sync_action_table_after_change;
+use super::custom_fields::{custom_fields_for, parse_custom_fields};
+use super::delete_impact::ensure_delete_acknowledged;
+use super::file_locks::with_file_lock;
+use super::file_meta::{created_times, parse_timestamp, record_file_change};
+use super::folder_encryption::{
+    encrypted_logical_path, encrypted_sibling, is_encrypted_markdown_path, read_encrypted_markdown,
+    save_encrypted_markdown, stored_markdown_path, transfer_encrypted_markdown,
+};
+use super::path_remap::{emit_remap, PathRemap};
 use super::seed_data::generate_action_template;
+use super::space_config::{ensure_file_editable, ensure_space_writable};
+use super::space_walk::{walk_directory, WalkOptions};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Component, Path};
+use tauri::AppHandle;
 use tempfile::NamedTempFile;
 
 const DELETE_FILE_RETRY_BACKOFF_MS: [u64; 3] = [50, 150, 300];
@@ -31,6 +45,65 @@ fn strip_markdown_extension(name: &str) -> &str {
     }
 }
 
+/// Describe an IO failure, calling out OS permission problems distinctly.
+fn describe_io_error(action: &str, path: &Path, error: &io::Error) -> String {
+    if error.kind() == io::ErrorKind::PermissionDenied {
+        format!(
+            "Permission denied: cannot {} {} because it is read-only or not accessible",
+            action,
+            path.display()
+        )
+    } else {
+        format!("Failed to {}: {}", action, error)
+    }
+}
+
+fn metadata_is_writable(metadata: &fs::Metadata) -> bool {
+    !metadata.permissions().readonly()
+}
+
+/// Build a `MarkdownFile` entry for any file from its metadata.
+pub(crate) fn file_entry(path: &Path, metadata: &fs::Metadata) -> MarkdownFile {
+    let extension = path
+        .extension()
+        .map(|ext| format!(".{}", ext.to_string_lossy()))
+        .unwrap_or_default();
+    MarkdownFile {
+        id: generate_stable_file_id(path),
+        name: path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+        path: path.to_string_lossy().to_string(),
+        size: metadata.len(),
+        last_modified: metadata
+            .modified()
+            .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+            .duration_since(std::time::SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs(),
+        extension,
+        is_writable: metadata_is_writable(metadata),
+        custom_fields: BTreeMap::new(),
+    }
+}
+
+/// Refuse to replace a file the OS marks read-only.
+///
+/// Atomic saves swap the file via rename, which succeeds even when the target
+/// itself is read-only, so the permission bit has to be checked up front.
+fn ensure_os_writable(path: &Path, action: &str) -> Result<(), String> {
+    match fs::metadata(path) {
+        Ok(metadata) if !metadata_is_writable(&metadata) => Err(describe_io_error(
+            action,
+            path,
+            &io::Error::from(io::ErrorKind::PermissionDenied),
+        )),
+        _ => Ok(()),
+    }
+}
+
 fn has_markdown_extension(name: &str) -> bool {
     let lower = name.to_ascii_lowercase();
     lower.ends_with(".md") || lower.ends_with(".markdown")
@@ -227,6 +300,17 @@ pub struct MarkdownFile {
     pub last_modified: u64,
     /// File extension (.md, .markdown)
     pub extension: String,
+    /// Whether the OS permissions allow writing to the file
+    #[serde(default = "default_is_writable")]
+    pub is_writable: bool,
+    /// Custom field values, filled in for project actions in spaces that
+    /// declare custom fields
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub custom_fields: BTreeMap<String, String>,
+}
+
+fn default_is_writable() -> bool {
+    true
 }
 
 /// File operation result for create operations
@@ -240,89 +324,177 @@ pub struct FileOperationResult {
     pub message: Option<String>,
 }
 
-/// Helper function to recursively scan directories for markdown files
-fn scan_directory_recursive(dir_path: &Path, files: &mut Vec<MarkdownFile>) -> Result<(), String> {
-    let markdown_extensions = ["md", "markdown"];
+/// Sort key for paged file listings
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FileSortKey {
+    /// File name, case-insensitive
+    Name,
+    /// Last modification time
+    Modified,
+    /// File size in bytes
+    Size,
+    /// `created_date_time` marker, else filesystem creation time
+    Created,
+}
 
-    match fs::read_dir(dir_path) {
-        Ok(entries) => {
-            for entry_result in entries {
-                let entry = match entry_result {
-                    Ok(entry) => entry,
-                    Err(error) => {
-                        log::warn!(
-                            "Skipping unreadable entry in {}: {}",
-                            dir_path.display(),
-                            error
-                        );
-                        continue;
-                    }
-                };
-                let path = entry.path();
-                let metadata = match fs::symlink_metadata(&path) {
-                    Ok(metadata) => metadata,
-                    Err(error) => {
-                        log::warn!(
-                            "Skipping entry with unreadable metadata {}: {}",
-                            path.display(),
-                            error
-                        );
-                        continue;
-                    }
-                };
+/// Filtering, sorting, and pagination for paged file listings
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ListFilesOptions {
+    /// Sort key; unset keeps the listing's default order
+    #[serde(default)]
+    pub sort_by: Option<FileSortKey>,
+    #[serde(default)]
+    pub descending: bool,
+    /// Modified at or after this date/time (RFC 3339 or YYYY-MM-DD, local time)
+    #[serde(default)]
+    pub modified_after: Option<String>,
+    /// Case-insensitive substring of the file name
+    #[serde(default)]
+    pub name_contains: Option<String>,
+    /// Matches to skip
+    #[serde(default)]
+    pub offset: usize,
+    /// Page size; unset returns every remaining match
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
 
-                // Recursively scan subdirectories
-                if metadata.file_type().is_symlink() {
-                    continue;
-                } else if metadata.file_type().is_dir() {
-                    // Skip hidden directories (starting with .)
-                    if let Some(dir_name) = path.file_name() {
-                        if !dir_name.to_string_lossy().starts_with('.') {
-                            if let Err(error) = scan_directory_recursive(&path, files) {
-                                log::warn!(
-                                    "Skipping unreadable child directory {}: {}",
-                                    path.display(),
-                                    error
-                                );
-                            }
-                        }
-                    }
-                } else if metadata.file_type().is_file() {
-                    // Process markdown files
-                    if let Some(extension) = path.extension() {
-                        let ext_str = extension.to_string_lossy().to_lowercase();
-                        if markdown_extensions.contains(&ext_str.as_str()) {
-                            let file_name = path
-                                .file_name()
-                                .unwrap_or_default()
-                                .to_string_lossy()
-                                .to_string();
+/// One page of a file listing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarkdownFilePage {
+    pub files: Vec<MarkdownFile>,
+    /// Matches across all pages
+    pub total_available: usize,
+    pub offset: usize,
+    pub has_more: bool,
+}
 
-                            files.push(MarkdownFile {
-                                id: generate_stable_file_id(&path),
-                                name: file_name,
-                                path: path.to_string_lossy().to_string(),
-                                size: metadata.len(),
-                                last_modified: metadata
-                                    .modified()
-                                    .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
-                                    .duration_since(std::time::SystemTime::UNIX_EPOCH)
-                                    .unwrap_or_default()
-                                    .as_secs(),
-                                extension: if ext_str.is_empty() {
-                                    String::new()
-                                } else {
-                                    format!(".{}", ext_str)
-                                },
-                            });
-                        }
-                    }
-                }
-            }
-            Ok(())
+/// Filter, sort, and paginate `files` listed from `dir`.
+///
+/// `default_order` is used when no sort key is given and to break ties.
+fn apply_list_options(
+    dir: &Path,
+    mut files: Vec<MarkdownFile>,
+    options: &ListFilesOptions,
+    default_order: impl Fn(&MarkdownFile, &MarkdownFile) -> std::cmp::Ordering,
+) -> Result<MarkdownFilePage, String> {
+    let modified_after = match options
+        .modified_after
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+    {
+        Some(value) => Some(
+            parse_timestamp(value).ok_or_else(|| format!("Invalid modified_after '{}'", value))?,
+        ),
+        None => None,
+    };
+    let name_contains = options
+        .name_contains
+        .as_deref()
+        .map(|needle| needle.trim().to_lowercase())
+        .filter(|needle| !needle.is_empty());
+    files.retain(|file| {
+        modified_after.is_none_or(|after| file.last_modified >= after)
+            && name_contains
+                .as_ref()
+                .is_none_or(|needle| file.name.to_lowercase().contains(needle))
+    });
+
+    match options.sort_by {
+        Some(FileSortKey::Created) => {
+            let paths: Vec<&Path> = files.iter().map(|file| Path::new(&file.path)).collect();
+            let created = created_times(dir, &paths);
+            let mut keyed: Vec<(Option<u64>, MarkdownFile)> =
+                created.into_iter().zip(files).collect();
+            keyed.sort_by(|(a_created, a), (b_created, b)| {
+                a_created.cmp(b_created).then_with(|| default_order(a, b))
+            });
+            files = keyed.into_iter().map(|(_, file)| file).collect();
         }
-        Err(e) => Err(format!("Failed to read directory: {}", e)),
+        Some(key) => files.sort_by(|a, b| {
+            let primary = match key {
+                FileSortKey::Modified => a.last_modified.cmp(&b.last_modified),
+                FileSortKey::Size => a.size.cmp(&b.size),
+                _ => a.name.to_lowercase().cmp(&b.name.to_lowercase()),
+            };
+            primary.then_with(|| default_order(a, b))
+        }),
+        None => files.sort_by(&default_order),
+    }
+    if options.descending {
+        files.reverse();
     }
+
+    let total_available = files.len();
+    let page: Vec<MarkdownFile> = files
+        .into_iter()
+        .skip(options.offset)
+        .take(options.limit.unwrap_or(usize::MAX))
+        .collect();
+    Ok(MarkdownFilePage {
+        has_more: options.offset + page.len() < total_available,
+        files: page,
+        total_available,
+        offset: options.offset,
+    })
+}
+
+/// Helper function to recursively scan directories for markdown files
+///
+/// Hidden folders and symlinks are skipped; see [`walk_directory`].
+pub(crate) fn scan_directory_recursive(
+    dir_path: &Path,
+    files: &mut Vec<MarkdownFile>,
+) -> Result<(), String> {
+    let markdown_extensions = ["md", "markdown"];
+    let options = WalkOptions {
+        skip_hidden_dirs: true,
+        ..WalkOptions::default()
+    };
+
+    walk_directory(dir_path, options, |entry| {
+        let metadata = entry.metadata;
+        if !metadata.is_file() {
+            return Ok(true);
+        }
+        // Encrypted files are listed under their logical markdown path
+        let path = encrypted_logical_path(entry.path).unwrap_or_else(|| entry.path.to_path_buf());
+        // Process markdown files
+        if let Some(extension) = path.extension() {
+            let ext_str = extension.to_string_lossy().to_lowercase();
+            if markdown_extensions.contains(&ext_str.as_str()) {
+                let file_name = path
+                    .file_name()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string();
+
+                files.push(MarkdownFile {
+                    id: generate_stable_file_id(&path),
+                    name: file_name,
+                    path: path.to_string_lossy().to_string(),
+                    size: metadata.len(),
+                    last_modified: metadata
+                        .modified()
+                        .unwrap_or(std::time::SystemTime::UNIX_EPOCH)
+                        .duration_since(std::time::SystemTime::UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs(),
+                    extension: if ext_str.is_empty() {
+                        String::new()
+                    } else {
+                        format!(".{}", ext_str)
+                    },
+                    is_writable: metadata_is_writable(metadata),
+                    custom_fields: BTreeMap::new(),
+                });
+            }
+        }
+        Ok(true)
+    })?;
+    Ok(())
 }
 
 /// List all markdown files in the specified directory and its subdirectories
@@ -353,8 +525,16 @@ fn scan_directory_recursive(dir_path: &Path, files: &mut Vec<MarkdownFile>) -> R
 pub fn list_markdown_files(path: String) -> Result<Vec<MarkdownFile>, String> {
     log::info!("Listing markdown files recursively in: {}", path);
 
-    let dir_path = Path::new(&path);
+    let mut files = collect_markdown_files(Path::new(&path))?;
+
+    // Sort files by path for consistent ordering
+    files.sort_by_key(|a| a.path.to_lowercase());
 
+    log::info!("Found {} markdown files", files.len());
+    Ok(files)
+}
+
+fn collect_markdown_files(dir_path: &Path) -> Result<Vec<MarkdownFile>, String> {
     if !dir_path.exists() {
         return Err("Directory does not exist".to_string());
     }
@@ -367,11 +547,97 @@ pub fn list_markdown_files(path: String) -> Result<Vec<MarkdownFile>, String> {
 
     // Recursively scan the directory
     scan_directory_recursive(dir_path, &mut files)?;
+    Ok(files)
+}
 
-    // Sort files by path for consistent ordering
-    files.sort_by(|a, b| a.path.to_lowercase().cmp(&b.path.to_lowercase()));
+/// List markdown files with server-side filtering, sorting, and pagination
+///
+/// Same scan as `list_markdown_files`, but only the requested page crosses
+/// IPC. Without `sort_by`, files keep the `list_markdown_files` path order.
+///
+/// # Arguments
+///
+/// * `path` - Directory path to scan for markdown files
+/// * `options` - Sort, filter, and pagination options (all optional)
+///
+/// # Returns
+///
+/// One page of files plus the number of matches across all pages
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const page = await invoke<MarkdownFilePage>('list_markdown_files_page', {
+///   path: '/path/to/space/Cabinet',
+///   options: { sort_by: 'modified', descending: true, limit: 50 },
+/// });
+/// ```
+#[tauri::command]
+pub fn list_markdown_files_page(
+    path: String,
+    options: Option<ListFilesOptions>,
+) -> Result<MarkdownFilePage, String> {
+    log::info!("Listing a page of markdown files in: {}", path);
 
-    log::info!("Found {} markdown files", files.len());
+    let dir_path = Path::new(&path);
+    let files = collect_markdown_files(dir_path)?;
+    apply_list_options(dir_path, files, &options.unwrap_or_default(), |a, b| {
+        a.path.to_lowercase().cmp(&b.path.to_lowercase())
+    })
+}
+
+/// List markdown files modified after a point in time
+///
+/// Used when the app regains focus to find files edited externally while
+/// the file watcher was not running.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `since_unix` - Unix timestamp (seconds); only files modified later are returned
+///
+/// # Returns
+///
+/// Matching files, most recently modified first
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const changed = await invoke<MarkdownFile[]>('list_files_modified_since', {
+///   spacePath: '/path/to/space',
+///   sinceUnix: settings.last_active_timestamp ?? 0,
+/// });
+/// ```
+#[tauri::command]
+pub fn list_files_modified_since(
+    space_path: String,
+    since_unix: u64,
+) -> Result<Vec<MarkdownFile>, String> {
+    log::info!(
+        "Listing files modified since {} in: {}",
+        since_unix,
+        space_path
+    );
+
+    let dir_path = Path::new(&space_path);
+    if !dir_path.is_dir() {
+        return Err("Directory does not exist".to_string());
+    }
+
+    let mut files = Vec::new();
+    scan_directory_recursive(dir_path, &mut files)?;
+    files.retain(|file| file.last_modified > since_unix);
+    files.sort_by(|a, b| {
+        b.last_modified
+            .cmp(&a.last_modified)
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    log::info!("Found {} files modified since {}", files.len(), since_unix);
     Ok(files)
 }
 
@@ -381,7 +647,50 @@ pub fn list_markdown_files(path: String) -> Result<Vec<MarkdownFile>, String> {
 pub fn list_project_actions(project_path: String) -> Result<Vec<MarkdownFile>, String> {
     log::info!("Listing project actions in: {}", project_path);
 
+    let mut files = collect_project_actions(Path::new(&project_path))?;
+    files.sort_by_key(|a| a.name.to_lowercase());
+    log::info!("Found {} project actions", files.len());
+    Ok(files)
+}
+
+/// List project actions with server-side filtering, sorting, and pagination
+///
+/// Without `sort_by`, actions keep the `list_project_actions` name order.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder
+/// * `options` - Sort, filter, and pagination options (all optional)
+///
+/// # Returns
+///
+/// One page of actions plus the number of matches across all pages
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const page = await invoke<MarkdownFilePage>('list_project_actions_page', {
+///   projectPath: '/path/to/space/Projects/Website',
+///   options: { sort_by: 'created', name_contains: 'draft' },
+/// });
+/// ```
+#[tauri::command]
+pub fn list_project_actions_page(
+    project_path: String,
+    options: Option<ListFilesOptions>,
+) -> Result<MarkdownFilePage, String> {
+    log::info!("Listing a page of project actions in: {}", project_path);
+
     let dir_path = Path::new(&project_path);
+    let files = collect_project_actions(dir_path)?;
+    apply_list_options(dir_path, files, &options.unwrap_or_default(), |a, b| {
+        a.name.to_lowercase().cmp(&b.name.to_lowercase())
+    })
+}
+
+fn collect_project_actions(dir_path: &Path) -> Result<Vec<MarkdownFile>, String> {
     if !dir_path.exists() {
         return Err("Project directory does not exist".to_string());
     }
@@ -457,6 +766,8 @@ pub fn list_project_actions(project_path: String) -> Result<Vec<MarkdownFile>, S
                                 } else {
                                     format!(".{}", ext_str)
                                 },
+                                is_writable: metadata_is_writable(&metadata),
+                                custom_fields: BTreeMap::new(),
                             });
                         }
                     }
@@ -466,8 +777,15 @@ pub fn list_project_actions(project_path: String) -> Result<Vec<MarkdownFile>, S
         Err(e) => return Err(format!("Failed to read project directory: {}", e)),
     }
 
-    files.sort_by(|a, b| a.name.to_lowercase().cmp(&b.name.to_lowercase()));
-    log::info!("Found {} project actions", files.len());
+    // Action files are only read when the space declares custom fields
+    if custom_fields_for(dir_path).is_ok_and(|fields| !fields.is_empty()) {
+        for file in &mut files {
+            if let Ok(content) = fs::read_to_string(&file.path) {
+                file.custom_fields = parse_custom_fields(&content);
+            }
+        }
+    }
+
     Ok(files)
 }
 
@@ -500,6 +818,11 @@ pub fn read_file(path: String) -> Result<String, String> {
 
     let file_path = Path::new(&path);
 
+    if let Some(content) = read_encrypted_markdown(file_path)? {
+        log::info!("Decrypted file: {} ({} bytes)", path, content.len());
+        return Ok(content);
+    }
+
     if !file_path.exists() {
         log::error!("File does not exist: {}", path);
         return Err(format!("File does not exist: {}", path));
@@ -517,7 +840,7 @@ pub fn read_file(path: String) -> Result<String, String> {
         }
         Err(e) => {
             log::error!("Failed to read file {}: {:?}", path, e);
-            Err(format!("Failed to read file: {}", e))
+            Err(describe_io_error("read file", file_path, &e))
         }
     }
 }
@@ -551,19 +874,37 @@ pub fn save_file(path: String, content: String) -> Result<String, String> {
     log::info!("Saving file: {} ({} bytes)", path, content.len());
 
     let file_path = Path::new(&path);
+    with_file_lock(file_path, || save_file_contents(file_path, &content))?;
+    Ok("File saved successfully".to_string())
+}
+
+/// Write `content` to `file_path` atomically.
+///
+/// Callers that read the file first should hold its lock from
+/// [`with_file_lock`] around both steps and call this directly.
+pub(crate) fn save_file_contents(file_path: &Path, content: &str) -> Result<(), String> {
+    let path = file_path.display();
+    ensure_file_editable(file_path)?;
+    ensure_os_writable(file_path, "save")?;
+
+    if save_encrypted_markdown(file_path, content)? {
+        log::info!("Saved encrypted file: {}", path);
+        sync_action_table_after_change(file_path);
+        return Ok(());
+    }
 
     // Create parent directories if they don't exist
     if let Some(parent) = file_path.parent() {
         if !parent.exists() {
             if let Err(e) = fs::create_dir_all(parent) {
-                return Err(format!("Failed to create parent directories: {}", e));
+                return Err(describe_io_error("create parent directories", parent, &e));
             }
         }
     }
 
     let temp_dir = file_path.parent().unwrap_or_else(|| Path::new("."));
     let mut temp_file = NamedTempFile::new_in(temp_dir)
-        .map_err(|e| format!("Failed to create temporary file for save: {}", e))?;
+        .map_err(|e| describe_io_error("create temporary file for save", temp_dir, &e))?;
 
     temp_file
         .write_all(content.as_bytes())
@@ -577,10 +918,12 @@ pub fn save_file(path: String, content: String) -> Result<String, String> {
         .map_err(|e| format!("Failed to sync temporary file for save: {}", e))?;
     temp_file
         .persist(file_path)
-        .map_err(|e| format!("Failed to replace file atomically: {}", e.error))?;
+        .map_err(|e| describe_io_error("replace file atomically", file_path, &e.error))?;
 
     log::info!("Successfully saved file atomically: {}", path);
-    Ok("File saved successfully".to_string())
+    sync_action_table_after_change(file_path);
+    record_file_change(file_path);
+    Ok(())
 }
 
 /// Create a new markdown file
@@ -615,6 +958,7 @@ pub fn create_file(directory: String, name: String) -> Result<FileOperationResul
     log::info!("Creating file: {} in directory: {}", name, directory);
 
     let dir_path = Path::new(&directory);
+    ensure_space_writable(dir_path)?;
 
     if !dir_path.exists() || !dir_path.is_dir() {
         return Ok(FileOperationResult {
@@ -662,7 +1006,16 @@ pub fn create_file(directory: String, name: String) -> Result<FileOperationResul
 
     // Create appropriate template content based on GTD horizon
     let template_content = if is_in_projects && is_project_dir {
-        generate_action_template(&clean_name, "in-progress", None, None, "medium", None, None)
+        generate_action_template(
+            &clean_name,
+            "in-progress",
+            None,
+            None,
+            "medium",
+            None,
+            None,
+            None,
+        )
     } else if is_in_vision {
         format!(
             r#"# {}
@@ -832,6 +1185,24 @@ pub fn create_file(directory: String, name: String) -> Result<FileOperationResul
         )
     };
 
+    if is_encrypted_markdown_path(&file_path) {
+        if stored_markdown_path(&file_path).exists() {
+            return Ok(FileOperationResult {
+                success: false,
+                path: None,
+                message: Some("File already exists".to_string()),
+            });
+        }
+        save_encrypted_markdown(&file_path, &template_content)?;
+        let path_str = file_path.to_string_lossy().to_string();
+        log::info!("Created encrypted file: {}", path_str);
+        return Ok(FileOperationResult {
+            success: true,
+            path: Some(path_str),
+            message: Some("File created successfully".to_string()),
+        });
+    }
+
     match fs::OpenOptions::new()
         .write(true)
         .create_new(true)
@@ -850,6 +1221,8 @@ pub fn create_file(directory: String, name: String) -> Result<FileOperationResul
             }
             let path_str = file_path.to_string_lossy().to_string();
             log::info!("Successfully created file: {}", path_str);
+            sync_action_table_after_change(&file_path);
+            record_file_change(&file_path);
             Ok(FileOperationResult {
                 success: true,
                 path: Some(path_str),
@@ -864,7 +1237,7 @@ pub fn create_file(directory: String, name: String) -> Result<FileOperationResul
                 message: Some(if e.kind() == io::ErrorKind::AlreadyExists {
                     "File already exists".to_string()
                 } else {
-                    format!("Failed to create file: {}", e)
+                    describe_io_error("create file", &file_path, &e)
                 }),
             })
         }
@@ -895,12 +1268,33 @@ pub fn create_file(directory: String, name: String) -> Result<FileOperationResul
 /// });
 /// ```
 #[tauri::command]
-pub fn rename_file(old_path: String, new_name: String) -> Result<FileOperationResult, String> {
+pub fn rename_file(
+    app: AppHandle,
+    old_path: String,
+    new_name: String,
+) -> Result<FileOperationResult, String> {
+    let result = rename_markdown_file(&old_path, &new_name)?;
+    if let Some(new_path) = result.path.as_deref().filter(|_| result.success) {
+        emit_remap(
+            &app,
+            &[PathRemap::new(Path::new(&old_path), Path::new(new_path))],
+        );
+    }
+    Ok(result)
+}
+
+/// [`rename_file`] without the remap event.
+pub(crate) fn rename_markdown_file(
+    old_path: &str,
+    new_name: &str,
+) -> Result<FileOperationResult, String> {
     log::info!("Renaming file: {} to: {}", old_path, new_name);
 
     let old_file_path = Path::new(&old_path);
+    ensure_space_writable(old_file_path)?;
+    let stored_old_path = stored_markdown_path(old_file_path);
 
-    if !old_file_path.exists() {
+    if !stored_old_path.exists() {
         return Ok(FileOperationResult {
             success: false,
             path: None,
@@ -908,7 +1302,7 @@ pub fn rename_file(old_path: String, new_name: String) -> Result<FileOperationRe
         });
     }
 
-    if !old_file_path.is_file() {
+    if !stored_old_path.is_file() {
         return Ok(FileOperationResult {
             success: false,
             path: None,
@@ -927,7 +1321,7 @@ pub fn rename_file(old_path: String, new_name: String) -> Result<FileOperationRe
         }
     };
 
-    let safe_name = match extract_safe_file_name(&new_name) {
+    let safe_name = match extract_safe_file_name(new_name) {
         Ok(name) => name,
         Err(message) => {
             return Ok(FileOperationResult {
@@ -952,11 +1346,20 @@ pub fn rename_file(old_path: String, new_name: String) -> Result<FileOperationRe
     };
 
     let new_file_path = directory.join(&file_name);
+    // Encrypted files stay encrypted: rename the `.md.enc` siblings.
+    let stored_new_path = if stored_old_path == old_file_path {
+        new_file_path.clone()
+    } else {
+        encrypted_sibling(&new_file_path)
+    };
 
-    match rename_path(old_file_path, &new_file_path) {
+    match rename_path(&stored_old_path, &stored_new_path) {
         Ok(_) => {
             let path_str = new_file_path.to_string_lossy().to_string();
             log::info!("Successfully renamed file to: {}", path_str);
+            sync_action_table_after_change(&new_file_path);
+            record_file_change(old_file_path);
+            record_file_change(&new_file_path);
             Ok(FileOperationResult {
                 success: true,
                 path: Some(path_str),
@@ -980,11 +1383,17 @@ pub fn rename_file(old_path: String, new_name: String) -> Result<FileOperationRe
 
 /// Delete a file
 ///
-/// Permanently deletes the specified file from the file system.
+/// Moves the file to the OS trash when `use_trash` is true, otherwise
+/// deletes it permanently.
 ///
 /// # Arguments
 ///
 /// * `path` - Full path of the file to delete
+/// * `acknowledge_impact` - Pass false to fail with a JSON-encoded
+///   `ImpactWarning` when other files reference this one, then true to
+///   delete anyway; omit it to delete without the check
+/// * `use_trash` - Move to the trash instead of deleting permanently
+///   (defaults to false; the frontend passes true)
 ///
 /// # Returns
 ///
@@ -996,24 +1405,58 @@ pub fn rename_file(old_path: String, new_name: String) -> Result<FileOperationRe
 /// import { invoke } from '@tauri-apps/api/core';
 ///
 /// const result = await invoke('delete_file', {
-///   path: '/path/to/file.md'
+///   path: '/path/to/file.md',
+///   acknowledgeImpact: true,
+///   useTrash: true
 /// });
 /// ```
 #[tauri::command]
-pub fn delete_file(path: String) -> Result<FileOperationResult, String> {
+pub fn delete_file(
+    path: String,
+    acknowledge_impact: Option<bool>,
+    use_trash: Option<bool>,
+) -> Result<FileOperationResult, String> {
+    log::info!("Deleting file: {}", path);
+
+    let file_path = Path::new(&path);
+    ensure_space_writable(file_path)?;
+    ensure_delete_acknowledged(file_path, acknowledge_impact)?;
+
+    // Encrypted files are removed through their `.md.enc` sibling
+    let stored_path = stored_markdown_path(file_path);
+    let stored = stored_path.to_string_lossy().to_string();
+    let mut result = if use_trash.unwrap_or(false) {
+        if stored_path.exists() && !stored_path.is_file() {
+            return Ok(FileOperationResult {
+                success: false,
+                path: None,
+                message: Some("Path is not a file".to_string()),
+            });
+        }
+        move_to_trash(&stored, "File")
+    } else {
+        remove_file_with_retries(&stored)?
+    };
+    if result.success {
+        result.path = Some(path.clone());
+        sync_action_table_after_change(file_path);
+        record_file_change(file_path);
+    }
+    Ok(result)
+}
+
+fn remove_file_with_retries(path: &str) -> Result<FileOperationResult, String> {
     use std::io::ErrorKind;
     use std::thread::sleep;
     use std::time::Duration;
 
-    log::info!("Deleting file: {}", path);
-
-    let file_path = Path::new(&path);
+    let file_path = Path::new(path);
 
     if !file_path.exists() {
         // Idempotent delete: treat as success if file is already gone
         return Ok(FileOperationResult {
             success: true,
-            path: Some(path.clone()),
+            path: Some(path.to_string()),
             message: Some("File deleted successfully".to_string()),
         });
     }
@@ -1037,7 +1480,7 @@ pub fn delete_file(path: String) -> Result<FileOperationResult, String> {
                 log::info!("Successfully deleted file: {}", path);
                 return Ok(FileOperationResult {
                     success: true,
-                    path: Some(path.clone()),
+                    path: Some(path.to_string()),
                     message: Some("File deleted successfully".to_string()),
                 });
             }
@@ -1046,7 +1489,7 @@ pub fn delete_file(path: String) -> Result<FileOperationResult, String> {
                     log::info!("File already gone (NotFound): {}", path);
                     return Ok(FileOperationResult {
                         success: true,
-                        path: Some(path.clone()),
+                        path: Some(path.to_string()),
                         message: Some("File deleted successfully".to_string()),
                     });
                 }
@@ -1093,7 +1536,7 @@ pub fn delete_file(path: String) -> Result<FileOperationResult, String> {
                                     log::info!("Deleted file via rename workaround: {}", path);
                                     return Ok(FileOperationResult {
                                         success: true,
-                                        path: Some(path.clone()),
+                                        path: Some(path.to_string()),
                                         message: Some("File deleted successfully".to_string()),
                                     });
                                 }
@@ -1105,7 +1548,7 @@ pub fn delete_file(path: String) -> Result<FileOperationResult, String> {
                                     );
                                     return Ok(FileOperationResult {
                                         success: true,
-                                        path: Some(path.clone()),
+                                        path: Some(path.to_string()),
                                         message: Some(
                                             "File removed (renamed to temp); cleanup of temp may be pending"
                                                 .to_string(),
@@ -1139,19 +1582,32 @@ pub fn delete_file(path: String) -> Result<FileOperationResult, String> {
 
 /// Delete a folder and all its contents
 ///
+/// With `acknowledge_impact: false`, fails with a JSON-encoded
+/// `ImpactWarning` when files outside the folder reference it; pass true to
+/// delete anyway, or omit it to skip the check. With `use_trash` the
+/// folder is moved to the OS trash instead of being deleted permanently.
+///
 /// # Example
 /// ```javascript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
 /// const result = await invoke('delete_folder', {
-///   path: '/path/to/folder'
+///   path: '/path/to/folder',
+///   acknowledgeImpact: true,
+///   useTrash: true
 /// });
 /// ```
 #[tauri::command]
-pub fn delete_folder(path: String) -> Result<FileOperationResult, String> {
+pub fn delete_folder(
+    path: String,
+    acknowledge_impact: Option<bool>,
+    use_trash: Option<bool>,
+) -> Result<FileOperationResult, String> {
     log::info!("Deleting folder: {}", path);
 
     let folder_path = Path::new(&path);
+    ensure_space_writable(folder_path)?;
+    ensure_delete_acknowledged(folder_path, acknowledge_impact)?;
 
     if !folder_path.exists() {
         return Ok(FileOperationResult {
@@ -1169,6 +1625,10 @@ pub fn delete_folder(path: String) -> Result<FileOperationResult, String> {
         });
     }
 
+    if use_trash.unwrap_or(false) {
+        return Ok(move_to_trash(&path, "Folder"));
+    }
+
     match fs::remove_dir_all(folder_path) {
         Ok(_) => {
             log::info!("Successfully deleted folder: {}", path);
@@ -1189,6 +1649,122 @@ pub fn delete_folder(path: String) -> Result<FileOperationResult, String> {
     }
 }
 
+/// Move `path` to the OS trash. `kind` is "File" or "Folder" for messages.
+fn move_to_trash(path: &str, kind: &str) -> FileOperationResult {
+    if !Path::new(path).exists() {
+        return FileOperationResult {
+            success: true,
+            path: Some(path.to_string()),
+            message: Some(format!("{} already removed", kind)),
+        };
+    }
+
+    match trash::delete(path) {
+        Ok(()) => {
+            log::info!("Moved {} to trash: {}", kind.to_lowercase(), path);
+            FileOperationResult {
+                success: true,
+                path: Some(path.to_string()),
+                message: Some(format!("{} moved to trash", kind)),
+            }
+        }
+        Err(e) => {
+            log::error!("Failed to move {} to trash: {}", path, e);
+            FileOperationResult {
+                success: false,
+                path: None,
+                message: Some(format!(
+                    "Failed to move {} to trash: {}",
+                    kind.to_lowercase(),
+                    e
+                )),
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+))]
+fn restore_trashed_item(original_path: &Path) -> Result<(), String> {
+    let items = trash::os_limited::list().map_err(|e| format!("Failed to read trash: {}", e))?;
+    // The newest entry wins when the same path was trashed more than once
+    let item = items
+        .into_iter()
+        .filter(|item| item.original_path() == original_path)
+        .max_by_key(|item| item.time_deleted)
+        .ok_or_else(|| "No item for that path was found in the trash".to_string())?;
+
+    trash::os_limited::restore_all([item])
+        .map_err(|e| format!("Failed to restore from trash: {}", e))
+}
+
+#[cfg(not(any(
+    target_os = "windows",
+    all(
+        unix,
+        not(target_os = "macos"),
+        not(target_os = "ios"),
+        not(target_os = "android")
+    )
+)))]
+fn restore_trashed_item(_original_path: &Path) -> Result<(), String> {
+    Err("Restoring from the trash is not supported on this platform".to_string())
+}
+
+/// Restore a file or folder moved to the trash by `delete_file` or
+/// `delete_folder`
+///
+/// Supported on Windows and Linux. On macOS use Finder's "Put Back".
+///
+/// # Arguments
+///
+/// * `original_path` - Path the item had before it was trashed
+///
+/// # Returns
+///
+/// Unit on success, or an error when the item is not in the trash or its
+/// original path is taken
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('restore_from_trash', {
+///   originalPath: '/path/to/space/Projects/Website',
+/// });
+/// ```
+#[tauri::command]
+pub fn restore_from_trash(original_path: String) -> Result<(), String> {
+    log::info!("Restoring from trash: {}", original_path);
+
+    let path = Path::new(&original_path);
+    ensure_space_writable(path)?;
+    if stored_markdown_path(path).exists() {
+        return Err("A file or folder already exists at the original path".to_string());
+    }
+    // Encrypted files were trashed as their `.md.enc` sibling
+    restore_trashed_item(path).or_else(|error| {
+        if is_encrypted_markdown_path(path) {
+            restore_trashed_item(&encrypted_sibling(path)).map_err(|_| error)
+        } else {
+            Err(error)
+        }
+    })?;
+
+    sync_action_table_after_change(path);
+    record_file_change(path);
+    log::info!("Restored from trash: {}", original_path);
+    Ok(())
+}
+
 /// ```typescript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
@@ -1203,13 +1779,15 @@ pub fn copy_file(source_path: String, dest_path: String) -> Result<String, Strin
 
     let source = Path::new(&source_path);
     let dest = Path::new(&dest_path);
+    ensure_space_writable(dest)?;
 
     // Validate source file exists
-    if !source.exists() {
+    let stored_source = stored_markdown_path(source);
+    if !stored_source.exists() {
         return Err("Source file does not exist".to_string());
     }
 
-    if !source.is_file() {
+    if !stored_source.is_file() {
         return Err("Source path is not a file".to_string());
     }
 
@@ -1225,6 +1803,12 @@ pub fn copy_file(source_path: String, dest_path: String) -> Result<String, Strin
         }
     }
 
+    if let Some(bytes) = transfer_encrypted_markdown(source, dest, false)? {
+        log::info!("Copied file through folder encryption: {}", dest_path);
+        sync_action_table_after_change(dest);
+        return Ok(format!("File copied successfully ({} bytes)", bytes));
+    }
+
     let mut source_file = fs::File::open(source).map_err(|e| {
         log::error!("Failed to open source file {}: {}", source_path, e);
         format!("Failed to open source file: {}", e)
@@ -1265,6 +1849,7 @@ pub fn copy_file(source_path: String, dest_path: String) -> Result<String, Strin
         dest_path,
         copy_result
     );
+    sync_action_table_after_change(dest);
     Ok(format!("File copied successfully ({} bytes)", copy_result))
 }
 
@@ -1293,18 +1878,21 @@ pub fn copy_file(source_path: String, dest_path: String) -> Result<String, Strin
 /// });
 /// ```
 #[tauri::command]
-pub fn move_file(source_path: String, dest_path: String) -> Result<String, String> {
+pub fn move_file(app: AppHandle, source_path: String, dest_path: String) -> Result<String, String> {
     log::info!("Moving file from {} to {}", source_path, dest_path);
 
     let source = Path::new(&source_path);
     let dest = Path::new(&dest_path);
+    ensure_space_writable(source)?;
+    ensure_space_writable(dest)?;
 
     // Validate source file exists
-    if !source.exists() {
+    let stored_source = stored_markdown_path(source);
+    if !stored_source.exists() {
         return Err("Source file does not exist".to_string());
     }
 
-    if !source.is_file() {
+    if !stored_source.is_file() {
         return Err("Source path is not a file".to_string());
     }
 
@@ -1320,10 +1908,21 @@ pub fn move_file(source_path: String, dest_path: String) -> Result<String, Strin
         }
     }
 
+    if transfer_encrypted_markdown(source, dest, true)?.is_some() {
+        log::info!("Moved file through folder encryption: {}", dest_path);
+        sync_action_table_after_change(source);
+        sync_action_table_after_change(dest);
+        emit_remap(&app, &[PathRemap::new(source, dest)]);
+        return Ok("File moved successfully".to_string());
+    }
+
     // Perform the move
     match rename_path(source, dest) {
         Ok(()) => {
             log::info!("Successfully moved file to: {}", dest_path);
+            sync_action_table_after_change(source);
+            sync_action_table_after_change(dest);
+            emit_remap(&app, &[PathRemap::new(source, dest)]);
             Ok("File moved successfully".to_string())
         }
         Err(e) => {
@@ -1386,6 +1985,9 @@ pub fn move_file(source_path: String, dest_path: String) -> Result<String, Strin
                 }
 
                 log::info!("Successfully moved file to: {}", dest_path);
+                sync_action_table_after_change(source);
+                sync_action_table_after_change(dest);
+                emit_remap(&app, &[PathRemap::new(source, dest)]);
                 return Ok("File moved successfully".to_string());
             }
 
@@ -1440,67 +2042,55 @@ pub fn replace_in_file(
     // Validate file path
     let path = Path::new(&file_path);
 
-    if !path.exists() {
+    let stored_path = stored_markdown_path(path);
+    if !stored_path.exists() {
         return Err(format!("File does not exist: {}", file_path));
     }
 
-    if !path.is_file() {
+    if !stored_path.is_file() {
         return Err(format!("Path is not a file: {}", file_path));
     }
 
-    // Read the file content
-    let content = match fs::read_to_string(path) {
-        Ok(content) => content,
-        Err(e) => return Err(format!("Failed to read file: {}", e)),
-    };
+    ensure_file_editable(path)?;
+    ensure_os_writable(&stored_path, "modify")?;
 
-    if search_term.is_empty() {
-        return Err("search term cannot be empty".to_string());
-    }
-
-    // Perform replacement
-    let treat_as_regex = is_regex.unwrap_or(false);
-    let (new_content, match_count) = if treat_as_regex {
-        let regex =
-            regex::Regex::new(&search_term).map_err(|e| format!("Invalid regex pattern: {}", e))?;
-        let match_count = regex.find_iter(&content).count();
-        (
-            regex
-                .replace_all(&content, replace_term.as_str())
-                .to_string(),
-            match_count,
-        )
-    } else {
-        let match_count = content.matches(&search_term).count();
-        (content.replace(&search_term, &replace_term), match_count)
-    };
+    let match_count = with_file_lock(path, || {
+        // Read the file content, decrypting files in encrypted folders
+        let content = read_file(file_path.clone())?;
 
-    if match_count == 0 {
-        return Err(format!(
-            "No matches found in {}",
-            path.file_name().unwrap_or_default().to_string_lossy()
-        ));
-    }
+        if search_term.is_empty() {
+            return Err("search term cannot be empty".to_string());
+        }
 
-    log::info!("Replacing {} matches in file: {}", match_count, file_path);
+        // Perform replacement
+        let treat_as_regex = is_regex.unwrap_or(false);
+        let (new_content, match_count) = if treat_as_regex {
+            let regex = regex::Regex::new(&search_term)
+                .map_err(|e| format!("Invalid regex pattern: {}", e))?;
+            let match_count = regex.find_iter(&content).count();
+            (
+                regex
+                    .replace_all(&content, replace_term.as_str())
+                    .to_string(),
+                match_count,
+            )
+        } else {
+            let match_count = content.matches(&search_term).count();
+            (content.replace(&search_term, &replace_term), match_count)
+        };
+
+        if match_count == 0 {
+            return Err(format!(
+                "No matches found in {}",
+                path.file_name().unwrap_or_default().to_string_lossy()
+            ));
+        }
 
-    let temp_dir = path.parent().unwrap_or_else(|| Path::new("."));
-    let mut temp_file = NamedTempFile::new_in(temp_dir)
-        .map_err(|e| format!("Failed to create temporary file for replace: {}", e))?;
-    temp_file
-        .write_all(new_content.as_bytes())
-        .map_err(|e| format!("Failed to write temporary replacement file: {}", e))?;
-    temp_file
-        .flush()
-        .map_err(|e| format!("Failed to flush temporary replacement file: {}", e))?;
-    temp_file
-        .as_file()
-        .sync_all()
-        .map_err(|e| format!("Failed to sync temporary replacement file: {}", e))?;
-    temp_file
-        .persist(path)
-        .map_err(|e| format!("Failed to replace file atomically: {}", e.error))?;
+        log::info!("Replacing {} matches in file: {}", match_count, file_path);
 
+        save_file_contents(path, &new_content)?;
+        Ok(match_count)
+    })?;
     log::info!(
         "Successfully replaced {} occurrence(s) in {}",
         match_count,
@@ -1558,6 +2148,7 @@ pub fn check_directory_exists(path: String) -> Result<bool, String> {
 pub fn create_directory(path: String) -> Result<String, String> {
     log::info!("Creating directory: {}", path);
     let dir_path = Path::new(&path);
+    ensure_space_writable(dir_path)?;
 
     // Validate path doesn't contain dangerous parent traversal segments.
     if dir_path
@@ -1604,3 +2195,244 @@ pub fn check_file_exists(file_path: String) -> Result<bool, String> {
     log::info!("File exists: {} -> {}", file_path, exists);
     Ok(exists)
 }
+
+/// Set or clear the OS read-only flag on a file
+///
+/// On Unix this toggles the write bits (owner write is restored when making
+/// the file writable); elsewhere it flips the platform read-only attribute.
+///
+/// # Arguments
+///
+/// * `path` - File whose permissions should change
+/// * `writable` - Whether the file should be writable
+///
+/// # Returns
+///
+/// Success message or error details
+///
+/// # Example
+///
+/// ```typescript
+/// await invoke('set_file_writable', { path: '/path/to/file.md', writable: true });
+/// ```
+#[tauri::command]
+pub fn set_file_writable(path: String, writable: bool) -> Result<String, String> {
+    log::info!("Setting writable={} for file: {}", writable, path);
+    let file_path = Path::new(&path);
+
+    if !file_path.is_file() {
+        return Err(format!("File does not exist: {}", path));
+    }
+    ensure_space_writable(file_path)?;
+
+    let metadata = fs::metadata(file_path)
+        .map_err(|e| describe_io_error("read permissions of", file_path, &e))?;
+    let mut permissions = metadata.permissions();
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = permissions.mode();
+        permissions.set_mode(if writable {
+            mode | 0o200
+        } else {
+            mode & !0o222
+        });
+    }
+    #[cfg(not(unix))]
+    {
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(!writable);
+    }
+
+    fs::set_permissions(file_path, permissions)
+        .map_err(|e| describe_io_error("change permissions of", file_path, &e))?;
+
+    Ok(if writable {
+        "File is now writable".to_string()
+    } else {
+        "File is now read-only".to_string()
+    })
+}
+
+/// Check whether new files can be created inside a directory
+///
+/// Probes the directory by creating and removing a temporary file, which
+/// catches read-only mounts and ACL restrictions that permission bits alone
+/// do not reveal.
+///
+/// # Arguments
+///
+/// * `path` - Directory to probe
+///
+/// # Returns
+///
+/// True if a file could be created in the directory, false otherwise
+///
+/// # Example
+///
+/// ```typescript
+/// const canCreate = await invoke('check_directory_writable', { path: '/path/to/space/Projects' });
+/// ```
+#[tauri::command]
+pub fn check_directory_writable(path: String) -> Result<bool, String> {
+    let dir_path = Path::new(&path);
+    if !dir_path.is_dir() {
+        return Err(format!("Directory does not exist: {}", path));
+    }
+
+    match NamedTempFile::new_in(dir_path) {
+        Ok(_probe) => Ok(true),
+        Err(e)
+            if matches!(
+                e.kind(),
+                io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem
+            ) =>
+        {
+            Ok(false)
+        }
+        Err(e) => Err(describe_io_error("probe directory", dir_path, &e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn names(page: &MarkdownFilePage) -> Vec<&str> {
+        page.files.iter().map(|file| file.name.as_str()).collect()
+    }
+
+    #[test]
+    fn paged_listings_filter_sort_and_paginate() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        for (name, created, body) in [
+            ("Draft outline.md", "2026-03-03", "x"),
+            ("Draft copy.md", "2026-03-01", "xxxxxxxx"),
+            ("Review.md", "2026-03-02", "xxxx"),
+        ] {
+            write_test_file(
+                project.join(name),
+                &format!(
+                    "# {}\n{}\n[!datetime:created_date_time:{}]\n",
+                    name, body, created
+                ),
+            )?;
+        }
+        let project_path = project.to_string_lossy().to_string();
+
+        let page = list_project_actions_page(project_path.clone(), None)?;
+        assert_eq!(page.total_available, 3);
+        assert_eq!(
+            names(&page),
+            vec!["Draft copy.md", "Draft outline.md", "Review.md"]
+        );
+
+        let by_created = list_project_actions_page(
+            project_path.clone(),
+            Some(ListFilesOptions {
+                sort_by: Some(FileSortKey::Created),
+                descending: true,
+                ..ListFilesOptions::default()
+            }),
+        )?;
+        assert_eq!(
+            names(&by_created),
+            vec!["Draft outline.md", "Review.md", "Draft copy.md"]
+        );
+
+        let drafts_by_size = list_project_actions_page(
+            project_path.clone(),
+            Some(ListFilesOptions {
+                sort_by: Some(FileSortKey::Size),
+                name_contains: Some("DRAFT".to_string()),
+                offset: 1,
+                limit: Some(1),
+                ..ListFilesOptions::default()
+            }),
+        )?;
+        assert_eq!(drafts_by_size.total_available, 2);
+        assert_eq!(names(&drafts_by_size), vec!["Draft copy.md"]);
+        assert!(!drafts_by_size.has_more);
+
+        let recent = list_markdown_files_page(
+            workspace.path().to_string_lossy().to_string(),
+            Some(ListFilesOptions {
+                modified_after: Some("2999-01-01".to_string()),
+                ..ListFilesOptions::default()
+            }),
+        )?;
+        assert_eq!(recent.total_available, 0);
+        assert!(list_project_actions_page(
+            project_path,
+            Some(ListFilesOptions {
+                modified_after: Some("soon".to_string()),
+                ..ListFilesOptions::default()
+            }),
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_only_files_are_reported_and_can_be_made_writable() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let action = workspace.path().join("Projects/Alpha Project/Locked.md");
+        write_test_file(&action, "# Locked\n")?;
+        let action_path = action.to_string_lossy().to_string();
+        let is_writable = |path: &str| -> Result<bool, String> {
+            list_markdown_files(workspace.path().to_string_lossy().to_string())?
+                .into_iter()
+                .find(|file| file.path == path)
+                .map(|file| file.is_writable)
+                .ok_or_else(|| format!("{} not listed", path))
+        };
+        assert!(is_writable(&action_path)?);
+
+        set_file_writable(action_path.clone(), false)?;
+        assert!(!is_writable(&action_path)?);
+        let error = save_file(action_path.clone(), "# Changed\n".to_string())
+            .err()
+            .ok_or("saving a read-only file should fail")?;
+        assert!(error.starts_with("Permission denied: cannot save"));
+        assert_eq!(read_file(action_path.clone())?, "# Locked\n");
+
+        set_file_writable(action_path.clone(), true)?;
+        assert!(is_writable(&action_path)?);
+        save_file(action_path.clone(), "# Changed\n".to_string())?;
+        assert_eq!(read_file(action_path.clone())?, "# Changed\n");
+
+        assert!(set_file_writable(format!("{}.missing", action_path), true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn directory_writability_is_probed() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let projects = workspace.path().join("Projects");
+        assert!(check_directory_writable(
+            projects.to_string_lossy().to_string()
+        )?);
+        assert!(
+            check_directory_writable(projects.join("Missing").to_string_lossy().to_string())
+                .is_err()
+        );
+
+        let denied = describe_io_error(
+            "create file",
+            &projects,
+            &io::Error::from(io::ErrorKind::PermissionDenied),
+        );
+        assert!(denied.starts_with("Permission denied: cannot create file"));
+        let other = describe_io_error(
+            "create file",
+            &projects,
+            &io::Error::from(io::ErrorKind::NotFound),
+        );
+        assert!(other.starts_with("Failed to create file:"));
+        Ok(())
+    }
+}
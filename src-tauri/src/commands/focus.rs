@@ -0,0 +1,307 @@
+//! Today's focus actions, grouped by GTD context for time-blocking.
+//!
+//! An action is in today's focus when its `[!datetime:focus_date:...]` is
+//! today or earlier and it is neither completed nor cancelled.
+
+use chrono::{Local, NaiveDate};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::action_table::ACTION_DUE_DATE_REGEX;
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary};
+use super::fields::{get_field, split_multiselect, FieldKind};
+use super::project_velocity::ACTION_STATUS_REGEX;
+
+/// Block for actions without a context
+const ANYWHERE_CONTEXT: &str = "@anywhere";
+
+/// An action due for focus today
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusItem {
+    /// Action file stem
+    pub name: String,
+    /// Full path to the action file
+    pub path: String,
+    /// Folder name of the owning project
+    pub project_name: String,
+    pub status: String,
+    /// Focus date (YYYY-MM-DD)
+    pub focus_date: String,
+    pub due_date: Option<String>,
+    /// small, medium, large, extra-large, or a custom effort
+    pub effort: String,
+    /// Contexts without the leading `@`
+    pub contexts: Vec<String>,
+}
+
+/// Today's focus actions sharing one context
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContextBlock {
+    /// Context name with a leading `@`
+    pub context: String,
+    pub actions: Vec<FocusItem>,
+    /// Sum of effort points (small=1, medium=2, large=3, extra-large=5);
+    /// custom efforts score as the built-in effort they map to
+    pub total_effort_points: u32,
+}
+
+/// Points for a built-in effort; anything else scores as medium.
+pub(crate) fn effort_points(effort: &str) -> u32 {
+    match effort {
+        "small" => 1,
+        "large" => 3,
+        "extra-large" => 5,
+        _ => 2,
+    }
+}
+
+fn capture(regex: &Regex, content: &str) -> String {
+    regex
+        .captures(content)
+        .and_then(|captures| captures.get(1))
+        .map(|value| value.as_str().trim().to_string())
+        .unwrap_or_default()
+}
+
+fn focus_item(
+    path: &Path,
+    project_name: &str,
+    today: NaiveDate,
+    vocabulary: &FieldVocabulary,
+) -> Option<FocusItem> {
+    let content = fs::read_to_string(path).ok()?;
+
+    let status = capture(&ACTION_STATUS_REGEX, &content);
+    if matches!(
+        vocabulary
+            .builtin_status(&status)
+            .to_ascii_lowercase()
+            .as_str(),
+        "completed" | "complete" | "done" | "cancelled" | "canceled"
+    ) {
+        return None;
+    }
+
+    let raw_focus = get_field(&content, FieldKind::Datetime, "focus_date").unwrap_or_default();
+    let focus_date = NaiveDate::parse_from_str(raw_focus.get(..10)?, "%Y-%m-%d").ok()?;
+    if focus_date > today {
+        return None;
+    }
+
+    let due_date = capture(&ACTION_DUE_DATE_REGEX, &content);
+    let effort = get_field(&content, FieldKind::SingleSelect, "effort")
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let contexts = split_multiselect(
+        &get_field(&content, FieldKind::MultiSelect, "contexts").unwrap_or_default(),
+    )
+    .into_iter()
+    .map(|context| context.trim_start_matches('@').to_ascii_lowercase())
+    .filter(|context| !context.is_empty())
+    .collect();
+
+    Some(FocusItem {
+        name: path
+            .file_stem()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+        path: path.to_string_lossy().to_string(),
+        project_name: project_name.to_string(),
+        status,
+        focus_date: focus_date.format("%Y-%m-%d").to_string(),
+        due_date: (!due_date.is_empty()).then(|| due_date.chars().take(10).collect()),
+        effort: if effort.is_empty() {
+            "medium".to_string()
+        } else {
+            effort
+        },
+        contexts,
+    })
+}
+
+/// Collect today's focus actions from every project, ordered by focus date.
+pub(crate) fn collect_focus_items(space_root: &Path, today: NaiveDate) -> Vec<FocusItem> {
+    let Ok(projects) = fs::read_dir(space_root.join("Projects")) else {
+        return Vec::new();
+    };
+
+    let vocabulary = field_vocabulary_or_default(space_root);
+    let mut items = Vec::new();
+    for project in projects.flatten().map(|entry| entry.path()) {
+        let Ok(entries) = fs::read_dir(&project) else {
+            continue;
+        };
+        let project_name = project
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let is_markdown = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                });
+            let is_readme = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.eq_ignore_ascii_case("README"));
+            if path.is_file() && is_markdown && !is_readme {
+                items.extend(focus_item(&path, &project_name, today, &vocabulary));
+            }
+        }
+    }
+
+    items.sort_by(|a, b| {
+        a.focus_date
+            .cmp(&b.focus_date)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    items
+}
+
+fn group_by_context(items: Vec<FocusItem>, vocabulary: &FieldVocabulary) -> Vec<ContextBlock> {
+    let mut blocks: HashMap<String, ContextBlock> = HashMap::new();
+    for item in items {
+        let mut contexts: Vec<String> = item
+            .contexts
+            .iter()
+            .map(|context| format!("@{}", context))
+            .collect();
+        if contexts.is_empty() {
+            contexts.push(ANYWHERE_CONTEXT.to_string());
+        }
+
+        for context in contexts {
+            let block = blocks
+                .entry(context.clone())
+                .or_insert_with(|| ContextBlock {
+                    context,
+                    actions: Vec::new(),
+                    total_effort_points: 0,
+                });
+            block.total_effort_points += effort_points(vocabulary.builtin_effort(&item.effort));
+            block.actions.push(item.clone());
+        }
+    }
+
+    let mut blocks: Vec<ContextBlock> = blocks.into_values().collect();
+    blocks.sort_by(|a, b| {
+        b.total_effort_points
+            .cmp(&a.total_effort_points)
+            .then_with(|| a.context.cmp(&b.context))
+    });
+    blocks
+}
+
+/// Group today's focus actions by context for time-blocking
+///
+/// An action with several contexts appears in each of their blocks; actions
+/// without a context go into `@anywhere`.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+///
+/// # Returns
+///
+/// Context blocks sorted by total effort points (largest first), or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const blocks = await invoke<ContextBlock[]>('get_focus_blocks', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_focus_blocks(space_path: String) -> Result<Vec<ContextBlock>, String> {
+    log::info!("Building focus blocks for: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let items = collect_focus_items(space_root, Local::now().date_naive());
+    Ok(group_by_context(
+        items,
+        &field_vocabulary_or_default(space_root),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(focus: &str, effort: &str, contexts: &str, status: &str) -> String {
+        format!(
+            "# Action\n\n## Status\n[!singleselect:status:{}]\n\n## Focus Date\n[!datetime:focus_date:{}]\n\n## Effort\n[!singleselect:effort:{}]\n\n## Contexts\n[!multiselect:contexts:{}]\n",
+            status, focus, effort, contexts
+        )
+    }
+
+    #[test]
+    fn focus_blocks_group_by_context_and_sum_effort() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        write_test_file(
+            project.join("Call Bank.md"),
+            &action("2026-04-05T09:00", "small", "phone", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Write Draft.md"),
+            &action("2026-04-04", "extra-large", "computer", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Email Team.md"),
+            &action("2026-04-05", "medium", "computer,phone", "waiting"),
+        )?;
+        write_test_file(
+            project.join("Think.md"),
+            &action("2026-04-05", "large", "", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Tomorrow.md"),
+            &action("2026-04-06", "small", "phone", "in-progress"),
+        )?;
+        write_test_file(
+            project.join("Finished.md"),
+            &action("2026-04-05", "small", "phone", "completed"),
+        )?;
+
+        let today = NaiveDate::from_ymd_opt(2026, 4, 5).expect("valid date");
+        let blocks = group_by_context(
+            collect_focus_items(workspace.path(), today),
+            &FieldVocabulary::default(),
+        );
+
+        let summary: Vec<(&str, u32, usize)> = blocks
+            .iter()
+            .map(|block| {
+                (
+                    block.context.as_str(),
+                    block.total_effort_points,
+                    block.actions.len(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![("@computer", 7, 2), ("@anywhere", 3, 1), ("@phone", 3, 2)]
+        );
+        assert_eq!(blocks[0].actions[0].name, "Write Draft");
+        assert_eq!(blocks[0].actions[0].project_name, "Alpha Project");
+
+        Ok(())
+    }
+}
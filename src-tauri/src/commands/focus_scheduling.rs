@@ -0,0 +1,561 @@
+//! Scheduling focus time around meetings and other focus blocks.
+//!
+//! Busy time comes from the persisted Google Calendar cache and from actions
+//! whose `[!datetime:focus_date:...]` includes a time of day; those actions
+//! block as long as their effort (30 minutes for small up to 3 hours for
+//! extra-large, matching the calendar view). Everything is computed in local
+//! time. The cache works offline, so every result carries its age and is
+//! flagged as possibly stale once the last sync is too old.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary};
+use super::fields::{get_field, FieldKind};
+use super::project_velocity::ACTION_STATUS_REGEX;
+use crate::google_calendar::{load_google_calendar_cache, GoogleCalendarEvent};
+
+/// Cache older than this is flagged; auto-sync normally runs every 5 minutes
+const STALE_AFTER_MINUTES: i64 = 15;
+/// Length assumed for events without an end time
+const DEFAULT_EVENT_MINUTES: i64 = 30;
+/// Working hours used when the caller passes none
+const DEFAULT_WORK_START: &str = "09:00";
+const DEFAULT_WORK_END: &str = "17:00";
+/// Format of the local times returned to the frontend
+const LOCAL_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// Where a busy block comes from
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum BusySource {
+    CalendarEvent,
+    Action,
+}
+
+/// A meeting or focus block occupying time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BusyBlock {
+    pub source: BusySource,
+    /// Event summary or action file stem
+    pub title: String,
+    /// Local start time (`YYYY-MM-DDTHH:MM:SS`)
+    pub start: String,
+    /// Local end time (`YYYY-MM-DDTHH:MM:SS`)
+    pub end: String,
+    /// Google event id for calendar events
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_id: Option<String>,
+    /// Action file path for focus blocks
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+/// How current the calendar cache behind a result is
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarFreshness {
+    /// When the cache was last synced (RFC 3339), if it exists
+    pub cache_updated_at: Option<String>,
+    /// Minutes since the last sync
+    pub cache_age_minutes: Option<i64>,
+    /// True when there is no cache or it is older than 15 minutes
+    pub possibly_stale: bool,
+}
+
+/// Result of `check_focus_conflicts`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusConflictReport {
+    /// Events and focus blocks overlapping the proposed block, by start time
+    pub conflicts: Vec<BusyBlock>,
+    pub freshness: CalendarFreshness,
+}
+
+/// A free gap within working hours
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FocusSlot {
+    /// Local start time (`YYYY-MM-DDTHH:MM:SS`)
+    pub start: String,
+    /// Local end time (`YYYY-MM-DDTHH:MM:SS`)
+    pub end: String,
+    pub duration_minutes: i64,
+}
+
+/// Result of `suggest_focus_slots`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusSlotReport {
+    pub slots: Vec<FocusSlot>,
+    pub freshness: CalendarFreshness,
+}
+
+/// Working day bounds as `HH:MM`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkHours {
+    pub start: String,
+    pub end: String,
+}
+
+/// A busy block with parsed times for comparisons
+#[derive(Debug, Clone)]
+struct Busy {
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    block: BusyBlock,
+}
+
+fn format_local(datetime: NaiveDateTime) -> String {
+    datetime.format(LOCAL_TIME_FORMAT).to_string()
+}
+
+/// Parse a local datetime (`YYYY-MM-DDTHH:MM[:SS]`) or an RFC 3339 timestamp.
+/// Date-only values have no time of day and are rejected.
+fn parse_local_datetime(value: &str) -> Option<NaiveDateTime> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Local).naive_local());
+    }
+    [
+        "%Y-%m-%dT%H:%M:%S",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M:%S",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+}
+
+fn parse_time_of_day(value: &str, field: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M")
+        .map_err(|_| format!("Invalid {} '{}'. Expected HH:MM", field, value))
+}
+
+fn minutes(duration_minutes: u32) -> Result<Duration, String> {
+    if duration_minutes == 0 {
+        return Err("duration_minutes must be greater than 0".to_string());
+    }
+    Ok(Duration::minutes(i64::from(duration_minutes)))
+}
+
+/// Timed, non-cancelled events; all-day events don't block focus time.
+fn event_busy(event: &GoogleCalendarEvent) -> Option<Busy> {
+    if event.status.eq_ignore_ascii_case("cancelled") {
+        return None;
+    }
+    let start = parse_local_datetime(event.start.as_deref()?)?;
+    let end = event
+        .end
+        .as_deref()
+        .and_then(parse_local_datetime)
+        .filter(|end| *end > start)
+        .unwrap_or(start + Duration::minutes(DEFAULT_EVENT_MINUTES));
+
+    Some(Busy {
+        start,
+        end,
+        block: BusyBlock {
+            source: BusySource::CalendarEvent,
+            title: event.summary.clone(),
+            start: format_local(start),
+            end: format_local(end),
+            event_id: Some(event.id.clone()),
+            path: None,
+        },
+    })
+}
+
+fn effort_minutes(effort: &str) -> i64 {
+    match effort {
+        "medium" => 60,
+        "large" => 120,
+        "extra-large" => 180,
+        _ => 30,
+    }
+}
+
+fn action_busy(path: &Path, vocabulary: &FieldVocabulary) -> Option<Busy> {
+    let content = fs::read_to_string(path).ok()?;
+
+    let status = ACTION_STATUS_REGEX
+        .captures(&content)
+        .and_then(|captures| captures.get(1))
+        .map(|value| value.as_str().trim())
+        .unwrap_or_default();
+    if matches!(
+        vocabulary
+            .builtin_status(status)
+            .to_ascii_lowercase()
+            .as_str(),
+        "completed" | "complete" | "done" | "cancelled" | "canceled"
+    ) {
+        return None;
+    }
+
+    let start = parse_local_datetime(&get_field(&content, FieldKind::Datetime, "focus_date")?)?;
+    let effort = get_field(&content, FieldKind::SingleSelect, "effort")
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    let end = start + Duration::minutes(effort_minutes(vocabulary.builtin_effort(&effort)));
+
+    Some(Busy {
+        start,
+        end,
+        block: BusyBlock {
+            source: BusySource::Action,
+            title: path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            start: format_local(start),
+            end: format_local(end),
+            event_id: None,
+            path: Some(path.to_string_lossy().to_string()),
+        },
+    })
+}
+
+/// Focus blocks of every open action with a timed focus date.
+fn action_focus_blocks(space_root: &Path) -> Vec<Busy> {
+    let Ok(projects) = fs::read_dir(space_root.join("Projects")) else {
+        return Vec::new();
+    };
+
+    let vocabulary = field_vocabulary_or_default(space_root);
+    let mut blocks = Vec::new();
+    for project in projects.flatten().map(|entry| entry.path()) {
+        let Ok(entries) = fs::read_dir(&project) else {
+            continue;
+        };
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let is_markdown = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                });
+            let is_readme = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.eq_ignore_ascii_case("README"));
+            if path.is_file() && is_markdown && !is_readme {
+                blocks.extend(action_busy(&path, &vocabulary));
+            }
+        }
+    }
+    blocks
+}
+
+fn freshness(updated_at: Option<DateTime<Utc>>, now: DateTime<Utc>) -> CalendarFreshness {
+    let age = updated_at.map(|updated_at| (now - updated_at).num_minutes().max(0));
+    CalendarFreshness {
+        cache_updated_at: updated_at.map(|updated_at| updated_at.to_rfc3339()),
+        cache_age_minutes: age,
+        possibly_stale: age.is_none_or(|age| age > STALE_AFTER_MINUTES),
+    }
+}
+
+/// Calendar and action busy blocks for a space, with the cache's freshness.
+fn load_busy(
+    space_root: &Path,
+    exclude_path: Option<&str>,
+) -> Result<(Vec<Busy>, CalendarFreshness), String> {
+    let cache = load_google_calendar_cache()?;
+    let freshness = freshness(cache.as_ref().map(|cache| cache.last_updated), Utc::now());
+
+    let excluded = exclude_path.and_then(|path| fs::canonicalize(path).ok());
+    let mut busy: Vec<Busy> = cache
+        .map(|cache| cache.events)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(event_busy)
+        .collect();
+    busy.extend(action_focus_blocks(space_root).into_iter().filter(|block| {
+        let path = block
+            .block
+            .path
+            .as_deref()
+            .and_then(|path| fs::canonicalize(path).ok());
+        excluded.is_none() || path != excluded
+    }));
+    Ok((busy, freshness))
+}
+
+/// Busy blocks overlapping `[start, end)`, ordered by start time.
+fn overlapping(busy: Vec<Busy>, start: NaiveDateTime, end: NaiveDateTime) -> Vec<BusyBlock> {
+    let mut conflicts: Vec<Busy> = busy
+        .into_iter()
+        .filter(|block| block.start < end && start < block.end)
+        .collect();
+    conflicts.sort_by(|a, b| {
+        a.start
+            .cmp(&b.start)
+            .then_with(|| a.block.title.cmp(&b.block.title))
+    });
+    conflicts.into_iter().map(|block| block.block).collect()
+}
+
+/// Gaps of at least `duration` between busy blocks within `[day_start, day_end)`.
+fn free_slots(
+    busy: &[Busy],
+    day_start: NaiveDateTime,
+    day_end: NaiveDateTime,
+    duration: Duration,
+) -> Vec<FocusSlot> {
+    let mut blocks: Vec<(NaiveDateTime, NaiveDateTime)> = busy
+        .iter()
+        .filter(|block| block.start < day_end && day_start < block.end)
+        .map(|block| (block.start, block.end))
+        .collect();
+    blocks.sort();
+
+    let mut slots = Vec::new();
+    let mut cursor = day_start;
+    let mut push_gap = |from: NaiveDateTime, to: NaiveDateTime| {
+        if to - from >= duration {
+            slots.push(FocusSlot {
+                start: format_local(from),
+                end: format_local(to),
+                duration_minutes: (to - from).num_minutes(),
+            });
+        }
+    };
+    for (start, end) in blocks {
+        if start > cursor {
+            push_gap(cursor, start.min(day_end));
+        }
+        cursor = cursor.max(end);
+        if cursor >= day_end {
+            break;
+        }
+    }
+    if cursor < day_end {
+        push_gap(cursor, day_end);
+    }
+    slots
+}
+
+/// Check whether a proposed focus block collides with meetings or other focus blocks
+///
+/// Compares the block against timed events in the Google Calendar cache and
+/// against the focus blocks of open actions. All-day and cancelled events
+/// are ignored.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `datetime` - Proposed start, local (`2026-04-02T09:00`) or RFC 3339
+/// * `duration_minutes` - Length of the proposed block
+/// * `exclude_path` - Action being rescheduled, so its own block is ignored
+///
+/// # Returns
+///
+/// Overlapping events and focus blocks with the cache's age, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke<FocusConflictReport>('check_focus_conflicts', {
+///   spacePath: '/path/to/space',
+///   datetime: '2026-04-02T09:00',
+///   durationMinutes: 60,
+///   excludePath: '/path/to/space/Projects/Website/Write copy.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn check_focus_conflicts(
+    space_path: String,
+    datetime: String,
+    duration_minutes: u32,
+    exclude_path: Option<String>,
+) -> Result<FocusConflictReport, String> {
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    let start = parse_local_datetime(&datetime)
+        .ok_or_else(|| format!("Invalid datetime '{}'. Expected YYYY-MM-DDTHH:MM", datetime))?;
+    let end = start + minutes(duration_minutes)?;
+
+    let (busy, freshness) = load_busy(space_root, exclude_path.as_deref())?;
+    Ok(FocusConflictReport {
+        conflicts: overlapping(busy, start, end),
+        freshness,
+    })
+}
+
+/// Suggest free focus slots for a day
+///
+/// Returns every gap within working hours that is at least
+/// `duration_minutes` long and free of timed calendar events and other
+/// focus blocks.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `date` - Day to plan (YYYY-MM-DD)
+/// * `duration_minutes` - Minimum slot length
+/// * `work_hours` - Working day bounds; defaults to 09:00–17:00
+///
+/// # Returns
+///
+/// Free slots in time order with the cache's age, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke<FocusSlotReport>('suggest_focus_slots', {
+///   spacePath: '/path/to/space',
+///   date: '2026-04-02',
+///   durationMinutes: 90,
+///   workHours: { start: '08:30', end: '18:00' },
+/// });
+/// ```
+#[tauri::command]
+pub fn suggest_focus_slots(
+    space_path: String,
+    date: String,
+    duration_minutes: u32,
+    work_hours: Option<WorkHours>,
+) -> Result<FocusSlotReport, String> {
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    let day = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid date '{}'. Expected YYYY-MM-DD", date))?;
+    let duration = minutes(duration_minutes)?;
+    let work_hours = work_hours.unwrap_or_else(|| WorkHours {
+        start: DEFAULT_WORK_START.to_string(),
+        end: DEFAULT_WORK_END.to_string(),
+    });
+    let day_start = day.and_time(parse_time_of_day(&work_hours.start, "work_hours.start")?);
+    let day_end = day.and_time(parse_time_of_day(&work_hours.end, "work_hours.end")?);
+    if day_end <= day_start {
+        return Err("work_hours.end must be after work_hours.start".to_string());
+    }
+
+    let (busy, freshness) = load_busy(space_root, None)?;
+    Ok(FocusSlotReport {
+        slots: free_slots(&busy, day_start, day_end, duration),
+        freshness,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn at(value: &str) -> NaiveDateTime {
+        parse_local_datetime(value).expect("valid test datetime")
+    }
+
+    fn event(id: &str, start: &str, end: &str, status: &str) -> GoogleCalendarEvent {
+        GoogleCalendarEvent {
+            id: id.to_string(),
+            summary: format!("Meeting {}", id),
+            description: None,
+            start: Some(start.to_string()),
+            end: Some(end.to_string()),
+            location: None,
+            attendees: Vec::new(),
+            meeting_link: None,
+            status: status.to_string(),
+            color_id: None,
+            category: None,
+            is_recurring: false,
+            recurring_event_id: None,
+            recurrence: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn conflicts_and_free_slots_account_for_events_and_focus_blocks() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        write_test_file(
+            project.join("Write copy.md"),
+            "# Write copy\n\n[!singleselect:status:in-progress]\n[!datetime:focus_date:2026-04-02T13:00:00]\n[!singleselect:effort:large]\n",
+        )?;
+        write_test_file(
+            project.join("Shipped.md"),
+            "# Shipped\n\n[!singleselect:status:completed]\n[!datetime:focus_date:2026-04-02T09:00:00]\n",
+        )?;
+        write_test_file(
+            project.join("Someday.md"),
+            "# Someday\n\n[!singleselect:status:in-progress]\n[!datetime:focus_date:2026-04-02]\n",
+        )?;
+
+        let mut busy: Vec<Busy> = [
+            event(
+                "standup",
+                "2026-04-02T09:30:00",
+                "2026-04-02T10:00:00",
+                "confirmed",
+            ),
+            event("offsite", "2026-04-02", "2026-04-03", "confirmed"),
+            event(
+                "dropped",
+                "2026-04-02T10:00:00",
+                "2026-04-02T11:00:00",
+                "cancelled",
+            ),
+        ]
+        .iter()
+        .filter_map(|event| {
+            // Cached events carry an offset; mimic that for local times
+            let mut event = event.clone();
+            for time in [&mut event.start, &mut event.end].into_iter().flatten() {
+                if let Some(local) = parse_local_datetime(time)
+                    .and_then(|naive| naive.and_local_timezone(Local).single())
+                {
+                    *time = local.to_rfc3339();
+                }
+            }
+            event_busy(&event)
+        })
+        .collect();
+        busy.extend(action_focus_blocks(workspace.path()));
+        assert_eq!(busy.len(), 2);
+
+        let conflicts = overlapping(busy.clone(), at("2026-04-02T09:00"), at("2026-04-02T14:00"));
+        let titles: Vec<&str> = conflicts.iter().map(|block| block.title.as_str()).collect();
+        assert_eq!(titles, vec!["Meeting standup", "Write copy"]);
+        assert_eq!(conflicts[1].end, "2026-04-02T15:00:00");
+        assert!(
+            overlapping(busy.clone(), at("2026-04-02T10:00"), at("2026-04-02T11:00")).is_empty()
+        );
+
+        let slots = free_slots(
+            &busy,
+            at("2026-04-02T09:00"),
+            at("2026-04-02T17:00"),
+            Duration::minutes(60),
+        );
+        let bounds: Vec<(&str, &str)> = slots
+            .iter()
+            .map(|slot| (slot.start.as_str(), slot.end.as_str()))
+            .collect();
+        assert_eq!(
+            bounds,
+            vec![
+                ("2026-04-02T10:00:00", "2026-04-02T13:00:00"),
+                ("2026-04-02T15:00:00", "2026-04-02T17:00:00"),
+            ]
+        );
+
+        let now = Utc::now();
+        assert!(freshness(None, now).possibly_stale);
+        assert!(!freshness(Some(now - Duration::minutes(5)), now).possibly_stale);
+        let old = freshness(Some(now - Duration::minutes(90)), now);
+        assert!(old.possibly_stale);
+        assert_eq!(old.cache_age_minutes, Some(90));
+
+        Ok(())
+    }
+}
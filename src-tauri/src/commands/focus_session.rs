@@ -0,0 +1,592 @@
+//! Pomodoro-style focus sessions on an action.
+//!
+//! At most one session exists at a time; it lives in the
+//! `focus-session.json` store as start and pause timestamps, so the current
+//! phase and remaining time are always recomputed from the wall clock and
+//! survive window reloads and app restarts. While the session is running a
+//! background task emits `focus-tick` every second and `focus-phase-change`
+//! when work turns to break or back. The session ends after the last work
+//! phase; stopping it can log the work time to the action's `## Time Log`.
+
+use chrono::{DateTime, Local};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter};
+use tauri_plugin_store::StoreExt;
+
+use super::action_timers::{format_time_log_row, insert_time_log_row};
+use super::file_locks::with_file_lock;
+use super::filesystem::{read_file, save_file_contents};
+
+const FOCUS_STORE_FILE: &str = "focus-session.json";
+const FOCUS_SESSION_KEY: &str = "focus_session";
+const TICK_INTERVAL_SECS: u64 = 1;
+
+/// Event emitted every second while a session is running
+pub(crate) const FOCUS_TICK_EVENT: &str = "focus-tick";
+/// Event emitted when a session moves between phases
+pub(crate) const FOCUS_PHASE_CHANGE_EVENT: &str = "focus-phase-change";
+
+/// Phase of a focus session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FocusPhase {
+    Work,
+    Break,
+    /// Every work phase has elapsed
+    Complete,
+}
+
+/// The stored session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusSession {
+    pub action_path: String,
+    pub work_minutes: u32,
+    pub break_minutes: u32,
+    pub cycles: u32,
+    /// RFC 3339 start time
+    pub started_at: String,
+    /// RFC 3339 time the session was paused, while paused
+    pub paused_at: Option<String>,
+    /// Seconds spent paused before the current pause
+    pub paused_seconds: u64,
+}
+
+/// Where a session stands; also the `focus-tick` payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FocusSessionStatus {
+    pub action_path: String,
+    pub phase: FocusPhase,
+    /// Current cycle, 1-based
+    pub cycle: u32,
+    pub cycles: u32,
+    /// Seconds left in the current phase
+    pub remaining_seconds: u64,
+    /// Seconds of work completed so far
+    pub work_seconds: u64,
+    pub paused: bool,
+}
+
+/// Payload of [`FOCUS_PHASE_CHANGE_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusPhaseChange {
+    pub action_path: String,
+    pub from: FocusPhase,
+    pub to: FocusPhase,
+    /// Cycle the session is now in, 1-based
+    pub cycle: u32,
+}
+
+/// Result of stopping a session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoppedFocusSession {
+    pub action_path: String,
+    pub started_at: String,
+    pub ended_at: String,
+    /// Work time, excluding breaks and pauses
+    pub work_minutes: u64,
+    pub completed_cycles: u32,
+    /// Whether a Time Log row was written
+    pub logged: bool,
+}
+
+/// Why a focus session command was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum FocusSessionErrorKind {
+    /// Another session is already running; retry with `force: true` to
+    /// replace it
+    Conflict,
+}
+
+/// Structured error returned (JSON-encoded) when a session cannot start
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FocusSessionError {
+    pub kind: FocusSessionErrorKind,
+    /// Action of the session that is already running
+    pub action_path: String,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl FocusSessionError {
+    fn into_command_error(self) -> String {
+        serde_json::to_string(&self).unwrap_or(self.message)
+    }
+}
+
+static FOCUS_TICKER: Lazy<Mutex<Option<tauri::async_runtime::JoinHandle<()>>>> =
+    Lazy::new(|| Mutex::new(None));
+
+fn parse_time(value: &str) -> Result<DateTime<Local>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Local))
+        .map_err(|e| format!("Invalid focus session time '{}': {}", value, e))
+}
+
+/// Seconds the session has been running at `now`, excluding pauses.
+fn active_seconds(session: &FocusSession, now: DateTime<Local>) -> Result<u64, String> {
+    let end = match session.paused_at.as_deref() {
+        Some(paused_at) => parse_time(paused_at)?,
+        None => now,
+    };
+    let elapsed = (end - parse_time(&session.started_at)?)
+        .num_seconds()
+        .max(0) as u64;
+    Ok(elapsed.saturating_sub(session.paused_seconds))
+}
+
+/// Phase, cycle, and remaining time of `session` at `now`.
+fn focus_status(
+    session: &FocusSession,
+    now: DateTime<Local>,
+) -> Result<FocusSessionStatus, String> {
+    let active = active_seconds(session, now)?;
+    let work = u64::from(session.work_minutes) * 60;
+    let cycle_length = work + u64::from(session.break_minutes) * 60;
+    let cycles = u64::from(session.cycles.max(1));
+    // No break follows the last work phase.
+    let total = cycles * cycle_length - (cycle_length - work);
+
+    let (phase, cycle, remaining_seconds, work_seconds) = if active >= total {
+        (FocusPhase::Complete, cycles, 0, cycles * work)
+    } else {
+        let completed = active / cycle_length;
+        let offset = active % cycle_length;
+        let work_seconds = completed * work + offset.min(work);
+        if offset < work {
+            (FocusPhase::Work, completed + 1, work - offset, work_seconds)
+        } else {
+            (
+                FocusPhase::Break,
+                completed + 1,
+                cycle_length - offset,
+                work_seconds,
+            )
+        }
+    };
+
+    Ok(FocusSessionStatus {
+        action_path: session.action_path.clone(),
+        phase,
+        cycle: cycle as u32,
+        cycles: cycles as u32,
+        remaining_seconds,
+        work_seconds,
+        paused: session.paused_at.is_some(),
+    })
+}
+
+fn load_focus_session(app: &AppHandle) -> Result<Option<FocusSession>, String> {
+    let store = app
+        .store(FOCUS_STORE_FILE)
+        .map_err(|e| format!("Failed to open focus session store: {}", e))?;
+    Ok(store
+        .get(FOCUS_SESSION_KEY)
+        .and_then(|value| serde_json::from_value(value).ok()))
+}
+
+fn save_focus_session(app: &AppHandle, session: Option<&FocusSession>) -> Result<(), String> {
+    let store = app
+        .store(FOCUS_STORE_FILE)
+        .map_err(|e| format!("Failed to open focus session store: {}", e))?;
+    match session {
+        Some(session) => store.set(
+            FOCUS_SESSION_KEY,
+            serde_json::to_value(session).map_err(|e| e.to_string())?,
+        ),
+        None => {
+            store.delete(FOCUS_SESSION_KEY);
+        }
+    }
+    store
+        .save()
+        .map_err(|e| format!("Failed to save focus session store: {}", e))
+}
+
+/// Abort the tick task, if one is running. Returns whether one was.
+pub(crate) fn abort_focus_ticker() -> bool {
+    let Ok(mut ticker) = FOCUS_TICKER.lock() else {
+        return false;
+    };
+    match ticker.take() {
+        Some(handle) => {
+            handle.abort();
+            true
+        }
+        None => false,
+    }
+}
+
+/// Replace the tick task with one that follows the stored session until it
+/// is paused, stopped, or complete.
+fn spawn_focus_ticker(app: AppHandle) {
+    let Ok(mut ticker) = FOCUS_TICKER.lock() else {
+        return;
+    };
+    if let Some(previous) = ticker.take() {
+        previous.abort();
+    }
+    *ticker = Some(tauri::async_runtime::spawn(async move {
+        let mut last: Option<(FocusPhase, u32)> = None;
+        loop {
+            let status = match load_focus_session(&app) {
+                Ok(Some(session)) if session.paused_at.is_none() => {
+                    match focus_status(&session, Local::now()) {
+                        Ok(status) => status,
+                        Err(error) => {
+                            log::warn!("Stopping focus ticks: {}", error);
+                            break;
+                        }
+                    }
+                }
+                _ => break,
+            };
+
+            if let Some((phase, cycle)) = last {
+                if (phase, cycle) != (status.phase, status.cycle) {
+                    let change = FocusPhaseChange {
+                        action_path: status.action_path.clone(),
+                        from: phase,
+                        to: status.phase,
+                        cycle: status.cycle,
+                    };
+                    if let Err(e) = app.emit(FOCUS_PHASE_CHANGE_EVENT, &change) {
+                        log::error!("Failed to emit focus phase change: {}", e);
+                    }
+                }
+            }
+            last = Some((status.phase, status.cycle));
+            if let Err(e) = app.emit(FOCUS_TICK_EVENT, &status) {
+                log::error!("Failed to emit focus tick: {}", e);
+            }
+
+            if status.phase == FocusPhase::Complete {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_secs(TICK_INTERVAL_SECS)).await;
+        }
+    }));
+}
+
+/// Restart ticks for a session that was running when the app last exited.
+pub(crate) fn resume_focus_ticker(app: &AppHandle) {
+    match load_focus_session(app) {
+        Ok(Some(session)) if session.paused_at.is_none() => {
+            log::info!("Resuming focus session for {}", session.action_path);
+            spawn_focus_ticker(app.clone());
+        }
+        Ok(_) => {}
+        Err(error) => log::warn!("Failed to restore focus session: {}", error),
+    }
+}
+
+fn require_focus_session(app: &AppHandle) -> Result<FocusSession, String> {
+    load_focus_session(app)?.ok_or_else(|| "No focus session is running".to_string())
+}
+
+/// Start a focus session on an action
+///
+/// Alternates `work_minutes` of work with `break_minutes` of break for
+/// `cycles` work phases, emitting `focus-tick` every second and
+/// `focus-phase-change` between phases. Only one session runs at a time:
+/// starting while another exists fails with a JSON-encoded
+/// `FocusSessionError` of kind `conflict` unless `force` is set, which
+/// discards the old session without logging it.
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+/// * `work_minutes` - Length of each work phase
+/// * `break_minutes` - Length of each break; may be 0
+/// * `cycles` - Number of work phases
+/// * `force` - Replace an existing session
+///
+/// # Returns
+///
+/// The new session's status, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const status = await invoke<FocusSessionStatus>('start_focus_session', {
+///   actionPath: '/path/to/space/Projects/Website/Write copy.md',
+///   workMinutes: 25,
+///   breakMinutes: 5,
+///   cycles: 4,
+/// });
+/// ```
+#[tauri::command]
+pub fn start_focus_session(
+    app: AppHandle,
+    action_path: String,
+    work_minutes: u32,
+    break_minutes: u32,
+    cycles: u32,
+    force: Option<bool>,
+) -> Result<FocusSessionStatus, String> {
+    log::info!("Starting focus session for action: {}", action_path);
+
+    if !Path::new(&action_path).is_file() {
+        return Err(format!("Action file does not exist: {}", action_path));
+    }
+    if work_minutes == 0 || cycles == 0 {
+        return Err("Work minutes and cycles must be at least 1".to_string());
+    }
+    if let Some(existing) = load_focus_session(&app)? {
+        if !force.unwrap_or(false) {
+            return Err(FocusSessionError {
+                kind: FocusSessionErrorKind::Conflict,
+                message: format!(
+                    "A focus session is already running for {}",
+                    existing.action_path
+                ),
+                action_path: existing.action_path,
+            }
+            .into_command_error());
+        }
+        log::info!(
+            "Replacing focus session for {} without logging it",
+            existing.action_path
+        );
+    }
+
+    let now = Local::now();
+    let session = FocusSession {
+        action_path,
+        work_minutes,
+        break_minutes,
+        cycles,
+        started_at: now.to_rfc3339(),
+        paused_at: None,
+        paused_seconds: 0,
+    };
+    save_focus_session(&app, Some(&session))?;
+    spawn_focus_ticker(app);
+    focus_status(&session, now)
+}
+
+/// Get the focus session's current status, if any
+///
+/// Also restarts `focus-tick` events for a running session whose tick task
+/// is gone, so a reloaded window can call this to pick the session back up.
+///
+/// # Returns
+///
+/// The session status, or `null` when there is no session
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const status = await invoke<FocusSessionStatus | null>('get_focus_session');
+/// ```
+#[tauri::command]
+pub fn get_focus_session(app: AppHandle) -> Result<Option<FocusSessionStatus>, String> {
+    let Some(session) = load_focus_session(&app)? else {
+        return Ok(None);
+    };
+    let status = focus_status(&session, Local::now())?;
+    let ticking = FOCUS_TICKER
+        .lock()
+        .map(|ticker| ticker.is_some())
+        .unwrap_or(true);
+    if !status.paused && status.phase != FocusPhase::Complete && !ticking {
+        spawn_focus_ticker(app);
+    }
+    Ok(Some(status))
+}
+
+/// Pause the focus session
+///
+/// Time stops counting until `resume_focus_session`. Pausing a paused
+/// session returns its status unchanged.
+///
+/// # Returns
+///
+/// The paused session's status, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const status = await invoke<FocusSessionStatus>('pause_focus_session');
+/// ```
+#[tauri::command]
+pub fn pause_focus_session(app: AppHandle) -> Result<FocusSessionStatus, String> {
+    let mut session = require_focus_session(&app)?;
+    let now = Local::now();
+    if session.paused_at.is_none() {
+        abort_focus_ticker();
+        session.paused_at = Some(now.to_rfc3339());
+        save_focus_session(&app, Some(&session))?;
+    }
+    focus_status(&session, now)
+}
+
+/// Resume a paused focus session
+///
+/// # Returns
+///
+/// The running session's status, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const status = await invoke<FocusSessionStatus>('resume_focus_session');
+/// ```
+#[tauri::command]
+pub fn resume_focus_session(app: AppHandle) -> Result<FocusSessionStatus, String> {
+    let mut session = require_focus_session(&app)?;
+    let now = Local::now();
+    if let Some(paused_at) = session.paused_at.take() {
+        let paused = (now - parse_time(&paused_at)?).num_seconds().max(0) as u64;
+        session.paused_seconds += paused;
+        save_focus_session(&app, Some(&session))?;
+        spawn_focus_ticker(app);
+    }
+    focus_status(&session, now)
+}
+
+/// Stop the focus session
+///
+/// With `log_time`, appends the work time (breaks and pauses excluded) to
+/// the action's `## Time Log` table the way `stop_action_timer` does. If the
+/// action file was deleted meanwhile, nothing is written and `logged` is
+/// false.
+///
+/// # Arguments
+///
+/// * `log_time` - Write a Time Log row for the work time
+///
+/// # Returns
+///
+/// The stopped session with its work time, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const stopped = await invoke<StoppedFocusSession>('stop_focus_session', {
+///   logTime: true,
+/// });
+/// ```
+#[tauri::command]
+pub fn stop_focus_session(app: AppHandle, log_time: bool) -> Result<StoppedFocusSession, String> {
+    let session = require_focus_session(&app)?;
+    log::info!("Stopping focus session for action: {}", session.action_path);
+
+    let end = Local::now();
+    let status = focus_status(&session, end)?;
+    let minutes = status.work_seconds / 60;
+    let completed_cycles = match status.phase {
+        FocusPhase::Work => status.cycle - 1,
+        FocusPhase::Break | FocusPhase::Complete => status.cycle,
+    };
+
+    let path = Path::new(&session.action_path);
+    let logged = log_time && path.is_file();
+    if logged {
+        let note = format!(
+            "Focus session, {} of {} cycles",
+            completed_cycles, session.cycles
+        );
+        let row = format_time_log_row(parse_time(&session.started_at)?, end, minutes, &note);
+        with_file_lock(path, || {
+            let content = read_file(session.action_path.clone())?;
+            save_file_contents(path, &insert_time_log_row(&content, &row))
+        })?;
+    }
+    abort_focus_ticker();
+    save_focus_session(&app, None)?;
+
+    Ok(StoppedFocusSession {
+        action_path: session.action_path,
+        started_at: session.started_at,
+        ended_at: end.to_rfc3339(),
+        work_minutes: minutes,
+        completed_cycles,
+        logged,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn session(started_at: DateTime<Local>) -> FocusSession {
+        FocusSession {
+            action_path: "/space/Projects/Site/Write copy.md".to_string(),
+            work_minutes: 25,
+            break_minutes: 5,
+            cycles: 2,
+            started_at: started_at.to_rfc3339(),
+            paused_at: None,
+            paused_seconds: 0,
+        }
+    }
+
+    #[test]
+    fn status_is_recomputed_from_the_wall_clock() -> Result<(), String> {
+        let start = Local::now() - Duration::hours(3);
+        let at = |minutes: i64, seconds: i64| {
+            focus_status(
+                &session(start),
+                start + Duration::minutes(minutes) + Duration::seconds(seconds),
+            )
+            .map(|status| {
+                (
+                    status.phase,
+                    status.cycle,
+                    status.remaining_seconds,
+                    status.work_seconds,
+                )
+            })
+        };
+
+        assert_eq!(at(0, 0)?, (FocusPhase::Work, 1, 1500, 0));
+        assert_eq!(at(10, 30)?, (FocusPhase::Work, 1, 870, 630));
+        assert_eq!(at(25, 0)?, (FocusPhase::Break, 1, 300, 1500));
+        assert_eq!(at(30, 0)?, (FocusPhase::Work, 2, 1500, 1500));
+        assert_eq!(at(54, 59)?, (FocusPhase::Work, 2, 1, 2999));
+        assert_eq!(at(55, 0)?, (FocusPhase::Complete, 2, 0, 3000));
+        assert_eq!(at(90, 0)?, (FocusPhase::Complete, 2, 0, 3000));
+        Ok(())
+    }
+
+    #[test]
+    fn pauses_do_not_count_as_work() -> Result<(), String> {
+        let start = Local::now() - Duration::hours(1);
+        let mut paused = session(start);
+        paused.paused_seconds = 120;
+        paused.paused_at = Some((start + Duration::minutes(12)).to_rfc3339());
+
+        let status = focus_status(&paused, start + Duration::minutes(40))?;
+        assert!(status.paused);
+        assert_eq!(status.phase, FocusPhase::Work);
+        assert_eq!(status.work_seconds, 600);
+        assert_eq!(status.remaining_seconds, 900);
+
+        paused.paused_at = None;
+        paused.paused_seconds += 28 * 60;
+        let resumed = focus_status(&paused, start + Duration::minutes(40))?;
+        assert_eq!(
+            resumed,
+            FocusSessionStatus {
+                paused: false,
+                ..status
+            }
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,700 @@
+//! Encryption at rest for sensitive folders inside a space.
+//!
+//! Each encrypted folder has a random data key, wrapped with AES-GCM under a
+//! key derived from the user's passphrase (the same PBKDF2 derivation git sync
+//! uses) and stored in the space config. Only the wrapped key is ever written
+//! to disk; the unwrapped key lives in memory while the folder is unlocked.
+//!
+//! Markdown files are stored as `<name>.md.enc` siblings. `read_file`,
+//! `save_file`, listings, and search all work with the logical `.md` path.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use base64::{engine::general_purpose, Engine as _};
+use once_cell::sync::Lazy;
+use rand::RngExt;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Mutex;
+use tempfile::NamedTempFile;
+
+use super::key_derivation::derive_encryption_key;
+use super::space_config::{
+    content_is_locked, ensure_space_writable, find_configured_space_root, load_space_config,
+    locked_file_error, save_space_config,
+};
+
+/// Suffix appended to encrypted markdown files
+pub(crate) const ENCRYPTED_SUFFIX: &str = ".enc";
+const FILE_MAGIC_HEADER: &[u8; 8] = b"GTDFENC1";
+const NONCE_LEN: usize = 12;
+const SALT_LEN: usize = 16;
+
+/// Data keys for unlocked folders, keyed by absolute folder path
+static UNLOCKED_KEYS: Lazy<Mutex<HashMap<PathBuf, [u8; 32]>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// An encrypted folder as recorded in the space config
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct EncryptedFolder {
+    /// Folder path relative to the space root, using `/` separators
+    pub folder: String,
+    /// Base64 PBKDF2 salt for the passphrase key
+    pub salt: String,
+    /// Base64 AES-GCM nonce used to wrap the data key
+    pub nonce: String,
+    /// Base64 data key encrypted under the passphrase key
+    pub wrapped_key: String,
+}
+
+/// Reason an encrypted folder operation was refused
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FolderEncryptionErrorKind {
+    /// The passphrase does not unwrap the folder key
+    WrongPassphrase,
+    /// The folder is encrypted and no key is held in memory
+    Locked,
+}
+
+/// Structured error returned (JSON-encoded) for encrypted folder failures
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FolderEncryptionError {
+    pub kind: FolderEncryptionErrorKind,
+    /// Encrypted folder the operation targeted
+    pub folder: String,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl FolderEncryptionError {
+    fn into_command_error(self) -> String {
+        serde_json::to_string(&self).unwrap_or(self.message)
+    }
+}
+
+fn unlocked_keys() -> std::sync::MutexGuard<'static, HashMap<PathBuf, [u8; 32]>> {
+    UNLOCKED_KEYS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn decode_field(value: &str, field: &str) -> Result<Vec<u8>, String> {
+    general_purpose::STANDARD
+        .decode(value)
+        .map_err(|e| format!("Corrupt encrypted folder {}: {}", field, e))
+}
+
+fn relative_folder_string(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Encrypted folder containing `path`, with its absolute folder path.
+fn encrypted_folder_for(path: &Path) -> Option<(PathBuf, EncryptedFolder)> {
+    let space_root = find_configured_space_root(path)?;
+    let config = load_space_config(&space_root).ok()?;
+    let relative = path.strip_prefix(&space_root).ok()?;
+    config
+        .encrypted_folders
+        .into_iter()
+        .find(|entry| relative.starts_with(&entry.folder))
+        .map(|entry| (space_root.join(&entry.folder), entry))
+}
+
+fn folder_key(folder_path: &Path) -> Result<[u8; 32], String> {
+    unlocked_keys().get(folder_path).copied().ok_or_else(|| {
+        FolderEncryptionError {
+            kind: FolderEncryptionErrorKind::Locked,
+            folder: folder_path.to_string_lossy().to_string(),
+            message: "This folder is encrypted and locked".to_string(),
+        }
+        .into_command_error()
+    })
+}
+
+/// The logical markdown path for an encrypted `<name>.md.enc` file.
+pub(crate) fn encrypted_logical_path(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let logical = name.strip_suffix(ENCRYPTED_SUFFIX)?;
+    let lower = logical.to_ascii_lowercase();
+    (lower.ends_with(".md") || lower.ends_with(".markdown")).then(|| path.with_file_name(logical))
+}
+
+/// The `<name>.md.enc` file that stores a logical markdown path.
+pub(crate) fn encrypted_sibling(path: &Path) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(ENCRYPTED_SUFFIX);
+    path.with_file_name(name)
+}
+
+fn encrypt_content(key: &[u8; 32], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    rand::rng().fill(&mut nonce_bytes);
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| format!("Failed to initialize cipher: {}", e))?;
+    let ciphertext = cipher
+        .encrypt(Nonce::from_slice(&nonce_bytes), plaintext)
+        .map_err(|_| "Encryption failed".to_string())?;
+
+    let mut payload = Vec::with_capacity(FILE_MAGIC_HEADER.len() + NONCE_LEN + ciphertext.len());
+    payload.extend_from_slice(FILE_MAGIC_HEADER);
+    payload.extend_from_slice(&nonce_bytes);
+    payload.extend_from_slice(&ciphertext);
+    Ok(payload)
+}
+
+fn decrypt_content(key: &[u8; 32], payload: &[u8]) -> Result<String, String> {
+    let body = payload
+        .strip_prefix(FILE_MAGIC_HEADER.as_slice())
+        .ok_or_else(|| "Invalid encrypted file header".to_string())?;
+    if body.len() < NONCE_LEN {
+        return Err("Encrypted file is too short".to_string());
+    }
+    let (nonce_bytes, ciphertext) = body.split_at(NONCE_LEN);
+
+    let cipher = Aes256Gcm::new_from_slice(key)
+        .map_err(|e| format!("Failed to initialize cipher: {}", e))?;
+    let plaintext = cipher
+        .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+        .map_err(|_| "Decryption failed; the file may be corrupt".to_string())?;
+    String::from_utf8(plaintext).map_err(|_| "Decrypted file is not valid UTF-8".to_string())
+}
+
+fn write_bytes_atomically(path: &Path, bytes: &[u8]) -> Result<(), String> {
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+
+    let mut temp_file = NamedTempFile::new_in(parent)
+        .map_err(|e| format!("Failed to create temporary file: {}", e))?;
+    temp_file
+        .write_all(bytes)
+        .map_err(|e| format!("Failed to write encrypted file: {}", e))?;
+    temp_file
+        .as_file()
+        .sync_all()
+        .map_err(|e| format!("Failed to sync encrypted file: {}", e))?;
+    temp_file
+        .persist(path)
+        .map_err(|e| format!("Failed to persist encrypted file: {}", e.error))?;
+    Ok(())
+}
+
+/// Overwrite a plaintext file with zeros before deleting it.
+fn secure_remove(path: &Path) -> Result<(), String> {
+    let len = fs::metadata(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+        .len();
+    let mut file = OpenOptions::new()
+        .write(true)
+        .open(path)
+        .map_err(|e| format!("Failed to open {} for removal: {}", path.display(), e))?;
+    file.write_all(&vec![0u8; len as usize])
+        .and_then(|_| file.sync_all())
+        .map_err(|e| format!("Failed to overwrite {}: {}", path.display(), e))?;
+    drop(file);
+    fs::remove_file(path).map_err(|e| format!("Failed to remove {}: {}", path.display(), e))
+}
+
+fn is_plain_markdown(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+fn plain_markdown_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read directory: {}", e))?;
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let hidden = path
+            .file_name()
+            .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_symlink() || hidden {
+            continue;
+        }
+        if path.is_dir() {
+            plain_markdown_files(&path, files)?;
+        } else if is_plain_markdown(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Decrypt the encrypted sibling of a logical markdown path.
+///
+/// Returns `Ok(None)` when `path` is not under an encrypted folder or has no
+/// encrypted sibling, so callers fall back to a plain read.
+pub(crate) fn read_encrypted_markdown(path: &Path) -> Result<Option<String>, String> {
+    let Some((folder_path, _)) = encrypted_folder_for(path) else {
+        return Ok(None);
+    };
+    let encrypted_path = encrypted_sibling(path);
+    if !encrypted_path.is_file() {
+        return Ok(None);
+    }
+
+    let key = folder_key(&folder_path)?;
+    let payload = fs::read(&encrypted_path)
+        .map_err(|e| format!("Failed to read {}: {}", encrypted_path.display(), e))?;
+    decrypt_content(&key, &payload).map(Some)
+}
+
+/// Encrypt `content` for a logical markdown path under an encrypted folder.
+///
+/// Any plaintext copy is securely removed. Returns `Ok(false)` when `path` is
+/// not under an encrypted folder, so callers fall back to a plain write.
+pub(crate) fn save_encrypted_markdown(path: &Path, content: &str) -> Result<bool, String> {
+    let Some((folder_path, _)) = encrypted_folder_for(path) else {
+        return Ok(false);
+    };
+    if !is_plain_markdown(path) {
+        return Ok(false);
+    }
+    let key = folder_key(&folder_path)?;
+
+    let encrypted_path = encrypted_sibling(path);
+    if encrypted_path.is_file() {
+        let existing = fs::read(&encrypted_path)
+            .map_err(|e| format!("Failed to read {}: {}", encrypted_path.display(), e))?;
+        if decrypt_content(&key, &existing).is_ok_and(|existing| content_is_locked(&existing)) {
+            return Err(locked_file_error(path));
+        }
+    }
+
+    write_bytes_atomically(&encrypted_path, &encrypt_content(&key, content.as_bytes())?)?;
+    if path.is_file() {
+        secure_remove(path)?;
+    }
+    Ok(true)
+}
+
+/// Whether writes to `path` go through encryption: a markdown path under an
+/// encrypted folder.
+pub(crate) fn is_encrypted_markdown_path(path: &Path) -> bool {
+    is_plain_markdown(path) && encrypted_folder_for(path).is_some()
+}
+
+/// Where a logical markdown path is stored on disk: its `.md.enc` sibling
+/// when the path is encrypted, otherwise the path itself.
+///
+/// A plaintext copy that has not been re-encrypted yet wins, so it is never
+/// left behind by a rename or delete.
+pub(crate) fn stored_markdown_path(path: &Path) -> PathBuf {
+    if !path.exists() && is_encrypted_markdown_path(path) {
+        let encrypted_path = encrypted_sibling(path);
+        if encrypted_path.is_file() {
+            return encrypted_path;
+        }
+    }
+    path.to_path_buf()
+}
+
+/// Copy or move a markdown file when either side is encrypted, decrypting
+/// and re-encrypting through the folder keys.
+///
+/// Returns the number of content bytes written, or `Ok(None)` when neither
+/// path is encrypted so callers copy the file directly. A plaintext source is
+/// securely removed after a move.
+pub(crate) fn transfer_encrypted_markdown(
+    source: &Path,
+    dest: &Path,
+    remove_source: bool,
+) -> Result<Option<usize>, String> {
+    let stored_source = stored_markdown_path(source);
+    if stored_source == source && !is_encrypted_markdown_path(dest) {
+        return Ok(None);
+    }
+    if stored_markdown_path(dest).exists() {
+        return Err("Destination file already exists".to_string());
+    }
+
+    let content = match read_encrypted_markdown(source)? {
+        Some(content) => content,
+        None => fs::read_to_string(source)
+            .map_err(|e| format!("Failed to read {}: {}", source.display(), e))?,
+    };
+    if !save_encrypted_markdown(dest, &content)? {
+        write_bytes_atomically(dest, content.as_bytes())?;
+    }
+
+    if remove_source {
+        let removed = if stored_source == source {
+            secure_remove(source)
+        } else {
+            fs::remove_file(&stored_source)
+                .map_err(|e| format!("Failed to remove {}: {}", stored_source.display(), e))
+        };
+        if let Err(error) = removed {
+            let _ = fs::remove_file(stored_markdown_path(dest));
+            return Err(error);
+        }
+    }
+    Ok(Some(content.len()))
+}
+
+/// Markdown content for search, or `None` for files in locked folders.
+pub(crate) fn read_searchable_markdown(path: &Path) -> Option<std::io::Result<String>> {
+    let Some(logical) = encrypted_logical_path(path) else {
+        return Some(fs::read_to_string(path));
+    };
+    read_encrypted_markdown(&logical).ok().flatten().map(Ok)
+}
+
+fn resolve_folder(space_root: &Path, folder: &str) -> Result<(PathBuf, PathBuf), String> {
+    let folder_path = Path::new(folder.trim());
+    let relative = if folder_path.is_absolute() {
+        folder_path
+            .strip_prefix(space_root)
+            .map_err(|_| "Folder must be inside the space".to_string())?
+            .to_path_buf()
+    } else {
+        folder_path.to_path_buf()
+    };
+
+    let is_plain_relative = relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)));
+    if relative.as_os_str().is_empty() || !is_plain_relative {
+        return Err("Folder must be a subfolder of the space".to_string());
+    }
+
+    let absolute = space_root.join(&relative);
+    if !absolute.is_dir() {
+        return Err(format!("Folder does not exist: {}", absolute.display()));
+    }
+    Ok((absolute, relative))
+}
+
+/// Encrypt every markdown file in a folder
+///
+/// Generates a data key for the folder, stores it in the space config wrapped
+/// under the passphrase, then replaces each markdown file with an encrypted
+/// `.md.enc` sibling. The folder is left unlocked for the rest of the session.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `folder` - Folder to encrypt, relative to the space or absolute
+/// * `passphrase` - Passphrase protecting the folder key
+///
+/// # Returns
+///
+/// Number of files encrypted, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const count = await invoke<number>('enable_folder_encryption', {
+///   spacePath: '/path/to/space',
+///   folder: 'Cabinet/Journal',
+///   passphrase,
+/// });
+/// ```
+#[tauri::command]
+pub fn enable_folder_encryption(
+    space_path: String,
+    folder: String,
+    passphrase: String,
+) -> Result<u32, String> {
+    log::info!(
+        "Enabling encryption for folder {} in {}",
+        folder,
+        space_path
+    );
+
+    if passphrase.trim().is_empty() {
+        return Err("Passphrase cannot be empty".to_string());
+    }
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+    ensure_space_writable(space_root)?;
+
+    let (folder_path, relative) = resolve_folder(space_root, &folder)?;
+    let relative = relative_folder_string(&relative);
+    let mut config = load_space_config(space_root)?;
+    if let Some(existing) = config.encrypted_folders.iter().find(|entry| {
+        Path::new(&relative).starts_with(&entry.folder)
+            || Path::new(&entry.folder).starts_with(&relative)
+    }) {
+        return Err(format!(
+            "Folder overlaps encrypted folder '{}'",
+            existing.folder
+        ));
+    }
+
+    let mut data_key = [0u8; 32];
+    let mut salt = [0u8; SALT_LEN];
+    let mut nonce = [0u8; NONCE_LEN];
+    let mut rng = rand::rng();
+    rng.fill(&mut data_key);
+    rng.fill(&mut salt);
+    rng.fill(&mut nonce);
+
+    let passphrase_key = derive_encryption_key(&passphrase, &salt);
+    let wrapped_key = Aes256Gcm::new_from_slice(&passphrase_key)
+        .map_err(|e| format!("Failed to initialize cipher: {}", e))?
+        .encrypt(Nonce::from_slice(&nonce), data_key.as_slice())
+        .map_err(|_| "Failed to wrap folder key".to_string())?;
+
+    // Record the wrapped key before touching any file so an interrupted run
+    // never leaves encrypted files without a way to unlock them.
+    config.encrypted_folders.push(EncryptedFolder {
+        folder: relative,
+        salt: general_purpose::STANDARD.encode(salt),
+        nonce: general_purpose::STANDARD.encode(nonce),
+        wrapped_key: general_purpose::STANDARD.encode(wrapped_key),
+    });
+    save_space_config(space_root, &config)?;
+    unlocked_keys().insert(folder_path.clone(), data_key);
+
+    let mut files = Vec::new();
+    plain_markdown_files(&folder_path, &mut files)?;
+    for path in &files {
+        let content =
+            fs::read(path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        write_bytes_atomically(
+            &encrypted_sibling(path),
+            &encrypt_content(&data_key, &content)?,
+        )?;
+        secure_remove(path)?;
+    }
+
+    log::info!(
+        "Encrypted {} file(s) in {}",
+        files.len(),
+        folder_path.display()
+    );
+    Ok(files.len() as u32)
+}
+
+/// Unlock an encrypted folder for this session
+///
+/// # Arguments
+///
+/// * `folder` - Full path to the encrypted folder
+/// * `passphrase` - Passphrase given when encryption was enabled
+///
+/// # Returns
+///
+/// Ok on success; a JSON-encoded `FolderEncryptionError` with kind
+/// `wrong-passphrase` when the passphrase does not match
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('unlock_encrypted_folder', {
+///   folder: '/path/to/space/Cabinet/Journal',
+///   passphrase,
+/// });
+/// ```
+#[tauri::command]
+pub fn unlock_encrypted_folder(folder: String, passphrase: String) -> Result<(), String> {
+    log::info!("Unlocking encrypted folder: {}", folder);
+
+    let folder_path = Path::new(&folder);
+    let Some((encrypted_path, entry)) = encrypted_folder_for(folder_path) else {
+        return Err(format!("Folder is not encrypted: {}", folder));
+    };
+
+    let passphrase_key = derive_encryption_key(&passphrase, &decode_field(&entry.salt, "salt")?);
+    let nonce = decode_field(&entry.nonce, "nonce")?;
+    if nonce.len() != NONCE_LEN {
+        return Err("Corrupt encrypted folder nonce".to_string());
+    }
+    let wrapped_key = decode_field(&entry.wrapped_key, "key")?;
+
+    let data_key = Aes256Gcm::new_from_slice(&passphrase_key)
+        .map_err(|e| format!("Failed to initialize cipher: {}", e))?
+        .decrypt(Nonce::from_slice(&nonce), wrapped_key.as_slice())
+        .ok()
+        .and_then(|key| <[u8; 32]>::try_from(key.as_slice()).ok())
+        .ok_or_else(|| {
+            log::warn!("Wrong passphrase for encrypted folder {}", folder);
+            FolderEncryptionError {
+                kind: FolderEncryptionErrorKind::WrongPassphrase,
+                folder: encrypted_path.to_string_lossy().to_string(),
+                message: "The passphrase is incorrect".to_string(),
+            }
+            .into_command_error()
+        })?;
+
+    unlocked_keys().insert(encrypted_path, data_key);
+    Ok(())
+}
+
+/// Forget the in-memory key for an encrypted folder
+///
+/// # Arguments
+///
+/// * `folder` - Full path to the encrypted folder
+///
+/// # Returns
+///
+/// Ok on success, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('lock_encrypted_folder', {
+///   folder: '/path/to/space/Cabinet/Journal',
+/// });
+/// ```
+#[tauri::command]
+pub fn lock_encrypted_folder(folder: String) -> Result<(), String> {
+    log::info!("Locking encrypted folder: {}", folder);
+
+    let Some((encrypted_path, _)) = encrypted_folder_for(Path::new(&folder)) else {
+        return Err(format!("Folder is not encrypted: {}", folder));
+    };
+    unlocked_keys().remove(&encrypted_path);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::fields::{set_file_field, FieldKind};
+    use crate::commands::filesystem::{
+        create_file, delete_file, list_markdown_files, read_file, rename_markdown_file,
+        replace_in_file, save_file,
+    };
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn error_kind(error: &str) -> Option<FolderEncryptionErrorKind> {
+        serde_json::from_str::<FolderEncryptionError>(error)
+            .ok()
+            .map(|error| error.kind)
+    }
+
+    #[test]
+    fn encrypted_folder_round_trips_through_read_and_save() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let journal = root.join("Cabinet/Journal");
+        let entry = journal.join("Day One.md");
+        write_test_file(&entry, "# Day One\n\nPrivate thoughts\n")?;
+
+        let count = enable_folder_encryption(
+            root.to_string_lossy().to_string(),
+            "Cabinet/Journal".to_string(),
+            "correct horse".to_string(),
+        )?;
+        assert_eq!(count, 1);
+        assert!(!entry.exists());
+        let raw = fs::read(encrypted_sibling(&entry)).map_err(|e| e.to_string())?;
+        assert!(!String::from_utf8_lossy(&raw).contains("Private thoughts"));
+
+        let entry_path = entry.to_string_lossy().to_string();
+        let listed = list_markdown_files(journal.to_string_lossy().to_string())?;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].name, "Day One.md");
+        assert_eq!(listed[0].path, entry_path);
+        assert!(read_file(entry_path.clone())?.contains("Private thoughts"));
+
+        save_file(entry_path.clone(), "# Day One\n\nEdited\n".to_string())?;
+        assert!(!entry.exists());
+        let folder = journal.to_string_lossy().to_string();
+        lock_encrypted_folder(folder.clone())?;
+
+        let locked = read_file(entry_path.clone()).expect_err("locked folder should not read");
+        assert_eq!(error_kind(&locked), Some(FolderEncryptionErrorKind::Locked));
+        assert!(read_searchable_markdown(&encrypted_sibling(&entry)).is_none());
+
+        let wrong = unlock_encrypted_folder(folder.clone(), "wrong".to_string())
+            .expect_err("wrong passphrase should fail");
+        assert_eq!(
+            error_kind(&wrong),
+            Some(FolderEncryptionErrorKind::WrongPassphrase)
+        );
+
+        unlock_encrypted_folder(folder, "correct horse".to_string())?;
+        assert_eq!(read_file(entry_path)?, "# Day One\n\nEdited\n");
+
+        let config =
+            fs::read_to_string(root.join(".gtdspace/space.json")).map_err(|e| e.to_string())?;
+        assert!(config.contains("wrapped_key"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn file_operations_in_encrypted_folders_keep_files_encrypted() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let journal = root.join("Cabinet/Journal");
+        fs::create_dir_all(&journal).map_err(|e| e.to_string())?;
+        enable_folder_encryption(
+            root.to_string_lossy().to_string(),
+            "Cabinet/Journal".to_string(),
+            "correct horse".to_string(),
+        )?;
+
+        let created = create_file(journal.to_string_lossy().to_string(), "Entry".to_string())?;
+        assert!(created.success);
+        let entry = journal.join("Entry.md");
+        let entry_path = entry.to_string_lossy().to_string();
+        assert_eq!(created.path.as_deref(), Some(entry_path.as_str()));
+        assert!(!entry.exists());
+        assert!(encrypted_sibling(&entry).is_file());
+        let duplicate = create_file(journal.to_string_lossy().to_string(), "Entry".to_string())?;
+        assert!(!duplicate.success);
+
+        save_file(
+            entry_path.clone(),
+            "# Entry\n\nSecret plans\n[!singleselect:status:in-progress]\n".to_string(),
+        )?;
+        set_file_field(
+            entry_path.clone(),
+            FieldKind::SingleSelect,
+            "status".to_string(),
+            "completed".to_string(),
+        )?;
+        replace_in_file(
+            entry_path.clone(),
+            "Secret".to_string(),
+            "Hidden".to_string(),
+            None,
+        )?;
+        assert!(!entry.exists());
+        assert_eq!(
+            read_file(entry_path.clone())?,
+            "# Entry\n\nHidden plans\n[!singleselect:status:completed]\n"
+        );
+
+        let renamed = rename_markdown_file(&entry_path, "Renamed")?;
+        assert!(renamed.success);
+        let renamed_entry = journal.join("Renamed.md");
+        assert!(!encrypted_sibling(&entry).exists());
+        assert!(!renamed_entry.exists());
+        assert!(encrypted_sibling(&renamed_entry).is_file());
+        let renamed_path = renamed_entry.to_string_lossy().to_string();
+        assert!(read_file(renamed_path.clone())?.contains("Hidden plans"));
+
+        let deleted = delete_file(renamed_path, Some(true), Some(false))?;
+        assert!(deleted.success);
+        assert!(!encrypted_sibling(&renamed_entry).exists());
+        assert!(fs::read_dir(&journal)
+            .map_err(|e| e.to_string())?
+            .flatten()
+            .all(|entry| entry.path().extension().is_none_or(|ext| ext != "md")));
+
+        Ok(())
+    }
+}
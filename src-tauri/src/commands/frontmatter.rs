@@ -0,0 +1,269 @@
+//! YAML frontmatter migration into GTD field markers.
+//!
+//! Files imported from tools like Obsidian or Notion carry metadata in a YAML
+//! block at the top of the file. This converts mapped keys into the inline
+//! `[!type:field:value]` markers the editor understands.
+
+use serde::{Deserialize, Serialize};
+use serde_yaml::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::gtd_projects::write_string_atomically;
+use super::space_config::ensure_file_editable;
+
+/// A YAML key that was converted into a GTD field marker
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConvertedField {
+    /// Original YAML key
+    pub yaml_key: String,
+    /// Target GTD field (e.g. "singleselect:status")
+    pub gtd_field: String,
+    /// Marker written to the file
+    pub marker: String,
+}
+
+/// Result of normalizing a file's frontmatter
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NormalizeResult {
+    /// Whether the file had a frontmatter block and was rewritten
+    pub modified: bool,
+    /// Fields converted into GTD markers, in frontmatter order
+    pub converted_fields: Vec<ConvertedField>,
+    /// YAML keys with no entry in the field mapping
+    pub unmapped_keys: Vec<String>,
+}
+
+/// Split `content` into the raw YAML block and the remaining body.
+pub(crate) fn split_frontmatter(content: &str) -> Option<(&str, &str)> {
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+    let rest = content
+        .strip_prefix("---\r\n")
+        .or_else(|| content.strip_prefix("---\n"))?;
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        let trimmed = line.trim_end_matches(['\r', '\n']);
+        if trimmed == "---" || trimmed == "..." {
+            let yaml = &rest[..offset];
+            let body = &rest[offset + line.len()..];
+            return Some((yaml, body));
+        }
+        offset += line.len();
+    }
+
+    None
+}
+
+fn is_valid_gtd_field(field: &str) -> bool {
+    !field.is_empty()
+        && !field.starts_with(':')
+        && !field.ends_with(':')
+        && field
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == ':')
+}
+
+fn yaml_key_to_string(key: &Value) -> String {
+    match key {
+        Value::String(s) => s.clone(),
+        other => yaml_scalar_to_string(other),
+    }
+}
+
+fn yaml_scalar_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::String(s) => s.clone(),
+        Value::Sequence(items) => items
+            .iter()
+            .map(yaml_scalar_to_string)
+            .filter(|item| !item.is_empty())
+            .collect::<Vec<_>>()
+            .join(","),
+        Value::Tagged(tagged) => yaml_scalar_to_string(&tagged.value),
+        Value::Mapping(_) => serde_yaml::to_string(value)
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    }
+}
+
+/// Markers are single-line and bracket-delimited, so flatten values to fit.
+fn sanitize_marker_value(value: &str) -> String {
+    value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .replace(']', ")")
+}
+
+/// Insert marker lines after the first H1, or at the top when there is none.
+fn insert_markers(body: &str, markers: &[String]) -> String {
+    let body = body.trim_start_matches(['\r', '\n']);
+    if markers.is_empty() {
+        return body.to_string();
+    }
+    let block = markers.join("\n");
+
+    let mut offset = 0;
+    for line in body.split_inclusive('\n') {
+        if line.starts_with("# ") {
+            let (head, tail) = body.split_at(offset + line.len());
+            let head = head.trim_end_matches(['\r', '\n']);
+            let tail = tail.trim_start_matches(['\r', '\n']);
+            return if tail.is_empty() {
+                format!("{}\n\n{}\n", head, block)
+            } else {
+                format!("{}\n\n{}\n\n{}", head, block, tail)
+            };
+        }
+        offset += line.len();
+    }
+
+    if body.is_empty() {
+        format!("{}\n", block)
+    } else {
+        format!("{}\n\n{}", block, body)
+    }
+}
+
+/// Convert a frontmatter block into GTD markers, returning the new content.
+fn normalize_frontmatter_content(
+    content: &str,
+    field_mapping: &HashMap<String, String>,
+) -> Result<Option<(String, NormalizeResult)>, String> {
+    let Some((yaml, body)) = split_frontmatter(content) else {
+        return Ok(None);
+    };
+
+    let parsed: Value = if yaml.trim().is_empty() {
+        Value::Mapping(Default::default())
+    } else {
+        serde_yaml::from_str(yaml).map_err(|e| format!("Invalid YAML frontmatter: {}", e))?
+    };
+    let Value::Mapping(mapping) = parsed else {
+        return Err("YAML frontmatter must be a key/value mapping".to_string());
+    };
+
+    let mut result = NormalizeResult {
+        modified: true,
+        ..Default::default()
+    };
+    let mut markers = Vec::new();
+
+    for (key, value) in &mapping {
+        let yaml_key = yaml_key_to_string(key);
+        let Some(gtd_field) = field_mapping.get(&yaml_key) else {
+            result.unmapped_keys.push(yaml_key);
+            continue;
+        };
+
+        let marker = format!(
+            "[!{}:{}]",
+            gtd_field,
+            sanitize_marker_value(&yaml_scalar_to_string(value))
+        );
+        markers.push(marker.clone());
+        result.converted_fields.push(ConvertedField {
+            yaml_key,
+            gtd_field: gtd_field.clone(),
+            marker,
+        });
+    }
+
+    Ok(Some((insert_markers(body, &markers), result)))
+}
+
+/// Convert YAML frontmatter into GTD field markers
+///
+/// Parses the `---` delimited YAML block at the top of the file, writes each
+/// mapped key as a `[!...]` marker below the title, and removes the YAML block.
+/// Keys missing from `field_mapping` are dropped and reported back.
+///
+/// # Arguments
+///
+/// * `file_path` - Full path to the markdown file
+/// * `field_mapping` - YAML key to GTD field (e.g. `"due"` to `"datetime:due_date"`)
+///
+/// # Returns
+///
+/// `NormalizeResult` with converted fields and unmapped keys, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke<NormalizeResult>('normalize_markdown_frontmatter', {
+///   filePath: '/path/to/space/Cabinet/Imported.md',
+///   fieldMapping: { status: 'singleselect:status', due: 'datetime:due_date' },
+/// });
+/// ```
+#[tauri::command]
+pub fn normalize_markdown_frontmatter(
+    file_path: String,
+    field_mapping: HashMap<String, String>,
+) -> Result<NormalizeResult, String> {
+    log::info!("Normalizing frontmatter for file: {}", file_path);
+
+    if let Some((key, field)) = field_mapping
+        .iter()
+        .find(|(_, field)| !is_valid_gtd_field(field))
+    {
+        return Err(format!("Invalid GTD field '{}' for key '{}'", field, key));
+    }
+
+    let path = Path::new(&file_path);
+    if !path.is_file() {
+        return Err(format!("File does not exist: {}", file_path));
+    }
+
+    let content = fs::read_to_string(path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let Some((updated, result)) = normalize_frontmatter_content(&content, &field_mapping)? else {
+        return Ok(NormalizeResult::default());
+    };
+
+    ensure_file_editable(path)?;
+    write_string_atomically(path, &updated)?;
+
+    log::info!(
+        "Converted {} frontmatter fields ({} unmapped) in {}",
+        result.converted_fields.len(),
+        result.unmapped_keys.len(),
+        file_path
+    );
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_mapped_keys_and_reports_unmapped() {
+        let content = "---\nstatus: in-progress\ndue: 2025-03-01\ntags: [home, phone]\nauthor: Sam\n---\n# Imported\n\nBody text\n";
+        let mapping = HashMap::from([
+            ("status".to_string(), "singleselect:status".to_string()),
+            ("due".to_string(), "datetime:due_date".to_string()),
+            ("tags".to_string(), "multiselect:contexts".to_string()),
+        ]);
+
+        let (updated, result) = normalize_frontmatter_content(content, &mapping)
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            updated,
+            "# Imported\n\n[!singleselect:status:in-progress]\n[!datetime:due_date:2025-03-01]\n[!multiselect:contexts:home,phone]\n\nBody text\n"
+        );
+        assert_eq!(result.converted_fields.len(), 3);
+        assert_eq!(result.unmapped_keys, vec!["author".to_string()]);
+        assert!(normalize_frontmatter_content("# No YAML\n", &mapping)
+            .unwrap()
+            .is_none());
+    }
+}
@@ -8,7 +8,8 @@ use tokio::task;
 
 use super::git_sync::{
     build_git_sync_config, compute_git_status, perform_git_pull, perform_git_push,
-    preview_git_push, GitOperationResultPayload, GitSyncPreviewResponse, GitSyncStatusResponse,
+    perform_git_restore_file, preview_git_push, GitOperationResultPayload, GitSyncPreviewResponse,
+    GitSyncStatusResponse,
 };
 use super::settings::{load_settings, update_settings};
 
@@ -97,3 +98,24 @@ pub async fn git_sync_pull(
 
     Ok(outcome)
 }
+
+/// Restore one deleted file from the newest encrypted backup that contains it
+///
+/// `relative_file_path` is relative to the workspace root. Existing files are
+/// never overwritten. Returns the restored file's absolute path.
+#[tauri::command]
+pub async fn git_sync_restore_file(
+    app: AppHandle,
+    relative_file_path: String,
+) -> Result<String, String> {
+    let _guard = GIT_SYNC_METADATA_LOCK.lock().await;
+    let settings_snapshot = load_settings(app).await?;
+    let restored = task::spawn_blocking(move || {
+        let config = build_git_sync_config(&settings_snapshot, None)?;
+        perform_git_restore_file(config, &relative_file_path)
+    })
+    .await
+    .map_err(|e| format!("Git restore task failed: {}", e))??;
+
+    Ok(restored.to_string_lossy().to_string())
+}
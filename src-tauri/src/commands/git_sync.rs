@@ -1,4 +1,6 @@
+use super::key_derivation::derive_encryption_key;
 use super::settings::UserSettings;
+use super::space_walk::{walk_directory, WalkOptions};
 use aes_gcm::{
     aead::{
         stream::{DecryptorBE32, EncryptorBE32, StreamBE32},
@@ -10,29 +12,25 @@ use chrono::{DateTime, Utc};
 use flate2::{read::GzDecoder, write::GzEncoder, Compression};
 use log::{debug, info, warn};
 use mime_guess::MimeGuess;
-use pbkdf2::pbkdf2_hmac;
 use rand::RngExt;
 use serde::Serialize;
 use serde_json::json;
 use sha2::{Digest, Sha256};
-use sha2_010::Sha256 as Pbkdf2Sha256;
 use similar::{ChangeTag, TextDiff};
 use std::ffi::OsStr;
 use std::fmt;
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufReader, BufWriter, Read, Write};
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
 use std::process::Command;
 use std::time::SystemTime;
 use tar::{Archive, Builder as TarBuilder};
 use tempfile::Builder as TempDirBuilder;
-use walkdir::WalkDir;
 
 const LEGACY_MAGIC_HEADER: &[u8; 8] = b"GTDENC01";
 const STREAM_MAGIC_HEADER: &[u8; 8] = b"GTDENC02";
 const STREAM_NONCE_LEN: usize = 7;
 const LEGACY_NONCE_LEN: usize = 12;
-const PBKDF2_ITERATIONS: u32 = 600_000;
 const REMOTE_NAME: &str = "origin";
 const MIN_KEEP_HISTORY: usize = 1;
 const MAX_KEEP_HISTORY: usize = 20;
@@ -679,28 +677,21 @@ pub fn perform_git_push(
 fn build_workspace_manifest(root: &Path) -> Result<Vec<ManifestEntry>, String> {
     let mut entries = Vec::new();
 
-    for entry in WalkDir::new(root).into_iter() {
-        let entry = entry.map_err(|e| format!("Failed to walk workspace: {}", e))?;
-        let path = entry.path();
-        if path == root {
-            continue;
-        }
-
+    walk_directory(root, backup_walk_options(), |entry| {
+        let path = entry.path;
         let relative = path
             .strip_prefix(root)
             .map_err(|e| format!("Failed to determine relative path: {}", e))?;
 
         if should_skip_path(relative) {
-            continue;
+            return Ok(false);
         }
 
-        if !entry.file_type().is_file() {
-            continue;
+        if !entry.metadata.is_file() {
+            return Ok(true);
         }
 
-        let metadata = entry
-            .metadata()
-            .map_err(|e| format!("Failed to read metadata for {}: {}", path.display(), e))?;
+        let metadata = entry.metadata;
         let relative_path = relative.to_string_lossy().replace('\\', "/");
         let mime = guess_mime(&relative_path);
         let size = metadata.len();
@@ -726,7 +717,8 @@ fn build_workspace_manifest(root: &Path) -> Result<Vec<ManifestEntry>, String> {
             text,
             mime,
         });
-    }
+        Ok(true)
+    })?;
 
     entries.sort_by(|a, b| a.relative_path.cmp(&b.relative_path));
     Ok(entries)
@@ -1202,6 +1194,137 @@ pub fn perform_git_pull(
     })
 }
 
+/// Restore one deleted file from the newest committed backup that contains it.
+///
+/// Backups are read from `HEAD` with `git show`, newest first, so a file that
+/// was pruned from later snapshots is still found in an older one. Returns
+/// the restored file's path.
+pub fn perform_git_restore_file(
+    config: GitSyncConfig,
+    relative_file_path: &str,
+) -> Result<PathBuf, String> {
+    let relative = normalize_restore_path(relative_file_path)?;
+    let target = config.workspace_path.join(&relative);
+    if target.exists() {
+        return Err(format!(
+            "File already exists in the workspace: {}",
+            target.display()
+        ));
+    }
+    if !config.repo_path.join(".git").exists() {
+        return Err("No backups are available to restore".to_string());
+    }
+
+    let listing = run_git_command(
+        &config.repo_path,
+        ["ls-tree", "--name-only", "HEAD", "backups/"],
+    )?;
+    let mut backups: Vec<&str> = listing
+        .lines()
+        .filter_map(|line| line.strip_prefix("backups/"))
+        .filter(|name| name.ends_with(".enc"))
+        .collect();
+    backups.sort_by_key(|name| std::cmp::Reverse(parse_backup_filename_timestamp(name)));
+    if backups.is_empty() {
+        return Err("No backups are available to restore".to_string());
+    }
+
+    let temp_dir = TempDirBuilder::new()
+        .prefix("gtdspace-restore-file-")
+        .tempdir()
+        .map_err(|e| format!("Failed to prepare temporary decrypt directory: {}", e))?;
+    let encrypted_backup = temp_dir.path().join("backup.tar.gz.enc");
+    let decrypted_archive = temp_dir.path().join("workspace.tar.gz");
+    let extract_dir = temp_dir.path().join("restored");
+
+    for backup in backups {
+        let object = format!("HEAD:backups/{}", backup);
+        let blob = run_git_command_bytes(&config.repo_path, ["show", object.as_str()])?;
+        fs::write(&encrypted_backup, blob)
+            .map_err(|e| format!("Failed to write backup {}: {}", backup, e))?;
+        decrypt_file_to_path(
+            &config.encryption_key,
+            &encrypted_backup,
+            &decrypted_archive,
+        )?;
+
+        let Some(extracted) = extract_archive_entry(&decrypted_archive, &relative, &extract_dir)?
+        else {
+            debug!("{} is not in backup {}", relative.display(), backup);
+            continue;
+        };
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| format!("Failed to recreate {}: {}", parent.display(), e))?;
+        }
+        fs::copy(&extracted, &target)
+            .map_err(|e| format!("Failed to restore {}: {}", target.display(), e))?;
+        info!("Restored {} from backup {}", relative.display(), backup);
+        return Ok(target);
+    }
+
+    Err(format!("No backup contains {}", relative.display()))
+}
+
+/// Validate a workspace-relative path, rejecting anything that could escape
+/// the workspace or name sync metadata.
+fn normalize_restore_path(relative_file_path: &str) -> Result<PathBuf, String> {
+    let mut relative = PathBuf::new();
+    for component in Path::new(relative_file_path.trim()).components() {
+        match component {
+            Component::Normal(part) => relative.push(part),
+            Component::CurDir => {}
+            _ => {
+                return Err(format!(
+                    "Path must be relative to the workspace root: {}",
+                    relative_file_path
+                ))
+            }
+        }
+    }
+    if relative.as_os_str().is_empty() || should_skip_path(&relative) {
+        return Err(format!("Invalid file path: {}", relative_file_path));
+    }
+    Ok(relative)
+}
+
+/// Unpack the regular file stored at `relative` in a workspace archive into
+/// `output_dir`, returning where it was written, or `None` if it is absent.
+fn extract_archive_entry(
+    archive_path: &Path,
+    relative: &Path,
+    output_dir: &Path,
+) -> Result<Option<PathBuf>, String> {
+    let archive_file = File::open(archive_path).map_err(|e| {
+        format!(
+            "Failed to open decrypted archive {}: {}",
+            archive_path.display(),
+            e
+        )
+    })?;
+    let mut tar = Archive::new(GzDecoder::new(archive_file));
+    let entries = tar
+        .entries()
+        .map_err(|e| format!("Failed to read archive: {}", e))?;
+    for entry in entries {
+        let mut entry = entry.map_err(|e| format!("Failed to read archive entry: {}", e))?;
+        let is_match = entry.header().entry_type().is_file()
+            && entry.path().is_ok_and(|path| path.as_ref() == relative);
+        if !is_match {
+            continue;
+        }
+
+        fs::create_dir_all(output_dir)
+            .map_err(|e| format!("Failed to prepare restore directory: {}", e))?;
+        let output_path = output_dir.join("restored-file");
+        entry
+            .unpack(&output_path)
+            .map_err(|e| format!("Failed to unpack {}: {}", relative.display(), e))?;
+        return Ok(Some(output_path));
+    }
+    Ok(None)
+}
+
 fn ensure_repo(config: &GitSyncConfig) -> Result<(), String> {
     if config.repo_path.join(".git").exists() {
         return Ok(());
@@ -1269,37 +1392,32 @@ fn create_workspace_archive(workspace: &Path, output_path: &Path) -> Result<(),
     let encoder = GzEncoder::new(buf_writer, Compression::default());
     let mut builder = TarBuilder::new(encoder);
 
-    for entry in WalkDir::new(workspace).into_iter() {
-        let entry = entry.map_err(|e| format!("Failed to walk workspace: {}", e))?;
-        let path = entry.path();
-
-        if path == workspace {
-            continue;
-        }
-
+    walk_directory(workspace, backup_walk_options(), |entry| {
+        let path = entry.path;
         let relative = path
             .strip_prefix(workspace)
             .map_err(|e| format!("Failed to determine relative path: {}", e))?;
 
         if should_skip_path(relative) {
-            if entry.file_type().is_dir() {
+            if entry.metadata.is_dir() {
                 debug!("Skipping directory during archive: {}", relative.display());
             }
-            continue;
+            return Ok(false);
         }
 
-        if entry.file_type().is_dir() {
+        if entry.metadata.is_dir() {
             builder
                 .append_dir(relative, path)
                 .map_err(|e| format!("Failed to append directory {}: {}", relative.display(), e))?;
-        } else if entry.file_type().is_file() {
+        } else if entry.metadata.is_file() {
             let mut file = File::open(path)
                 .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
             builder
                 .append_file(relative, &mut file)
                 .map_err(|e| format!("Failed to append file {}: {}", relative.display(), e))?;
         }
-    }
+        Ok(true)
+    })?;
 
     let encoder = builder
         .into_inner()
@@ -1318,6 +1436,14 @@ fn create_workspace_archive(workspace: &Path, output_path: &Path) -> Result<(),
     Ok(())
 }
 
+/// Backups must not silently drop content, so unreadable entries fail the walk
+fn backup_walk_options() -> WalkOptions {
+    WalkOptions {
+        skip_unreadable: false,
+        ..WalkOptions::default()
+    }
+}
+
 fn should_skip_path(relative: &Path) -> bool {
     relative.components().any(|component| {
         if let Some(name) = component.as_os_str().to_str() {
@@ -1365,8 +1491,7 @@ fn encrypt_file_to_path(
         .write_all(&nonce_bytes)
         .map_err(|e| format!("Failed to write nonce: {}", e))?;
 
-    let mut key = [0u8; 32];
-    pbkdf2_hmac::<Pbkdf2Sha256>(passphrase.as_bytes(), &salt, PBKDF2_ITERATIONS, &mut key);
+    let key = derive_encryption_key(passphrase, &salt);
     let cipher = Aes256Gcm::new_from_slice(&key)
         .map_err(|e| format!("Failed to initialize cipher: {}", e))?;
 
@@ -1465,8 +1590,7 @@ fn decrypt_file_to_path(
         .read_exact(&mut salt)
         .map_err(|e| format!("Failed to read salt: {}", e))?;
 
-    let mut key = [0u8; 32];
-    pbkdf2_hmac::<Pbkdf2Sha256>(passphrase.as_bytes(), &salt, PBKDF2_ITERATIONS, &mut key);
+    let key = derive_encryption_key(passphrase, &salt);
 
     let mut writer = BufWriter::new(
         File::create(output_path)
@@ -1790,6 +1914,16 @@ fn should_force_batch_mode_ssh() -> bool {
 }
 
 fn run_git_command<I, S>(repo_path: &Path, args: I) -> Result<String, String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<OsStr>,
+{
+    let stdout = run_git_command_bytes(repo_path, args)?;
+    Ok(String::from_utf8_lossy(&stdout).trim().to_string())
+}
+
+/// Run git and return its raw stdout, for commands that print binary blobs
+fn run_git_command_bytes<I, S>(repo_path: &Path, args: I) -> Result<Vec<u8>, String>
 where
     I: IntoIterator<Item = S>,
     S: AsRef<OsStr>,
@@ -1811,7 +1945,7 @@ where
         .map_err(|e| format!("Failed to run git: {}", e))?;
 
     if output.status.success() {
-        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        Ok(output.stdout)
     } else {
         let stderr = String::from_utf8_lossy(&output.stderr);
         Err(format!("Git command failed: {}", stderr.trim()))
@@ -1849,6 +1983,9 @@ mod tests {
             auto_initialize: Some(true),
             seed_example_content: Some(true),
             default_space_path: None,
+            known_spaces: Vec::new(),
+            calendar_color_mapping: HashMap::new(),
+            last_active_timestamp: None,
             git_sync_enabled: Some(false),
             git_sync_repo_path: None,
             git_sync_workspace_path: None,
@@ -1864,6 +2001,7 @@ mod tests {
             mcp_server_workspace_path: None,
             mcp_server_read_only: Some(false),
             mcp_server_log_level: Some("info".to_string()),
+            spellcheck_language: None,
         }
     }
 
@@ -2024,6 +2162,74 @@ mod tests {
         assert_eq!(status.message.as_deref(), Some("Git sync is disabled"));
     }
 
+    #[test]
+    #[cfg_attr(not(git_available), ignore)]
+    fn compute_git_status_reports_pending_backups_and_remote() {
+        let dir = tempdir().expect("tempdir");
+        let workspace_path = dir.path().join("workspace");
+        let repo_path = dir.path().join("repo");
+        fs::create_dir_all(&workspace_path).expect("create workspace");
+        fs::create_dir_all(repo_path.join("backups")).expect("create backups dir");
+        run_git_command(&repo_path, ["init"]).expect("git init");
+
+        let mut settings = base_settings();
+        settings.git_sync_enabled = Some(true);
+        settings.git_sync_workspace_path = Some(workspace_path.to_string_lossy().to_string());
+        settings.git_sync_repo_path = Some(repo_path.to_string_lossy().to_string());
+        settings.git_sync_encryption_key = Some("legacy-local-key".to_string());
+
+        let status = compute_git_status(&settings, None);
+        assert!(status.configured);
+        assert!(status.encryption_configured);
+        assert!(!status.has_pending_commits);
+        assert!(!status.has_remote);
+        assert!(status.latest_backup_file.is_none());
+        assert!(status.message.is_none());
+
+        fs::write(
+            repo_path.join("backups/backup-20260325T143012456.tar.gz.enc"),
+            b"backup",
+        )
+        .expect("write backup");
+        run_git_command(
+            &repo_path,
+            [
+                "remote",
+                "add",
+                REMOTE_NAME,
+                "https://example.com/backups.git",
+            ],
+        )
+        .expect("git remote add");
+
+        let status = compute_git_status(&settings, None);
+        assert!(status.has_pending_commits);
+        assert!(status.has_remote);
+        assert_eq!(
+            status.latest_backup_file.as_deref(),
+            Some("backup-20260325T143012456.tar.gz.enc")
+        );
+
+        run_git_command(&repo_path, ["add", "backups"]).expect("git add");
+        run_git_command(
+            &repo_path,
+            [
+                "-c",
+                "user.name=GTD Space Tests",
+                "-c",
+                "user.email=tests@gtdspace.local",
+                "commit",
+                "-m",
+                "seed backup",
+            ],
+        )
+        .expect("git commit");
+
+        let status = compute_git_status(&settings, None);
+        assert!(!status.has_pending_commits);
+        assert!(status.message.is_none());
+    }
+
     #[test]
     fn perform_git_push_creates_encrypted_backup_and_commit() {
         let dir = tempdir().expect("tempdir");
@@ -2183,6 +2389,40 @@ mod tests {
         assert!(!workspace_path.join("scratch.md").exists());
     }
 
+    #[test]
+    fn perform_git_restore_file_recovers_from_newest_backup_containing_it() {
+        let dir = tempdir().expect("tempdir");
+        let workspace_path = dir.path().join("workspace");
+        let repo_path = dir.path().join("repo");
+        fs::create_dir_all(&workspace_path).expect("create workspace");
+        fs::create_dir_all(&repo_path).expect("create repo dir");
+
+        let note_relative = "Cabinet/Reference/Note.md";
+        write_workspace_file(&workspace_path, note_relative, "first");
+        let config = build_test_config(repo_path, workspace_path.clone(), 5);
+        perform_git_push(config.clone(), false).expect("first push");
+
+        write_workspace_file(&workspace_path, note_relative, "second");
+        perform_git_push(config.clone(), false).expect("second push");
+
+        fs::remove_dir_all(workspace_path.join("Cabinet")).expect("delete folder");
+        write_workspace_file(&workspace_path, "Projects/Alpha/README.md", "# Alpha");
+        perform_git_push(config.clone(), false).expect("push without note");
+
+        let restored =
+            perform_git_restore_file(config.clone(), note_relative).expect("restore deleted note");
+        assert_eq!(restored, workspace_path.join(note_relative));
+        assert_eq!(fs::read_to_string(&restored).expect("read note"), "second");
+
+        assert!(perform_git_restore_file(config.clone(), note_relative)
+            .unwrap_err()
+            .contains("already exists"));
+        assert!(perform_git_restore_file(config.clone(), "Missing.md")
+            .unwrap_err()
+            .contains("No backup contains"));
+        assert!(perform_git_restore_file(config, "../outside.md").is_err());
+    }
+
     #[test]
     fn perform_git_push_respects_keep_history_limit() {
         let dir = tempdir().expect("tempdir");
@@ -1,16 +1,26 @@
 //! Tauri commands that wrap the Google Calendar integration module.
 
+use crate::commands::calendar_actions::{remove_event_links, EventLinkCleanup};
+use crate::commands::gtd_projects::{
+    create_action, resolve_project_readme_path, validate_projects_child_directory,
+    CreateActionError,
+};
+use crate::commands::sections::get_section;
+use crate::google_calendar::cache::delete_google_calendar_cache;
+use crate::google_calendar::colors::{apply_event_categories, normalize_color_mapping};
 use crate::google_calendar::{
-    load_google_calendar_cache, GoogleCalendarEvent, GoogleCalendarManager, SyncStatus,
+    load_google_calendar_cache, CalendarColors, CalendarSyncResult, GoogleCalendarEvent,
+    GoogleCalendarManager, SyncStatus,
 };
+use chrono::{DateTime, Duration, Local, NaiveDate};
 use lazy_static::lazy_static;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tauri::AppHandle;
 use tokio::sync::Mutex as TokioMutex;
 
-#[cfg(test)]
-use std::path::Path;
-
 lazy_static! {
     static ref GOOGLE_CALENDAR_MANAGER: TokioMutex<Option<Arc<GoogleCalendarManager>>> =
         TokioMutex::new(None);
@@ -37,7 +47,9 @@ async fn get_or_init_google_calendar_manager(
             .await
             .map_err(|e| {
                 println!("[GoogleCalendar] Failed to create manager: {}", e);
-                format!("Failed to create Google Calendar manager: {}", e)
+                let message = format!("Failed to create Google Calendar manager: {}", e);
+                crate::commands::app::record_command_error("google_calendar", &message);
+                message
             })?,
     );
 
@@ -56,10 +68,18 @@ async fn get_google_calendar_manager_if_initialized() -> Option<Arc<GoogleCalend
     manager_guard.as_ref().cloned()
 }
 
+pub(crate) async fn is_google_calendar_manager_initialized() -> bool {
+    get_google_calendar_manager_if_initialized().await.is_some()
+}
+
 async fn clear_google_calendar_session_locked(app: AppHandle) -> Result<(), String> {
+    use crate::google_calendar::storage::TokenStorage;
     use crate::google_calendar::token_manager::TokenManager;
+    use crate::google_calendar::webhook;
 
     let token_cleanup_result = tokio::task::spawn_blocking(move || {
+        let channel_path = webhook::channel_path(&TokenStorage::new(app.clone()).get_token_path());
+        webhook::delete_channel(&channel_path)?;
         let token_manager = TokenManager::new(app).map_err(|e| e.to_string())?;
         token_manager.delete_tokens().map_err(|e| e.to_string())
     })
@@ -79,12 +99,109 @@ async fn clear_google_calendar_session(app: AppHandle) -> Result<(), String> {
     clear_google_calendar_session_locked(app).await
 }
 
+/// What a disconnect removed besides the stored tokens
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisconnectSummary {
+    pub message: String,
+    /// The persisted event cache was deleted, so no last sync is reported
+    pub cache_cleared: bool,
+    /// Files whose `[!gcal:event_id:...]` links were removed
+    pub links_cleared: Vec<EventLinkCleanup>,
+}
+
+/// Space to strip event links from, checked before anything is removed
+fn disconnect_links_root(
+    clear_links: Option<bool>,
+    space_path: Option<String>,
+) -> Result<Option<PathBuf>, String> {
+    if !clear_links.unwrap_or(false) {
+        return Ok(None);
+    }
+    let space_path = space_path
+        .filter(|path| !path.trim().is_empty())
+        .ok_or_else(|| "A space path is required to clear calendar links".to_string())?;
+    let root = PathBuf::from(space_path);
+    if !root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+    Ok(Some(root))
+}
+
+/// Clear the cache and event links requested alongside a disconnect.
+async fn clear_disconnect_data(
+    clear_cache: bool,
+    links_root: Option<PathBuf>,
+) -> Result<DisconnectSummary, String> {
+    tokio::task::spawn_blocking(move || {
+        let cache_cleared = clear_cache && delete_google_calendar_cache()?;
+        let links_cleared = match links_root {
+            Some(root) => remove_event_links(&root)?,
+            None => Vec::new(),
+        };
+        Ok(DisconnectSummary {
+            message: "Successfully disconnected from Google Calendar".to_string(),
+            cache_cleared,
+            links_cleared,
+        })
+    })
+    .await
+    .map_err(|e| format!("Failed to clear Google Calendar data: {}", e))?
+}
+
+/// The saved color-to-category mapping; empty when settings cannot be loaded.
+async fn load_calendar_color_mapping(app: AppHandle) -> HashMap<String, String> {
+    match crate::commands::settings::load_settings(app).await {
+        Ok(settings) => settings.calendar_color_mapping,
+        Err(error) => {
+            println!("[GoogleCalendar] Failed to load color mapping: {}", error);
+            HashMap::new()
+        }
+    }
+}
+
+async fn categorize_events(app: AppHandle, events: &mut [GoogleCalendarEvent]) {
+    let mapping = load_calendar_color_mapping(app).await;
+    apply_event_categories(events, &mapping);
+}
+
 fn read_cached_google_calendar_events_from_disk() -> Result<Vec<GoogleCalendarEvent>, String> {
     Ok(load_google_calendar_cache()?
         .map(|cache| cache.events)
         .unwrap_or_default())
 }
 
+/// Recurrence lines for each series, fetched from the live calendar.
+///
+/// Expanded occurrences don't carry their series' RRULEs, so they are read
+/// from the series event. Only runs when the calendar is already connected;
+/// series that can't be fetched are left out.
+pub(crate) async fn fetch_series_recurrence(series_ids: &[String]) -> HashMap<String, Vec<String>> {
+    let mut recurrence = HashMap::new();
+    let Some(manager) = get_google_calendar_manager_if_initialized().await else {
+        return recurrence;
+    };
+    for series_id in series_ids {
+        match manager
+            .get_event(series_id)
+            .await
+            .map_err(|e| e.to_string())
+        {
+            Ok(Some(event)) => {
+                recurrence.insert(series_id.clone(), event.recurrence);
+            }
+            Ok(None) => {}
+            Err(error) => {
+                log::warn!(
+                    "[GoogleCalendar] Failed to fetch recurring series {}: {}",
+                    series_id,
+                    error
+                );
+            }
+        }
+    }
+    recurrence
+}
+
 #[cfg(test)]
 fn read_cached_google_calendar_events_from_path(
     path: &Path,
@@ -279,7 +396,8 @@ pub async fn google_calendar_fetch_events(
     let events = manager
         .sync_events(None, None)
         .await
-        .map_err(|e| format!("Failed to fetch Google Calendar events: {}", e))?;
+        .map_err(|e| format!("Failed to fetch Google Calendar events: {}", e))?
+        .events;
 
     println!(
         "[GoogleCalendar] Successfully fetched {} events",
@@ -320,25 +438,61 @@ pub async fn google_calendar_connect(app: AppHandle) -> Result<String, String> {
 ///
 /// This is a synchronous wrapper to avoid async/AppHandle issues.
 /// Securely deletes the stored OAuth tokens, effectively logging the user out.
+/// By default only the tokens are removed; `clear_cache` also deletes the
+/// persisted events, and `clear_links` removes `[!gcal:event_id:...]` links
+/// from files in `space_path`.
 ///
 /// # Security
 ///
 /// Uses secure deletion to remove tokens from disk storage.
 ///
+/// # Arguments
+///
+/// * `clear_cache` - Also delete cached events and the last sync time
+/// * `clear_links` - Also remove event links from the space's files
+/// * `space_path` - Space to clear links in; required with `clear_links`
+///
 /// # Returns
 ///
-/// Success message or error if token deletion fails
+/// Summary of what was cleared, or error if token deletion fails
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const summary = await invoke<DisconnectSummary>('google_calendar_disconnect_simple', {
+///   clearCache: true,
+///   clearLinks: true,
+///   spacePath: '/path/to/space',
+/// });
+/// ```
 #[tauri::command]
-pub async fn google_calendar_disconnect_simple(app: AppHandle) -> Result<String, String> {
+pub async fn google_calendar_disconnect_simple(
+    app: AppHandle,
+    clear_cache: Option<bool>,
+    clear_links: Option<bool>,
+    space_path: Option<String>,
+) -> Result<DisconnectSummary, String> {
+    let links_root = disconnect_links_root(clear_links, space_path)?;
     println!("[GoogleCalendar] Disconnecting...");
     clear_google_calendar_session(app).await?;
 
     println!("[GoogleCalendar] Tokens deleted, disconnected successfully");
-    Ok("Successfully disconnected from Google Calendar".to_string())
+    clear_disconnect_data(clear_cache.unwrap_or(false), links_root).await
 }
 
+/// Disconnect from Google Calendar, revoking the token and stopping the
+/// webhook channel first. Takes the same clearing options as
+/// `google_calendar_disconnect_simple`.
 #[tauri::command]
-pub async fn google_calendar_disconnect(app: AppHandle) -> Result<String, String> {
+pub async fn google_calendar_disconnect(
+    app: AppHandle,
+    clear_cache: Option<bool>,
+    clear_links: Option<bool>,
+    space_path: Option<String>,
+) -> Result<DisconnectSummary, String> {
+    let links_root = disconnect_links_root(clear_links, space_path)?;
     let _lifecycle_guard = GOOGLE_CALENDAR_LIFECYCLE_LOCK.lock().await;
     let manager = {
         let manager_guard = GOOGLE_CALENDAR_MANAGER.lock().await;
@@ -346,6 +500,9 @@ pub async fn google_calendar_disconnect(app: AppHandle) -> Result<String, String
     };
 
     let disconnect_result = if let Some(manager) = manager {
+        if let Err(e) = manager.stop_webhook().await {
+            println!("[GoogleCalendar] Failed to stop webhook channel: {}", e);
+        }
         manager
             .disconnect()
             .await
@@ -357,45 +514,612 @@ pub async fn google_calendar_disconnect(app: AppHandle) -> Result<String, String
     clear_google_calendar_session_locked(app).await?;
     disconnect_result?;
 
-    Ok("Successfully disconnected from Google Calendar".to_string())
+    clear_disconnect_data(clear_cache.unwrap_or(false), links_root).await
 }
 
+/// Sync events from the primary Google Calendar.
+///
+/// Rate-limited and transient API failures are retried with backoff. After
+/// repeated hard failures, auto-sync requests (`auto: true`) are refused until
+/// a cooldown passes; manual syncs always run.
+///
+/// # Returns
+///
+/// The synced events and how many API retries were needed
 #[tauri::command]
-pub async fn google_calendar_sync(app: AppHandle) -> Result<Vec<GoogleCalendarEvent>, String> {
-    let manager = get_or_init_google_calendar_manager(app).await?;
+pub async fn google_calendar_sync(
+    app: AppHandle,
+    auto: Option<bool>,
+) -> Result<CalendarSyncResult, String> {
+    let manager = get_or_init_google_calendar_manager(app.clone()).await?;
+
+    if auto.unwrap_or(false) {
+        if let Some(until) = manager.auto_sync_paused_until().await {
+            return Err(format!(
+                "Auto-sync paused after repeated failures until {}",
+                until.to_rfc3339()
+            ));
+        }
+    }
 
-    let events = manager
+    let mut outcome = manager
         .sync_events(None, None)
         .await
         .map_err(|e| format!("Failed to sync Google Calendar events: {}", e))?;
+    categorize_events(app, &mut outcome.events).await;
 
-    Ok(events)
+    Ok(CalendarSyncResult {
+        events: outcome.events,
+        retries: outcome.retries,
+    })
+}
+
+/// Register for push notifications about primary calendar changes.
+///
+/// Google posts to `notification_url` whenever events change; the endpoint
+/// should forward the `X-Goog-Resource-ID` and `X-Goog-Channel-ID` headers to
+/// `google_calendar_handle_webhook_notification`. Any previously registered
+/// channel is stopped first. The channel is stored next to the OAuth tokens.
+///
+/// # Arguments
+///
+/// * `notification_url` - Public HTTPS endpoint that receives notifications
+///
+/// # Returns
+///
+/// The new channel id, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const channelId = await invoke<string>('google_calendar_register_webhook', {
+///   notificationUrl: 'https://hooks.example.com/gtdspace/calendar',
+/// });
+/// ```
+#[tauri::command]
+pub async fn google_calendar_register_webhook(
+    app: AppHandle,
+    notification_url: String,
+) -> Result<String, String> {
+    let notification_url = notification_url.trim();
+    if !notification_url.starts_with("https://") {
+        return Err("Notification URL must use HTTPS".to_string());
+    }
+
+    let manager = get_or_init_google_calendar_manager(app).await?;
+    let channel = manager
+        .register_webhook(notification_url)
+        .await
+        .map_err(|e| format!("Failed to register Google Calendar webhook: {}", e))?;
+
+    println!(
+        "[GoogleCalendar] Registered webhook channel {} for {}",
+        channel.id, notification_url
+    );
+    Ok(channel.id)
 }
 
+/// Re-sync after a push notification from Google Calendar.
+///
+/// Notifications for any channel other than the stored one are rejected.
+/// The re-sync runs as an auto-sync, so it honors the failure cooldown.
+///
+/// # Arguments
+///
+/// * `resource_id` - Value of the `X-Goog-Resource-ID` header
+/// * `channel_id` - Value of the `X-Goog-Channel-ID` header
+///
+/// # Returns
+///
+/// Ok once the re-sync finishes, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('google_calendar_handle_webhook_notification', {
+///   resourceId: headers['x-goog-resource-id'],
+///   channelId: headers['x-goog-channel-id'],
+/// });
+/// ```
+#[tauri::command]
+pub async fn google_calendar_handle_webhook_notification(
+    app: AppHandle,
+    resource_id: String,
+    channel_id: String,
+) -> Result<(), String> {
+    use crate::google_calendar::storage::TokenStorage;
+    use crate::google_calendar::webhook;
+
+    let channel_path = webhook::channel_path(&TokenStorage::new(app.clone()).get_token_path());
+    let stored = webhook::load_channel(&channel_path)?;
+    webhook::validate_notification(stored.as_ref(), &channel_id, &resource_id)?;
+
+    println!(
+        "[GoogleCalendar] Change notification on channel {}, re-syncing",
+        channel_id
+    );
+    google_calendar_sync(app, Some(true)).await?;
+    Ok(())
+}
+
+/// Whether OAuth client credentials exist, without loading them.
+fn google_oauth_credentials_configured(app: AppHandle) -> bool {
+    use crate::google_calendar::config_manager::GoogleConfigManager;
+
+    let stored = GoogleConfigManager::new(app)
+        .map(|manager| manager.has_config())
+        .unwrap_or(false);
+    let env_value_present = |key: &str| {
+        std::env::var(key)
+            .map(|value| !value.trim().is_empty())
+            .unwrap_or(false)
+    };
+
+    stored
+        || (env_value_present("GOOGLE_CALENDAR_CLIENT_ID")
+            && env_value_present("GOOGLE_CALENDAR_CLIENT_SECRET"))
+}
+
+/// Report Google Calendar connection state for the settings page.
+///
+/// Reads credentials, stored tokens, and the event cache from disk and only
+/// initializes the manager when credentials and usable tokens are both
+/// present. An unconfigured integration is reported, never returned as an error.
+///
+/// # Returns
+///
+/// `SyncStatus` describing credentials, tokens, and the last sync
 #[tauri::command]
 pub async fn google_calendar_get_status(app: AppHandle) -> Result<SyncStatus, String> {
-    let manager = get_or_init_google_calendar_manager(app).await?;
+    use crate::google_calendar::storage::{read_token_summary, TokenStorage};
 
-    let status = manager
-        .get_status()
-        .await
-        .map_err(|e| format!("Failed to get Google Calendar status: {}", e))?;
+    let credentials_configured = google_oauth_credentials_configured(app.clone());
+    let token_path = TokenStorage::new(app.clone()).get_token_path();
+    let (token_summary, token_error) = match read_token_summary(&token_path) {
+        Ok(summary) => (summary, None),
+        Err(error) => (None, Some(error)),
+    };
+    let token_expired = token_summary
+        .and_then(|summary| summary.expires_at)
+        .is_some_and(|expires_at| expires_at <= chrono::Utc::now());
+    let needs_reauth = token_error.is_some()
+        || token_summary.is_some_and(|summary| !summary.has_refresh_token && token_expired);
+    let cached_last_sync = load_google_calendar_cache()
+        .ok()
+        .flatten()
+        .map(|cache| cache.last_updated);
+
+    let mut status = SyncStatus {
+        is_connected: false,
+        last_sync: cached_last_sync,
+        sync_in_progress: false,
+        error: token_error,
+        credentials_configured,
+        needs_reauth,
+        token_expires_at: token_summary.and_then(|summary| summary.expires_at),
+    };
+
+    if !credentials_configured || token_summary.is_none() || needs_reauth {
+        return Ok(status);
+    }
+
+    match get_or_init_google_calendar_manager(app).await {
+        Ok(manager) => match manager.get_status().await {
+            Ok(live) => {
+                status.is_connected = live.is_connected;
+                status.sync_in_progress = live.sync_in_progress;
+                status.last_sync = live.last_sync.max(status.last_sync);
+                status.error = live.error;
+            }
+            Err(e) => {
+                status.error = Some(format!("Failed to get Google Calendar status: {}", e));
+            }
+        },
+        Err(error) => status.error = Some(error),
+    }
 
     Ok(status)
 }
 
 #[tauri::command]
 pub async fn google_calendar_get_cached_events(
-    _app: AppHandle,
+    app: AppHandle,
 ) -> Result<Vec<GoogleCalendarEvent>, String> {
-    if let Some(manager) = get_google_calendar_manager_if_initialized().await {
-        return manager
+    let mut events = match get_google_calendar_manager_if_initialized().await {
+        Some(manager) => manager
             .get_cached_events()
             .await
-            .map_err(|e| format!("Failed to get cached Google Calendar events: {}", e));
+            .map_err(|e| format!("Failed to get cached Google Calendar events: {}", e))?,
+        None => read_cached_google_calendar_events_from_disk()?,
+    };
+    categorize_events(app, &mut events).await;
+    Ok(events)
+}
+
+/// Fetch a single live event from the primary Google Calendar.
+///
+/// # Returns
+///
+/// The event, or `None` if it was deleted or never existed
+#[tauri::command]
+pub async fn google_calendar_get_event(
+    app: AppHandle,
+    event_id: String,
+) -> Result<Option<GoogleCalendarEvent>, String> {
+    let event_id = event_id.trim();
+    if event_id.is_empty() {
+        return Err("Event ID cannot be empty".to_string());
+    }
+
+    let manager = get_or_init_google_calendar_manager(app.clone()).await?;
+    let mut event = manager
+        .get_event(event_id)
+        .await
+        .map_err(|e| format!("Failed to get Google Calendar event: {}", e))?;
+    if let Some(event) = event.as_mut() {
+        categorize_events(app, std::slice::from_mut(event)).await;
+    }
+    Ok(event)
+}
+
+/// Fetch the user's Google Calendar color palette.
+///
+/// # Returns
+///
+/// Calendar and event colors keyed by color id, as hex background/foreground pairs
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const colors = await invoke<CalendarColors>('google_calendar_get_colors');
+/// const meetingHex = colors.event['11']?.background;
+/// ```
+#[tauri::command]
+pub async fn google_calendar_get_colors(app: AppHandle) -> Result<CalendarColors, String> {
+    let manager = get_or_init_google_calendar_manager(app).await?;
+    manager
+        .get_colors()
+        .await
+        .map_err(|e| format!("Failed to get Google Calendar colors: {}", e))
+}
+
+/// Save the mapping from Google Calendar color ids to GTD categories.
+///
+/// Synced and cached events report the mapped category; events with an
+/// unmapped or missing color fall back to `event`.
+///
+/// # Arguments
+///
+/// * `mapping` - Color id to category, e.g. `{ "11": "meeting", "9": "deep-work" }`
+///
+/// # Returns
+///
+/// The saved mapping with ids and categories trimmed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_calendar_color_mapping', {
+///   mapping: { '11': 'meeting', '9': 'deep-work' },
+/// });
+/// ```
+#[tauri::command]
+pub async fn set_calendar_color_mapping(
+    app: AppHandle,
+    mapping: HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    let mapping = normalize_color_mapping(mapping)?;
+    crate::commands::settings::update_settings(app, |settings| {
+        settings.calendar_color_mapping = mapping.clone();
+    })
+    .await?;
+    Ok(mapping)
+}
+
+fn extract_calendar_event_id(content: &str) -> Option<String> {
+    const MARKER: &str = "[!calendar-event-id:";
+    let start = content.find(MARKER)? + MARKER.len();
+    let end = content[start..].find(']')? + start;
+    let value = content[start..end].trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// Look up the calendar event an action was created from.
+///
+/// Reads the `[!calendar-event-id:...]` field from the action file and fetches
+/// the live event.
+///
+/// # Returns
+///
+/// The linked event, or `None` if the action is not linked to a calendar event
+#[tauri::command]
+pub async fn get_calendar_event_by_gtd_action(
+    app: AppHandle,
+    action_path: String,
+) -> Result<Option<GoogleCalendarEvent>, String> {
+    let content = tokio::fs::read_to_string(&action_path)
+        .await
+        .map_err(|e| format!("Failed to read action file: {}", e))?;
+
+    let Some(event_id) = extract_calendar_event_id(&content) else {
+        return Ok(None);
+    };
+
+    google_calendar_get_event(app, event_id).await
+}
+
+fn parse_import_date(value: &str, label: &str) -> Result<NaiveDate, String> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d")
+        .map_err(|_| format!("Invalid {} '{}': expected YYYY-MM-DD", label, value))
+}
+
+/// Events starting within `from..=to`, skipping cancelled events and, unless
+/// requested, all-day events (whose start has no time).
+fn events_to_import(
+    events: &[GoogleCalendarEvent],
+    from: NaiveDate,
+    to: NaiveDate,
+    include_all_day: bool,
+) -> Vec<&GoogleCalendarEvent> {
+    events
+        .iter()
+        .filter(|event| event.status != "cancelled")
+        .filter(|event| {
+            let Some(start) = event.start.as_deref() else {
+                return false;
+            };
+            let is_all_day = !start.contains('T');
+            let in_range = start
+                .get(..10)
+                .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+                .is_some_and(|date| from <= date && date <= to);
+            in_range && (include_all_day || !is_all_day)
+        })
+        .collect()
+}
+
+/// A calendar event that did not become an action
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkippedCalendarEvent {
+    pub event_id: String,
+    pub summary: String,
+    pub reason: String,
+}
+
+/// Outcome of `export_calendar_events_to_gtd_actions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarExportResult {
+    /// Full paths of the created actions
+    pub created: Vec<String>,
+    pub skipped: Vec<SkippedCalendarEvent>,
+}
+
+/// Create one action per calendar event in a date range.
+///
+/// Uses the cached events, syncing first when the cache is empty. Each action
+/// is named after the event summary, focused at the event start, and linked
+/// back through `[!calendar-event-id:...]`. Events whose action already
+/// exists in the project are skipped, and an event that fails is reported
+/// as skipped with the error while the rest are still created.
+///
+/// # Arguments
+///
+/// * `project_path` - Project directory to create the actions in
+/// * `from_date` - First day to import (YYYY-MM-DD, inclusive)
+/// * `to_date` - Last day to import (YYYY-MM-DD, inclusive)
+/// * `include_all_day` - Also import all-day events
+///
+/// # Returns
+///
+/// Paths of the created actions and the events that were skipped, or error
+/// details when the events cannot be loaded
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { created, skipped } = await invoke<CalendarExportResult>('export_calendar_events_to_gtd_actions', {
+///   projectPath: '/path/to/space/Projects/Team Sync',
+///   fromDate: '2026-03-02',
+///   toDate: '2026-03-08',
+///   includeAllDay: false,
+/// });
+/// ```
+#[tauri::command]
+pub async fn export_calendar_events_to_gtd_actions(
+    app: AppHandle,
+    project_path: String,
+    from_date: String,
+    to_date: String,
+    include_all_day: bool,
+) -> Result<CalendarExportResult, String> {
+    let from = parse_import_date(&from_date, "from_date")?;
+    let to = parse_import_date(&to_date, "to_date")?;
+    if from > to {
+        return Err("from_date must not be after to_date".to_string());
+    }
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+    validate_projects_child_directory(project_dir)?;
+
+    let mut events = google_calendar_get_cached_events(app.clone()).await?;
+    if events.is_empty() {
+        events = google_calendar_fetch_events(app).await?;
     }
 
-    read_cached_google_calendar_events_from_disk()
+    let mut result = CalendarExportResult {
+        created: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for event in events_to_import(&events, from, to, include_all_day) {
+        let name = match event.summary.trim() {
+            "" => "Untitled event",
+            summary => summary,
+        };
+        let reason = match create_action(
+            project_path.clone(),
+            name.to_string(),
+            "in-progress".to_string(),
+            None,
+            event.start.clone(),
+            "medium".to_string(),
+            None,
+            event.description.clone(),
+            Some(event.id.clone()),
+            None,
+        ) {
+            Ok(path) => {
+                result.created.push(path);
+                continue;
+            }
+            Err(CreateActionError::AlreadyExists(_)) => "Action already exists".to_string(),
+            Err(CreateActionError::Failed(error)) => {
+                log::warn!("Failed to create calendar action {}: {}", name, error);
+                error
+            }
+        };
+        result.skipped.push(SkippedCalendarEvent {
+            event_id: event.id.clone(),
+            summary: name.to_string(),
+            reason,
+        });
+    }
+
+    log::info!(
+        "Created {} actions from calendar events, skipped {}",
+        result.created.len(),
+        result.skipped.len()
+    );
+    Ok(result)
+}
+
+/// Lowercased email addresses listed in a README's Team and Stakeholders
+/// sections.
+fn project_attendees(readme: &str) -> HashSet<String> {
+    ["Team", "Stakeholders"]
+        .iter()
+        .filter_map(|title| get_section(readme, title))
+        .flat_map(|body| {
+            body.split(|ch: char| ch.is_whitespace() || matches!(ch, ',' | ';'))
+                .map(|token| {
+                    token
+                        .trim_matches(|ch: char| !ch.is_alphanumeric())
+                        .trim_start_matches("mailto:")
+                        .to_lowercase()
+                })
+                .filter(|token| {
+                    token
+                        .split_once('@')
+                        .is_some_and(|(user, domain)| !user.is_empty() && domain.contains('.'))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Local start day of an event; all-day events start on their date.
+fn event_start_date(event: &GoogleCalendarEvent) -> Option<NaiveDate> {
+    let start = event.start.as_deref()?;
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(start) {
+        return Some(datetime.with_timezone(&Local).date_naive());
+    }
+    NaiveDate::parse_from_str(start.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Events starting `today..=today + days_ahead` that share an attendee with
+/// the project or mention its name in the summary, earliest first.
+fn events_for_project(
+    events: Vec<GoogleCalendarEvent>,
+    project_name: &str,
+    attendees: &HashSet<String>,
+    today: NaiveDate,
+    days_ahead: u32,
+) -> Vec<GoogleCalendarEvent> {
+    let project_name = project_name.trim().to_lowercase();
+    let last_day = today + Duration::days(days_ahead as i64);
+
+    let mut matches: Vec<(NaiveDate, GoogleCalendarEvent)> = events
+        .into_iter()
+        .filter(|event| event.status != "cancelled")
+        .filter(|event| {
+            (!project_name.is_empty() && event.summary.to_lowercase().contains(&project_name))
+                || event
+                    .attendees
+                    .iter()
+                    .any(|attendee| attendees.contains(&attendee.trim().to_lowercase()))
+        })
+        .filter_map(|event| {
+            let date = event_start_date(&event)?;
+            (today <= date && date <= last_day).then_some((date, event))
+        })
+        .collect();
+    matches.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.start.cmp(&b.1.start)));
+    matches.into_iter().map(|(_, event)| event).collect()
+}
+
+/// List upcoming cached calendar events related to a project.
+///
+/// An event is related when one of its attendees is an email address listed
+/// under the README's `## Team` or `## Stakeholders` heading, or when its
+/// summary contains the project folder name (case-insensitive). Only the
+/// cached events are read; nothing is fetched from Google.
+///
+/// # Arguments
+///
+/// * `project_path` - Project directory
+/// * `days_ahead` - Days after today to include; 0 means today only
+///
+/// # Returns
+///
+/// Matching events starting today through `days_ahead` days from now,
+/// earliest first, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const events = await invoke<GoogleCalendarEvent[]>('get_calendar_events_for_project', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+///   daysAhead: 14,
+/// });
+/// ```
+#[tauri::command]
+pub async fn get_calendar_events_for_project(
+    app: AppHandle,
+    project_path: String,
+    days_ahead: u32,
+) -> Result<Vec<GoogleCalendarEvent>, String> {
+    let project_dir = Path::new(&project_path);
+    let readme_path = resolve_project_readme_path(project_dir)
+        .ok_or_else(|| "Project README not found".to_string())?;
+    let readme = tokio::fs::read_to_string(&readme_path)
+        .await
+        .map_err(|e| format!("Failed to read README: {}", e))?;
+    let project_name = project_dir
+        .file_name()
+        .and_then(|name| name.to_str())
+        .unwrap_or_default();
+
+    let events = google_calendar_get_cached_events(app).await?;
+    Ok(events_for_project(
+        events,
+        project_name,
+        &project_attendees(&readme),
+        Local::now().date_naive(),
+        days_ahead,
+    ))
 }
 
 // ===== GOOGLE CALENDAR OAUTH CONFIGURATION =====
@@ -533,10 +1257,13 @@ pub async fn google_oauth_has_config(app: AppHandle) -> Result<bool, String> {
 
 #[cfg(test)]
 mod tests {
-    use super::read_cached_google_calendar_events_from_path;
+    use super::{
+        events_for_project, events_to_import, extract_calendar_event_id, project_attendees,
+        read_cached_google_calendar_events_from_path,
+    };
     use crate::google_calendar::cache::CachedEvents;
     use crate::google_calendar::GoogleCalendarEvent;
-    use chrono::Utc;
+    use chrono::{NaiveDate, Utc};
     use std::fs;
 
     #[test]
@@ -555,6 +1282,10 @@ mod tests {
                 meeting_link: Some("https://meet.example.com/planning".to_string()),
                 status: "confirmed".to_string(),
                 color_id: Some("2".to_string()),
+                category: None,
+                is_recurring: false,
+                recurring_event_id: None,
+                recurrence: Vec::new(),
             }],
             last_updated: Utc::now(),
         };
@@ -564,4 +1295,112 @@ mod tests {
         assert_eq!(events.len(), 1);
         assert_eq!(events[0].summary, "Planning");
     }
+
+    #[test]
+    fn extract_calendar_event_id_reads_marker_value() {
+        let content = "# Prep\n\n## Calendar Event\n[!calendar-event-id: abc123 ]\n";
+        assert_eq!(
+            extract_calendar_event_id(content).as_deref(),
+            Some("abc123")
+        );
+        assert_eq!(extract_calendar_event_id("[!calendar-event-id:]"), None);
+        assert_eq!(extract_calendar_event_id("# No link"), None);
+    }
+
+    #[test]
+    fn events_to_import_filters_by_day_status_and_all_day() {
+        let event = |id: &str, start: &str, status: &str| GoogleCalendarEvent {
+            id: id.to_string(),
+            summary: id.to_string(),
+            description: None,
+            start: Some(start.to_string()),
+            end: None,
+            location: None,
+            attendees: Vec::new(),
+            meeting_link: None,
+            status: status.to_string(),
+            color_id: None,
+            category: None,
+            is_recurring: false,
+            recurring_event_id: None,
+            recurrence: Vec::new(),
+        };
+        let events = vec![
+            event("before", "2026-03-01T23:00:00-05:00", "confirmed"),
+            event("standup", "2026-03-02T09:00:00-05:00", "confirmed"),
+            event("offsite", "2026-03-04", "confirmed"),
+            event("cancelled", "2026-03-05T10:00:00Z", "cancelled"),
+            event("review", "2026-03-08T16:00:00Z", "tentative"),
+            event("after", "2026-03-09T08:00:00Z", "confirmed"),
+        ];
+        let from = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+
+        let ids = |include_all_day| -> Vec<String> {
+            events_to_import(&events, from, to, include_all_day)
+                .into_iter()
+                .map(|event| event.id.clone())
+                .collect()
+        };
+        assert_eq!(ids(false), vec!["standup", "review"]);
+        assert_eq!(ids(true), vec!["standup", "offsite", "review"]);
+    }
+
+    #[test]
+    fn project_events_match_team_attendees_or_project_name() {
+        let readme = "# Website\n\n## Team\n- Ana <Ana@Example.com>\n- bo@example.com, not-an-email\n\n### Stakeholders\n- cy@example.com\n\n## Notes\nping dee@example.com\n";
+        let attendees = project_attendees(readme);
+        let mut listed: Vec<&str> = attendees.iter().map(String::as_str).collect();
+        listed.sort();
+        assert_eq!(
+            listed,
+            vec!["ana@example.com", "bo@example.com", "cy@example.com"]
+        );
+
+        let event = |id: &str, summary: &str, start: &str, attendees: &[&str], status: &str| {
+            GoogleCalendarEvent {
+                id: id.to_string(),
+                summary: summary.to_string(),
+                description: None,
+                start: Some(start.to_string()),
+                end: None,
+                location: None,
+                attendees: attendees.iter().map(|a| a.to_string()).collect(),
+                meeting_link: None,
+                status: status.to_string(),
+                color_id: None,
+                category: None,
+                is_recurring: false,
+                recurring_event_id: None,
+                recurrence: Vec::new(),
+            }
+        };
+        let events = vec![
+            event(
+                "sync",
+                "Weekly sync",
+                "2026-03-04",
+                &["ANA@example.com"],
+                "confirmed",
+            ),
+            event("launch", "WEBSITE launch", "2026-03-03", &[], "confirmed"),
+            event(
+                "other",
+                "Dentist",
+                "2026-03-03",
+                &["dee@example.com"],
+                "confirmed",
+            ),
+            event("dropped", "Website retro", "2026-03-03", &[], "cancelled"),
+            event("past", "Website kickoff", "2026-03-01", &[], "confirmed"),
+            event("far", "Website review", "2026-03-20", &[], "confirmed"),
+        ];
+        let today = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+
+        let ids: Vec<String> = events_for_project(events, "Website", &attendees, today, 7)
+            .into_iter()
+            .map(|event| event.id)
+            .collect();
+        assert_eq!(ids, vec!["launch", "sync"]);
+    }
 }
@@ -1,18 +1,42 @@
 //! GTD habit commands.
 
+use super::action_table::sync_action_table_after_change;
+use super::file_locks::with_file_lock;
+use super::file_meta::record_file_change;
 use super::gtd_habits_domain::{
-    apply_status_marker, calculate_missed_periods, format_history_entry, insert_history_entry,
-    parse_habit_state, repair_habit_history_content, should_reset_habit, HabitFrequency,
-    HabitStatus, DEFAULT_HISTORY_TEMPLATE,
+    append_history_archive, apply_status_marker, calculate_missed_periods, completion_rate_since,
+    format_history_entry, habit_history_archive_path, insert_history_entry,
+    is_habit_history_archive, merge_habit_histories, next_reset_after, parse_archived_history_rows,
+    parse_habit_state, parse_history_rows, plan_history_prune, repair_habit_history_content,
+    should_reset_habit, tally_habit_history, HabitFrequency, HabitStatus, DEFAULT_HISTORY_TEMPLATE,
 };
-use super::utils::sanitize_markdown_file_stem;
-use chrono::{Local, NaiveTime};
-use serde::Deserialize;
+use super::gtd_projects::{update_datetime_field, validate_projects_child_directory};
+use super::gtd_relationships::{render_validated_references, CreatedWithReferences, ReferenceForm};
+use super::habit_scheduler::ran_recently;
+use super::sections::insert_before_created_footer;
+use super::seed_data::{generate_action_template, HabitTemplateBuilder};
+use super::space_config::{
+    content_is_locked, ensure_file_editable, ensure_space_writable, space_is_read_only,
+};
+use super::utils::{is_markdown_path, sanitize_markdown_file_stem};
+use chrono::{Duration, Local, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
 use std::fs::{self, OpenOptions};
 use std::io::{self, ErrorKind, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
 use tempfile::NamedTempFile;
 
+/// Event emitted after a habit file is rewritten by a status change
+pub(crate) const HABITS_UPDATED_EVENT: &str = "habits-updated";
+
+/// Payload of [`HABITS_UPDATED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitsUpdatedEvent {
+    /// Habit path as passed to the command
+    pub habit_path: String,
+}
+
 #[derive(Debug, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
 pub struct HabitReferenceInput {
@@ -55,6 +79,129 @@ fn normalize_habit_title(habit_name: &str) -> Result<String, String> {
     Ok(trimmed.to_string())
 }
 
+/// Outcome of pruning one habit's history
+#[derive(Debug, Clone, Serialize)]
+pub struct HabitPruneResult {
+    /// Full path to the habit file
+    pub habit_path: String,
+    /// Rows removed from the habit file
+    pub pruned_rows: usize,
+    /// Rows left in the habit file
+    pub kept_rows: usize,
+    /// Completed periods in the pruned span
+    pub completions: usize,
+    /// Date of the oldest pruned row
+    pub span_start: String,
+    /// Date of the newest pruned row
+    pub span_end: String,
+    /// Archive the rows were moved to, when archiving
+    pub archive_path: Option<String>,
+    /// False for dry runs
+    pub applied: bool,
+}
+
+/// Completion and streak counts for a habit
+#[derive(Debug, Clone, Serialize)]
+pub struct HabitStats {
+    /// Full path to the habit file
+    pub habit_path: String,
+    /// Completed periods across the whole history
+    pub completions: usize,
+    /// Consecutive completed periods up to now
+    pub current_streak: usize,
+    /// Longest run of consecutive completed periods
+    pub longest_streak: usize,
+    /// Rows in the habit file's History table
+    pub history_rows: usize,
+    /// Rows in the sibling `.history.md` archive
+    pub archived_rows: usize,
+    /// Whether the archive was replayed rather than the stored summary
+    pub used_archive: bool,
+}
+
+/// A habit's status and streaks after `toggle_habit`
+#[derive(Debug, Clone, Serialize)]
+pub struct HabitToggleResult {
+    /// `todo` or `completed`
+    pub status: String,
+    /// Consecutive completed periods up to now
+    pub current_streak: usize,
+    /// Longest run of consecutive completed periods
+    pub longest_streak: usize,
+}
+
+/// Completion overview across every habit in a space
+#[derive(Debug, Clone, Serialize)]
+pub struct HabitsOverview {
+    pub total_habits: u32,
+    /// Habits with at least one finished period in the lookback window
+    pub active_habits: u32,
+    /// Mean completion rate (0.0-1.0) of the active habits
+    pub average_completion_rate: f32,
+    /// Active habits that completed every period in the window
+    pub perfect_habits: Vec<String>,
+    /// Active habits that completed fewer than half their periods
+    pub struggling_habits: Vec<String>,
+    /// Habits not yet completed for their current period
+    pub habits_due_today: Vec<String>,
+}
+
+fn resolve_habit_file(habit_path: &str) -> Result<PathBuf, String> {
+    let canonical_habit_path = Path::new(habit_path)
+        .canonicalize()
+        .map_err(|error| format!("Failed to resolve habit file: {}", error))?;
+    if !is_markdown_path(&canonical_habit_path) {
+        return Err(
+            "Habit path must point to a .md or .markdown file inside the Habits folder".to_string(),
+        );
+    }
+    if is_habit_history_archive(&canonical_habit_path) {
+        return Err("Habit path points to a history archive, not a habit".to_string());
+    }
+    let is_in_habits = canonical_habit_path.ancestors().any(|ancestor| {
+        ancestor
+            .file_name()
+            .and_then(|value| value.to_str())
+            .map(|value| value.eq_ignore_ascii_case("Habits"))
+            .unwrap_or(false)
+    });
+    if !is_in_habits {
+        return Err("Habit path must be inside the Habits folder".to_string());
+    }
+
+    Ok(canonical_habit_path)
+}
+
+/// Create a new habit in the Habits folder
+///
+/// References are checked against the space before they are written; ones
+/// that do not resolve are left out and reported back.
+///
+/// # Arguments
+///
+/// * `space_path` - Path to the GTD space root
+/// * `habit_name` - Name of the habit
+/// * `frequency` - How often the habit resets (e.g. `daily`, `weekly`)
+/// * `focus_time` - Optional time of day (HH:MM, 24-hour)
+/// * `references` - Optional project, area, goal, vision, and purpose paths
+///
+/// # Returns
+///
+/// Path to the created habit plus any dropped references, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { path, dropped_references } = await invoke('create_gtd_habit', {
+///   spacePath: '/path/to/gtd/space',
+///   habitName: 'Morning pages',
+///   frequency: 'daily',
+///   focusTime: '07:30',
+///   references: { projects: ['/path/to/gtd/space/Projects/Write Book'] },
+/// });
+/// ```
 #[tauri::command]
 pub fn create_gtd_habit(
     space_path: String,
@@ -62,10 +209,11 @@ pub fn create_gtd_habit(
     frequency: String,
     focus_time: Option<String>,
     references: Option<HabitReferenceInput>,
-) -> Result<String, String> {
+) -> Result<CreatedWithReferences, String> {
     let normalized_habit_name = normalize_habit_title(&habit_name)?;
     log::info!("Creating GTD habit: {}", normalized_habit_name);
 
+    ensure_space_writable(Path::new(&space_path))?;
     let habits_path = Path::new(&space_path).join("Habits");
     if !habits_path.exists() {
         return Err("Habits directory does not exist. Initialize GTD space first.".to_string());
@@ -77,81 +225,47 @@ pub fn create_gtd_habit(
     let frequency_value = HabitFrequency::from_create_input(&frequency)?.as_marker_token();
     let now = Local::now();
     let reference_values = references.unwrap_or_default();
-
-    let render_reference_token = |items: &[String]| -> String {
-        let normalized: Vec<String> = items
-            .iter()
-            .map(|value| value.trim().replace('\\', "/"))
-            .filter(|value| !value.is_empty())
-            .collect();
-
-        if normalized.is_empty() {
-            String::new()
-        } else {
-            match serde_json::to_string(&normalized) {
-                Ok(json) => urlencoding::encode(&json).into_owned(),
-                Err(_) => urlencoding::encode(&normalized.join(",")).into_owned(),
-            }
-        }
-    };
-
-    let focus_time_section = if let Some(time) = focus_time {
-        let trimmed = time.trim();
-        let parsed_time = NaiveTime::parse_from_str(trimmed, "%H:%M").map_err(|_| {
-            format!(
-                "Invalid focus time '{}'. Expected HH:MM in 24-hour format",
-                time
-            )
-        })?;
-        format!(
-            "\n## Focus Date\n[!datetime:focus_date:{}T{}:00]\n\n",
-            now.format("%Y-%m-%d"),
-            parsed_time.format("%H:%M")
-        )
-    } else {
-        "\n".to_string()
+    let space_root = Path::new(&space_path);
+    let mut dropped_references = Vec::new();
+    let mut render_references = |items: &[String], form: ReferenceForm| {
+        render_validated_references(space_root, items, form, &mut dropped_references)
     };
-
-    let habit_content = format!(
-        r#"# {}
-
-## Status
-[!checkbox:habit-status:false]
-
-## Frequency
-[!singleselect:habit-frequency:{}]
-{}## Projects References
-[!projects-references:{}]
-
-## Areas References
-[!areas-references:{}]
-
-## Goals References
-[!goals-references:{}]
-
-## Vision References
-[!vision-references:{}]
-
-## Purpose & Principles References
-[!purpose-references:{}]
-
-## Created
-[!datetime:created_date_time:{}]
-
-## History
-{}
-"#,
-        normalized_habit_name,
-        frequency_value,
-        focus_time_section,
-        render_reference_token(&reference_values.projects),
-        render_reference_token(&reference_values.areas),
-        render_reference_token(&reference_values.goals),
-        render_reference_token(&reference_values.vision),
-        render_reference_token(&reference_values.purpose),
-        now.to_rfc3339(),
-        DEFAULT_HISTORY_TEMPLATE
-    );
+    let projects_token = render_references(&reference_values.projects, ReferenceForm::Folder);
+    let areas_token = render_references(&reference_values.areas, ReferenceForm::File);
+    let goals_token = render_references(&reference_values.goals, ReferenceForm::File);
+    let vision_token = render_references(&reference_values.vision, ReferenceForm::File);
+    let purpose_token = render_references(&reference_values.purpose, ReferenceForm::File);
+
+    let focus_date = focus_time
+        .map(|time| {
+            let trimmed = time.trim();
+            let parsed_time = NaiveTime::parse_from_str(trimmed, "%H:%M").map_err(|_| {
+                format!(
+                    "Invalid focus time '{}'. Expected HH:MM in 24-hour format",
+                    time
+                )
+            })?;
+            Ok::<_, String>(format!(
+                "{}T{}:00",
+                now.format("%Y-%m-%d"),
+                parsed_time.format("%H:%M")
+            ))
+        })
+        .transpose()?;
+
+    let created_at = now.to_rfc3339();
+    let mut template = HabitTemplateBuilder::new(&normalized_habit_name)
+        .frequency(frequency_value)
+        .with_projects_reference(&projects_token)
+        .with_areas_reference(&areas_token)
+        .with_goals_reference(&goals_token)
+        .with_vision_reference(&vision_token)
+        .with_purpose_reference(&purpose_token)
+        .created_at(&created_at);
+    if let Some(focus_date) = focus_date.as_deref() {
+        template = template.focus_time(focus_date);
+    }
+    let habit_content = template.build();
 
     let mut file = OpenOptions::new()
         .write(true)
@@ -180,37 +294,111 @@ pub fn create_gtd_habit(
         }
     }
 
-    Ok(habit_path.to_string_lossy().to_string())
+    record_file_change(&habit_path);
+
+    if !dropped_references.is_empty() {
+        log::warn!(
+            "Dropped {} invalid habit references: {:?}",
+            dropped_references.len(),
+            dropped_references
+        );
+    }
+
+    Ok(CreatedWithReferences {
+        path: habit_path.to_string_lossy().to_string(),
+        dropped_references,
+    })
 }
 
+/// Set a habit's status and record the change in its History table
+///
+/// Emits a `habits-updated` event with the habit path after every write, so
+/// the frontend can refresh that entry even when the file watcher debounces
+/// rapid successive updates.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for emitting the update event
+/// * `habit_path` - Full path to the habit file
+/// * `new_status` - `todo` or `completed`
+/// * `note` - Optional note for the history row's Details column
+///
+/// # Returns
+///
+/// `true` when the status changed, `false` when it already matched
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+/// import { listen } from '@tauri-apps/api/event';
+///
+/// await listen<{ habit_path: string }>('habits-updated', (event) => {
+///   refreshHabit(event.payload.habit_path);
+/// });
+/// await invoke('update_habit_status', {
+///   habitPath: '/path/to/space/Habits/Morning pages.md',
+///   newStatus: 'completed',
+///   note: '3 pages before coffee',
+/// });
+/// ```
 #[tauri::command]
-pub fn update_habit_status(habit_path: String, new_status: String) -> Result<bool, String> {
-    let next_status = HabitStatus::from_input(&new_status)?;
-    let canonical_habit_path = Path::new(&habit_path)
-        .canonicalize()
-        .map_err(|error| format!("Failed to resolve habit file: {}", error))?;
-    let is_markdown_habit = canonical_habit_path
-        .extension()
-        .and_then(|value| value.to_str())
-        .map(|value| value.eq_ignore_ascii_case("md") || value.eq_ignore_ascii_case("markdown"))
-        .unwrap_or(false);
-    if !is_markdown_habit {
-        return Err(
-            "Habit path must point to a .md or .markdown file inside the Habits folder".to_string(),
-        );
+pub fn update_habit_status(
+    app: AppHandle,
+    habit_path: String,
+    new_status: String,
+    note: Option<String>,
+) -> Result<bool, String> {
+    let updated = apply_habit_status(&habit_path, &new_status, note.as_deref())?;
+    if updated {
+        emit_habit_updated(&app, &habit_path);
     }
-    let is_in_habits = canonical_habit_path.ancestors().any(|ancestor| {
-        ancestor
-            .file_name()
-            .and_then(|value| value.to_str())
-            .map(|value| value.eq_ignore_ascii_case("Habits"))
-            .unwrap_or(false)
-    });
-    if !is_in_habits {
-        return Err("Habit path must be inside the Habits folder".to_string());
+    Ok(updated)
+}
+
+fn emit_habit_updated(app: &AppHandle, habit_path: &str) {
+    if let Err(e) = app.emit(
+        HABITS_UPDATED_EVENT,
+        HabitsUpdatedEvent {
+            habit_path: habit_path.to_string(),
+        },
+    ) {
+        log::error!("Failed to emit habits updated event: {}", e);
     }
+}
 
-    let content = fs::read_to_string(&canonical_habit_path)
+/// Set a habit's status without notifying the frontend, returning whether it changed.
+pub(crate) fn apply_habit_status(
+    habit_path: &str,
+    new_status: &str,
+    note: Option<&str>,
+) -> Result<bool, String> {
+    let next_status = HabitStatus::from_input(new_status)?;
+    let canonical_habit_path = resolve_habit_file(habit_path)?;
+    with_file_lock(&canonical_habit_path, || {
+        write_habit_status(&canonical_habit_path, next_status, note)
+    })
+}
+
+/// Flatten a user note onto one line without pipes so the History table
+/// stays valid.
+fn sanitize_history_note(note: &str) -> Option<String> {
+    let cleaned = note
+        .split(|ch: char| ch == '|' || ch.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!cleaned.is_empty()).then_some(cleaned)
+}
+
+fn write_habit_status(
+    canonical_habit_path: &Path,
+    next_status: HabitStatus,
+    note: Option<&str>,
+) -> Result<bool, String> {
+    ensure_file_editable(canonical_habit_path)?;
+
+    let content = fs::read_to_string(canonical_habit_path)
         .map_err(|error| format!("Failed to read habit file: {}", error))?;
     let parsed = parse_habit_state(&content)?;
 
@@ -224,27 +412,261 @@ pub fn update_habit_status(habit_path: String, new_status: String) -> Result<boo
     }
 
     let now = Local::now().naive_local();
-    let history_entry = format_history_entry(
-        now,
-        next_status,
-        "Manual",
-        &format!("Changed from {}", parsed.status.history_label()),
-    );
+    let mut details = format!("Changed from {}", parsed.status.history_label());
+    if let Some(note) = note.and_then(sanitize_history_note) {
+        details.push_str(" - ");
+        details.push_str(&note);
+    }
+    let history_entry = format_history_entry(now, next_status, "Manual", &details);
     let updated_content = apply_status_marker(&content, next_status, parsed.status_format);
     let final_content = insert_history_entry(&updated_content, &history_entry)?;
 
-    atomic_write_habit_file(&canonical_habit_path, &final_content)
+    atomic_write_habit_file(canonical_habit_path, &final_content)
         .map_err(|error| format!("Failed to write habit file: {}", error))?;
 
     Ok(true)
 }
 
+/// Flip a habit between To Do and Completed
+///
+/// Reads the current status under the habit's file lock, so the frontend does
+/// not need to know it. Emits `habits-updated` like `update_habit_status`.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for emitting the update event
+/// * `habit_path` - Full path to the habit file
+/// * `note` - Optional note for the history row's Details column
+///
+/// # Returns
+///
+/// The new status and streak counts after the change
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { status, current_streak } = await invoke<HabitToggleResult>('toggle_habit', {
+///   habitPath: '/path/to/space/Habits/Run.md',
+///   note: '30 min run, felt great',
+/// });
+/// ```
+#[tauri::command]
+pub fn toggle_habit(
+    app: AppHandle,
+    habit_path: String,
+    note: Option<String>,
+) -> Result<HabitToggleResult, String> {
+    let canonical_habit_path = resolve_habit_file(&habit_path)?;
+    let result = with_file_lock(&canonical_habit_path, || {
+        let content = fs::read_to_string(&canonical_habit_path)
+            .map_err(|error| format!("Failed to read habit file: {}", error))?;
+        let next_status = match parse_habit_state(&content)?.status {
+            HabitStatus::Todo => HabitStatus::Completed,
+            HabitStatus::Completed => HabitStatus::Todo,
+        };
+        write_habit_status(&canonical_habit_path, next_status, note.as_deref())?;
+
+        let stats = read_habit_stats(&canonical_habit_path)?;
+        Ok::<_, String>(HabitToggleResult {
+            status: next_status.marker_token().to_string(),
+            current_streak: stats.current_streak,
+            longest_streak: stats.longest_streak,
+        })
+    })?;
+
+    emit_habit_updated(&app, &habit_path);
+    Ok(result)
+}
+
+/// Set or clear a habit's focus date
+///
+/// Rewrites only the `[!datetime:focus_date:...]` marker so concurrent edits
+/// to the rest of the habit file are not overwritten.
+///
+/// # Arguments
+///
+/// * `habit_path` - Full path to the habit file
+/// * `new_focus_date` - New focus date, or `None` to clear it
+///
+/// # Returns
+///
+/// Unit on success or error message
+#[tauri::command]
+pub fn set_habit_focus_date(
+    habit_path: String,
+    new_focus_date: Option<String>,
+) -> Result<(), String> {
+    let canonical_habit_path = resolve_habit_file(&habit_path)?;
+    with_file_lock(&canonical_habit_path, || {
+        update_datetime_field(
+            &canonical_habit_path,
+            "focus_date",
+            new_focus_date.as_deref(),
+        )
+    })
+}
+
+/// Earliest reset boundary after `now` across the space's habits, or `None`
+/// when no habit has one.
+pub(crate) fn next_habit_reset(space_path: &str, now: NaiveDateTime) -> Option<NaiveDateTime> {
+    let entries = fs::read_dir(Path::new(space_path).join("Habits")).ok()?;
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_markdown_path(path) && !is_habit_history_archive(path))
+        .filter_map(|path| {
+            let parsed = parse_habit_state(&fs::read_to_string(&path).ok()?).ok()?;
+            Some(next_reset_after(parsed.frequency, parsed.reset_anchor?))
+        })
+        .filter(|next_reset| *next_reset > now)
+        .min()
+}
+
+/// Append missed-period rows and reset one habit to To Do when its period has rolled over.
+///
+/// Returns whether the habit was rewritten.
+fn reset_habit_if_due(path: &Path, now: NaiveDateTime) -> bool {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => {
+            log::warn!("Skipping habit {:?}: {}", path, error);
+            return false;
+        }
+    };
+    if content_is_locked(&content) {
+        log::info!("Skipping locked habit {:?}", path);
+        return false;
+    }
+    let parsed = match parse_habit_state(&content) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            log::warn!("Skipping habit {:?}: {}", path, error);
+            return false;
+        }
+    };
+
+    let Some(anchor) = parsed.reset_anchor else {
+        log::debug!("Skipping habit {:?}: no reset anchor available", path);
+        return false;
+    };
+
+    if !should_reset_habit(parsed.frequency, anchor, now) {
+        return false;
+    }
+
+    let (missed_periods, missed_periods_truncated) =
+        calculate_missed_periods(anchor, parsed.frequency, now);
+    if missed_periods.is_empty() {
+        return false;
+    }
+    if missed_periods_truncated {
+        log::warn!(
+            "Habit {:?} exceeded missed-period scan cap; processing only the newest periods",
+            path
+        );
+    }
+
+    // Apply a stricter write cap than the domain-layer scan cap so one wake-up
+    // does not flood a habit file with an extreme number of backfilled rows.
+    let periods_to_process = if missed_periods.len() > 100 {
+        &missed_periods[missed_periods.len() - 100..]
+    } else {
+        &missed_periods[..]
+    };
+
+    let mut content_with_history = content.clone();
+    let mut should_skip_habit = false;
+    for (index, period_time) in periods_to_process.iter().enumerate() {
+        let is_catchup = index < periods_to_process.len() - 1;
+        let history_entry = format_history_entry(
+            *period_time,
+            HabitStatus::Todo,
+            if is_catchup { "Backfill" } else { "Auto-Reset" },
+            if is_catchup {
+                "Missed - app offline"
+            } else {
+                "New period"
+            },
+        );
+        match insert_history_entry(&content_with_history, &history_entry) {
+            Ok(next_content) => {
+                content_with_history = next_content;
+            }
+            Err(error) => {
+                log::warn!("Skipping habit {:?}: {}", path, error);
+                should_skip_habit = true;
+                break;
+            }
+        }
+    }
+
+    if should_skip_habit {
+        return false;
+    }
+
+    let final_content = apply_status_marker(
+        &content_with_history,
+        HabitStatus::Todo,
+        parsed.status_format,
+    );
+    if let Err(error) = atomic_write_habit_file(path, &final_content) {
+        log::warn!("Skipping habit {:?}: {}", path, error);
+        return false;
+    }
+
+    true
+}
+
+/// Check and reset habits whose period has rolled over
+///
+/// Calls that land within a few seconds of a scheduled check of the same
+/// space return no names; the scheduler's `habits-reset` event carries them.
+/// Read-only spaces are skipped and also return no names.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// File names of the habits that were reset, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const reset = await invoke<string[]>('check_and_reset_habits', { spacePath: '/path/to/space' });
+/// ```
 #[tauri::command]
 pub fn check_and_reset_habits(space_path: String) -> Result<Vec<String>, String> {
-    let habits_path = Path::new(&space_path).join("Habits");
+    if ran_recently(&space_path) {
+        log::debug!(
+            "Skipping habit check; the scheduler just ran for {}",
+            space_path
+        );
+        return Ok(Vec::new());
+    }
+    reset_due_habits(&space_path)
+}
+
+/// Reset every habit in the space that is due, returning their file names.
+///
+/// Read-only spaces are left alone and report no resets.
+pub(crate) fn reset_due_habits(space_path: &str) -> Result<Vec<String>, String> {
+    let habits_path = Path::new(space_path).join("Habits");
     if !habits_path.exists() {
         return Ok(Vec::new());
     }
+    // Background resets skip read-only spaces quietly; the user can't act on
+    // an error from a poll they didn't start
+    if space_is_read_only(&habits_path) {
+        log::debug!("Skipping habit resets; {} is read-only", space_path);
+        return Ok(Vec::new());
+    }
+    ensure_space_writable(&habits_path)?;
 
     let now = Local::now().naive_local();
     let mut reset_habits = Vec::new();
@@ -264,102 +686,91 @@ pub fn check_and_reset_habits(space_path: String) -> Result<Vec<String>, String>
             }
         };
         let path = entry.path();
+        if !is_markdown_path(&path) || is_habit_history_archive(&path) {
+            continue;
+        }
 
-        let is_markdown = path
-            .extension()
-            .and_then(|value| value.to_str())
-            .map(|value| matches!(value.to_ascii_lowercase().as_str(), "md" | "markdown"))
-            .unwrap_or(false);
-
-        if !is_markdown {
+        if !with_file_lock(&path, || reset_habit_if_due(&path, now)) {
             continue;
         }
 
-        let content = match fs::read_to_string(&path) {
-            Ok(content) => content,
-            Err(error) => {
-                log::warn!("Skipping habit {:?}: {}", path, error);
-                continue;
-            }
-        };
-        let parsed = match parse_habit_state(&content) {
-            Ok(parsed) => parsed,
-            Err(error) => {
-                log::warn!("Skipping habit {:?}: {}", path, error);
-                continue;
-            }
-        };
+        reset_habits.push(
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .unwrap_or("unknown")
+                .to_string(),
+        );
+    }
 
-        let Some(anchor) = parsed.reset_anchor else {
-            log::debug!("Skipping habit {:?}: no reset anchor available", path);
-            continue;
-        };
+    Ok(reset_habits)
+}
 
-        if !should_reset_habit(parsed.frequency, anchor, now) {
-            continue;
+/// Rewrite one habit's history table if it needs repair, returning whether it changed.
+fn repair_habit_file(path: &Path) -> bool {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => {
+            log::warn!("Skipping habit {:?}: {}", path, error);
+            return false;
         }
+    };
+    if content_is_locked(&content) {
+        log::info!("Skipping locked habit {:?}", path);
+        return false;
+    }
 
-        let (missed_periods, missed_periods_truncated) =
-            calculate_missed_periods(anchor, parsed.frequency, now);
-        if missed_periods.is_empty() {
-            continue;
-        }
-        if missed_periods_truncated {
-            log::warn!(
-                "Habit {:?} exceeded missed-period scan cap; processing only the newest periods",
-                path
-            );
+    let Some(repaired_content) = (match repair_habit_history_content(&content) {
+        Ok(result) => result,
+        Err(error) => {
+            log::warn!("Skipping habit {:?}: {}", path, error);
+            return false;
         }
+    }) else {
+        return false;
+    };
 
-        // Apply a stricter write cap than the domain-layer scan cap so one wake-up
-        // does not flood a habit file with an extreme number of backfilled rows.
-        let periods_to_process = if missed_periods.len() > 100 {
-            &missed_periods[missed_periods.len() - 100..]
-        } else {
-            &missed_periods[..]
-        };
+    if let Err(error) = atomic_write_habit_file(path, &repaired_content) {
+        log::warn!("Skipping habit {:?}: {}", path, error);
+        return false;
+    }
 
-        let mut content_with_history = content.clone();
-        let mut should_skip_habit = false;
-        for (index, period_time) in periods_to_process.iter().enumerate() {
-            let is_catchup = index < periods_to_process.len() - 1;
-            let history_entry = format_history_entry(
-                *period_time,
-                HabitStatus::Todo,
-                if is_catchup { "Backfill" } else { "Auto-Reset" },
-                if is_catchup {
-                    "Missed - app offline"
-                } else {
-                    "New period"
-                },
-            );
-            match insert_history_entry(&content_with_history, &history_entry) {
-                Ok(next_content) => {
-                    content_with_history = next_content;
-                }
-                Err(error) => {
-                    log::warn!("Skipping habit {:?}: {}", path, error);
-                    should_skip_habit = true;
-                    break;
-                }
-            }
-        }
+    true
+}
+
+#[tauri::command]
+pub fn repair_habit_history(space_path: String) -> Result<Vec<String>, String> {
+    let habits_path = Path::new(&space_path).join("Habits");
+    if !habits_path.exists() {
+        return Ok(Vec::new());
+    }
+    ensure_space_writable(&habits_path)?;
+
+    let entries = fs::read_dir(&habits_path)
+        .map_err(|error| format!("Failed to read Habits directory: {}", error))?;
+    let mut repaired_habits = Vec::new();
 
-        if should_skip_habit {
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(error) => {
+                log::warn!(
+                    "Skipping unreadable directory entry in {:?}: {}",
+                    habits_path,
+                    error
+                );
+                continue;
+            }
+        };
+        let path = entry.path();
+        if !is_markdown_path(&path) || is_habit_history_archive(&path) {
             continue;
         }
 
-        let final_content = apply_status_marker(
-            &content_with_history,
-            HabitStatus::Todo,
-            parsed.status_format,
-        );
-        if let Err(error) = atomic_write_habit_file(&path, &final_content) {
-            log::warn!("Skipping habit {:?}: {}", path, error);
+        if !with_file_lock(&path, || repair_habit_file(&path)) {
             continue;
         }
 
-        reset_habits.push(
+        repaired_habits.push(
             path.file_name()
                 .and_then(|name| name.to_str())
                 .unwrap_or("unknown")
@@ -367,19 +778,116 @@ pub fn check_and_reset_habits(space_path: String) -> Result<Vec<String>, String>
         );
     }
 
-    Ok(reset_habits)
+    Ok(repaired_habits)
+}
+
+fn prune_habit_file(
+    path: &Path,
+    keep_days: u32,
+    archive: bool,
+    dry_run: bool,
+    now: NaiveDateTime,
+) -> Result<Option<HabitPruneResult>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|error| format!("Failed to read habit file: {}", error))?;
+    parse_habit_state(&content)?;
+
+    let cutoff = now.date().and_hms_opt(0, 0, 0).unwrap_or(now) - Duration::days(keep_days.into());
+    let Some(plan) = plan_history_prune(&content, cutoff, archive)? else {
+        return Ok(None);
+    };
+
+    let archive_path = archive.then(|| habit_history_archive_path(path));
+    if !dry_run {
+        // Write the archive first so a failed habit write leaves rows
+        // duplicated rather than lost.
+        if let Some(archive_path) = &archive_path {
+            let existing = if archive_path.exists() {
+                Some(
+                    fs::read_to_string(archive_path)
+                        .map_err(|error| format!("Failed to read history archive: {}", error))?,
+                )
+            } else {
+                None
+            };
+            let habit_title = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let archive_content = append_history_archive(
+                existing.as_deref(),
+                &habit_title,
+                &plan.archive_span_lines(),
+            );
+            atomic_write_habit_file(archive_path, &archive_content)
+                .map_err(|error| format!("Failed to write history archive: {}", error))?;
+        }
+
+        atomic_write_habit_file(path, &plan.content)
+            .map_err(|error| format!("Failed to write habit file: {}", error))?;
+    }
+
+    Ok(Some(HabitPruneResult {
+        habit_path: path.to_string_lossy().to_string(),
+        pruned_rows: plan.pruned_lines.len(),
+        kept_rows: plan.kept_rows,
+        completions: plan.span_completions,
+        span_start: plan.span_start,
+        span_end: plan.span_end,
+        archive_path: archive_path.map(|value| value.to_string_lossy().to_string()),
+        applied: !dry_run,
+    }))
 }
 
 #[tauri::command]
-pub fn repair_habit_history(space_path: String) -> Result<Vec<String>, String> {
+pub fn prune_habit_history(
+    habit_path: String,
+    keep_days: u32,
+    archive: bool,
+) -> Result<Option<HabitPruneResult>, String> {
+    let canonical_habit_path = resolve_habit_file(&habit_path)?;
+    ensure_file_editable(&canonical_habit_path)?;
+
+    let result = with_file_lock(&canonical_habit_path, || {
+        prune_habit_file(
+            &canonical_habit_path,
+            keep_days,
+            archive,
+            false,
+            Local::now().naive_local(),
+        )
+    })?;
+    if let Some(result) = &result {
+        log::info!(
+            "Pruned {} history rows from {:?} ({})",
+            result.pruned_rows,
+            canonical_habit_path,
+            if archive { "archived" } else { "deleted" }
+        );
+    }
+
+    Ok(result)
+}
+
+#[tauri::command]
+pub fn prune_all_habit_histories(
+    space_path: String,
+    keep_days: u32,
+    archive: bool,
+    dry_run: bool,
+) -> Result<Vec<HabitPruneResult>, String> {
     let habits_path = Path::new(&space_path).join("Habits");
     if !habits_path.exists() {
         return Ok(Vec::new());
     }
+    if !dry_run {
+        ensure_space_writable(&habits_path)?;
+    }
 
+    let now = Local::now().naive_local();
     let entries = fs::read_dir(&habits_path)
         .map_err(|error| format!("Failed to read Habits directory: {}", error))?;
-    let mut repaired_habits = Vec::new();
+    let mut results = Vec::new();
 
     for entry in entries {
         let entry = match entry {
@@ -394,48 +902,441 @@ pub fn repair_habit_history(space_path: String) -> Result<Vec<String>, String> {
             }
         };
         let path = entry.path();
-
-        let is_markdown = path
-            .extension()
-            .and_then(|value| value.to_str())
-            .map(|value| matches!(value.to_ascii_lowercase().as_str(), "md" | "markdown"))
-            .unwrap_or(false);
-        if !is_markdown {
+        if !is_markdown_path(&path) || is_habit_history_archive(&path) {
             continue;
         }
 
-        let content = match fs::read_to_string(&path) {
-            Ok(content) => content,
-            Err(error) => {
-                log::warn!("Skipping habit {:?}: {}", path, error);
+        if !dry_run {
+            if let Err(error) = ensure_file_editable(&path) {
+                log::info!("Skipping habit {:?}: {}", path, error);
                 continue;
             }
-        };
+        }
 
-        let Some(repaired_content) = (match repair_habit_history_content(&content) {
-            Ok(result) => result,
-            Err(error) => {
-                log::warn!("Skipping habit {:?}: {}", path, error);
-                continue;
-            }
-        }) else {
+        match with_file_lock(&path, || {
+            prune_habit_file(&path, keep_days, archive, dry_run, now)
+        }) {
+            Ok(Some(result)) => results.push(result),
+            Ok(None) => {}
+            Err(error) => log::warn!("Skipping habit {:?}: {}", path, error),
+        }
+    }
+
+    results.sort_by(|a, b| a.habit_path.cmp(&b.habit_path));
+    Ok(results)
+}
+
+/// Merge another habit file's history into a habit
+///
+/// Use this when one habit ended up split across two files, or to fold a
+/// `.history.md` archive back into its habit. Rows from both files are
+/// deduplicated by date and time, keeping the primary file's row, and written
+/// to the primary file in chronological order.
+///
+/// # Arguments
+///
+/// * `primary_path` - Habit file that keeps the merged history
+/// * `secondary_path` - Habit file or history archive to merge in
+/// * `keep_secondary` - Keep the secondary file instead of deleting it
+///
+/// # Returns
+///
+/// The primary path or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<string>('merge_habit_history_files', {
+///   primaryPath: '/path/to/gtd/space/Habits/Morning pages.md',
+///   secondaryPath: '/path/to/gtd/space/Habits/Morning pages-2.md',
+///   keepSecondary: false,
+/// });
+/// ```
+#[tauri::command]
+pub fn merge_habit_history_files(
+    primary_path: String,
+    secondary_path: String,
+    keep_secondary: bool,
+) -> Result<String, String> {
+    let canonical_primary = resolve_habit_file(&primary_path)?;
+    let canonical_secondary = Path::new(&secondary_path)
+        .canonicalize()
+        .map_err(|error| format!("Failed to resolve secondary habit file: {}", error))?;
+    let secondary_in_habits = canonical_secondary.parent() == canonical_primary.parent();
+    if !is_markdown_path(&canonical_secondary) || !secondary_in_habits {
+        return Err(
+            "Secondary path must be a habit or history archive in the same Habits folder"
+                .to_string(),
+        );
+    }
+    if canonical_secondary == canonical_primary {
+        return Err("Cannot merge a habit's history into itself".to_string());
+    }
+    ensure_file_editable(&canonical_primary)?;
+    if !keep_secondary {
+        ensure_file_editable(&canonical_secondary)?;
+    }
+
+    let secondary_content = fs::read_to_string(&canonical_secondary)
+        .map_err(|error| format!("Failed to read secondary habit file: {}", error))?;
+    let secondary_is_archive = is_habit_history_archive(&canonical_secondary);
+    with_file_lock(&canonical_primary, || {
+        let primary_content = fs::read_to_string(&canonical_primary)
+            .map_err(|error| format!("Failed to read habit file: {}", error))?;
+        let merged =
+            merge_habit_histories(&primary_content, &secondary_content, secondary_is_archive)?;
+        atomic_write_habit_file(&canonical_primary, &merged)
+            .map_err(|error| format!("Failed to write habit file: {}", error))
+    })?;
+    record_file_change(&canonical_primary);
+
+    if !keep_secondary {
+        fs::remove_file(&canonical_secondary)
+            .map_err(|error| format!("Failed to delete secondary habit file: {}", error))?;
+        record_file_change(&canonical_secondary);
+    }
+
+    log::info!(
+        "Merged habit history from {:?} into {:?}",
+        canonical_secondary,
+        canonical_primary
+    );
+    Ok(primary_path)
+}
+
+#[tauri::command]
+pub fn get_habit_stats(habit_path: String) -> Result<HabitStats, String> {
+    read_habit_stats(&resolve_habit_file(&habit_path)?)
+}
+
+fn read_habit_stats(canonical_habit_path: &Path) -> Result<HabitStats, String> {
+    let content = fs::read_to_string(canonical_habit_path)
+        .map_err(|error| format!("Failed to read habit file: {}", error))?;
+
+    let archive_path = habit_history_archive_path(canonical_habit_path);
+    let archive_content = if archive_path.exists() {
+        Some(
+            fs::read_to_string(&archive_path)
+                .map_err(|error| format!("Failed to read history archive: {}", error))?,
+        )
+    } else {
+        None
+    };
+
+    let (tally, used_archive) = tally_habit_history(&content, archive_content.as_deref());
+    Ok(HabitStats {
+        habit_path: canonical_habit_path.to_string_lossy().to_string(),
+        completions: tally.completions,
+        current_streak: tally.current_streak,
+        longest_streak: tally.longest_streak,
+        history_rows: parse_history_rows(&content).len(),
+        archived_rows: archive_content
+            .as_deref()
+            .map(|archive| parse_archived_history_rows(archive).len())
+            .unwrap_or(0),
+        used_archive,
+    })
+}
+
+/// Default lookback window for habit completion rates, in days
+const DEFAULT_COMPLETION_PERIOD_DAYS: u32 = 30;
+
+fn build_habits_overview(
+    habits_path: &Path,
+    period_days: u32,
+    now: NaiveDateTime,
+) -> HabitsOverview {
+    let since = now - Duration::days(period_days.into());
+    let mut overview = HabitsOverview {
+        total_habits: 0,
+        active_habits: 0,
+        average_completion_rate: 0.0,
+        perfect_habits: Vec::new(),
+        struggling_habits: Vec::new(),
+        habits_due_today: Vec::new(),
+    };
+    let Ok(entries) = fs::read_dir(habits_path) else {
+        return overview;
+    };
+
+    let mut rate_sum = 0.0;
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if !path.is_file() || !is_markdown_path(&path) || is_habit_history_archive(&path) {
+            continue;
+        }
+        let Ok(content) = fs::read_to_string(&path) else {
             continue;
         };
-
-        if let Err(error) = atomic_write_habit_file(&path, &repaired_content) {
-            log::warn!("Skipping habit {:?}: {}", path, error);
+        let Ok(parsed) = parse_habit_state(&content) else {
+            log::debug!("Skipping non-habit file {:?} in overview", path);
             continue;
+        };
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        overview.total_habits += 1;
+        if parsed.status == HabitStatus::Todo {
+            overview.habits_due_today.push(name.clone());
         }
+        let Some(rate) = completion_rate_since(&content, since) else {
+            continue;
+        };
+        overview.active_habits += 1;
+        rate_sum += rate;
+        if rate >= 1.0 {
+            overview.perfect_habits.push(name);
+        } else if rate < 0.5 {
+            overview.struggling_habits.push(name);
+        }
+    }
 
-        repaired_habits.push(
-            path.file_name()
-                .and_then(|name| name.to_str())
-                .unwrap_or("unknown")
-                .to_string(),
-        );
+    if overview.active_habits > 0 {
+        overview.average_completion_rate = rate_sum / overview.active_habits as f32;
     }
+    overview.perfect_habits.sort();
+    overview.struggling_habits.sort();
+    overview.habits_due_today.sort();
+    overview
+}
 
-    Ok(repaired_habits)
+/// Summarize completion rates across all habits for the dashboard
+///
+/// Rates count the habit's reset periods that closed inside the lookback
+/// window, using the same period replay as `get_habit_stats`. The period in
+/// progress only counts once it has been completed.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `period_days` - Lookback window in days; `0` uses the default of 30
+///
+/// # Returns
+///
+/// Overview of all habits, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const overview = await invoke<HabitsOverview>('check_all_habits_completion_rate', {
+///   spacePath: '/path/to/space',
+///   periodDays: 30,
+/// });
+/// ```
+#[tauri::command]
+pub fn check_all_habits_completion_rate(
+    space_path: String,
+    period_days: u32,
+) -> Result<HabitsOverview, String> {
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let period_days = if period_days == 0 {
+        DEFAULT_COMPLETION_PERIOD_DAYS
+    } else {
+        period_days
+    };
+    Ok(build_habits_overview(
+        &space_root.join("Habits"),
+        period_days,
+        Local::now().naive_local(),
+    ))
+}
+
+/// History rows above which the conversion summary is trimmed
+const CONVERSION_SUMMARY_THRESHOLD: usize = 30;
+/// Rows kept in a trimmed conversion summary
+const CONVERSION_SUMMARY_ROWS: usize = 10;
+
+fn habit_title(content: &str, path: &Path) -> String {
+    content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().to_string())
+                .unwrap_or_default()
+        })
+}
+
+/// `## From Habit` section summarizing a habit's frequency and history,
+/// including rows moved to its history archive.
+fn render_habit_conversion_summary(
+    content: &str,
+    archive_content: Option<&str>,
+    frequency: HabitFrequency,
+) -> String {
+    let mut rows = archive_content
+        .map(parse_archived_history_rows)
+        .unwrap_or_default();
+    rows.extend(parse_history_rows(content));
+    rows.sort_by_key(|row| row.timestamp);
+    let completions = rows
+        .iter()
+        .filter(|row| row.status == HabitStatus::Completed.history_label())
+        .count();
+    let shown = if rows.len() > CONVERSION_SUMMARY_THRESHOLD {
+        &rows[rows.len() - CONVERSION_SUMMARY_ROWS..]
+    } else {
+        &rows[..]
+    };
+
+    let mut section = format!(
+        "## From Habit\nConverted from a habit on {}.\n\n- Frequency: {}\n- History entries: {} ({} completed)\n",
+        Local::now().format("%Y-%m-%d"),
+        frequency.as_marker_token(),
+        rows.len(),
+        completions
+    );
+    if !shown.is_empty() {
+        if shown.len() < rows.len() {
+            section.push_str(&format!(
+                "\nLast {} of {} entries:\n",
+                shown.len(),
+                rows.len()
+            ));
+        }
+        section.push_str("\n| Date | Time | Status | Action | Details |\n");
+        section.push_str("|------|------|--------|--------|---------|\n");
+        for row in shown {
+            section.push_str(&format!(
+                "| {} | {} | {} | {} | {} |\n",
+                row.date, row.time, row.status, row.action, row.details
+            ));
+        }
+    }
+    section
+}
+
+/// Turn a habit into a one-off action in a project
+///
+/// The new action is named after the habit and gets a `## From Habit`
+/// section with the habit's frequency and history, including rows in its
+/// `.history.md` archive. Habits with more than 30 history entries only carry
+/// their last 10 into the summary. The habit file and its history archive
+/// are deleted once the action is written.
+///
+/// # Arguments
+///
+/// * `habit_path` - Full path to the habit file
+/// * `project_path` - Full path to the destination project folder
+///
+/// # Returns
+///
+/// Path to the new action file, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const actionPath = await invoke<string>('convert_habit_to_project_action', {
+///   habitPath: '/path/to/space/Habits/Clean garage.md',
+///   projectPath: '/path/to/space/Projects/Home Reset',
+/// });
+/// ```
+#[tauri::command]
+pub fn convert_habit_to_project_action(
+    habit_path: String,
+    project_path: String,
+) -> Result<String, String> {
+    log::info!(
+        "Converting habit {} into an action in {}",
+        habit_path,
+        project_path
+    );
+
+    let canonical_habit_path = resolve_habit_file(&habit_path)?;
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+    validate_projects_child_directory(project_dir)?;
+    ensure_space_writable(project_dir)?;
+
+    with_file_lock(&canonical_habit_path, || {
+        ensure_file_editable(&canonical_habit_path)?;
+        let content = fs::read_to_string(&canonical_habit_path)
+            .map_err(|error| format!("Failed to read habit file: {}", error))?;
+        let parsed = parse_habit_state(&content)?;
+        let archive_path = habit_history_archive_path(&canonical_habit_path);
+        let archive_content = if archive_path.exists() {
+            Some(
+                fs::read_to_string(&archive_path)
+                    .map_err(|error| format!("Failed to read history archive: {}", error))?,
+            )
+        } else {
+            None
+        };
+
+        let action_name = habit_title(&content, &canonical_habit_path);
+        let action_path =
+            project_dir.join(format!("{}.md", sanitize_markdown_file_stem(&action_name)));
+        let template = generate_action_template(
+            &action_name,
+            "in-progress",
+            None,
+            None,
+            "medium",
+            None,
+            None,
+            None,
+        );
+        let action_content = insert_before_created_footer(
+            &template,
+            &render_habit_conversion_summary(
+                &content,
+                archive_content.as_deref(),
+                parsed.frequency,
+            ),
+        );
+
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&action_path)
+            .map_err(|error| {
+                if error.kind() == ErrorKind::AlreadyExists {
+                    format!("Action '{}' already exists", action_name)
+                } else {
+                    format!("Failed to create action file: {}", error)
+                }
+            })?;
+        if let Err(error) = file.write_all(action_content.as_bytes()) {
+            drop(file);
+            let _ = fs::remove_file(&action_path);
+            return Err(format!("Failed to create action file: {}", error));
+        }
+        drop(file);
+
+        if let Err(error) = fs::remove_file(&canonical_habit_path) {
+            let _ = fs::remove_file(&action_path);
+            return Err(format!("Failed to delete habit file: {}", error));
+        }
+        if archive_content.is_some() {
+            if let Err(error) = fs::remove_file(&archive_path) {
+                log::warn!(
+                    "Failed to delete history archive {:?}: {}",
+                    archive_path,
+                    error
+                );
+            }
+        }
+
+        sync_action_table_after_change(&action_path);
+        record_file_change(&action_path);
+        record_file_change(&canonical_habit_path);
+        Ok(action_path.to_string_lossy().to_string())
+    })
 }
 
 #[cfg(test)]
@@ -443,6 +1344,110 @@ mod tests {
     use super::*;
     use crate::test_utils::{seed_test_workspace, write_test_file};
 
+    #[test]
+    fn merge_habit_history_files_dedupes_and_orders_rows() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let habits = workspace.path().join("Habits");
+        let habit = |rows: &str| {
+            format!(
+                "# Stretch\n\n## Status\n[!checkbox:habit-status:false]\n\n## Frequency\n[!singleselect:habit-frequency:daily]\n\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00Z]\n\n## History\n\n{}\n{}\n",
+                DEFAULT_HISTORY_TEMPLATE, rows
+            )
+        };
+        let primary = habits.join("Stretch.md");
+        let secondary = habits.join("Stretch-2.md");
+        write_test_file(
+            &primary,
+            &habit("| 2026-03-03 | 9:00 AM | To Do | Auto-Reset | New period |\n| 2026-03-02 | 8:00 AM | Complete | Manual | Primary row |"),
+        )?;
+        write_test_file(
+            &secondary,
+            &habit("| 2026-03-02 | 8:00 AM | Complete | Manual | Duplicate row |\n| 2026-03-04 | 7:30 AM | Complete | Manual | Newest |"),
+        )?;
+
+        let result = merge_habit_history_files(
+            primary.to_string_lossy().to_string(),
+            secondary.to_string_lossy().to_string(),
+            false,
+        )?;
+        assert_eq!(result, primary.to_string_lossy());
+        assert!(!secondary.exists());
+
+        let merged = fs::read_to_string(&primary).map_err(|e| e.to_string())?;
+        let details: Vec<String> = parse_history_rows(&merged)
+            .into_iter()
+            .map(|row| row.details)
+            .collect();
+        assert_eq!(details, vec!["Primary row", "New period", "Newest"]);
+        assert!(merged.contains("[!checkbox:habit-status:true]"));
+
+        assert!(merge_habit_history_files(
+            primary.to_string_lossy().to_string(),
+            primary.to_string_lossy().to_string(),
+            true,
+        )
+        .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn next_habit_reset_picks_the_earliest_upcoming_boundary() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path().to_string_lossy().to_string();
+        let habit = |frequency: &str| {
+            format!(
+                "# Stretch\n\n## Status\n[!checkbox:habit-status:false]\n\n## Frequency\n[!singleselect:habit-frequency:{}]\n\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00Z]\n\n## History\n\n{}\n| 2026-03-04 | 9:00 AM | To Do | Auto-Reset | New period |\n",
+                frequency, DEFAULT_HISTORY_TEMPLATE
+            )
+        };
+        let at = |hour, minute| {
+            chrono::NaiveDate::from_ymd_opt(2026, 3, 4)
+                .and_then(|date| date.and_hms_opt(hour, minute, 0))
+                .ok_or("invalid test time")
+        };
+
+        write_test_file(workspace.path().join("Habits/Stretch.md"), &habit("daily"))?;
+        assert_eq!(
+            next_habit_reset(&root, at(9, 3)?),
+            Some(at(0, 0)? + Duration::days(1))
+        );
+
+        write_test_file(workspace.path().join("Habits/Water.md"), &habit("5-minute"))?;
+        assert_eq!(next_habit_reset(&root, at(9, 3)?), Some(at(9, 5)?));
+        // A boundary that already passed is left to the next reset run
+        assert_eq!(
+            next_habit_reset(&root, at(9, 7)?),
+            Some(at(0, 0)? + Duration::days(1))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn read_only_spaces_skip_background_resets() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path().to_string_lossy().to_string();
+        let habit_path = workspace.path().join("Habits/Stretch.md");
+        let habit = format!(
+            "# Stretch\n\n## Status\n[!checkbox:habit-status:true]\n\n## Frequency\n[!singleselect:habit-frequency:daily]\n\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00Z]\n\n## History\n\n{}\n| 2026-03-01 | 9:00 AM | Complete | Manual | Done |\n",
+            DEFAULT_HISTORY_TEMPLATE
+        );
+        write_test_file(&habit_path, &habit)?;
+        crate::commands::space_config::set_space_read_only(root.clone(), true)?;
+
+        assert!(reset_due_habits(&root)?.is_empty());
+        assert_eq!(
+            fs::read_to_string(&habit_path).map_err(|e| e.to_string())?,
+            habit
+        );
+        assert!(repair_habit_history(root.clone()).is_err());
+
+        crate::commands::space_config::set_space_read_only(root.clone(), false)?;
+        assert_eq!(reset_due_habits(&root)?, vec!["Stretch.md".to_string()]);
+        Ok(())
+    }
+
     #[test]
     fn repair_habit_history_repairs_only_changed_habits() -> Result<(), String> {
         let workspace = seed_test_workspace()?;
@@ -529,4 +1534,245 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn prune_all_habit_histories_archives_rows_and_keeps_stats() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let habit_path = workspace.path().join("Habits/Run.md");
+        write_test_file(
+            &habit_path,
+            r#"# Run
+
+## Status
+[!checkbox:habit-status:false]
+
+## Frequency
+[!singleselect:habit-frequency:daily]
+
+## History
+| Date | Time | Status | Action | Details |
+|------|------|--------|--------|---------|
+| 2026-01-01 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-01 | 8:00 AM | Complete | Manual | Changed from To Do |
+| 2026-01-02 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-02 | 9:00 AM | Complete | Manual | Changed from To Do |
+| 2026-01-03 | 12:00 AM | To Do | Auto-Reset | New period |
+"#,
+        )?;
+        let space_path = workspace.path().to_string_lossy().to_string();
+        let before = get_habit_stats(habit_path.to_string_lossy().to_string())?;
+
+        let dry_run = prune_all_habit_histories(space_path.clone(), 30, true, true)?;
+        assert_eq!(dry_run.len(), 1);
+        assert!(!dry_run[0].applied);
+        assert!(!workspace.path().join("Habits/Run.history.md").exists());
+
+        let pruned = prune_all_habit_histories(space_path.clone(), 30, true, false)?;
+        assert_eq!(pruned[0].pruned_rows, 4);
+        assert!(workspace.path().join("Habits/Run.history.md").exists());
+
+        let after = get_habit_stats(habit_path.to_string_lossy().to_string())?;
+        assert!(after.used_archive);
+        assert_eq!(after.history_rows, 1);
+        assert_eq!(after.archived_rows, 4);
+        assert_eq!(
+            (after.completions, after.longest_streak),
+            (before.completions, before.longest_streak)
+        );
+
+        // The archive is not itself treated as a habit.
+        assert!(prune_all_habit_histories(space_path, 30, true, false)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn concurrent_status_updates_keep_every_history_row() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let habit_path = workspace.path().join("Habits/Stretch.md");
+        write_test_file(
+            &habit_path,
+            r#"# Stretch
+
+## Status
+[!checkbox:habit-status:false]
+
+## Frequency
+[!singleselect:habit-frequency:daily]
+
+## Created
+[!datetime:created_date_time:2026-03-01T09:00:00Z]
+
+## History
+| Date | Time | Status | Action | Details |
+|------|------|--------|--------|---------|
+"#,
+        )?;
+        let habit_path = habit_path.to_string_lossy().to_string();
+
+        let handles: Vec<_> = (0..8)
+            .map(|worker| {
+                let habit_path = habit_path.clone();
+                std::thread::spawn(move || -> Result<usize, String> {
+                    let mut written = 0;
+                    for round in 0..10 {
+                        let status = if (worker + round) % 2 == 0 {
+                            "completed"
+                        } else {
+                            "todo"
+                        };
+                        if apply_habit_status(&habit_path, status, None)? {
+                            written += 1;
+                        }
+                    }
+                    Ok(written)
+                })
+            })
+            .collect();
+
+        let mut written = 0;
+        for handle in handles {
+            written += handle
+                .join()
+                .map_err(|_| "status worker panicked".to_string())??;
+        }
+
+        let content = fs::read_to_string(&habit_path).map_err(|error| error.to_string())?;
+        assert!(written > 0);
+        assert_eq!(parse_history_rows(&content).len(), written);
+        assert!(!crate::commands::file_locks::is_lock_tracked(Path::new(
+            &habit_path
+        )));
+
+        Ok(())
+    }
+
+    #[test]
+    fn status_notes_land_in_history_details_on_one_line() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let habit_path = workspace.path().join("Habits/Run.md");
+        write_test_file(
+            &habit_path,
+            "# Run\n\n## Status\n[!checkbox:habit-status:false]\n\n## Frequency\n[!singleselect:habit-frequency:daily]\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n",
+        )?;
+        let habit_path = habit_path.to_string_lossy().to_string();
+
+        assert!(apply_habit_status(
+            &habit_path,
+            "completed",
+            Some("30 min run |\n felt great")
+        )?);
+        let content = fs::read_to_string(&habit_path).map_err(|error| error.to_string())?;
+        let rows = parse_history_rows(&content);
+        assert_eq!(rows.len(), 1);
+        assert!(content.contains("| Manual | Changed from To Do - 30 min run felt great |"));
+
+        let stats = read_habit_stats(&resolve_habit_file(&habit_path)?)?;
+        assert_eq!(stats.current_streak, 1);
+        assert_eq!(sanitize_history_note(" | \n "), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn convert_habit_to_project_action_moves_habit_into_project() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let habit_path = workspace.path().join("Habits/Clean Garage.md");
+        write_test_file(
+            workspace.path().join("Habits/Clean Garage.history.md"),
+            "# Clean Garage history\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n| 2026-02-27 | 9:00 AM | Complete | Manual | Archived |\n| 2026-02-28 | 9:00 AM | To Do | Manual | Archived |\n",
+        )?;
+        let rows: String = (1..=31)
+            .map(|day| {
+                format!(
+                    "| 2026-03-{:02} | 9:00 AM | {} | Manual | Day {} |\n",
+                    day,
+                    if day % 2 == 0 { "Complete" } else { "To Do" },
+                    day
+                )
+            })
+            .collect();
+        write_test_file(
+            &habit_path,
+            &format!(
+                "# Clean Garage\n\n## Status\n[!checkbox:habit-status:false]\n\n## Frequency\n[!singleselect:habit-frequency:weekly]\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n{}",
+                rows
+            ),
+        )?;
+        let project = workspace.path().join("Projects/Alpha Project");
+
+        let action_path = convert_habit_to_project_action(
+            habit_path.to_string_lossy().to_string(),
+            project.to_string_lossy().to_string(),
+        )?;
+
+        assert!(!habit_path.exists());
+        assert!(!workspace
+            .path()
+            .join("Habits/Clean Garage.history.md")
+            .exists());
+        assert!(action_path.ends_with("Clean Garage.md"));
+        let action = fs::read_to_string(&action_path).map_err(|error| error.to_string())?;
+        assert!(action.starts_with("# Clean Garage\n"));
+        assert!(action.contains("- Frequency: weekly\n- History entries: 33 (16 completed)"));
+        assert!(action.contains("Last 10 of 33 entries:"));
+        assert!(action.contains("| 2026-03-22 |") && action.contains("| 2026-03-31 |"));
+        assert!(!action.contains("| 2026-03-21 |"));
+        assert!(action.find("## From Habit") < action.find("## Created"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn habits_overview_rates_periods_inside_the_window() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let habit = |status: &str, rows: &str| {
+            format!(
+                "# Habit\n\n## Status\n[!checkbox:habit-status:{}]\n\n## Frequency\n[!singleselect:habit-frequency:daily]\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n{}",
+                status, rows
+            )
+        };
+        write_test_file(
+            workspace.path().join("Habits/Read.md"),
+            &habit(
+                "true",
+                "| 2026-01-02 | 12:00 AM | To Do | Auto-Reset | New period |\n\
+                 | 2026-03-01 | 12:00 AM | To Do | Auto-Reset | New period |\n\
+                 | 2026-03-01 | 8:00 AM | Complete | Manual | Done |\n\
+                 | 2026-03-02 | 12:00 AM | To Do | Auto-Reset | New period |\n\
+                 | 2026-03-02 | 9:00 AM | Complete | Manual | Done |\n",
+            ),
+        )?;
+        write_test_file(
+            workspace.path().join("Habits/Run.md"),
+            &habit(
+                "false",
+                "| 2026-03-01 | 12:00 AM | To Do | Auto-Reset | New period |\n\
+                 | 2026-03-01 | 8:00 AM | Complete | Manual | Done |\n\
+                 | 2026-03-02 | 12:00 AM | To Do | Auto-Reset | New period |\n\
+                 | 2026-03-03 | 12:00 AM | To Do | Auto-Reset | New period |\n\
+                 | 2026-03-04 | 12:00 AM | To Do | Auto-Reset | New period |\n",
+            ),
+        )?;
+        write_test_file(
+            workspace.path().join("Habits/Stretch.md"),
+            &habit("false", ""),
+        )?;
+
+        let now = NaiveDateTime::parse_from_str("2026-03-04T12:00:00", "%Y-%m-%dT%H:%M:%S")
+            .map_err(|error| error.to_string())?;
+        let overview = build_habits_overview(&workspace.path().join("Habits"), 30, now);
+
+        assert_eq!(overview.total_habits, 3);
+        assert_eq!(overview.active_habits, 2);
+        assert_eq!(overview.perfect_habits, vec!["Read".to_string()]);
+        assert_eq!(overview.struggling_habits, vec!["Run".to_string()]);
+        assert_eq!(
+            overview.habits_due_today,
+            vec!["Run".to_string(), "Stretch".to_string()]
+        );
+        assert!((overview.average_completion_rate - (1.0 + 1.0 / 3.0) / 2.0).abs() < 0.001);
+
+        Ok(())
+    }
 }
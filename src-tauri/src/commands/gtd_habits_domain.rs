@@ -1,6 +1,10 @@
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
 use once_cell::sync::Lazy;
 use regex::Regex;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+use super::fields::{set_field, FieldKind};
 
 pub(crate) const DEFAULT_HISTORY_TEMPLATE: &str =
     "*Track your habit completions below:*\n\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|";
@@ -642,20 +646,11 @@ pub(crate) fn apply_status_marker(
     status: HabitStatus,
     format: HabitStatusFormat,
 ) -> String {
-    match format {
-        HabitStatusFormat::Checkbox => HABIT_CHECKBOX_REGEX
-            .replace(
-                content,
-                format!("[!checkbox:habit-status:{}]", status.checkbox_token()).as_str(),
-            )
-            .to_string(),
-        HabitStatusFormat::SingleSelect => HABIT_STATUS_FIELD_REGEX
-            .replace(
-                content,
-                format!("[!singleselect:habit-status:{}]", status.marker_token()).as_str(),
-            )
-            .to_string(),
-    }
+    let (kind, token) = match format {
+        HabitStatusFormat::Checkbox => (FieldKind::Checkbox, status.checkbox_token()),
+        HabitStatusFormat::SingleSelect => (FieldKind::SingleSelect, status.marker_token()),
+    };
+    set_field(content, kind, "habit-status", token).unwrap_or_else(|_| content.to_string())
 }
 
 fn history_section_has_plain_prose_lines(content: &str) -> bool {
@@ -969,6 +964,501 @@ pub(crate) fn should_reset_habit(
     next_reset_after(frequency, anchor) <= now
 }
 
+pub(crate) const HABIT_HISTORY_ARCHIVE_SUFFIX: &str = ".history.md";
+
+const HISTORY_SUMMARY_HEADING: &str = "## History Summary";
+
+/// Whether `path` is a `<name>.history.md` archive rather than a habit.
+pub(crate) fn is_habit_history_archive(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| {
+            name.to_ascii_lowercase()
+                .ends_with(HABIT_HISTORY_ARCHIVE_SUFFIX)
+        })
+        .unwrap_or(false)
+}
+
+pub(crate) fn habit_history_archive_path(habit_path: &Path) -> PathBuf {
+    let stem = habit_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    habit_path.with_file_name(format!("{}{}", stem, HABIT_HISTORY_ARCHIVE_SUFFIX))
+}
+
+/// Aggregate counts for pruned history, kept in the habit's `## History Summary`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct HistorySummary {
+    pub span_start: String,
+    pub span_end: String,
+    pub archived_rows: usize,
+    pub deleted_rows: usize,
+    pub completions: usize,
+    pub longest_streak: usize,
+    pub trailing_streak: usize,
+}
+
+impl HistorySummary {
+    fn render(&self) -> Vec<String> {
+        vec![
+            HISTORY_SUMMARY_HEADING.to_string(),
+            format!("- **Span:** {} to {}", self.span_start, self.span_end),
+            format!("- **Archived rows:** {}", self.archived_rows),
+            format!("- **Deleted rows:** {}", self.deleted_rows),
+            format!("- **Completions:** {}", self.completions),
+            format!("- **Longest streak:** {}", self.longest_streak),
+            format!("- **Trailing streak:** {}", self.trailing_streak),
+        ]
+    }
+}
+
+fn is_history_summary_heading_line(line: &str) -> bool {
+    line.trim().eq_ignore_ascii_case(HISTORY_SUMMARY_HEADING)
+}
+
+pub(crate) fn parse_history_summary(content: &str) -> Option<HistorySummary> {
+    let summary_index = content.lines().position(is_history_summary_heading_line)?;
+    let mut summary = HistorySummary::default();
+
+    for line in content.lines().skip(summary_index + 1) {
+        let trimmed = line.trim();
+        if trimmed.starts_with('#') {
+            break;
+        }
+        let Some((key, value)) = trimmed
+            .strip_prefix("- **")
+            .and_then(|rest| rest.split_once(":**"))
+        else {
+            continue;
+        };
+        let value = value.trim();
+        let count = || value.parse::<usize>().unwrap_or(0);
+        match key.trim().to_lowercase().as_str() {
+            "span" => {
+                if let Some((start, end)) = value.split_once(" to ") {
+                    summary.span_start = start.trim().to_string();
+                    summary.span_end = end.trim().to_string();
+                }
+            }
+            "archived rows" => summary.archived_rows = count(),
+            "deleted rows" => summary.deleted_rows = count(),
+            "completions" => summary.completions = count(),
+            "longest streak" => summary.longest_streak = count(),
+            "trailing streak" => summary.trailing_streak = count(),
+            _ => {}
+        }
+    }
+
+    Some(summary)
+}
+
+/// Replays history rows period by period. Reset and backfill rows open a new
+/// period; the period counts as completed if its latest status row is Complete.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct StreakTally {
+    pub completions: usize,
+    pub current_streak: usize,
+    pub longest_streak: usize,
+    period_open: bool,
+    period_completed: bool,
+}
+
+impl StreakTally {
+    pub(crate) fn seeded(summary: &HistorySummary) -> Self {
+        Self {
+            completions: summary.completions,
+            current_streak: summary.trailing_streak,
+            longest_streak: summary.longest_streak,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn record(&mut self, row: &ParsedHistoryRow) {
+        if is_reset_action(&row.action) {
+            self.close_period();
+            self.period_open = true;
+            self.period_completed = false;
+        } else if let Some(status) = HabitStatus::from_history_label(&row.status) {
+            self.period_open = true;
+            self.period_completed = status == HabitStatus::Completed;
+        }
+    }
+
+    fn close_period(&mut self) {
+        if !self.period_open {
+            return;
+        }
+        if self.period_completed {
+            self.completions += 1;
+            self.current_streak += 1;
+            self.longest_streak = self.longest_streak.max(self.current_streak);
+        } else {
+            self.current_streak = 0;
+        }
+        self.period_open = false;
+        self.period_completed = false;
+    }
+
+    /// Count a completed in-progress period without breaking the streak for
+    /// one that is still open.
+    pub(crate) fn finish(mut self) -> Self {
+        if self.period_completed {
+            self.close_period();
+        }
+        self
+    }
+}
+
+fn is_archive_summary_row(row: &ParsedHistoryRow) -> bool {
+    row.status.eq_ignore_ascii_case("Summary") && row.action.eq_ignore_ascii_case("Archive")
+}
+
+/// History rows from an archive file, without the per-span summary rows.
+pub(crate) fn parse_archived_history_rows(archive_content: &str) -> Vec<ParsedHistoryRow> {
+    parse_history_rows(archive_content)
+        .into_iter()
+        .filter(|row| !is_archive_summary_row(row))
+        .collect()
+}
+
+/// Result of splitting a habit's history at a cutoff.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct HistoryPrunePlan {
+    /// Habit content with pruned rows removed and the summary updated
+    pub content: String,
+    /// Original table lines of the pruned rows, oldest first
+    pub pruned_lines: Vec<String>,
+    pub kept_rows: usize,
+    pub span_start: String,
+    pub span_start_time: String,
+    pub span_end: String,
+    pub span_completions: usize,
+}
+
+impl HistoryPrunePlan {
+    /// Archive lines for this span: a summary row followed by the pruned rows.
+    pub(crate) fn archive_span_lines(&self) -> Vec<String> {
+        let mut lines = vec![format!(
+            "| {} | {} | Summary | Archive | {} completions between {} and {} |",
+            self.span_start,
+            self.span_start_time,
+            self.span_completions,
+            self.span_start,
+            self.span_end
+        )];
+        lines.extend(self.pruned_lines.iter().cloned());
+        lines
+    }
+}
+
+fn history_section_bounds(lines: &[&str]) -> Option<(usize, usize)> {
+    let start = lines
+        .iter()
+        .position(|line| is_history_heading_line(line))?;
+    let end = lines
+        .iter()
+        .enumerate()
+        .skip(start + 1)
+        .find(|(_, line)| line.trim().starts_with('#'))
+        .map(|(idx, _)| idx)
+        .unwrap_or(lines.len());
+    Some((start, end))
+}
+
+/// Plan pruning of history rows older than `cutoff`.
+///
+/// Rows are pruned up to the last reset row at or before the cutoff so the
+/// pruned span always ends on a period boundary and the newest reset anchor
+/// stays in the file. Remaining rows are reordered chronologically. Returns
+/// `None` when nothing qualifies.
+pub(crate) fn plan_history_prune(
+    content: &str,
+    cutoff: NaiveDateTime,
+    archive: bool,
+) -> Result<Option<HistoryPrunePlan>, String> {
+    parse_history_rows_strict(content)
+        .map_err(|error| format!("{}. Repair habit history before pruning", error))?;
+
+    let lines: Vec<&str> = content.lines().collect();
+    let Some((history_start, history_end)) = history_section_bounds(&lines) else {
+        return Ok(None);
+    };
+
+    let mut rows: Vec<(ParsedHistoryRow, &str)> = lines[history_start + 1..history_end]
+        .iter()
+        .filter_map(|line| parse_history_row_from_table(line).map(|row| (row, line.trim())))
+        .collect();
+    rows.sort_by_key(|(row, _)| row.timestamp);
+
+    let Some(pivot) = rows
+        .iter()
+        .rposition(|(row, _)| is_reset_action(&row.action) && row.timestamp <= cutoff)
+    else {
+        return Ok(None);
+    };
+    if pivot == 0 {
+        return Ok(None);
+    }
+
+    let (pruned, kept) = rows.split_at(pivot);
+    let mut span_tally = StreakTally::default();
+    for (row, _) in pruned {
+        span_tally.record(row);
+    }
+    span_tally.close_period();
+
+    let previous = parse_history_summary(content);
+    let mut seeded = previous
+        .as_ref()
+        .map(StreakTally::seeded)
+        .unwrap_or_default();
+    for (row, _) in pruned {
+        seeded.record(row);
+    }
+    seeded.close_period();
+
+    let span_start = pruned[0].0.date.clone();
+    let span_start_time = pruned[0].0.time.clone();
+    let span_end = pruned[pruned.len() - 1].0.date.clone();
+    let summary = HistorySummary {
+        span_start: previous
+            .as_ref()
+            .map(|summary| summary.span_start.clone())
+            .filter(|start| !start.is_empty())
+            .unwrap_or_else(|| span_start.clone()),
+        span_end: span_end.clone(),
+        archived_rows: previous.as_ref().map_or(0, |s| s.archived_rows)
+            + if archive { pruned.len() } else { 0 },
+        deleted_rows: previous.as_ref().map_or(0, |s| s.deleted_rows)
+            + if archive { 0 } else { pruned.len() },
+        completions: seeded.completions,
+        longest_streak: seeded.longest_streak,
+        trailing_streak: seeded.current_streak,
+    };
+
+    // Rebuild the history section: prose, header, and separator lines stay in
+    // place, and the kept rows are written in order at the first row position.
+    let mut history_lines = Vec::new();
+    let mut rows_written = false;
+    for line in &lines[history_start + 1..history_end] {
+        if parse_history_row_from_table(line).is_some() {
+            if !rows_written {
+                history_lines.extend(kept.iter().map(|(_, line)| line.to_string()));
+                rows_written = true;
+            }
+            continue;
+        }
+        history_lines.push(line.to_string());
+    }
+
+    let mut new_lines: Vec<String> = Vec::new();
+    let mut index = 0;
+    while index < lines.len() {
+        if is_history_summary_heading_line(lines[index]) {
+            index += 1;
+            while index < lines.len() && !lines[index].trim().starts_with('#') {
+                index += 1;
+            }
+            continue;
+        }
+        if index == history_start {
+            new_lines.extend(summary.render());
+            new_lines.push(String::new());
+            new_lines.push(lines[index].to_string());
+            new_lines.append(&mut history_lines);
+            index = history_end;
+            continue;
+        }
+        new_lines.push(lines[index].to_string());
+        index += 1;
+    }
+
+    let mut updated = new_lines.join("\n");
+    if content.ends_with('\n') {
+        updated.push('\n');
+    }
+
+    Ok(Some(HistoryPrunePlan {
+        content: updated,
+        pruned_lines: pruned.iter().map(|(_, line)| line.to_string()).collect(),
+        kept_rows: kept.len(),
+        span_start,
+        span_start_time,
+        span_end,
+        span_completions: span_tally.completions,
+    }))
+}
+
+/// Append pruned rows to an archive, creating it when missing.
+pub(crate) fn append_history_archive(
+    existing: Option<&str>,
+    habit_title: &str,
+    span_lines: &[String],
+) -> String {
+    let mut archive = match existing {
+        Some(content) if !content.trim().is_empty() => content.trim_end().to_string(),
+        _ => format!(
+            "# {} History Archive\n\n*Rows pruned from {}. Each span starts with a summary row.*\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|",
+            habit_title, habit_title
+        ),
+    };
+    for line in span_lines {
+        archive.push('\n');
+        archive.push_str(line);
+    }
+    archive.push('\n');
+    archive
+}
+
+/// Insert several history rows, in order, with `insert_history_entry`.
+pub(crate) fn insert_history_entries_bulk(
+    content: &str,
+    entries: &[String],
+) -> Result<String, String> {
+    entries
+        .iter()
+        .try_fold(content.to_string(), |content, entry| {
+            insert_history_entry(&content, entry)
+        })
+}
+
+/// History table rows with their original line text.
+fn history_row_lines(content: &str) -> Vec<(ParsedHistoryRow, String)> {
+    let lines: Vec<&str> = content.lines().collect();
+    let Some((start, end)) = history_section_bounds(&lines) else {
+        return Vec::new();
+    };
+    lines[start + 1..end]
+        .iter()
+        .filter_map(|line| {
+            parse_history_row_from_table(line).map(|row| (row, line.trim().to_string()))
+        })
+        .collect()
+}
+
+/// Merge another habit file's history rows into `primary`.
+///
+/// Rows are deduplicated by timestamp (the row's date and time), keeping
+/// the primary file's row, and written back in chronological order. Archive
+/// summary rows are dropped when `secondary` is a history archive. The status
+/// marker follows the newest merged row.
+pub(crate) fn merge_habit_histories(
+    primary: &str,
+    secondary: &str,
+    secondary_is_archive: bool,
+) -> Result<String, String> {
+    let state = parse_habit_state(primary)?;
+    for content in [primary, secondary] {
+        parse_history_rows_strict(content)
+            .map_err(|error| format!("{}. Repair habit history before merging", error))?;
+    }
+
+    let mut seen = HashSet::new();
+    let mut rows: Vec<(ParsedHistoryRow, String)> = history_row_lines(primary)
+        .into_iter()
+        .chain(
+            history_row_lines(secondary)
+                .into_iter()
+                .filter(|(row, _)| !(secondary_is_archive && is_archive_summary_row(row))),
+        )
+        .filter(|(row, _)| seen.insert(row.timestamp))
+        .collect();
+    rows.sort_by_key(|(row, _)| row.timestamp);
+
+    let lines: Vec<&str> = primary.lines().collect();
+    let stripped = match history_section_bounds(&lines) {
+        Some((start, end)) => lines
+            .iter()
+            .enumerate()
+            .filter(|(index, line)| {
+                !(*index > start && *index < end && parse_history_row_from_table(line).is_some())
+            })
+            .map(|(_, line)| *line)
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None => primary.to_string(),
+    };
+
+    let entries: Vec<String> = rows.iter().map(|(_, line)| line.clone()).collect();
+    let mut merged = insert_history_entries_bulk(&stripped, &entries)?;
+    let parsed_rows: Vec<ParsedHistoryRow> = rows.into_iter().map(|(row, _)| row).collect();
+    let status = derive_latest_history_status(&parsed_rows, state.status);
+    if status != state.status {
+        merged = apply_status_marker(&merged, status, state.status_format);
+    }
+    if primary.ends_with('\n') && !merged.ends_with('\n') {
+        merged.push('\n');
+    }
+    Ok(merged)
+}
+
+/// Share of periods at or after `since` that were completed, from the habit
+/// file's own history. `None` when no period in the window has closed.
+pub(crate) fn completion_rate_since(content: &str, since: NaiveDateTime) -> Option<f32> {
+    let mut rows: Vec<ParsedHistoryRow> = parse_history_rows(content)
+        .into_iter()
+        .filter(|row| row.timestamp >= since)
+        .collect();
+    rows.sort_by_key(|row| row.timestamp);
+
+    // A reset closes the period before it; the period still in progress only
+    // counts once completed, matching `StreakTally::finish`.
+    let mut tally = StreakTally::default();
+    let mut periods = 0;
+    let mut period_open = false;
+    for row in &rows {
+        if is_reset_action(&row.action) && period_open {
+            periods += 1;
+        }
+        period_open = true;
+        tally.record(row);
+    }
+    let closed_completions = tally.completions;
+    let tally = tally.finish();
+    if tally.completions > closed_completions {
+        periods += 1;
+    }
+    (periods > 0).then(|| tally.completions as f32 / periods as f32)
+}
+
+/// Replay a habit's history, including archived rows when the archive covers
+/// everything pruned so far. Otherwise the stored summary seeds the tally.
+///
+/// Returns the tally and whether the archive was used.
+pub(crate) fn tally_habit_history(
+    content: &str,
+    archive_content: Option<&str>,
+) -> (StreakTally, bool) {
+    let summary = parse_history_summary(content);
+    let archived_rows = archive_content
+        .map(parse_archived_history_rows)
+        .unwrap_or_default();
+    let archive_is_complete = archive_content.is_some()
+        && summary.as_ref().is_none_or(|summary| {
+            summary.deleted_rows == 0 && archived_rows.len() >= summary.archived_rows
+        });
+
+    let (mut tally, mut rows) = if archive_is_complete {
+        (StreakTally::default(), archived_rows)
+    } else {
+        (
+            summary
+                .as_ref()
+                .map(StreakTally::seeded)
+                .unwrap_or_default(),
+            Vec::new(),
+        )
+    };
+    rows.extend(parse_history_rows(content));
+    rows.sort_by_key(|row| row.timestamp);
+    for row in &rows {
+        tally.record(row);
+    }
+
+    (tally.finish(), archive_is_complete)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1378,4 +1868,61 @@ Still here
         assert!(repaired.contains("## Notes\nStill here"));
         assert!(second_pass.is_none());
     }
+
+    #[test]
+    fn plan_history_prune_summarizes_span_and_preserves_streaks() {
+        let content = r#"# Run
+
+## Status
+[!checkbox:habit-status:false]
+
+## Frequency
+[!singleselect:habit-frequency:daily]
+
+## History
+| Date | Time | Status | Action | Details |
+|------|------|--------|--------|---------|
+| 2026-01-01 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-01 | 8:00 AM | Complete | Manual | Changed from To Do |
+| 2026-01-02 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-02 | 9:00 AM | Complete | Manual | Changed from To Do |
+| 2026-01-03 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-04 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-05 | 12:00 AM | To Do | Auto-Reset | New period |
+| 2026-01-04 | 7:00 AM | Complete | Manual | Changed from To Do |
+"#;
+        let (full, _) = tally_habit_history(content, None);
+
+        let plan = plan_history_prune(content, dt(2026, 1, 3, 12, 0), true)
+            .unwrap()
+            .unwrap();
+        assert_eq!(plan.pruned_lines.len(), 4);
+        assert_eq!(plan.kept_rows, 4);
+        assert_eq!(plan.span_completions, 2);
+        assert!(plan.content.contains(
+            "## History Summary\n- **Span:** 2026-01-01 to 2026-01-02\n- **Archived rows:** 4"
+        ));
+        assert!(plan.content.contains(
+            "| 2026-01-04 | 7:00 AM | Complete | Manual | Changed from To Do |\n| 2026-01-05 | 12:00 AM"
+        ));
+        assert_eq!(
+            parse_habit_state(&plan.content).unwrap().reset_anchor,
+            Some(dt(2026, 1, 5, 0, 0))
+        );
+
+        let archive = append_history_archive(None, "Run", &plan.archive_span_lines());
+        assert!(archive
+            .contains("| Summary | Archive | 2 completions between 2026-01-01 and 2026-01-02 |"));
+
+        let (from_summary, used_archive) = tally_habit_history(&plan.content, None);
+        assert!(!used_archive);
+        assert_eq!(from_summary, full);
+        let (from_archive, used_archive) = tally_habit_history(&plan.content, Some(&archive));
+        assert!(used_archive);
+        assert_eq!(from_archive, full);
+        assert_eq!(
+            (full.completions, full.current_streak, full.longest_streak),
+            (3, 1, 2)
+        );
+    }
 }
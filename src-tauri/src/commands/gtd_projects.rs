@@ -1,15 +1,35 @@
 //! GTD project and action commands.
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Component, Path, PathBuf};
+use tauri::AppHandle;
 use tempfile::NamedTempFile;
 
-use super::seed_data::{generate_action_template, generate_project_readme};
+use super::action_table::sync_action_table_after_change;
+use super::custom_fields::{custom_fields_for, validate_custom_fields, write_custom_fields};
+use super::field_vocabulary::{field_vocabulary_for, field_vocabulary_or_default};
+use super::fields::{update_file_field, FieldKind};
+use super::file_icons::extract_icon;
+use super::file_meta::{parse_timestamp, record_file_change};
+use super::filesystem::{delete_file, read_file};
+use super::gtd_relationships::{
+    extract_reference_block, parse_reference_paths, render_validated_references,
+    CreatedWithReferences, ReferenceForm,
+};
+use super::path_remap::{directory_remaps, emit_remap, PathRemap};
+use super::project_rollup::compute_project_rollup;
+use super::sections::{list_sections, section_body_start};
+use super::seed_data::{
+    generate_action_template, generate_project_readme_with_refs, ProjectReadmeParams,
+};
+use super::space_config::{ensure_file_editable, ensure_space_writable};
 use super::utils::sanitize_markdown_file_stem;
 
-fn resolve_project_readme_path(project_path: &Path) -> Option<PathBuf> {
+pub(crate) fn resolve_project_readme_path(project_path: &Path) -> Option<PathBuf> {
     let markdown_path = project_path.join("README.markdown");
     let md_path = project_path.join("README.md");
 
@@ -22,7 +42,24 @@ fn resolve_project_readme_path(project_path: &Path) -> Option<PathBuf> {
     }
 }
 
-fn write_string_atomically(path: &Path, content: &str) -> Result<(), String> {
+/// Horizon and general references for a new project
+#[derive(Debug, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub struct ProjectReferenceInput {
+    #[serde(default)]
+    pub areas: Vec<String>,
+    #[serde(default)]
+    pub goals: Vec<String>,
+    #[serde(default)]
+    pub vision: Vec<String>,
+    #[serde(default)]
+    pub purpose: Vec<String>,
+    /// Cabinet or other supporting files for `[!references:...]`
+    #[serde(default)]
+    pub general: Vec<String>,
+}
+
+pub(crate) fn write_string_atomically(path: &Path, content: &str) -> Result<(), String> {
     let temp_dir = path.parent().unwrap_or_else(|| Path::new("."));
     let mut temp_file = NamedTempFile::new_in(temp_dir)
         .map_err(|e| format!("Failed to create temporary file: {}", e))?;
@@ -53,10 +90,12 @@ fn write_string_atomically(path: &Path, content: &str) -> Result<(), String> {
 /// * `description` - Project description
 /// * `due_date` - Optional due date (ISO format: YYYY-MM-DD)
 /// * `status` - Optional project status (in-progress, waiting, completed). Defaults to 'in-progress'
+/// * `references` - Optional area, goal, vision, purpose, and general reference paths;
+///   ones that do not resolve inside the space are dropped
 ///
 /// # Returns
 ///
-/// Path to the created project or error details
+/// Path to the created project plus any dropped references, or error details
 ///
 /// # Examples
 ///
@@ -68,7 +107,8 @@ fn write_string_atomically(path: &Path, content: &str) -> Result<(), String> {
 ///   project_name: 'Build Website',
 ///   description: 'Create company website',
 ///   due_date: '2024-12-31',
-///   status: 'in-progress'
+///   status: 'in-progress',
+///   references: { areas: ['/path/to/gtd/space/Areas of Focus/Career.md'] }
 /// });
 /// ```
 #[tauri::command]
@@ -78,9 +118,11 @@ pub fn create_gtd_project(
     description: String,
     due_date: Option<String>,
     status: Option<String>,
-) -> Result<String, String> {
+    references: Option<ProjectReferenceInput>,
+) -> Result<CreatedWithReferences, String> {
     log::info!("Creating GTD project: {}", project_name);
 
+    ensure_space_writable(Path::new(&space_path))?;
     let projects_path = Path::new(&space_path).join("Projects");
 
     // Ensure Projects directory exists
@@ -93,17 +135,12 @@ pub fn create_gtd_project(
     // Create project folder
     let project_path = projects_path.join(&safe_project_name);
 
-    // Validate status if provided
-    if let Some(ref status_value) = status {
-        let valid_statuses = ["in-progress", "waiting", "completed"];
-        if !valid_statuses.contains(&status_value.as_str()) {
-            return Err(format!(
-                "Invalid status '{}'. Must be one of: {}",
-                status_value,
-                valid_statuses.join(", ")
-            ));
-        }
-    }
+    // Validate status against the space's vocabulary
+    let vocabulary = field_vocabulary_for(&projects_path)?;
+    let project_status = match status {
+        Some(status_value) => vocabulary.validate_status(&status_value)?,
+        None => vocabulary.default_status(),
+    };
 
     if let Err(e) = fs::create_dir(&project_path) {
         if e.kind() == io::ErrorKind::AlreadyExists {
@@ -114,9 +151,34 @@ pub fn create_gtd_project(
 
     // Create README.md with project template
     let readme_path = project_path.join("README.md");
-    let project_status = status.unwrap_or_else(|| "in-progress".to_string());
-    let readme_content =
-        generate_project_readme(&safe_project_name, &description, due_date, &project_status);
+    let reference_values = references.unwrap_or_default();
+    let space_root = Path::new(&space_path);
+    let mut dropped_references = Vec::new();
+    let mut render_references = |items: &[String]| {
+        render_validated_references(
+            space_root,
+            items,
+            ReferenceForm::File,
+            &mut dropped_references,
+        )
+    };
+    let areas_refs = render_references(&reference_values.areas);
+    let goals_refs = render_references(&reference_values.goals);
+    let vision_refs = render_references(&reference_values.vision);
+    let purpose_refs = render_references(&reference_values.purpose);
+    let general_refs = render_references(&reference_values.general);
+    let readme_content = generate_project_readme_with_refs(ProjectReadmeParams {
+        name: &safe_project_name,
+        description: &description,
+        due_date: due_date.as_deref(),
+        focus_date: None,
+        status: &project_status,
+        areas_refs: &areas_refs,
+        goals_refs: &goals_refs,
+        vision_refs: &vision_refs,
+        purpose_refs: &purpose_refs,
+        general_refs: &general_refs,
+    });
 
     if let Err(e) = fs::write(&readme_path, readme_content) {
         // Clean up project directory if README creation fails
@@ -125,43 +187,181 @@ pub fn create_gtd_project(
         return Err(format!("Failed to create project README: {}", e));
     }
 
+    if !dropped_references.is_empty() {
+        log::warn!(
+            "Dropped {} invalid project references: {:?}",
+            dropped_references.len(),
+            dropped_references
+        );
+    }
+
+    record_file_change(&readme_path);
     log::info!("Successfully created project: {}", safe_project_name);
-    Ok(project_path.to_string_lossy().to_string())
+    Ok(CreatedWithReferences {
+        path: project_path.to_string_lossy().to_string(),
+        dropped_references,
+    })
 }
 
-/// Create a new GTD action
+/// Someday Maybe notes as project description text.
 ///
-/// Creates a new action (task) file within a project directory.
+/// Marker-only lines (references, dates, statuses) are dropped, headings are
+/// demoted below the README's `##` sections, and emptied sections removed.
+fn someday_notes_for_description(content: &str) -> String {
+    let mut body = content.lines().skip_while(|line| line.trim().is_empty());
+    let first = body.next().unwrap_or_default();
+    let lines: Vec<&str> = if first.trim_start().starts_with("# ") {
+        body.collect()
+    } else {
+        std::iter::once(first).chain(body).collect()
+    };
+    let lines: Vec<&str> = lines
+        .into_iter()
+        .filter(|line| {
+            let trimmed = line.trim();
+            !(trimmed.starts_with("[!") && trimmed.ends_with(']'))
+        })
+        .collect();
+
+    let mut kept: Vec<String> = Vec::new();
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with('#') {
+            let section_is_empty = lines[index + 1..]
+                .iter()
+                .map(|next| next.trim())
+                .find(|next| !next.is_empty())
+                .is_none_or(|next| next.starts_with('#'));
+            if !section_is_empty {
+                kept.push(format!("#{}", trimmed));
+            }
+            continue;
+        }
+        kept.push(line.to_string());
+    }
+    kept.join("\n").trim().to_string()
+}
+
+/// Promote a Someday Maybe item to an active project
+///
+/// Creates the project from the item's title and notes, carries over its
+/// horizon and general references, then deletes the Someday Maybe file.
 ///
 /// # Arguments
 ///
-/// * `project_path` - Full path to the project directory
-/// * `action_name` - Name of the action
-/// * `status` - Initial status (In Progress / Waiting / Completed)
-/// * `due_date` - Optional due date (ISO format: YYYY-MM-DD)
-/// * `effort` - Effort estimate (Small / Medium / Large / Extra Large)
+/// * `item_path` - Full path to the Someday Maybe file
+/// * `space_path` - Path to the GTD space root
+/// * `due_date` - Optional due date for the new project (YYYY-MM-DD)
 ///
 /// # Returns
 ///
-/// Path to the created action file or error details
+/// Path to the new project folder, or an error if a project with the same
+/// name already exists
 ///
 /// # Examples
 ///
 /// ```typescript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// await invoke('create_gtd_action', {
-///   project_path: '/path/to/gtd/space/Projects/Build Website',
-///   action_name: 'Design homepage',
-///   status: 'in-progress',
-///   due_date: '2024-11-15',
-///   focus_date: '2024-11-14T14:30:00',
-///   effort: 'Medium'
+/// const projectPath = await invoke<string>('convert_someday_maybe_to_project', {
+///   itemPath: '/path/to/space/Someday Maybe/Learn Spanish.md',
+///   spacePath: '/path/to/space',
+///   dueDate: '2026-12-31',
 /// });
 /// ```
 #[tauri::command]
+pub fn convert_someday_maybe_to_project(
+    item_path: String,
+    space_path: String,
+    due_date: Option<String>,
+) -> Result<String, String> {
+    log::info!("Converting Someday Maybe item to project: {}", item_path);
+
+    let item = Path::new(&item_path);
+    if !item.starts_with(Path::new(&space_path).join("Someday Maybe")) {
+        return Err(format!("Not a Someday Maybe item: {}", item_path));
+    }
+    let content = read_file(item_path.clone())?;
+
+    let title = content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# "))
+        .map(str::trim)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            item.file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        });
+    let references_for = |tag: &str| {
+        extract_reference_block(&content, tag)
+            .map(|raw| parse_reference_paths(&raw))
+            .unwrap_or_default()
+    };
+    let references = ProjectReferenceInput {
+        areas: references_for("areas-references"),
+        goals: references_for("goals-references"),
+        vision: references_for("vision-references"),
+        purpose: references_for("purpose-references"),
+        general: references_for("references"),
+    };
+
+    let created = create_gtd_project(
+        space_path,
+        title,
+        someday_notes_for_description(&content),
+        due_date,
+        None,
+        Some(references),
+    )?;
+
+    match delete_file(item_path.clone(), Some(true), None) {
+        Ok(result) if result.success => Ok(created.path),
+        outcome => {
+            let reason = match outcome {
+                Ok(result) => result.message.unwrap_or_default(),
+                Err(error) => error,
+            };
+            // Roll back so the item is not left both deferred and active
+            let _ = fs::remove_dir_all(&created.path);
+            Err(format!(
+                "Failed to remove Someday Maybe item {}: {}",
+                item_path, reason
+            ))
+        }
+    }
+}
+
+/// Why `create_action` did not create an action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum CreateActionError {
+    /// The project already has an action with this name
+    AlreadyExists(String),
+    Failed(String),
+}
+
+impl From<String> for CreateActionError {
+    fn from(error: String) -> Self {
+        CreateActionError::Failed(error)
+    }
+}
+
+impl fmt::Display for CreateActionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CreateActionError::AlreadyExists(name) => {
+                write!(f, "Action '{}' already exists", name)
+            }
+            CreateActionError::Failed(error) => f.write_str(error),
+        }
+    }
+}
+
+/// Create an action file in a project; see `create_gtd_action`.
 #[allow(clippy::too_many_arguments)]
-pub fn create_gtd_action(
+pub(crate) fn create_action(
     project_path: String,
     action_name: String,
     status: String,
@@ -170,7 +370,9 @@ pub fn create_gtd_action(
     effort: String,
     contexts: Option<Vec<String>>,
     notes: Option<String>,
-) -> Result<String, String> {
+    calendar_event_id: Option<String>,
+    custom_fields: Option<HashMap<String, String>>,
+) -> Result<String, CreateActionError> {
     log::info!(
         "Creating GTD action: {} in project: {}",
         action_name,
@@ -178,9 +380,10 @@ pub fn create_gtd_action(
     );
 
     let project_dir = Path::new(&project_path);
+    ensure_space_writable(project_dir)?;
 
     if !project_dir.exists() || !project_dir.is_dir() {
-        return Err("Project directory does not exist".to_string());
+        return Err("Project directory does not exist".to_string().into());
     }
 
     let _projects_root = validate_projects_child_directory(project_dir)?;
@@ -189,26 +392,13 @@ pub fn create_gtd_action(
     let file_name = format!("{}.md", sanitize_markdown_file_stem(&action_name));
     let action_path = project_dir.join(&file_name);
 
-    // Validate status
-    let status_value = status.as_str();
-    let valid_statuses = ["in-progress", "waiting", "completed"];
-    if !valid_statuses.contains(&status_value) {
-        return Err(format!(
-            "Invalid status '{}'. Must be one of: {}",
-            status,
-            valid_statuses.join(", ")
-        ));
-    }
-
-    let effort_value = match effort.as_str() {
-        "Small" | "small" => "small",
-        "Medium" | "medium" => "medium",
-        "Large" | "large" => "large",
-        "Extra Large" | "ExtraLarge" | "extra-large" | "extra_large" => "extra-large",
-        _ => {
-            log::warn!("Unknown effort value '{}', defaulting to 'medium'", effort);
-            "medium"
-        }
+    // Validate status and effort against the space's vocabulary
+    let vocabulary = field_vocabulary_for(project_dir)?;
+    let status_value = vocabulary.validate_status(&status)?;
+    let effort_value = vocabulary.normalize_effort(&effort);
+    let custom_values = match custom_fields {
+        Some(values) => validate_custom_fields(&custom_fields_for(project_dir)?, &values)?,
+        None => Vec::new(),
     };
 
     // Map contexts to normalized values for multiselect
@@ -232,15 +422,19 @@ pub fn create_gtd_action(
     });
 
     // Create action file with template using single select and datetime fields
-    let action_content = generate_action_template(
+    let mut action_content = generate_action_template(
         &action_name,
-        status_value,
+        &status_value,
         focus_date,
         due_date,
-        effort_value,
+        &effort_value,
         contexts_value,
         notes,
+        calendar_event_id.as_deref(),
     );
+    if !custom_values.is_empty() {
+        action_content = write_custom_fields(&action_content, &custom_values);
+    }
 
     match fs::OpenOptions::new()
         .write(true)
@@ -251,23 +445,89 @@ pub fn create_gtd_action(
             if let Err(e) = file.write_all(action_content.as_bytes()) {
                 drop(file);
                 let _ = fs::remove_file(&action_path);
-                return Err(format!("Failed to create action file: {}", e));
+                return Err(format!("Failed to create action file: {}", e).into());
             }
             log::info!("Successfully created action: {}", action_name);
+            sync_action_table_after_change(&action_path);
+            record_file_change(&action_path);
             Ok(action_path.to_string_lossy().to_string())
         }
         Err(e) => {
             if e.kind() == io::ErrorKind::AlreadyExists {
-                Err(format!("Action '{}' already exists", action_name))
+                Err(CreateActionError::AlreadyExists(action_name))
             } else {
-                Err(format!("Failed to create action file: {}", e))
+                Err(format!("Failed to create action file: {}", e).into())
             }
         }
     }
 }
 
+/// Create a new GTD action
+///
+/// Creates a new action (task) file within a project directory.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project directory
+/// * `action_name` - Name of the action
+/// * `status` - Initial status (In Progress / Waiting / Completed)
+/// * `due_date` - Optional due date (ISO format: YYYY-MM-DD)
+/// * `effort` - Effort estimate (Small / Medium / Large / Extra Large)
+/// * `calendar_event_id` - Optional Google Calendar event this action was created from
+/// * `custom_fields` - Optional values for the space's custom fields, written
+///   under a `## Custom` section; rejected values return a JSON-encoded
+///   `CustomFieldError`
+///
+/// # Returns
+///
+/// Path to the created action file or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('create_gtd_action', {
+///   project_path: '/path/to/gtd/space/Projects/Build Website',
+///   action_name: 'Design homepage',
+///   status: 'in-progress',
+///   due_date: '2024-11-15',
+///   focus_date: '2024-11-14T14:30:00',
+///   effort: 'Medium',
+///   custom_fields: { client: 'Acme' }
+/// });
+/// ```
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn create_gtd_action(
+    project_path: String,
+    action_name: String,
+    status: String,
+    due_date: Option<String>,
+    focus_date: Option<String>,
+    effort: String,
+    contexts: Option<Vec<String>>,
+    notes: Option<String>,
+    calendar_event_id: Option<String>,
+    custom_fields: Option<HashMap<String, String>>,
+) -> Result<String, String> {
+    create_action(
+        project_path,
+        action_name,
+        status,
+        due_date,
+        focus_date,
+        effort,
+        contexts,
+        notes,
+        calendar_event_id,
+        custom_fields,
+    )
+    .map_err(|error| error.to_string())
+}
+
 /// GTD Project metadata structure
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GTDProject {
     /// Project name
     pub name: String,
@@ -285,6 +545,10 @@ pub struct GTDProject {
     pub created_date_time: String,
     /// Number of actions in the project
     pub action_count: u32,
+    /// Emoji or icon name from the README's `[!icon:...]` marker
+    pub icon: Option<String>,
+    /// Built-in status implied by the project's actions; `None` without actions
+    pub derived_status: Option<String>,
 }
 
 /// List all GTD projects in a space
@@ -320,6 +584,7 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
     }
 
     let mut projects = Vec::new();
+    let vocabulary = field_vocabulary_or_default(&projects_path);
 
     // Read all directories in Projects folder
     match fs::read_dir(&projects_path) {
@@ -338,14 +603,15 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
                     // Read README.md to extract project metadata
                     let readme_path = resolve_project_readme_path(&path);
 
-                    let (title, description, due_date, status, mut created_date_time) =
+                    let (title, description, due_date, status, mut created_date_time, icon) =
                         if let Some(ref readme_path) = readme_path {
                             match fs::read_to_string(readme_path) {
                                 Ok(content) => {
                                     let (desc, due, stat, created) = parse_project_readme(&content);
                                     // Extract title from README
                                     let readme_title = extract_readme_title(&content);
-                                    (readme_title, desc, due, stat, created)
+                                    let icon = extract_icon(&content);
+                                    (readme_title, desc, due, stat, created, icon)
                                 }
                                 Err(_) => (
                                     folder_name.clone(),
@@ -353,6 +619,7 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
                                     None,
                                     "in-progress".to_string(),
                                     String::new(),
+                                    None,
                                 ),
                             }
                         } else {
@@ -362,6 +629,7 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
                                 None,
                                 "in-progress".to_string(),
                                 String::new(),
+                                None,
                             )
                         };
 
@@ -426,6 +694,7 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
 
                     // Count action files in the project
                     let action_count = count_project_actions(&path);
+                    let derived_status = compute_project_rollup(&path, &vocabulary);
 
                     projects.push(GTDProject {
                         name: if title != folder_name {
@@ -439,6 +708,8 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
                         path: path.to_string_lossy().to_string(),
                         created_date_time,
                         action_count,
+                        icon,
+                        derived_status,
                     });
                 }
             }
@@ -479,8 +750,22 @@ pub fn list_gtd_projects(space_path: String) -> Result<Vec<GTDProject>, String>
 /// ```
 #[tauri::command]
 pub fn rename_gtd_project(
+    app: AppHandle,
     old_project_path: String,
     new_project_name: String,
+) -> Result<String, String> {
+    let new_project_path = rename_project_folder(&old_project_path, &new_project_name)?;
+    emit_remap(
+        &app,
+        &directory_remaps(Path::new(&old_project_path), Path::new(&new_project_path)),
+    );
+    Ok(new_project_path)
+}
+
+/// Rename a project folder and retitle its README, returning the new path.
+pub(crate) fn rename_project_folder(
+    old_project_path: &str,
+    new_project_name: &str,
 ) -> Result<String, String> {
     log::info!(
         "Renaming GTD project from {} to {}",
@@ -488,7 +773,8 @@ pub fn rename_gtd_project(
         new_project_name
     );
 
-    let old_path = Path::new(&old_project_path);
+    let old_path = Path::new(old_project_path);
+    ensure_space_writable(old_path)?;
 
     // Validate old path exists and is a directory
     if !old_path.exists() {
@@ -506,7 +792,7 @@ pub fn rename_gtd_project(
         .parent()
         .ok_or_else(|| "Cannot get parent directory".to_string())?;
 
-    let safe_project_name = validate_project_name(&new_project_name)?;
+    let safe_project_name = validate_project_name(new_project_name)?;
 
     // Create new path with the new name
     let new_path = parent.join(&safe_project_name);
@@ -579,8 +865,19 @@ pub fn rename_gtd_project(
 /// ```
 #[tauri::command]
 pub fn rename_gtd_action(
+    app: AppHandle,
     old_action_path: String,
     new_action_name: String,
+) -> Result<String, String> {
+    let new_action_path = rename_action_file(&old_action_path, &new_action_name)?;
+    emit_remap(&app, &[PathRemap::new(&old_action_path, &new_action_path)]);
+    Ok(new_action_path)
+}
+
+/// Rename an action file and retitle it, returning the new path.
+pub(crate) fn rename_action_file(
+    old_action_path: &str,
+    new_action_name: &str,
 ) -> Result<String, String> {
     log::info!(
         "Renaming GTD action from {} to {}",
@@ -588,7 +885,8 @@ pub fn rename_gtd_action(
         new_action_name
     );
 
-    let old_path = Path::new(&old_action_path);
+    let old_path = Path::new(old_action_path);
+    ensure_space_writable(old_path)?;
 
     // Validate old path exists and is a file
     if !old_path.exists() {
@@ -627,7 +925,7 @@ pub fn rename_gtd_action(
     }
 
     // Preserve the existing file extension when renaming.
-    let sanitized_name = sanitize_markdown_file_stem(&new_action_name);
+    let sanitized_name = sanitize_markdown_file_stem(new_action_name);
     let extension = old_path
         .extension()
         .and_then(|value| value.to_str())
@@ -652,7 +950,7 @@ pub fn rename_gtd_action(
         match fs::read_to_string(old_path) {
             Ok(content) => {
                 // Update the H1 title
-                let updated_content = update_readme_title(&content, &new_action_name);
+                let updated_content = update_readme_title(&content, new_action_name);
 
                 // Write back the updated content
                 if let Err(e) = write_string_atomically(old_path, &updated_content) {
@@ -672,10 +970,12 @@ pub fn rename_gtd_action(
                 if old_file_name != new_file_name {
                     rename_path(old_path, &new_path)
                         .map_err(|e| format!("Failed to rename action file: {}", e))?;
+                    sync_action_table_after_change(&new_path);
                     return Ok(new_path.to_string_lossy().to_string());
                 }
 
                 log::info!("Updated action title in file: {}", old_path.display());
+                sync_action_table_after_change(old_path);
                 return Ok(old_path.to_string_lossy().to_string());
             }
             Err(e) => {
@@ -697,7 +997,7 @@ pub fn rename_gtd_action(
             match fs::read_to_string(&new_path) {
                 Ok(content) => {
                     // Update the H1 title
-                    let updated_content = update_readme_title(&content, &new_action_name);
+                    let updated_content = update_readme_title(&content, new_action_name);
 
                     if let Err(e) = write_string_atomically(&new_path, &updated_content) {
                         log::error!("Failed to update action title: {}", e);
@@ -709,6 +1009,7 @@ pub fn rename_gtd_action(
                     // Don't fail the operation, file is already renamed
                 }
             }
+            sync_action_table_after_change(&new_path);
 
             Ok(new_path.to_string_lossy().to_string())
         }
@@ -719,6 +1020,136 @@ pub fn rename_gtd_action(
     }
 }
 
+/// Rewrite a single datetime field in place, leaving the rest of the file alone.
+///
+/// `None` or a blank value clears the field. Other values must parse the way
+/// marker readers parse them (`YYYY-MM-DD`, local date-time, or RFC 3339).
+pub(crate) fn update_datetime_field(
+    path: &Path,
+    field: &str,
+    value: Option<&str>,
+) -> Result<(), String> {
+    let value = value.map(str::trim).unwrap_or_default();
+    if !value.is_empty() && parse_timestamp(value).is_none() {
+        return Err(format!(
+            "Invalid {} '{}': expected YYYY-MM-DD or a date-time",
+            field, value
+        ));
+    }
+    update_file_field(path, FieldKind::Datetime, field, value)
+}
+
+fn resolve_action_file(action_path: &str) -> Result<&Path, String> {
+    let path = Path::new(action_path);
+    if !path.is_file() {
+        return Err("Action file does not exist".to_string());
+    }
+    let is_readme = path
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem.eq_ignore_ascii_case("README"));
+    if is_readme {
+        return Err("Path is a project README, not an action".to_string());
+    }
+    Ok(path)
+}
+
+/// Set or clear a project's due date
+///
+/// Rewrites only the `[!datetime:due_date:...]` marker in the project README,
+/// avoiding the race of replacing the whole file through `save_file`.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project directory
+/// * `new_due_date` - New due date, or `None` to clear it
+///
+/// # Returns
+///
+/// Unit on success or error message
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_project_due_date', {
+///   projectPath: '/path/to/gtd/Projects/Website Redesign',
+///   newDueDate: '2026-05-01'
+/// });
+/// ```
+#[tauri::command]
+pub fn set_project_due_date(
+    project_path: String,
+    new_due_date: Option<String>,
+) -> Result<(), String> {
+    log::info!("Setting due date for project: {}", project_path);
+
+    let readme_path = resolve_project_readme_path(Path::new(&project_path))
+        .ok_or_else(|| "Project README not found".to_string())?;
+    update_datetime_field(&readme_path, "due_date", new_due_date.as_deref())
+}
+
+/// Set or clear an action's due date
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+/// * `new_due_date` - New due date, or `None` to clear it
+///
+/// # Returns
+///
+/// Unit on success or error message
+///
+/// # Examples
+///
+/// ```typescript
+/// await invoke('set_action_due_date', {
+///   actionPath: '/path/to/gtd/Projects/Website Redesign/Draft copy.md',
+///   newDueDate: null
+/// });
+/// ```
+#[tauri::command]
+pub fn set_action_due_date(
+    action_path: String,
+    new_due_date: Option<String>,
+) -> Result<(), String> {
+    log::info!("Setting due date for action: {}", action_path);
+
+    let path = resolve_action_file(&action_path)?;
+    update_datetime_field(path, "due_date", new_due_date.as_deref())
+}
+
+/// Set or clear an action's focus date
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+/// * `new_focus_date` - New focus date, or `None` to clear it
+///
+/// # Returns
+///
+/// Unit on success or error message
+///
+/// # Examples
+///
+/// ```typescript
+/// await invoke('set_action_focus_date', {
+///   actionPath: '/path/to/gtd/Projects/Website Redesign/Draft copy.md',
+///   newFocusDate: '2026-04-20T09:00:00'
+/// });
+/// ```
+#[tauri::command]
+pub fn set_action_focus_date(
+    action_path: String,
+    new_focus_date: Option<String>,
+) -> Result<(), String> {
+    log::info!("Setting focus date for action: {}", action_path);
+
+    let path = resolve_action_file(&action_path)?;
+    update_datetime_field(path, "focus_date", new_focus_date.as_deref())
+}
+
 fn validate_project_name(name: &str) -> Result<String, String> {
     if name.ends_with(' ') || name.trim_end().ends_with('.') {
         return Err("Project name cannot end with a space or period".to_string());
@@ -793,7 +1224,7 @@ fn validate_project_name(name: &str) -> Result<String, String> {
     Ok(trimmed.to_string())
 }
 
-fn validate_projects_child_directory(path: &Path) -> Result<PathBuf, String> {
+pub(crate) fn validate_projects_child_directory(path: &Path) -> Result<PathBuf, String> {
     let canonical_path =
         fs::canonicalize(path).map_err(|e| format!("Failed to resolve path: {}", e))?;
     let projects_dir = canonical_path
@@ -928,7 +1359,7 @@ fn rename_path(old_path: &Path, new_path: &Path) -> Result<(), std::io::Error> {
 }
 
 /// Extract the H1 title from README content
-fn extract_readme_title(content: &str) -> String {
+pub(crate) fn extract_readme_title(content: &str) -> String {
     for line in content.lines() {
         let trimmed = line.trim();
         if let Some(stripped) = trimmed.strip_prefix("# ") {
@@ -940,36 +1371,43 @@ fn extract_readme_title(content: &str) -> String {
 }
 
 /// Parse project README.md to extract metadata
-fn parse_project_readme(content: &str) -> (String, Option<String>, String, String) {
+pub(crate) fn parse_project_readme(content: &str) -> (String, Option<String>, String, String) {
     let mut description = "No description available".to_string();
     let mut due_date = None;
     let mut status = "in-progress".to_string();
     let mut created_date_time = String::new();
 
     let lines: Vec<&str> = content.lines().collect();
-    let mut current_section = "";
+    let sections = list_sections(content);
 
-    for line in lines {
-        let trimmed = line.trim();
-
-        // Detect section headers
-        if trimmed.starts_with("## Desired Outcome") || trimmed.starts_with("## Description") {
-            current_section = "description";
-        } else if trimmed.starts_with("## Due Date") {
-            current_section = "due_date";
-        } else if trimmed.starts_with("## Status") {
-            current_section = "status";
-        } else if trimmed.starts_with("## Created") {
-            current_section = "created";
-        } else if trimmed.starts_with("##") {
-            current_section = "";
-        } else if !trimmed.is_empty() && !trimmed.starts_with('#') {
-            // Parse content based on current section
-            match current_section {
-                "description" => {
-                    if description == "No description available" {
-                        description = trimmed.to_string();
-                    }
+    for (index, section) in sections.iter().enumerate() {
+        if section.level != 2 {
+            continue;
+        }
+        let field = match section.title.as_str() {
+            title if title.starts_with("Desired Outcome") || title.starts_with("Description") => {
+                "description"
+            }
+            title if title.starts_with("Due Date") => "due_date",
+            title if title.starts_with("Status") => "status",
+            title if title.starts_with("Created") => "created",
+            _ => continue,
+        };
+
+        // Only the section's own lines, not those of nested subsections
+        let own_end = sections.get(index + 1).map_or(section.end_line, |next| {
+            next.start_line.min(section.end_line)
+        });
+        let own_lines = lines[section_body_start(&lines, section).min(own_end)..own_end]
+            .iter()
+            .map(|line| line.trim())
+            .filter(|trimmed| !trimmed.is_empty());
+
+        for trimmed in own_lines {
+            // Parse content based on the section it belongs to
+            match field {
+                "description" if description == "No description available" => {
+                    description = trimmed.to_string();
                 }
                 "due_date" => {
                     // Parse datetime syntax [!datetime:due_date:value]
@@ -979,7 +1417,7 @@ fn parse_project_readme(content: &str) -> (String, Option<String>, String, Strin
                                 due_date = Some(value.to_string());
                             }
                         }
-                    } else if trimmed != "Not set" && !trimmed.is_empty() {
+                    } else if trimmed != "Not set" {
                         // Fallback to raw text for backward compatibility
                         due_date = Some(trimmed.to_string());
                     }
@@ -1011,14 +1449,12 @@ fn parse_project_readme(content: &str) -> (String, Option<String>, String, Strin
                         status = trimmed.to_string();
                     }
                 }
-                "created" => {
-                    if trimmed.starts_with("[!datetime:created_date_time:") {
-                        if let Some(value) =
-                            extract_marker_value(trimmed, "[!datetime:created_date_time:")
-                        {
-                            if !value.is_empty() {
-                                created_date_time = value.to_string();
-                            }
+                "created" if trimmed.starts_with("[!datetime:created_date_time:") => {
+                    if let Some(value) =
+                        extract_marker_value(trimmed, "[!datetime:created_date_time:")
+                    {
+                        if !value.is_empty() {
+                            created_date_time = value.to_string();
                         }
                     }
                 }
@@ -1073,7 +1509,127 @@ fn count_project_actions(project_path: &Path) -> u32 {
 
 #[cfg(test)]
 mod tests {
-    use super::validate_project_name;
+    use super::{
+        convert_someday_maybe_to_project, create_gtd_project, set_project_due_date,
+        validate_project_name, ProjectReferenceInput,
+    };
+    use crate::commands::fields::{set_field, FieldKind};
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+    use std::fs;
+
+    #[test]
+    fn create_gtd_project_writes_valid_references_and_reports_dropped() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+
+        let created = create_gtd_project(
+            root.to_string_lossy().to_string(),
+            "Beta".to_string(),
+            "Ship beta".to_string(),
+            None,
+            None,
+            Some(ProjectReferenceInput {
+                goals: vec!["Goals/Fitness.md".to_string()],
+                vision: vec!["Vision/Missing.md".to_string()],
+                ..Default::default()
+            }),
+        )?;
+        assert_eq!(created.dropped_references, vec!["Vision/Missing.md"]);
+
+        let content =
+            fs::read_to_string(root.join("Projects/Beta/README.md")).map_err(|e| e.to_string())?;
+        let goal = root
+            .join("Goals/Fitness.md")
+            .to_string_lossy()
+            .replace('\\', "/");
+        let encoded = urlencoding::encode(&format!("[\"{}\"]", goal)).into_owned();
+        assert!(content.contains(&format!("[!goals-references:{}]", encoded)));
+        assert!(content.contains("[!vision-references:]"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn someday_item_converts_to_project_with_notes_and_references() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let item = root.join("Someday Maybe/Learn Spanish.md");
+        write_test_file(
+            &item,
+            "# Learn Spanish\n\n**Why**: Travel\n\n## When I'm ready\n- [ ] Pick a tutor\n\n## References\n[!goals-references:Goals/Fitness.md]\n\n## Created\n[!datetime:created_date_time:2026-01-01]\n",
+        )?;
+        let space_path = root.to_string_lossy().to_string();
+
+        let project = convert_someday_maybe_to_project(
+            item.to_string_lossy().to_string(),
+            space_path.clone(),
+            Some("2026-12-31".to_string()),
+        )?;
+        assert!(project.ends_with("Projects/Learn Spanish"));
+        assert!(!item.exists());
+
+        let readme = fs::read_to_string(root.join("Projects/Learn Spanish/README.md"))
+            .map_err(|e| e.to_string())?;
+        assert!(readme.contains(
+            "## Desired Outcome\n**Why**: Travel\n\n### When I'm ready\n- [ ] Pick a tutor\n\n## Horizon"
+        ));
+        assert!(readme.contains("[!datetime:due_date:2026-12-31]"));
+        assert!(readme.contains("Fitness.md"));
+        assert!(!readme.contains("### Created"));
+
+        // A second item with the same title collides with the new project
+        write_test_file(&item, "# Learn Spanish\n")?;
+        let error =
+            convert_someday_maybe_to_project(item.to_string_lossy().to_string(), space_path, None)
+                .expect_err("duplicate project name should be rejected");
+        assert!(error.contains("already exists"));
+        assert!(item.exists());
+
+        Ok(())
+    }
+
+    #[test]
+    fn set_project_due_date_only_touches_the_due_date_marker() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        let readme = project.join("README.md");
+        write_test_file(
+            &readme,
+            "# Alpha\n\n## Due Date\n[!datetime:due_date:2026-04-01]\n\n## Notes\nSee [!datetime:due_date:x] docs\n",
+        )?;
+
+        set_project_due_date(
+            project.to_string_lossy().to_string(),
+            Some("2026-05-15".to_string()),
+        )?;
+        let content = fs::read_to_string(&readme).map_err(|e| e.to_string())?;
+        assert_eq!(
+            content,
+            "# Alpha\n\n## Due Date\n[!datetime:due_date:2026-05-15]\n\n## Notes\nSee [!datetime:due_date:x] docs\n"
+        );
+
+        let before = fs::read_to_string(&readme).map_err(|e| e.to_string())?;
+        for invalid in ["next week", "2026-13-40", "05/15/2026"] {
+            assert!(set_project_due_date(
+                project.to_string_lossy().to_string(),
+                Some(invalid.to_string()),
+            )
+            .is_err());
+        }
+        assert_eq!(
+            fs::read_to_string(&readme).map_err(|e| e.to_string())?,
+            before
+        );
+
+        set_project_due_date(project.to_string_lossy().to_string(), None)?;
+        let content = fs::read_to_string(&readme).map_err(|e| e.to_string())?;
+        assert!(content.starts_with("# Alpha\n\n## Due Date\n[!datetime:due_date:]\n"));
+
+        assert!(set_field("# Empty\n", FieldKind::Datetime, "focus_date", "").is_err());
+        assert!(set_field(&content, FieldKind::Datetime, "due_date", "2026]").is_err());
+
+        Ok(())
+    }
 
     #[test]
     fn validate_project_name_rejects_windows_invalid_characters() {
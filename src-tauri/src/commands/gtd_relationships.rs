@@ -1,9 +1,13 @@
 //! GTD relationship lookup commands.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use super::filesystem::scan_directory_recursive;
+use super::gtd_projects::{list_gtd_projects, resolve_project_readme_path, GTDProject};
+
 const MARKDOWN_EXTENSIONS: [&str; 2] = ["md", "markdown"];
 
 fn redact_path(path: &str) -> String {
@@ -70,7 +74,7 @@ fn strip_project_readme_suffix(path: &str) -> Option<String> {
         .find_map(|suffix| path.strip_suffix(suffix).map(|value| value.to_string()))
 }
 
-fn extract_reference_block(content: &str, tag: &str) -> Option<String> {
+pub(crate) fn extract_reference_block(content: &str, tag: &str) -> Option<String> {
     let marker = format!("[!{}:", tag);
     let start_idx = content.find(&marker)?;
     let value_start = start_idx + marker.len();
@@ -110,7 +114,7 @@ fn decode_reference_block(raw: &str) -> String {
     decoded
 }
 
-fn parse_reference_paths(raw: &str) -> Vec<String> {
+pub(crate) fn parse_reference_paths(raw: &str) -> Vec<String> {
     let decoded = decode_reference_block(raw);
 
     if decoded.starts_with('[') && decoded.ends_with(']') {
@@ -147,6 +151,102 @@ fn normalize_reference_target(path: &str) -> String {
     normalized
 }
 
+/// How a reference field stores its targets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ReferenceForm {
+    /// Project folder (`Projects/Name`), never its README
+    Folder,
+    /// Markdown file
+    File,
+}
+
+/// A newly created item plus the references dropped because they did not resolve
+#[derive(Debug, Clone, Serialize)]
+pub struct CreatedWithReferences {
+    /// Full path to the created file or folder
+    pub path: String,
+    /// References, as given, whose target was missing or outside the space
+    pub dropped_references: Vec<String>,
+}
+
+/// Resolve a user-supplied reference to the form stored in `form` fields.
+///
+/// Relative paths are taken from the space root. A folder with a README and
+/// the README itself both resolve to whichever form the field stores.
+fn resolve_reference_input(
+    space_root: &Path,
+    canonical_root: &Path,
+    raw: &str,
+    form: ReferenceForm,
+) -> Option<String> {
+    let raw = raw.trim().replace('\\', "/");
+    if raw.is_empty() {
+        return None;
+    }
+    let given = Path::new(&raw);
+    let target = if given.is_absolute() {
+        given.to_path_buf()
+    } else {
+        space_root.join(given)
+    };
+    if !target.canonicalize().ok()?.starts_with(canonical_root) {
+        return None;
+    }
+
+    let resolved = if target.is_dir() {
+        let readme = find_readme_file(&target)?;
+        match form {
+            ReferenceForm::Folder => target,
+            ReferenceForm::File => readme,
+        }
+    } else if target.is_file() && is_markdown_file(&target) {
+        let is_readme = target
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem.eq_ignore_ascii_case("README"));
+        match (form, target.parent()) {
+            (ReferenceForm::Folder, Some(parent)) if is_readme => parent.to_path_buf(),
+            (ReferenceForm::Folder, _) => return None,
+            (ReferenceForm::File, _) => target,
+        }
+    } else {
+        return None;
+    };
+
+    Some(resolved.to_string_lossy().replace('\\', "/"))
+}
+
+/// Validate references and render the survivors as a reference field value.
+///
+/// Valid targets are de-duplicated and stored as a URL-encoded JSON array;
+/// the rest are appended to `dropped` as given.
+pub(crate) fn render_validated_references(
+    space_root: &Path,
+    references: &[String],
+    form: ReferenceForm,
+    dropped: &mut Vec<String>,
+) -> String {
+    let canonical_root = space_root
+        .canonicalize()
+        .unwrap_or_else(|_| space_root.to_path_buf());
+
+    let mut resolved: Vec<String> = Vec::new();
+    for reference in references.iter().filter(|value| !value.trim().is_empty()) {
+        match resolve_reference_input(space_root, &canonical_root, reference, form) {
+            Some(path) if !resolved.contains(&path) => resolved.push(path),
+            Some(_) => {}
+            None => dropped.push(reference.clone()),
+        }
+    }
+
+    if resolved.is_empty() {
+        return String::new();
+    }
+    serde_json::to_string(&resolved)
+        .map(|json| urlencoding::encode(&json).into_owned())
+        .unwrap_or_default()
+}
+
 /// Find files that reference a target file (reverse relationships)
 ///
 /// Searches through GTD horizon files to find which ones reference the target file.
@@ -178,7 +278,7 @@ pub fn find_reverse_relationships(
 
     // Normalize the target path for comparison - handle both absolute and relative paths
     let target_normalized = normalize_reference_target(&target_path);
-    log::debug!("Target normalized: {}", target_normalized);
+    log::debug!("Target normalized: {}", redact_path(&target_normalized));
 
     // Determine which directories to search based on filter type
     let search_dirs = match filter_type.as_str() {
@@ -371,7 +471,7 @@ pub fn find_reverse_relationships(
     }
 
     log::debug!("=== find_reverse_relationships END ===");
-    log::debug!("Found {} files referencing the target", relationships.len());
+    log::info!("Found {} files referencing the target", relationships.len());
     for rel in &relationships {
         log::debug!("  - {} ({})", rel.file_name, rel.file_type);
     }
@@ -419,7 +519,7 @@ pub fn find_habits_referencing(
 
     // Normalize the target path for comparison
     let target_normalized = normalize_reference_target(&target_path);
-    log::debug!("Target normalized: {}", target_normalized);
+    log::debug!("Target normalized: {}", redact_path(&target_normalized));
 
     // For project README files, also check against the project folder path
     let alt_target =
@@ -541,3 +641,628 @@ pub struct HabitReference {
     pub status: String,
     pub frequency: String,
 }
+
+/// One reference field found in a workspace file
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReferenceEntry {
+    /// File containing the reference field
+    pub source_file: String,
+    /// Field tag (e.g. "areas-references")
+    pub reference_type: String,
+    /// Paths listed in the field, as written
+    pub referenced_paths: Vec<String>,
+    /// False when any listed path is missing on disk
+    pub all_targets_exist: bool,
+}
+
+/// Reference field tags in `content`, in order of first appearance.
+pub(crate) fn reference_tags_in(content: &str) -> Vec<&str> {
+    let mut tags = Vec::new();
+    for (idx, _) in content.match_indices("[!") {
+        let rest = &content[idx + 2..];
+        let Some(colon) = rest.find(':') else {
+            continue;
+        };
+        let tag = &rest[..colon];
+        let is_reference_tag = tag == "references"
+            || tag.strip_suffix("-references").is_some_and(|prefix| {
+                !prefix.is_empty() && prefix.chars().all(|c| c.is_ascii_lowercase() || c == '-')
+            });
+        if is_reference_tag && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+fn reference_target_exists(space_root: &Path, reference: &str) -> bool {
+    let target = Path::new(reference);
+    if target.is_absolute() {
+        target.exists()
+    } else {
+        space_root.join(target).exists()
+    }
+}
+
+/// List every reference field in the workspace (forward relationships)
+///
+/// Scans all markdown files for `[!*-references:...]` and `[!references:...]`
+/// fields and returns one entry per field, sorted by source file. This is the
+/// forward complement to `find_reverse_relationships`.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Reference entries grouped by source file, with missing targets flagged
+#[tauri::command]
+pub fn list_all_references(space_path: String) -> Result<Vec<ReferenceEntry>, String> {
+    log::debug!("Listing all references in: {}", redact_path(&space_path));
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let mut files = Vec::new();
+    scan_directory_recursive(space_root, &mut files)?;
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut entries = Vec::new();
+    for file in files {
+        let Ok(content) = fs::read_to_string(&file.path) else {
+            continue;
+        };
+
+        for tag in reference_tags_in(&content) {
+            let Some(block) = extract_reference_block(&content, tag) else {
+                continue;
+            };
+            let referenced_paths = parse_reference_paths(&block);
+            if referenced_paths.is_empty() {
+                continue;
+            }
+
+            let all_targets_exist = referenced_paths
+                .iter()
+                .all(|path| reference_target_exists(space_root, path));
+            entries.push(ReferenceEntry {
+                source_file: file.path.clone(),
+                reference_type: tag.to_string(),
+                referenced_paths,
+                all_targets_exist,
+            });
+        }
+    }
+
+    log::debug!("Found {} reference fields", entries.len());
+    Ok(entries)
+}
+
+/// Projects whose README lists `target_path` in its `tag` reference field.
+fn projects_referencing(
+    space_path: String,
+    target_path: &str,
+    tag: &str,
+) -> Result<Vec<GTDProject>, String> {
+    let target_normalized = normalize_reference_target(target_path);
+    let projects = list_gtd_projects(space_path)?;
+
+    Ok(projects
+        .into_iter()
+        .filter(|project| {
+            resolve_project_readme_path(Path::new(&project.path))
+                .and_then(|readme| fs::read_to_string(readme).ok())
+                .and_then(|content| extract_reference_block(&content, tag))
+                .is_some_and(|block| {
+                    parse_reference_paths(&block)
+                        .iter()
+                        .any(|path| normalize_reference_target(path) == target_normalized)
+                })
+        })
+        .collect())
+}
+
+/// List projects linked to an area of focus
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `area_path` - Path to the area file, as stored in `areas-references`
+///
+/// # Returns
+///
+/// Projects (sorted by name) whose README references the area
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const projects = await invoke<GTDProject[]>('get_projects_by_area', {
+///   spacePath: '/path/to/space',
+///   areaPath: '/path/to/space/Areas of Focus/Health.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_projects_by_area(
+    space_path: String,
+    area_path: String,
+) -> Result<Vec<GTDProject>, String> {
+    log::debug!("Listing projects for area: {}", redact_path(&area_path));
+    projects_referencing(space_path, &area_path, "areas-references")
+}
+
+/// List projects linked to a goal
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `goal_path` - Path to the goal file, as stored in `goals-references`
+///
+/// # Returns
+///
+/// Projects (sorted by name) whose README references the goal
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const projects = await invoke<GTDProject[]>('get_projects_by_goal', {
+///   spacePath: '/path/to/space',
+///   goalPath: '/path/to/space/Goals/Run a marathon.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_projects_by_goal(
+    space_path: String,
+    goal_path: String,
+) -> Result<Vec<GTDProject>, String> {
+    log::debug!("Listing projects for goal: {}", redact_path(&goal_path));
+    projects_referencing(space_path, &goal_path, "goals-references")
+}
+
+/// List projects linked to a vision
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `vision_path` - Path to the vision file, as stored in `vision-references`
+///
+/// # Returns
+///
+/// Projects (sorted by name) whose README references the vision
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const projects = await invoke<GTDProject[]>('get_projects_by_vision', {
+///   spacePath: '/path/to/space',
+///   visionPath: '/path/to/space/Vision/Three Years.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_projects_by_vision(
+    space_path: String,
+    vision_path: String,
+) -> Result<Vec<GTDProject>, String> {
+    log::debug!("Listing projects for vision: {}", redact_path(&vision_path));
+    projects_referencing(space_path, &vision_path, "vision-references")
+}
+
+/// A file related to another through reference fields
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RelatedFile {
+    pub path: String,
+    pub name: String,
+    /// Reference targets both files list
+    pub shared_reference_count: u32,
+    /// `parent` (the file references it), `child` (it references the file),
+    /// or `sibling` (shared references only)
+    pub relationship_type: String,
+}
+
+/// Comparable key for a reference target: absolute, `/`-separated, README
+/// paths collapsed to their folder.
+pub(crate) fn reference_key(space_root: &Path, raw: &str) -> String {
+    let raw = raw.trim().replace('\\', "/");
+    let given = Path::new(&raw);
+    let absolute = if given.is_absolute() {
+        given.to_path_buf()
+    } else {
+        space_root.join(given)
+    };
+    normalize_reference_target(&absolute.to_string_lossy().replace('\\', "/"))
+}
+
+/// Display name matching `find_reverse_relationships`: folder name for READMEs.
+fn related_file_name(path: &Path) -> String {
+    let is_readme = matches!(
+        path.file_name().and_then(|n| n.to_str()),
+        Some("README.md" | "README.markdown")
+    );
+    let named = if is_readme { path.parent() } else { Some(path) };
+    named
+        .and_then(|p| p.file_name())
+        .and_then(|n| n.to_str())
+        .unwrap_or("Unknown")
+        .to_string()
+}
+
+/// Find files related to a file through shared references
+///
+/// Files the target references are `parent`s and files referencing the
+/// target are `child`ren; both are always returned. Other files are
+/// `sibling`s when they share at least `min_shared_refs` reference targets
+/// (minimum 1) with the target.
+///
+/// # Arguments
+///
+/// * `file_path` - Path to the file to find relations for
+/// * `space_path` - Root path of the GTD space
+/// * `min_shared_refs` - Shared references required for a sibling
+///
+/// # Returns
+///
+/// Related files: parents, then children, then siblings, each ordered by
+/// shared reference count (descending) and path
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const related = await invoke<RelatedFile[]>('get_related_files', {
+///   filePath: '/path/to/space/Projects/Website Redesign/README.md',
+///   spacePath: '/path/to/space',
+///   minSharedRefs: 1,
+/// });
+/// ```
+#[tauri::command]
+pub fn get_related_files(
+    file_path: String,
+    space_path: String,
+    min_shared_refs: usize,
+) -> Result<Vec<RelatedFile>, String> {
+    log::debug!("Finding files related to: {}", redact_path(&file_path));
+
+    let space_root = Path::new(&space_path);
+    let target_key = reference_key(space_root, &file_path);
+
+    // Forward index: source key -> (source path, referenced keys)
+    let mut forward: BTreeMap<String, (String, BTreeSet<String>)> = BTreeMap::new();
+    for entry in list_all_references(space_path.clone())? {
+        let source_key = reference_key(space_root, &entry.source_file);
+        let (_, targets) = forward
+            .entry(source_key)
+            .or_insert_with(|| (entry.source_file.clone(), BTreeSet::new()));
+        targets.extend(
+            entry
+                .referenced_paths
+                .iter()
+                .map(|path| reference_key(space_root, path)),
+        );
+    }
+    let target_refs = forward
+        .get(&target_key)
+        .map(|(_, refs)| refs.clone())
+        .unwrap_or_default();
+    let shared_with = |key: &str| -> u32 {
+        forward.get(key).map_or(0, |(_, refs)| {
+            refs.intersection(&target_refs).count() as u32
+        })
+    };
+
+    let mut related: BTreeMap<String, RelatedFile> = BTreeMap::new();
+
+    for parent_key in &target_refs {
+        let parent = Path::new(parent_key);
+        let parent_file = if parent.is_dir() {
+            find_readme_file(parent)
+        } else {
+            parent.is_file().then(|| parent.to_path_buf())
+        };
+        let Some(parent_file) = parent_file else {
+            continue;
+        };
+        if *parent_key == target_key {
+            continue;
+        }
+        related.insert(
+            parent_key.clone(),
+            RelatedFile {
+                path: parent_file.to_string_lossy().to_string(),
+                name: related_file_name(&parent_file),
+                shared_reference_count: shared_with(parent_key),
+                relationship_type: "parent".to_string(),
+            },
+        );
+    }
+
+    let mut children: Vec<(String, String, String)> =
+        find_reverse_relationships(file_path.clone(), space_path.clone(), "all".to_string())?
+            .into_iter()
+            .map(|rel| {
+                (
+                    reference_key(space_root, &rel.file_path),
+                    rel.file_path,
+                    rel.file_name,
+                )
+            })
+            .collect();
+    children.extend(
+        forward
+            .iter()
+            .filter(|(_, (_, refs))| refs.contains(&target_key))
+            .map(|(key, (path, _))| {
+                (
+                    key.clone(),
+                    path.clone(),
+                    related_file_name(Path::new(path)),
+                )
+            }),
+    );
+    for (child_key, path, name) in children {
+        if child_key == target_key || related.contains_key(&child_key) {
+            continue;
+        }
+        let shared_reference_count = shared_with(&child_key);
+        related.insert(
+            child_key,
+            RelatedFile {
+                path,
+                name,
+                shared_reference_count,
+                relationship_type: "child".to_string(),
+            },
+        );
+    }
+
+    let min_shared = min_shared_refs.max(1);
+    for (key, (path, _)) in &forward {
+        if *key == target_key || related.contains_key(key) {
+            continue;
+        }
+        let shared_reference_count = shared_with(key);
+        if (shared_reference_count as usize) < min_shared {
+            continue;
+        }
+        related.insert(
+            key.clone(),
+            RelatedFile {
+                path: path.clone(),
+                name: related_file_name(Path::new(path)),
+                shared_reference_count,
+                relationship_type: "sibling".to_string(),
+            },
+        );
+    }
+
+    let rank = |relationship: &str| match relationship {
+        "parent" => 0,
+        "child" => 1,
+        _ => 2,
+    };
+    let mut related: Vec<RelatedFile> = related.into_values().collect();
+    related.sort_by(|a, b| {
+        rank(&a.relationship_type)
+            .cmp(&rank(&b.relationship_type))
+            .then(b.shared_reference_count.cmp(&a.shared_reference_count))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    log::debug!("Found {} related files", related.len());
+    Ok(related)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::seed_data::{generate_project_readme_with_refs, ProjectReadmeParams};
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn project_readme(name: &str, areas_refs: &str, goals_refs: &str) -> String {
+        generate_project_readme_with_refs(ProjectReadmeParams {
+            name,
+            description: "Linked project",
+            due_date: None,
+            focus_date: None,
+            status: "in-progress",
+            areas_refs,
+            goals_refs,
+            vision_refs: "",
+            purpose_refs: "",
+            general_refs: "",
+        })
+    }
+
+    #[test]
+    fn projects_by_horizon_match_only_their_reference_field() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let space_path = root.to_string_lossy().to_string();
+        let health = root.join("Areas of Focus/Health.md");
+        let fitness = root.join("Goals/Fitness.md");
+        let health_path = health.to_string_lossy().to_string();
+        let fitness_path = fitness.to_string_lossy().to_string();
+        write_test_file(&health, "# Health\n")?;
+
+        write_test_file(
+            root.join("Projects/Gym Plan/README.md"),
+            &project_readme("Gym Plan", &health_path, &fitness_path),
+        )?;
+        write_test_file(
+            root.join("Projects/Race Prep/README.md"),
+            &project_readme("Race Prep", "", &fitness_path),
+        )?;
+        // Referencing the goal from the wrong field does not count as an area link
+        write_test_file(
+            root.join("Projects/Meal Prep/README.md"),
+            &project_readme("Meal Prep", &fitness_path, ""),
+        )?;
+
+        let by_area = get_projects_by_area(space_path.clone(), health_path.clone())?;
+        assert_eq!(
+            by_area.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["Gym Plan"]
+        );
+        assert_eq!(by_area[0].status, "in-progress");
+
+        let by_goal = get_projects_by_goal(space_path.clone(), fitness_path)?;
+        assert_eq!(
+            by_goal.iter().map(|p| p.name.as_str()).collect::<Vec<_>>(),
+            vec!["Gym Plan", "Race Prep"]
+        );
+
+        let vision_path = root.join("Vision/Someday.md").to_string_lossy().to_string();
+        assert!(get_projects_by_vision(space_path, vision_path)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn related_files_classify_parents_children_and_siblings() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let space_path = root.to_string_lossy().to_string();
+        let health = root.join("Areas of Focus/Health.md");
+        let fitness = root.join("Goals/Fitness.md");
+        let health_path = health.to_string_lossy().to_string();
+        let fitness_path = fitness.to_string_lossy().to_string();
+        write_test_file(&health, "# Health\n")?;
+        write_test_file(
+            &fitness,
+            "# Fitness\n\n[!areas-references:Areas of Focus/Health.md]\n",
+        )?;
+
+        let gym = root.join("Projects/Gym Plan/README.md");
+        write_test_file(
+            &gym,
+            &project_readme("Gym Plan", &health_path, &fitness_path),
+        )?;
+        write_test_file(
+            root.join("Projects/Race Prep/README.md"),
+            &project_readme("Race Prep", &health_path, &fitness_path),
+        )?;
+        write_test_file(
+            root.join("Projects/Meal Prep/README.md"),
+            &project_readme("Meal Prep", &health_path, ""),
+        )?;
+        write_test_file(
+            root.join("Cabinet/Gym Notes.md"),
+            "# Gym Notes\n\n[!references:Projects/Gym Plan]\n",
+        )?;
+
+        let summarize = |related: Vec<RelatedFile>| -> Vec<(String, String, u32)> {
+            related
+                .into_iter()
+                .map(|file| {
+                    (
+                        file.name,
+                        file.relationship_type,
+                        file.shared_reference_count,
+                    )
+                })
+                .collect()
+        };
+        let gym_path = gym.to_string_lossy().to_string();
+        assert_eq!(
+            summarize(get_related_files(gym_path.clone(), space_path.clone(), 1)?),
+            vec![
+                ("Fitness.md".to_string(), "parent".to_string(), 1),
+                ("Health.md".to_string(), "parent".to_string(), 0),
+                ("Gym Notes.md".to_string(), "child".to_string(), 0),
+                ("Race Prep".to_string(), "sibling".to_string(), 2),
+                ("Meal Prep".to_string(), "sibling".to_string(), 1),
+            ]
+        );
+        assert_eq!(
+            summarize(get_related_files(gym_path, space_path.clone(), 2)?)
+                .into_iter()
+                .filter(|(_, kind, _)| kind == "sibling")
+                .map(|(name, _, _)| name)
+                .collect::<Vec<_>>(),
+            vec!["Race Prep"]
+        );
+
+        let children = get_related_files(health_path, space_path, 1)?;
+        assert!(children
+            .iter()
+            .all(|file| file.relationship_type == "child"));
+        assert_eq!(children.len(), 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn list_all_references_flags_missing_targets() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            root.join("Goals/Marathon.md"),
+            "# Marathon\n\n[!areas-references:]\n[!projects-references:Projects/Alpha Project/README.md]\n[!vision-references:%5B%22Vision%2FMissing.md%22%5D]\n",
+        )?;
+
+        let entries = list_all_references(root.to_string_lossy().to_string())?;
+        let marathon: Vec<&ReferenceEntry> = entries
+            .iter()
+            .filter(|entry| entry.source_file.ends_with("Marathon.md"))
+            .collect();
+
+        assert_eq!(marathon.len(), 2);
+        assert_eq!(marathon[0].reference_type, "projects-references");
+        assert!(marathon[0].all_targets_exist);
+        assert_eq!(marathon[1].reference_type, "vision-references");
+        assert_eq!(marathon[1].referenced_paths, vec!["Vision/Missing.md"]);
+        assert!(!marathon[1].all_targets_exist);
+
+        Ok(())
+    }
+
+    #[test]
+    fn render_validated_references_resolves_forms_and_drops_missing() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let goal = root.join("Goals/Fitness.md");
+        let decode = |token: &str| parse_reference_paths(token);
+        let slash = |path: &Path| path.to_string_lossy().replace('\\', "/");
+
+        let mut dropped = Vec::new();
+        let projects = render_validated_references(
+            root,
+            &[
+                "Projects/Alpha Project/README.md".to_string(),
+                slash(&project),
+                "Projects/Missing".to_string(),
+                "Goals/Fitness.md".to_string(),
+            ],
+            ReferenceForm::Folder,
+            &mut dropped,
+        );
+        assert_eq!(decode(&projects), vec![slash(&project)]);
+        assert_eq!(dropped, vec!["Projects/Missing", "Goals/Fitness.md"]);
+
+        dropped.clear();
+        let goals = render_validated_references(
+            root,
+            &[
+                "Goals/Fitness.md".to_string(),
+                " ".to_string(),
+                "../outside.md".to_string(),
+            ],
+            ReferenceForm::File,
+            &mut dropped,
+        );
+        assert!(goals.starts_with("%5B%22"));
+        assert_eq!(decode(&goals), vec![slash(&goal)]);
+        assert_eq!(dropped, vec!["../outside.md"]);
+
+        Ok(())
+    }
+}
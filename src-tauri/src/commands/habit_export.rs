@@ -0,0 +1,282 @@
+//! Habit history as JSON for external dashboards.
+//!
+//! The document carries a `schema_version` so consumers can tell when the
+//! shape changes; bump [`HABIT_EXPORT_SCHEMA_VERSION`] whenever a field is
+//! renamed, removed, or changes meaning. Timestamps are RFC 3339 with the
+//! local UTC offset, since history rows are written in local time.
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::fields::{get_field, FieldKind};
+use super::file_meta::parse_timestamp;
+use super::gtd_habits_domain::{
+    habit_history_archive_path, is_habit_history_archive, parse_archived_history_rows,
+    parse_habit_state, parse_history_rows, HabitStatus,
+};
+use super::gtd_projects::write_string_atomically;
+
+/// Version of the [`HabitDataExport`] layout
+pub(crate) const HABIT_EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// One history row
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HabitEntry {
+    /// RFC 3339 local time of the row
+    pub date: String,
+    /// The row records a completion rather than a reset to To Do
+    pub completed: bool,
+    /// What wrote the row, e.g. `Manual` or `Auto-Reset`
+    pub action: String,
+}
+
+/// A habit and its history, oldest row first
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HabitData {
+    /// File name without extension
+    pub name: String,
+    /// Full path to the habit file
+    pub path: String,
+    /// Frequency marker token, e.g. `daily` or `weekly`
+    pub frequency: String,
+    /// RFC 3339 local time from the `created_date_time` marker
+    pub created_at: Option<String>,
+    /// Rows from the habit and its history archive
+    pub entries: Vec<HabitEntry>,
+}
+
+/// Every habit in a space with its history
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HabitDataExport {
+    pub schema_version: u32,
+    /// RFC 3339 local time the export was made
+    pub exported_at: String,
+    /// Lower bound applied to entries, as given
+    pub since: Option<String>,
+    pub habits: Vec<HabitData>,
+}
+
+fn local_rfc3339(naive: NaiveDateTime) -> Option<String> {
+    Local
+        .from_local_datetime(&naive)
+        .earliest()
+        .map(|time| time.to_rfc3339())
+}
+
+/// Marker value in local time; values without an offset already are.
+fn created_at(content: &str) -> Option<String> {
+    let raw = get_field(content, FieldKind::Datetime, "created_date_time")?;
+    let raw = raw.trim();
+    if let Ok(time) = DateTime::parse_from_rfc3339(raw) {
+        return Some(time.with_timezone(&Local).to_rfc3339());
+    }
+    ["%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(raw, format).ok())
+        .and_then(local_rfc3339)
+}
+
+fn read_habit(path: &Path, since: Option<i64>) -> Option<HabitData> {
+    let content = fs::read_to_string(path).ok()?;
+    let state = parse_habit_state(&content).ok()?;
+
+    let archive_path = habit_history_archive_path(path);
+    let mut rows = fs::read_to_string(&archive_path)
+        .map(|archive| parse_archived_history_rows(&archive))
+        .unwrap_or_default();
+    rows.extend(parse_history_rows(&content));
+    rows.sort_by_key(|row| row.timestamp);
+
+    let entries = rows
+        .into_iter()
+        .filter_map(|row| {
+            let local = Local.from_local_datetime(&row.timestamp).earliest()?;
+            if since.is_some_and(|since| local.timestamp() < since) {
+                return None;
+            }
+            Some(HabitEntry {
+                date: local.to_rfc3339(),
+                completed: row.status == HabitStatus::Completed.history_label(),
+                action: row.action,
+            })
+        })
+        .collect();
+
+    Some(HabitData {
+        name: path.file_stem()?.to_string_lossy().to_string(),
+        path: path.to_string_lossy().to_string(),
+        frequency: state.frequency.as_marker_token().to_string(),
+        created_at: created_at(&content),
+        entries,
+    })
+}
+
+fn collect_habit_data(space_root: &Path, since: Option<String>) -> Result<HabitDataExport, String> {
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+    let since_secs =
+        match since.as_deref().map(str::trim) {
+            None | Some("") => None,
+            Some(value) => Some(parse_timestamp(value).ok_or_else(|| {
+                format!("Invalid since '{}': expected YYYY-MM-DD or RFC 3339", value)
+            })? as i64),
+        };
+
+    let mut habits = Vec::new();
+    if let Ok(entries) = fs::read_dir(space_root.join("Habits")) {
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let is_markdown = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                });
+            if !path.is_file() || !is_markdown || is_habit_history_archive(&path) {
+                continue;
+            }
+            habits.extend(read_habit(&path, since_secs));
+        }
+    }
+    habits.sort_by_key(|habit| habit.name.to_lowercase());
+
+    Ok(HabitDataExport {
+        schema_version: HABIT_EXPORT_SCHEMA_VERSION,
+        exported_at: Local::now().to_rfc3339(),
+        since,
+        habits,
+    })
+}
+
+/// Read every habit's frequency and history for an external dashboard
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `since` - Optional earliest entry (YYYY-MM-DD or RFC 3339)
+///
+/// # Returns
+///
+/// Versioned habit data with RFC 3339 local timestamps, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const data = await invoke<HabitDataExport>('get_habit_data', {
+///   spacePath: '/path/to/space',
+///   since: '2026-01-01',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_habit_data(
+    space_path: String,
+    since: Option<String>,
+) -> Result<HabitDataExport, String> {
+    log::info!("Reading habit data for: {}", space_path);
+    collect_habit_data(Path::new(&space_path), since)
+}
+
+/// Write every habit's frequency and history to a JSON file
+///
+/// Writes the same document `get_habit_data` returns, pretty-printed,
+/// replacing the file if it exists.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `output_path` - JSON file to write; its folder must exist
+/// * `since` - Optional earliest entry (YYYY-MM-DD or RFC 3339)
+///
+/// # Returns
+///
+/// Number of habits written, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const count = await invoke<number>('export_habit_data_json', {
+///   spacePath: '/path/to/space',
+///   outputPath: '/path/to/metrics/habits.json',
+///   since: '2026-01-01',
+/// });
+/// ```
+#[tauri::command]
+pub fn export_habit_data_json(
+    space_path: String,
+    output_path: String,
+    since: Option<String>,
+) -> Result<usize, String> {
+    log::info!(
+        "Exporting habit data from {} to {}",
+        space_path,
+        output_path
+    );
+
+    let output = Path::new(&output_path);
+    if !output.parent().is_some_and(|parent| parent.is_dir()) {
+        return Err("Output folder does not exist".to_string());
+    }
+    let export = collect_habit_data(Path::new(&space_path), since)?;
+    let json = serde_json::to_string_pretty(&export)
+        .map_err(|e| format!("Failed to serialize habit data: {}", e))?;
+    write_string_atomically(output, &json)?;
+    Ok(export.habits.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::seed_data::generate_weekly_review_habit;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn seeded_weekly_review_round_trips_through_the_export() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let habit = generate_weekly_review_habit().replace(
+            "|------|------|--------|--------|---------|",
+            "|------|------|--------|--------|---------|\n| 2026-03-01 | 2:00 PM | Complete | Manual | Done |\n| 2026-03-08 | 12:00 AM | To Do | Auto-Reset | New period |\n| 2026-03-08 | 3:30 PM | Complete | Manual | Done |",
+        );
+        write_test_file(root.join("Habits/Weekly GTD Review.md"), &habit)?;
+
+        let space_path = root.to_string_lossy().to_string();
+        let output = root.join("habits.json");
+        let written = export_habit_data_json(
+            space_path.clone(),
+            output.to_string_lossy().to_string(),
+            Some("2026-03-02".to_string()),
+        )?;
+        assert_eq!(written, 1);
+
+        let json = fs::read_to_string(&output).map_err(|e| e.to_string())?;
+        let exported: HabitDataExport = serde_json::from_str(&json).map_err(|e| e.to_string())?;
+        let read = get_habit_data(space_path, Some("2026-03-02".to_string()))?;
+        assert_eq!(exported.habits, read.habits);
+        assert_eq!(exported.schema_version, HABIT_EXPORT_SCHEMA_VERSION);
+
+        let review = &exported.habits[0];
+        assert_eq!(review.name, "Weekly GTD Review");
+        assert_eq!(review.frequency, "weekly");
+        assert!(review
+            .created_at
+            .as_deref()
+            .is_some_and(|created| DateTime::parse_from_rfc3339(created).is_ok()));
+        let completed: Vec<bool> = review.entries.iter().map(|entry| entry.completed).collect();
+        assert_eq!(completed, vec![false, true]);
+        let expected_date = local_rfc3339(
+            NaiveDateTime::parse_from_str("2026-03-08 15:30", "%Y-%m-%d %H:%M")
+                .map_err(|e| e.to_string())?,
+        );
+        assert_eq!(Some(review.entries[1].date.clone()), expected_date);
+        assert_eq!(review.entries[0].action, "Auto-Reset");
+
+        assert!(get_habit_data(root.to_string_lossy().to_string(), Some("soon".into())).is_err());
+        Ok(())
+    }
+}
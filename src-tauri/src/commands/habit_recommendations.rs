@@ -0,0 +1,237 @@
+//! Habit suggestions for areas of focus and goals that no habit supports yet.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::filesystem::read_file;
+use super::gtd_relationships::find_habits_referencing;
+
+/// Title keywords mapped to a suggested habit and why it helps
+const HABIT_SUGGESTIONS: &[(&[&str], &str, &str)] = &[
+    (
+        &["health", "fitness", "exercise", "wellness", "body"],
+        "Daily Exercise",
+        "Regular movement turns health intentions into steady progress.",
+    ),
+    (
+        &["finance", "finances", "financial", "money", "budget"],
+        "Monthly Budget Review",
+        "A monthly look at spending catches problems while they are small.",
+    ),
+    (
+        &["career", "work", "job", "professional", "business"],
+        "Weekly Career Reflection",
+        "A short weekly reflection keeps long-term career direction in view.",
+    ),
+    (
+        &[
+            "learning",
+            "learn",
+            "education",
+            "study",
+            "skills",
+            "reading",
+        ],
+        "Daily Reading",
+        "A little reading every day compounds into real expertise.",
+    ),
+    (
+        &[
+            "family",
+            "relationships",
+            "relationship",
+            "friends",
+            "social",
+        ],
+        "Weekly Family Time",
+        "Protected time each week keeps important relationships from drifting.",
+    ),
+    (
+        &["home", "house", "household"],
+        "Weekly Home Reset",
+        "A weekly reset stops household chores from piling up.",
+    ),
+    (
+        &["mind", "mental", "spiritual", "mindfulness", "meditation"],
+        "Daily Meditation",
+        "A few quiet minutes a day support focus and resilience.",
+    ),
+    (
+        &["creative", "creativity", "writing", "art", "music"],
+        "Daily Creative Practice",
+        "Creative skills grow fastest with small, frequent sessions.",
+    ),
+    (
+        &["sleep", "rest", "recovery"],
+        "Consistent Bedtime",
+        "A regular bedtime is the simplest lever for better rest.",
+    ),
+];
+
+/// A suggested habit for an area of focus or goal without one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitRecommendation {
+    /// Area or goal title
+    pub area_or_goal_name: String,
+    /// Full path to the area or goal file
+    pub area_or_goal_path: String,
+    /// Suggested habit name
+    pub suggestion: String,
+    /// Why the habit would help
+    pub rationale: String,
+}
+
+fn title_of(path: &Path, content: &str) -> String {
+    content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        })
+}
+
+/// Suggest a habit from the lookup table, or a generic check-in.
+fn suggest_habit(title: &str, kind: &str) -> (String, String) {
+    let words: Vec<String> = title
+        .split(|ch: char| !ch.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+
+    HABIT_SUGGESTIONS
+        .iter()
+        .find(|(keywords, _, _)| keywords.iter().any(|k| words.iter().any(|w| w == k)))
+        .map(|(_, suggestion, rationale)| (suggestion.to_string(), rationale.to_string()))
+        .unwrap_or_else(|| {
+            (
+                format!("Weekly {} Check-in", title),
+                format!(
+                    "No habit supports this {} yet; a recurring check-in keeps it moving between reviews.",
+                    kind
+                ),
+            )
+        })
+}
+
+fn recommendations_for(
+    space_path: &str,
+    directory: &str,
+    kind: &str,
+) -> Result<Vec<HabitRecommendation>, String> {
+    let dir_path = Path::new(space_path).join(directory);
+    let Ok(entries) = fs::read_dir(&dir_path) else {
+        return Ok(Vec::new());
+    };
+
+    let mut recommendations = Vec::new();
+    for path in entries.flatten().map(|entry| entry.path()) {
+        let is_markdown = path.is_file()
+            && path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("md"));
+        if !is_markdown {
+            continue;
+        }
+
+        let path_str = path.to_string_lossy().to_string();
+        if !find_habits_referencing(path_str.clone(), space_path.to_string())?.is_empty() {
+            continue;
+        }
+        let content = read_file(path_str.clone()).unwrap_or_default();
+        let name = title_of(&path, &content);
+        let (suggestion, rationale) = suggest_habit(&name, kind);
+        recommendations.push(HabitRecommendation {
+            area_or_goal_name: name,
+            area_or_goal_path: path_str,
+            suggestion,
+            rationale,
+        });
+    }
+
+    recommendations.sort_by_key(|rec| rec.area_or_goal_name.to_lowercase());
+    Ok(recommendations)
+}
+
+/// Suggest habits for areas of focus and goals that have none
+///
+/// An area or goal needs a habit when no habit file references it. The
+/// suggestion comes from keywords in its title, falling back to a weekly
+/// check-in.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Recommendations for areas, then goals, each sorted by name
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const recommendations = await invoke<HabitRecommendation[]>(
+///   'get_habit_recommendations',
+///   { spacePath: '/path/to/space' },
+/// );
+/// ```
+#[tauri::command]
+pub fn get_habit_recommendations(space_path: String) -> Result<Vec<HabitRecommendation>, String> {
+    log::info!("Building habit recommendations for: {}", space_path);
+
+    let mut recommendations = recommendations_for(&space_path, "Areas of Focus", "area")?;
+    recommendations.extend(recommendations_for(&space_path, "Goals", "goal")?);
+
+    log::info!("Found {} habit recommendations", recommendations.len());
+    Ok(recommendations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn only_areas_and_goals_without_habits_get_suggestions() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            root.join("Areas of Focus/Personal Finance.md"),
+            "# Personal Finance\n",
+        )?;
+        write_test_file(root.join("Areas of Focus/Garden.md"), "# Garden\n")?;
+        write_test_file(root.join("Goals/Run a Marathon.md"), "# Run a Marathon\n")?;
+        write_test_file(
+            root.join("Habits/Long run.md"),
+            &format!(
+                "# Long run\n\n[!goals-references:{}]\n",
+                root.join("Goals/Run a Marathon.md").display()
+            ),
+        )?;
+
+        let recommendations = get_habit_recommendations(root.to_string_lossy().to_string())?;
+        let summary: Vec<(&str, &str)> = recommendations
+            .iter()
+            .map(|rec| (rec.area_or_goal_name.as_str(), rec.suggestion.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Garden", "Weekly Garden Check-in"),
+                ("Personal Finance", "Monthly Budget Review"),
+                ("Fitness", "Daily Exercise"),
+            ]
+        );
+        assert!(recommendations[0].rationale.contains("area"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,317 @@
+//! Backend scheduler for automatic habit resets.
+//!
+//! Habits used to reset only while the frontend polled `check_and_reset_habits`,
+//! so a throttled or hidden window missed resets until the next interaction.
+//! The scheduler runs the reset for the active space when it starts and again
+//! at the top of every hour, or sooner when a habit's period rolls over first
+//! (5-minute habits are checked every five minutes), and emits `habits-reset`
+//! with the results.
+
+use chrono::{Duration, Local, NaiveDateTime, Timelike};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::{AppHandle, Emitter};
+
+use super::gtd_habits::{next_habit_reset, reset_due_habits};
+
+/// Event emitted after each scheduled habit check
+pub(crate) const HABITS_RESET_EVENT: &str = "habits-reset";
+
+/// Frontend-triggered checks this soon after a scheduled run are skipped
+const DEDUPE_WINDOW_SECS: u64 = 30;
+
+/// Payload of [`HABITS_RESET_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitsResetEvent {
+    pub space_path: String,
+    /// Names of habits that were reset
+    pub reset_habits: Vec<String>,
+}
+
+/// State reported by `get_habit_scheduler_status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HabitSchedulerStatus {
+    /// Whether a scheduler task is active
+    pub running: bool,
+    /// Space the scheduler checks
+    pub space_path: Option<String>,
+    /// Unix seconds of the last completed check
+    pub last_run: Option<u64>,
+    /// Unix seconds of the next planned check
+    pub next_run: Option<u64>,
+    /// Error from the last check, cleared by a successful one
+    pub last_error: Option<String>,
+}
+
+#[derive(Default)]
+struct SchedulerState {
+    handle: Option<tokio::task::JoinHandle<()>>,
+    space_path: Option<String>,
+    last_run: Option<u64>,
+    next_run: Option<u64>,
+    last_error: Option<String>,
+}
+
+static SCHEDULER: Lazy<Mutex<SchedulerState>> = Lazy::new(|| Mutex::new(SchedulerState::default()));
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Start of the hour after `now`
+fn next_top_of_hour(now: NaiveDateTime) -> NaiveDateTime {
+    let hour_start = now
+        .with_minute(0)
+        .and_then(|time| time.with_second(0))
+        .and_then(|time| time.with_nanosecond(0))
+        .unwrap_or(now);
+    hour_start + Duration::hours(1)
+}
+
+/// When to check next: the next habit reset boundary, or the top of the hour
+/// if that comes first.
+fn next_check_at(now: NaiveDateTime, next_reset: Option<NaiveDateTime>) -> NaiveDateTime {
+    let top_of_hour = next_top_of_hour(now);
+    next_reset
+        .filter(|next_reset| *next_reset > now)
+        .map_or(top_of_hour, |next_reset| next_reset.min(top_of_hour))
+}
+
+/// Whether a check of `space_path` at `now` repeats one that ran at `last_run`
+fn is_duplicate_check(
+    scheduled_space: Option<&str>,
+    last_run: Option<u64>,
+    space_path: &str,
+    now: u64,
+) -> bool {
+    scheduled_space.is_some_and(|scheduled| Path::new(scheduled) == Path::new(space_path))
+        && last_run.is_some_and(|last_run| now.saturating_sub(last_run) < DEDUPE_WINDOW_SECS)
+}
+
+/// Whether the scheduler checked `space_path` within the last few seconds, so
+/// a frontend-triggered check can be skipped.
+pub(crate) fn ran_recently(space_path: &str) -> bool {
+    let Ok(state) = SCHEDULER.lock() else {
+        return false;
+    };
+    is_duplicate_check(
+        state.space_path.as_deref(),
+        state.last_run,
+        space_path,
+        unix_now(),
+    )
+}
+
+/// Abort the scheduler task, if one is running. Returns whether one was.
+pub(crate) fn abort_habit_scheduler() -> bool {
+    let Ok(mut state) = SCHEDULER.lock() else {
+        return false;
+    };
+    state.next_run = None;
+    match state.handle.take() {
+        Some(handle) => {
+            handle.abort();
+            log::info!("Stopped habit scheduler");
+            true
+        }
+        None => false,
+    }
+}
+
+/// Reset due habits and emit the results. Returns how long to wait before the
+/// next check.
+async fn run_scheduled_check(app: &AppHandle, space_path: &str) -> std::time::Duration {
+    let root = space_path.to_string();
+    let (result, next_reset) = tokio::task::spawn_blocking(move || {
+        let result = reset_due_habits(&root);
+        (result, next_habit_reset(&root, Local::now().naive_local()))
+    })
+    .await
+    .unwrap_or_else(|error| (Err(format!("Habit check task failed: {}", error)), None));
+
+    let now = Local::now().naive_local();
+    let wait = (next_check_at(now, next_reset) - now)
+        .to_std()
+        .unwrap_or_default();
+    if let Ok(mut state) = SCHEDULER.lock() {
+        let finished_at = unix_now();
+        state.last_run = Some(finished_at);
+        state.next_run = Some(finished_at + wait.as_secs());
+        state.last_error = result.as_ref().err().cloned();
+    }
+
+    match result {
+        Ok(reset_habits) => {
+            if !reset_habits.is_empty() {
+                log::info!("Scheduled check reset {} habit(s)", reset_habits.len());
+            }
+            let event = HabitsResetEvent {
+                space_path: space_path.to_string(),
+                reset_habits,
+            };
+            if let Err(e) = app.emit(HABITS_RESET_EVENT, &event) {
+                log::error!("Failed to emit habits reset event: {}", e);
+            }
+        }
+        Err(error) => log::warn!("Scheduled habit check failed: {}", error),
+    }
+    wait
+}
+
+/// Start the habit scheduler for a space
+///
+/// Checks the space's habits straight away and then at the top of every
+/// hour, or at the next habit reset boundary when that comes first, emitting
+/// `habits-reset` after each check. Starting it for another space stops the
+/// previous scheduler.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for emitting events
+/// * `space_path` - Root path of the GTD space that became active
+///
+/// # Returns
+///
+/// Nothing on success, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('start_habit_scheduler', { spacePath: '/path/to/space' });
+/// ```
+#[tauri::command]
+pub async fn start_habit_scheduler(app: AppHandle, space_path: String) -> Result<(), String> {
+    log::info!("Starting habit scheduler for: {}", space_path);
+
+    if !Path::new(&space_path).is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let mut state = SCHEDULER
+        .lock()
+        .map_err(|_| "Habit scheduler state is poisoned".to_string())?;
+    if let Some(previous) = state.handle.take() {
+        previous.abort();
+        log::info!("Stopped existing habit scheduler before starting a new one");
+    }
+
+    let root = space_path.clone();
+    state.handle = Some(tokio::spawn(async move {
+        loop {
+            let wait = run_scheduled_check(&app, &root).await;
+            tokio::time::sleep(wait).await;
+        }
+    }));
+    if state.space_path.as_deref() != Some(space_path.as_str()) {
+        state.last_run = None;
+        state.last_error = None;
+    }
+    state.space_path = Some(space_path);
+
+    Ok(())
+}
+
+/// Stop the habit scheduler
+///
+/// # Returns
+///
+/// Whether a scheduler was running
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<boolean>('stop_habit_scheduler');
+/// ```
+#[tauri::command]
+pub fn stop_habit_scheduler() -> Result<bool, String> {
+    Ok(abort_habit_scheduler())
+}
+
+/// Report the habit scheduler's last run, next run, and last error
+///
+/// # Returns
+///
+/// The scheduler status, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const status = await invoke<HabitSchedulerStatus>('get_habit_scheduler_status');
+/// ```
+#[tauri::command]
+pub fn get_habit_scheduler_status() -> Result<HabitSchedulerStatus, String> {
+    let state = SCHEDULER
+        .lock()
+        .map_err(|_| "Habit scheduler state is poisoned".to_string())?;
+    Ok(HabitSchedulerStatus {
+        running: state
+            .handle
+            .as_ref()
+            .is_some_and(|handle| !handle.is_finished()),
+        space_path: state.space_path.clone(),
+        last_run: state.last_run,
+        next_run: state.next_run,
+        last_error: state.last_error.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn schedule_aligns_to_hour_and_dedupes_recent_checks() -> Result<(), String> {
+        let at = |hour, minute, second| {
+            NaiveDate::from_ymd_opt(2025, 12, 31)
+                .and_then(|date| date.and_hms_opt(hour, minute, second))
+                .ok_or("invalid test time")
+        };
+        assert_eq!(next_top_of_hour(at(9, 41, 7)?), at(10, 0, 0)?);
+        assert_eq!(next_top_of_hour(at(10, 0, 0)?), at(11, 0, 0)?);
+        assert_eq!(
+            next_top_of_hour(at(23, 59, 59)?),
+            at(0, 0, 0)? + Duration::days(1)
+        );
+        assert_eq!(
+            next_check_at(at(9, 41, 7)?, Some(at(9, 45, 0)?)),
+            at(9, 45, 0)?
+        );
+        assert_eq!(
+            next_check_at(at(9, 41, 7)?, Some(at(12, 0, 0)?)),
+            at(10, 0, 0)?
+        );
+        assert_eq!(
+            next_check_at(at(9, 41, 7)?, Some(at(9, 40, 0)?)),
+            at(10, 0, 0)?
+        );
+        assert_eq!(next_check_at(at(9, 41, 7)?, None), at(10, 0, 0)?);
+
+        let after_run = |space_path, now| {
+            is_duplicate_check(Some("/spaces/home"), Some(1_000), space_path, now)
+        };
+        assert!(after_run("/spaces/home", 1_010));
+        assert!(!after_run("/spaces/home", 1_100));
+        assert!(!after_run("/spaces/work", 1_010));
+        assert!(!is_duplicate_check(
+            None,
+            Some(1_000),
+            "/spaces/home",
+            1_010
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+//! Score how well each horizon links up to the one above it.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::filesystem::scan_directory_recursive;
+use super::gtd_projects::{list_gtd_projects, resolve_project_readme_path};
+use super::gtd_relationships::{extract_reference_block, parse_reference_paths};
+
+/// Linked and unlinked counts for projects, areas, and goals
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AlignmentReport {
+    pub projects_with_area_links: u32,
+    pub projects_without_area_links: u32,
+    pub areas_with_goal_links: u32,
+    pub areas_without_goal_links: u32,
+    pub goals_with_vision_links: u32,
+    pub goals_without_vision_links: u32,
+    /// Mean linked share across the levels that have items; 1.0 when every
+    /// item links up the hierarchy (or there is nothing to link)
+    pub alignment_score: f32,
+}
+
+/// Whether `content` lists at least one path in its `tag` reference field
+fn has_reference(content: &str, tag: &str) -> bool {
+    extract_reference_block(content, tag)
+        .is_some_and(|block| !parse_reference_paths(&block).is_empty())
+}
+
+/// Count files in a horizon directory with and without a `tag` reference.
+fn count_horizon_links(dir: &Path, tag: &str) -> Result<(u32, u32), String> {
+    if !dir.is_dir() {
+        return Ok((0, 0));
+    }
+    let mut files = Vec::new();
+    scan_directory_recursive(dir, &mut files)?;
+
+    let linked = files
+        .iter()
+        .filter(|file| {
+            fs::read_to_string(&file.path).is_ok_and(|content| has_reference(&content, tag))
+        })
+        .count() as u32;
+    Ok((linked, files.len() as u32 - linked))
+}
+
+/// Mean of `with / (with + without)` over the levels that have items
+fn alignment_score(levels: &[(u32, u32)]) -> f32 {
+    let ratios: Vec<f32> = levels
+        .iter()
+        .filter(|(with, without)| with + without > 0)
+        .map(|(with, without)| *with as f32 / (with + without) as f32)
+        .collect();
+    if ratios.is_empty() {
+        return 1.0;
+    }
+    ratios.iter().sum::<f32>() / ratios.len() as f32
+}
+
+/// Score how well the GTD horizons are connected
+///
+/// Counts projects that reference an area of focus, areas that reference a
+/// goal, and goals that reference a vision, then averages the linked share
+/// of each level into one score.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// The alignment report, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke<AlignmentReport>('compute_gtd_horizon_alignment', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn compute_gtd_horizon_alignment(space_path: String) -> Result<AlignmentReport, String> {
+    log::info!("Computing horizon alignment for: {}", space_path);
+
+    let root = Path::new(&space_path);
+    if !root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let projects = list_gtd_projects(space_path.clone())?;
+    let projects_with_area_links = projects
+        .iter()
+        .filter(|project| {
+            resolve_project_readme_path(Path::new(&project.path))
+                .and_then(|readme| fs::read_to_string(readme).ok())
+                .is_some_and(|content| has_reference(&content, "areas-references"))
+        })
+        .count() as u32;
+    let projects_without_area_links = projects.len() as u32 - projects_with_area_links;
+
+    let (areas_with_goal_links, areas_without_goal_links) =
+        count_horizon_links(&root.join("Areas of Focus"), "goals-references")?;
+    let (goals_with_vision_links, goals_without_vision_links) =
+        count_horizon_links(&root.join("Goals"), "vision-references")?;
+
+    Ok(AlignmentReport {
+        projects_with_area_links,
+        projects_without_area_links,
+        areas_with_goal_links,
+        areas_without_goal_links,
+        goals_with_vision_links,
+        goals_without_vision_links,
+        alignment_score: alignment_score(&[
+            (projects_with_area_links, projects_without_area_links),
+            (areas_with_goal_links, areas_without_goal_links),
+            (goals_with_vision_links, goals_without_vision_links),
+        ]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn alignment_counts_links_per_level() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        for goal in ["Goals/Fitness.md", "Goals/Savings.md"] {
+            write_test_file(root.join(goal), "# Goal\n\n[!vision-references:]\n")?;
+        }
+        let goal = root.join("Goals/Fitness.md");
+        write_test_file(
+            root.join("Areas of Focus/Health.md"),
+            &format!("# Health\n\n[!goals-references:{}]\n", goal.display()),
+        )?;
+        let area = root.join("Areas of Focus/Health.md");
+        write_test_file(
+            root.join("Projects/Gym Plan/README.md"),
+            &format!("# Gym Plan\n\n[!areas-references:{}]\n", area.display()),
+        )?;
+
+        let report = compute_gtd_horizon_alignment(root.to_string_lossy().to_string())?;
+        let goal_files = report.goals_with_vision_links + report.goals_without_vision_links;
+        assert_eq!(report.projects_with_area_links, 1);
+        assert_eq!(report.projects_without_area_links, 1);
+        assert_eq!(report.areas_with_goal_links, 1);
+        assert_eq!(report.areas_without_goal_links, 0);
+        assert_eq!(report.goals_with_vision_links, 0);
+        assert!(goal_files >= 2);
+        assert!((report.alignment_score - 0.5).abs() < 1e-6);
+
+        assert_eq!(alignment_score(&[(0, 0), (0, 0)]), 1.0);
+        Ok(())
+    }
+}
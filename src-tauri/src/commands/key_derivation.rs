@@ -0,0 +1,13 @@
+//! Passphrase key derivation shared by git sync backups and encrypted folders.
+
+use pbkdf2::pbkdf2_hmac;
+use sha2_010::Sha256 as Pbkdf2Sha256;
+
+const PBKDF2_ITERATIONS: u32 = 600_000;
+
+/// Derive a 256-bit AES key from a passphrase with PBKDF2-HMAC-SHA256.
+pub(crate) fn derive_encryption_key(passphrase: &str, salt: &[u8]) -> [u8; 32] {
+    let mut key = [0u8; 32];
+    pbkdf2_hmac::<Pbkdf2Sha256>(passphrase.as_bytes(), salt, PBKDF2_ITERATIONS, &mut key);
+    key
+}
@@ -0,0 +1,395 @@
+//! Structured data from meeting notes files.
+//!
+//! Meeting notes are plain markdown with optional `## Attendees`,
+//! `## Decisions`, and `## Action Items` sections. Open action items can be
+//! turned into project actions.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::filesystem::read_file;
+use super::gtd_projects::{create_action, validate_projects_child_directory, CreateActionError};
+
+static ISO_DATE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\b\d{4}-\d{2}-\d{2}\b").expect("Invalid date regex pattern"));
+
+/// `Date: ...` or `**Date:** ...` lines in the note body
+static DATE_LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)^\s*(?:\*\*)?date(?::\*\*|\*\*:|:)\s*(.+?)\s*$")
+        .expect("Invalid date line regex pattern")
+});
+
+static CHECKBOX_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\s*[-*+]\s+\[([ xX])\]\s*(.*)$").expect("Invalid checkbox regex pattern")
+});
+
+static ASSIGNEE_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?:^|\s)@([\w.-]*\w)").expect("Invalid assignee regex pattern"));
+
+/// A checklist item under `## Action Items`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ActionItem {
+    /// Item text with the `@assignee` mention removed
+    pub text: String,
+    pub checked: bool,
+    /// Name after the first `@`, e.g. `sam` for `@sam`
+    pub assignee: Option<String>,
+}
+
+/// Structured contents of a meeting notes file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingNotes {
+    pub title: String,
+    /// From a `## Date` section, a `Date:` line, or a YYYY-MM-DD in the title
+    /// or file name
+    pub date: Option<String>,
+    pub attendees: Vec<String>,
+    pub decisions: Vec<String>,
+    pub action_items: Vec<ActionItem>,
+}
+
+/// An open action item that did not become an action
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SkippedActionItem {
+    pub text: String,
+    pub reason: String,
+}
+
+/// Outcome of `create_actions_from_meeting`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MeetingActionsResult {
+    /// Full paths of the created actions
+    pub created: Vec<String>,
+    pub skipped: Vec<SkippedActionItem>,
+}
+
+/// Non-empty lines of the `## {title}` section (case-insensitive).
+fn section_lines<'a>(lines: &[&'a str], title: &str) -> Vec<&'a str> {
+    let Some(heading) = lines.iter().position(|line| {
+        line.trim()
+            .strip_prefix("## ")
+            .is_some_and(|heading| heading.trim().eq_ignore_ascii_case(title))
+    }) else {
+        return Vec::new();
+    };
+
+    lines[heading + 1..]
+        .iter()
+        .take_while(|line| {
+            let trimmed = line.trim_start();
+            !trimmed.starts_with("# ") && !trimmed.starts_with("## ")
+        })
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && *line != "---")
+        .collect()
+}
+
+/// Strip a leading `-`, `*`, `+`, or `1.` list marker.
+fn strip_list_marker(line: &str) -> &str {
+    let line = line.trim();
+    if let Some(rest) = line
+        .strip_prefix("- ")
+        .or_else(|| line.strip_prefix("* "))
+        .or_else(|| line.strip_prefix("+ "))
+    {
+        return rest.trim();
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        if let Some(rest) = line[digits..].strip_prefix(". ") {
+            return rest.trim();
+        }
+    }
+    line
+}
+
+fn list_entries(lines: &[&str], title: &str) -> Vec<String> {
+    section_lines(lines, title)
+        .into_iter()
+        .map(strip_list_marker)
+        .filter(|entry| !entry.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+fn parse_action_item(line: &str) -> Option<ActionItem> {
+    let captures = CHECKBOX_REGEX.captures(line)?;
+    let raw = captures[2].trim();
+    let assignee = ASSIGNEE_REGEX
+        .captures(raw)
+        .map(|captures| captures[1].to_string());
+    let text = match assignee {
+        Some(_) => ASSIGNEE_REGEX
+            .replace(raw, "")
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" "),
+        None => raw.to_string(),
+    };
+    if text.is_empty() {
+        return None;
+    }
+
+    Some(ActionItem {
+        text,
+        checked: !captures[1].trim().is_empty(),
+        assignee,
+    })
+}
+
+pub(crate) fn parse_meeting_content(content: &str, file_stem: &str) -> MeetingNotes {
+    let lines: Vec<&str> = content.lines().collect();
+    let title = lines
+        .iter()
+        .find_map(|line| line.trim().strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| file_stem.to_string());
+
+    let date = section_lines(&lines, "Date")
+        .first()
+        .map(|line| strip_list_marker(line).to_string())
+        .or_else(|| {
+            lines
+                .iter()
+                .find_map(|line| DATE_LINE_REGEX.captures(line))
+                .map(|captures| captures[1].to_string())
+        })
+        .or_else(|| {
+            ISO_DATE_REGEX
+                .find(&title)
+                .or_else(|| ISO_DATE_REGEX.find(file_stem))
+                .map(|date| date.as_str().to_string())
+        });
+
+    MeetingNotes {
+        title,
+        date,
+        attendees: list_entries(&lines, "Attendees"),
+        decisions: list_entries(&lines, "Decisions"),
+        action_items: section_lines(&lines, "Action Items")
+            .into_iter()
+            .filter_map(parse_action_item)
+            .collect(),
+    }
+}
+
+fn read_meeting_notes(file_path: &str) -> Result<MeetingNotes, String> {
+    let content = read_file(file_path.to_string())?;
+    let stem = Path::new(file_path)
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    Ok(parse_meeting_content(&content, &stem))
+}
+
+/// Parse a meeting notes file
+///
+/// # Arguments
+///
+/// * `file_path` - Full path to the meeting notes markdown file
+///
+/// # Returns
+///
+/// Title, date, attendees, decisions, and action items, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const notes = await invoke<MeetingNotes>('parse_meeting_notes', {
+///   filePath: '/path/to/space/Cabinet/2026-03-02 Planning.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn parse_meeting_notes(file_path: String) -> Result<MeetingNotes, String> {
+    log::info!("Parsing meeting notes: {}", file_path);
+    read_meeting_notes(&file_path)
+}
+
+/// Create one action per unchecked action item
+///
+/// Actions are created in-progress with a note pointing back at the meeting
+/// and naming the assignee. Items whose action already exists in the project
+/// are skipped, so running this again after adding items only creates the
+/// new ones. An item that fails is reported as skipped with the error, and
+/// the remaining items are still created.
+///
+/// # Arguments
+///
+/// * `file_path` - Full path to the meeting notes markdown file
+/// * `project_path` - Project directory to create the actions in
+///
+/// # Returns
+///
+/// Paths of the created actions and the items that were skipped, or error
+/// details when the meeting or project cannot be read
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { created, skipped } = await invoke<MeetingActionsResult>('create_actions_from_meeting', {
+///   filePath: '/path/to/space/Cabinet/2026-03-02 Planning.md',
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+/// });
+/// ```
+#[tauri::command]
+pub fn create_actions_from_meeting(
+    file_path: String,
+    project_path: String,
+) -> Result<MeetingActionsResult, String> {
+    log::info!(
+        "Creating actions from meeting {} in {}",
+        file_path,
+        project_path
+    );
+
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+    validate_projects_child_directory(project_dir)?;
+
+    let meeting = read_meeting_notes(&file_path)?;
+    let source = match &meeting.date {
+        Some(date) => format!("From meeting: {} ({})", meeting.title, date),
+        None => format!("From meeting: {}", meeting.title),
+    };
+
+    let mut result = MeetingActionsResult {
+        created: Vec::new(),
+        skipped: Vec::new(),
+    };
+    for item in meeting.action_items.iter().filter(|item| !item.checked) {
+        let notes = match &item.assignee {
+            Some(assignee) => format!("{}\n\n**Assignee:** @{}", source, assignee),
+            None => source.clone(),
+        };
+        let reason = match create_action(
+            project_path.clone(),
+            item.text.clone(),
+            "in-progress".to_string(),
+            None,
+            None,
+            "medium".to_string(),
+            None,
+            Some(notes),
+            None,
+            None,
+        ) {
+            Ok(path) => {
+                result.created.push(path);
+                continue;
+            }
+            Err(CreateActionError::AlreadyExists(_)) => "Action already exists".to_string(),
+            Err(CreateActionError::Failed(error)) => {
+                log::warn!("Failed to create meeting action {}: {}", item.text, error);
+                error
+            }
+        };
+        result.skipped.push(SkippedActionItem {
+            text: item.text.clone(),
+            reason,
+        });
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+    use std::fs;
+
+    const NOTES: &str = "# Launch sync\n\n**Date:** 2026-03-02\n\n## Attendees\n- Ana\n- Bo\n\n## Decisions\n1. Ship on Friday\n\n## Action Items\n- [ ] Draft release notes @ana\n- [x] Book room\n- [ ] Update pricing page\n\n## Discussion\n- [ ] Not an action item\n";
+
+    #[test]
+    fn meeting_sections_are_parsed() {
+        let notes = parse_meeting_content(NOTES, "ignored");
+        assert_eq!(notes.title, "Launch sync");
+        assert_eq!(notes.date.as_deref(), Some("2026-03-02"));
+        assert_eq!(notes.attendees, vec!["Ana", "Bo"]);
+        assert_eq!(notes.decisions, vec!["Ship on Friday"]);
+        assert_eq!(
+            notes.action_items,
+            vec![
+                ActionItem {
+                    text: "Draft release notes".to_string(),
+                    checked: false,
+                    assignee: Some("ana".to_string()),
+                },
+                ActionItem {
+                    text: "Book room".to_string(),
+                    checked: true,
+                    assignee: None,
+                },
+                ActionItem {
+                    text: "Update pricing page".to_string(),
+                    checked: false,
+                    assignee: None,
+                },
+            ]
+        );
+
+        let bare = parse_meeting_content("No headings here\n", "2026-04-01 Standup");
+        assert_eq!(bare.title, "2026-04-01 Standup");
+        assert_eq!(bare.date.as_deref(), Some("2026-04-01"));
+        assert!(bare.action_items.is_empty());
+    }
+
+    #[test]
+    fn unchecked_items_become_actions_once() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let meeting = root.join("Cabinet/Launch sync.md");
+        write_test_file(&meeting, NOTES)?;
+        let project = root.join("Projects/Alpha Project");
+
+        let result = create_actions_from_meeting(
+            meeting.to_string_lossy().to_string(),
+            project.to_string_lossy().to_string(),
+        )?;
+        assert_eq!(result.created.len(), 2);
+        assert!(result.skipped.is_empty());
+        let content = fs::read_to_string(project.join("Draft release notes.md"))
+            .map_err(|e| e.to_string())?;
+        assert!(content.contains("From meeting: Launch sync (2026-03-02)"));
+        assert!(content.contains("**Assignee:** @ana"));
+        assert!(!project.join("Book room.md").exists());
+
+        let again = create_actions_from_meeting(
+            meeting.to_string_lossy().to_string(),
+            project.to_string_lossy().to_string(),
+        )?;
+        assert!(again.created.is_empty());
+        assert_eq!(
+            again.skipped,
+            vec![
+                SkippedActionItem {
+                    text: "Draft release notes".to_string(),
+                    reason: "Action already exists".to_string(),
+                },
+                SkippedActionItem {
+                    text: "Update pricing page".to_string(),
+                    reason: "Action already exists".to_string(),
+                },
+            ]
+        );
+
+        let missing = root.join("Projects/Missing");
+        assert!(create_actions_from_meeting(
+            meeting.to_string_lossy().to_string(),
+            missing.to_string_lossy().to_string(),
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
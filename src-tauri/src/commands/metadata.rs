@@ -0,0 +1,383 @@
+//! Parsed header metadata for project READMEs and action files.
+//!
+//! These commands give the frontend one parser for the fields shown in
+//! project and action headers. Missing sections fall back to empty values and
+//! anything that looks wrong is described in `parse_warnings` instead of
+//! failing the call.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::action_table::collect_action_rows;
+use super::calendar_actions::linked_event_id;
+use super::custom_fields::parse_custom_fields;
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary, VocabularyValue};
+use super::fields::{get_field, split_multiselect, FieldKind};
+use super::gtd_projects::resolve_project_readme_path;
+use super::gtd_relationships::{extract_reference_block, parse_reference_paths, reference_tags_in};
+use super::project_report::{
+    compute_project_completion_percentage, extract_description, ProjectCompletion,
+};
+
+/// One `[!*-references:...]` or `[!references:...]` field
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ReferenceBlock {
+    /// Marker tag, e.g. `areas-references`
+    pub tag: String,
+    pub paths: Vec<String>,
+}
+
+/// Header metadata for a project README
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectMetadata {
+    pub title: String,
+    pub status: Option<String>,
+    /// Desired Outcome / Description section text
+    pub description: String,
+    pub due_date: Option<String>,
+    pub focus_date: Option<String>,
+    pub created_date_time: Option<String>,
+    pub completed_date_time: Option<String>,
+    pub references: Vec<ReferenceBlock>,
+    pub action_stats: ProjectCompletion,
+    pub parse_warnings: Vec<String>,
+}
+
+/// Header metadata for an action file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionMetadata {
+    pub title: String,
+    pub status: Option<String>,
+    pub focus_date: Option<String>,
+    pub due_date: Option<String>,
+    pub effort: Option<String>,
+    /// Contexts without the leading `@`
+    pub contexts: Vec<String>,
+    pub references: Vec<ReferenceBlock>,
+    pub calendar_event_id: Option<String>,
+    /// Values from the `## Custom` section, keyed by field key
+    pub custom_fields: BTreeMap<String, String>,
+    pub created_date_time: Option<String>,
+    pub completed_date_time: Option<String>,
+    pub parse_warnings: Vec<String>,
+}
+
+fn non_empty(value: Option<String>) -> Option<String> {
+    value.filter(|value| !value.is_empty())
+}
+
+fn parse_title(content: &str, warnings: &mut Vec<String>) -> String {
+    let title = content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .unwrap_or_default();
+    if title.is_empty() {
+        warnings.push("missing '# ' title heading".to_string());
+    }
+    title
+}
+
+/// First non-empty value among the given single-select keys.
+fn parse_choice(
+    content: &str,
+    keys: &[&str],
+    label: &str,
+    recognized: &[VocabularyValue],
+    warnings: &mut Vec<String>,
+) -> Option<String> {
+    let value = keys
+        .iter()
+        .find_map(|key| non_empty(get_field(content, FieldKind::SingleSelect, key)));
+    match &value {
+        None => warnings.push(format!("missing {} value", label)),
+        Some(value) if !recognized.iter().any(|entry| entry.value == *value) => warnings.push(
+            format!("{} value '{}' is not a recognized value", label, value),
+        ),
+        Some(_) => {}
+    }
+    value
+}
+
+pub(crate) fn is_valid_datetime(value: &str) -> bool {
+    DateTime::parse_from_rfc3339(value).is_ok()
+        || NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S").is_ok()
+        || NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M").is_ok()
+        || NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+}
+
+fn parse_datetime(content: &str, keys: &[&str], warnings: &mut Vec<String>) -> Option<String> {
+    let (key, value) = keys.iter().find_map(|key| {
+        non_empty(get_field(content, FieldKind::Datetime, key)).map(|value| (*key, value))
+    })?;
+    if !is_valid_datetime(&value) {
+        warnings.push(format!("{} value '{}' is not a valid date", key, value));
+    }
+    Some(value)
+}
+
+fn parse_references(content: &str) -> Vec<ReferenceBlock> {
+    reference_tags_in(content)
+        .into_iter()
+        .map(|tag| ReferenceBlock {
+            tag: tag.to_string(),
+            paths: extract_reference_block(content, tag)
+                .map(|raw| parse_reference_paths(&raw))
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+pub(crate) fn parse_project_metadata(content: &str, project_dir: &Path) -> ProjectMetadata {
+    let vocabulary = field_vocabulary_or_default(project_dir);
+    let mut warnings = Vec::new();
+    let title = parse_title(content, &mut warnings);
+    let status = parse_choice(
+        content,
+        &["project-status", "status"],
+        "status",
+        &vocabulary.statuses,
+        &mut warnings,
+    );
+    let action_stats = match collect_action_rows(project_dir) {
+        Ok(rows) => compute_project_completion_percentage(&rows, &vocabulary),
+        Err(error) => {
+            warnings.push(error);
+            ProjectCompletion::default()
+        }
+    };
+
+    ProjectMetadata {
+        title,
+        status,
+        description: extract_description(content),
+        due_date: parse_datetime(content, &["due_date"], &mut warnings),
+        focus_date: parse_datetime(content, &["focus_date"], &mut warnings),
+        created_date_time: parse_datetime(content, &["created_date_time"], &mut warnings),
+        completed_date_time: parse_datetime(
+            content,
+            &["completed_date_time", "completed_date"],
+            &mut warnings,
+        ),
+        references: parse_references(content),
+        action_stats,
+        parse_warnings: warnings,
+    }
+}
+
+pub(crate) fn parse_action_metadata(content: &str, vocabulary: &FieldVocabulary) -> ActionMetadata {
+    let mut warnings = Vec::new();
+    let title = parse_title(content, &mut warnings);
+    let status = parse_choice(
+        content,
+        &["status"],
+        "status",
+        &vocabulary.statuses,
+        &mut warnings,
+    );
+    let effort = parse_choice(
+        content,
+        &["effort"],
+        "effort",
+        &vocabulary.efforts,
+        &mut warnings,
+    );
+    let contexts = split_multiselect(
+        &get_field(content, FieldKind::MultiSelect, "contexts").unwrap_or_default(),
+    )
+    .into_iter()
+    .map(|context| context.trim_start_matches('@').to_string())
+    .filter(|context| !context.is_empty())
+    .collect();
+    ActionMetadata {
+        title,
+        status,
+        focus_date: parse_datetime(content, &["focus_date"], &mut warnings),
+        due_date: parse_datetime(content, &["due_date"], &mut warnings),
+        effort,
+        contexts,
+        references: parse_references(content),
+        calendar_event_id: linked_event_id(content),
+        custom_fields: parse_custom_fields(content),
+        created_date_time: parse_datetime(content, &["created_date_time"], &mut warnings),
+        completed_date_time: parse_datetime(
+            content,
+            &["completed_date_time", "completed_date"],
+            &mut warnings,
+        ),
+        parse_warnings: warnings,
+    }
+}
+
+/// Read a project's header metadata
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder (or its README)
+///
+/// # Returns
+///
+/// Parsed metadata with `parse_warnings` for anything missing or invalid,
+/// or error details when the README cannot be read
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const meta = await invoke<ProjectMetadata>('get_project_metadata', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_project_metadata(project_path: String) -> Result<ProjectMetadata, String> {
+    log::info!("Reading project metadata: {}", project_path);
+
+    let path = Path::new(&project_path);
+    let project_dir = if path.is_file() {
+        path.parent()
+            .ok_or_else(|| "Cannot determine project folder".to_string())?
+    } else {
+        path
+    };
+    let readme_path = resolve_project_readme_path(project_dir)
+        .ok_or_else(|| "Project README not found".to_string())?;
+    let content =
+        fs::read_to_string(&readme_path).map_err(|e| format!("Failed to read README: {}", e))?;
+
+    Ok(parse_project_metadata(&content, project_dir))
+}
+
+/// Read an action's header metadata
+///
+/// # Arguments
+///
+/// * `action_path` - Full path to the action file
+///
+/// # Returns
+///
+/// Parsed metadata with `parse_warnings` for anything missing or invalid,
+/// or error details when the file cannot be read
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const meta = await invoke<ActionMetadata>('get_action_metadata', {
+///   actionPath: '/path/to/space/Projects/Website Redesign/Draft copy.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_action_metadata(action_path: String) -> Result<ActionMetadata, String> {
+    log::info!("Reading action metadata: {}", action_path);
+
+    let content =
+        fs::read_to_string(&action_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let vocabulary = field_vocabulary_or_default(Path::new(&action_path));
+    Ok(parse_action_metadata(&content, &vocabulary))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::seed_data::{
+        generate_action_template, generate_project_readme_with_refs, ProjectReadmeParams,
+    };
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn metadata_parses_templates_and_warns_on_bad_values() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        write_test_file(
+            project.join("README.md"),
+            &generate_project_readme_with_refs(ProjectReadmeParams {
+                name: "Alpha Project",
+                description: "Ship it",
+                due_date: Some("2026-05-01"),
+                focus_date: None,
+                status: "waiting",
+                areas_refs: "/space/Areas of Focus/Work.md",
+                goals_refs: "",
+                vision_refs: "",
+                purpose_refs: "",
+                general_refs: "",
+            }),
+        )?;
+        write_test_file(
+            project.join("Draft.md"),
+            &generate_action_template(
+                "Draft",
+                "completed",
+                None,
+                Some("2026-04-10".to_string()),
+                "small",
+                Some(vec!["computer".to_string(), "office".to_string()]),
+                None,
+                Some("evt-1"),
+            ),
+        )?;
+
+        let meta = get_project_metadata(project.to_string_lossy().to_string())?;
+        assert_eq!(meta.title, "Alpha Project");
+        assert_eq!(meta.status.as_deref(), Some("waiting"));
+        assert_eq!(meta.description, "Ship it");
+        assert_eq!(meta.due_date.as_deref(), Some("2026-05-01"));
+        assert!(meta.created_date_time.is_some());
+        assert_eq!(meta.action_stats.completed_actions, 1);
+        assert!(meta.references.contains(&ReferenceBlock {
+            tag: "areas-references".to_string(),
+            paths: vec!["/space/Areas of Focus/Work.md".to_string()],
+        }));
+        assert!(meta.parse_warnings.is_empty(), "{:?}", meta.parse_warnings);
+
+        let action = get_action_metadata(project.join("Draft.md").to_string_lossy().to_string())?;
+        assert_eq!(action.status.as_deref(), Some("completed"));
+        assert_eq!(action.effort.as_deref(), Some("small"));
+        assert_eq!(action.contexts, vec!["computer", "office"]);
+        assert_eq!(action.calendar_event_id.as_deref(), Some("evt-1"));
+        assert!(
+            action.parse_warnings.is_empty(),
+            "{:?}",
+            action.parse_warnings
+        );
+
+        let broken_content =
+            "## Status\n[!singleselect:status:done]\n\n## Due Date\n[!datetime:due_date:soon]\n";
+        let broken = parse_action_metadata(broken_content, &FieldVocabulary::default());
+        assert_eq!(
+            broken.parse_warnings,
+            vec![
+                "missing '# ' title heading".to_string(),
+                "status value 'done' is not a recognized value".to_string(),
+                "missing effort value".to_string(),
+                "due_date value 'soon' is not a valid date".to_string(),
+            ]
+        );
+        assert_eq!(broken.due_date.as_deref(), Some("soon"));
+        let from_calendar = parse_action_metadata(
+            "# Kickoff\n\n[!gcal:event_id:evt-2]\n",
+            &FieldVocabulary::default(),
+        );
+        assert_eq!(from_calendar.calendar_event_id.as_deref(), Some("evt-2"));
+
+        // A space that adds `done` to its vocabulary accepts it
+        let mut custom = FieldVocabulary::default();
+        custom.statuses.push(VocabularyValue {
+            value: "done".to_string(),
+            maps_to: "completed".to_string(),
+            label: None,
+        });
+        let accepted = parse_action_metadata(broken_content, &custom);
+        assert!(!accepted
+            .parse_warnings
+            .iter()
+            .any(|warning| warning.starts_with("status")));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+//! Project milestones stored in a project's `milestones/` folder.
+//!
+//! Milestone files live one level below the project, so action scans and the
+//! generated action table never pick them up.
+
+use chrono::{DateTime, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use super::fields::{get_field, FieldKind};
+use super::file_meta::record_file_change;
+use super::gtd_projects::validate_projects_child_directory;
+use super::seed_data::generate_milestone_template;
+use super::space_config::ensure_space_writable;
+use super::utils::sanitize_markdown_file_stem;
+
+/// Folder inside a project holding milestone files
+const MILESTONES_DIR: &str = "milestones";
+
+/// A project milestone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Milestone {
+    pub name: String,
+    /// Full path to the milestone file
+    pub path: String,
+    pub target_date: Option<String>,
+    /// `milestone-status` value, e.g. `pending`
+    pub status: String,
+}
+
+fn parse_milestone(path: &Path, content: &str) -> Milestone {
+    let name = content
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("# "))
+        .map(|title| title.trim().to_string())
+        .filter(|title| !title.is_empty())
+        .unwrap_or_else(|| {
+            path.file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        });
+
+    Milestone {
+        name,
+        path: path.to_string_lossy().to_string(),
+        target_date: get_field(content, FieldKind::Datetime, "target_date")
+            .filter(|value| !value.is_empty()),
+        status: get_field(content, FieldKind::SingleSelect, "milestone-status")
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "pending".to_string()),
+    }
+}
+
+/// Normalize a target date to YYYY-MM-DD.
+fn normalize_target_date(target_date: &str) -> Result<String, String> {
+    let trimmed = target_date.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(trimmed, "%Y-%m-%d") {
+        return Ok(date.format("%Y-%m-%d").to_string());
+    }
+    DateTime::parse_from_rfc3339(trimmed)
+        .map(|datetime| datetime.format("%Y-%m-%d").to_string())
+        .map_err(|_| format!("Invalid target date '{}'. Expected YYYY-MM-DD", target_date))
+}
+
+/// Create a milestone inside a project
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder
+/// * `milestone_name` - Name of the milestone
+/// * `target_date` - Target date (YYYY-MM-DD)
+///
+/// # Returns
+///
+/// Path to the created milestone file or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const path = await invoke<string>('create_project_milestone', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+///   milestoneName: 'Beta launch',
+///   targetDate: '2026-06-30',
+/// });
+/// ```
+#[tauri::command]
+pub fn create_project_milestone(
+    project_path: String,
+    milestone_name: String,
+    target_date: String,
+) -> Result<String, String> {
+    log::info!(
+        "Creating milestone '{}' in project: {}",
+        milestone_name,
+        project_path
+    );
+
+    let project_dir = Path::new(&project_path);
+    ensure_space_writable(project_dir)?;
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+    validate_projects_child_directory(project_dir)?;
+
+    let name = milestone_name.trim();
+    if name.is_empty() {
+        return Err("Milestone name cannot be empty".to_string());
+    }
+    let target_date = normalize_target_date(&target_date)?;
+
+    let milestones_dir = project_dir.join(MILESTONES_DIR);
+    fs::create_dir_all(&milestones_dir)
+        .map_err(|e| format!("Failed to create milestones folder: {}", e))?;
+    let milestone_path = milestones_dir.join(format!("{}.md", sanitize_markdown_file_stem(name)));
+
+    let mut file = match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&milestone_path)
+    {
+        Ok(file) => file,
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            return Err(format!("Milestone '{}' already exists", name));
+        }
+        Err(e) => return Err(format!("Failed to create milestone file: {}", e)),
+    };
+    if let Err(e) = file.write_all(generate_milestone_template(name, &target_date).as_bytes()) {
+        drop(file);
+        let _ = fs::remove_file(&milestone_path);
+        return Err(format!("Failed to create milestone file: {}", e));
+    }
+
+    record_file_change(&milestone_path);
+    Ok(milestone_path.to_string_lossy().to_string())
+}
+
+/// List a project's milestones
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder
+///
+/// # Returns
+///
+/// Milestones sorted by target date (undated last), then name
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const milestones = await invoke<Milestone[]>('list_project_milestones', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_project_milestones(project_path: String) -> Result<Vec<Milestone>, String> {
+    log::info!("Listing milestones in project: {}", project_path);
+
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+
+    let Ok(entries) = fs::read_dir(project_dir.join(MILESTONES_DIR)) else {
+        return Ok(Vec::new());
+    };
+
+    let mut milestones: Vec<Milestone> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| {
+                        ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                    })
+        })
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path).ok()?;
+            Some(parse_milestone(&path, &content))
+        })
+        .collect();
+
+    milestones.sort_by(|a, b| {
+        (a.target_date.is_none(), &a.target_date, &a.name).cmp(&(
+            b.target_date.is_none(),
+            &b.target_date,
+            &b.name,
+        ))
+    });
+    Ok(milestones)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::action_table::collect_action_rows;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn milestones_are_created_listed_and_kept_out_of_actions() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        let project_path = project.to_string_lossy().to_string();
+        let actions_before = collect_action_rows(&project)?.len();
+
+        let path = create_project_milestone(
+            project_path.clone(),
+            "Beta launch".to_string(),
+            "2026-06-30".to_string(),
+        )?;
+        let content = fs::read_to_string(&path).map_err(|e| e.to_string())?;
+        assert!(content.contains("[!datetime:target_date:2026-06-30]"));
+        assert!(content.contains("[!singleselect:milestone-status:pending]"));
+        assert!(content.contains("## Success Criteria"));
+        assert!(content.contains("## Actions Completed"));
+
+        create_project_milestone(
+            project_path.clone(),
+            "Kickoff".to_string(),
+            "2026-05-01T09:00:00Z".to_string(),
+        )?;
+        write_test_file(
+            project.join("milestones/Someday.md"),
+            "# Someday\n\n[!singleselect:milestone-status:reached]\n",
+        )?;
+
+        assert!(create_project_milestone(
+            project_path.clone(),
+            "Beta launch".to_string(),
+            "2026-07-01".to_string(),
+        )
+        .is_err());
+        assert!(create_project_milestone(
+            project_path.clone(),
+            "Later".to_string(),
+            "next week".to_string(),
+        )
+        .is_err());
+
+        let milestones = list_project_milestones(project_path)?;
+        let summary: Vec<(&str, Option<&str>, &str)> = milestones
+            .iter()
+            .map(|m| (m.name.as_str(), m.target_date.as_deref(), m.status.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Kickoff", Some("2026-05-01"), "pending"),
+                ("Beta launch", Some("2026-06-30"), "pending"),
+                ("Someday", None, "reached"),
+            ]
+        );
+        assert_eq!(collect_action_rows(&project)?.len(), actions_before);
+
+        Ok(())
+    }
+}
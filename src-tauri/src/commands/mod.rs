@@ -3,71 +3,341 @@
 // Command implementations are organized by backend domain and re-exported here for
 // shared backend use. `lib.rs` registers Tauri handlers against their concrete module
 // paths so the command macros stay attached to the defining module.
+pub(crate) mod action_notes;
+pub(crate) mod action_table;
+pub(crate) mod action_timers;
+pub(crate) mod activity;
 pub(crate) mod app;
+pub(crate) mod app_data;
+pub(crate) mod area_standards;
+pub(crate) mod badges;
+pub(crate) mod batch_move;
+pub(crate) mod calendar_actions;
+pub(crate) mod chunked_read;
+pub(crate) mod custom_fields;
+pub(crate) mod deadlines;
+pub(crate) mod delete_impact;
 pub(crate) mod dialogs;
+pub(crate) mod due_summary;
+pub(crate) mod effort_distribution;
+pub(crate) mod field_vocabulary;
+pub(crate) mod fields;
+pub(crate) mod file_diff;
+pub(crate) mod file_icons;
+pub(crate) mod file_locks;
+pub(crate) mod file_meta;
+pub(crate) mod file_previews;
 pub(crate) mod filesystem;
+pub(crate) mod focus;
+pub(crate) mod focus_scheduling;
+pub(crate) mod focus_session;
+pub(crate) mod folder_encryption;
+pub(crate) mod frontmatter;
+#[cfg(feature = "git-sync")]
 pub(crate) mod git_commands;
+#[cfg(feature = "git-sync")]
 pub(crate) mod git_sync;
 pub(crate) mod google_calendar_commands;
 pub(crate) mod gtd_habits;
 pub(crate) mod gtd_habits_domain;
 pub(crate) mod gtd_projects;
 pub(crate) mod gtd_relationships;
+pub(crate) mod habit_export;
+pub(crate) mod habit_recommendations;
+pub(crate) mod habit_scheduler;
+pub(crate) mod horizon_alignment;
+pub(crate) mod key_derivation;
+pub(crate) mod meeting_notes;
+pub(crate) mod metadata;
+pub(crate) mod milestones;
+pub(crate) mod motivation;
+pub(crate) mod note_split;
+pub(crate) mod orphaned_actions;
+pub(crate) mod outline;
+pub(crate) mod path_remap;
+pub(crate) mod pre_op_backups;
+pub(crate) mod project_report;
+pub(crate) mod project_rollup;
+pub(crate) mod project_templates;
+pub(crate) mod project_velocity;
+pub(crate) mod recurring_events;
+pub(crate) mod rename_impact;
+pub(crate) mod review_prompts;
 pub(crate) mod search;
+pub(crate) mod search_query;
+pub(crate) mod sections;
 pub(crate) mod seed_data;
+pub(crate) mod seed_packs;
 pub(crate) mod settings;
+pub(crate) mod snippets;
+pub(crate) mod space_config;
+pub(crate) mod space_copy;
+pub(crate) mod space_relocate;
+pub(crate) mod space_walk;
+pub(crate) mod spellcheck;
+pub(crate) mod standup;
+pub(crate) mod startup;
+pub(crate) mod stuck_projects;
+pub(crate) mod tags;
 pub(crate) mod utils;
+pub(crate) mod waiting_for;
 pub(crate) mod watcher;
 pub(crate) mod workspace;
+pub(crate) mod workspace_size;
+pub(crate) mod workspace_snapshots;
 
+#[allow(unused_imports)]
+pub use action_notes::{append_action_note, get_action_notes};
+#[allow(unused_imports)]
+pub use action_table::sync_project_action_table;
+#[allow(unused_imports)]
+pub use action_timers::{
+    get_action_time_total, get_project_time_totals, get_running_action_timer, start_action_timer,
+    stop_action_timer, ActionTimeTotal, ProjectTimeTotals, RunningTimer, StoppedTimer,
+};
+#[allow(unused_imports)]
+pub use activity::{
+    get_daily_action_counts, get_weekly_completion_trend, get_workspace_activity_feed,
+    ActivityEvent, DailyActionCount, WeeklyCount,
+};
 #[cfg(debug_assertions)]
 #[allow(unused_imports)]
 pub use app::test_select_folder;
 #[allow(unused_imports)]
-pub use app::{check_permissions, get_app_version, ping, PermissionStatus};
+pub use app::{
+    check_permissions, get_app_health, get_app_version, ping, AppHealth, PermissionStatus,
+};
+#[allow(unused_imports)]
+pub use app_data::{
+    clear_app_caches, get_app_paths, open_app_data_dir, AppCacheKind, AppPaths,
+    ClearAppCachesResult,
+};
+#[allow(unused_imports)]
+pub use area_standards::{create_area_standard, list_area_standards};
+#[allow(unused_imports)]
+pub use badges::{get_inbox_count, get_overdue_count};
+#[allow(unused_imports)]
+pub use batch_move::{batch_move_actions, BatchMoveResult};
+#[allow(unused_imports)]
+pub use calendar_actions::{
+    create_action_from_event, list_unprocessed_events, EventConversionError,
+    EventConversionErrorKind, EventLinkCleanup,
+};
+#[allow(unused_imports)]
+pub use chunked_read::{cancel_file_read, read_file_chunked, FileChunk, FileReadComplete};
+#[allow(unused_imports)]
+pub use custom_fields::{
+    get_custom_field_definitions, set_action_custom_fields, set_custom_field_definitions,
+    CustomFieldDefinition, CustomFieldError, CustomFieldErrorKind,
+};
+#[allow(unused_imports)]
+pub use deadlines::{check_upcoming_deadlines, DeadlineItem, DeadlineReport};
+#[allow(unused_imports)]
+pub use delete_impact::{
+    get_delete_impact, DeleteImpact, ImpactWarning, ImpactWarningKind, IncomingReference,
+};
+#[allow(unused_imports)]
+pub use dialogs::{
+    get_external_editors, open_file_in_external_app, open_file_location, open_folder_in_explorer,
+    select_folder, ExternalEditor,
+};
 #[allow(unused_imports)]
-pub use dialogs::{open_file_location, open_folder_in_explorer, select_folder};
+pub use due_summary::{get_due_summary, DueBucket, DueBuckets, DueItem, DueSummary};
+#[allow(unused_imports)]
+pub use effort_distribution::{get_action_effort_distribution, EffortDistribution};
+#[allow(unused_imports)]
+pub use field_vocabulary::{
+    get_field_vocabulary, set_field_vocabulary, FieldVocabulary, VocabularyValue,
+};
+#[allow(unused_imports)]
+pub use fields::{get_file_fields, set_file_field, FieldKind, FileField};
+#[allow(unused_imports)]
+pub use file_diff::{diff_file_versions, DiffHunk};
+#[allow(unused_imports)]
+pub use file_icons::set_file_icon;
+#[allow(unused_imports)]
+pub use file_meta::{query_files_meta, FileMeta, FileMetaFilter, FileMetaItem, FileMetaPage};
+#[allow(unused_imports)]
+pub use file_previews::get_file_previews;
 #[allow(unused_imports)]
 pub use filesystem::{
-    check_directory_exists, check_file_exists, copy_file, create_directory, create_file,
-    delete_file, delete_folder, list_markdown_files, list_project_actions, move_file, read_file,
-    rename_file, replace_in_file, save_file, FileOperationResult, MarkdownFile,
+    check_directory_exists, check_directory_writable, check_file_exists, copy_file,
+    create_directory, create_file, delete_file, delete_folder, list_files_modified_since,
+    list_markdown_files, list_markdown_files_page, list_project_actions, list_project_actions_page,
+    move_file, read_file, rename_file, replace_in_file, restore_from_trash, save_file,
+    set_file_writable, FileOperationResult, FileSortKey, ListFilesOptions, MarkdownFile,
+    MarkdownFilePage,
 };
 #[allow(unused_imports)]
-pub use git_commands::{git_sync_preview_push, git_sync_pull, git_sync_push, git_sync_status};
+pub use focus::{get_focus_blocks, ContextBlock, FocusItem};
+#[allow(unused_imports)]
+pub use focus_scheduling::{
+    check_focus_conflicts, suggest_focus_slots, BusyBlock, BusySource, CalendarFreshness,
+    FocusConflictReport, FocusSlot, FocusSlotReport, WorkHours,
+};
+
+#[allow(unused_imports)]
+pub use focus_session::{
+    get_focus_session, pause_focus_session, resume_focus_session, start_focus_session,
+    stop_focus_session, FocusPhase, FocusPhaseChange, FocusSession, FocusSessionError,
+    FocusSessionErrorKind, FocusSessionStatus, StoppedFocusSession,
+};
+#[allow(unused_imports)]
+pub use folder_encryption::{
+    enable_folder_encryption, lock_encrypted_folder, unlock_encrypted_folder, EncryptedFolder,
+    FolderEncryptionError, FolderEncryptionErrorKind,
+};
+#[allow(unused_imports)]
+pub use frontmatter::{normalize_markdown_frontmatter, ConvertedField, NormalizeResult};
+#[cfg(feature = "git-sync")]
+#[allow(unused_imports)]
+pub use git_commands::{
+    git_sync_preview_push, git_sync_pull, git_sync_push, git_sync_restore_file, git_sync_status,
+};
 #[allow(unused_imports)]
 pub use google_calendar_commands::{
-    google_calendar_connect, google_calendar_disconnect, google_calendar_disconnect_simple,
-    google_calendar_fetch_events, google_calendar_get_cached_events, google_calendar_get_status,
-    google_calendar_is_authenticated, google_calendar_start_auth, google_calendar_sync,
-    google_oauth_clear_config, google_oauth_get_config, google_oauth_has_config,
-    google_oauth_store_config,
+    export_calendar_events_to_gtd_actions, get_calendar_event_by_gtd_action,
+    get_calendar_events_for_project, google_calendar_connect, google_calendar_disconnect,
+    google_calendar_disconnect_simple, google_calendar_fetch_events,
+    google_calendar_get_cached_events, google_calendar_get_colors, google_calendar_get_event,
+    google_calendar_get_status, google_calendar_handle_webhook_notification,
+    google_calendar_is_authenticated, google_calendar_register_webhook, google_calendar_start_auth,
+    google_calendar_sync, google_oauth_clear_config, google_oauth_get_config,
+    google_oauth_has_config, google_oauth_store_config, set_calendar_color_mapping,
+    CalendarExportResult, DisconnectSummary, SkippedCalendarEvent,
 };
 #[cfg(debug_assertions)]
 #[allow(unused_imports)]
 pub use google_calendar_commands::{google_calendar_test, google_calendar_test_async};
 #[allow(unused_imports)]
-pub use gtd_habits::{check_and_reset_habits, create_gtd_habit, update_habit_status};
+pub use gtd_habits::{
+    check_all_habits_completion_rate, check_and_reset_habits, convert_habit_to_project_action,
+    create_gtd_habit, get_habit_stats, merge_habit_history_files, prune_all_habit_histories,
+    prune_habit_history, set_habit_focus_date, toggle_habit, update_habit_status, HabitPruneResult,
+    HabitStats, HabitToggleResult, HabitsOverview,
+};
 #[allow(unused_imports)]
 pub use gtd_projects::{
-    create_gtd_action, create_gtd_project, list_gtd_projects, rename_gtd_action,
-    rename_gtd_project, GTDProject,
+    convert_someday_maybe_to_project, create_gtd_action, create_gtd_project, list_gtd_projects,
+    rename_gtd_action, rename_gtd_project, set_action_due_date, set_action_focus_date,
+    set_project_due_date, GTDProject,
 };
 #[allow(unused_imports)]
 pub use gtd_relationships::{
-    find_habits_referencing, find_reverse_relationships, HabitReference, ReverseRelationship,
+    find_habits_referencing, find_reverse_relationships, get_projects_by_area,
+    get_projects_by_goal, get_projects_by_vision, get_related_files, list_all_references,
+    HabitReference, ReferenceEntry, RelatedFile, ReverseRelationship,
+};
+#[allow(unused_imports)]
+pub use habit_export::{
+    export_habit_data_json, get_habit_data, HabitData, HabitDataExport, HabitEntry,
 };
 #[allow(unused_imports)]
+pub use habit_recommendations::{get_habit_recommendations, HabitRecommendation};
+#[allow(unused_imports)]
+pub use habit_scheduler::{
+    get_habit_scheduler_status, start_habit_scheduler, stop_habit_scheduler, HabitSchedulerStatus,
+    HabitsResetEvent,
+};
+#[allow(unused_imports)]
+pub use horizon_alignment::{compute_gtd_horizon_alignment, AlignmentReport};
+#[allow(unused_imports)]
+pub use meeting_notes::{
+    create_actions_from_meeting, parse_meeting_notes, ActionItem, MeetingActionsResult,
+    MeetingNotes, SkippedActionItem,
+};
+#[allow(unused_imports)]
+pub use metadata::{get_action_metadata, get_project_metadata, ActionMetadata, ProjectMetadata};
+#[allow(unused_imports)]
+pub use milestones::{create_project_milestone, list_project_milestones, Milestone};
+#[allow(unused_imports)]
+pub use motivation::{get_motivation_quote, MotivationQuote};
+#[allow(unused_imports)]
+pub use note_split::{split_note_into_actions, NoteSplitAction, NoteSplitResult, SkippedHeading};
+#[allow(unused_imports)]
+pub use orphaned_actions::{
+    adopt_orphaned_action, create_readme_for_folder, find_orphaned_actions, OrphanReason,
+    OrphanedAction,
+};
+#[allow(unused_imports)]
+pub use outline::{
+    get_outline_generation, get_space_outline, HabitSummary, HorizonFile, HorizonFiles,
+    OutlineCounts, OutlineNode, SpaceOutline,
+};
+#[allow(unused_imports)]
+pub use path_remap::PathRemap;
+#[allow(unused_imports)]
+pub use pre_op_backups::{list_pre_op_backups, restore_pre_op_backup, PreOpBackup};
+#[allow(unused_imports)]
+pub use project_report::{generate_project_report_pdf, ProjectCompletion};
+#[allow(unused_imports)]
+pub use project_rollup::{reconcile_project_statuses, ProjectStatusMismatch};
+#[allow(unused_imports)]
+pub use project_templates::{
+    create_project_from_template, list_project_templates, ProjectTemplate, ProjectTemplateAction,
+    ProjectTemplateSummary,
+};
+#[allow(unused_imports)]
+pub use project_velocity::{get_estimated_project_completion, CompletionForecast};
+#[allow(unused_imports)]
+pub use recurring_events::{list_recurring_calendar_events, RecurringEvent};
+#[allow(unused_imports)]
+pub use rename_impact::{preview_file_rename_impact, RenameImpact};
+#[allow(unused_imports)]
+pub use review_prompts::{get_gtd_review_prompt, ReviewItem, ReviewPrompt};
+#[allow(unused_imports)]
 pub use search::{search_files, SearchFilters, SearchResponse, SearchResult};
 #[allow(unused_imports)]
+pub use search_query::{SearchQueryError, SearchQueryErrorKind};
+#[allow(unused_imports)]
+pub use sections::{get_file_section, replace_file_section, Section};
+#[allow(unused_imports)]
+pub use seed_packs::{validate_seed_pack, SeedPackValidation};
+#[allow(unused_imports)]
 pub use settings::{
-    load_settings, save_settings, secure_store_get, secure_store_remove, secure_store_set,
-    UserSettings,
+    load_settings, reset_settings, save_settings, secure_store_get, secure_store_remove,
+    secure_store_set, update_last_active_timestamp, ResetSettingsResult, UserSettings,
 };
 #[allow(unused_imports)]
+pub use snippets::{
+    delete_snippet, get_snippet, insert_snippet_into_file, list_snippets, render_snippet,
+    save_snippet, Snippet, SnippetRenderError, SnippetRenderErrorKind,
+};
+#[allow(unused_imports)]
+pub use space_config::{
+    get_space_config, set_action_tables_enabled, set_file_locked, set_pre_op_backup_threshold,
+    set_space_read_only, set_waiting_threshold_days, SpaceConfig, WriteBlockedError,
+    WriteBlockedKind,
+};
+#[allow(unused_imports)]
+pub use space_copy::{duplicate_space, DuplicateSpaceResult, SpaceDuplicateProgress};
+#[allow(unused_imports)]
+pub use space_relocate::{relocate_space, RelocateSpaceResult};
+#[allow(unused_imports)]
+pub use spellcheck::{
+    add_word_to_dictionary, check_file, check_text, FileSpellcheck, Misspelling, SpellcheckError,
+    SpellcheckErrorKind,
+};
+#[allow(unused_imports)]
+pub use standup::{generate_standup_notes, StandupNotes};
+#[allow(unused_imports)]
+pub use startup::{startup_prefetch, PrefetchPart, SpacePrefetch, StartupPrefetch};
+#[allow(unused_imports)]
+pub use stuck_projects::get_projects_without_next_action;
+#[allow(unused_imports)]
+pub use tags::{list_all_tags, list_files_by_tag, TagSummary, TaggedFile};
+#[allow(unused_imports)]
+pub use waiting_for::{list_waiting_items, WaitingItem};
+#[allow(unused_imports)]
 pub use watcher::{start_file_watcher, stop_file_watcher, FileChangeEvent};
 #[allow(unused_imports)]
 pub use workspace::{
     check_is_gtd_space, get_default_gtd_space_path, initialize_default_gtd_space,
-    initialize_gtd_space, seed_example_gtd_content,
+    initialize_gtd_space, seed_example_gtd_content, set_default_gtd_space,
+};
+#[allow(unused_imports)]
+pub use workspace_size::{get_workspace_size, WorkspaceSize};
+#[allow(unused_imports)]
+pub use workspace_snapshots::{
+    list_snapshots, restore_snapshot, snapshot_workspace, SnapshotInfo, SnapshotResult,
 };
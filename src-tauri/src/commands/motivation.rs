@@ -0,0 +1,255 @@
+//! Daily quote drawn from the user's own Purpose & Principles and Vision
+//! pages.
+//!
+//! Quotes are markdown blockquotes. The pick is seeded by a hash of the local
+//! date, so the same quote shows all day and changes the next.
+
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+/// Horizon folders scanned for quotes
+const QUOTE_FOLDERS: [&str; 2] = ["Purpose & Principles", "Vision"];
+
+/// A blockquote from the space
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MotivationQuote {
+    /// Quote text with the `>` markers removed; lines joined by spaces
+    pub text: String,
+    /// Full path to the file the quote came from
+    pub source_file: String,
+    /// Paragraph around the quote, or its heading when there is none
+    pub context: String,
+}
+
+fn is_fence(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("```") || trimmed.starts_with("~~~")
+}
+
+fn quote_line(line: &str) -> Option<&str> {
+    let rest = line.trim_start().strip_prefix('>')?;
+    Some(rest.strip_prefix(' ').unwrap_or(rest).trim_end())
+}
+
+fn is_paragraph_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    !trimmed.is_empty()
+        && !trimmed.starts_with('#')
+        && !trimmed.starts_with('>')
+        && !trimmed.starts_with("[!")
+        && !trimmed.starts_with('|')
+        && !is_fence(trimmed)
+}
+
+/// The nearest paragraph before line `at` (exclusive) or from it onwards,
+/// across blank lines but not across headings, tables, or other quotes.
+fn paragraph_at(lines: &[&str], at: usize, backwards: bool) -> Option<String> {
+    let indexes: Box<dyn Iterator<Item = usize>> = if backwards {
+        Box::new((0..at).rev())
+    } else {
+        Box::new(at..lines.len())
+    };
+    let mut paragraph: Vec<&str> = indexes
+        .map(|index| lines[index].trim())
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| is_paragraph_line(line))
+        .collect();
+    if backwards {
+        paragraph.reverse();
+    }
+    (!paragraph.is_empty()).then(|| paragraph.join(" "))
+}
+
+fn heading_before(lines: &[&str], index: usize) -> Option<String> {
+    lines[..index].iter().rev().find_map(|line| {
+        let title = line.trim_start().trim_start_matches('#');
+        (line.trim_start().starts_with('#') && title.starts_with(' '))
+            .then(|| title.trim().to_string())
+    })
+}
+
+/// Blockquotes in `content` with their context, skipping front matter and
+/// code blocks. Callout markers such as `[!NOTE]` are dropped from the text.
+fn extract_quotes(content: &str, source_file: &str) -> Vec<MotivationQuote> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut index = 0;
+    if lines.first().is_some_and(|line| line.trim() == "---") {
+        if let Some(end) = lines[1..].iter().position(|line| line.trim() == "---") {
+            index = end + 2;
+        }
+    }
+
+    let mut quotes = Vec::new();
+    let mut in_fence = false;
+    while index < lines.len() {
+        if is_fence(lines[index]) {
+            in_fence = !in_fence;
+        }
+        if in_fence || quote_line(lines[index]).is_none() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        let mut parts = Vec::new();
+        while let Some(text) = lines.get(index).and_then(|line| quote_line(line)) {
+            let is_callout = text.starts_with("[!") && text.ends_with(']');
+            if !text.is_empty() && !is_callout {
+                parts.push(text);
+            }
+            index += 1;
+        }
+        let text = parts.join(" ");
+        if text.is_empty() {
+            continue;
+        }
+        let context = paragraph_at(&lines, start, true)
+            .or_else(|| paragraph_at(&lines, index, false))
+            .or_else(|| heading_before(&lines, start))
+            .unwrap_or_default();
+        quotes.push(MotivationQuote {
+            text,
+            source_file: source_file.to_string(),
+            context,
+        });
+    }
+    quotes
+}
+
+/// Every quote in the space's Purpose & Principles and Vision pages, in
+/// folder then file name order.
+fn collect_quotes(space_root: &Path) -> Vec<MotivationQuote> {
+    let mut quotes = Vec::new();
+    for folder in QUOTE_FOLDERS {
+        let Ok(entries) = fs::read_dir(space_root.join(folder)) else {
+            continue;
+        };
+        let mut paths: Vec<_> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| {
+                            ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                        })
+            })
+            .collect();
+        paths.sort();
+        for path in paths {
+            if let Ok(content) = fs::read_to_string(&path) {
+                quotes.extend(extract_quotes(&content, &path.to_string_lossy()));
+            }
+        }
+    }
+    quotes
+}
+
+/// Index of the quote for `date`; stable for a given date and quote count.
+fn daily_index(date: NaiveDate, count: usize) -> usize {
+    let digest = Sha256::digest(date.format("%Y-%m-%d").to_string().as_bytes());
+    let mut seed = [0u8; 8];
+    seed.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(seed) % count as u64) as usize
+}
+
+/// Pick today's quote from the space's own writing
+///
+/// Collects blockquotes (`> ...` lines) from the markdown files in
+/// `Purpose & Principles/` and `Vision/` and picks one using a hash of
+/// today's local date, so the quote stays the same all day. Entirely
+/// offline.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+///
+/// # Returns
+///
+/// The quote with its source file and surrounding paragraph, or an error
+/// when the space has no quotes
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const quote = await invoke<MotivationQuote>('get_motivation_quote', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_motivation_quote(space_path: String) -> Result<MotivationQuote, String> {
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let mut quotes = collect_quotes(space_root);
+    if quotes.is_empty() {
+        return Err("No quotes found in Purpose & Principles or Vision".to_string());
+    }
+    let index = daily_index(Local::now().date_naive(), quotes.len());
+    Ok(quotes.swap_remove(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn quotes_come_from_horizon_pages_with_context() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            root.join("Vision/My 3-5 Year Vision.md"),
+            "---\ntitle: Vision\n---\n# Vision\n\n## Craft\n\nWhy the work matters to me,\nwritten on a good day.\n> Make things that\n> last.\n\n```\n> not a quote\n```\n\n> [!NOTE]\n> Ship, then polish.\n",
+        )?;
+        write_test_file(
+            root.join("Purpose & Principles/Principles.md"),
+            "# Principles\n\n## Rest\n\n> Rest is part of the work.\n",
+        )?;
+        write_test_file(root.join("Goals/Goal.md"), "> Not a horizon quote\n")?;
+
+        let quotes = collect_quotes(root);
+        let summary: Vec<(&str, &str)> = quotes
+            .iter()
+            .map(|quote| (quote.text.as_str(), quote.context.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Rest is part of the work.", "Rest"),
+                (
+                    "Make things that last.",
+                    "Why the work matters to me, written on a good day."
+                ),
+                ("Ship, then polish.", "Craft"),
+            ]
+        );
+        assert!(quotes[0].source_file.ends_with("Principles.md"));
+
+        let quote = get_motivation_quote(root.to_string_lossy().to_string())?;
+        assert!(quotes.contains(&quote));
+        Ok(())
+    }
+
+    #[test]
+    fn daily_pick_is_stable_within_a_day() -> Result<(), String> {
+        let date = NaiveDate::from_ymd_opt(2026, 5, 1).ok_or("invalid date")?;
+        assert_eq!(daily_index(date, 7), daily_index(date, 7));
+        assert!(daily_index(date, 7) < 7);
+        let picks: std::collections::HashSet<usize> = (0..30)
+            .filter_map(|day| date.checked_add_days(chrono::Days::new(day)))
+            .map(|day| daily_index(day, 7))
+            .collect();
+        assert!(picks.len() > 1);
+        Ok(())
+    }
+}
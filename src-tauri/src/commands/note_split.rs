@@ -0,0 +1,364 @@
+//! Splitting a long note into one action per heading.
+//!
+//! Each heading at the chosen level becomes an action whose notes hold the
+//! section body. The note keeps its headings, but each split section is
+//! replaced by a link to the action it became.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::field_vocabulary::field_vocabulary_for;
+use super::file_locks::with_file_lock;
+use super::file_meta::record_file_change;
+use super::gtd_projects::{
+    create_action, validate_projects_child_directory, write_string_atomically, CreateActionError,
+};
+use super::space_config::ensure_file_editable;
+use super::utils::sanitize_markdown_file_stem;
+
+/// An action created, or planned on a dry run, from a note section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteSplitAction {
+    pub heading: String,
+    /// Full path to the action file
+    pub path: String,
+}
+
+/// A heading that was left in the note
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedHeading {
+    pub heading: String,
+    pub reason: String,
+}
+
+/// Outcome of `split_note_into_actions`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoteSplitResult {
+    pub actions: Vec<NoteSplitAction>,
+    pub skipped: Vec<SkippedHeading>,
+    /// Whether nothing was written
+    pub dry_run: bool,
+}
+
+/// A heading at the split level and the lines that belong to it
+#[derive(Debug)]
+struct NoteSection {
+    heading: String,
+    /// Index of the heading line
+    heading_line: usize,
+    /// Index one past the section's last line
+    end_line: usize,
+    body: String,
+}
+
+/// Heading text when `line` is a heading at `level` or shallower.
+fn heading_at_or_above(line: &str, level: usize) -> Option<(usize, &str)> {
+    let trimmed = line.trim_start();
+    let hashes = trimmed.chars().take_while(|ch| *ch == '#').count();
+    if hashes == 0 || hashes > level {
+        return None;
+    }
+    let rest = &trimmed[hashes..];
+    if !rest.is_empty() && !rest.starts_with([' ', '\t']) {
+        return None;
+    }
+    Some((hashes, rest.trim().trim_end_matches('#').trim()))
+}
+
+/// Sections headed at exactly `level`, ignoring headings in code fences.
+fn split_sections(lines: &[&str], level: usize) -> Vec<NoteSection> {
+    let mut sections: Vec<NoteSection> = Vec::new();
+    let mut in_fence = false;
+    for (index, line) in lines.iter().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+            continue;
+        }
+        if in_fence {
+            continue;
+        }
+        let Some((hashes, text)) = heading_at_or_above(line, level) else {
+            continue;
+        };
+        if let Some(open) = sections.last_mut().filter(|s| s.end_line == lines.len()) {
+            open.end_line = index;
+        }
+        if hashes == level {
+            sections.push(NoteSection {
+                heading: text.to_string(),
+                heading_line: index,
+                end_line: lines.len(),
+                body: String::new(),
+            });
+        }
+    }
+
+    for section in &mut sections {
+        section.body = lines[section.heading_line + 1..section.end_line]
+            .join("\n")
+            .trim()
+            .to_string();
+    }
+    sections
+}
+
+/// Path of `target` relative to the directory `from_dir`.
+fn relative_path(from_dir: &Path, target: &Path) -> PathBuf {
+    let from: Vec<Component> = from_dir.components().collect();
+    let to: Vec<Component> = target.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut relative = PathBuf::new();
+    for _ in common..from.len() {
+        relative.push("..");
+    }
+    for component in &to[common..] {
+        relative.push(component);
+    }
+    relative
+}
+
+/// Markdown link from the note to an action file.
+fn action_link(note_path: &Path, heading: &str, action_path: &Path) -> String {
+    let note_dir = note_path.parent().unwrap_or_else(|| Path::new(""));
+    let target = relative_path(note_dir, action_path)
+        .to_string_lossy()
+        .replace('\\', "/");
+    format!("Moved to action: [{}](<{}>)", heading, target)
+}
+
+/// Replace each split section's body with a link to its action.
+fn rewrite_note(
+    lines: &[&str],
+    sections: &[NoteSection],
+    links: &[(usize, String)],
+    trailing_newline: bool,
+) -> String {
+    let mut output: Vec<String> = Vec::with_capacity(lines.len());
+    let mut next_line = 0;
+    for (section_index, link) in links {
+        let section = &sections[*section_index];
+        output.extend(
+            lines[next_line..=section.heading_line]
+                .iter()
+                .map(|line| line.to_string()),
+        );
+        output.push(String::new());
+        output.push(link.clone());
+        if section.end_line < lines.len() {
+            output.push(String::new());
+        }
+        next_line = section.end_line;
+    }
+    output.extend(lines[next_line..].iter().map(|line| line.to_string()));
+
+    let mut content = output.join("\n");
+    if trailing_newline {
+        content.push('\n');
+    }
+    content
+}
+
+/// Split a note into one action per heading
+///
+/// Every heading at `heading_level` becomes an action named after the
+/// heading, with the section body (checklists included) as its notes.
+/// Headings whose action already exists in the project, or that repeat an
+/// earlier heading, are skipped and reported. The note is then rewritten so
+/// each split section holds a link to its action instead of its body.
+///
+/// # Arguments
+///
+/// * `note_path` - Full path to the note to split
+/// * `project_path` - Project directory to create the actions in
+/// * `heading_level` - Heading level to split on, 1 for `#` to 6 for `######`
+/// * `dry_run` - Report the planned split without writing anything
+///
+/// # Returns
+///
+/// Created (or planned) actions and skipped headings, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const plan = await invoke<NoteSplitResult>('split_note_into_actions', {
+///   notePath: '/path/to/space/Cabinet/Brainstorm.md',
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+///   headingLevel: 2,
+///   dryRun: true,
+/// });
+/// ```
+#[tauri::command]
+pub fn split_note_into_actions(
+    note_path: String,
+    project_path: String,
+    heading_level: u8,
+    dry_run: bool,
+) -> Result<NoteSplitResult, String> {
+    log::info!(
+        "Splitting note {} into actions in {} (level {}, dry_run={})",
+        note_path,
+        project_path,
+        heading_level,
+        dry_run
+    );
+
+    if !(1..=6).contains(&heading_level) {
+        return Err(format!(
+            "Invalid heading level {}. Must be between 1 and 6",
+            heading_level
+        ));
+    }
+    let note = Path::new(&note_path);
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+    validate_projects_child_directory(project_dir)?;
+    if !dry_run {
+        ensure_file_editable(note)?;
+    }
+
+    let content = fs::read_to_string(note).map_err(|e| format!("Failed to read note: {}", e))?;
+    let lines: Vec<&str> = content.lines().collect();
+    let sections = split_sections(&lines, heading_level as usize);
+    let status = field_vocabulary_for(project_dir)?.default_status();
+
+    let mut result = NoteSplitResult {
+        actions: Vec::new(),
+        skipped: Vec::new(),
+        dry_run,
+    };
+    let mut seen = HashSet::new();
+    let mut links = Vec::new();
+    for (index, section) in sections.iter().enumerate() {
+        let skip = |reason: &str| SkippedHeading {
+            heading: section.heading.clone(),
+            reason: reason.to_string(),
+        };
+        if section.heading.is_empty() {
+            result.skipped.push(skip("Heading is empty"));
+            continue;
+        }
+        let stem = sanitize_markdown_file_stem(&section.heading);
+        if !seen.insert(stem.to_lowercase()) {
+            result.skipped.push(skip("Repeats an earlier heading"));
+            continue;
+        }
+        let action_path = project_dir.join(format!("{}.md", stem));
+        if action_path.exists() {
+            result.skipped.push(skip("Action already exists"));
+            continue;
+        }
+        if dry_run {
+            result.actions.push(NoteSplitAction {
+                heading: section.heading.clone(),
+                path: action_path.to_string_lossy().to_string(),
+            });
+            continue;
+        }
+
+        let notes = Some(section.body.clone()).filter(|body| !body.is_empty());
+        match create_action(
+            project_path.clone(),
+            section.heading.clone(),
+            status.clone(),
+            None,
+            None,
+            "medium".to_string(),
+            None,
+            notes,
+            None,
+            None,
+        ) {
+            Ok(path) => {
+                links.push((index, action_link(note, &section.heading, Path::new(&path))));
+                result.actions.push(NoteSplitAction {
+                    heading: section.heading.clone(),
+                    path,
+                });
+            }
+            Err(CreateActionError::AlreadyExists(_)) => {
+                result.skipped.push(skip("Action already exists"))
+            }
+            Err(CreateActionError::Failed(error)) => result.skipped.push(skip(&error)),
+        }
+    }
+
+    if !links.is_empty() {
+        with_file_lock(note, || {
+            let current =
+                fs::read_to_string(note).map_err(|e| format!("Failed to read note: {}", e))?;
+            if current != content {
+                return Err(
+                    "Note changed while it was being split; it was not rewritten".to_string(),
+                );
+            }
+            let rewritten = rewrite_note(&lines, &sections, &links, content.ends_with('\n'));
+            write_string_atomically(note, &rewritten)?;
+            record_file_change(note);
+            Ok(())
+        })?;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    const NOTE: &str = "# Brainstorm\n\nIdeas from the offsite.\n\n## Draft release notes\nCover the new sync.\n- [ ] Collect changelog\n- [x] Pick a title\n\n### Detail\nStays with the section.\n\n## Old action\nAlready exists.\n\n```\n## Not a heading\n```\n## Draft release notes\nDuplicate.\n";
+
+    #[test]
+    fn note_sections_become_linked_actions() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let note = root.join("Cabinet/Brainstorm.md");
+        write_test_file(&note, NOTE)?;
+        let project = root.join("Projects/Alpha Project");
+        write_test_file(project.join("Old action.md"), "# Old action\n")?;
+        let note_path = note.to_string_lossy().to_string();
+        let project_path = project.to_string_lossy().to_string();
+
+        let plan = split_note_into_actions(note_path.clone(), project_path.clone(), 2, true)?;
+        assert!(plan.dry_run);
+        let planned: Vec<&str> = plan.actions.iter().map(|a| a.heading.as_str()).collect();
+        assert_eq!(planned, vec!["Draft release notes"]);
+        let skipped: Vec<(&str, &str)> = plan
+            .skipped
+            .iter()
+            .map(|s| (s.heading.as_str(), s.reason.as_str()))
+            .collect();
+        assert_eq!(
+            skipped,
+            vec![
+                ("Old action", "Action already exists"),
+                ("Draft release notes", "Repeats an earlier heading"),
+            ]
+        );
+        assert!(!project.join("Draft release notes.md").exists());
+        assert_eq!(fs::read_to_string(&note).map_err(|e| e.to_string())?, NOTE);
+
+        assert!(split_note_into_actions(note_path.clone(), project_path.clone(), 7, true).is_err());
+
+        let result = split_note_into_actions(note_path, project_path, 2, false)?;
+        assert_eq!(result.actions.len(), 1);
+        let action = fs::read_to_string(&result.actions[0].path).map_err(|e| e.to_string())?;
+        assert!(action.contains("Cover the new sync.\n- [ ] Collect changelog\n- [x] Pick a title"));
+        assert!(action.contains("### Detail\nStays with the section."));
+
+        let rewritten = fs::read_to_string(&note).map_err(|e| e.to_string())?;
+        assert!(rewritten.starts_with("# Brainstorm\n\nIdeas from the offsite.\n\n## Draft release notes\n\nMoved to action: [Draft release notes](<../Projects/Alpha Project/Draft release notes.md>)\n\n## Old action\nAlready exists.\n"));
+        assert!(!rewritten.contains("Collect changelog"));
+        assert!(rewritten.ends_with("## Draft release notes\nDuplicate.\n"));
+
+        Ok(())
+    }
+}
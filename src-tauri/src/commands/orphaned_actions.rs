@@ -0,0 +1,302 @@
+//! Action files under `Projects` that no project view can see.
+//!
+//! Projects are folders with a README, so a markdown file in the Projects
+//! root or in a folder without a README is orphaned. Orphans can be moved
+//! into a real project, or their folder can be given a README.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use tauri::AppHandle;
+use walkdir::WalkDir;
+
+use super::action_table::sync_action_table_after_change;
+use super::file_meta::record_file_change;
+use super::filesystem::move_file;
+use super::gtd_projects::{resolve_project_readme_path, validate_projects_child_directory};
+use super::seed_data::generate_project_readme;
+use super::space_config::ensure_space_writable;
+use super::utils::{is_markdown_path, is_readme_path};
+
+/// Why an action is orphaned
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum OrphanReason {
+    /// The file sits directly in the Projects directory
+    ProjectsRoot,
+    /// The file's folder has no README.md
+    MissingReadme,
+}
+
+/// A markdown file under Projects outside any project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrphanedAction {
+    /// Full path to the file
+    pub path: String,
+    /// File name without extension
+    pub name: String,
+    pub reason: OrphanReason,
+    /// Folder without a README, for `missing-readme` orphans
+    pub folder: Option<String>,
+}
+
+pub(crate) fn collect_orphaned_actions(space_path: &Path) -> Result<Vec<OrphanedAction>, String> {
+    let projects_dir = space_path.join("Projects");
+    if !projects_dir.is_dir() {
+        return Err("Projects directory does not exist".to_string());
+    }
+
+    // Projects are direct children of Projects; deeper folders such as
+    // `milestones/` belong to their project
+    let entries = WalkDir::new(&projects_dir)
+        .min_depth(1)
+        .max_depth(2)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| !entry.file_name().to_string_lossy().starts_with('.'));
+
+    let mut orphans = Vec::new();
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to scan Projects: {}", e))?;
+        let path = entry.path();
+        if !entry.file_type().is_file() || !is_markdown_path(path) || is_readme_path(path) {
+            continue;
+        }
+        let Some(parent) = path.parent() else {
+            continue;
+        };
+
+        let (reason, folder) = if parent == projects_dir {
+            (OrphanReason::ProjectsRoot, None)
+        } else if resolve_project_readme_path(parent).is_none() {
+            (
+                OrphanReason::MissingReadme,
+                Some(parent.to_string_lossy().to_string()),
+            )
+        } else {
+            continue;
+        };
+        orphans.push(OrphanedAction {
+            path: path.to_string_lossy().to_string(),
+            name: path
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string(),
+            reason,
+            folder,
+        });
+    }
+    Ok(orphans)
+}
+
+/// Find action files that belong to no project
+///
+/// Reports markdown files in the Projects root and in any folder directly
+/// under Projects that has no README.md. Subfolders of project folders, such
+/// as `milestones/`, are not scanned.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Orphaned files in path order, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const orphans = await invoke<OrphanedAction[]>('find_orphaned_actions', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn find_orphaned_actions(space_path: String) -> Result<Vec<OrphanedAction>, String> {
+    log::info!("Finding orphaned actions in: {}", space_path);
+
+    let orphans = collect_orphaned_actions(Path::new(&space_path))?;
+    log::info!("Found {} orphaned actions", orphans.len());
+    Ok(orphans)
+}
+
+/// Move an orphaned action into a project
+///
+/// Uses `move_file`, so action tables are refreshed and open tabs follow the
+/// file to its new path.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for the path remap event
+/// * `action_path` - Full path to the orphaned action
+/// * `target_project_path` - Project folder (with a README) to move it into
+///
+/// # Returns
+///
+/// The action's new path, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const newPath = await invoke<string>('adopt_orphaned_action', {
+///   actionPath: '/path/to/space/Projects/Call Sam.md',
+///   targetProjectPath: '/path/to/space/Projects/Website',
+/// });
+/// ```
+#[tauri::command]
+pub fn adopt_orphaned_action(
+    app: AppHandle,
+    action_path: String,
+    target_project_path: String,
+) -> Result<String, String> {
+    log::info!(
+        "Adopting orphaned action {} into {}",
+        action_path,
+        target_project_path
+    );
+
+    let source = Path::new(&action_path);
+    if !source.is_file() || !is_markdown_path(source) {
+        return Err("Action path is not a markdown file".to_string());
+    }
+    let target = Path::new(&target_project_path);
+    validate_projects_child_directory(target)?;
+    if resolve_project_readme_path(target).is_none() {
+        return Err("Target folder is not a project (it has no README.md)".to_string());
+    }
+
+    let file_name = source
+        .file_name()
+        .ok_or_else(|| "Action path has no file name".to_string())?;
+    let dest = target.join(file_name);
+    if dest.exists() {
+        return Err(format!(
+            "An action named {} already exists in the target project",
+            file_name.to_string_lossy()
+        ));
+    }
+
+    let dest_path = dest.to_string_lossy().to_string();
+    move_file(app, action_path, dest_path.clone())?;
+    Ok(dest_path)
+}
+
+/// Turn a folder of orphaned actions into a project
+///
+/// Writes a README.md named after the folder, with in-progress status.
+///
+/// # Arguments
+///
+/// * `folder_path` - Folder directly inside Projects that has no README
+/// * `description` - Project description for the README
+///
+/// # Returns
+///
+/// Path to the new README, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<string>('create_readme_for_folder', {
+///   folderPath: '/path/to/space/Projects/Garage Cleanup',
+///   description: 'Clear out and organize the garage',
+/// });
+/// ```
+#[tauri::command]
+pub fn create_readme_for_folder(
+    folder_path: String,
+    description: String,
+) -> Result<String, String> {
+    log::info!("Creating README for folder: {}", folder_path);
+
+    let folder = Path::new(&folder_path);
+    ensure_space_writable(folder)?;
+    if !folder.is_dir() {
+        return Err("Folder does not exist".to_string());
+    }
+    validate_projects_child_directory(folder)?;
+    if resolve_project_readme_path(folder).is_some() {
+        return Err("Folder already has a README".to_string());
+    }
+
+    let name = folder
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    let description = match description.trim() {
+        "" => "No description available",
+        description => description,
+    };
+    let readme = generate_project_readme(&name, description, None, "in-progress");
+
+    let readme_path = folder.join("README.md");
+    let mut file = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&readme_path)
+        .map_err(|e| format!("Failed to create README: {}", e))?;
+    if let Err(e) = file.write_all(readme.as_bytes()) {
+        drop(file);
+        let _ = fs::remove_file(&readme_path);
+        return Err(format!("Failed to write README: {}", e));
+    }
+
+    sync_action_table_after_change(&readme_path);
+    record_file_change(&readme_path);
+    Ok(readme_path.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn orphans_are_found_and_folders_can_become_projects() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let projects = root.join("Projects");
+        write_test_file(projects.join("Alpha Project/Draft.md"), "# Draft\n")?;
+        write_test_file(
+            projects.join("Alpha Project/milestones/Beta launch.md"),
+            "# Beta launch\n",
+        )?;
+        write_test_file(projects.join("Garage/Shelves/Plan.md"), "# Plan\n")?;
+        write_test_file(projects.join("Call Sam.md"), "# Call Sam\n")?;
+        write_test_file(projects.join("Garage/Sort tools.md"), "# Sort tools\n")?;
+        write_test_file(projects.join(".trash/Old.md"), "# Old\n")?;
+
+        let orphans = collect_orphaned_actions(root)?;
+        let summary: Vec<(&str, OrphanReason)> = orphans
+            .iter()
+            .map(|orphan| (orphan.name.as_str(), orphan.reason))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Call Sam", OrphanReason::ProjectsRoot),
+                ("Sort tools", OrphanReason::MissingReadme),
+            ]
+        );
+        let folder = orphans[1].folder.clone().expect("orphan folder");
+
+        let readme = create_readme_for_folder(folder.clone(), String::new())?;
+        let content = fs::read_to_string(&readme).map_err(|e| e.to_string())?;
+        assert!(content.starts_with("# Garage\n"));
+        assert!(create_readme_for_folder(folder, String::new()).is_err());
+
+        let orphans = collect_orphaned_actions(root)?;
+        assert_eq!(orphans.len(), 1);
+        assert_eq!(orphans[0].reason, OrphanReason::ProjectsRoot);
+
+        Ok(())
+    }
+}
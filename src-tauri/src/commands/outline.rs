@@ -0,0 +1,471 @@
+//! Single-payload space outline for the sidebar.
+//!
+//! The outline is cached per space and tagged with a generation number. The
+//! file watcher bumps the generation for any change under a space, which drops
+//! the cached outline and tells pollers to refetch.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use super::file_icons::read_file_icon;
+use super::file_previews::{file_preview, truncate_preview, DEFAULT_PREVIEW_LEN};
+use super::filesystem::{scan_directory_recursive, MarkdownFile};
+use super::gtd_habits_domain::{is_habit_history_archive, parse_habit_state};
+use super::gtd_projects::{list_gtd_projects, GTDProject};
+
+/// Horizon directories listed in the outline, in sidebar order
+pub(crate) const HORIZON_DIRECTORIES: [&str; 4] =
+    ["Areas of Focus", "Goals", "Vision", "Purpose & Principles"];
+
+#[derive(Default)]
+struct OutlineState {
+    generation: u64,
+    cached: Option<SpaceOutline>,
+}
+
+lazy_static::lazy_static! {
+    static ref OUTLINE_STATE: Mutex<HashMap<PathBuf, OutlineState>> = Mutex::new(HashMap::new());
+}
+
+/// Markdown files in one horizon directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonFiles {
+    /// Directory name (e.g. "Goals")
+    pub directory: String,
+    /// Markdown files found in the directory
+    pub files: Vec<HorizonFile>,
+}
+
+/// A horizon file with its icon, if it has one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonFile {
+    #[serde(flatten)]
+    pub file: MarkdownFile,
+    /// Emoji or icon name from the file's `[!icon:...]` marker
+    pub icon: Option<String>,
+    /// First line of body text, when previews were requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+}
+
+/// Lightweight habit info for the sidebar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HabitSummary {
+    /// Habit name (file stem)
+    pub name: String,
+    /// Full path to the habit file
+    pub path: String,
+    /// "todo" or "completed", if the habit could be parsed
+    pub status: Option<String>,
+    /// Frequency token (e.g. "daily"), if the habit could be parsed
+    pub frequency: Option<String>,
+}
+
+/// A file or folder in a nested outline tree
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutlineNode {
+    /// File or folder name
+    pub name: String,
+    /// Full path
+    pub path: String,
+    /// Whether this node is a folder
+    pub is_directory: bool,
+    /// Child nodes (folders only)
+    pub children: Vec<OutlineNode>,
+    /// First line of body text for files, when previews were requested
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preview: Option<String>,
+}
+
+/// Item counts for the outline sections
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OutlineCounts {
+    pub projects: usize,
+    pub actions: usize,
+    pub habits: usize,
+    pub horizon_files: usize,
+    pub someday_maybe: usize,
+    pub cabinet: usize,
+}
+
+/// Everything the sidebar needs for a space in one payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceOutline {
+    /// Generation this outline was built for
+    pub generation: u64,
+    /// Projects with action counts
+    pub projects: Vec<GTDProject>,
+    /// Files per horizon directory
+    pub horizons: Vec<HorizonFiles>,
+    /// Habit summaries
+    pub habits: Vec<HabitSummary>,
+    /// Someday Maybe tree
+    pub someday_maybe: Vec<OutlineNode>,
+    /// Cabinet tree
+    pub cabinet: Vec<OutlineNode>,
+    /// Section counts
+    pub counts: OutlineCounts,
+}
+
+fn space_key(space_path: &str) -> PathBuf {
+    let path = Path::new(space_path);
+    path.canonicalize().unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn lock_state() -> std::sync::MutexGuard<'static, HashMap<PathBuf, OutlineState>> {
+    OUTLINE_STATE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Bump the generation of every space containing `changed_path`.
+pub(crate) fn invalidate_outline(changed_path: &Path) {
+    // Deleted paths cannot be canonicalized, so fall back to the parent folder.
+    let canonical_parent = changed_path.parent().and_then(|p| p.canonicalize().ok());
+
+    let mut state = lock_state();
+    for (root, entry) in state.iter_mut() {
+        let contains_change = changed_path.starts_with(root)
+            || canonical_parent
+                .as_ref()
+                .is_some_and(|parent| parent.starts_with(root));
+        if contains_change {
+            entry.generation += 1;
+            entry.cached = None;
+        }
+    }
+}
+
+fn count_tree_files(nodes: &[OutlineNode]) -> usize {
+    nodes
+        .iter()
+        .map(|node| {
+            if node.is_directory {
+                count_tree_files(&node.children)
+            } else {
+                1
+            }
+        })
+        .sum()
+}
+
+fn build_outline_tree(dir: &Path) -> Vec<OutlineNode> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut nodes = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') {
+            continue;
+        }
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        if file_type.is_symlink() {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            nodes.push(OutlineNode {
+                name,
+                children: build_outline_tree(&path),
+                path: path.to_string_lossy().to_string(),
+                is_directory: true,
+                preview: None,
+            });
+        } else if file_type.is_file() {
+            let is_markdown = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                });
+            if is_markdown {
+                nodes.push(OutlineNode {
+                    name,
+                    path: path.to_string_lossy().to_string(),
+                    is_directory: false,
+                    children: Vec::new(),
+                    preview: None,
+                });
+            }
+        }
+    }
+
+    nodes.sort_by(|a, b| {
+        b.is_directory
+            .cmp(&a.is_directory)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    nodes
+}
+
+fn list_habit_summaries(habits_dir: &Path) -> Vec<HabitSummary> {
+    let Ok(entries) = fs::read_dir(habits_dir) else {
+        return Vec::new();
+    };
+
+    let mut habits: Vec<HabitSummary> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && !is_habit_history_archive(path)
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| {
+                        ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                    })
+        })
+        .map(|path| {
+            let parsed = fs::read_to_string(&path)
+                .ok()
+                .and_then(|content| parse_habit_state(&content).ok());
+            HabitSummary {
+                name: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .to_string(),
+                path: path.to_string_lossy().to_string(),
+                status: parsed
+                    .as_ref()
+                    .map(|state| state.status.marker_token().to_string()),
+                frequency: parsed.map(|state| state.frequency.as_marker_token().to_string()),
+            }
+        })
+        .collect();
+
+    habits.sort_by_key(|habit| habit.name.to_lowercase());
+    habits
+}
+
+fn preview_text(path: &str) -> String {
+    truncate_preview(&file_preview(Path::new(path)), DEFAULT_PREVIEW_LEN)
+}
+
+fn add_tree_previews(nodes: &mut [OutlineNode]) {
+    for node in nodes {
+        if node.is_directory {
+            add_tree_previews(&mut node.children);
+        } else {
+            node.preview = Some(preview_text(&node.path));
+        }
+    }
+}
+
+/// Fill in previews for horizon files and tree files. Done after caching so
+/// the cached outline serves both kinds of request.
+fn add_outline_previews(outline: &mut SpaceOutline) {
+    for horizon in &mut outline.horizons {
+        for file in &mut horizon.files {
+            file.preview = Some(preview_text(&file.file.path));
+        }
+    }
+    add_tree_previews(&mut outline.someday_maybe);
+    add_tree_previews(&mut outline.cabinet);
+}
+
+fn build_space_outline(space_root: &Path, generation: u64) -> Result<SpaceOutline, String> {
+    let projects = list_gtd_projects(space_root.to_string_lossy().to_string())?;
+
+    let mut horizons = Vec::new();
+    for directory in HORIZON_DIRECTORIES {
+        let dir_path = space_root.join(directory);
+        let mut files = Vec::new();
+        if dir_path.is_dir() {
+            scan_directory_recursive(&dir_path, &mut files)?;
+            files.sort_by_key(|file| file.name.to_lowercase());
+        }
+        horizons.push(HorizonFiles {
+            directory: directory.to_string(),
+            files: files
+                .into_iter()
+                .map(|file| HorizonFile {
+                    icon: read_file_icon(Path::new(&file.path)),
+                    preview: None,
+                    file,
+                })
+                .collect(),
+        });
+    }
+
+    let habits = list_habit_summaries(&space_root.join("Habits"));
+    let someday_maybe = build_outline_tree(&space_root.join("Someday Maybe"));
+    let cabinet = build_outline_tree(&space_root.join("Cabinet"));
+
+    let counts = OutlineCounts {
+        projects: projects.len(),
+        actions: projects.iter().map(|p| p.action_count as usize).sum(),
+        habits: habits.len(),
+        horizon_files: horizons.iter().map(|h| h.files.len()).sum(),
+        someday_maybe: count_tree_files(&someday_maybe),
+        cabinet: count_tree_files(&cabinet),
+    };
+
+    Ok(SpaceOutline {
+        generation,
+        projects,
+        horizons,
+        habits,
+        someday_maybe,
+        cabinet,
+        counts,
+    })
+}
+
+/// Get the full sidebar outline for a GTD space
+///
+/// Returns projects, horizon files, habit summaries, the Someday Maybe and
+/// Cabinet trees, and counts in one payload. While the file watcher covers
+/// the space, repeat calls are served from cache until a change bumps the
+/// generation.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `include_previews` - Add a one-line content preview to every horizon
+///   file and Someday Maybe / Cabinet file (defaults to false)
+///
+/// # Returns
+///
+/// `SpaceOutline` or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const outline = await invoke<SpaceOutline>('get_space_outline', {
+///   spacePath: '/path/to/space',
+///   includePreviews: true,
+/// });
+/// ```
+#[tauri::command]
+pub async fn get_space_outline(
+    space_path: String,
+    include_previews: Option<bool>,
+) -> Result<SpaceOutline, String> {
+    let outline = cached_or_built_outline(space_path).await?;
+    if !include_previews.unwrap_or(false) {
+        return Ok(outline);
+    }
+
+    tokio::task::spawn_blocking(move || {
+        let mut outline = outline;
+        add_outline_previews(&mut outline);
+        outline
+    })
+    .await
+    .map_err(|e| format!("Failed to add outline previews: {}", e))
+}
+
+async fn cached_or_built_outline(space_path: String) -> Result<SpaceOutline, String> {
+    let key = space_key(&space_path);
+    if !key.is_dir() {
+        return Err("Invalid directory path".to_string());
+    }
+
+    let watched = super::watcher::watched_folder()
+        .await
+        .map(|folder| folder.canonicalize().unwrap_or(folder))
+        .is_some_and(|folder| key.starts_with(folder));
+
+    let generation = {
+        let mut state = lock_state();
+        let entry = state.entry(key.clone()).or_default();
+        if watched {
+            if let Some(cached) = &entry.cached {
+                return Ok(cached.clone());
+            }
+        }
+        entry.generation
+    };
+
+    let build_root = key.clone();
+    let outline = tokio::task::spawn_blocking(move || build_space_outline(&build_root, generation))
+        .await
+        .map_err(|e| format!("Failed to build space outline: {}", e))??;
+
+    if watched {
+        let mut state = lock_state();
+        let entry = state.entry(key).or_default();
+        // Only cache if nothing changed while the outline was being built.
+        if entry.generation == generation {
+            entry.cached = Some(outline.clone());
+        }
+    }
+
+    Ok(outline)
+}
+
+/// Get the current outline generation for a GTD space
+///
+/// The number increases whenever the file watcher sees a change in the
+/// space, so the frontend can poll this cheaply and refetch
+/// `get_space_outline` only when it changes.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// Current generation number
+#[tauri::command]
+pub fn get_outline_generation(space_path: String) -> Result<u64, String> {
+    let key = space_key(&space_path);
+    let mut state = lock_state();
+    Ok(state.entry(key).or_default().generation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[tokio::test]
+    async fn outline_collects_sections_and_generation_tracks_changes() -> Result<(), String> {
+        let temp_dir = seed_test_workspace()?;
+        let root = temp_dir.path();
+        write_test_file(
+            root.join("Habits/Stretch.md"),
+            "# Stretch\n\n[!checkbox:habit-status:true]\n[!singleselect:habit-frequency:daily]\n",
+        )?;
+        write_test_file(root.join("Cabinet/Reference/Tax.md"), "# Tax\n")?;
+        write_test_file(
+            root.join("Someday Maybe/Sail.md"),
+            "# Sail\n\nCross the Atlantic.\n",
+        )?;
+        let space_path = root.to_string_lossy().to_string();
+
+        let outline = get_space_outline(space_path.clone(), None).await?;
+        assert_eq!(outline.counts.projects, 1);
+        assert_eq!(outline.counts.habits, 1);
+        assert_eq!(outline.habits[0].status.as_deref(), Some("completed"));
+        assert_eq!(outline.habits[0].frequency.as_deref(), Some("daily"));
+        assert_eq!(outline.counts.horizon_files, 1);
+        assert_eq!(outline.counts.cabinet, 1);
+        assert!(outline.cabinet[0].is_directory);
+        assert_eq!(outline.counts.someday_maybe, 1);
+        assert!(outline.cabinet[0].children[0].preview.is_none());
+
+        let with_previews = get_space_outline(space_path.clone(), Some(true)).await?;
+        assert_eq!(
+            with_previews.someday_maybe[0].preview.as_deref(),
+            Some("Cross the Atlantic.")
+        );
+
+        let before = get_outline_generation(space_path.clone())?;
+        invalidate_outline(&root.join("Cabinet/Reference/Tax.md"));
+        assert_eq!(get_outline_generation(space_path)?, before + 1);
+        Ok(())
+    }
+}
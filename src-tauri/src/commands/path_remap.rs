@@ -0,0 +1,106 @@
+//! `paths-remapped` notifications for backend-driven renames and moves.
+//!
+//! The frontend keeps open tabs keyed by path; whenever a command changes a
+//! path it may know about, it emits one event listing every old/new pair so
+//! those tabs can follow the file instead of going stale.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use tauri::{AppHandle, Emitter};
+
+/// Event name listened to by the frontend tab manager
+pub(crate) const PATHS_REMAPPED_EVENT: &str = "paths-remapped";
+
+/// A single path that moved
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PathRemap {
+    /// Path before the operation
+    pub old_path: String,
+    /// Path after the operation
+    pub new_path: String,
+}
+
+impl PathRemap {
+    pub(crate) fn new(old_path: impl AsRef<Path>, new_path: impl AsRef<Path>) -> Self {
+        Self {
+            old_path: old_path.as_ref().to_string_lossy().to_string(),
+            new_path: new_path.as_ref().to_string_lossy().to_string(),
+        }
+    }
+}
+
+/// Emit a single `paths-remapped` event for `pairs`.
+///
+/// Unchanged pairs are dropped and nothing is emitted when none remain.
+/// Emit failures are logged so the filesystem operation still succeeds.
+pub(crate) fn emit_remap(app: &AppHandle, pairs: &[PathRemap]) {
+    let pairs: Vec<&PathRemap> = pairs
+        .iter()
+        .filter(|pair| pair.old_path != pair.new_path)
+        .collect();
+    if pairs.is_empty() {
+        return;
+    }
+
+    log::info!("Emitting {} path remap(s)", pairs.len());
+    if let Err(e) = app.emit(PATHS_REMAPPED_EVENT, &pairs) {
+        log::error!("Failed to emit paths remapped event: {}", e);
+    }
+}
+
+/// Pair every file now under `new_dir` with where it lived under `old_dir`.
+///
+/// Call after the directory has been renamed. Pairs are sorted by new path.
+pub(crate) fn directory_remaps(old_dir: &Path, new_dir: &Path) -> Vec<PathRemap> {
+    fn collect(dir: &Path, old_dir: &Path, new_dir: &Path, pairs: &mut Vec<PathRemap>) {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.is_dir() {
+                collect(&path, old_dir, new_dir, pairs);
+            } else if let Ok(relative) = path.strip_prefix(new_dir) {
+                pairs.push(PathRemap::new(old_dir.join(relative), &path));
+            }
+        }
+    }
+
+    let mut pairs = Vec::new();
+    collect(new_dir, old_dir, new_dir, &mut pairs);
+    pairs.sort_by(|a, b| a.new_path.cmp(&b.new_path));
+    pairs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::gtd_projects::rename_project_folder;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn project_rename_maps_readme_and_every_action() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let projects = workspace.path().join("Projects");
+        let old_dir = projects.join("Launch");
+        write_test_file(old_dir.join("README.md"), "# Launch\n")?;
+        write_test_file(old_dir.join("Draft.md"), "# Draft\n")?;
+        write_test_file(old_dir.join("Review.md"), "# Review\n")?;
+
+        let new_dir = rename_project_folder(&old_dir.to_string_lossy(), "Relaunch")?;
+        let remaps = directory_remaps(&old_dir, Path::new(&new_dir));
+
+        let new_dir = projects.join("Relaunch");
+        assert_eq!(
+            remaps,
+            vec![
+                PathRemap::new(old_dir.join("Draft.md"), new_dir.join("Draft.md")),
+                PathRemap::new(old_dir.join("README.md"), new_dir.join("README.md")),
+                PathRemap::new(old_dir.join("Review.md"), new_dir.join("Review.md")),
+            ]
+        );
+
+        Ok(())
+    }
+}
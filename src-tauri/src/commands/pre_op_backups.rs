@@ -0,0 +1,424 @@
+//! Automatic snapshots taken before bulk mutations.
+//!
+//! Bulk operations call [`backup_before_bulk_operation`] with the files they
+//! are about to modify. When more files are touched than the space's
+//! threshold, those files are archived to
+//! `.gtdspace/pre-op-backups/<timestamp>-<operation>.tar.gz` with a JSON
+//! sidecar recording the operation id. This works without git sync being
+//! configured.
+
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::BufWriter;
+use std::path::{Component, Path, PathBuf};
+use tar::{Archive, Builder as TarBuilder};
+
+use super::space_config::{ensure_space_writable, load_space_config, SPACE_CONFIG_DIR};
+
+const PRE_OP_BACKUP_DIR: &str = "pre-op-backups";
+const ARCHIVE_EXTENSION: &str = ".tar.gz";
+/// Default file count above which bulk operations are backed up
+pub(crate) const DEFAULT_PRE_OP_BACKUP_THRESHOLD: usize = 10;
+/// Backups older than this are pruned
+const MAX_BACKUP_AGE_DAYS: i64 = 30;
+/// Only this many of the newest backups are kept
+const MAX_BACKUP_COUNT: usize = 20;
+
+/// A snapshot written before a bulk operation
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PreOpBackup {
+    /// Archive file name, e.g. `20260302T091500Z-replace_in_files.tar.gz`
+    pub name: String,
+    /// Operation that triggered the backup
+    pub operation: String,
+    /// Id of the operation run, for matching backups to history entries
+    pub operation_id: String,
+    /// RFC 3339 creation time
+    pub created_at: String,
+    /// Space-relative paths stored in the archive
+    pub files: Vec<String>,
+    /// Archive size in bytes
+    #[serde(default)]
+    pub size: u64,
+}
+
+fn backup_dir(space_root: &Path) -> PathBuf {
+    space_root.join(SPACE_CONFIG_DIR).join(PRE_OP_BACKUP_DIR)
+}
+
+fn sidecar_path(archive: &Path) -> PathBuf {
+    let name = archive.file_name().unwrap_or_default().to_string_lossy();
+    let stem = name.strip_suffix(ARCHIVE_EXTENSION).unwrap_or(&name);
+    archive.with_file_name(format!("{}.json", stem))
+}
+
+/// Keep operation names safe for file names.
+fn sanitize_operation(operation: &str) -> String {
+    let cleaned: String = operation
+        .trim()
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '-' || ch == '_' {
+                ch
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if cleaned.is_empty() {
+        "operation".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Space-relative path with forward slashes, or `None` outside the space.
+fn relative_to_space(space_root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(space_root).ok()?;
+    relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+        .then(|| relative.to_string_lossy().replace('\\', "/"))
+}
+
+fn unused_archive_path(dir: &Path, stem: &str) -> PathBuf {
+    let mut candidate = dir.join(format!("{}{}", stem, ARCHIVE_EXTENSION));
+    let mut suffix = 2;
+    while candidate.exists() {
+        candidate = dir.join(format!("{}-{}{}", stem, suffix, ARCHIVE_EXTENSION));
+        suffix += 1;
+    }
+    candidate
+}
+
+fn write_archive(space_root: &Path, archive: &Path, files: &[String]) -> Result<(), String> {
+    let file = File::create(archive).map_err(|e| {
+        format!(
+            "Failed to create pre-op backup {}: {}",
+            archive.display(),
+            e
+        )
+    })?;
+    let encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    let mut builder = TarBuilder::new(encoder);
+    for relative in files {
+        builder
+            .append_path_with_name(space_root.join(relative), relative)
+            .map_err(|e| format!("Failed to add {} to pre-op backup: {}", relative, e))?;
+    }
+    builder
+        .into_inner()
+        .and_then(|encoder| encoder.finish())
+        .map_err(|e| format!("Failed to finish pre-op backup: {}", e))?;
+    Ok(())
+}
+
+fn read_backups(space_root: &Path) -> Result<Vec<PreOpBackup>, String> {
+    let dir = backup_dir(space_root);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(&dir).map_err(|e| format!("Failed to read backups: {}", e))? {
+        let path = entry
+            .map_err(|e| format!("Failed to read backups: {}", e))?
+            .path();
+        if !path.to_string_lossy().ends_with(ARCHIVE_EXTENSION) {
+            continue;
+        }
+        let Some(mut backup) = fs::read_to_string(sidecar_path(&path))
+            .ok()
+            .and_then(|raw| serde_json::from_str::<PreOpBackup>(&raw).ok())
+        else {
+            log::warn!(
+                "Skipping pre-op backup without metadata: {}",
+                path.display()
+            );
+            continue;
+        };
+        backup.size = fs::metadata(&path).map(|m| m.len()).unwrap_or_default();
+        backups.push(backup);
+    }
+    backups.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.name.cmp(&a.name)));
+    Ok(backups)
+}
+
+/// Remove backups past the age limit and beyond the count cap.
+fn prune_backups(space_root: &Path, now: DateTime<Utc>) -> Result<(), String> {
+    let cutoff = now - chrono::Duration::days(MAX_BACKUP_AGE_DAYS);
+    let dir = backup_dir(space_root);
+    for (index, backup) in read_backups(space_root)?.into_iter().enumerate() {
+        let expired = DateTime::parse_from_rfc3339(&backup.created_at)
+            .is_ok_and(|created| created.with_timezone(&Utc) < cutoff);
+        if index < MAX_BACKUP_COUNT && !expired {
+            continue;
+        }
+        let archive = dir.join(&backup.name);
+        log::info!("Pruning pre-op backup: {}", archive.display());
+        fs::remove_file(&archive)
+            .map_err(|e| format!("Failed to prune backup {}: {}", backup.name, e))?;
+        let _ = fs::remove_file(sidecar_path(&archive));
+    }
+    Ok(())
+}
+
+/// Snapshot `files` before a bulk operation modifies them.
+///
+/// Returns `None` without writing anything when `skip_backup` is set or the
+/// operation touches no more files than the space's threshold. Paths outside
+/// the space and files that do not exist yet are left out of the archive.
+pub(crate) fn backup_before_bulk_operation(
+    space_root: &Path,
+    operation: &str,
+    operation_id: &str,
+    files: &[PathBuf],
+    skip_backup: bool,
+) -> Result<Option<PreOpBackup>, String> {
+    if skip_backup {
+        log::info!("Skipping pre-op backup for {} on request", operation);
+        return Ok(None);
+    }
+    let threshold = load_space_config(space_root)?
+        .pre_op_backup_threshold
+        .unwrap_or(DEFAULT_PRE_OP_BACKUP_THRESHOLD);
+    if files.len() <= threshold {
+        return Ok(None);
+    }
+
+    let mut relative_files: Vec<String> = files
+        .iter()
+        .filter(|path| path.is_file())
+        .filter_map(|path| relative_to_space(space_root, path))
+        .collect();
+    relative_files.sort();
+    relative_files.dedup();
+
+    let dir = backup_dir(space_root);
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create backup directory: {}", e))?;
+    let now = Utc::now();
+    let operation = sanitize_operation(operation);
+    let archive = unused_archive_path(
+        &dir,
+        &format!("{}-{}", now.format("%Y%m%dT%H%M%SZ"), operation),
+    );
+    write_archive(space_root, &archive, &relative_files)?;
+
+    let backup = PreOpBackup {
+        name: archive
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string(),
+        operation,
+        operation_id: operation_id.to_string(),
+        created_at: now.to_rfc3339(),
+        files: relative_files,
+        size: fs::metadata(&archive).map(|m| m.len()).unwrap_or_default(),
+    };
+    let sidecar = serde_json::to_string_pretty(&backup)
+        .map_err(|e| format!("Failed to serialize backup metadata: {}", e))?;
+    fs::write(sidecar_path(&archive), sidecar)
+        .map_err(|e| format!("Failed to write backup metadata: {}", e))?;
+    log::info!(
+        "Wrote pre-op backup {} ({} files)",
+        backup.name,
+        backup.files.len()
+    );
+
+    prune_backups(space_root, now)?;
+    Ok(Some(backup))
+}
+
+/// List automatic pre-operation backups
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// Backups newest first, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const backups = await invoke<PreOpBackup[]>('list_pre_op_backups', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_pre_op_backups(space_path: String) -> Result<Vec<PreOpBackup>, String> {
+    log::info!("Listing pre-op backups in: {}", space_path);
+    read_backups(Path::new(&space_path))
+}
+
+/// Restore the files saved by a pre-operation backup
+///
+/// Overwrites the current copies of the backed-up files; files created or
+/// modified outside the backup are left alone.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `backup_name` - Archive name from `list_pre_op_backups`
+///
+/// # Returns
+///
+/// Full paths of the restored files, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const restored = await invoke<string[]>('restore_pre_op_backup', {
+///   spacePath: '/path/to/space',
+///   backupName: '20260302T091500Z-replace_in_files.tar.gz',
+/// });
+/// ```
+#[tauri::command]
+pub fn restore_pre_op_backup(
+    space_path: String,
+    backup_name: String,
+) -> Result<Vec<String>, String> {
+    log::info!("Restoring pre-op backup {} in: {}", backup_name, space_path);
+
+    let space_root = Path::new(&space_path);
+    ensure_space_writable(space_root)?;
+    if backup_name.contains(['/', '\\']) || !backup_name.ends_with(ARCHIVE_EXTENSION) {
+        return Err(format!("Invalid backup name: {}", backup_name));
+    }
+    let archive_path = backup_dir(space_root).join(&backup_name);
+    let file = File::open(&archive_path)
+        .map_err(|e| format!("Failed to open backup {}: {}", backup_name, e))?;
+
+    let mut archive = Archive::new(GzDecoder::new(file));
+    let mut restored = Vec::new();
+    for entry in archive
+        .entries()
+        .map_err(|e| format!("Failed to read backup {}: {}", backup_name, e))?
+    {
+        let mut entry = entry.map_err(|e| format!("Failed to read backup entry: {}", e))?;
+        let relative = entry
+            .path()
+            .map_err(|e| format!("Invalid path in backup: {}", e))?
+            .into_owned();
+        // unpack_in refuses entries that would land outside the space
+        if entry
+            .unpack_in(space_root)
+            .map_err(|e| format!("Failed to restore {}: {}", relative.display(), e))?
+        {
+            restored.push(space_root.join(&relative).to_string_lossy().to_string());
+        }
+    }
+
+    log::info!("Restored {} files from {}", restored.len(), backup_name);
+    Ok(restored)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::space_config::{save_space_config, SpaceConfig};
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn bulk_operations_over_threshold_are_backed_up_and_restorable() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        save_space_config(
+            root,
+            &SpaceConfig {
+                pre_op_backup_threshold: Some(2),
+                ..SpaceConfig::default()
+            },
+        )?;
+        let files: Vec<PathBuf> = ["One", "Two", "Three"]
+            .iter()
+            .map(|name| root.join(format!("Cabinet/{}.md", name)))
+            .collect();
+        for file in &files {
+            write_test_file(file, "original\n")?;
+        }
+
+        assert!(backup_before_bulk_operation(
+            root,
+            "replace_in_files",
+            "op-1",
+            &files[..2],
+            false
+        )?
+        .is_none());
+        assert!(
+            backup_before_bulk_operation(root, "replace_in_files", "op-1", &files, true)?.is_none()
+        );
+        let backup = backup_before_bulk_operation(root, "replace_in_files", "op-2", &files, false)?
+            .expect("backup over threshold");
+        assert!(backup.name.ends_with("-replace_in_files.tar.gz"));
+        assert_eq!(backup.files.len(), 3);
+
+        let space_path = root.to_string_lossy().to_string();
+        let listed = list_pre_op_backups(space_path.clone())?;
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].operation_id, "op-2");
+
+        for file in &files {
+            write_test_file(file, "changed\n")?;
+        }
+        let restored = restore_pre_op_backup(space_path.clone(), backup.name.clone())?;
+        assert_eq!(restored.len(), 3);
+        for file in &files {
+            assert_eq!(
+                fs::read_to_string(file).map_err(|e| e.to_string())?,
+                "original\n"
+            );
+        }
+        assert!(restore_pre_op_backup(space_path, "../escape.tar.gz".to_string()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn old_and_excess_backups_are_pruned() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let dir = backup_dir(root);
+        let now = Utc::now();
+        // One backup per hour, newest first, plus one past the age limit
+        let ages: Vec<chrono::Duration> = (0..MAX_BACKUP_COUNT as i64 + 2)
+            .map(chrono::Duration::hours)
+            .chain([chrono::Duration::days(MAX_BACKUP_AGE_DAYS + 1)])
+            .collect();
+        for (index, age) in ages.iter().enumerate() {
+            let archive = dir.join(format!("backup-{:02}{}", index, ARCHIVE_EXTENSION));
+            write_test_file(&archive, "")?;
+            let backup = PreOpBackup {
+                name: archive.file_name().unwrap().to_string_lossy().to_string(),
+                operation: "test".to_string(),
+                operation_id: index.to_string(),
+                created_at: (now - *age).to_rfc3339(),
+                files: Vec::new(),
+                size: 0,
+            };
+            write_test_file(
+                sidecar_path(&archive),
+                &serde_json::to_string(&backup).map_err(|e| e.to_string())?,
+            )?;
+        }
+
+        prune_backups(root, now)?;
+        let remaining = read_backups(root)?;
+        assert_eq!(remaining.len(), MAX_BACKUP_COUNT);
+        assert_eq!(remaining[0].operation_id, "0");
+        assert!(!dir
+            .join(format!("backup-{:02}.json", ages.len() - 1))
+            .exists());
+
+        Ok(())
+    }
+}
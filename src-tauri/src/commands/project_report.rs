@@ -0,0 +1,626 @@
+//! One-page PDF summaries of a project.
+//!
+//! Reports are drawn directly with `printpdf` using the built-in Helvetica
+//! faces, so no external PDF tool or bundled font is needed. Long action lists
+//! continue onto further pages with the table header repeated.
+
+use printpdf::{
+    BuiltinFont, Color, IndirectFontRef, Mm, PaintMode, PdfDocument, PdfDocumentReference,
+    PdfLayerReference, Rect, Rgb,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::action_table::{collect_action_rows, ActionRow};
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary};
+use super::file_icons::{extract_icon, is_named_icon};
+use super::gtd_projects::{
+    extract_readme_title, parse_project_readme, resolve_project_readme_path,
+};
+
+const MARGIN_MM: f32 = 20.0;
+const PT_TO_MM: f32 = 0.3528;
+/// Average Helvetica glyph width as a fraction of the font size
+const AVERAGE_GLYPH_EM: f32 = 0.52;
+
+const TITLE_SIZE: f32 = 22.0;
+const HEADING_SIZE: f32 = 13.0;
+const BODY_SIZE: f32 = 10.5;
+const TABLE_SIZE: f32 = 10.0;
+const TABLE_ROW_MM: f32 = 7.0;
+
+/// Page sizes a report can be laid out on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PaperSize {
+    A4,
+    Letter,
+}
+
+impl PaperSize {
+    pub(crate) fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "a4" => Ok(PaperSize::A4),
+            "letter" => Ok(PaperSize::Letter),
+            other => Err(format!(
+                "Unsupported paper size '{}' (expected a4 or letter)",
+                other
+            )),
+        }
+    }
+
+    /// Width and height in millimetres
+    fn dimensions(self) -> (f32, f32) {
+        match self {
+            PaperSize::A4 => (210.0, 297.0),
+            PaperSize::Letter => (215.9, 279.4),
+        }
+    }
+}
+
+/// Action counts used for a project's completion percentage
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+pub struct ProjectCompletion {
+    pub total_actions: usize,
+    pub completed_actions: usize,
+    /// Cancelled actions are left out of the percentage
+    pub cancelled_actions: usize,
+    /// Completed share of non-cancelled actions, 0-100
+    pub percentage: f32,
+}
+
+fn is_completed(status: &str) -> bool {
+    matches!(
+        status.to_ascii_lowercase().as_str(),
+        "completed" | "complete" | "done"
+    )
+}
+
+fn is_cancelled(status: &str) -> bool {
+    matches!(
+        status.to_ascii_lowercase().as_str(),
+        "cancelled" | "canceled"
+    )
+}
+
+/// Completion statistics for a set of action rows.
+///
+/// Custom statuses count by the built-in status they map to.
+pub(crate) fn compute_project_completion_percentage(
+    rows: &[ActionRow],
+    vocabulary: &FieldVocabulary,
+) -> ProjectCompletion {
+    let completed_actions = rows
+        .iter()
+        .filter(|row| is_completed(vocabulary.builtin_status(&row.status)))
+        .count();
+    let cancelled_actions = rows
+        .iter()
+        .filter(|row| is_cancelled(vocabulary.builtin_status(&row.status)))
+        .count();
+    let counted = rows.len() - cancelled_actions;
+
+    ProjectCompletion {
+        total_actions: rows.len(),
+        completed_actions,
+        cancelled_actions,
+        percentage: if counted == 0 {
+            0.0
+        } else {
+            completed_actions as f32 * 100.0 / counted as f32
+        },
+    }
+}
+
+fn status_color(status: &str) -> (f32, f32, f32) {
+    match status.to_ascii_lowercase().as_str() {
+        "completed" | "complete" | "done" => (0.16, 0.55, 0.27),
+        "in-progress" => (0.15, 0.39, 0.78),
+        "waiting" => (0.80, 0.52, 0.05),
+        "cancelled" | "canceled" => (0.55, 0.55, 0.55),
+        _ => (0.30, 0.30, 0.30),
+    }
+}
+
+fn rgb((r, g, b): (f32, f32, f32)) -> Color {
+    Color::Rgb(Rgb::new(r, g, b, None))
+}
+
+/// Built-in PDF fonts only cover Latin text; swap common punctuation for
+/// ASCII and replace anything else so it cannot garble the page.
+fn pdf_safe_text(value: &str) -> String {
+    value
+        .chars()
+        .map(|ch| match ch {
+            '\u{2018}' | '\u{2019}' => '\'',
+            '\u{201C}' | '\u{201D}' => '"',
+            '\u{2013}' | '\u{2014}' => '-',
+            ' '..='~' => ch,
+            _ => '?',
+        })
+        .collect()
+}
+
+fn text_width_mm(text: &str, size: f32) -> f32 {
+    text.chars().count() as f32 * size * AVERAGE_GLYPH_EM * PT_TO_MM
+}
+
+fn line_height_mm(size: f32) -> f32 {
+    size * PT_TO_MM * 1.4
+}
+
+/// Greedy word wrap to lines no wider than `width_mm`.
+pub(crate) fn wrap_text(text: &str, size: f32, width_mm: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in text.split_whitespace() {
+        let candidate = if current.is_empty() {
+            word.to_string()
+        } else {
+            format!("{} {}", current, word)
+        };
+        if !current.is_empty() && text_width_mm(&candidate, size) > width_mm {
+            lines.push(std::mem::replace(&mut current, word.to_string()));
+        } else {
+            current = candidate;
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Shorten `text` with a trailing ellipsis so it fits `width_mm`.
+fn truncate_to_width(text: &str, size: f32, width_mm: f32) -> String {
+    if text_width_mm(text, size) <= width_mm {
+        return text.to_string();
+    }
+    let mut truncated: String = text.to_string();
+    while !truncated.is_empty() && text_width_mm(&format!("{}...", truncated), size) > width_mm {
+        truncated.pop();
+    }
+    format!("{}...", truncated.trim_end())
+}
+
+/// Full text of the Desired Outcome / Description section, markers removed.
+pub(crate) fn extract_description(content: &str) -> String {
+    let mut in_description = false;
+    let mut lines = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with("## ") {
+            in_description =
+                trimmed.starts_with("## Desired Outcome") || trimmed.starts_with("## Description");
+            continue;
+        }
+        if in_description && !trimmed.is_empty() && !trimmed.starts_with("[!") {
+            lines.push(trimmed);
+        }
+    }
+    lines.join(" ")
+}
+
+/// README title led by a named icon such as `[rocket]`. Emoji icons are
+/// left out because the built-in fonts cannot draw them.
+fn report_title(content: &str) -> String {
+    let title = extract_readme_title(content);
+    match extract_icon(content) {
+        Some(icon) if is_named_icon(&icon) => format!("[{}] {}", icon, title),
+        _ => title,
+    }
+}
+
+/// Everything a report shows, gathered before any drawing happens
+struct ProjectReport {
+    title: String,
+    status: String,
+    due_date: Option<String>,
+    description: String,
+    actions: Vec<ActionRow>,
+    completion: ProjectCompletion,
+}
+
+fn load_project_report(project_dir: &Path) -> Result<ProjectReport, String> {
+    let readme_path = resolve_project_readme_path(project_dir)
+        .ok_or_else(|| "Project README not found".to_string())?;
+    let content =
+        fs::read_to_string(&readme_path).map_err(|e| format!("Failed to read README: {}", e))?;
+    let (_, due_date, status, _) = parse_project_readme(&content);
+    let actions = collect_action_rows(project_dir)?;
+    let completion =
+        compute_project_completion_percentage(&actions, &field_vocabulary_or_default(project_dir));
+
+    Ok(ProjectReport {
+        title: report_title(&content),
+        status,
+        due_date,
+        description: extract_description(&content),
+        actions,
+        completion,
+    })
+}
+
+/// Drawing state for the page currently being filled
+struct PageWriter<'a> {
+    doc: &'a PdfDocumentReference,
+    layer: PdfLayerReference,
+    regular: IndirectFontRef,
+    bold: IndirectFontRef,
+    width: f32,
+    height: f32,
+    /// Baseline of the next line, measured from the bottom edge
+    y: f32,
+    pages: usize,
+}
+
+impl PageWriter<'_> {
+    fn content_width(&self) -> f32 {
+        self.width - 2.0 * MARGIN_MM
+    }
+
+    fn new_page(&mut self) {
+        self.pages += 1;
+        let (page, layer) = self.doc.add_page(
+            Mm(self.width),
+            Mm(self.height),
+            format!("Page {}", self.pages),
+        );
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = self.height - MARGIN_MM;
+    }
+
+    /// Start a new page unless `needed_mm` still fits above the bottom margin.
+    fn ensure_space(&mut self, needed_mm: f32) -> bool {
+        if self.y - needed_mm < MARGIN_MM {
+            self.new_page();
+            return true;
+        }
+        false
+    }
+
+    fn text(&self, text: &str, size: f32, x: f32, bold: bool, color: (f32, f32, f32)) {
+        self.layer.set_fill_color(rgb(color));
+        let font = if bold { &self.bold } else { &self.regular };
+        self.layer
+            .use_text(pdf_safe_text(text), size, Mm(x), Mm(self.y), font);
+    }
+
+    fn fill_rect(&self, x: f32, y: f32, width: f32, height: f32, color: (f32, f32, f32)) {
+        self.layer.set_fill_color(rgb(color));
+        self.layer.add_rect(
+            Rect::new(Mm(x), Mm(y), Mm(x + width), Mm(y + height)).with_mode(PaintMode::Fill),
+        );
+    }
+
+    fn paragraph(&mut self, text: &str, size: f32) {
+        let line_height = line_height_mm(size);
+        for line in wrap_text(&pdf_safe_text(text), size, self.content_width()) {
+            self.ensure_space(line_height);
+            self.text(&line, size, MARGIN_MM, false, (0.1, 0.1, 0.1));
+            self.y -= line_height;
+        }
+    }
+
+    fn heading(&mut self, text: &str) {
+        self.ensure_space(line_height_mm(HEADING_SIZE) + TABLE_ROW_MM);
+        self.y -= 3.0;
+        self.text(text, HEADING_SIZE, MARGIN_MM, true, (0.1, 0.1, 0.1));
+        self.y -= line_height_mm(HEADING_SIZE);
+    }
+
+    fn table_columns(&self) -> [f32; 3] {
+        let width = self.content_width();
+        [MARGIN_MM, MARGIN_MM + width * 0.6, MARGIN_MM + width * 0.8]
+    }
+
+    fn table_header(&mut self) {
+        let columns = self.table_columns();
+        self.fill_rect(
+            MARGIN_MM,
+            self.y - 2.0,
+            self.content_width(),
+            TABLE_ROW_MM,
+            (0.92, 0.92, 0.92),
+        );
+        for (label, x) in ["Action", "Status", "Due"].iter().zip(columns) {
+            self.text(label, TABLE_SIZE, x + 1.5, true, (0.1, 0.1, 0.1));
+        }
+        self.y -= TABLE_ROW_MM;
+    }
+
+    fn table_row(&mut self, row: &ActionRow) {
+        if self.ensure_space(TABLE_ROW_MM) {
+            self.table_header();
+        }
+        let columns = self.table_columns();
+        let name_width = columns[1] - columns[0] - 3.0;
+        let name = truncate_to_width(&pdf_safe_text(&row.name), TABLE_SIZE, name_width);
+        self.text(&name, TABLE_SIZE, columns[0] + 1.5, false, (0.1, 0.1, 0.1));
+        let status = if row.status.is_empty() {
+            "-"
+        } else {
+            row.status.as_str()
+        };
+        self.text(
+            status,
+            TABLE_SIZE,
+            columns[1] + 1.5,
+            true,
+            status_color(&row.status),
+        );
+        let due = if row.due.is_empty() { "-" } else { &row.due };
+        self.text(due, TABLE_SIZE, columns[2] + 1.5, false, (0.1, 0.1, 0.1));
+        self.y -= TABLE_ROW_MM;
+    }
+}
+
+fn render_report(report: &ProjectReport, paper: PaperSize) -> Result<Vec<u8>, String> {
+    let (width, height) = paper.dimensions();
+    let title = pdf_safe_text(&report.title);
+    let (doc, page, layer) = PdfDocument::new(&title, Mm(width), Mm(height), "Page 1");
+    let regular = doc
+        .add_builtin_font(BuiltinFont::Helvetica)
+        .map_err(|e| format!("Failed to load PDF font: {}", e))?;
+    let bold = doc
+        .add_builtin_font(BuiltinFont::HelveticaBold)
+        .map_err(|e| format!("Failed to load PDF font: {}", e))?;
+
+    let mut writer = PageWriter {
+        layer: doc.get_page(page).get_layer(layer),
+        doc: &doc,
+        regular,
+        bold,
+        width,
+        height,
+        y: height - MARGIN_MM - TITLE_SIZE * PT_TO_MM,
+        pages: 1,
+    };
+
+    for line in wrap_text(&title, TITLE_SIZE, writer.content_width()) {
+        writer.text(&line, TITLE_SIZE, MARGIN_MM, true, (0.05, 0.05, 0.05));
+        writer.y -= line_height_mm(TITLE_SIZE);
+    }
+
+    // Status badge, followed by the due date on the same line
+    let badge_text = report.status.to_ascii_uppercase();
+    let badge_width = text_width_mm(&badge_text, BODY_SIZE) + 6.0;
+    writer.fill_rect(
+        MARGIN_MM,
+        writer.y - 2.0,
+        badge_width,
+        BODY_SIZE * PT_TO_MM + 4.0,
+        status_color(&report.status),
+    );
+    writer.text(
+        &badge_text,
+        BODY_SIZE,
+        MARGIN_MM + 3.0,
+        true,
+        (1.0, 1.0, 1.0),
+    );
+    if let Some(due_date) = &report.due_date {
+        writer.text(
+            &format!("Due {}", due_date.chars().take(10).collect::<String>()),
+            BODY_SIZE,
+            MARGIN_MM + badge_width + 4.0,
+            false,
+            (0.3, 0.3, 0.3),
+        );
+    }
+    writer.y -= line_height_mm(BODY_SIZE) + 4.0;
+
+    if !report.description.is_empty() {
+        writer.heading("Description");
+        writer.paragraph(&report.description, BODY_SIZE);
+    }
+
+    let completion = &report.completion;
+    writer.heading("Completion");
+    writer.paragraph(
+        &format!(
+            "{} of {} actions completed ({:.0}%), {} cancelled",
+            completion.completed_actions,
+            completion.total_actions - completion.cancelled_actions,
+            completion.percentage,
+            completion.cancelled_actions
+        ),
+        BODY_SIZE,
+    );
+    writer.ensure_space(8.0);
+    let bar_width = writer.content_width();
+    writer.fill_rect(
+        MARGIN_MM,
+        writer.y - 1.0,
+        bar_width,
+        4.0,
+        (0.88, 0.88, 0.88),
+    );
+    if completion.percentage > 0.0 {
+        writer.fill_rect(
+            MARGIN_MM,
+            writer.y - 1.0,
+            bar_width * completion.percentage / 100.0,
+            4.0,
+            status_color("completed"),
+        );
+    }
+    writer.y -= 8.0;
+
+    writer.heading("Actions");
+    if report.actions.is_empty() {
+        writer.paragraph("No actions yet", BODY_SIZE);
+    } else {
+        writer.table_header();
+        for row in &report.actions {
+            writer.table_row(row);
+        }
+    }
+
+    doc.save_to_bytes()
+        .map_err(|e| format!("Failed to render PDF: {}", e))
+}
+
+/// Export a project summary as a PDF
+///
+/// The report shows the project title, a status badge, the description,
+/// completion statistics, and a table of actions colored by status. A named
+/// icon is shown before the title as `[name]`; emoji icons are omitted.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project folder
+/// * `dest_path` - Where to write the PDF; an existing file is replaced
+/// * `paper_size` - `a4` or `letter`
+///
+/// # Returns
+///
+/// Path to the written PDF, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const pdfPath = await invoke<string>('generate_project_report_pdf', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+///   destPath: '/path/to/exports/Website Redesign.pdf',
+///   paperSize: 'letter',
+/// });
+/// ```
+#[tauri::command]
+pub fn generate_project_report_pdf(
+    project_path: String,
+    dest_path: String,
+    paper_size: String,
+) -> Result<String, String> {
+    log::info!(
+        "Generating PDF report for {} at {}",
+        project_path,
+        dest_path
+    );
+
+    let paper = PaperSize::parse(&paper_size)?;
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+
+    let dest = Path::new(&dest_path);
+    if dest.is_dir() {
+        return Err("Destination is a directory".to_string());
+    }
+    if let Some(parent) = dest
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        if !parent.is_dir() {
+            return Err("Destination folder does not exist".to_string());
+        }
+    }
+
+    let report = load_project_report(project_dir)?;
+    let bytes = render_report(&report, paper)?;
+    fs::write(dest, bytes).map_err(|e| format!("Failed to write PDF: {}", e))?;
+
+    Ok(dest.to_string_lossy().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn row(name: &str, status: &str) -> ActionRow {
+        ActionRow {
+            name: name.to_string(),
+            status: status.to_string(),
+            due: String::new(),
+        }
+    }
+
+    #[test]
+    fn completion_percentage_ignores_cancelled_actions() {
+        let rows = vec![
+            row("A", "completed"),
+            row("B", "in-progress"),
+            row("C", "cancelled"),
+            row("D", "Done"),
+        ];
+        let completion = compute_project_completion_percentage(&rows, &FieldVocabulary::default());
+        assert_eq!(completion.total_actions, 4);
+        assert_eq!(completion.completed_actions, 2);
+        assert_eq!(completion.cancelled_actions, 1);
+        assert!((completion.percentage - 66.666_67).abs() < 0.01);
+        assert_eq!(
+            compute_project_completion_percentage(&[], &FieldVocabulary::default()).percentage,
+            0.0
+        );
+    }
+
+    #[test]
+    fn layout_helpers_wrap_truncate_and_sanitize() {
+        let lines = wrap_text("one two three four five six seven", BODY_SIZE, 20.0);
+        assert!(lines.len() > 1);
+        assert_eq!(lines.join(" "), "one two three four five six seven");
+        assert!(lines
+            .iter()
+            .all(|line| text_width_mm(line, BODY_SIZE) <= 20.0));
+
+        let name = truncate_to_width("A very long action name indeed", TABLE_SIZE, 25.0);
+        assert!(name.ends_with("..."));
+        assert!(text_width_mm(&name, TABLE_SIZE) <= 25.0);
+
+        assert_eq!(
+            pdf_safe_text("Plan \u{2014} \u{201C}Q3\u{201D} \u{1F680}"),
+            "Plan - \"Q3\" ?"
+        );
+        assert_eq!(PaperSize::parse(" Letter ").unwrap(), PaperSize::Letter);
+        assert!(PaperSize::parse("legal").is_err());
+    }
+
+    #[test]
+    fn generate_project_report_pdf_writes_a_pdf() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        write_test_file(
+            project.join("README.md"),
+            "# Alpha Project\n[!icon:rocket]\n\n## Desired Outcome\nShip the first release.\nWith docs.\n\n## Status\n[!singleselect:project-status:in-progress]\n",
+        )?;
+        write_test_file(
+            project.join("Draft.md"),
+            "# Draft\n\n## Status\n[!singleselect:status:completed]\n",
+        )?;
+        write_test_file(
+            project.join("Review.md"),
+            "# Review\n\n## Status\n[!singleselect:status:waiting]\n",
+        )?;
+
+        let report = load_project_report(&project)?;
+        assert_eq!(report.title, "[rocket] Alpha Project");
+        assert_eq!(
+            report_title("# Alpha Project\n[!icon:\u{1F680}]\n"),
+            "Alpha Project"
+        );
+        assert_eq!(report.description, "Ship the first release. With docs.");
+        assert_eq!(report.completion.completed_actions, 1);
+
+        let dest = workspace.path().join("alpha.pdf");
+        let written = generate_project_report_pdf(
+            project.to_string_lossy().to_string(),
+            dest.to_string_lossy().to_string(),
+            "a4".to_string(),
+        )?;
+        assert_eq!(written, dest.to_string_lossy());
+        let bytes = fs::read(&dest).map_err(|e| e.to_string())?;
+        assert!(bytes.starts_with(b"%PDF"));
+
+        assert!(generate_project_report_pdf(
+            project.to_string_lossy().to_string(),
+            dest.to_string_lossy().to_string(),
+            "tabloid".to_string(),
+        )
+        .is_err());
+        Ok(())
+    }
+}
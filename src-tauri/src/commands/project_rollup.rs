@@ -0,0 +1,278 @@
+//! Project status derived from the project's actions.
+//!
+//! A project whose actions are all finished is effectively done even when
+//! its README still says otherwise, and one whose actions were all cancelled
+//! is effectively cancelled. The derived status is reported next to
+//! the declared one, and `reconcile_project_statuses` can write it back.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use super::action_table::collect_action_rows;
+use super::field_vocabulary::{field_vocabulary_for, FieldVocabulary};
+use super::fields::{get_field, set_field, FieldKind};
+use super::file_locks::with_file_lock;
+use super::file_meta::record_file_change;
+use super::gtd_projects::{
+    list_gtd_projects, resolve_project_readme_path, write_string_atomically,
+};
+use super::pre_op_backups::backup_before_bulk_operation;
+use super::space_config::{ensure_file_editable, ensure_space_writable};
+use super::watcher::emit_file_changed;
+
+/// A project whose declared status differs from the one its actions imply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectStatusMismatch {
+    pub project_name: String,
+    /// Full path to the project folder
+    pub project_path: String,
+    /// Status in the README
+    pub declared_status: String,
+    /// Status implied by the actions, in the space's vocabulary
+    pub derived_status: String,
+    /// Whether the README was rewritten
+    pub applied: bool,
+}
+
+/// Derive a project's built-in status from its actions.
+///
+/// `completed` when every action is finished and at least one completed,
+/// `cancelled` when every action was cancelled, `waiting` when every open
+/// action is waiting, otherwise `in-progress`.
+/// `None` when the project has no actions to derive from.
+pub(crate) fn compute_project_rollup(
+    project_path: &Path,
+    vocabulary: &FieldVocabulary,
+) -> Option<String> {
+    let rows = collect_action_rows(project_path).ok()?;
+    if rows.is_empty() {
+        return None;
+    }
+
+    let statuses: Vec<&str> = rows
+        .iter()
+        .map(|row| vocabulary.builtin_status(&row.status))
+        .collect();
+    let open: Vec<&str> = statuses
+        .iter()
+        .copied()
+        .filter(|status| !matches!(*status, "completed" | "cancelled"))
+        .collect();
+    let derived = if open.is_empty() {
+        if statuses.contains(&"completed") {
+            "completed"
+        } else {
+            "cancelled"
+        }
+    } else if open.iter().all(|status| *status == "waiting") {
+        "waiting"
+    } else {
+        "in-progress"
+    };
+    Some(derived.to_string())
+}
+
+/// Rewrite the README's status marker, whichever key it uses.
+fn write_project_status(readme_path: &Path, status: &str) -> Result<(), String> {
+    ensure_file_editable(readme_path)?;
+    with_file_lock(readme_path, || {
+        let content =
+            fs::read_to_string(readme_path).map_err(|e| format!("Failed to read README: {}", e))?;
+        let key = if get_field(&content, FieldKind::SingleSelect, "project-status").is_some() {
+            "project-status"
+        } else {
+            "status"
+        };
+        let updated = set_field(&content, FieldKind::SingleSelect, key, status)?;
+        if updated != content {
+            write_string_atomically(readme_path, &updated)?;
+            record_file_change(readme_path);
+        }
+        Ok(())
+    })
+}
+
+/// Projects whose declared status differs from the derived one.
+///
+/// Cancelled projects and projects without actions are left alone.
+fn find_status_mismatches(
+    space_path: &str,
+    vocabulary: &FieldVocabulary,
+) -> Result<Vec<ProjectStatusMismatch>, String> {
+    let mut mismatches = Vec::new();
+    for project in list_gtd_projects(space_path.to_string())? {
+        let Some(derived) = project.derived_status.as_deref() else {
+            continue;
+        };
+        let declared = vocabulary.builtin_status(&project.status);
+        if declared == "cancelled" || declared == derived {
+            continue;
+        }
+        mismatches.push(ProjectStatusMismatch {
+            project_name: project.name,
+            project_path: project.path,
+            declared_status: project.status.clone(),
+            derived_status: vocabulary.status_for(derived),
+            applied: false,
+        });
+    }
+    Ok(mismatches)
+}
+
+/// List, and optionally fix, projects whose status disagrees with their actions
+///
+/// The derived status is `completed` when every action is finished and at
+/// least one was completed, `cancelled` when every action was cancelled,
+/// `waiting` when every open action is waiting, and `in-progress`
+/// otherwise. Cancelled projects and projects without actions are skipped.
+/// Applied changes rewrite the README's status marker atomically and emit a
+/// `file-changed` event for each rewritten README. Rewriting
+/// more READMEs than the space's backup threshold backs them up to
+/// `.gtdspace/pre-op-backups` first.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for the file change events
+/// * `space_path` - Root directory of the GTD space
+/// * `apply` - Rewrite mismatched READMEs instead of only listing them
+/// * `skip_backup` - Skip the automatic pre-operation backup
+///
+/// # Returns
+///
+/// The mismatched projects, with `applied` set for rewritten ones, or error
+/// details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const mismatches = await invoke<ProjectStatusMismatch[]>('reconcile_project_statuses', {
+///   spacePath: '/path/to/space',
+///   apply: false,
+/// });
+/// ```
+#[tauri::command]
+pub fn reconcile_project_statuses(
+    app: AppHandle,
+    space_path: String,
+    apply: bool,
+    skip_backup: Option<bool>,
+) -> Result<Vec<ProjectStatusMismatch>, String> {
+    log::info!(
+        "Reconciling project statuses in: {} (apply={})",
+        space_path,
+        apply
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    let vocabulary = field_vocabulary_for(space_root)?;
+    let mut mismatches = find_status_mismatches(&space_path, &vocabulary)?;
+    if !apply || mismatches.is_empty() {
+        return Ok(mismatches);
+    }
+
+    ensure_space_writable(space_root)?;
+    let readmes: Vec<Option<PathBuf>> = mismatches
+        .iter()
+        .map(|mismatch| resolve_project_readme_path(Path::new(&mismatch.project_path)))
+        .collect();
+    let files: Vec<PathBuf> = readmes.iter().flatten().cloned().collect();
+    backup_before_bulk_operation(
+        space_root,
+        "reconcile_project_statuses",
+        &uuid::Uuid::new_v4().to_string(),
+        &files,
+        skip_backup.unwrap_or(false),
+    )?;
+
+    for (mismatch, readme_path) in mismatches.iter_mut().zip(readmes) {
+        let Some(readme_path) = readme_path else {
+            continue;
+        };
+        match write_project_status(&readme_path, &mismatch.derived_status) {
+            Ok(()) => {
+                mismatch.applied = true;
+                emit_file_changed(&app, &readme_path, "modified");
+            }
+            Err(error) => log::warn!(
+                "Failed to update status of {}: {}",
+                mismatch.project_path,
+                error
+            ),
+        }
+    }
+    Ok(mismatches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(status: &str) -> String {
+        format!("# Action\n\n## Status\n[!singleselect:status:{}]\n", status)
+    }
+
+    #[test]
+    fn rollup_derives_status_and_mismatches_are_applied() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let vocabulary = FieldVocabulary::default();
+        let alpha = root.join("Projects/Alpha Project");
+        assert_eq!(compute_project_rollup(&alpha, &vocabulary), None);
+
+        write_test_file(alpha.join("Ship.md"), &action("completed"))?;
+        write_test_file(alpha.join("Drop.md"), &action("cancelled"))?;
+        assert_eq!(
+            compute_project_rollup(&alpha, &vocabulary).as_deref(),
+            Some("completed")
+        );
+        write_test_file(alpha.join("Chase.md"), &action("waiting"))?;
+        assert_eq!(
+            compute_project_rollup(&alpha, &vocabulary).as_deref(),
+            Some("waiting")
+        );
+        write_test_file(alpha.join("Build.md"), &action("in-progress"))?;
+        assert_eq!(
+            compute_project_rollup(&alpha, &vocabulary).as_deref(),
+            Some("in-progress")
+        );
+
+        let gamma = root.join("Projects/Gamma");
+        write_test_file(
+            gamma.join("README.md"),
+            "# Gamma\n\n## Status\n[!singleselect:project-status:cancelled]\n",
+        )?;
+        write_test_file(gamma.join("Drop.md"), &action("cancelled"))?;
+        assert_eq!(
+            compute_project_rollup(&gamma, &vocabulary).as_deref(),
+            Some("cancelled")
+        );
+
+        let beta = root.join("Projects/Beta");
+        write_test_file(
+            beta.join("README.md"),
+            "# Beta\n\n## Status\n[!singleselect:project-status:in-progress]\n",
+        )?;
+        write_test_file(beta.join("Done.md"), &action("completed"))?;
+
+        let space_path = root.to_string_lossy().to_string();
+        let mismatches = find_status_mismatches(&space_path, &vocabulary)?;
+        assert_eq!(mismatches.len(), 1);
+        assert_eq!(mismatches[0].project_name, "Beta");
+        assert_eq!(mismatches[0].derived_status, "completed");
+
+        write_project_status(&beta.join("README.md"), "completed")?;
+        let readme = fs::read_to_string(beta.join("README.md")).map_err(|e| e.to_string())?;
+        assert!(readme.contains("[!singleselect:project-status:completed]"));
+        assert!(find_status_mismatches(&space_path, &vocabulary)?.is_empty());
+
+        Ok(())
+    }
+}
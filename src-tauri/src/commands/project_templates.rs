@@ -0,0 +1,508 @@
+//! Project templates: a README plus a starting checklist of actions.
+//!
+//! Besides the built-ins, a space can define its own templates as JSON files
+//! in `.gtdspace/project-templates/`. A space template with the same name as a
+//! built-in replaces it.
+//!
+//! ```json
+//! {
+//!   "name": "Client Onboarding",
+//!   "description": "Get a new client set up",
+//!   "due_offset": "+30d",
+//!   "readme": "## Client Details\n- Primary contact:\n",
+//!   "actions": [
+//!     { "name": "Schedule kickoff call", "effort": "small", "due_offset": "+3d" },
+//!     { "name": "Send welcome packet", "status": "waiting" }
+//!   ]
+//! }
+//! ```
+//!
+//! Offsets are `+<n>d`, `+<n>w`, or `+<n>m`, counted from the project's start
+//! date.
+
+use chrono::{Local, Months, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::filesystem::save_file_contents;
+use super::gtd_projects::{create_gtd_action, create_gtd_project};
+use super::space_config::SPACE_CONFIG_DIR;
+
+/// Folder inside [`SPACE_CONFIG_DIR`] holding space-defined project templates
+const PROJECT_TEMPLATES_DIR: &str = "project-templates";
+
+fn default_action_status() -> String {
+    "in-progress".to_string()
+}
+
+fn default_action_effort() -> String {
+    "medium".to_string()
+}
+
+/// A project template definition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectTemplate {
+    pub name: String,
+    /// Desired outcome written to the project README
+    #[serde(default)]
+    pub description: String,
+    /// Project due date, relative to the start date
+    #[serde(default)]
+    pub due_offset: Option<String>,
+    /// Markdown added to the end of the project README
+    #[serde(default)]
+    pub readme: Option<String>,
+    #[serde(default)]
+    pub actions: Vec<ProjectTemplateAction>,
+}
+
+/// One action created with the project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProjectTemplateAction {
+    pub name: String,
+    #[serde(default = "default_action_effort")]
+    pub effort: String,
+    #[serde(default = "default_action_status")]
+    pub status: String,
+    /// Due date relative to the start date
+    #[serde(default)]
+    pub due_offset: Option<String>,
+    #[serde(default)]
+    pub contexts: Vec<String>,
+}
+
+/// A template available to `create_project_from_template`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectTemplateSummary {
+    pub name: String,
+    pub description: String,
+    pub action_count: usize,
+    pub built_in: bool,
+    /// Template file, for space-defined templates
+    pub path: Option<String>,
+}
+
+fn template_action(name: &str, effort: &str, due_offset: &str) -> ProjectTemplateAction {
+    ProjectTemplateAction {
+        name: name.to_string(),
+        effort: effort.to_string(),
+        status: default_action_status(),
+        due_offset: Some(due_offset.to_string()),
+        contexts: Vec::new(),
+    }
+}
+
+fn built_in_templates() -> Vec<ProjectTemplate> {
+    vec![
+        ProjectTemplate {
+            name: "Client Onboarding".to_string(),
+            description: "New client is set up, informed, and ready to start work.".to_string(),
+            due_offset: Some("+4w".to_string()),
+            readme: Some(
+                "## Client Details\n- Primary contact:\n- Billing contact:\n- Shared folder:\n"
+                    .to_string(),
+            ),
+            actions: vec![
+                template_action("Send welcome email", "small", "+1d"),
+                template_action("Schedule kickoff call", "small", "+2d"),
+                template_action("Collect signed contract", "small", "+5d"),
+                template_action("Set up shared folder", "small", "+5d"),
+                template_action("Gather access credentials", "medium", "+1w"),
+                template_action("Hold kickoff call", "medium", "+1w"),
+                template_action("Draft project plan", "large", "+2w"),
+                template_action("Review plan with client", "medium", "+3w"),
+            ],
+        },
+        ProjectTemplate {
+            name: "Event Planning".to_string(),
+            description: "Event runs smoothly and attendees know what to expect.".to_string(),
+            due_offset: Some("+2m".to_string()),
+            readme: Some(
+                "## Event Details\n- Date:\n- Venue:\n- Expected attendees:\n".to_string(),
+            ),
+            actions: vec![
+                template_action("Set budget", "small", "+3d"),
+                template_action("Book venue", "medium", "+2w"),
+                template_action("Send invitations", "medium", "+4w"),
+                template_action("Confirm catering", "medium", "+6w"),
+                template_action("Send reminder to attendees", "small", "+8w"),
+            ],
+        },
+    ]
+}
+
+fn templates_dir(space_root: &Path) -> PathBuf {
+    space_root
+        .join(SPACE_CONFIG_DIR)
+        .join(PROJECT_TEMPLATES_DIR)
+}
+
+fn load_template_file(path: &Path) -> Result<ProjectTemplate, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read template {}: {}", path.display(), e))?;
+    serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid template {}: {}", path.display(), e))
+}
+
+/// Space-defined templates, sorted by file name; unreadable ones are skipped.
+fn space_templates(space_root: &Path) -> Vec<(ProjectTemplate, PathBuf)> {
+    let Ok(entries) = fs::read_dir(templates_dir(space_root)) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| match load_template_file(&path) {
+            Ok(template) => Some((template, path)),
+            Err(error) => {
+                log::warn!("Skipping project template: {}", error);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Resolve `+<n>d`, `+<n>w`, or `+<n>m` against `start`.
+pub(crate) fn resolve_due_offset(offset: &str, start: NaiveDate) -> Result<NaiveDate, String> {
+    let trimmed = offset.trim();
+    let invalid = || {
+        format!(
+            "Invalid due offset '{}'; expected e.g. +7d, +2w, +1m",
+            offset
+        )
+    };
+    let body = trimmed.strip_prefix('+').unwrap_or(trimmed);
+    let unit = body.chars().last().ok_or_else(invalid)?;
+    let amount: u32 = body[..body.len() - unit.len_utf8()]
+        .parse()
+        .map_err(|_| invalid())?;
+
+    let resolved = match unit.to_ascii_lowercase() {
+        'd' => start.checked_add_days(chrono::Days::new(u64::from(amount))),
+        'w' => start.checked_add_days(chrono::Days::new(u64::from(amount) * 7)),
+        'm' => start.checked_add_months(Months::new(amount)),
+        _ => return Err(invalid()),
+    };
+    resolved.ok_or_else(|| format!("Due offset '{}' is out of range", offset))
+}
+
+fn parse_start_date(start_date: &str) -> Result<NaiveDate, String> {
+    let trimmed = start_date.trim();
+    if trimmed.is_empty() {
+        return Ok(Local::now().date_naive());
+    }
+    NaiveDate::parse_from_str(trimmed, "%Y-%m-%d")
+        .map_err(|_| format!("Invalid start date '{}'; expected YYYY-MM-DD", start_date))
+}
+
+fn find_template(space_root: &Path, template_name: &str) -> Result<ProjectTemplate, String> {
+    let wanted = template_name.trim();
+    space_templates(space_root)
+        .into_iter()
+        .map(|(template, _)| template)
+        .chain(built_in_templates())
+        .find(|template| template.name.eq_ignore_ascii_case(wanted))
+        .ok_or_else(|| format!("Project template '{}' not found", template_name))
+}
+
+/// Append the template's README body to the new project's README.
+fn append_readme_body(project_path: &Path, body: &str) -> Result<(), String> {
+    let readme_path = project_path.join("README.md");
+    let content = fs::read_to_string(&readme_path)
+        .map_err(|e| format!("Failed to read project README: {}", e))?;
+    let updated = format!("{}\n\n{}\n", content.trim_end(), body.trim());
+    save_file_contents(&readme_path, &updated)
+}
+
+/// Remove a project created from a template after a later step failed.
+fn roll_back_project(project_path: &str) {
+    if let Err(cleanup_error) = fs::remove_dir_all(project_path) {
+        log::error!(
+            "Failed to roll back project {}: {}",
+            project_path,
+            cleanup_error
+        );
+    }
+}
+
+fn format_date(date: Option<NaiveDate>) -> Option<String> {
+    date.map(|date| date.format("%Y-%m-%d").to_string())
+}
+
+/// List the project templates available in a space
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Space-defined templates followed by the built-ins they do not replace
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const templates = await invoke<ProjectTemplateSummary[]>('list_project_templates', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_project_templates(space_path: String) -> Result<Vec<ProjectTemplateSummary>, String> {
+    log::info!("Listing project templates in: {}", space_path);
+
+    let mut summaries: Vec<ProjectTemplateSummary> = space_templates(Path::new(&space_path))
+        .into_iter()
+        .map(|(template, path)| ProjectTemplateSummary {
+            action_count: template.actions.len(),
+            name: template.name,
+            description: template.description,
+            built_in: false,
+            path: Some(path.to_string_lossy().to_string()),
+        })
+        .collect();
+
+    for template in built_in_templates() {
+        if summaries
+            .iter()
+            .any(|summary| summary.name.eq_ignore_ascii_case(&template.name))
+        {
+            continue;
+        }
+        summaries.push(ProjectTemplateSummary {
+            action_count: template.actions.len(),
+            name: template.name,
+            description: template.description,
+            built_in: true,
+            path: None,
+        });
+    }
+
+    Ok(summaries)
+}
+
+/// Create a project and its starting actions from a template
+///
+/// Relative due offsets are resolved from `start_date` and the template's
+/// README body is added below the generated README. If any action cannot be
+/// created, the new project folder is removed again.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `template_name` - Template name (case-insensitive)
+/// * `project_name` - Name of the new project
+/// * `start_date` - Date offsets count from (YYYY-MM-DD); blank means today
+///
+/// # Returns
+///
+/// Path to the created project or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const projectPath = await invoke<string>('create_project_from_template', {
+///   spacePath: '/path/to/space',
+///   templateName: 'Client Onboarding',
+///   projectName: 'Onboard Acme',
+///   startDate: '2026-05-04',
+/// });
+/// ```
+#[tauri::command]
+pub fn create_project_from_template(
+    space_path: String,
+    template_name: String,
+    project_name: String,
+    start_date: String,
+) -> Result<String, String> {
+    log::info!(
+        "Creating project '{}' from template '{}'",
+        project_name,
+        template_name
+    );
+
+    let template = find_template(Path::new(&space_path), &template_name)?;
+    let start = parse_start_date(&start_date)?;
+
+    // Resolve every date up front so a bad offset creates nothing
+    let project_due = template
+        .due_offset
+        .as_deref()
+        .map(|offset| resolve_due_offset(offset, start))
+        .transpose()?;
+    let action_dues = template
+        .actions
+        .iter()
+        .map(|action| {
+            action
+                .due_offset
+                .as_deref()
+                .map(|offset| resolve_due_offset(offset, start))
+                .transpose()
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let project_path = create_gtd_project(
+        space_path,
+        project_name,
+        template.description.clone(),
+        format_date(project_due),
+        None,
+        None,
+    )?
+    .path;
+
+    if let Some(body) = template
+        .readme
+        .as_deref()
+        .filter(|body| !body.trim().is_empty())
+    {
+        if let Err(error) = append_readme_body(Path::new(&project_path), body) {
+            roll_back_project(&project_path);
+            return Err(error);
+        }
+    }
+
+    for (action, due) in template.actions.iter().zip(action_dues) {
+        let contexts = (!action.contexts.is_empty()).then(|| action.contexts.clone());
+        if let Err(error) = create_gtd_action(
+            project_path.clone(),
+            action.name.clone(),
+            action.status.clone(),
+            format_date(due),
+            None,
+            action.effort.clone(),
+            contexts,
+            None,
+            None,
+            None,
+        ) {
+            roll_back_project(&project_path);
+            return Err(format!(
+                "Failed to create action '{}' from template: {}",
+                action.name, error
+            ));
+        }
+    }
+
+    Ok(project_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid test date")
+    }
+
+    #[test]
+    fn due_offsets_resolve_days_weeks_and_months() {
+        let start = date("2026-01-31");
+        assert_eq!(resolve_due_offset("+7d", start), Ok(date("2026-02-07")));
+        assert_eq!(resolve_due_offset("2w", start), Ok(date("2026-02-14")));
+        assert_eq!(resolve_due_offset("+1m", start), Ok(date("2026-02-28")));
+        assert_eq!(resolve_due_offset("+0d", start), Ok(start));
+        assert!(resolve_due_offset("+7", start).is_err());
+        assert!(resolve_due_offset("-3d", start).is_err());
+        assert!(resolve_due_offset("soon", start).is_err());
+    }
+
+    #[test]
+    fn creates_project_and_actions_from_space_template() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let space_path = root.to_string_lossy().to_string();
+        write_test_file(
+            templates_dir(root).join("onboarding.json"),
+            r###"{
+  "name": "Client Onboarding",
+  "description": "Client is ready",
+  "due_offset": "+2w",
+  "readme": "## Client Details\n- Primary contact: Dana\n",
+  "actions": [
+    { "name": "Kickoff call", "effort": "small", "due_offset": "+3d" },
+    { "name": "Collect contract", "status": "waiting" }
+  ]
+}"###,
+        )?;
+
+        let templates = list_project_templates(space_path.clone())?;
+        let onboarding = templates
+            .iter()
+            .find(|template| template.name == "Client Onboarding")
+            .expect("space template should be listed");
+        assert!(!onboarding.built_in);
+        assert_eq!(onboarding.action_count, 2);
+        assert!(templates
+            .iter()
+            .any(|template| template.name == "Event Planning" && template.built_in));
+        assert_eq!(
+            templates
+                .iter()
+                .filter(|template| template.name == "Client Onboarding")
+                .count(),
+            1
+        );
+
+        let project_path = create_project_from_template(
+            space_path,
+            "client onboarding".to_string(),
+            "Onboard Acme".to_string(),
+            "2026-05-04".to_string(),
+        )?;
+        let project = Path::new(&project_path);
+        let readme = fs::read_to_string(project.join("README.md")).map_err(|e| e.to_string())?;
+        assert!(readme.contains("Client is ready"));
+        assert!(readme.contains("[!datetime:due_date:2026-05-18]"));
+        assert!(readme.ends_with("[!habits-list]\n\n## Client Details\n- Primary contact: Dana\n"));
+
+        let kickoff =
+            fs::read_to_string(project.join("Kickoff call.md")).map_err(|e| e.to_string())?;
+        assert!(kickoff.contains("[!datetime:due_date:2026-05-07]"));
+        assert!(kickoff.contains("[!singleselect:effort:small]"));
+        let contract =
+            fs::read_to_string(project.join("Collect contract.md")).map_err(|e| e.to_string())?;
+        assert!(contract.contains("[!singleselect:status:waiting]"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn colliding_action_rolls_back_the_project() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            templates_dir(root).join("dupe.json"),
+            r#"{ "name": "Dupe", "actions": [{ "name": "Call" }, { "name": "Call" }] }"#,
+        )?;
+
+        let error = create_project_from_template(
+            root.to_string_lossy().to_string(),
+            "Dupe".to_string(),
+            "Broken".to_string(),
+            String::new(),
+        )
+        .expect_err("duplicate action should fail");
+        assert!(error.contains("Call"), "{}", error);
+        assert!(!root.join("Projects/Broken").exists());
+
+        Ok(())
+    }
+}
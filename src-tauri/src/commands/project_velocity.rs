@@ -0,0 +1,258 @@
+//! Project velocity and completion forecasts.
+//!
+//! Velocity is derived from action files alone: an action counts as completed
+//! when its status marker says so, and its completion time comes from a
+//! `completed_date` marker or, failing that, the file's modification time.
+
+use chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::field_vocabulary::field_vocabulary_or_default;
+
+/// Lookback window used for velocity, in days
+const VELOCITY_WINDOW_DAYS: i64 = 30;
+
+pub(crate) static ACTION_STATUS_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?i)\[!singleselect:status:([^\]]*)\]")
+        .expect("Invalid action status regex pattern")
+});
+
+static ACTION_COMPLETED_DATE_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\[!datetime:completed_date(?:_time)?:([^\]]+)\]")
+        .expect("Invalid action completed date regex pattern")
+});
+
+/// Completion history for the actions in one project
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ProjectVelocity {
+    /// Actions not yet completed or cancelled
+    pub remaining_actions: u32,
+    /// Completion time of every completed action, oldest first
+    pub completed_at: Vec<NaiveDateTime>,
+}
+
+impl ProjectVelocity {
+    /// Completed actions at or after `since`
+    pub(crate) fn completed_since(&self, since: NaiveDateTime) -> usize {
+        self.completed_at
+            .iter()
+            .filter(|completed| **completed >= since)
+            .count()
+    }
+}
+
+/// Forecast of when a project's remaining actions will be done
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionForecast {
+    /// Actions not yet completed or cancelled
+    pub remaining_actions: u32,
+    /// Average days between completions over the last 30 days
+    pub average_days_per_action: f32,
+    /// Forecast completion date (YYYY-MM-DD), if there is enough history
+    pub estimated_completion_date: Option<String>,
+    /// "high", "medium", or "low" depending on sample size
+    pub confidence: String,
+}
+
+fn parse_completed_date(raw: &str) -> Option<NaiveDateTime> {
+    let raw = raw.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(raw) {
+        return Some(datetime.with_timezone(&Local).naive_local());
+    }
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S") {
+        return Some(datetime);
+    }
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M") {
+        return Some(datetime);
+    }
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+}
+
+fn modified_time(path: &Path) -> Option<NaiveDateTime> {
+    let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok()?;
+    Some(DateTime::<Local>::from(modified).naive_local())
+}
+
+/// When a completed file was finished: its `completed_date` marker or, failing
+/// that, its modification time.
+pub(crate) fn completion_time(content: &str, path: &Path) -> Option<NaiveDateTime> {
+    ACTION_COMPLETED_DATE_REGEX
+        .captures(content)
+        .and_then(|captures| captures.get(1))
+        .and_then(|value| parse_completed_date(value.as_str()))
+        .or_else(|| modified_time(path))
+}
+
+/// Collect completion data for every action file directly inside `project_path`.
+pub(crate) fn compute_project_velocity(project_path: &Path) -> Result<ProjectVelocity, String> {
+    let entries = fs::read_dir(project_path)
+        .map_err(|e| format!("Failed to read project directory: {}", e))?;
+    let vocabulary = field_vocabulary_or_default(project_path);
+    let mut velocity = ProjectVelocity::default();
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_markdown = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+            });
+        let is_readme = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .is_some_and(|stem| stem.eq_ignore_ascii_case("README"));
+        if !path.is_file() || !is_markdown || is_readme {
+            continue;
+        }
+
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let Some(status) = ACTION_STATUS_REGEX
+            .captures(&content)
+            .and_then(|captures| captures.get(1))
+            .map(|value| value.as_str().trim())
+        else {
+            continue;
+        };
+
+        match vocabulary
+            .builtin_status(status)
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "completed" | "complete" | "done" => {
+                if let Some(completed) = completion_time(&content, &path) {
+                    velocity.completed_at.push(completed);
+                }
+            }
+            "cancelled" | "canceled" => {}
+            _ => velocity.remaining_actions += 1,
+        }
+    }
+
+    velocity.completed_at.sort();
+    Ok(velocity)
+}
+
+fn forecast_completion(velocity: &ProjectVelocity, now: DateTime<Local>) -> CompletionForecast {
+    let recent = velocity.completed_since(now.naive_local() - Duration::days(VELOCITY_WINDOW_DAYS));
+    let average_days_per_action = if recent == 0 {
+        0.0
+    } else {
+        VELOCITY_WINDOW_DAYS as f32 / recent as f32
+    };
+
+    let confidence = match recent {
+        0..=2 => "low",
+        3..=9 => "medium",
+        _ => "high",
+    };
+    let estimated_completion_date = (recent >= 3).then(|| {
+        let days = (velocity.remaining_actions as f32 * average_days_per_action).ceil() as i64;
+        (now + Duration::days(days)).format("%Y-%m-%d").to_string()
+    });
+
+    CompletionForecast {
+        remaining_actions: velocity.remaining_actions,
+        average_days_per_action,
+        estimated_completion_date,
+        confidence: confidence.to_string(),
+    }
+}
+
+/// Forecast a project's completion date from recent velocity
+///
+/// Counts actions completed in the last 30 days and projects the remaining
+/// actions forward at that rate. With fewer than three recent completions the
+/// forecast has low confidence and no date.
+///
+/// # Arguments
+///
+/// * `project_path` - Full path to the project directory
+///
+/// # Returns
+///
+/// `CompletionForecast` or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const forecast = await invoke<CompletionForecast>('get_estimated_project_completion', {
+///   projectPath: '/path/to/space/Projects/Website Redesign',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_estimated_project_completion(
+    project_path: String,
+) -> Result<CompletionForecast, String> {
+    log::info!("Estimating completion for project: {}", project_path);
+
+    let project_dir = Path::new(&project_path);
+    if !project_dir.is_dir() {
+        return Err("Project directory does not exist".to_string());
+    }
+
+    let velocity = compute_project_velocity(project_dir)?;
+    Ok(forecast_completion(&velocity, Local::now()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn write_action(root: &Path, name: &str, status: &str, completed: Option<&str>) {
+        let completed_marker = completed
+            .map(|date| format!("\n## Completed\n[!datetime:completed_date:{}]\n", date))
+            .unwrap_or_default();
+        write_test_file(
+            root.join(format!("Projects/Alpha Project/{}.md", name)),
+            &format!(
+                "# {}\n\n## Status\n[!singleselect:status:{}]\n{}",
+                name, status, completed_marker
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn forecast_requires_three_recent_completions() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let now = Local::now();
+        let recent = |days: i64| (now - Duration::days(days)).format("%Y-%m-%d").to_string();
+
+        write_action(root, "One", "completed", Some(&recent(2)));
+        write_action(root, "Two", "completed", Some(&recent(5)));
+        write_action(root, "Old", "completed", Some(&recent(90)));
+        write_action(root, "Next", "in-progress", None);
+        write_action(root, "Later", "waiting", None);
+
+        let low = forecast_completion(&compute_project_velocity(&project)?, now);
+        assert_eq!(low.remaining_actions, 2);
+        assert_eq!(low.confidence, "low");
+        assert!(low.estimated_completion_date.is_none());
+
+        write_action(root, "Three", "completed", Some(&recent(10)));
+        let medium = forecast_completion(&compute_project_velocity(&project)?, now);
+        assert_eq!(medium.confidence, "medium");
+        assert_eq!(medium.average_days_per_action, 10.0);
+        assert_eq!(
+            medium.estimated_completion_date,
+            Some((now + Duration::days(20)).format("%Y-%m-%d").to_string())
+        );
+
+        Ok(())
+    }
+}
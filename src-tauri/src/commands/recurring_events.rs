@@ -0,0 +1,226 @@
+//! Recurring calendar events, one entry per series.
+//!
+//! The calendar cache holds expanded occurrences, so a weekly meeting shows
+//! up once per week. Grouping them by series surfaces the regular meetings
+//! that may deserve their own GTD actions or habits.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::AppHandle;
+
+use super::calendar_actions::parse_event_time;
+use super::google_calendar_commands::{fetch_series_recurrence, google_calendar_get_cached_events};
+use crate::google_calendar::GoogleCalendarEvent;
+
+/// Window counted by `occurrences_in_next_30_days`
+const LOOKAHEAD_DAYS: i64 = 30;
+
+/// A recurring series, represented by its next (or latest) occurrence
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurringEvent {
+    #[serde(flatten)]
+    pub event: GoogleCalendarEvent,
+    /// Start of the next occurrence, if one is cached
+    pub next_occurrence: Option<String>,
+    /// Cached occurrences starting within the next 30 days
+    pub occurrences_in_next_30_days: u32,
+}
+
+/// Group recurring occurrences by series.
+///
+/// Cancelled occurrences are ignored. Series are sorted by next occurrence,
+/// with series that have none last.
+pub(crate) fn group_recurring_events(
+    events: &[GoogleCalendarEvent],
+    now: DateTime<Utc>,
+) -> Vec<RecurringEvent> {
+    let mut series: BTreeMap<&str, Vec<(DateTime<Utc>, &GoogleCalendarEvent)>> = BTreeMap::new();
+    for event in events {
+        if !event.is_recurring || event.status == "cancelled" {
+            continue;
+        }
+        let Some(start) = event.start.as_deref().and_then(parse_event_time) else {
+            continue;
+        };
+        let series_id = event.recurring_event_id.as_deref().unwrap_or(&event.id);
+        series.entry(series_id).or_default().push((start, event));
+    }
+
+    let horizon = now + Duration::days(LOOKAHEAD_DAYS);
+    let mut recurring: Vec<(Option<DateTime<Utc>>, RecurringEvent)> = series
+        .into_values()
+        .filter_map(|mut occurrences| {
+            occurrences.sort_by_key(|(start, _)| *start);
+            let next = occurrences.iter().find(|(start, _)| *start >= now);
+            let (next_start, representative) = match next {
+                Some((start, event)) => (Some(*start), *event),
+                None => (None, occurrences.last()?.1),
+            };
+            let upcoming = occurrences
+                .iter()
+                .filter(|(start, _)| *start >= now && *start <= horizon)
+                .count() as u32;
+            Some((
+                next_start,
+                RecurringEvent {
+                    event: representative.clone(),
+                    next_occurrence: next_start.and(representative.start.clone()),
+                    occurrences_in_next_30_days: upcoming,
+                },
+            ))
+        })
+        .collect();
+
+    recurring.sort_by(|(a, a_event), (b, b_event)| {
+        (a.is_none(), a, &a_event.event.summary).cmp(&(b.is_none(), b, &b_event.event.summary))
+    });
+    recurring.into_iter().map(|(_, event)| event).collect()
+}
+
+/// List recurring calendar events, one per series
+///
+/// Built from the cached calendar events. When Google Calendar is connected,
+/// each series' RRULE lines are read from the series event; otherwise
+/// `recurrence` is empty.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+///
+/// # Returns
+///
+/// Recurring series sorted by next occurrence, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const meetings = await invoke<RecurringEvent[]>('list_recurring_calendar_events');
+/// const weekly = meetings.filter((m) => m.occurrences_in_next_30_days >= 4);
+/// ```
+#[tauri::command]
+pub async fn list_recurring_calendar_events(app: AppHandle) -> Result<Vec<RecurringEvent>, String> {
+    let events = google_calendar_get_cached_events(app).await?;
+    let mut recurring = group_recurring_events(&events, Utc::now());
+
+    let missing: Vec<String> = recurring
+        .iter()
+        .filter(|series| series.event.recurrence.is_empty())
+        .filter_map(|series| series.event.recurring_event_id.clone())
+        .collect();
+    if !missing.is_empty() {
+        let mut fetched = fetch_series_recurrence(&missing).await;
+        for series in &mut recurring {
+            if let Some(rules) = series
+                .event
+                .recurring_event_id
+                .as_ref()
+                .and_then(|id| fetched.remove(id))
+            {
+                series.event.recurrence = rules;
+            }
+        }
+    }
+
+    Ok(recurring)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn occurrence(
+        id: &str,
+        series: Option<&str>,
+        summary: &str,
+        start: &str,
+    ) -> GoogleCalendarEvent {
+        GoogleCalendarEvent {
+            id: id.to_string(),
+            summary: summary.to_string(),
+            description: None,
+            start: Some(start.to_string()),
+            end: None,
+            location: None,
+            attendees: Vec::new(),
+            meeting_link: None,
+            status: "confirmed".to_string(),
+            color_id: None,
+            category: None,
+            is_recurring: series.is_some(),
+            recurring_event_id: series.map(str::to_string),
+            recurrence: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn occurrences_are_grouped_by_series() {
+        let now = parse_event_time("2026-04-01T12:00:00Z").expect("valid time");
+        let mut events: Vec<GoogleCalendarEvent> = [
+            (
+                "standup-1",
+                Some("standup"),
+                "Standup",
+                "2026-03-30T09:00:00Z",
+            ),
+            (
+                "standup-2",
+                Some("standup"),
+                "Standup",
+                "2026-04-02T09:00:00Z",
+            ),
+            (
+                "standup-3",
+                Some("standup"),
+                "Standup",
+                "2026-04-09T09:00:00Z",
+            ),
+            (
+                "standup-4",
+                Some("standup"),
+                "Standup",
+                "2026-04-03T09:00:00Z",
+            ),
+            (
+                "standup-9",
+                Some("standup"),
+                "Standup",
+                "2026-05-14T09:00:00Z",
+            ),
+            (
+                "review-1",
+                Some("review"),
+                "Quarterly review",
+                "2026-04-01T15:00:00Z",
+            ),
+            ("retro-1", Some("retro"), "Retro", "2026-03-15T15:00:00Z"),
+            ("lunch", None, "Lunch", "2026-04-02T12:00:00Z"),
+        ]
+        .into_iter()
+        .map(|(id, series, summary, start)| occurrence(id, series, summary, start))
+        .collect();
+        events[3].status = "cancelled".to_string();
+
+        let recurring = group_recurring_events(&events, now);
+        let summary: Vec<(&str, Option<&str>, u32)> = recurring
+            .iter()
+            .map(|series| {
+                (
+                    series.event.id.as_str(),
+                    series.next_occurrence.as_deref(),
+                    series.occurrences_in_next_30_days,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("review-1", Some("2026-04-01T15:00:00Z"), 1),
+                ("standup-2", Some("2026-04-02T09:00:00Z"), 2),
+                ("retro-1", None, 0),
+            ]
+        );
+    }
+}
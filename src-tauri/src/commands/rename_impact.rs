@@ -0,0 +1,135 @@
+//! Dry-run impact report shown before a rename is confirmed.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use super::gtd_relationships::{find_habits_referencing, find_reverse_relationships};
+
+/// What renaming a file or project would affect
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RenameImpact {
+    /// Whether `old_path` exists and `new_path` is free, so the rename itself
+    /// can go ahead
+    pub direct_rename: bool,
+    /// Files whose reference fields point at `old_path`, sorted
+    pub files_referencing_old_path: Vec<String>,
+    /// Kinds of referencing files, e.g. `project` or `habit`, sorted
+    pub reference_types: Vec<String>,
+    /// Referencing files whose references would need updating
+    pub total_files_to_update: u32,
+}
+
+/// Preview which files a rename would touch
+///
+/// A dry run for `rename_file` and `rename_gtd_project`: horizon files and
+/// habits that reference `old_path` are listed without changing anything.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `old_path` - File or project folder about to be renamed
+/// * `new_path` - Path it would be renamed to
+///
+/// # Returns
+///
+/// The impact report for a confirmation dialog, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const impact = await invoke<RenameImpact>('preview_file_rename_impact', {
+///   spacePath: '/path/to/space',
+///   oldPath: '/path/to/space/Goals/Fitness.md',
+///   newPath: '/path/to/space/Goals/Health.md',
+/// });
+/// ```
+#[tauri::command]
+pub fn preview_file_rename_impact(
+    space_path: String,
+    old_path: String,
+    new_path: String,
+) -> Result<RenameImpact, String> {
+    log::info!("Previewing rename impact: {} -> {}", old_path, new_path);
+
+    if !Path::new(&space_path).is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    let old = Path::new(&old_path);
+    let new = Path::new(&new_path);
+
+    let mut files = BTreeSet::new();
+    let mut reference_types = BTreeSet::new();
+    for relationship in
+        find_reverse_relationships(old_path.clone(), space_path.clone(), "all".to_string())?
+    {
+        files.insert(relationship.file_path);
+        reference_types.insert(relationship.file_type);
+    }
+    for habit in find_habits_referencing(old_path.clone(), space_path)? {
+        files.insert(habit.file_path);
+        reference_types.insert("habit".to_string());
+    }
+
+    Ok(RenameImpact {
+        direct_rename: old.exists() && (old == new || !new.exists()),
+        total_files_to_update: files.len() as u32,
+        files_referencing_old_path: files.into_iter().collect(),
+        reference_types: reference_types.into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn rename_preview_lists_referencing_files() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let goal = root.join("Goals/Fitness.md");
+        write_test_file(&goal, "# Fitness\n")?;
+        let reference = format!("[!goals-references:{}]\n", goal.display());
+        write_test_file(
+            root.join("Projects/Gym Plan/README.md"),
+            &format!("# Gym Plan\n\n{}", reference),
+        )?;
+        write_test_file(
+            root.join("Areas of Focus/Health.md"),
+            &format!("# Health\n\n{}", reference),
+        )?;
+        write_test_file(
+            root.join("Habits/Stretch.md"),
+            &format!("# Stretch\n\n{}", reference),
+        )?;
+        write_test_file(root.join("Goals/Taken.md"), "# Taken\n")?;
+
+        let space_path = root.to_string_lossy().to_string();
+        let old_path = goal.to_string_lossy().to_string();
+        let impact = preview_file_rename_impact(
+            space_path.clone(),
+            old_path.clone(),
+            root.join("Goals/Health.md").to_string_lossy().to_string(),
+        )?;
+        assert!(impact.direct_rename);
+        assert_eq!(impact.total_files_to_update, 3);
+        assert_eq!(impact.reference_types, vec!["area", "habit", "project"]);
+        assert!(impact
+            .files_referencing_old_path
+            .iter()
+            .any(|path| path.ends_with("Stretch.md")));
+        assert!(goal.exists());
+
+        let blocked = preview_file_rename_impact(
+            space_path,
+            old_path,
+            root.join("Goals/Taken.md").to_string_lossy().to_string(),
+        )?;
+        assert!(!blocked.direct_rename);
+
+        Ok(())
+    }
+}
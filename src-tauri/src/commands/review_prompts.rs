@@ -0,0 +1,567 @@
+//! Review agendas built from the current state of a space.
+//!
+//! Each review type asks about a different horizon: the daily review looks
+//! at today's commitments, the weekly review at projects and loose ends, and
+//! the monthly review at the shape of the whole system. Only items with
+//! something to look at are included.
+
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::deadlines::{collect_deadlines, DeadlineItem};
+use super::field_vocabulary::field_vocabulary_or_default;
+use super::filesystem::{list_markdown_files, read_file};
+use super::focus::collect_focus_items;
+use super::gtd_habits_domain::{parse_habit_state, HabitStatus};
+use super::gtd_projects::{list_gtd_projects, GTDProject};
+use super::habit_recommendations::get_habit_recommendations;
+use super::orphaned_actions::collect_orphaned_actions;
+use super::stuck_projects::find_projects_without_next_action;
+use super::waiting_for::{collect_waiting_items, waiting_threshold_days};
+
+/// Names listed in `context_data` before the rest are summarized
+const MAX_CONTEXT_NAMES: usize = 10;
+
+/// One question on a review agenda
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewItem {
+    /// What to look at, e.g. "You have 3 overdue actions"
+    pub prompt: String,
+    /// The items or counts behind the prompt
+    pub context_data: String,
+    /// Command that lists the items, when there is one
+    pub action_command: Option<String>,
+}
+
+/// A review agenda
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewPrompt {
+    pub title: String,
+    pub items: Vec<ReviewItem>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ReviewType {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl ReviewType {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value.trim().to_lowercase().as_str() {
+            "daily" => Ok(Self::Daily),
+            "weekly" => Ok(Self::Weekly),
+            "monthly" => Ok(Self::Monthly),
+            other => Err(format!(
+                "Invalid review type '{}': expected daily, weekly, or monthly",
+                other
+            )),
+        }
+    }
+}
+
+fn plural(count: usize, singular: &str, plural: &str) -> String {
+    if count == 1 {
+        format!("1 {}", singular)
+    } else {
+        format!("{} {}", count, plural)
+    }
+}
+
+/// Join names, summarizing any past the first `MAX_CONTEXT_NAMES`.
+fn summarize_names(names: &[String]) -> String {
+    let mut summary = names
+        .iter()
+        .take(MAX_CONTEXT_NAMES)
+        .cloned()
+        .collect::<Vec<_>>()
+        .join(", ");
+    if names.len() > MAX_CONTEXT_NAMES {
+        summary.push_str(&format!(", and {} more", names.len() - MAX_CONTEXT_NAMES));
+    }
+    summary
+}
+
+fn review_item(prompt: String, names: &[String], action_command: Option<&str>) -> ReviewItem {
+    ReviewItem {
+        prompt,
+        context_data: summarize_names(names),
+        action_command: action_command.map(str::to_string),
+    }
+}
+
+fn file_name(path: &str) -> String {
+    Path::new(path)
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string()
+}
+
+/// Action file name or project name
+fn deadline_name(item: &DeadlineItem) -> String {
+    if item.item_type == "action" {
+        file_name(&item.path)
+    } else {
+        item.name.clone()
+    }
+}
+
+/// Deadlines in due-date order, then by name
+fn sorted_deadlines(space_root: &Path, today: NaiveDate) -> Vec<DeadlineItem> {
+    let mut deadlines = collect_deadlines(&space_root.join("Projects"), today);
+    deadlines.sort_by(|a, b| {
+        a.days_until_due
+            .cmp(&b.days_until_due)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    deadlines
+}
+
+fn project_names(projects: &[GTDProject]) -> Vec<String> {
+    projects
+        .iter()
+        .map(|project| project.name.clone())
+        .collect()
+}
+
+fn markdown_names(dir: &Path) -> Vec<String> {
+    list_markdown_files(dir.to_string_lossy().to_string())
+        .map(|files| files.into_iter().map(|file| file.name).collect())
+        .unwrap_or_default()
+}
+
+fn pending_habits(space_root: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(space_root.join("Habits")) else {
+        return Vec::new();
+    };
+
+    let mut names: Vec<String> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        })
+        .filter(|path| {
+            read_file(path.to_string_lossy().to_string())
+                .ok()
+                .and_then(|content| parse_habit_state(&content).ok())
+                .is_some_and(|state| state.status == HabitStatus::Todo)
+        })
+        .map(|path| file_name(&path.to_string_lossy()))
+        .collect();
+    names.sort_by_key(|name| name.to_lowercase());
+    names
+}
+
+fn daily_items(space_root: &Path, today: NaiveDate) -> Vec<ReviewItem> {
+    let deadlines = sorted_deadlines(space_root, today);
+    let mut items = Vec::new();
+
+    let overdue: Vec<String> = deadlines
+        .iter()
+        .filter(|item| item.item_type == "action" && item.days_until_due < 0)
+        .map(deadline_name)
+        .collect();
+    if !overdue.is_empty() {
+        items.push(review_item(
+            format!(
+                "You have {}",
+                plural(overdue.len(), "overdue action", "overdue actions")
+            ),
+            &overdue,
+            Some("check_upcoming_deadlines"),
+        ));
+    }
+
+    let due_today: Vec<String> = deadlines
+        .iter()
+        .filter(|item| item.days_until_due == 0)
+        .map(deadline_name)
+        .collect();
+    if !due_today.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} due today",
+                plural(due_today.len(), "item is", "items are")
+            ),
+            &due_today,
+            Some("check_upcoming_deadlines"),
+        ));
+    }
+
+    let focus: Vec<String> = collect_focus_items(space_root, today)
+        .into_iter()
+        .map(|item| file_name(&item.path))
+        .collect();
+    if !focus.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} scheduled for focus today",
+                plural(focus.len(), "action is", "actions are")
+            ),
+            &focus,
+            Some("get_focus_blocks"),
+        ));
+    }
+
+    let habits = pending_habits(space_root);
+    if !habits.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} not done yet",
+                plural(habits.len(), "habit is", "habits are")
+            ),
+            &habits,
+            Some("toggle_habit"),
+        ));
+    }
+
+    items
+}
+
+fn weekly_items(space_root: &Path, today: NaiveDate) -> Result<Vec<ReviewItem>, String> {
+    let space_path = space_root.to_string_lossy().to_string();
+    let deadlines = sorted_deadlines(space_root, today);
+    let mut items = Vec::new();
+
+    let overdue: Vec<String> = deadlines
+        .iter()
+        .filter(|item| item.days_until_due < 0)
+        .map(deadline_name)
+        .collect();
+    if !overdue.is_empty() {
+        items.push(review_item(
+            format!("{} past due", plural(overdue.len(), "item is", "items are")),
+            &overdue,
+            Some("check_upcoming_deadlines"),
+        ));
+    }
+
+    let this_week: Vec<String> = deadlines
+        .iter()
+        .filter(|item| (0..=7).contains(&item.days_until_due))
+        .map(deadline_name)
+        .collect();
+    if !this_week.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} due in the next 7 days",
+                plural(this_week.len(), "item is", "items are")
+            ),
+            &this_week,
+            Some("check_upcoming_deadlines"),
+        ));
+    }
+
+    let stuck = find_projects_without_next_action(space_path.clone())?;
+    if !stuck.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} no next action",
+                plural(stuck.len(), "project has", "projects have")
+            ),
+            &project_names(&stuck),
+            Some("get_projects_without_next_action"),
+        ));
+    }
+
+    let threshold_days = waiting_threshold_days(space_root);
+    let waiting = collect_waiting_items(space_root, today, threshold_days);
+    let follow_ups: Vec<String> = waiting
+        .iter()
+        .filter(|item| item.needs_follow_up)
+        .map(|item| file_name(&item.path))
+        .collect();
+    if !follow_ups.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} waiting longer than {} days; time to follow up",
+                plural(follow_ups.len(), "action has been", "actions have been"),
+                threshold_days
+            ),
+            &follow_ups,
+            Some("list_waiting_items"),
+        ));
+    }
+    let still_waiting: Vec<String> = waiting
+        .iter()
+        .filter(|item| !item.needs_follow_up)
+        .map(|item| file_name(&item.path))
+        .collect();
+    if !still_waiting.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} waiting on someone else; follow up where needed",
+                plural(still_waiting.len(), "action is", "actions are")
+            ),
+            &still_waiting,
+            Some("list_waiting_items"),
+        ));
+    }
+
+    let orphans: Vec<String> = collect_orphaned_actions(space_root)?
+        .into_iter()
+        .map(|orphan| file_name(&orphan.path))
+        .collect();
+    if !orphans.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} outside any project",
+                plural(orphans.len(), "action file is", "action files are")
+            ),
+            &orphans,
+            Some("find_orphaned_actions"),
+        ));
+    }
+
+    let someday = markdown_names(&space_root.join("Someday Maybe"));
+    if !someday.is_empty() {
+        items.push(review_item(
+            format!(
+                "Scan {} for anything ready to start",
+                plural(someday.len(), "someday/maybe item", "someday/maybe items")
+            ),
+            &someday,
+            Some("list_markdown_files"),
+        ));
+    }
+
+    Ok(items)
+}
+
+fn monthly_items(space_root: &Path, today: NaiveDate) -> Result<Vec<ReviewItem>, String> {
+    let space_path = space_root.to_string_lossy().to_string();
+    let vocabulary = field_vocabulary_or_default(space_root);
+    let mut items = Vec::new();
+
+    let active: Vec<GTDProject> = list_gtd_projects(space_path.clone())?
+        .into_iter()
+        .filter(|project| vocabulary.builtin_status(&project.status) != "completed")
+        .collect();
+    if !active.is_empty() {
+        items.push(review_item(
+            format!(
+                "Confirm your {} still deserve attention",
+                plural(active.len(), "active project", "active projects")
+            ),
+            &project_names(&active),
+            Some("list_gtd_projects"),
+        ));
+    }
+
+    let overdue_projects: Vec<String> = sorted_deadlines(space_root, today)
+        .iter()
+        .filter(|item| item.item_type == "project" && item.days_until_due < 0)
+        .map(deadline_name)
+        .collect();
+    if !overdue_projects.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} past its due date; renegotiate or finish",
+                plural(overdue_projects.len(), "project is", "projects are")
+            ),
+            &overdue_projects,
+            Some("check_upcoming_deadlines"),
+        ));
+    }
+
+    let stuck = find_projects_without_next_action(space_path.clone())?;
+    if !stuck.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} no next action",
+                plural(stuck.len(), "project has", "projects have")
+            ),
+            &project_names(&stuck),
+            Some("get_projects_without_next_action"),
+        ));
+    }
+
+    let unsupported: Vec<String> = get_habit_recommendations(space_path)?
+        .into_iter()
+        .map(|rec| rec.area_or_goal_name)
+        .collect();
+    if !unsupported.is_empty() {
+        items.push(review_item(
+            format!(
+                "{} no supporting habit",
+                plural(
+                    unsupported.len(),
+                    "area or goal has",
+                    "areas and goals have"
+                )
+            ),
+            &unsupported,
+            Some("get_habit_recommendations"),
+        ));
+    }
+
+    let someday = markdown_names(&space_root.join("Someday Maybe"));
+    if !someday.is_empty() {
+        items.push(review_item(
+            format!(
+                "Prune or activate your {}",
+                plural(someday.len(), "someday/maybe item", "someday/maybe items")
+            ),
+            &someday,
+            Some("list_markdown_files"),
+        ));
+    }
+
+    Ok(items)
+}
+
+fn build_review_prompt(
+    space_root: &Path,
+    review_type: ReviewType,
+    today: NaiveDate,
+) -> Result<ReviewPrompt, String> {
+    let (label, mut items) = match review_type {
+        ReviewType::Daily => ("Daily", daily_items(space_root, today)),
+        ReviewType::Weekly => ("Weekly", weekly_items(space_root, today)?),
+        ReviewType::Monthly => ("Monthly", monthly_items(space_root, today)?),
+    };
+    if items.is_empty() {
+        items.push(review_item(
+            "Nothing needs attention right now".to_string(),
+            &[],
+            None,
+        ));
+    }
+
+    Ok(ReviewPrompt {
+        title: format!("{} Review for {}", label, today.format("%A, %B %-d, %Y")),
+        items,
+    })
+}
+
+/// Build a review agenda from the space's current state
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `review_type` - `"daily"`, `"weekly"`, or `"monthly"`
+///
+/// # Returns
+///
+/// A titled list of review items, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const review = await invoke<ReviewPrompt>('get_gtd_review_prompt', {
+///   spacePath: '/path/to/space',
+///   reviewType: 'weekly',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_gtd_review_prompt(
+    space_path: String,
+    review_type: String,
+) -> Result<ReviewPrompt, String> {
+    log::info!("Building {} review prompt for: {}", review_type, space_path);
+
+    let review_type = ReviewType::parse(&review_type)?;
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    build_review_prompt(space_root, review_type, Local::now().date_naive())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(status: &str, due: &str) -> String {
+        format!(
+            "# Action\n\n## Status\n[!singleselect:status:{}]\n\n## Due Date\n[!datetime:due_date:{}]\n",
+            status, due
+        )
+    }
+
+    fn prompts(review: &ReviewPrompt) -> Vec<(&str, &str, Option<&str>)> {
+        review
+            .items
+            .iter()
+            .map(|item| {
+                (
+                    item.prompt.as_str(),
+                    item.context_data.as_str(),
+                    item.action_command.as_deref(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn review_items_reflect_the_space() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        write_test_file(
+            project.join("Late.md"),
+            &action("in-progress", "2026-04-01"),
+        )?;
+        write_test_file(
+            project.join("Later.md"),
+            &action("in-progress", "2026-04-03"),
+        )?;
+        write_test_file(project.join("Chase.md"), &action("waiting", ""))?;
+        write_test_file(root.join("Someday Maybe/Learn Rust.md"), "# Learn Rust\n")?;
+        let today = NaiveDate::from_ymd_opt(2026, 4, 5).expect("valid date");
+
+        let daily = build_review_prompt(root, ReviewType::Daily, today)?;
+        assert_eq!(daily.title, "Daily Review for Sunday, April 5, 2026");
+        assert_eq!(
+            prompts(&daily),
+            vec![(
+                "You have 2 overdue actions",
+                "Late.md, Later.md",
+                Some("check_upcoming_deadlines")
+            )]
+        );
+
+        let weekly = build_review_prompt(root, ReviewType::Weekly, today)?;
+        assert_eq!(
+            prompts(&weekly),
+            vec![
+                (
+                    "3 items are past due",
+                    "Alpha Project, Late.md, Later.md",
+                    Some("check_upcoming_deadlines")
+                ),
+                (
+                    "1 action is waiting on someone else; follow up where needed",
+                    "Chase.md",
+                    Some("list_waiting_items")
+                ),
+                (
+                    "Scan 1 someday/maybe item for anything ready to start",
+                    "Learn Rust.md",
+                    Some("list_markdown_files")
+                ),
+            ]
+        );
+
+        assert!(ReviewType::parse("yearly").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_name_lists_are_summarized() {
+        let names: Vec<String> = (1..=12).map(|i| format!("a{}.md", i)).collect();
+        assert!(summarize_names(&names).ends_with("a10.md, and 2 more"));
+    }
+}
@@ -2,10 +2,13 @@
 
 use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
-use std::fs;
 use std::path::Path;
 use tokio::task;
-use walkdir::WalkDir;
+
+use super::custom_fields::find_custom_field;
+use super::folder_encryption::{encrypted_logical_path, read_searchable_markdown};
+use super::search_query::SearchQuery;
+use super::space_walk::{walk_files, WalkOptions};
 
 /// Search result item
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -18,9 +21,10 @@ pub struct SearchResult {
     pub line_number: usize,
     /// Line content containing the match
     pub line_content: String,
-    /// Start position of match within the line
+    /// Start of the match within `line_content`, in UTF-16 code units so the
+    /// frontend can slice the JavaScript string directly
     pub match_start: usize,
-    /// End position of match within the line
+    /// End of the match within `line_content` (exclusive, UTF-16 code units)
     pub match_end: usize,
     /// Context lines before the match
     pub context_before: Option<Vec<String>>,
@@ -41,6 +45,34 @@ pub struct SearchFilters {
     pub include_file_names: bool,
     /// Maximum number of results
     pub max_results: usize,
+    /// Parse AND/OR/NOT, quoted phrases, and `name:`/`path:` prefixes
+    #[serde(default)]
+    pub use_query_syntax: bool,
+    /// Only search actions whose custom field has this value; with a blank
+    /// query, each matching action is returned at its field marker
+    #[serde(default)]
+    pub custom_field: Option<CustomFieldFilter>,
+    /// Descend into symlinked folders; loops are detected and skipped
+    #[serde(default)]
+    pub follow_symlinks: bool,
+}
+
+/// Custom field equality predicate, e.g. `client = Acme`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CustomFieldFilter {
+    pub key: String,
+    pub value: String,
+}
+
+impl CustomFieldFilter {
+    fn matches(&self, value: &str, case_sensitive: bool) -> bool {
+        let expected = self.value.trim();
+        if case_sensitive {
+            value == expected
+        } else {
+            value.to_lowercase() == expected.to_lowercase()
+        }
+    }
 }
 
 /// Search response from backend
@@ -56,6 +88,9 @@ pub struct SearchResponse {
     pub duration_ms: u64,
     /// Whether search was truncated due to limits
     pub truncated: bool,
+    /// Why some folders were not searched, e.g. the depth limit was hit
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
 }
 
 fn byte_offset_to_utf16(text: &str, byte_offset: usize) -> usize {
@@ -74,6 +109,7 @@ fn truncated_response(
     results: Vec<SearchResult>,
     total_matches: usize,
     files_searched: usize,
+    warning: Option<String>,
 ) -> SearchResponse {
     let duration = start_time.elapsed().as_millis() as u64;
     log::info!(
@@ -87,6 +123,7 @@ fn truncated_response(
         files_searched,
         duration_ms: duration,
         truncated: true,
+        warning,
     }
 }
 
@@ -104,22 +141,25 @@ pub async fn search_files(
     };
 
     log::debug!(
-        "Starting search (query_len={}, use_regex={}, case_sensitive={}, whole_word={}, include_file_names={}, max_results={})",
+        "Starting search (query_len={}, use_regex={}, use_query_syntax={}, case_sensitive={}, whole_word={}, include_file_names={}, max_results={})",
         query.chars().count(),
         filters.use_regex,
+        filters.use_query_syntax,
         filters.case_sensitive,
         filters.whole_word,
         filters.include_file_names,
         filters.max_results
     );
 
-    if query.trim().is_empty() {
+    let query_is_blank = query.trim().is_empty();
+    if query_is_blank && filters.custom_field.is_none() {
         return Ok(SearchResponse {
             results: vec![],
             total_matches: 0,
             files_searched: 0,
             duration_ms: start_time.elapsed().as_millis() as u64,
             truncated: false,
+            warning: None,
         });
     }
 
@@ -134,167 +174,200 @@ pub async fn search_files(
         let mut total_matches = 0;
         let markdown_extensions = ["md", "markdown"];
 
-        let regex_pattern = if filters.use_regex {
-            let pattern = if filters.whole_word {
-                format!(r"\b(?:{})\b", query)
-            } else {
-                query.clone()
-            };
-
-            match RegexBuilder::new(&pattern)
-                .case_insensitive(!filters.case_sensitive)
-                .build()
-            {
-                Ok(re) => Some(re),
-                Err(e) => return Err(format!("Invalid regex pattern: {}", e)),
+        let query_syntax = if query_is_blank {
+            None
+        } else if filters.use_query_syntax {
+            match SearchQuery::parse(&query, &filters) {
+                Ok(parsed) => Some(parsed),
+                Err(error) => {
+                    return Err(serde_json::to_string(&error)
+                        .unwrap_or_else(|_| format!("Invalid search query: {}", error.message)))
+                }
             }
         } else {
             None
         };
-
-        let plain_text_matcher = if filters.use_regex {
-            None
+        let (regex_pattern, plain_text_matcher) = if query_is_blank || query_syntax.is_some() {
+            (None, None)
         } else {
-            let pattern = if filters.whole_word {
-                format!(r"\b{}\b", regex::escape(&query))
-            } else {
-                regex::escape(&query)
-            };
-
-            match RegexBuilder::new(&pattern)
-                .case_insensitive(!filters.case_sensitive)
-                .build()
-            {
-                Ok(re) => Some(re),
-                Err(e) => return Err(format!("Invalid search pattern: {}", e)),
-            }
+            build_matchers(&query, &filters)?
         };
 
-        for entry in WalkDir::new(&directory)
-            .into_iter()
-            .filter_map(|entry| match entry {
-                Ok(entry) => Some(entry),
-                Err(error) => {
-                    log::warn!("Skipping unreadable search entry: {}", error);
-                    None
-                }
-            })
-        {
-            let path = entry.path();
-
-            if path.is_file() {
-                if let Some(extension) = path.extension() {
-                    let ext_str = extension.to_string_lossy().to_lowercase();
-                    if markdown_extensions.contains(&ext_str.as_str()) {
-                        files_searched += 1;
-
-                        if let Ok(content) = fs::read_to_string(path) {
-                            let file_name = path
-                                .file_name()
-                                .unwrap_or_default()
-                                .to_string_lossy()
-                                .to_string();
-                            let file_path = path.to_string_lossy().to_string();
-
-                            if filters.include_file_names {
-                                for match_result in search_in_text(
+        let walk_options = WalkOptions {
+            follow_symlinks: filters.follow_symlinks,
+            ..WalkOptions::default()
+        };
+        let (paths, walk_report) = walk_files(Path::new(&directory), walk_options)?;
+        for path in paths {
+            let path = path.as_path();
+            // Encrypted files are searched under their logical markdown path
+            let logical_path = encrypted_logical_path(path);
+            let display_path = logical_path.as_deref().unwrap_or(path);
+            if let Some(extension) = display_path.extension() {
+                let ext_str = extension.to_string_lossy().to_lowercase();
+                if markdown_extensions.contains(&ext_str.as_str()) {
+                    // Files in locked folders are skipped entirely
+                    let Some(read_result) = read_searchable_markdown(path) else {
+                        continue;
+                    };
+                    files_searched += 1;
+
+                    if let Ok(content) = read_result {
+                        let file_name = display_path
+                            .file_name()
+                            .unwrap_or_default()
+                            .to_string_lossy()
+                            .to_string();
+                        let file_path = display_path.to_string_lossy().to_string();
+
+                        if let Some(filter) = &filters.custom_field {
+                            let Some(field) =
+                                find_custom_field(&content, &filter.key).filter(|field| {
+                                    filter.matches(&field.value, filters.case_sensitive)
+                                })
+                            else {
+                                continue;
+                            };
+                            if query_is_blank {
+                                let line =
+                                    content.lines().nth(field.line_number).unwrap_or_default();
+                                let (match_start, match_end) =
+                                    match_range_to_utf16(line, field.value_range);
+                                total_matches += 1;
+
+                                if results.len() >= filters.max_results {
+                                    return Ok(truncated_response(
+                                        start_time,
+                                        results,
+                                        total_matches,
+                                        files_searched,
+                                        walk_report.warning(),
+                                    ));
+                                }
+
+                                results.push(SearchResult {
+                                    file_path,
+                                    file_name,
+                                    line_number: field.line_number,
+                                    line_content: line.to_string(),
+                                    match_start,
+                                    match_end,
+                                    context_before: None,
+                                    context_after: None,
+                                });
+                                continue;
+                            }
+                        }
+
+                        if filters.include_file_names {
+                            let name_matches = match &query_syntax {
+                                Some(parsed) => {
+                                    parsed.file_name_matches(&file_name, &file_path, &filters)
+                                }
+                                None => search_in_text(
                                     &file_name,
                                     &filters,
                                     &regex_pattern,
                                     &plain_text_matcher,
-                                ) {
-                                    let prefix = "📁 ";
-                                    let prefix_utf16_len = prefix.encode_utf16().count();
-                                    let (match_start, match_end) =
-                                        match_range_to_utf16(&file_name, match_result);
-                                    total_matches += 1;
-
-                                    if results.len() >= filters.max_results {
-                                        return Ok(truncated_response(
-                                            start_time,
-                                            results,
-                                            total_matches,
-                                            files_searched,
-                                        ));
-                                    }
-
-                                    results.push(SearchResult {
-                                        file_path: file_path.clone(),
-                                        file_name: file_name.clone(),
-                                        line_number: 0,
-                                        line_content: format!("{}{}", prefix, file_name),
-                                        match_start: prefix_utf16_len + match_start,
-                                        match_end: prefix_utf16_len + match_end,
-                                        context_before: None,
-                                        context_after: None,
-                                    });
+                                ),
+                            };
+                            for match_result in name_matches {
+                                let prefix = "📁 ";
+                                let prefix_utf16_len = prefix.encode_utf16().count();
+                                let (match_start, match_end) =
+                                    match_range_to_utf16(&file_name, match_result);
+                                total_matches += 1;
+
+                                if results.len() >= filters.max_results {
+                                    return Ok(truncated_response(
+                                        start_time,
+                                        results,
+                                        total_matches,
+                                        files_searched,
+                                        walk_report.warning(),
+                                    ));
                                 }
+
+                                results.push(SearchResult {
+                                    file_path: file_path.clone(),
+                                    file_name: file_name.clone(),
+                                    line_number: 0,
+                                    line_content: format!("{}{}", prefix, file_name),
+                                    match_start: prefix_utf16_len + match_start,
+                                    match_end: prefix_utf16_len + match_end,
+                                    context_before: None,
+                                    context_after: None,
+                                });
                             }
+                        }
 
-                            let lines: Vec<&str> = content.lines().collect();
-                            for (line_number, line) in lines.iter().enumerate() {
-                                for match_result in search_in_text(
+                        let lines: Vec<&str> = content.lines().collect();
+                        for (line_number, line) in lines.iter().enumerate() {
+                            let line_matches = match &query_syntax {
+                                Some(parsed) => {
+                                    parsed.line_matches(line, &file_name, &file_path, &filters)
+                                }
+                                None => search_in_text(
                                     line,
                                     &filters,
                                     &regex_pattern,
                                     &plain_text_matcher,
-                                ) {
-                                    let (match_start, match_end) =
-                                        match_range_to_utf16(line, match_result);
-                                    total_matches += 1;
-
-                                    if results.len() >= filters.max_results {
-                                        return Ok(truncated_response(
-                                            start_time,
-                                            results,
-                                            total_matches,
-                                            files_searched,
-                                        ));
-                                    }
-
-                                    let context_before = if line_number > 0 {
-                                        Some(
-                                            lines
-                                                .get(line_number.saturating_sub(2)..line_number)
-                                                .unwrap_or(&[])
-                                                .iter()
-                                                .map(|s| s.to_string())
-                                                .collect(),
-                                        )
-                                    } else {
-                                        None
-                                    };
-
-                                    let context_after = if line_number < lines.len() - 1 {
-                                        Some(
-                                            lines
-                                                .get(
-                                                    line_number + 1
-                                                        ..std::cmp::min(
-                                                            line_number + 3,
-                                                            lines.len(),
-                                                        ),
-                                                )
-                                                .unwrap_or(&[])
-                                                .iter()
-                                                .map(|s| s.to_string())
-                                                .collect(),
-                                        )
-                                    } else {
-                                        None
-                                    };
-
-                                    results.push(SearchResult {
-                                        file_path: file_path.clone(),
-                                        file_name: file_name.clone(),
-                                        line_number,
-                                        line_content: line.to_string(),
-                                        match_start,
-                                        match_end,
-                                        context_before,
-                                        context_after,
-                                    });
+                                ),
+                            };
+                            for match_result in line_matches {
+                                let (match_start, match_end) =
+                                    match_range_to_utf16(line, match_result);
+                                total_matches += 1;
+
+                                if results.len() >= filters.max_results {
+                                    return Ok(truncated_response(
+                                        start_time,
+                                        results,
+                                        total_matches,
+                                        files_searched,
+                                        walk_report.warning(),
+                                    ));
                                 }
+
+                                let context_before = if line_number > 0 {
+                                    Some(
+                                        lines
+                                            .get(line_number.saturating_sub(2)..line_number)
+                                            .unwrap_or(&[])
+                                            .iter()
+                                            .map(|s| s.to_string())
+                                            .collect(),
+                                    )
+                                } else {
+                                    None
+                                };
+
+                                let context_after = if line_number < lines.len() - 1 {
+                                    Some(
+                                        lines
+                                            .get(
+                                                line_number + 1
+                                                    ..std::cmp::min(line_number + 3, lines.len()),
+                                            )
+                                            .unwrap_or(&[])
+                                            .iter()
+                                            .map(|s| s.to_string())
+                                            .collect(),
+                                    )
+                                } else {
+                                    None
+                                };
+
+                                results.push(SearchResult {
+                                    file_path: file_path.clone(),
+                                    file_name: file_name.clone(),
+                                    line_number,
+                                    line_content: line.to_string(),
+                                    match_start,
+                                    match_end,
+                                    context_before,
+                                    context_after,
+                                });
                             }
                         }
                     }
@@ -315,14 +388,68 @@ pub async fn search_files(
             files_searched,
             duration_ms: duration,
             truncated: false,
+            warning: walk_report.warning(),
         })
     })
     .await
     .map_err(|error| format!("Search task failed: {}", error))?
 }
 
+fn is_word_char(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+/// Escape `query` and anchor it at word boundaries. A `\b` is only added
+/// next to word characters, so queries like `C++` or `#tag` still match.
+fn whole_word_pattern(query: &str) -> String {
+    let starts_with_word = query.chars().next().is_some_and(is_word_char);
+    let ends_with_word = query.chars().next_back().is_some_and(is_word_char);
+    format!(
+        "{}{}{}",
+        if starts_with_word { r"\b" } else { "" },
+        regex::escape(query),
+        if ends_with_word { r"\b" } else { "" }
+    )
+}
+
+/// Compile `query` into the regex or plain-text matcher used by `search_in_text`
+pub(crate) fn build_matchers(
+    query: &str,
+    filters: &SearchFilters,
+) -> Result<(Option<Regex>, Option<Regex>), String> {
+    if filters.use_regex {
+        let pattern = if filters.whole_word {
+            format!(r"\b(?:{})\b", query)
+        } else {
+            query.to_string()
+        };
+
+        match RegexBuilder::new(&pattern)
+            .case_insensitive(!filters.case_sensitive)
+            .build()
+        {
+            Ok(re) => Ok((Some(re), None)),
+            Err(e) => Err(format!("Invalid regex pattern: {}", e)),
+        }
+    } else {
+        let pattern = if filters.whole_word {
+            whole_word_pattern(query)
+        } else {
+            regex::escape(query)
+        };
+
+        match RegexBuilder::new(&pattern)
+            .case_insensitive(!filters.case_sensitive)
+            .build()
+        {
+            Ok(re) => Ok((None, Some(re))),
+            Err(e) => Err(format!("Invalid search pattern: {}", e)),
+        }
+    }
+}
+
 /// Search for a pattern in text with various options
-fn search_in_text(
+pub(crate) fn search_in_text(
     text: &str,
     filters: &SearchFilters,
     regex_pattern: &Option<Regex>,
@@ -355,6 +482,9 @@ mod tests {
             use_regex,
             include_file_names: false,
             max_results: 10,
+            use_query_syntax: false,
+            custom_field: None,
+            follow_symlinks: false,
         }
     }
 
@@ -381,4 +511,87 @@ mod tests {
 
         assert_eq!(matches, vec![(0, 4), (5, 9), (10, 14)]);
     }
+
+    fn utf16_matches(query: &str, text: &str, case_sensitive: bool) -> Vec<(usize, usize)> {
+        let filters = SearchFilters {
+            case_sensitive,
+            whole_word: true,
+            ..build_filters(false)
+        };
+        let (regex_pattern, plain_text_matcher) =
+            build_matchers(query, &filters).expect("matcher should compile");
+        search_in_text(text, &filters, &regex_pattern, &plain_text_matcher)
+            .into_iter()
+            .map(|range| match_range_to_utf16(text, range))
+            .collect()
+    }
+
+    #[test]
+    fn whole_word_offsets_are_utf16_across_tabs_emoji_and_accents() {
+        // Tab + 🚀 (two UTF-16 units) + space puts the first match at 4
+        assert_eq!(
+            utf16_matches("café", "\t🚀 café  Café,cafés", false),
+            vec![(4, 8), (10, 14)]
+        );
+        assert_eq!(utf16_matches("café", "\t🚀 café  Café", true), vec![(4, 8)]);
+        // Case folding is done by the regex, so lengths are never changed
+        assert_eq!(utf16_matches("été", "L'ÉTÉ\tdernier", false), vec![(2, 5)]);
+        assert_eq!(
+            utf16_matches("review", "\t\t- [ ] review: weekly", false),
+            vec![(8, 14)]
+        );
+        assert!(utf16_matches("view", "review preview", false).is_empty());
+    }
+
+    #[test]
+    fn whole_word_queries_may_start_or_end_with_punctuation() {
+        assert_eq!(utf16_matches("C++", "Learn C++ today", true), vec![(6, 9)]);
+        assert_eq!(
+            utf16_matches("#tag", "see #tag, not #tags", true),
+            vec![(4, 8)]
+        );
+    }
+
+    #[tokio::test]
+    async fn custom_field_filter_narrows_and_lists_actions() -> Result<(), String> {
+        let workspace = crate::test_utils::seed_test_workspace()?;
+        let project = workspace.path().join("Projects/Alpha Project");
+        for (name, client) in [("Invoice", "Acme"), ("Audit", "Globex")] {
+            crate::test_utils::write_test_file(
+                project.join(format!("{}.md", name)),
+                &format!(
+                    "# {}\n\nSend the report.\n\n## Custom\n[!text:client:{}]\n",
+                    name, client
+                ),
+            )?;
+        }
+        let filters = SearchFilters {
+            case_sensitive: false,
+            custom_field: Some(CustomFieldFilter {
+                key: "client".to_string(),
+                value: "acme".to_string(),
+            }),
+            ..build_filters(false)
+        };
+        let directory = workspace.path().to_string_lossy().to_string();
+
+        let listed = search_files(String::new(), directory.clone(), filters.clone()).await?;
+        assert_eq!(listed.results.len(), 1);
+        assert_eq!(listed.results[0].file_name, "Invoice.md");
+        assert_eq!(listed.results[0].line_number, 5);
+        assert_eq!(
+            (listed.results[0].match_start, listed.results[0].match_end),
+            (14, 18)
+        );
+
+        let narrowed = search_files("report".to_string(), directory, filters).await?;
+        let files: Vec<&str> = narrowed
+            .results
+            .iter()
+            .map(|result| result.file_name.as_str())
+            .collect();
+        assert_eq!(files, vec!["Invoice.md"]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,536 @@
+//! Query syntax for `search_files` when `use_query_syntax` is enabled.
+//!
+//! Terms are ANDed implicitly, `OR` joins alternatives, and `NOT` or a
+//! leading `-` excludes a term. `NOT` binds tighter than `AND`, which binds
+//! tighter than `OR`; parentheses group. Quoted phrases are matched as-is, so
+//! operators inside them are plain text. `name:` and `path:` prefixes match
+//! the file name or path instead of the line. Every term honours the usual
+//! case, whole-word, and regex options; regex terms containing spaces or
+//! parentheses need quoting.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::search::{build_matchers, search_in_text, SearchFilters};
+
+/// Reason a search query was rejected
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SearchQueryErrorKind {
+    /// The query could not be parsed or a term could not be compiled
+    InvalidInput,
+}
+
+/// Structured error returned (JSON-encoded) for a malformed query
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SearchQueryError {
+    pub kind: SearchQueryErrorKind,
+    /// Character offset into the query where the problem starts
+    pub offset: usize,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl SearchQueryError {
+    fn invalid(offset: usize, message: impl Into<String>) -> Self {
+        Self {
+            kind: SearchQueryErrorKind::InvalidInput,
+            offset,
+            message: message.into(),
+        }
+    }
+}
+
+/// What a term is matched against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TermField {
+    Content,
+    Name,
+    Path,
+}
+
+impl TermField {
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        if prefix.eq_ignore_ascii_case("name") {
+            Some(Self::Name)
+        } else if prefix.eq_ignore_ascii_case("path") {
+            Some(Self::Path)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Term { field: TermField, value: String },
+    And,
+    Or,
+    Not,
+    LParen,
+    RParen,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    offset: usize,
+}
+
+/// Read a quoted phrase starting at the opening quote at `start`.
+fn read_phrase(chars: &[char], start: usize) -> Result<(String, usize), SearchQueryError> {
+    let close = chars[start + 1..]
+        .iter()
+        .position(|c| *c == '"')
+        .map(|position| start + 1 + position)
+        .ok_or_else(|| SearchQueryError::invalid(start, "unterminated quoted phrase"))?;
+    let phrase: String = chars[start + 1..close].iter().collect();
+    if phrase.trim().is_empty() {
+        return Err(SearchQueryError::invalid(start, "empty quoted phrase"));
+    }
+    Ok((phrase, close + 1))
+}
+
+fn tokenize(query: &str) -> Result<Vec<Token>, SearchQueryError> {
+    let chars: Vec<char> = query.chars().collect();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < chars.len() {
+        let start = index;
+        let kind = match chars[index] {
+            c if c.is_whitespace() => {
+                index += 1;
+                continue;
+            }
+            '(' => {
+                index += 1;
+                TokenKind::LParen
+            }
+            ')' => {
+                index += 1;
+                TokenKind::RParen
+            }
+            '-' if chars
+                .get(index + 1)
+                .is_some_and(|next| !next.is_whitespace() && *next != ')') =>
+            {
+                index += 1;
+                TokenKind::Not
+            }
+            '"' => {
+                let (value, next) = read_phrase(&chars, index)?;
+                index = next;
+                TokenKind::Term {
+                    field: TermField::Content,
+                    value,
+                }
+            }
+            _ => {
+                while index < chars.len()
+                    && !chars[index].is_whitespace()
+                    && !matches!(chars[index], '(' | ')' | '"')
+                {
+                    index += 1;
+                }
+                let word: String = chars[start..index].iter().collect();
+                match word.as_str() {
+                    "AND" => TokenKind::And,
+                    "OR" => TokenKind::Or,
+                    "NOT" => TokenKind::Not,
+                    _ => match word
+                        .split_once(':')
+                        .and_then(|(prefix, rest)| Some((TermField::from_prefix(prefix)?, rest)))
+                    {
+                        Some((field, "")) if chars.get(index) == Some(&'"') => {
+                            let (value, next) = read_phrase(&chars, index)?;
+                            index = next;
+                            TokenKind::Term { field, value }
+                        }
+                        Some((_, "")) => {
+                            return Err(SearchQueryError::invalid(
+                                start,
+                                format!("expected a value after '{}'", word),
+                            ))
+                        }
+                        Some((field, rest)) => TokenKind::Term {
+                            field,
+                            value: rest.to_string(),
+                        },
+                        None => TokenKind::Term {
+                            field: TermField::Content,
+                            value: word,
+                        },
+                    },
+                }
+            }
+        };
+        tokens.push(Token {
+            kind,
+            offset: start,
+        });
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug)]
+enum QueryNode {
+    Term(usize),
+    Not(Box<QueryNode>),
+    And(Vec<QueryNode>),
+    Or(Vec<QueryNode>),
+}
+
+#[derive(Debug)]
+struct QueryTerm {
+    field: TermField,
+    /// Not under an odd number of `NOT`s; only these are highlighted
+    positive: bool,
+    regex_pattern: Option<Regex>,
+    plain_text_matcher: Option<Regex>,
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    position: usize,
+    end_offset: usize,
+    filters: &'a SearchFilters,
+    terms: Vec<QueryTerm>,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.position).map(|token| &token.kind)
+    }
+
+    fn parse_or(&mut self, negated: bool) -> Result<QueryNode, SearchQueryError> {
+        let mut nodes = vec![self.parse_and(negated)?];
+        while self.peek() == Some(&TokenKind::Or) {
+            self.position += 1;
+            nodes.push(self.parse_and(negated)?);
+        }
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            QueryNode::Or(nodes)
+        })
+    }
+
+    fn parse_and(&mut self, negated: bool) -> Result<QueryNode, SearchQueryError> {
+        let mut nodes = vec![self.parse_unary(negated)?];
+        loop {
+            match self.peek() {
+                None | Some(TokenKind::Or) | Some(TokenKind::RParen) => break,
+                Some(TokenKind::And) => self.position += 1,
+                Some(_) => {}
+            }
+            nodes.push(self.parse_unary(negated)?);
+        }
+        Ok(if nodes.len() == 1 {
+            nodes.remove(0)
+        } else {
+            QueryNode::And(nodes)
+        })
+    }
+
+    fn parse_unary(&mut self, negated: bool) -> Result<QueryNode, SearchQueryError> {
+        if self.peek() == Some(&TokenKind::Not) {
+            self.position += 1;
+            return Ok(QueryNode::Not(Box::new(self.parse_unary(!negated)?)));
+        }
+        self.parse_primary(negated)
+    }
+
+    fn parse_primary(&mut self, negated: bool) -> Result<QueryNode, SearchQueryError> {
+        let Some(token) = self.tokens.get(self.position).cloned() else {
+            return Err(SearchQueryError::invalid(
+                self.end_offset,
+                "expected a search term at end of query",
+            ));
+        };
+        self.position += 1;
+
+        match token.kind {
+            TokenKind::Term { field, value } => {
+                let (regex_pattern, plain_text_matcher) = build_matchers(&value, self.filters)
+                    .map_err(|error| SearchQueryError::invalid(token.offset, error))?;
+                self.terms.push(QueryTerm {
+                    field,
+                    positive: !negated,
+                    regex_pattern,
+                    plain_text_matcher,
+                });
+                Ok(QueryNode::Term(self.terms.len() - 1))
+            }
+            TokenKind::LParen => {
+                let node = self.parse_or(negated)?;
+                if self.peek() != Some(&TokenKind::RParen) {
+                    return Err(SearchQueryError::invalid(
+                        token.offset,
+                        "missing closing ')'",
+                    ));
+                }
+                self.position += 1;
+                Ok(node)
+            }
+            other => {
+                let found = match other {
+                    TokenKind::And => "AND",
+                    TokenKind::Or => "OR",
+                    _ => ")",
+                };
+                Err(SearchQueryError::invalid(
+                    token.offset,
+                    format!("expected a search term, found '{}'", found),
+                ))
+            }
+        }
+    }
+}
+
+/// What one query evaluation can see
+struct MatchTarget<'a> {
+    text: &'a str,
+    file_name: &'a str,
+    file_path: &'a str,
+}
+
+/// A parsed query with its compiled terms
+#[derive(Debug)]
+pub(crate) struct SearchQuery {
+    root: QueryNode,
+    terms: Vec<QueryTerm>,
+}
+
+impl SearchQuery {
+    pub(crate) fn parse(query: &str, filters: &SearchFilters) -> Result<Self, SearchQueryError> {
+        let mut parser = Parser {
+            tokens: tokenize(query)?,
+            position: 0,
+            end_offset: query.chars().count(),
+            filters,
+            terms: Vec::new(),
+        };
+        let root = parser.parse_or(false)?;
+        if let Some(token) = parser.tokens.get(parser.position) {
+            return Err(SearchQueryError::invalid(token.offset, "unmatched ')'"));
+        }
+        Ok(Self {
+            root,
+            terms: parser.terms,
+        })
+    }
+
+    fn term_ranges(
+        &self,
+        term: &QueryTerm,
+        target: &MatchTarget,
+        filters: &SearchFilters,
+    ) -> Vec<(usize, usize)> {
+        let text = match term.field {
+            TermField::Content => target.text,
+            TermField::Name => target.file_name,
+            TermField::Path => target.file_path,
+        };
+        search_in_text(text, filters, &term.regex_pattern, &term.plain_text_matcher)
+    }
+
+    fn evaluate(&self, node: &QueryNode, target: &MatchTarget, filters: &SearchFilters) -> bool {
+        match node {
+            QueryNode::Term(index) => !self
+                .term_ranges(&self.terms[*index], target, filters)
+                .is_empty(),
+            QueryNode::Not(inner) => !self.evaluate(inner, target, filters),
+            QueryNode::And(nodes) => nodes
+                .iter()
+                .all(|node| self.evaluate(node, target, filters)),
+            QueryNode::Or(nodes) => nodes
+                .iter()
+                .any(|node| self.evaluate(node, target, filters)),
+        }
+    }
+
+    /// Ranges of the first positive term (in query order) found in `target.text`.
+    ///
+    /// Empty when the query does not match or nothing on the text can be
+    /// highlighted.
+    fn highlight(
+        &self,
+        target: &MatchTarget,
+        filters: &SearchFilters,
+        fields: &[TermField],
+    ) -> Vec<(usize, usize)> {
+        if !self.evaluate(&self.root, target, filters) {
+            return Vec::new();
+        }
+        self.terms
+            .iter()
+            .filter(|term| term.positive && fields.contains(&term.field))
+            .map(|term| {
+                search_in_text(
+                    target.text,
+                    filters,
+                    &term.regex_pattern,
+                    &term.plain_text_matcher,
+                )
+            })
+            .find(|ranges| !ranges.is_empty())
+            .unwrap_or_default()
+    }
+
+    /// Match ranges for one line of `file_path`.
+    pub(crate) fn line_matches(
+        &self,
+        line: &str,
+        file_name: &str,
+        file_path: &str,
+        filters: &SearchFilters,
+    ) -> Vec<(usize, usize)> {
+        let target = MatchTarget {
+            text: line,
+            file_name,
+            file_path,
+        };
+        self.highlight(&target, filters, &[TermField::Content])
+    }
+
+    /// Match ranges for the file name row; `name:` terms highlight here too.
+    pub(crate) fn file_name_matches(
+        &self,
+        file_name: &str,
+        file_path: &str,
+        filters: &SearchFilters,
+    ) -> Vec<(usize, usize)> {
+        let target = MatchTarget {
+            text: file_name,
+            file_name,
+            file_path,
+        };
+        self.highlight(&target, filters, &[TermField::Content, TermField::Name])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filters() -> SearchFilters {
+        SearchFilters {
+            case_sensitive: false,
+            whole_word: false,
+            use_regex: false,
+            include_file_names: false,
+            max_results: 10,
+            use_query_syntax: true,
+            custom_field: None,
+            follow_symlinks: false,
+        }
+    }
+
+    fn line_matches(query: &str, line: &str) -> Vec<(usize, usize)> {
+        let filters = filters();
+        SearchQuery::parse(query, &filters)
+            .expect("query should parse")
+            .line_matches(line, "Invoices.md", "/space/Cabinet/Invoices.md", &filters)
+    }
+
+    fn is_match(query: &str, line: &str) -> bool {
+        let filters = filters();
+        let query = SearchQuery::parse(query, &filters).expect("query should parse");
+        let target = MatchTarget {
+            text: line,
+            file_name: "Invoices.md",
+            file_path: "/space/Cabinet/Invoices.md",
+        };
+        query.evaluate(&query.root, &target, &filters)
+    }
+
+    fn parse_error(query: &str) -> SearchQueryError {
+        SearchQuery::parse(query, &filters()).expect_err("query should be rejected")
+    }
+
+    #[test]
+    fn not_binds_tighter_than_or_and_and_tighter_than_or() {
+        // invoice OR (NOT paid)
+        assert!(is_match("invoice OR NOT paid", "invoice paid"));
+        assert!(is_match("invoice OR NOT paid", "receipt"));
+        assert!(!is_match("invoice OR NOT paid", "paid receipt"));
+
+        // invoice OR (review AND quarterly)
+        assert!(is_match("invoice OR review quarterly", "quarterly review"));
+        assert!(!is_match("invoice OR review quarterly", "review only"));
+        assert!(!is_match("(invoice OR review) quarterly", "review only"));
+
+        assert!(is_match("invoice -paid", "invoice due"));
+        assert!(!is_match("invoice NOT paid", "invoice paid"));
+        assert!(is_match("NOT NOT paid", "paid"));
+    }
+
+    #[test]
+    fn quoted_phrases_keep_operators_literal() {
+        assert!(is_match(
+            "\"review OR quarterly\"",
+            "Notes: review OR quarterly"
+        ));
+        assert!(!is_match("\"review OR quarterly\"", "review"));
+        assert!(is_match("\"NOT paid\"", "NOT paid yet"));
+        assert!(!is_match("\"NOT paid\"", "unpaid"));
+        assert_eq!(line_matches("\"-paid\"", "pre-paid"), vec![(3, 8)]);
+    }
+
+    #[test]
+    fn highlights_first_positive_term_that_matches() {
+        assert_eq!(
+            line_matches("NOT paid invoice", "an invoice"),
+            vec![(3, 10)]
+        );
+        assert_eq!(
+            line_matches("missing OR invoice", "invoice, invoice"),
+            vec![(0, 7), (9, 16)]
+        );
+        assert!(line_matches("NOT paid", "receipt").is_empty());
+        assert!(line_matches("invoice NOT paid", "invoice paid").is_empty());
+    }
+
+    #[test]
+    fn field_prefixes_match_name_and_path() {
+        assert!(is_match("name:invoices due", "due soon"));
+        assert!(!is_match("name:receipts due", "due soon"));
+        assert!(is_match("path:\"Cabinet/Inv\"", "anything"));
+        assert!(!is_match("-path:cabinet", "anything"));
+
+        let filters = filters();
+        let query = SearchQuery::parse("name:invoice", &filters).expect("query should parse");
+        assert_eq!(
+            query.file_name_matches("Invoices.md", "/space/Invoices.md", &filters),
+            vec![(0, 7)]
+        );
+        assert!(query
+            .line_matches("invoice", "Invoices.md", "/space/Invoices.md", &filters)
+            .is_empty());
+    }
+
+    #[test]
+    fn parse_errors_report_character_offsets() {
+        let error = parse_error("café \"unterminated");
+        assert_eq!(error.kind, SearchQueryErrorKind::InvalidInput);
+        assert_eq!(error.offset, 5);
+        assert_eq!(error.message, "unterminated quoted phrase");
+
+        assert_eq!(parse_error("invoice OR").offset, 10);
+        assert_eq!(parse_error("OR invoice").offset, 0);
+        assert_eq!(parse_error("(invoice paid").offset, 0);
+        assert_eq!(parse_error("invoice ) paid").offset, 8);
+        assert_eq!(parse_error("due name:").offset, 4);
+        assert_eq!(parse_error("due \"\"").offset, 4);
+
+        let regex_filters = SearchFilters {
+            use_regex: true,
+            ..filters()
+        };
+        let error = SearchQuery::parse("ok [a-", &regex_filters)
+            .expect_err("regex term should be rejected");
+        assert_eq!(error.offset, 3);
+    }
+}
@@ -0,0 +1,595 @@
+//! Find and rewrite markdown sections by heading.
+//!
+//! A section is a heading plus everything up to the next heading of the same
+//! or higher level, so `## Notes` owns its `### Call log` subsection. ATX
+//! (`## Title`) and setext (`Title` underlined with `===` or `---`) headings
+//! are recognized; lines inside fenced code blocks and YAML front matter are
+//! not. The trailing `---` footer ahead of `## Created` also closes a
+//! section, so it stays in place when a body is replaced; other thematic
+//! breaks (`---`, `***`, `___`) are part of the body they sit in.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::file_locks::with_file_lock;
+use super::filesystem::{read_file, save_file_contents};
+
+/// A heading and the lines it owns
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Section {
+    /// Heading text without markers
+    pub title: String,
+    /// 1-6; setext headings are 1 (`===`) or 2 (`---`)
+    pub level: u8,
+    /// Zero-based line of the heading (first line of a setext heading)
+    pub start_line: usize,
+    /// Zero-based line after the section's last line
+    pub end_line: usize,
+}
+
+struct Heading {
+    section: Section,
+    /// First line after the heading markup
+    body_start: usize,
+}
+
+fn indent_width(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+fn atx_heading(line: &str) -> Option<(u8, String)> {
+    if indent_width(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start_matches(' ');
+    let hashes = rest.chars().take_while(|ch| *ch == '#').count();
+    if hashes == 0 || hashes > 6 {
+        return None;
+    }
+    let after = &rest[hashes..];
+    if !after.is_empty() && !after.starts_with([' ', '\t']) {
+        return None;
+    }
+
+    let text = after.trim();
+    let without_closing = text.trim_end_matches('#');
+    let title = if without_closing.is_empty() {
+        without_closing
+    } else if without_closing.ends_with([' ', '\t']) {
+        without_closing.trim_end()
+    } else {
+        text
+    };
+    Some((hashes as u8, title.to_string()))
+}
+
+/// Level of a setext underline: 1 for `===`, 2 for `---`.
+fn setext_level(line: &str) -> Option<u8> {
+    if indent_width(line) > 3 {
+        return None;
+    }
+    let trimmed = line.trim();
+    if !trimmed.is_empty() && trimmed.chars().all(|ch| ch == '=') {
+        Some(1)
+    } else if !trimmed.is_empty() && trimmed.chars().all(|ch| ch == '-') {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn is_thematic_break(line: &str) -> bool {
+    if indent_width(line) > 3 {
+        return false;
+    }
+    let marks: Vec<char> = line.chars().filter(|ch| !ch.is_whitespace()).collect();
+    marks.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|mark| marks.iter().all(|ch| ch == mark))
+}
+
+/// Fence character and length when `line` opens a code block.
+fn fence_open(line: &str) -> Option<(char, usize)> {
+    if indent_width(line) > 3 {
+        return None;
+    }
+    let rest = line.trim_start_matches(' ');
+    let ch = rest.chars().next().filter(|ch| *ch == '`' || *ch == '~')?;
+    let len = rest.chars().take_while(|c| *c == ch).count();
+    if len < 3 || (ch == '`' && rest[len..].contains('`')) {
+        return None;
+    }
+    Some((ch, len))
+}
+
+fn closes_fence(line: &str, fence: (char, usize)) -> bool {
+    let trimmed = line.trim();
+    indent_width(line) <= 3 && trimmed.len() >= fence.1 && trimmed.chars().all(|ch| ch == fence.0)
+}
+
+/// Lines that start a block other than a paragraph, so a `---` under them is
+/// a thematic break rather than a setext underline.
+fn starts_non_paragraph(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    trimmed.starts_with("- ")
+        || trimmed.starts_with("* ")
+        || trimmed.starts_with("+ ")
+        || trimmed.starts_with('>')
+        || trimmed.starts_with('|')
+        || trimmed.starts_with("[!")
+        || indent_width(line) > 3
+}
+
+/// Line after a leading `---` front matter block, or 0.
+fn front_matter_end(lines: &[&str]) -> usize {
+    if lines.first().map(|line| line.trim_end()) != Some("---") {
+        return 0;
+    }
+    lines[1..]
+        .iter()
+        .position(|line| matches!(line.trim_end(), "---" | "..."))
+        .map_or(0, |offset| offset + 2)
+}
+
+fn scan_headings(lines: &[&str]) -> Vec<Heading> {
+    let mut headings: Vec<Heading> = Vec::new();
+    let mut fence = None;
+    let mut paragraph_start: Option<usize> = None;
+
+    for (index, line) in lines.iter().enumerate().skip(front_matter_end(lines)) {
+        if let Some(open) = fence {
+            if closes_fence(line, open) {
+                fence = None;
+            }
+            continue;
+        }
+        if let Some(open) = fence_open(line) {
+            fence = Some(open);
+            paragraph_start = None;
+            continue;
+        }
+        if let Some((level, title)) = atx_heading(line) {
+            headings.push(Heading {
+                section: Section {
+                    title,
+                    level,
+                    start_line: index,
+                    end_line: lines.len(),
+                },
+                body_start: index + 1,
+            });
+            paragraph_start = None;
+            continue;
+        }
+        if let (Some(start), Some(level)) = (paragraph_start, setext_level(line)) {
+            let title = lines[start..index]
+                .iter()
+                .map(|line| line.trim())
+                .collect::<Vec<_>>()
+                .join(" ");
+            headings.push(Heading {
+                section: Section {
+                    title,
+                    level,
+                    start_line: start,
+                    end_line: lines.len(),
+                },
+                body_start: index + 1,
+            });
+            paragraph_start = None;
+            continue;
+        }
+        if is_thematic_break(line) {
+            paragraph_start = None;
+            continue;
+        }
+
+        if line.trim().is_empty() {
+            paragraph_start = None;
+        } else if paragraph_start.is_none() && !starts_non_paragraph(line) {
+            paragraph_start = Some(index);
+        }
+    }
+
+    let footer = footer_start(lines, &headings);
+    for index in 0..headings.len() {
+        let level = headings[index].section.level;
+        let next_heading = headings[index + 1..]
+            .iter()
+            .find(|heading| heading.section.level <= level)
+            .map(|heading| heading.section.start_line);
+        let next_footer = (footer >= headings[index].body_start).then_some(footer);
+        headings[index].section.end_line = [next_heading, next_footer]
+            .into_iter()
+            .flatten()
+            .min()
+            .unwrap_or(lines.len());
+    }
+    headings
+}
+
+/// Every section in document order, nested ones included.
+pub(crate) fn list_sections(content: &str) -> Vec<Section> {
+    let lines: Vec<&str> = content.lines().collect();
+    scan_headings(&lines)
+        .into_iter()
+        .map(|heading| heading.section)
+        .collect()
+}
+
+/// First line of a section's body, after the heading markup.
+pub(crate) fn section_body_start(lines: &[&str], section: &Section) -> usize {
+    let start = section.start_line;
+    if lines
+        .get(start)
+        .is_some_and(|line| atx_heading(line).is_some())
+    {
+        return start + 1;
+    }
+    lines
+        .get(start + 1..section.end_line)
+        .and_then(|rest| rest.iter().position(|line| setext_level(line).is_some()))
+        .map_or(start + 1, |offset| start + offset + 2)
+}
+
+fn find_heading<'a>(headings: &'a [Heading], title: &str) -> Option<&'a Heading> {
+    let title = title.trim();
+    headings
+        .iter()
+        .find(|heading| heading.section.title.eq_ignore_ascii_case(title))
+}
+
+/// Body of the first section titled `title` (case-insensitive), trimmed of
+/// surrounding blank lines. Subsections are part of the body.
+pub(crate) fn get_section(content: &str, title: &str) -> Option<String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let headings = scan_headings(&lines);
+    let heading = find_heading(&headings, title)?;
+    let body = &lines[heading.body_start.min(heading.section.end_line)..heading.section.end_line];
+    Some(body.join("\n").trim_matches('\n').to_string())
+}
+
+/// Replace the body of the first section titled `title`, keeping its heading
+/// and everything outside the section byte for byte.
+///
+/// With `create_if_missing`, a missing section is added as `## {title}` at the
+/// end of the document, ahead of a trailing `---` footer when there is one.
+pub(crate) fn replace_section(
+    content: &str,
+    title: &str,
+    new_body: &str,
+    create_if_missing: bool,
+) -> Result<String, String> {
+    let lines: Vec<&str> = content.lines().collect();
+    let headings = scan_headings(&lines);
+    let new_body = new_body.trim_matches('\n');
+
+    let (before, replacement, after) = match find_heading(&headings, title) {
+        Some(heading) => {
+            let start = heading.body_start.min(heading.section.end_line);
+            let end = heading.section.end_line;
+            let leading_blank = lines.get(start).is_some_and(|line| line.trim().is_empty());
+            let mut replacement = Vec::new();
+            if leading_blank && !new_body.is_empty() {
+                replacement.push(String::new());
+            }
+            replacement.extend(new_body.lines().map(str::to_string));
+            if end < lines.len() {
+                replacement.push(String::new());
+            }
+            (&lines[..start], replacement, &lines[end..])
+        }
+        None if create_if_missing => {
+            let title = title.trim();
+            if title.is_empty() {
+                return Err("Section title is required".to_string());
+            }
+            let footer = footer_start(&lines, &headings);
+            let mut kept = &lines[..footer];
+            while kept.last().is_some_and(|line| line.trim().is_empty()) {
+                kept = &kept[..kept.len() - 1];
+            }
+            let mut replacement = Vec::new();
+            if !kept.is_empty() {
+                replacement.push(String::new());
+            }
+            replacement.push(format!("## {}", title));
+            if !new_body.is_empty() {
+                replacement.push(String::new());
+                replacement.extend(new_body.lines().map(str::to_string));
+            }
+            if footer < lines.len() {
+                replacement.push(String::new());
+            }
+            (kept, replacement, &lines[footer..])
+        }
+        None => return Err(format!("Section '{}' not found", title.trim())),
+    };
+
+    let mut updated: Vec<&str> = before.to_vec();
+    updated.extend(replacement.iter().map(String::as_str));
+    updated.extend_from_slice(after);
+    let mut result = updated.join("\n");
+    if content.ends_with('\n') || content.is_empty() {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// Insert `block` ahead of the `---` / `## Created` footer that GTD files
+/// end with, or at the end of the document when there is none, with a blank
+/// line on either side.
+pub(crate) fn insert_before_created_footer(content: &str, block: &str) -> String {
+    let mut lines: Vec<&str> = content.lines().collect();
+    let block: Vec<&str> = block.trim_matches('\n').lines().collect();
+    let footer = lines
+        .iter()
+        .rposition(|line| line.trim().eq_ignore_ascii_case("## Created"))
+        .map(|created| {
+            if created > 0 && is_thematic_break(lines[created - 1]) {
+                created - 1
+            } else {
+                created
+            }
+        });
+    let index = footer.unwrap_or(lines.len());
+
+    let mut inserted = Vec::with_capacity(block.len() + 2);
+    if index > 0 && !lines[index - 1].trim().is_empty() {
+        inserted.push("");
+    }
+    inserted.extend(block);
+    if footer.is_some() {
+        inserted.push("");
+    }
+    lines.splice(index..index, inserted);
+
+    let mut updated = lines.join("\n");
+    updated.push('\n');
+    updated
+}
+
+/// Line of a trailing footer that starts with a thematic break and holds no
+/// top-level content of its own, or the end of the document.
+fn footer_start(lines: &[&str], headings: &[Heading]) -> usize {
+    let last_break = lines
+        .iter()
+        .enumerate()
+        .skip(front_matter_end(lines))
+        .rev()
+        .find(|(index, line)| {
+            is_thematic_break(line)
+                && !headings
+                    .iter()
+                    .any(|heading| heading.body_start - 1 == *index)
+        })
+        .map(|(index, _)| index);
+    match last_break {
+        Some(index)
+            if headings
+                .iter()
+                .any(|heading| heading.section.start_line > index) =>
+        {
+            index
+        }
+        _ => lines.len(),
+    }
+}
+
+/// Read one section of a markdown file
+///
+/// Matches the first heading with the given title, ignoring case and heading
+/// level. Subsections are included in the body.
+///
+/// # Arguments
+///
+/// * `path` - Full path to the markdown file
+/// * `title` - Heading text without the `#` markers
+///
+/// # Returns
+///
+/// Section body without surrounding blank lines, `null` when no heading
+/// matches, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const outcome = await invoke<string | null>('get_file_section', {
+///   path: '/path/to/space/Projects/Website/README.md',
+///   title: 'Desired Outcome',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_file_section(path: String, title: String) -> Result<Option<String>, String> {
+    log::info!("Reading section '{}' from: {}", title, path);
+    Ok(get_section(&read_file(path)?, &title))
+}
+
+/// Replace the body of one section in a markdown file
+///
+/// Keeps the heading and everything outside the section unchanged, including
+/// a `---` footer below it. Fails when the section is missing unless
+/// `create_if_missing` is set, in which case a `## {title}` section is added
+/// at the end of the document, ahead of the footer.
+///
+/// # Arguments
+///
+/// * `path` - Full path to the markdown file
+/// * `title` - Heading text without the `#` markers
+/// * `body` - New section body
+/// * `create_if_missing` - Add the section when no heading matches
+///
+/// # Returns
+///
+/// Nothing on success, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('replace_file_section', {
+///   path: '/path/to/space/Projects/Website/README.md',
+///   title: 'Desired Outcome',
+///   body: 'Launch the redesigned site before the spring campaign.',
+/// });
+/// ```
+#[tauri::command]
+pub fn replace_file_section(
+    path: String,
+    title: String,
+    body: String,
+    create_if_missing: Option<bool>,
+) -> Result<(), String> {
+    log::info!("Replacing section '{}' in: {}", title, path);
+
+    let file_path = Path::new(&path);
+    with_file_lock(file_path, || {
+        let content = read_file(path.clone())?;
+        let updated = replace_section(&content, &title, &body, create_if_missing.unwrap_or(false))?;
+        if updated == content {
+            return Ok(());
+        }
+        save_file_contents(file_path, &updated)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    const DOC: &str = "---\ntitle: Plan\n---\n# Plan\n\n## Notes\n\nFirst note\n\n### Call log\n- called\n\n```md\n## Not a heading\n```\n\n## Notes\nSecond notes\n\nReferences\n==========\nLink\n\n---\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00]\n";
+
+    #[test]
+    fn sections_follow_heading_levels_and_skip_code() {
+        let sections = list_sections(DOC);
+        let outline: Vec<(&str, u8, usize, usize)> = sections
+            .iter()
+            .map(|s| (s.title.as_str(), s.level, s.start_line, s.end_line))
+            .collect();
+        assert_eq!(
+            outline,
+            vec![
+                ("Plan", 1, 3, 19),
+                ("Notes", 2, 5, 16),
+                ("Call log", 3, 9, 16),
+                ("Notes", 2, 16, 19),
+                ("References", 1, 19, 23),
+                ("Created", 2, 24, 26),
+            ]
+        );
+
+        assert_eq!(
+            get_section(DOC, "notes").as_deref(),
+            Some("First note\n\n### Call log\n- called\n\n```md\n## Not a heading\n```")
+        );
+        assert_eq!(get_section(DOC, "References").as_deref(), Some("Link"));
+        assert_eq!(get_section(DOC, "Missing"), None);
+    }
+
+    #[test]
+    fn replacing_a_section_leaves_the_rest_untouched() -> Result<(), String> {
+        let updated = replace_section(DOC, "References", "- new link\n", false)?;
+        assert!(updated.contains("References\n==========\n- new link\n\n---\n## Created\n"));
+        assert_eq!(
+            updated.replace("- new link", "Link"),
+            DOC,
+            "only the body should change"
+        );
+
+        let updated = replace_section(DOC, "Notes", "Rewritten", false)?;
+        assert!(updated.contains("## Notes\n\nRewritten\n\n## Notes\nSecond notes"));
+        assert!(!updated.contains("Call log"));
+
+        let unchanged = replace_section(
+            DOC,
+            "Notes",
+            &get_section(DOC, "Notes").unwrap_or_default(),
+            false,
+        )?;
+        assert_eq!(unchanged, DOC);
+
+        assert_eq!(
+            replace_section(DOC, "Missing", "x", false),
+            Err("Section 'Missing' not found".to_string())
+        );
+        let created = replace_section(DOC, "Next Steps", "- call back", true)?;
+        assert!(created.contains("Link\n\n## Next Steps\n\n- call back\n\n---\n## Created"));
+
+        let plain = replace_section("# Plan\n\nBody\n", "Next Steps", "", true)?;
+        assert_eq!(plain, "# Plan\n\nBody\n\n## Next Steps\n");
+        Ok(())
+    }
+
+    #[test]
+    fn rules_inside_a_body_stay_in_the_section() -> Result<(), String> {
+        let doc = "# Trip\n\n## Notes\n\nPacking\n\n***\n\nBooking\n\n___\n\nVisas\n\n---\n## Created\n2026\n";
+        assert_eq!(
+            get_section(doc, "Notes").as_deref(),
+            Some("Packing\n\n***\n\nBooking\n\n___\n\nVisas")
+        );
+
+        let updated = replace_section(doc, "Notes", "Done", false)?;
+        assert_eq!(
+            updated,
+            "# Trip\n\n## Notes\n\nDone\n\n---\n## Created\n2026\n"
+        );
+
+        let without_footer = "## Notes\nOne\n\n---\n\nTwo\n";
+        assert_eq!(
+            get_section(without_footer, "Notes").as_deref(),
+            Some("One\n\n---\n\nTwo")
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn blocks_are_inserted_ahead_of_the_created_footer() {
+        let inserted = insert_before_created_footer(DOC, "## Time Log\n\n| a |\n");
+        assert!(inserted.ends_with(
+            "Link\n\n## Time Log\n\n| a |\n\n---\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00]\n"
+        ));
+        assert_eq!(
+            insert_before_created_footer("# Task\n## Created\n2026", "## Notes\n- a"),
+            "# Task\n\n## Notes\n- a\n\n## Created\n2026\n"
+        );
+        assert_eq!(
+            insert_before_created_footer("# Task\n", "## Notes\n- a"),
+            "# Task\n\n## Notes\n- a\n"
+        );
+    }
+
+    #[test]
+    fn file_sections_are_read_and_written() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let readme = workspace.path().join("Projects/Alpha Project/README.md");
+        let path = readme.to_string_lossy().to_string();
+
+        assert_eq!(
+            get_file_section(path.clone(), "Desired Outcome".to_string())?.as_deref(),
+            Some("Ship the Alpha project cleanly.")
+        );
+        replace_file_section(
+            path.clone(),
+            "Desired Outcome".to_string(),
+            "Ship it twice.".to_string(),
+            None,
+        )?;
+        assert_eq!(
+            get_file_section(path.clone(), "Desired Outcome".to_string())?.as_deref(),
+            Some("Ship it twice.")
+        );
+
+        let note = workspace.path().join("Notes.md");
+        write_test_file(&note, "# Notes\n")?;
+        let note = note.to_string_lossy().to_string();
+        assert!(replace_file_section(note.clone(), "Ideas".into(), "x".into(), None).is_err());
+        replace_file_section(note.clone(), "Ideas".into(), "x".into(), Some(true))?;
+        assert_eq!(read_file(note)?, "# Notes\n\n## Ideas\n\nx\n");
+        Ok(())
+    }
+}
@@ -5,6 +5,8 @@
 
 use chrono::{Datelike, Local, Timelike, Weekday};
 
+use super::gtd_habits_domain::DEFAULT_HISTORY_TEMPLATE;
+
 struct HorizonOverviewParams<'a> {
     title: &'a str,
     altitude_token: &'a str,
@@ -399,6 +401,20 @@ Every week, process everything:
 Start exploring your seeded examples to see GTD in action!
 "#;
 
+/// Template for Someday Maybe - Write a Book
+pub const SOMEDAY_WRITE_BOOK_TEMPLATE: &str = r#"# Write a Book
+
+**Topic**: Practical guide to building sustainable business systems
+
+## When I'm ready:
+- [ ] Outline key chapters
+- [ ] Research publishers vs self-publishing
+- [ ] Build audience platform first
+- [ ] Dedicate 2 hours daily to writing
+
+*Will support my Financial Freedom goal when activated*
+"#;
+
 /// Template for Someday Maybe - Learn a New Language
 pub const SOMEDAY_LEARN_LANGUAGE_TEMPLATE: &str = r#"# Learn Spanish
 
@@ -466,6 +482,144 @@ pub const CABINET_GTD_PRINCIPLES_TEMPLATE: &str = r#"# GTD Principles Reference
 5. What's the next action? (Next step)
 "#;
 
+/// Builds habit files with the full set of habit field markers
+///
+/// References are written as given; callers validate and encode them first.
+pub struct HabitTemplateBuilder<'a> {
+    name: &'a str,
+    frequency: &'a str,
+    focus_time: Option<&'a str>,
+    projects_reference: &'a str,
+    areas_reference: &'a str,
+    goals_reference: &'a str,
+    vision_reference: &'a str,
+    purpose_reference: &'a str,
+    notes: Option<&'a str>,
+    created_at: Option<&'a str>,
+}
+
+impl<'a> HabitTemplateBuilder<'a> {
+    /// Start a daily habit with no focus time, references, or notes
+    pub fn new(name: &'a str) -> Self {
+        Self {
+            name,
+            frequency: "daily",
+            focus_time: None,
+            projects_reference: "",
+            areas_reference: "",
+            goals_reference: "",
+            vision_reference: "",
+            purpose_reference: "",
+            notes: None,
+            created_at: None,
+        }
+    }
+
+    /// Frequency marker token, e.g. `weekly` or `every-other-day`
+    pub fn frequency(mut self, frequency: &'a str) -> Self {
+        self.frequency = frequency;
+        self
+    }
+
+    /// Focus date value, written as a `focus_date` datetime marker
+    pub fn focus_time(mut self, focus_time: &'a str) -> Self {
+        self.focus_time = Some(focus_time);
+        self
+    }
+
+    pub fn with_projects_reference(mut self, reference: &'a str) -> Self {
+        self.projects_reference = reference;
+        self
+    }
+
+    pub fn with_areas_reference(mut self, reference: &'a str) -> Self {
+        self.areas_reference = reference;
+        self
+    }
+
+    pub fn with_goals_reference(mut self, reference: &'a str) -> Self {
+        self.goals_reference = reference;
+        self
+    }
+
+    pub fn with_vision_reference(mut self, reference: &'a str) -> Self {
+        self.vision_reference = reference;
+        self
+    }
+
+    pub fn with_purpose_reference(mut self, reference: &'a str) -> Self {
+        self.purpose_reference = reference;
+        self
+    }
+
+    /// Body of a `## Notes` section placed before `## Created`
+    pub fn notes(mut self, notes: &'a str) -> Self {
+        self.notes = Some(notes);
+        self
+    }
+
+    /// Creation timestamp; defaults to now
+    pub fn created_at(mut self, created_at: &'a str) -> Self {
+        self.created_at = Some(created_at);
+        self
+    }
+
+    pub fn build(&self) -> String {
+        let focus_section = self.focus_time.map_or_else(
+            || "\n".to_string(),
+            |focus_time| format!("\n## Focus Date\n[!datetime:focus_date:{}]\n\n", focus_time),
+        );
+        let notes_section = self.notes.map_or_else(String::new, |notes| {
+            format!("## Notes\n{}\n\n", notes.trim_end())
+        });
+        let created_at = self
+            .created_at
+            .map_or_else(|| Local::now().to_rfc3339(), str::to_string);
+
+        format!(
+            r#"# {}
+
+## Status
+[!checkbox:habit-status:false]
+
+## Frequency
+[!singleselect:habit-frequency:{}]
+{}## Projects References
+[!projects-references:{}]
+
+## Areas References
+[!areas-references:{}]
+
+## Goals References
+[!goals-references:{}]
+
+## Vision References
+[!vision-references:{}]
+
+## Purpose & Principles References
+[!purpose-references:{}]
+
+{}## Created
+[!datetime:created_date_time:{}]
+
+## History
+{}
+"#,
+            self.name,
+            self.frequency,
+            focus_section,
+            self.projects_reference,
+            self.areas_reference,
+            self.goals_reference,
+            self.vision_reference,
+            self.purpose_reference,
+            notes_section,
+            created_at,
+            DEFAULT_HISTORY_TEMPLATE
+        )
+    }
+}
+
 /// Generate a Weekly Review habit template with next Sunday
 pub fn generate_weekly_review_habit() -> String {
     let now = Local::now();
@@ -487,33 +641,27 @@ pub fn generate_weekly_review_habit() -> String {
         next_sunday += chrono::Duration::days(7);
     }
 
-    format!(
-        r#"# Weekly Review
-## Frequency
-[!singleselect:habit-frequency:weekly]
-## Status
-[!checkbox:habit-status:false]
-## Focus Date
-[!datetime:focus_date:{}]
-## Notes
-Complete weekly GTD review:
+    HabitTemplateBuilder::new("Weekly Review")
+        .frequency("weekly")
+        .focus_time(&next_sunday.to_rfc3339())
+        .notes(
+            "Complete weekly GTD review:
 - Process all inboxes to zero
 - Review project lists
 - Update action lists
 - Review Someday/Maybe items
-- Clean up and organize
----
-Created: {}"#,
-        next_sunday.to_rfc3339(),
-        now.to_rfc3339()
-    )
+- Clean up and organize",
+        )
+        .created_at(&now.to_rfc3339())
+        .build()
 }
 
 /// Parameters for generating a project README with references
 pub struct ProjectReadmeParams<'a> {
     pub name: &'a str,
     pub description: &'a str,
-    pub due_date: Option<String>,
+    pub due_date: Option<&'a str>,
+    pub focus_date: Option<&'a str>,
     pub status: &'a str,
     pub areas_refs: &'a str,
     pub goals_refs: &'a str,
@@ -526,13 +674,14 @@ pub struct ProjectReadmeParams<'a> {
 pub fn generate_project_readme(
     name: &str,
     description: &str,
-    due_date: Option<String>,
+    due_date: Option<&str>,
     status: &str,
 ) -> String {
     let params = ProjectReadmeParams {
         name,
         description,
         due_date,
+        focus_date: None,
         status,
         areas_refs: "",
         goals_refs: "",
@@ -545,8 +694,9 @@ pub fn generate_project_readme(
 
 /// Template for project README.md file with references
 pub fn generate_project_readme_with_refs(params: ProjectReadmeParams) -> String {
-    // Always include the due date section, even if empty, so users can fill it in later
+    // Always include the date sections, even if empty, so users can fill them in later
     let due_date_value = params.due_date.unwrap_or_default();
+    let focus_date_value = params.focus_date.unwrap_or_default();
 
     format!(
         r#"# {}
@@ -557,6 +707,9 @@ pub fn generate_project_readme_with_refs(params: ProjectReadmeParams) -> String
 ## Due Date (optional)
 [!datetime:due_date:{}]
 
+## Focus Date (optional)
+[!datetime:focus_date:{}]
+
 ## Desired Outcome
 {}
 
@@ -581,6 +734,7 @@ pub fn generate_project_readme_with_refs(params: ProjectReadmeParams) -> String
         params.name,
         params.status,
         due_date_value,
+        focus_date_value,
         params.description,
         params.areas_refs,
         params.goals_refs,
@@ -592,6 +746,7 @@ pub fn generate_project_readme_with_refs(params: ProjectReadmeParams) -> String
 }
 
 /// Template for action file
+#[allow(clippy::too_many_arguments)]
 pub fn generate_action_template(
     name: &str,
     status: &str,
@@ -600,6 +755,7 @@ pub fn generate_action_template(
     effort: &str,
     contexts: Option<Vec<String>>,
     notes: Option<String>,
+    calendar_event_id: Option<&str>,
 ) -> String {
     let mut template = format!(
         r#"# {}
@@ -669,6 +825,17 @@ pub fn generate_action_template(
 "#,
     );
 
+    // Link back to the calendar event this action was created from, if any
+    if let Some(event_id) = calendar_event_id.filter(|id| !id.trim().is_empty()) {
+        template.push_str(&format!(
+            r#"
+## Calendar Event
+[!calendar-event-id:{}]
+"#,
+            event_id.trim()
+        ));
+    }
+
     // Notes section - use provided notes or a helpful placeholder
     let notes_content = notes.unwrap_or_else(|| {
         "<!-- Add any additional notes or details about this action here -->".to_string()
@@ -689,6 +856,59 @@ pub fn generate_action_template(
     template
 }
 
+/// Generate a project milestone file
+pub fn generate_milestone_template(name: &str, target_date: &str) -> String {
+    format!(
+        r#"# {}
+
+## Target Date
+[!datetime:target_date:{}]
+
+## Status
+[!singleselect:milestone-status:pending]
+
+## Success Criteria
+<!-- What has to be true for this milestone to count as reached? -->
+
+## Actions Completed
+<!-- List the actions that moved this milestone forward -->
+
+---
+## Created
+[!datetime:created_date_time:{}]
+"#,
+        name,
+        target_date,
+        Local::now().to_rfc3339()
+    )
+}
+
+/// Generate an area of focus standard document
+pub fn generate_area_standard_template(name: &str) -> String {
+    format!(
+        r#"# {} Standard
+
+## What Excellence Looks Like
+<!-- Describe the standard you want this area to meet -->
+
+## Current Reality
+<!-- Where things stand today -->
+
+## Gap Analysis
+<!-- What separates the current reality from the standard? -->
+
+## Improvement Actions
+[!projects-references:]
+
+---
+## Created
+[!datetime:created_date_time:{}]
+"#,
+        name,
+        Local::now().to_rfc3339()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -743,7 +963,8 @@ mod tests {
         let template = generate_project_readme_with_refs(ProjectReadmeParams {
             name: "Alpha",
             description: "Alpha description",
-            due_date: Some("2026-04-01".to_string()),
+            due_date: Some("2026-04-01"),
+            focus_date: None,
             status: "in-progress",
             areas_refs: "Areas/Health.md",
             goals_refs: "Goals/Fitness.md",
@@ -761,6 +982,43 @@ mod tests {
         assert!(template.contains("[!habits-list]"));
     }
 
+    #[test]
+    fn project_readme_renders_empty_dates_and_all_sections_as_utf8() {
+        let template = generate_project_readme_with_refs(ProjectReadmeParams {
+            name: "Café Launch — 日本",
+            description: "Ouvrir le café ☕",
+            due_date: None,
+            focus_date: Some("2026-05-02T09:00:00Z"),
+            status: "waiting",
+            areas_refs: "",
+            goals_refs: "",
+            vision_refs: "",
+            purpose_refs: "",
+            general_refs: "",
+        });
+
+        let bytes = template.clone().into_bytes();
+        let decoded = String::from_utf8(bytes).expect("README should be valid UTF-8");
+        assert!(decoded.starts_with("# Café Launch — 日本\n"));
+        assert!(decoded.contains("Ouvrir le café ☕"));
+        assert!(decoded.contains("[!datetime:due_date:]"));
+        assert!(decoded.contains("[!datetime:focus_date:2026-05-02T09:00:00Z]"));
+
+        for section in [
+            "## Status",
+            "## Due Date (optional)",
+            "## Focus Date (optional)",
+            "## Desired Outcome",
+            "## Horizon References",
+            "## References (optional)",
+            "## Created",
+            "## Actions",
+            "## Related Habits (optional)",
+        ] {
+            assert!(decoded.contains(section), "missing section {}", section);
+        }
+    }
+
     #[test]
     fn action_template_formats_due_date_and_contexts() {
         let template = generate_action_template(
@@ -771,6 +1029,7 @@ mod tests {
             "medium",
             Some(vec!["deep-work".to_string(), "coding".to_string()]),
             Some("Finish coverage improvements".to_string()),
+            Some("evt_123"),
         );
 
         assert!(template.contains("# Write tests"));
@@ -778,6 +1037,7 @@ mod tests {
         assert!(template.contains("[!datetime:due_date:2026-03-15]"));
         assert!(template.contains("[!multiselect:contexts:deep-work,coding]"));
         assert!(template.contains("Finish coverage improvements"));
+        assert!(template.contains("[!calendar-event-id:evt_123]"));
     }
 
     #[test]
@@ -788,6 +1048,99 @@ mod tests {
         assert!(template.contains("[!singleselect:habit-frequency:weekly]"));
         assert!(template.contains("[!checkbox:habit-status:false]"));
         assert!(template.contains("[!datetime:focus_date:"));
-        assert!(template.contains("Created: "));
+        assert!(template.contains("[!datetime:created_date_time:"));
+        assert!(template.contains("## Notes\nComplete weekly GTD review:"));
+        assert_habit_markers(&template);
+    }
+
+    fn assert_habit_markers(template: &str) {
+        for marker in [
+            "[!checkbox:habit-status:false]",
+            "[!singleselect:habit-frequency:",
+            "[!projects-references:",
+            "[!areas-references:",
+            "[!goals-references:",
+            "[!vision-references:",
+            "[!purpose-references:",
+            "[!datetime:created_date_time:",
+            "## History",
+        ] {
+            assert!(template.contains(marker), "missing {}", marker);
+        }
+    }
+
+    #[test]
+    fn habit_builder_defaults_to_daily_without_focus_or_notes() {
+        let template = HabitTemplateBuilder::new("Stretch").build();
+
+        assert_habit_markers(&template);
+        assert!(template.starts_with("# Stretch\n"));
+        assert!(template.contains("[!singleselect:habit-frequency:daily]"));
+        assert!(!template.contains("## Focus Date"));
+        assert!(!template.contains("## Notes"));
+    }
+
+    #[test]
+    fn habit_builder_writes_frequency_and_focus_time() {
+        let template = HabitTemplateBuilder::new("Journal")
+            .frequency("weekdays")
+            .focus_time("2026-03-10T07:30:00")
+            .build();
+
+        assert_habit_markers(&template);
+        assert!(template.contains("[!singleselect:habit-frequency:weekdays]"));
+        assert!(template.contains(
+            "[!singleselect:habit-frequency:weekdays]\n\n## Focus Date\n[!datetime:focus_date:2026-03-10T07:30:00]\n\n## Projects References"
+        ));
+    }
+
+    #[test]
+    fn habit_builder_writes_each_reference_in_its_own_marker() {
+        let template = HabitTemplateBuilder::new("Read")
+            .with_projects_reference("Projects/Write Book")
+            .with_areas_reference("Areas of Focus/Learning.md")
+            .with_goals_reference("Goals/Read 24 Books.md")
+            .with_vision_reference("Vision/Writer.md")
+            .with_purpose_reference("Purpose & Principles/Curiosity.md")
+            .build();
+
+        assert_habit_markers(&template);
+        assert!(template.contains("[!projects-references:Projects/Write Book]"));
+        assert!(template.contains("[!areas-references:Areas of Focus/Learning.md]"));
+        assert!(template.contains("[!goals-references:Goals/Read 24 Books.md]"));
+        assert!(template.contains("[!vision-references:Vision/Writer.md]"));
+        assert!(template.contains("[!purpose-references:Purpose & Principles/Curiosity.md]"));
+    }
+
+    #[test]
+    fn habit_builder_places_notes_before_created() {
+        let template = HabitTemplateBuilder::new("Inbox zero")
+            .notes("- Empty every inbox\n")
+            .created_at("2026-03-01T09:00:00+00:00")
+            .build();
+
+        assert_habit_markers(&template);
+        assert!(template.contains(
+            "## Notes\n- Empty every inbox\n\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00+00:00]\n\n## History\n"
+        ));
+        assert!(template.ends_with(&format!("{}\n", DEFAULT_HISTORY_TEMPLATE)));
+    }
+
+    #[test]
+    fn habit_builder_output_parses_as_a_habit() {
+        let template = HabitTemplateBuilder::new("Meditate")
+            .frequency("twice-weekly")
+            .created_at("2026-03-01T09:00:00+00:00")
+            .build();
+
+        assert_habit_markers(&template);
+        let fields = crate::commands::fields::parse_fields(&template);
+        assert!(fields
+            .iter()
+            .any(|field| field.key == "habit-frequency" && field.value == "twice-weekly"));
+        assert!(fields
+            .iter()
+            .any(|field| field.key == "created_date_time"
+                && field.value == "2026-03-01T09:00:00+00:00"));
     }
 }
@@ -0,0 +1,596 @@
+//! Seed packs: starter content described by a JSON manifest.
+//!
+//! A pack is a `seed-pack.json` manifest plus template files stored next to
+//! it. The manifest lists directories to create and files to write, each file
+//! rendered from a template. Files can be given an `id`, and `references`
+//! gives ids to files the space already has, so templates can point at them
+//! with `{{path:<id>}}`. That resolves to the file's absolute path in the
+//! URL-encoded JSON array form reference markers such as `[!references:...]`
+//! store.
+//!
+//! ```json
+//! {
+//!   "name": "Team Starter",
+//!   "directories": ["Projects/Onboarding"],
+//!   "references": { "career": "Areas of Focus/Career.md" },
+//!   "files": [
+//!     { "id": "handbook", "path": "Cabinet/Handbook.md", "template": "handbook.md" },
+//!     { "path": "Projects/Onboarding/README.md", "template": "onboarding.md" }
+//!   ]
+//! }
+//! ```
+//!
+//! Templates may also use `{{today}}` (YYYY-MM-DD), `{{today+<days>}}`, and
+//! `{{now}}` (RFC 3339). Without a pack the space is seeded from the built-in
+//! [`default_seed_pack`].
+
+use chrono::{Duration, Local, Timelike};
+use once_cell::sync::Lazy;
+use regex::{Captures, Regex};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use super::seed_data::{
+    generate_action_template, generate_project_readme_with_refs, generate_weekly_review_habit,
+    ProjectReadmeParams, CABINET_GTD_PRINCIPLES_TEMPLATE, SOMEDAY_WRITE_BOOK_TEMPLATE,
+};
+
+pub(crate) static PLACEHOLDER_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"\{\{\s*([^{}\s]+)\s*\}\}").expect("Invalid seed pack placeholder regex pattern")
+});
+
+/// Seed pack manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedPackManifest {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Directories to create, relative to the space root
+    #[serde(default)]
+    pub directories: Vec<String>,
+    /// Ids for files the space already has, such as the horizon pages
+    /// created at initialization, relative to the space root
+    #[serde(default)]
+    pub references: BTreeMap<String, String>,
+    #[serde(default)]
+    pub files: Vec<SeedPackFile>,
+}
+
+/// One file written by a seed pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SeedPackFile {
+    /// Symbolic name other templates use in `{{path:<id>}}`
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Destination, relative to the space root
+    pub path: String,
+    /// Template file, relative to the manifest
+    pub template: String,
+}
+
+/// Result of checking a seed pack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedPackValidation {
+    pub valid: bool,
+    /// Pack name, when the manifest could be parsed
+    pub name: Option<String>,
+    pub directory_count: usize,
+    pub file_count: usize,
+    /// Every problem found; empty when `valid`
+    pub errors: Vec<String>,
+}
+
+/// A validated pack ready to be applied
+pub(crate) struct SeedPack {
+    pub manifest: SeedPackManifest,
+    /// Template contents by template name
+    pub templates: HashMap<String, String>,
+}
+
+/// Manifest path for `pack_path`, which may name the manifest or its folder.
+fn manifest_path(pack_path: &Path) -> PathBuf {
+    if pack_path.is_dir() {
+        pack_path.join("seed-pack.json")
+    } else {
+        pack_path.to_path_buf()
+    }
+}
+
+/// A non-empty relative path that stays inside its root.
+fn check_relative_path(value: &str, what: &str, errors: &mut Vec<String>) {
+    let path = Path::new(value);
+    let escapes = path
+        .components()
+        .any(|component| !matches!(component, Component::Normal(_)));
+    if value.trim().is_empty() || escapes {
+        errors.push(format!(
+            "{} '{}' must be a relative path without '..'",
+            what, value
+        ));
+    }
+}
+
+fn check_placeholders(
+    template: &str,
+    template_name: &str,
+    ids: &HashSet<&str>,
+    errors: &mut Vec<String>,
+) {
+    for captures in PLACEHOLDER_REGEX.captures_iter(template) {
+        let placeholder = &captures[1];
+        match placeholder.strip_prefix("path:") {
+            Some(id) if !ids.contains(id) => errors.push(format!(
+                "Template '{}' references unknown id '{}'",
+                template_name, id
+            )),
+            Some(_) => {}
+            None if placeholder == "now" || today_offset(placeholder).is_some() => {}
+            None => errors.push(format!(
+                "Template '{}' uses unknown placeholder '{{{{{}}}}}'",
+                template_name, placeholder
+            )),
+        }
+    }
+}
+
+/// Days after today named by `today` or `today+<days>`.
+fn today_offset(placeholder: &str) -> Option<i64> {
+    match placeholder.strip_prefix("today")? {
+        "" => Some(0),
+        rest => rest.strip_prefix('+')?.parse().ok(),
+    }
+}
+
+fn check_id(id: &str, ids: &mut HashSet<String>, errors: &mut Vec<String>) {
+    if id.trim().is_empty() || id.contains(char::is_whitespace) {
+        errors.push(format!(
+            "File id '{}' cannot be blank or contain spaces",
+            id
+        ));
+    } else if !ids.insert(id.to_string()) {
+        errors.push(format!("File id '{}' is used more than once", id));
+    }
+}
+
+fn validate_manifest(
+    manifest: &SeedPackManifest,
+    templates: &HashMap<String, String>,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    if manifest.name.trim().is_empty() {
+        errors.push("Pack name cannot be empty".to_string());
+    }
+
+    for directory in &manifest.directories {
+        check_relative_path(directory, "Directory", &mut errors);
+    }
+
+    let mut ids = HashSet::new();
+    for (id, path) in &manifest.references {
+        check_id(id, &mut ids, &mut errors);
+        check_relative_path(path, "Reference", &mut errors);
+    }
+
+    let mut destinations = HashSet::new();
+    for file in &manifest.files {
+        check_relative_path(&file.path, "File path", &mut errors);
+        check_relative_path(&file.template, "Template", &mut errors);
+        if !destinations.insert(file.path.as_str()) {
+            errors.push(format!("File path '{}' is listed twice", file.path));
+        }
+        if let Some(id) = &file.id {
+            check_id(id, &mut ids, &mut errors);
+        }
+    }
+
+    let ids: HashSet<&str> = ids.iter().map(String::as_str).collect();
+    for file in &manifest.files {
+        if let Some(template) = templates.get(&file.template) {
+            check_placeholders(template, &file.template, &ids, &mut errors);
+        }
+    }
+
+    errors
+}
+
+/// Read and check a seed pack, returning the parsed pack and any problems.
+fn inspect_seed_pack(pack_path: &Path) -> Result<(SeedPack, Vec<String>), String> {
+    let manifest_path = manifest_path(pack_path);
+    let content = fs::read_to_string(&manifest_path).map_err(|error| {
+        format!(
+            "Failed to read seed pack manifest '{}': {}",
+            manifest_path.display(),
+            error
+        )
+    })?;
+    let manifest: SeedPackManifest = serde_json::from_str(&content)
+        .map_err(|error| format!("Invalid seed pack manifest: {}", error))?;
+    let root = manifest_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+
+    let mut errors = Vec::new();
+    let mut templates = HashMap::new();
+    for file in &manifest.files {
+        if templates.contains_key(&file.template) {
+            continue;
+        }
+        match fs::read_to_string(root.join(&file.template)) {
+            Ok(template) => {
+                templates.insert(file.template.clone(), template);
+            }
+            Err(error) => errors.push(format!(
+                "Template '{}' could not be read: {}",
+                file.template, error
+            )),
+        }
+    }
+
+    errors.extend(validate_manifest(&manifest, &templates));
+    Ok((
+        SeedPack {
+            manifest,
+            templates,
+        },
+        errors,
+    ))
+}
+
+/// Load a seed pack, failing with every problem found if it is invalid.
+pub(crate) fn load_seed_pack(pack_path: &Path) -> Result<SeedPack, String> {
+    let (pack, errors) = inspect_seed_pack(pack_path)?;
+    if !errors.is_empty() {
+        return Err(format!("Invalid seed pack: {}", errors.join("; ")));
+    }
+    Ok(pack)
+}
+
+/// `path` as a reference marker value: a URL-encoded JSON array holding the
+/// path with forward slashes.
+fn encode_reference(path: &Path) -> String {
+    let path = path.to_string_lossy().replace('\\', "/");
+    serde_json::to_string(&[path])
+        .map(|json| urlencoding::encode(&json).into_owned())
+        .unwrap_or_default()
+}
+
+fn render_template(template: &str, paths: &HashMap<&str, String>) -> String {
+    let now = Local::now();
+    PLACEHOLDER_REGEX
+        .replace_all(template, |captures: &Captures| {
+            let placeholder = &captures[1];
+            if placeholder == "now" {
+                return now.to_rfc3339();
+            }
+            if let Some(days) = today_offset(placeholder) {
+                return (now + Duration::days(days)).format("%Y-%m-%d").to_string();
+            }
+            placeholder
+                .strip_prefix("path:")
+                .and_then(|id| paths.get(id))
+                .cloned()
+                .unwrap_or_else(|| captures[0].to_string())
+        })
+        .into_owned()
+}
+
+/// Create the pack's directories and files under `space_root`.
+///
+/// Existing files are left untouched. Returns the number of files written.
+pub(crate) fn apply_seed_pack(pack: &SeedPack, space_root: &Path) -> Result<usize, String> {
+    for directory in &pack.manifest.directories {
+        fs::create_dir_all(space_root.join(directory))
+            .map_err(|error| format!("Failed to create directory '{}': {}", directory, error))?;
+    }
+
+    let references = pack
+        .manifest
+        .references
+        .iter()
+        .map(|(id, path)| (id.as_str(), path));
+    let files = pack
+        .manifest
+        .files
+        .iter()
+        .filter_map(|file| Some((file.id.as_deref()?, &file.path)));
+    let paths: HashMap<&str, String> = references
+        .chain(files)
+        .map(|(id, path)| (id, encode_reference(&space_root.join(path))))
+        .collect();
+
+    let mut written = 0;
+    for file in &pack.manifest.files {
+        let destination = space_root.join(&file.path);
+        if destination.exists() {
+            continue;
+        }
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).map_err(|error| {
+                format!("Failed to create directory for '{}': {}", file.path, error)
+            })?;
+        }
+
+        let template = pack
+            .templates
+            .get(&file.template)
+            .ok_or_else(|| format!("Template '{}' is missing", file.template))?;
+        fs::write(&destination, render_template(template, &paths))
+            .map_err(|error| format!("Failed to create '{}': {}", file.path, error))?;
+        written += 1;
+    }
+
+    log::info!(
+        "Applied seed pack '{}' ({} files written)",
+        pack.manifest.name,
+        written
+    );
+    Ok(written)
+}
+
+fn default_pack_file(id: Option<&str>, path: &str, template: &str) -> SeedPackFile {
+    SeedPackFile {
+        id: id.map(str::to_string),
+        path: path.to_string(),
+        template: template.to_string(),
+    }
+}
+
+/// The built-in example content: one project with two actions that links to
+/// every horizon and the Cabinet's GTD principles page, a weekly review
+/// habit, and a Someday Maybe item. Dates are relative to today.
+pub(crate) fn default_seed_pack() -> SeedPack {
+    let project = "Projects/Launch Side Business";
+    let now = Local::now();
+    let base_next_week = now + Duration::days(7);
+    let next_week = base_next_week
+        .with_hour(17)
+        .and_then(|dt| dt.with_minute(0))
+        .and_then(|dt| dt.with_second(0))
+        .unwrap_or(base_next_week);
+
+    let manifest = SeedPackManifest {
+        name: "GTD Space Example".to_string(),
+        description: Some("Example projects, actions, habits, and references".to_string()),
+        directories: Vec::new(),
+        references: [
+            ("area", "Areas of Focus/Professional Excellence.md"),
+            ("goal", "Goals/Build Financial Freedom.md"),
+            ("vision", "Vision/My 3-5 Year Vision.md"),
+            ("purpose", "Purpose & Principles/Core Values.md"),
+        ]
+        .into_iter()
+        .map(|(id, path)| (id.to_string(), path.to_string()))
+        .collect(),
+        files: vec![
+            default_pack_file(
+                Some("principles"),
+                "Cabinet/GTD Principles Reference.md",
+                "principles.md",
+            ),
+            default_pack_file(None, &format!("{}/README.md", project), "project.md"),
+            default_pack_file(
+                None,
+                &format!("{}/Define service offerings.md", project),
+                "define-offerings.md",
+            ),
+            default_pack_file(
+                None,
+                &format!("{}/Create landing page.md", project),
+                "landing-page.md",
+            ),
+            default_pack_file(None, "Habits/Weekly GTD Review.md", "weekly-review.md"),
+            default_pack_file(None, "Someday Maybe/Write a Book.md", "write-a-book.md"),
+        ],
+    };
+
+    let project_readme = generate_project_readme_with_refs(ProjectReadmeParams {
+        name: "Launch Side Business",
+        description: "Create and launch consulting business for passive income generation",
+        due_date: Some(&next_week.to_rfc3339()),
+        focus_date: None,
+        status: "in-progress",
+        areas_refs: "{{path:area}}",
+        goals_refs: "{{path:goal}}",
+        vision_refs: "{{path:vision}}",
+        purpose_refs: "{{path:purpose}}",
+        general_refs: "{{path:principles}}",
+    });
+    let templates = [
+        ("principles.md", CABINET_GTD_PRINCIPLES_TEMPLATE.to_string()),
+        ("project.md", project_readme),
+        (
+            "define-offerings.md",
+            generate_action_template(
+                "Define service offerings",
+                "in-progress",
+                Some(now.to_rfc3339()),
+                None,
+                "medium",
+                None,
+                None,
+                None,
+            ),
+        ),
+        (
+            "landing-page.md",
+            generate_action_template(
+                "Create landing page",
+                "waiting",
+                None,
+                Some(next_week.format("%Y-%m-%d").to_string()),
+                "large",
+                None,
+                None,
+                None,
+            ),
+        ),
+        ("weekly-review.md", generate_weekly_review_habit()),
+        ("write-a-book.md", SOMEDAY_WRITE_BOOK_TEMPLATE.to_string()),
+    ]
+    .into_iter()
+    .map(|(name, template)| (name.to_string(), template))
+    .collect();
+
+    SeedPack {
+        manifest,
+        templates,
+    }
+}
+
+/// Check a seed pack before using it
+///
+/// Verifies the manifest schema, that paths stay inside the space, that ids
+/// are unique, that every template exists, and that every `{{path:<id>}}`
+/// placeholder names a file in the pack.
+///
+/// # Arguments
+///
+/// * `pack_path` - Path to `seed-pack.json` or the folder containing it
+///
+/// # Returns
+///
+/// Validation summary listing every problem, or error details when the
+/// manifest cannot be read or parsed at all
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke<SeedPackValidation>('validate_seed_pack', {
+///   packPath: '/path/to/packs/team-starter',
+/// });
+/// if (!result.valid) console.warn(result.errors);
+/// ```
+#[tauri::command]
+pub fn validate_seed_pack(pack_path: String) -> Result<SeedPackValidation, String> {
+    log::info!("Validating seed pack: {}", pack_path);
+
+    let (pack, errors) = inspect_seed_pack(Path::new(&pack_path))?;
+    Ok(SeedPackValidation {
+        valid: errors.is_empty(),
+        name: Some(pack.manifest.name),
+        directory_count: pack.manifest.directories.len(),
+        file_count: pack.manifest.files.len(),
+        errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn seed_pack_resolves_references_and_reports_problems() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let pack_dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+        write_test_file(
+            pack_dir.path().join("seed-pack.json"),
+            r#"{
+  "name": "Team Starter",
+  "directories": ["Projects/Onboarding"],
+  "references": { "vision": "Vision/My 3-5 Year Vision.md" },
+  "files": [
+    { "id": "handbook", "path": "Cabinet/Handbook.md", "template": "handbook.md" },
+    { "path": "Projects/Onboarding/README.md", "template": "onboarding.md" }
+  ]
+}"#,
+        )?;
+        write_test_file(pack_dir.path().join("handbook.md"), "# Handbook\n")?;
+        write_test_file(
+            pack_dir.path().join("onboarding.md"),
+            "# Onboarding\n\n[!references:{{path:handbook}}]\n[!vision-references:{{path:vision}}]\n\nCreated {{ today }}, due {{today+7}}\n",
+        )?;
+
+        let validation = validate_seed_pack(pack_dir.path().to_string_lossy().to_string())?;
+        assert!(validation.valid, "{:?}", validation.errors);
+        assert_eq!(validation.file_count, 2);
+
+        let pack = load_seed_pack(pack_dir.path())?;
+        assert_eq!(apply_seed_pack(&pack, workspace.path())?, 2);
+        let readme = fs::read_to_string(workspace.path().join("Projects/Onboarding/README.md"))
+            .map_err(|e| e.to_string())?;
+        let encoded = |relative: &str| {
+            let path = workspace.path().join(relative);
+            let json = format!("[\"{}\"]", path.to_string_lossy().replace('\\', "/"));
+            urlencoding::encode(&json).into_owned()
+        };
+        assert!(readme.contains(&format!("[!references:{}]", encoded("Cabinet/Handbook.md"))));
+        assert!(readme.contains(&format!(
+            "[!vision-references:{}]",
+            encoded("Vision/My 3-5 Year Vision.md")
+        )));
+        let today = Local::now();
+        assert!(readme.contains(&format!(
+            "Created {}, due {}",
+            today.format("%Y-%m-%d"),
+            (today + Duration::days(7)).format("%Y-%m-%d")
+        )));
+        assert_eq!(apply_seed_pack(&pack, workspace.path())?, 0);
+
+        write_test_file(
+            pack_dir.path().join("seed-pack.json"),
+            r#"{
+  "name": "Broken",
+  "directories": ["../outside"],
+  "files": [
+    { "id": "a", "path": "A.md", "template": "onboarding.md" },
+    { "id": "a", "path": "B.md", "template": "missing.md" }
+  ]
+}"#,
+        )?;
+        let validation = validate_seed_pack(pack_dir.path().to_string_lossy().to_string())?;
+        assert!(!validation.valid);
+        assert_eq!(validation.errors.len(), 5, "{:?}", validation.errors);
+        assert!(load_seed_pack(pack_dir.path()).is_err());
+
+        write_test_file(
+            pack_dir.path().join("seed-pack.json"),
+            r#"{ "title": "x" }"#,
+        )?;
+        assert!(validate_seed_pack(pack_dir.path().to_string_lossy().to_string()).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn default_pack_seeds_the_example_project() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let pack = default_seed_pack();
+        assert!(
+            validate_manifest(&pack.manifest, &pack.templates).is_empty(),
+            "{:?}",
+            validate_manifest(&pack.manifest, &pack.templates)
+        );
+
+        assert!(apply_seed_pack(&pack, workspace.path())? >= 5);
+        let project = workspace.path().join("Projects/Launch Side Business");
+        let readme = fs::read_to_string(project.join("README.md")).map_err(|e| e.to_string())?;
+        let goal = workspace
+            .path()
+            .join("Goals/Build Financial Freedom.md")
+            .to_string_lossy()
+            .replace('\\', "/");
+        let encoded = urlencoding::encode(&format!("[\"{}\"]", goal)).into_owned();
+        assert!(readme.contains(&format!("[!goals-references:{}]", encoded)));
+        assert!(!readme.contains("{{"));
+        assert!(project.join("Define service offerings.md").is_file());
+        assert!(project.join("Create landing page.md").is_file());
+        assert!(workspace
+            .path()
+            .join("Habits/Weekly GTD Review.md")
+            .is_file());
+        assert!(workspace
+            .path()
+            .join("Someday Maybe/Write a Book.md")
+            .is_file());
+
+        Ok(())
+    }
+}
@@ -4,10 +4,12 @@ use once_cell::sync::Lazy;
 use serde::{Deserialize, Deserializer, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
-use tauri::AppHandle;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
 use tauri_plugin_store::StoreBuilder;
 use tokio::sync::Mutex as TokioMutex;
 
+use crate::commands::app::track_command_result;
 use crate::mcp_settings::{
     coerce_mcp_server_read_only, normalize_mcp_server_workspace_path,
     sanitize_mcp_server_log_level, settings_store_path, DEFAULT_MCP_SERVER_LOG_LEVEL,
@@ -274,6 +276,15 @@ pub struct UserSettings {
     pub seed_example_content: Option<bool>,
     /// Preferred default GTD space path override
     pub default_space_path: Option<String>,
+    /// Spaces created or registered by the app, such as duplicated sandboxes
+    #[serde(default)]
+    pub known_spaces: Vec<String>,
+    /// Google Calendar color id -> GTD category (e.g. `meeting`, `deep-work`)
+    #[serde(default)]
+    pub calendar_color_mapping: HashMap<String, String>,
+    /// Unix time (seconds) the app was last active, for spotting external edits
+    #[serde(default)]
+    pub last_active_timestamp: Option<u64>,
     /// Enable git-based syncing and backups
     pub git_sync_enabled: Option<bool>,
     /// Path to the dedicated git repository for encrypted backups
@@ -308,6 +319,9 @@ pub struct UserSettings {
     /// Default log level used by the standalone MCP server
     #[serde(default, deserialize_with = "deserialize_mcp_server_log_level")]
     pub mcp_server_log_level: Option<String>,
+    /// Spell check language such as `en-US`; unset means `en-US`
+    #[serde(default)]
+    pub spellcheck_language: Option<String>,
 }
 
 impl std::fmt::Debug for UserSettings {
@@ -329,6 +343,9 @@ impl std::fmt::Debug for UserSettings {
             .field("auto_initialize", &self.auto_initialize)
             .field("seed_example_content", &self.seed_example_content)
             .field("default_space_path", &self.default_space_path)
+            .field("known_spaces", &self.known_spaces)
+            .field("calendar_color_mapping", &self.calendar_color_mapping)
+            .field("last_active_timestamp", &self.last_active_timestamp)
             .field("git_sync_enabled", &self.git_sync_enabled)
             .field("git_sync_repo_path", &self.git_sync_repo_path)
             .field("git_sync_workspace_path", &self.git_sync_workspace_path)
@@ -337,6 +354,7 @@ impl std::fmt::Debug for UserSettings {
             .field("mcp_server_workspace_path", &self.mcp_server_workspace_path)
             .field("mcp_server_read_only", &self.mcp_server_read_only)
             .field("mcp_server_log_level", &self.mcp_server_log_level)
+            .field("spellcheck_language", &self.spellcheck_language)
             .field(
                 "git_sync_encryption_key",
                 &self
@@ -509,7 +527,7 @@ fn load_settings_unlocked(app: &AppHandle) -> Result<UserSettings, String> {
 #[tauri::command]
 pub async fn load_settings(app: AppHandle) -> Result<UserSettings, String> {
     let _guard = SETTINGS_LOCK.lock().await;
-    load_settings_unlocked(&app)
+    track_command_result("load_settings", load_settings_unlocked(&app))
 }
 
 /// Save user settings to persistent storage
@@ -610,7 +628,99 @@ where
 pub async fn save_settings(app: AppHandle, settings: UserSettings) -> Result<String, String> {
     let _guard = SETTINGS_LOCK.lock().await;
     let settings = normalize_mcp_server_settings(settings);
-    save_settings_unlocked(&app, &settings)
+    track_command_result("save_settings", save_settings_unlocked(&app, &settings))
+}
+
+/// Record the current time as the app's last active moment
+///
+/// The frontend calls this when the window loses focus and later passes the
+/// saved value to `list_files_modified_since` to find external edits.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for accessing the store
+///
+/// # Returns
+///
+/// Nothing on success, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('update_last_active_timestamp');
+/// ```
+#[tauri::command]
+pub async fn update_last_active_timestamp(app: AppHandle) -> Result<(), String> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| format!("System clock is before the Unix epoch: {}", e))?
+        .as_secs();
+    update_settings(app, |settings| settings.last_active_timestamp = Some(now)).await?;
+    Ok(())
+}
+
+/// Result of `reset_settings`
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResetSettingsResult {
+    /// Settings now in effect
+    pub settings: UserSettings,
+    /// Copy of the previous settings file, if one existed
+    pub backup_path: Option<String>,
+}
+
+/// Reset user settings to defaults after backing up the current file
+///
+/// The existing settings store is copied next to itself as
+/// `settings.backup-<timestamp>.json` before defaults are written, so a
+/// mistaken reset can be undone by hand. Secrets in the OS keychain are kept.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle for accessing the store
+///
+/// # Returns
+///
+/// The default settings and the backup location, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { backup_path } = await invoke('reset_settings');
+/// ```
+#[tauri::command]
+pub async fn reset_settings(app: AppHandle) -> Result<ResetSettingsResult, String> {
+    let _guard = SETTINGS_LOCK.lock().await;
+    log::info!("Resetting user settings to defaults");
+
+    let settings_file = app
+        .path()
+        .resolve(settings_store_path(), BaseDirectory::AppData)
+        .map_err(|e| format!("Failed to resolve settings file: {}", e))?;
+
+    let backup_path = if settings_file.is_file() {
+        let backup = settings_file.with_file_name(format!(
+            "settings.backup-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+        std::fs::copy(&settings_file, &backup)
+            .map_err(|e| format!("Failed to back up settings before reset: {}", e))?;
+        log::info!("Backed up settings to {}", backup.display());
+        Some(backup.to_string_lossy().to_string())
+    } else {
+        None
+    };
+
+    let defaults = get_default_settings();
+    track_command_result("reset_settings", save_settings_unlocked(&app, &defaults))?;
+
+    Ok(ResetSettingsResult {
+        settings: defaults,
+        backup_path,
+    })
 }
 
 /// Store a secret value in the OS keychain/credential manager
@@ -799,6 +909,9 @@ pub fn get_default_settings() -> UserSettings {
         auto_initialize: Some(true),
         seed_example_content: Some(true),
         default_space_path: None,
+        known_spaces: Vec::new(),
+        calendar_color_mapping: HashMap::new(),
+        last_active_timestamp: None,
         git_sync_enabled: Some(false),
         git_sync_repo_path: None,
         git_sync_workspace_path: None,
@@ -814,6 +927,7 @@ pub fn get_default_settings() -> UserSettings {
         mcp_server_workspace_path: None,
         mcp_server_read_only: Some(false),
         mcp_server_log_level: Some(DEFAULT_MCP_SERVER_LOG_LEVEL.to_string()),
+        spellcheck_language: None,
     }
 }
 
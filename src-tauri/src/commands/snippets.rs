@@ -0,0 +1,459 @@
+//! Reusable text snippets stored per space in `.gtdspace/snippets/`.
+//!
+//! Each snippet is a markdown file whose stem is the snippet name. Rendering
+//! uses the same `{{...}}` placeholders as seed pack templates: `{{today}}`,
+//! `{{now}}`, date math such as `{{today+3d}}` or `{{today+2w}}`, and any
+//! caller-supplied variable like `{{client}}`.
+
+use chrono::Local;
+use regex::Captures;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::file_locks::with_file_lock;
+use super::filesystem::{read_file, save_file_contents};
+use super::project_templates::resolve_due_offset;
+use super::seed_packs::PLACEHOLDER_REGEX;
+use super::space_config::{ensure_space_writable, SPACE_CONFIG_DIR};
+use super::utils::sanitize_markdown_file_stem;
+
+/// Folder inside [`SPACE_CONFIG_DIR`] holding snippets
+const SNIPPETS_DIR: &str = "snippets";
+
+/// A stored snippet
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Snippet {
+    pub name: String,
+    /// Raw snippet text, placeholders unexpanded
+    pub content: String,
+}
+
+/// Reason a snippet could not be rendered
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SnippetRenderErrorKind {
+    /// The snippet uses variables the caller did not supply
+    MissingVariables,
+}
+
+/// Structured error returned (JSON-encoded) when variables are missing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnippetRenderError {
+    pub kind: SnippetRenderErrorKind,
+    /// Variable names to prompt for, sorted
+    pub missing: Vec<String>,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+fn snippets_dir(space_root: &Path) -> PathBuf {
+    space_root.join(SPACE_CONFIG_DIR).join(SNIPPETS_DIR)
+}
+
+fn snippet_path(space_root: &Path, name: &str) -> Result<PathBuf, String> {
+    let stem = sanitize_markdown_file_stem(name);
+    if stem.is_empty() {
+        return Err("Snippet name cannot be empty".to_string());
+    }
+    if stem != name.trim() {
+        return Err(format!(
+            "Invalid snippet name '{}'; try '{}' instead",
+            name, stem
+        ));
+    }
+    Ok(snippets_dir(space_root).join(format!("{}.md", stem)))
+}
+
+fn load_snippet(space_root: &Path, name: &str) -> Result<Snippet, String> {
+    let path = snippet_path(space_root, name)?;
+    let content = fs::read_to_string(&path).map_err(|e| {
+        if e.kind() == std::io::ErrorKind::NotFound {
+            format!("Snippet '{}' not found", name)
+        } else {
+            format!("Failed to read snippet '{}': {}", name, e)
+        }
+    })?;
+    Ok(Snippet {
+        name: name.trim().to_string(),
+        content,
+    })
+}
+
+/// Expand built-in placeholders and `vars` in `content`.
+pub(crate) fn render_snippet_content(
+    content: &str,
+    vars: &HashMap<String, String>,
+) -> Result<String, String> {
+    let today = Local::now().date_naive();
+    let now = Local::now().to_rfc3339();
+    let mut missing = BTreeSet::new();
+
+    let rendered = PLACEHOLDER_REGEX.replace_all(content, |captures: &Captures| {
+        let placeholder = &captures[1];
+        if let Some(value) = vars.get(placeholder) {
+            return value.clone();
+        }
+        match placeholder {
+            "today" => today.format("%Y-%m-%d").to_string(),
+            "now" => now.clone(),
+            _ => match placeholder
+                .strip_prefix("today")
+                .filter(|offset| offset.starts_with('+'))
+                .and_then(|offset| resolve_due_offset(offset, today).ok())
+            {
+                Some(date) => date.format("%Y-%m-%d").to_string(),
+                None => {
+                    missing.insert(placeholder.to_string());
+                    captures[0].to_string()
+                }
+            },
+        }
+    });
+
+    if missing.is_empty() {
+        return Ok(rendered.into_owned());
+    }
+    let missing: Vec<String> = missing.into_iter().collect();
+    let message = format!("Missing snippet variables: {}", missing.join(", "));
+    Err(serde_json::to_string(&SnippetRenderError {
+        kind: SnippetRenderErrorKind::MissingVariables,
+        missing,
+        message: message.clone(),
+    })
+    .unwrap_or(message))
+}
+
+/// Insert `text` before line `position` (0-based); past the end appends.
+fn insert_at_line(content: &str, position: usize, text: &str) -> String {
+    let mut lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let mut block = text.to_string();
+    if !block.ends_with('\n') {
+        block.push('\n');
+    }
+    if position >= lines.len() {
+        let mut result = content.to_string();
+        if !result.is_empty() && !result.ends_with('\n') {
+            result.push('\n');
+        }
+        result.push_str(&block);
+        return result;
+    }
+    lines.insert(position, &block);
+    lines.concat()
+}
+
+/// Closest ancestor of `path` with a snippets folder.
+fn find_snippets_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .skip(1)
+        .find(|ancestor| snippets_dir(ancestor).is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// List the snippets saved in a space
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Snippets sorted by name; empty when none exist
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const snippets = await invoke<Snippet[]>('list_snippets', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_snippets(space_path: String) -> Result<Vec<Snippet>, String> {
+    log::info!("Listing snippets in: {}", space_path);
+
+    let Ok(entries) = fs::read_dir(snippets_dir(Path::new(&space_path))) else {
+        return Ok(Vec::new());
+    };
+    let mut snippets: Vec<Snippet> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+        })
+        .filter_map(|path| {
+            Some(Snippet {
+                name: path.file_stem()?.to_string_lossy().to_string(),
+                content: fs::read_to_string(&path).ok()?,
+            })
+        })
+        .collect();
+    snippets.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(snippets)
+}
+
+/// Read one snippet
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `name` - Snippet name
+///
+/// # Returns
+///
+/// The snippet with its raw content, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const snippet = await invoke<Snippet>('get_snippet', {
+///   spacePath: '/path/to/space',
+///   name: 'meeting-notes',
+/// });
+/// ```
+#[tauri::command]
+pub fn get_snippet(space_path: String, name: String) -> Result<Snippet, String> {
+    load_snippet(Path::new(&space_path), &name)
+}
+
+/// Create or replace a snippet
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `name` - Snippet name; must be usable as a file name
+/// * `content` - Snippet text with `{{...}}` placeholders
+///
+/// # Returns
+///
+/// Path to the snippet file or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('save_snippet', {
+///   spacePath: '/path/to/space',
+///   name: 'meeting-notes',
+///   content: '## Meeting {{today}}\n\nWith: {{attendees}}\n',
+/// });
+/// ```
+#[tauri::command]
+pub fn save_snippet(space_path: String, name: String, content: String) -> Result<String, String> {
+    log::info!("Saving snippet '{}' in: {}", name, space_path);
+
+    let space_root = Path::new(&space_path);
+    ensure_space_writable(space_root)?;
+    let path = snippet_path(space_root, &name)?;
+    save_file_contents(&path, &content)?;
+    Ok(path.to_string_lossy().to_string())
+}
+
+/// Delete a snippet
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `name` - Snippet name
+///
+/// # Returns
+///
+/// Success message or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('delete_snippet', { spacePath: '/path/to/space', name: 'meeting-notes' });
+/// ```
+#[tauri::command]
+pub fn delete_snippet(space_path: String, name: String) -> Result<String, String> {
+    log::info!("Deleting snippet '{}' in: {}", name, space_path);
+
+    let space_root = Path::new(&space_path);
+    ensure_space_writable(space_root)?;
+    let path = snippet_path(space_root, &name)?;
+    fs::remove_file(&path).map_err(|e| format!("Failed to delete snippet '{}': {}", name, e))?;
+    Ok(format!("Deleted snippet '{}'", name.trim()))
+}
+
+/// Render a snippet with variables
+///
+/// Unknown placeholders fail with a JSON-encoded `SnippetRenderError`
+/// listing the missing variable names.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+/// * `name` - Snippet name
+/// * `vars` - Values for custom placeholders
+///
+/// # Returns
+///
+/// Rendered text or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const text = await invoke<string>('render_snippet', {
+///   spacePath: '/path/to/space',
+///   name: 'meeting-notes',
+///   vars: { attendees: 'Sam, Kim' },
+/// });
+/// ```
+#[tauri::command]
+pub fn render_snippet(
+    space_path: String,
+    name: String,
+    vars: HashMap<String, String>,
+) -> Result<String, String> {
+    let snippet = load_snippet(Path::new(&space_path), &name)?;
+    render_snippet_content(&snippet.content, &vars)
+}
+
+/// Render a snippet and insert it into a file
+///
+/// The snippet is looked up in the closest enclosing space with a snippets
+/// folder. The file is rewritten atomically under its file lock.
+///
+/// # Arguments
+///
+/// * `path` - File to insert into
+/// * `name` - Snippet name
+/// * `position` - 0-based line to insert before; past the end appends
+/// * `vars` - Optional values for custom placeholders
+///
+/// # Returns
+///
+/// The inserted text, or error details (see `render_snippet`)
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke<string>('insert_snippet_into_file', {
+///   path: '/path/to/space/Cabinet/Meetings.md',
+///   name: 'meeting-notes',
+///   position: 3,
+///   vars: { attendees: 'Sam' },
+/// });
+/// ```
+#[tauri::command]
+pub fn insert_snippet_into_file(
+    path: String,
+    name: String,
+    position: usize,
+    vars: Option<HashMap<String, String>>,
+) -> Result<String, String> {
+    log::info!("Inserting snippet '{}' into: {}", name, path);
+
+    let file_path = Path::new(&path);
+    let space_root = find_snippets_root(file_path)
+        .ok_or_else(|| "No snippets folder found for this file's space".to_string())?;
+    let snippet = load_snippet(&space_root, &name)?;
+    let rendered = render_snippet_content(&snippet.content, &vars.unwrap_or_default())?;
+
+    with_file_lock(file_path, || {
+        let content = read_file(path.clone())?;
+        save_file_contents(file_path, &insert_at_line(&content, position, &rendered))
+    })?;
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+    use chrono::Days;
+
+    #[test]
+    fn render_expands_dates_and_reports_missing_variables() {
+        let today = Local::now().date_naive();
+        let vars = HashMap::from([("client".to_string(), "Acme".to_string())]);
+
+        let rendered =
+            render_snippet_content("{{client}} due {{ today+3d }} from {{today}}", &vars)
+                .expect("snippet should render");
+        assert_eq!(
+            rendered,
+            format!(
+                "Acme due {} from {}",
+                (today + Days::new(3)).format("%Y-%m-%d"),
+                today.format("%Y-%m-%d")
+            )
+        );
+
+        let error = render_snippet_content("{{owner}} {{client}} {{agenda}} {{owner}}", &vars)
+            .expect_err("missing variables should fail");
+        let error: SnippetRenderError =
+            serde_json::from_str(&error).expect("error should be structured");
+        assert_eq!(error.kind, SnippetRenderErrorKind::MissingVariables);
+        assert_eq!(error.missing, vec!["agenda", "owner"]);
+    }
+
+    #[test]
+    fn snippets_round_trip_and_insert_at_line() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let space_path = root.to_string_lossy().to_string();
+
+        save_snippet(
+            space_path.clone(),
+            "meeting".to_string(),
+            "## Meeting with {{who}}".to_string(),
+        )?;
+        assert!(save_snippet(space_path.clone(), "a/b".to_string(), String::new()).is_err());
+        assert_eq!(
+            list_snippets(space_path.clone())?,
+            vec![Snippet {
+                name: "meeting".to_string(),
+                content: "## Meeting with {{who}}".to_string(),
+            }]
+        );
+
+        let notes = root.join("Cabinet/Notes.md");
+        write_test_file(&notes, "# Notes\nfirst\nlast")?;
+        let vars = HashMap::from([("who".to_string(), "Sam".to_string())]);
+        insert_snippet_into_file(
+            notes.to_string_lossy().to_string(),
+            "meeting".to_string(),
+            1,
+            Some(vars.clone()),
+        )?;
+        insert_snippet_into_file(
+            notes.to_string_lossy().to_string(),
+            "meeting".to_string(),
+            99,
+            Some(vars),
+        )?;
+        assert_eq!(
+            fs::read_to_string(&notes).map_err(|e| e.to_string())?,
+            "# Notes\n## Meeting with Sam\nfirst\nlast\n## Meeting with Sam\n"
+        );
+
+        assert!(insert_snippet_into_file(
+            notes.to_string_lossy().to_string(),
+            "meeting".to_string(),
+            0,
+            None,
+        )
+        .is_err());
+        delete_snippet(space_path.clone(), "meeting".to_string())?;
+        assert!(get_snippet(space_path, "meeting".to_string()).is_err());
+
+        Ok(())
+    }
+}
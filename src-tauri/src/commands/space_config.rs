@@ -0,0 +1,535 @@
+//! Per-space overlay configuration and write guards.
+//!
+//! Space-level options live in `.gtdspace/space.json` inside the space root so
+//! they travel with the folder. The write guards in this module are called by
+//! every mutating command before it touches the disk.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use tempfile::NamedTempFile;
+
+use super::custom_fields::CustomFieldDefinition;
+use super::field_vocabulary::VocabularyValue;
+use super::folder_encryption::EncryptedFolder;
+
+/// Hidden directory holding per-space configuration
+pub(crate) const SPACE_CONFIG_DIR: &str = ".gtdspace";
+/// Overlay config file name inside [`SPACE_CONFIG_DIR`]
+pub(crate) const SPACE_CONFIG_FILE: &str = "space.json";
+
+static LOCKED_MARKER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\[!locked:(true|false)\]").expect("valid locked marker regex"));
+
+/// Per-space overlay configuration persisted inside the space
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SpaceConfig {
+    /// When true, every mutating command refuses to write inside the space
+    #[serde(default)]
+    pub read_only: bool,
+    /// When true, project READMEs no longer get a generated action table
+    #[serde(default)]
+    pub disable_action_tables: bool,
+    /// Folders whose markdown files are stored encrypted
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub encrypted_folders: Vec<EncryptedFolder>,
+    /// Bulk operations touching more files than this are backed up first;
+    /// unset means the default of 10
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pre_op_backup_threshold: Option<usize>,
+    /// Allowed action and project statuses; unset means the built-in values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_values: Option<Vec<VocabularyValue>>,
+    /// Allowed effort values; unset means the built-in values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub effort_values: Option<Vec<VocabularyValue>>,
+    /// Days an action may wait before a follow-up is due; unset means 7
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiting_threshold_days: Option<u32>,
+    /// Extra action fields, stored under each action's `## Custom` section
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_fields: Vec<CustomFieldDefinition>,
+}
+
+/// Reason a write was refused
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum WriteBlockedKind {
+    /// The containing space is marked read-only
+    ReadOnly,
+    /// The file carries a `[!locked:true]` tag
+    Locked,
+}
+
+/// Structured error returned (JSON-encoded) when a write is refused
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WriteBlockedError {
+    /// Why the write was refused
+    pub kind: WriteBlockedKind,
+    /// Path the command attempted to modify
+    pub path: String,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+impl WriteBlockedError {
+    fn into_command_error(self) -> String {
+        serde_json::to_string(&self).unwrap_or(self.message)
+    }
+}
+
+fn space_config_path(space_root: &Path) -> PathBuf {
+    space_root.join(SPACE_CONFIG_DIR).join(SPACE_CONFIG_FILE)
+}
+
+/// Load the overlay config for a space, returning defaults when none exists.
+pub(crate) fn load_space_config(space_root: &Path) -> Result<SpaceConfig, String> {
+    let config_path = space_config_path(space_root);
+    if !config_path.exists() {
+        return Ok(SpaceConfig::default());
+    }
+
+    let raw = fs::read_to_string(&config_path)
+        .map_err(|e| format!("Failed to read space config: {}", e))?;
+    serde_json::from_str(&raw).map_err(|e| format!("Failed to parse space config: {}", e))
+}
+
+/// Persist the overlay config for a space atomically.
+pub(crate) fn save_space_config(space_root: &Path, config: &SpaceConfig) -> Result<(), String> {
+    let config_dir = space_root.join(SPACE_CONFIG_DIR);
+    fs::create_dir_all(&config_dir)
+        .map_err(|e| format!("Failed to create space config directory: {}", e))?;
+
+    let serialized = serde_json::to_string_pretty(config)
+        .map_err(|e| format!("Failed to serialize space config: {}", e))?;
+
+    let mut temp_file = NamedTempFile::new_in(&config_dir)
+        .map_err(|e| format!("Failed to create temporary space config: {}", e))?;
+    temp_file
+        .write_all(serialized.as_bytes())
+        .map_err(|e| format!("Failed to write space config: {}", e))?;
+    temp_file
+        .flush()
+        .map_err(|e| format!("Failed to flush space config: {}", e))?;
+    temp_file
+        .as_file()
+        .sync_all()
+        .map_err(|e| format!("Failed to sync space config: {}", e))?;
+    temp_file
+        .persist(space_config_path(space_root))
+        .map_err(|e| format!("Failed to persist space config: {}", e.error))?;
+
+    Ok(())
+}
+
+/// Find the closest ancestor of `path` (inclusive) that has an overlay config.
+pub(crate) fn find_configured_space_root(path: &Path) -> Option<PathBuf> {
+    let absolute = if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        std::env::current_dir().ok()?.join(path)
+    };
+
+    absolute
+        .ancestors()
+        .find(|ancestor| space_config_path(ancestor).is_file())
+        .map(Path::to_path_buf)
+}
+
+/// Whether `path` lives inside a space marked read-only.
+pub(crate) fn space_is_read_only(path: &Path) -> bool {
+    find_configured_space_root(path)
+        .and_then(|space_root| load_space_config(&space_root).ok())
+        .is_some_and(|config| config.read_only)
+}
+
+/// Refuse the write when `path` lives inside a read-only space.
+pub(crate) fn ensure_space_writable(path: &Path) -> Result<(), String> {
+    let Some(space_root) = find_configured_space_root(path) else {
+        return Ok(());
+    };
+
+    let config = load_space_config(&space_root)?;
+    if !config.read_only {
+        return Ok(());
+    }
+
+    log::warn!(
+        "Blocked write to {} because the space is read-only",
+        path.display()
+    );
+    Err(WriteBlockedError {
+        kind: WriteBlockedKind::ReadOnly,
+        path: path.to_string_lossy().to_string(),
+        message: "This space is in read-only mode".to_string(),
+    }
+    .into_command_error())
+}
+
+/// Whether generated action tables are enabled for the space containing `path`.
+pub(crate) fn action_tables_enabled(path: &Path) -> bool {
+    let Some(space_root) = find_configured_space_root(path) else {
+        return true;
+    };
+
+    load_space_config(&space_root)
+        .map(|config| !config.disable_action_tables)
+        .unwrap_or(true)
+}
+
+/// Whether markdown content carries an active `[!locked:true]` tag.
+pub(crate) fn content_is_locked(content: &str) -> bool {
+    LOCKED_MARKER_REGEX
+        .captures(content)
+        .is_some_and(|captures| &captures[1] == "true")
+}
+
+/// Refuse the write when the existing file is tagged `[!locked:true]`.
+pub(crate) fn ensure_file_unlocked(path: &Path) -> Result<(), String> {
+    if !path.is_file() {
+        return Ok(());
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        // Non-UTF-8 files cannot carry the markdown tag.
+        Err(_) => return Ok(()),
+    };
+
+    if !content_is_locked(&content) {
+        return Ok(());
+    }
+
+    Err(locked_file_error(path))
+}
+
+/// The command error for a write refused by a `[!locked:true]` tag.
+pub(crate) fn locked_file_error(path: &Path) -> String {
+    log::warn!("Blocked write to locked file {}", path.display());
+    WriteBlockedError {
+        kind: WriteBlockedKind::Locked,
+        path: path.to_string_lossy().to_string(),
+        message: "This file is locked".to_string(),
+    }
+    .into_command_error()
+}
+
+/// Refuse the write when the space is read-only or the file is locked.
+pub(crate) fn ensure_file_editable(path: &Path) -> Result<(), String> {
+    ensure_space_writable(path)?;
+    ensure_file_unlocked(path)
+}
+
+fn apply_locked_marker(content: &str, locked: bool) -> String {
+    if LOCKED_MARKER_REGEX.is_match(content) {
+        if locked {
+            return LOCKED_MARKER_REGEX
+                .replace(content, "[!locked:true]")
+                .into_owned();
+        }
+
+        // Drop whole marker lines (and the spacer line inserted with them).
+        let mut result: Vec<&str> = Vec::new();
+        let mut skip_blank = false;
+        for line in content.split('\n') {
+            let trimmed = line.trim();
+            if LOCKED_MARKER_REGEX.is_match(trimmed)
+                && LOCKED_MARKER_REGEX.replace(trimmed, "").is_empty()
+            {
+                skip_blank = result.last().is_some_and(|prev| prev.trim().is_empty());
+                continue;
+            }
+            if skip_blank && trimmed.is_empty() {
+                skip_blank = false;
+                continue;
+            }
+            skip_blank = false;
+            result.push(line);
+        }
+        return LOCKED_MARKER_REGEX
+            .replace_all(&result.join("\n"), "")
+            .into_owned();
+    }
+
+    if !locked {
+        return content.to_string();
+    }
+
+    // Place the tag right after the title so it stays visible in the editor.
+    let mut lines: Vec<&str> = content.split('\n').collect();
+    let insert_at = lines
+        .iter()
+        .position(|line| line.starts_with("# "))
+        .map(|index| index + 1)
+        .unwrap_or(0);
+    if insert_at == 0 {
+        lines.insert(0, "[!locked:true]");
+        lines.insert(1, "");
+    } else {
+        lines.insert(insert_at, "");
+        lines.insert(insert_at + 1, "[!locked:true]");
+    }
+    lines.join("\n")
+}
+
+/// Mark a space as read-only or writable
+///
+/// Persists the flag in the space's `.gtdspace/space.json`. While enabled,
+/// every mutating command returns a `read-only` error before touching the
+/// disk. Reads and the file watcher are unaffected.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `read_only` - Whether writes should be refused
+///
+/// # Returns
+///
+/// The updated space configuration or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_space_read_only', { spacePath: '/path/to/space', readOnly: true });
+/// ```
+#[tauri::command]
+pub fn set_space_read_only(space_path: String, read_only: bool) -> Result<SpaceConfig, String> {
+    log::info!("Setting read-only={} for space: {}", read_only, space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let mut config = load_space_config(space_root)?;
+    config.read_only = read_only;
+    save_space_config(space_root, &config)?;
+
+    Ok(config)
+}
+
+/// Turn the generated README action tables on or off for a space
+///
+/// When disabled, action commands stop refreshing the
+/// `<!-- gtd:actions:start -->` block. Existing blocks are left as they are and
+/// `sync_project_action_table` still works on demand.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `enabled` - Whether action tables should be kept in sync
+///
+/// # Returns
+///
+/// The updated space configuration or error details
+#[tauri::command]
+pub fn set_action_tables_enabled(space_path: String, enabled: bool) -> Result<SpaceConfig, String> {
+    log::info!(
+        "Setting action tables enabled={} for space: {}",
+        enabled,
+        space_path
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let mut config = load_space_config(space_root)?;
+    config.disable_action_tables = !enabled;
+    save_space_config(space_root, &config)?;
+
+    Ok(config)
+}
+
+/// Set how many files a bulk operation may touch before it is backed up
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `threshold` - File count above which a pre-operation backup is written
+///
+/// # Returns
+///
+/// The updated space configuration or error details
+#[tauri::command]
+pub fn set_pre_op_backup_threshold(
+    space_path: String,
+    threshold: usize,
+) -> Result<SpaceConfig, String> {
+    log::info!(
+        "Setting pre-op backup threshold={} for space: {}",
+        threshold,
+        space_path
+    );
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let mut config = load_space_config(space_root)?;
+    config.pre_op_backup_threshold = Some(threshold);
+    save_space_config(space_root, &config)?;
+
+    Ok(config)
+}
+
+/// Set how many days an action may wait before a follow-up is due
+///
+/// Used by `list_waiting_items` and the weekly review.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+/// * `days` - Follow-up threshold in days, or `null` for the default of 7
+///
+/// # Returns
+///
+/// The updated space configuration or error details
+#[tauri::command]
+pub fn set_waiting_threshold_days(
+    space_path: String,
+    days: Option<u32>,
+) -> Result<SpaceConfig, String> {
+    log::info!(
+        "Setting waiting threshold={:?} days for space: {}",
+        days,
+        space_path
+    );
+
+    if days == Some(0) {
+        return Err("Waiting threshold must be at least 1 day".to_string());
+    }
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+
+    let mut config = load_space_config(space_root)?;
+    config.waiting_threshold_days = days;
+    save_space_config(space_root, &config)?;
+
+    Ok(config)
+}
+
+/// Load the overlay configuration for a space
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// The space configuration, or defaults when none has been saved
+#[tauri::command]
+pub fn get_space_config(space_path: String) -> Result<SpaceConfig, String> {
+    load_space_config(Path::new(&space_path))
+}
+
+/// Add or remove the `[!locked:true]` tag on a markdown file
+///
+/// Locked files are rejected by `save_file`, `replace_in_file`, and the GTD
+/// update commands. Toggling the lock itself is still refused inside a
+/// read-only space.
+///
+/// # Arguments
+///
+/// * `path` - Markdown file to lock or unlock
+/// * `locked` - Desired lock state
+///
+/// # Returns
+///
+/// Whether the file content changed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_file_locked', { path: '/path/to/space/Projects/Alpha/README.md', locked: true });
+/// ```
+#[tauri::command]
+pub fn set_file_locked(path: String, locked: bool) -> Result<bool, String> {
+    log::info!("Setting locked={} for file: {}", locked, path);
+
+    let file_path = Path::new(&path);
+    if !file_path.is_file() {
+        return Err(format!("File does not exist: {}", path));
+    }
+    ensure_space_writable(file_path)?;
+
+    let content =
+        fs::read_to_string(file_path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let updated = apply_locked_marker(&content, locked);
+    if updated == content {
+        return Ok(false);
+    }
+
+    let parent = file_path.parent().unwrap_or_else(|| Path::new("."));
+    let mut temp_file = NamedTempFile::new_in(parent)
+        .map_err(|e| format!("Failed to create temporary file: {}", e))?;
+    temp_file
+        .write_all(updated.as_bytes())
+        .map_err(|e| format!("Failed to write temporary file: {}", e))?;
+    temp_file
+        .flush()
+        .map_err(|e| format!("Failed to flush temporary file: {}", e))?;
+    temp_file
+        .as_file()
+        .sync_all()
+        .map_err(|e| format!("Failed to sync temporary file: {}", e))?;
+    temp_file
+        .persist(file_path)
+        .map_err(|e| format!("Failed to replace file atomically: {}", e.error))?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn read_only_space_blocks_nested_writes() -> Result<(), String> {
+        let temp_dir = seed_test_workspace()?;
+        let root = temp_dir.path();
+        let readme = root.join("Projects/Alpha Project/README.md");
+
+        ensure_space_writable(&readme)?;
+        set_space_read_only(root.to_string_lossy().to_string(), true)?;
+
+        let error = ensure_space_writable(&readme).expect_err("write should be blocked");
+        let parsed: WriteBlockedError =
+            serde_json::from_str(&error).map_err(|e| format!("unstructured error: {}", e))?;
+        assert_eq!(parsed.kind, WriteBlockedKind::ReadOnly);
+
+        set_space_read_only(root.to_string_lossy().to_string(), false)?;
+        ensure_space_writable(&readme)
+    }
+
+    #[test]
+    fn set_file_locked_round_trips_marker() -> Result<(), String> {
+        let temp_dir = seed_test_workspace()?;
+        let root = temp_dir.path();
+        let file = root.join("Cabinet/Notes.md");
+        write_test_file(&file, "# Notes\n\nBody\n")?;
+        let path = file.to_string_lossy().to_string();
+
+        assert!(set_file_locked(path.clone(), true)?);
+        let locked = fs::read_to_string(&file).map_err(|e| e.to_string())?;
+        assert_eq!(locked, "# Notes\n\n[!locked:true]\n\nBody\n");
+        assert!(ensure_file_unlocked(&file).is_err());
+
+        assert!(set_file_locked(path, false)?);
+        let unlocked = fs::read_to_string(&file).map_err(|e| e.to_string())?;
+        assert_eq!(unlocked, "# Notes\n\nBody\n");
+        ensure_file_unlocked(&file)
+    }
+}
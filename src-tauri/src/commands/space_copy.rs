@@ -0,0 +1,373 @@
+//! Full copies of a GTD space for experimenting without touching the original.
+//!
+//! Reference fields store absolute paths, so markdown files in the copy have
+//! every path under the old root rewritten to the new root, both plain and in
+//! the URL-encoded form used by reference arrays. Other files are streamed
+//! across unchanged.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter};
+
+use super::settings::update_settings;
+use super::space_config::SPACE_CONFIG_DIR;
+use super::space_walk::{walk_directory, WalkOptions};
+use super::utils::is_markdown_path;
+
+/// Event emitted while a space is being duplicated
+pub(crate) const SPACE_DUPLICATE_PROGRESS_EVENT: &str = "space-duplicate-progress";
+
+/// Rebuildable entries inside [`SPACE_CONFIG_DIR`] skipped unless requested
+const INTERNAL_ENTRIES: [&str; 5] = ["meta.json", "trash", "history", "index", "pre-op-backups"];
+
+/// Files copied between progress events
+const PROGRESS_INTERVAL: usize = 25;
+
+/// Progress payload for [`SPACE_DUPLICATE_PROGRESS_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpaceDuplicateProgress {
+    pub files_copied: usize,
+    pub bytes_copied: u64,
+    /// File most recently copied, relative to the space root
+    pub current_path: String,
+}
+
+/// Result of duplicating a space
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateSpaceResult {
+    /// Absolute path of the new space
+    pub target_path: String,
+    pub files_copied: usize,
+    pub bytes_copied: u64,
+    /// Absolute self-references rewritten to point into the copy
+    pub references_rewritten: usize,
+}
+
+pub(crate) fn is_internal_entry(relative: &Path) -> bool {
+    let mut components = relative.components();
+    components
+        .next()
+        .is_some_and(|first| first.as_os_str() == SPACE_CONFIG_DIR)
+        && components.next().is_some_and(|second| {
+            INTERNAL_ENTRIES
+                .iter()
+                .any(|entry| second.as_os_str() == *entry)
+        })
+}
+
+/// Absolute form of `path`, resolving the parent when `path` does not exist yet.
+pub(crate) fn resolve_target(path: &Path) -> Result<PathBuf, String> {
+    let absolute =
+        std::path::absolute(path).map_err(|e| format!("Failed to resolve target path: {}", e))?;
+    match (absolute.parent(), absolute.file_name()) {
+        (Some(parent), Some(name)) => fs::canonicalize(parent)
+            .map(|parent| parent.join(name))
+            .map_err(|e| format!("Target parent folder is not accessible: {}", e)),
+        _ => Err("Target path must name a new folder".to_string()),
+    }
+}
+
+fn forward_slashes(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
+
+/// Replacement pairs for absolute paths under `source` in plain and encoded form.
+pub(crate) fn root_rewrites(source_roots: &[PathBuf], target: &Path) -> Vec<(String, String)> {
+    let new_root = forward_slashes(target);
+    let mut rewrites = Vec::new();
+    for root in source_roots {
+        let old_root = forward_slashes(root);
+        for (old, new) in [
+            (format!("{}/", old_root), format!("{}/", new_root)),
+            (
+                format!("{}%2F", urlencoding::encode(&old_root)),
+                format!("{}%2F", urlencoding::encode(&new_root)),
+            ),
+        ] {
+            if !rewrites.iter().any(|(existing, _)| *existing == old) {
+                rewrites.push((old, new));
+            }
+        }
+    }
+    rewrites
+}
+
+pub(crate) fn rewrite_references(content: &str, rewrites: &[(String, String)]) -> (String, usize) {
+    let mut rewritten = content.to_string();
+    let mut count = 0;
+    for (old, new) in rewrites {
+        let occurrences = rewritten.matches(old.as_str()).count();
+        if occurrences > 0 {
+            count += occurrences;
+            rewritten = rewritten.replace(old.as_str(), new);
+        }
+    }
+    (rewritten, count)
+}
+
+fn copy_file(
+    source: &Path,
+    destination: &Path,
+    rewrites: &[(String, String)],
+) -> Result<(u64, usize), String> {
+    let fail = |e: io::Error| format!("Failed to copy {}: {}", source.display(), e);
+
+    if is_markdown_path(source) {
+        if let Ok(content) = fs::read_to_string(source) {
+            let (content, count) = rewrite_references(&content, rewrites);
+            fs::write(destination, &content).map_err(fail)?;
+            return Ok((content.len() as u64, count));
+        }
+    }
+
+    let mut reader = fs::File::open(source).map_err(fail)?;
+    let mut writer = fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(destination)
+        .map_err(fail)?;
+    let bytes = io::copy(&mut reader, &mut writer).map_err(fail)?;
+    Ok((bytes, 0))
+}
+
+/// Copy everything below `source` into the existing folder `target`.
+///
+/// Markdown files get `rewrites` applied and symlinks are skipped. Totals
+/// accumulate in `progress`. Returns the number of rewritten references.
+pub(crate) fn copy_tree(
+    source: &Path,
+    target: &Path,
+    rewrites: &[(String, String)],
+    include_internal: bool,
+    progress: &mut SpaceDuplicateProgress,
+    mut on_progress: impl FnMut(&SpaceDuplicateProgress),
+) -> Result<usize, String> {
+    let mut references_rewritten = 0;
+    let options = WalkOptions {
+        skip_unreadable: false,
+        ..WalkOptions::default()
+    };
+    walk_directory(source, options, |entry| {
+        let relative = entry.path.strip_prefix(source).map_err(|e| e.to_string())?;
+        if !include_internal && is_internal_entry(relative) {
+            return Ok(false);
+        }
+        let destination = target.join(relative);
+
+        if entry.metadata.is_dir() {
+            fs::create_dir_all(&destination)
+                .map_err(|e| format!("Failed to create {}: {}", destination.display(), e))?;
+        } else if entry.metadata.is_file() {
+            let (bytes, rewritten) = copy_file(entry.path, &destination, rewrites)?;
+            references_rewritten += rewritten;
+            progress.files_copied += 1;
+            progress.bytes_copied += bytes;
+            progress.current_path = forward_slashes(relative);
+            if progress.files_copied.is_multiple_of(PROGRESS_INTERVAL) {
+                on_progress(progress);
+            }
+        }
+        Ok(true)
+    })?;
+    Ok(references_rewritten)
+}
+
+/// Copy `source` to the new folder `target`, calling `on_progress` as files land.
+///
+/// Symlinks are skipped. On failure the partially written copy is removed.
+pub(crate) fn copy_space(
+    source: &Path,
+    target: &Path,
+    include_internal: bool,
+    mut on_progress: impl FnMut(&SpaceDuplicateProgress),
+) -> Result<DuplicateSpaceResult, String> {
+    if !source.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+    let canonical_source =
+        fs::canonicalize(source).map_err(|e| format!("Failed to resolve space path: {}", e))?;
+    let target = resolve_target(target)?;
+    if target.starts_with(&canonical_source) {
+        return Err("Target folder cannot be inside the space being copied".to_string());
+    }
+    if target.exists() {
+        return Err(format!("Target '{}' already exists", target.display()));
+    }
+
+    let given_source = std::path::absolute(source).unwrap_or_else(|_| source.to_path_buf());
+    let rewrites = root_rewrites(&[canonical_source.clone(), given_source], &target);
+    fs::create_dir_all(&target).map_err(|e| format!("Failed to create target folder: {}", e))?;
+
+    let mut progress = SpaceDuplicateProgress {
+        files_copied: 0,
+        bytes_copied: 0,
+        current_path: String::new(),
+    };
+
+    let copied = copy_tree(
+        &canonical_source,
+        &target,
+        &rewrites,
+        include_internal,
+        &mut progress,
+        &mut on_progress,
+    );
+
+    let references_rewritten = match copied {
+        Ok(count) => count,
+        Err(error) => {
+            if let Err(cleanup_error) = fs::remove_dir_all(&target) {
+                log::error!(
+                    "Failed to remove partial space copy {}: {}",
+                    target.display(),
+                    cleanup_error
+                );
+            }
+            return Err(error);
+        }
+    };
+    on_progress(&progress);
+
+    Ok(DuplicateSpaceResult {
+        target_path: target.to_string_lossy().to_string(),
+        files_copied: progress.files_copied,
+        bytes_copied: progress.bytes_copied,
+        references_rewritten,
+    })
+}
+
+/// Copy an entire GTD space to a new folder
+///
+/// Emits `space-duplicate-progress` events while copying. Absolute references
+/// to files in the original space are rewritten to point into the copy, and
+/// the copy is added to the known spaces in settings. Rebuildable internal
+/// data in `.gtdspace` (file index, trash, history) is skipped unless
+/// `include_internal` is set.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `space_path` - Root of the space to copy
+/// * `target_path` - New folder to create; must not exist or be inside the space
+/// * `include_internal` - Also copy rebuildable internal data
+///
+/// # Returns
+///
+/// Copy totals and the number of rewritten references, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke<DuplicateSpaceResult>('duplicate_space', {
+///   spacePath: '/Users/me/GTD Space',
+///   targetPath: '/Users/me/GTD Sandbox',
+///   includeInternal: false,
+/// });
+/// ```
+#[tauri::command]
+pub async fn duplicate_space(
+    app: AppHandle,
+    space_path: String,
+    target_path: String,
+    include_internal: bool,
+) -> Result<DuplicateSpaceResult, String> {
+    log::info!("Duplicating space {} to {}", space_path, target_path);
+
+    let progress_app = app.clone();
+    let result = tokio::task::spawn_blocking(move || {
+        copy_space(
+            Path::new(&space_path),
+            Path::new(target_path.trim()),
+            include_internal,
+            |progress| {
+                if let Err(e) = progress_app.emit(SPACE_DUPLICATE_PROGRESS_EVENT, progress) {
+                    log::error!("Failed to emit space duplicate progress: {}", e);
+                }
+            },
+        )
+    })
+    .await
+    .map_err(|error| format!("Space copy task failed: {}", error))??;
+
+    update_settings(app, |settings| {
+        if !settings.known_spaces.contains(&result.target_path) {
+            settings.known_spaces.push(result.target_path.clone());
+        }
+    })
+    .await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn copy_space_rewrites_self_references_and_skips_internal_data() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let source = fs::canonicalize(workspace.path()).map_err(|e| e.to_string())?;
+        let source_root = forward_slashes(&source);
+        let area = format!("{}/Areas of Focus/Health.md", source_root);
+        let encoded = urlencoding::encode(&format!("[\"{}\"]", area)).into_owned();
+        write_test_file(
+            source.join("Projects/Gym/README.md"),
+            &format!(
+                "# Gym\n\n[!areas-references:{}]\n[!references:{}]\n[!references:{}-other/x.md]\n",
+                encoded, area, source_root
+            ),
+        )?;
+        write_test_file(source.join(".gtdspace/space.json"), "{}")?;
+        write_test_file(source.join(".gtdspace/meta.json"), "{}")?;
+
+        let parent = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let target = parent.path().join("Sandbox");
+        let mut events = 0;
+        let result = copy_space(&source, &target, false, |_| events += 1)?;
+
+        let target = fs::canonicalize(&target).map_err(|e| e.to_string())?;
+        let target_root = forward_slashes(&target);
+        assert_eq!(result.target_path, target.to_string_lossy());
+        assert_eq!(result.references_rewritten, 2);
+        assert!(events >= 1);
+        assert!(target.join(".gtdspace/space.json").exists());
+        assert!(!target.join(".gtdspace/meta.json").exists());
+        assert!(target.join("Projects/Alpha Project/README.md").exists());
+
+        let copied =
+            fs::read_to_string(target.join("Projects/Gym/README.md")).map_err(|e| e.to_string())?;
+        let new_area = format!("{}/Areas of Focus/Health.md", target_root);
+        assert!(copied.contains(&urlencoding::encode(&format!("[\"{}\"]", new_area)).into_owned()));
+        assert!(copied.contains(&format!("[!references:{}]", new_area)));
+        // A sibling folder sharing the root as a prefix is left alone
+        assert!(copied.contains(&format!("{}-other/x.md", source_root)));
+
+        let with_internal = parent.path().join("Full");
+        let result = copy_space(&source, &with_internal, true, |_| {})?;
+        assert!(with_internal.join(".gtdspace/meta.json").exists());
+        assert!(result.bytes_copied > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn copy_space_refuses_targets_inside_source_or_existing() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let source = workspace.path();
+
+        let error = copy_space(source, &source.join("Cabinet/Copy"), false, |_| {})
+            .expect_err("nested target should be refused");
+        assert!(error.contains("inside"), "{}", error);
+        assert!(!source.join("Cabinet/Copy").exists());
+
+        let existing = tempfile::tempdir().map_err(|e| e.to_string())?;
+        assert!(copy_space(source, existing.path(), false, |_| {}).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,569 @@
+//! Move or copy a GTD space to a new folder and follow it everywhere.
+//!
+//! Unlike `duplicate_space`, which makes a sandbox next to the original, a
+//! relocation repoints the user's configuration: absolute references inside
+//! markdown, settings paths, the running action timer, and the file watcher
+//! all move to the new root.
+//!
+//! Each step is recorded in `.gtdspace/relocation.json` inside the space, so
+//! a relocation that fails partway resumes from the last finished step when
+//! retried with the same paths. The journal is removed once every step is
+//! done.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::AppHandle;
+
+use super::action_timers::remap_running_timer;
+use super::gtd_projects::write_string_atomically;
+use super::path_remap::{directory_remaps, emit_remap};
+use super::pre_op_backups::backup_before_bulk_operation;
+use super::settings::{update_settings, UserSettings};
+use super::space_config::SPACE_CONFIG_DIR;
+use super::space_copy::{
+    copy_tree, is_internal_entry, resolve_target, rewrite_references, root_rewrites,
+    SpaceDuplicateProgress,
+};
+use super::space_walk::{walk_directory, WalkOptions};
+use super::utils::is_markdown_path;
+use super::watcher::{start_file_watcher, watched_folder};
+
+/// Journal of an in-progress relocation, inside [`SPACE_CONFIG_DIR`]
+const RELOCATION_JOURNAL_FILE: &str = "relocation.json";
+
+/// Steps of a relocation, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum RelocationPhase {
+    Transfer,
+    RewriteReferences,
+    RemoveSource,
+    UpdateStores,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RelocationJournal {
+    /// Canonical source root at the start of the relocation
+    old_path: String,
+    new_path: String,
+    copy: bool,
+    phase: RelocationPhase,
+    references_rewritten: usize,
+}
+
+/// Result of relocating a space
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelocateSpaceResult {
+    /// Absolute path of the space's new root
+    pub new_path: String,
+    /// The original folder was left in place
+    pub copied: bool,
+    /// Absolute references inside markdown pointed at the new root
+    pub references_rewritten: usize,
+    /// Settings and timer entries pointed at the new root
+    pub store_entries_updated: usize,
+    pub watcher_restarted: bool,
+    /// An interrupted relocation was picked up from its journal
+    pub resumed: bool,
+}
+
+/// Files of a relocation that finished everything except the store updates
+#[derive(Debug)]
+struct RelocatedFiles {
+    /// Roots references and stored paths may still point into
+    old_roots: Vec<PathBuf>,
+    new_root: PathBuf,
+    references_rewritten: usize,
+    resumed: bool,
+}
+
+fn journal_path(root: &Path) -> PathBuf {
+    root.join(SPACE_CONFIG_DIR).join(RELOCATION_JOURNAL_FILE)
+}
+
+fn read_journal(root: &Path) -> Option<RelocationJournal> {
+    let content = fs::read_to_string(journal_path(root)).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+fn write_journal(root: &Path, journal: &RelocationJournal) -> Result<(), String> {
+    let path = journal_path(root);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    let content = serde_json::to_string_pretty(journal)
+        .map_err(|e| format!("Failed to serialize relocation journal: {}", e))?;
+    write_string_atomically(&path, &content)
+}
+
+fn remove_journal(root: &Path) -> Result<(), String> {
+    match fs::remove_file(journal_path(root)) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(format!("Failed to remove relocation journal: {}", error)),
+    }
+}
+
+/// `value` re-rooted from the first of `old_roots` containing it to `new_root`.
+fn remap_path(value: &str, old_roots: &[PathBuf], new_root: &Path) -> Option<String> {
+    let path = Path::new(value.trim());
+    old_roots.iter().find_map(|root| {
+        let relative = path.strip_prefix(root).ok()?;
+        let moved = if relative.as_os_str().is_empty() {
+            new_root.to_path_buf()
+        } else {
+            new_root.join(relative)
+        };
+        Some(moved.to_string_lossy().to_string())
+    })
+}
+
+/// Point every settings path under an old root at the new root.
+///
+/// Returns the number of entries changed.
+fn relocate_settings_paths(
+    settings: &mut UserSettings,
+    old_roots: &[PathBuf],
+    new_root: &Path,
+) -> usize {
+    let mut updated = 0;
+    for field in [
+        &mut settings.last_folder,
+        &mut settings.default_space_path,
+        &mut settings.git_sync_repo_path,
+        &mut settings.git_sync_workspace_path,
+        &mut settings.mcp_server_workspace_path,
+    ] {
+        if let Some(moved) = field
+            .as_deref()
+            .and_then(|value| remap_path(value, old_roots, new_root))
+        {
+            *field = Some(moved);
+            updated += 1;
+        }
+    }
+
+    let mut known_spaces: Vec<String> = Vec::with_capacity(settings.known_spaces.len());
+    for space in settings.known_spaces.drain(..) {
+        let space = match remap_path(&space, old_roots, new_root) {
+            Some(moved) => {
+                updated += 1;
+                moved
+            }
+            None => space,
+        };
+        if !known_spaces.contains(&space) {
+            known_spaces.push(space);
+        }
+    }
+    settings.known_spaces = known_spaces;
+    updated
+}
+
+/// Rewrite absolute references in every markdown file under `root`.
+///
+/// Internal history and trash keep their old contents.
+fn rewrite_space_references(
+    root: &Path,
+    rewrites: &[(String, String)],
+    skip_backup: bool,
+) -> Result<usize, String> {
+    let mut files = Vec::new();
+    let options = WalkOptions {
+        skip_unreadable: false,
+        ..WalkOptions::default()
+    };
+    walk_directory(root, options, |entry| {
+        let relative = entry.path.strip_prefix(root).map_err(|e| e.to_string())?;
+        if is_internal_entry(relative) {
+            return Ok(false);
+        }
+        if !entry.metadata.is_file() || !is_markdown_path(entry.path) {
+            return Ok(true);
+        }
+        let mentions_old_root = fs::read_to_string(entry.path).is_ok_and(|content| {
+            rewrites
+                .iter()
+                .any(|(old, _)| content.contains(old.as_str()))
+        });
+        if mentions_old_root {
+            files.push(entry.path.to_path_buf());
+        }
+        Ok(true)
+    })?;
+    backup_before_bulk_operation(
+        root,
+        "relocate_space",
+        &uuid::Uuid::new_v4().to_string(),
+        &files,
+        skip_backup,
+    )?;
+
+    let mut total = 0;
+    for path in &files {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+        let (rewritten, count) = rewrite_references(&content, rewrites);
+        if count > 0 {
+            write_string_atomically(path, &rewritten)?;
+            total += count;
+        }
+    }
+    Ok(total)
+}
+
+/// Copy `source` into the new folder `target`, journaling in the target first
+/// so an interrupted copy is recognized and redone on retry.
+fn copy_into(source: &Path, target: &Path, journal: &RelocationJournal) -> Result<(), String> {
+    // A stale journal in the source would collide with the target's own
+    remove_journal(source)?;
+    write_journal(target, journal)?;
+
+    let mut progress = SpaceDuplicateProgress {
+        files_copied: 0,
+        bytes_copied: 0,
+        current_path: String::new(),
+    };
+    copy_tree(source, target, &[], true, &mut progress, |_| {})?;
+    log::info!(
+        "Copied {} files ({} bytes) to {}",
+        progress.files_copied,
+        progress.bytes_copied,
+        target.display()
+    );
+    Ok(())
+}
+
+/// Move or copy the space and rewrite its references, resuming from the
+/// journal when a previous attempt with the same paths was interrupted.
+///
+/// Leaves the journal at [`RelocationPhase::UpdateStores`]; call
+/// [`finish_relocation`] once stored paths are updated.
+fn relocate_files(
+    old: &Path,
+    new: &Path,
+    copy: bool,
+    skip_backup: bool,
+) -> Result<RelocatedFiles, String> {
+    let given_old = std::path::absolute(old).unwrap_or_else(|_| old.to_path_buf());
+    let target = resolve_target(new)?;
+    let canonical_old = fs::canonicalize(old).ok();
+
+    let resumable = read_journal(&target).filter(|journal| {
+        journal.copy == copy
+            && Path::new(&journal.new_path) == target
+            && (Path::new(&journal.old_path) == given_old
+                || canonical_old
+                    .as_deref()
+                    .is_some_and(|canonical| Path::new(&journal.old_path) == canonical))
+    });
+    let resumed = resumable.is_some();
+
+    let mut journal = match resumable {
+        Some(journal) if journal.phase == RelocationPhase::Transfer => {
+            if Path::new(&journal.old_path).is_dir() {
+                log::info!("Redoing interrupted copy into {}", target.display());
+                fs::remove_dir_all(&target)
+                    .map_err(|e| format!("Failed to clear partial copy: {}", e))?;
+                None
+            } else {
+                // The rename went through before the journal was advanced
+                Some(RelocationJournal {
+                    phase: RelocationPhase::RewriteReferences,
+                    ..journal
+                })
+            }
+        }
+        other => other,
+    };
+
+    if journal.is_none() {
+        let source = canonical_old
+            .as_deref()
+            .filter(|source| source.is_dir())
+            .ok_or_else(|| "Space directory does not exist".to_string())?;
+        if target.starts_with(source) {
+            return Err("New location cannot be inside the space being moved".to_string());
+        }
+        if target.exists() {
+            return Err(format!("Target '{}' already exists", target.display()));
+        }
+
+        let mut started = RelocationJournal {
+            old_path: source.to_string_lossy().to_string(),
+            new_path: target.to_string_lossy().to_string(),
+            copy,
+            phase: RelocationPhase::Transfer,
+            references_rewritten: 0,
+        };
+        let moved = !copy && {
+            // The journal travels with the folder when the rename succeeds
+            write_journal(source, &started)?;
+            match fs::rename(source, &target) {
+                Ok(()) => true,
+                Err(error) => {
+                    log::info!("Rename failed ({}); copying instead", error);
+                    false
+                }
+            }
+        };
+        if !moved {
+            copy_into(source, &target, &started)?;
+        }
+        started.phase = RelocationPhase::RewriteReferences;
+        write_journal(&target, &started)?;
+        journal = Some(started);
+    }
+
+    let Some(mut journal) = journal else {
+        return Err("Relocation journal missing".to_string());
+    };
+    let mut old_roots = vec![PathBuf::from(&journal.old_path)];
+    if !old_roots.contains(&given_old) {
+        old_roots.push(given_old);
+    }
+
+    if journal.phase == RelocationPhase::RewriteReferences {
+        let rewrites = root_rewrites(&old_roots, &target);
+        journal.references_rewritten += rewrite_space_references(&target, &rewrites, skip_backup)?;
+        journal.phase = RelocationPhase::RemoveSource;
+        write_journal(&target, &journal)?;
+    }
+
+    if journal.phase == RelocationPhase::RemoveSource {
+        let source = Path::new(&journal.old_path);
+        if journal.copy {
+            remove_journal(source)?;
+        } else if source.exists() {
+            fs::remove_dir_all(source)
+                .map_err(|e| format!("Failed to remove original space folder: {}", e))?;
+        }
+        journal.phase = RelocationPhase::UpdateStores;
+        write_journal(&target, &journal)?;
+    }
+
+    Ok(RelocatedFiles {
+        old_roots,
+        new_root: target,
+        references_rewritten: journal.references_rewritten,
+        resumed,
+    })
+}
+
+fn finish_relocation(new_root: &Path) -> Result<(), String> {
+    remove_journal(new_root)
+}
+
+/// Move or copy a GTD space to a new folder and repoint everything at it
+///
+/// Moves the folder (or copies it when `copy` is set, leaving the original in
+/// place), rewrites absolute references inside markdown from the old root to
+/// the new one, updates settings paths (last folder, default space, known
+/// spaces, git sync and MCP workspace paths) and the running action timer,
+/// and restarts the file watcher when it was watching the old root. A
+/// `paths-remapped` event lets open and recently closed tabs follow. When more
+/// files need their references rewritten than the space's backup threshold,
+/// they are backed up to `.gtdspace/pre-op-backups` in the new folder first.
+///
+/// Progress is journaled inside the space; if a step fails, calling again
+/// with the same arguments resumes where it stopped.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `old_path` - Current root of the space
+/// * `new_path` - New folder for the space; must not exist yet
+/// * `copy` - Copy instead of move
+/// * `skip_backup` - Skip the automatic pre-operation backup
+///
+/// # Returns
+///
+/// Rewritten reference and updated store entry counts, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result = await invoke<RelocateSpaceResult>('relocate_space', {
+///   oldPath: '/Users/me/GTD Space',
+///   newPath: '/Volumes/Archive/GTD Space',
+///   copy: false,
+/// });
+/// ```
+#[tauri::command]
+pub async fn relocate_space(
+    app: AppHandle,
+    old_path: String,
+    new_path: String,
+    copy: bool,
+    skip_backup: Option<bool>,
+) -> Result<RelocateSpaceResult, String> {
+    log::info!(
+        "Relocating space {} to {} (copy: {})",
+        old_path,
+        new_path,
+        copy
+    );
+
+    let watched = watched_folder().await;
+    let (old, new) = (PathBuf::from(&old_path), PathBuf::from(new_path.trim()));
+    let skip_backup = skip_backup.unwrap_or(false);
+    let files = tokio::task::spawn_blocking(move || relocate_files(&old, &new, copy, skip_backup))
+        .await
+        .map_err(|error| format!("Space relocation task failed: {}", error))??;
+
+    let old_roots = files.old_roots.clone();
+    let new_root = files.new_root.clone();
+    let mut store_entries_updated = 0;
+    update_settings(app.clone(), |settings| {
+        store_entries_updated = relocate_settings_paths(settings, &old_roots, &new_root);
+    })
+    .await?;
+    if remap_running_timer(&app, |path| remap_path(path, &old_roots, &new_root))? {
+        store_entries_updated += 1;
+    }
+
+    let mut watcher_restarted = false;
+    if let Some(folder) =
+        watched.and_then(|folder| remap_path(&folder.to_string_lossy(), &old_roots, &new_root))
+    {
+        start_file_watcher(app.clone(), folder).await?;
+        watcher_restarted = true;
+    }
+
+    finish_relocation(&new_root)?;
+    if let Some(old_root) = old_roots.last() {
+        emit_remap(&app, &directory_remaps(old_root, &new_root));
+    }
+
+    Ok(RelocateSpaceResult {
+        new_path: new_root.to_string_lossy().to_string(),
+        copied: copy,
+        references_rewritten: files.references_rewritten,
+        store_entries_updated,
+        watcher_restarted,
+        resumed: files.resumed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::settings::get_default_settings;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn seeded_space(parent: &Path) -> Result<PathBuf, String> {
+        let workspace = seed_test_workspace()?;
+        let source = parent.join("Space");
+        copy_tree(
+            workspace.path(),
+            &source,
+            &[],
+            true,
+            &mut SpaceDuplicateProgress {
+                files_copied: 0,
+                bytes_copied: 0,
+                current_path: String::new(),
+            },
+            |_| {},
+        )?;
+        let source = fs::canonicalize(&source).map_err(|e| e.to_string())?;
+        let area = source.join("Areas of Focus/Health.md");
+        write_test_file(
+            source.join("Projects/Gym/README.md"),
+            &format!("# Gym\n\n[!references:{}]\n", area.display()),
+        )?;
+        Ok(source)
+    }
+
+    #[test]
+    fn moving_a_space_rewrites_references_and_settings() -> Result<(), String> {
+        let parent = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let parent = fs::canonicalize(parent.path()).map_err(|e| e.to_string())?;
+        let source = seeded_space(&parent)?;
+        let target = parent.join("Moved");
+
+        let files = relocate_files(&source, &target, false, false)?;
+        assert!(!source.exists());
+        assert!(!files.resumed);
+        assert_eq!(files.references_rewritten, 1);
+        let readme =
+            fs::read_to_string(target.join("Projects/Gym/README.md")).map_err(|e| e.to_string())?;
+        assert!(readme.contains(
+            &target
+                .join("Areas of Focus/Health.md")
+                .display()
+                .to_string()
+        ));
+        assert_eq!(
+            read_journal(&target).map(|journal| journal.phase),
+            Some(RelocationPhase::UpdateStores)
+        );
+
+        let mut settings = get_default_settings();
+        settings.last_folder = Some(source.join("Projects").display().to_string());
+        settings.default_space_path = Some(source.display().to_string());
+        settings.known_spaces = vec![
+            source.display().to_string(),
+            target.display().to_string(),
+            "/elsewhere".to_string(),
+        ];
+        let updated = relocate_settings_paths(&mut settings, &files.old_roots, &files.new_root);
+        assert_eq!(updated, 3);
+        assert_eq!(
+            settings.last_folder,
+            Some(target.join("Projects").display().to_string())
+        );
+        assert_eq!(
+            settings.known_spaces,
+            vec![target.display().to_string(), "/elsewhere".to_string()]
+        );
+
+        finish_relocation(&files.new_root)?;
+        assert!(read_journal(&target).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn interrupted_copy_resumes_from_the_journal() -> Result<(), String> {
+        let parent = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let parent = fs::canonicalize(parent.path()).map_err(|e| e.to_string())?;
+        let source = seeded_space(&parent)?;
+        let target = parent.join("Copy");
+
+        // A copy that died halfway leaves its journal in the target
+        write_journal(
+            &target,
+            &RelocationJournal {
+                old_path: source.display().to_string(),
+                new_path: target.display().to_string(),
+                copy: true,
+                phase: RelocationPhase::Transfer,
+                references_rewritten: 0,
+            },
+        )?;
+        write_test_file(target.join("Projects/partial.md"), "half")?;
+
+        let files = relocate_files(&source, &target, true, false)?;
+        assert!(files.resumed);
+        assert!(source.join("Projects/Gym/README.md").exists());
+        assert!(!target.join("Projects/partial.md").exists());
+        assert_eq!(files.references_rewritten, 1);
+
+        // Failing after the rewrite resumes without counting twice
+        let mut journal = read_journal(&target).ok_or("journal missing")?;
+        journal.phase = RelocationPhase::RewriteReferences;
+        write_journal(&target, &journal)?;
+        let files = relocate_files(&source, &target, true, false)?;
+        assert!(files.resumed);
+        assert_eq!(files.references_rewritten, 1);
+
+        // Without a matching journal an existing target is refused
+        finish_relocation(&target)?;
+        assert!(relocate_files(&source, &target, true, false).is_err());
+        Ok(())
+    }
+}
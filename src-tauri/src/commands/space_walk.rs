@@ -0,0 +1,262 @@
+//! Bounded directory walker shared by listing, search, relationship scans,
+//! and backups.
+//!
+//! Symlinks are skipped unless `follow_symlinks` is set, so a link to a folder
+//! outside the space is never scanned by default. When links are followed,
+//! every directory is entered once by its canonical path, which turns a
+//! symlink cycle into a skipped entry instead of an endless scan. Walks stop
+//! descending at `max_depth` and report that they did.
+
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Deepest directory level any walk descends into
+pub(crate) const MAX_WALK_DEPTH: usize = 64;
+
+/// How a walk treats symlinks, hidden folders, depth, and read errors
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WalkOptions {
+    /// Descend into symlinked folders and yield symlinked files
+    pub follow_symlinks: bool,
+    /// Skip folders whose name starts with `.`
+    pub skip_hidden_dirs: bool,
+    /// Entries directly under the root are depth 1
+    pub max_depth: usize,
+    /// Log and skip unreadable entries below the root instead of failing
+    pub skip_unreadable: bool,
+}
+
+impl Default for WalkOptions {
+    fn default() -> Self {
+        Self {
+            follow_symlinks: false,
+            skip_hidden_dirs: false,
+            max_depth: MAX_WALK_DEPTH,
+            skip_unreadable: true,
+        }
+    }
+}
+
+/// A file or folder found by [`walk_directory`]
+pub(crate) struct WalkEntry<'a> {
+    pub path: &'a Path,
+    /// Metadata of the link target when a followed symlink
+    pub metadata: &'a fs::Metadata,
+}
+
+/// What a finished walk ran into
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct WalkReport {
+    /// Some folder was deeper than `max_depth` and was not entered
+    pub depth_limit_reached: bool,
+    /// Followed symlinks that led back to an already visited folder
+    pub cycles_skipped: usize,
+}
+
+impl WalkReport {
+    /// User-facing note when the walk was cut short
+    pub(crate) fn warning(&self) -> Option<String> {
+        self.depth_limit_reached
+            .then(|| "Some folders are nested too deeply and were not scanned".to_string())
+    }
+}
+
+struct Walk<'v, F> {
+    options: WalkOptions,
+    visited: HashSet<PathBuf>,
+    report: WalkReport,
+    visit: &'v mut F,
+}
+
+impl<F> Walk<'_, F>
+where
+    F: FnMut(&WalkEntry) -> Result<bool, String>,
+{
+    fn skip_or_fail(&self, message: String) -> Result<(), String> {
+        if self.options.skip_unreadable {
+            log::warn!("{}", message);
+            Ok(())
+        } else {
+            Err(message)
+        }
+    }
+
+    fn walk(&mut self, dir: &Path, depth: usize) -> Result<(), String> {
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(error) => {
+                let message = format!("Failed to read directory {}: {}", dir.display(), error);
+                return if depth == 1 {
+                    Err(message)
+                } else {
+                    self.skip_or_fail(message)
+                };
+            }
+        };
+
+        for entry in entries {
+            let entry = match entry {
+                Ok(entry) => entry,
+                Err(error) => {
+                    self.skip_or_fail(format!(
+                        "Skipping unreadable entry in {}: {}",
+                        dir.display(),
+                        error
+                    ))?;
+                    continue;
+                }
+            };
+            let path = entry.path();
+            let metadata = match fs::symlink_metadata(&path) {
+                Ok(metadata) if metadata.file_type().is_symlink() => {
+                    if !self.options.follow_symlinks {
+                        continue;
+                    }
+                    match fs::metadata(&path) {
+                        Ok(target) => target,
+                        Err(error) => {
+                            log::debug!("Skipping dangling symlink {}: {}", path.display(), error);
+                            continue;
+                        }
+                    }
+                }
+                Ok(metadata) => metadata,
+                Err(error) => {
+                    self.skip_or_fail(format!(
+                        "Skipping entry with unreadable metadata {}: {}",
+                        path.display(),
+                        error
+                    ))?;
+                    continue;
+                }
+            };
+
+            if !metadata.is_dir() {
+                (self.visit)(&WalkEntry {
+                    path: &path,
+                    metadata: &metadata,
+                })?;
+                continue;
+            }
+
+            let hidden = path
+                .file_name()
+                .is_some_and(|name| name.to_string_lossy().starts_with('.'));
+            if hidden && self.options.skip_hidden_dirs {
+                continue;
+            }
+            if self.options.follow_symlinks {
+                let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+                if !self.visited.insert(canonical) {
+                    log::warn!("Skipping already visited folder {}", path.display());
+                    self.report.cycles_skipped += 1;
+                    continue;
+                }
+            }
+            if !(self.visit)(&WalkEntry {
+                path: &path,
+                metadata: &metadata,
+            })? {
+                continue;
+            }
+            if depth >= self.options.max_depth {
+                if !self.report.depth_limit_reached {
+                    log::warn!(
+                        "Not descending below {}: depth limit {} reached",
+                        path.display(),
+                        self.options.max_depth
+                    );
+                }
+                self.report.depth_limit_reached = true;
+                continue;
+            }
+            self.walk(&path, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Walk everything below `root`, folders before their contents.
+///
+/// `visit` sees every file and folder; returning `Ok(false)` for a folder
+/// skips its contents, and an error stops the walk. Fails when `root` itself
+/// cannot be read.
+pub(crate) fn walk_directory<F>(
+    root: &Path,
+    options: WalkOptions,
+    mut visit: F,
+) -> Result<WalkReport, String>
+where
+    F: FnMut(&WalkEntry) -> Result<bool, String>,
+{
+    let mut walk = Walk {
+        options,
+        visited: HashSet::new(),
+        report: WalkReport::default(),
+        visit: &mut visit,
+    };
+    if options.follow_symlinks {
+        walk.visited
+            .insert(root.canonicalize().unwrap_or_else(|_| root.to_path_buf()));
+    }
+    walk.walk(root, 1)?;
+    Ok(walk.report)
+}
+
+/// Paths of every file below `root`, in walk order.
+pub(crate) fn walk_files(
+    root: &Path,
+    options: WalkOptions,
+) -> Result<(Vec<PathBuf>, WalkReport), String> {
+    let mut files = Vec::new();
+    let report = walk_directory(root, options, |entry| {
+        if !entry.metadata.is_dir() {
+            files.push(entry.path.to_path_buf());
+        }
+        Ok(true)
+    })?;
+    Ok((files, report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::write_test_file;
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_cycles_terminate_and_depth_is_capped() -> Result<(), String> {
+        use std::os::unix::fs::symlink;
+
+        let temp_dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let root = temp_dir.path();
+        write_test_file(root.join("Cabinet/Note.md"), "# Note\n")?;
+        symlink(root, root.join("Cabinet/loop")).map_err(|e| e.to_string())?;
+        symlink(root.join("Cabinet"), root.join("Cabinet/self")).map_err(|e| e.to_string())?;
+
+        let (files, report) = walk_files(root, WalkOptions::default())?;
+        assert_eq!(files, vec![root.join("Cabinet/Note.md")]);
+        assert_eq!(report, WalkReport::default());
+
+        let follow = WalkOptions {
+            follow_symlinks: true,
+            ..WalkOptions::default()
+        };
+        let (files, report) = walk_files(root, follow)?;
+        assert_eq!(files, vec![root.join("Cabinet/Note.md")]);
+        assert_eq!(report.cycles_skipped, 2);
+
+        write_test_file(root.join("a/b/c/Deep.md"), "# Deep\n")?;
+        let shallow = WalkOptions {
+            max_depth: 2,
+            ..WalkOptions::default()
+        };
+        let (files, report) = walk_files(root, shallow)?;
+        assert!(!files.contains(&root.join("a/b/c/Deep.md")));
+        assert!(report.depth_limit_reached);
+        assert!(report.warning().is_some());
+
+        Ok(())
+    }
+}
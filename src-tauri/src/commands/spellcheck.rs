@@ -0,0 +1,509 @@
+//! Backend spell checking for the editor and space-health checks.
+//!
+//! Hunspell `.aff`/`.dic` pairs are loaded once per language and kept in
+//! memory. US English ships with the app under `resources/dictionaries/`;
+//! other languages are installed by the user. Words the user adds are stored in the app data dir and applied to
+//! every loaded dictionary. Code fences, inline code, URLs, and `[!...]`
+//! field tags are never checked.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use spellbook::Dictionary;
+use std::collections::HashMap;
+use std::fs;
+use std::io::Write;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::{AppHandle, Manager};
+
+use super::settings::load_settings;
+
+/// Language used when settings don't choose one
+pub(crate) const DEFAULT_SPELLCHECK_LANGUAGE: &str = "en-US";
+
+/// Folder under the app data dir holding user dictionaries and custom words
+const SPELLCHECK_DIR: &str = "spellcheck";
+
+/// Folder (under `spellcheck/` or the resource dir) holding `.aff`/`.dic` files
+const DICTIONARIES_DIR: &str = "dictionaries";
+
+/// One word per line, added with `add_word_to_dictionary`
+const CUSTOM_WORDS_FILE: &str = "custom-words.txt";
+
+/// Suggestions returned per misspelling
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Loaded dictionaries keyed by language
+static DICTIONARIES: Lazy<Mutex<HashMap<String, Dictionary>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+static WORD_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"\p{L}+(?:['’]\p{L}+)*").expect("Invalid word regex pattern"));
+
+/// Spans on a line that are never checked: inline code, `[!...]` tags,
+/// URLs, and link targets
+static SKIPPED_SPAN_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"`[^`]*`|\[![^\]]*\]|(?:https?|ftp|mailto):\S+|www\.\S+|\]\([^)]*\)")
+        .expect("Invalid skipped span regex pattern")
+});
+
+/// Reason spell checking could not run
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SpellcheckErrorKind {
+    /// No dictionary is installed for the language
+    NotAvailable,
+}
+
+/// Structured error returned (JSON-encoded) when spell checking can't run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SpellcheckError {
+    pub kind: SpellcheckErrorKind,
+    /// Requested language, e.g. `de-DE`
+    pub language: String,
+    /// Folders searched for `{language}.aff` and `{language}.dic`
+    pub searched: Vec<String>,
+    /// Human-readable explanation, including how to install the dictionary
+    pub message: String,
+}
+
+impl SpellcheckError {
+    fn into_command_error(self) -> String {
+        serde_json::to_string(&self).unwrap_or(self.message)
+    }
+}
+
+/// A misspelled word
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Misspelling {
+    pub word: String,
+    /// Start offset in UTF-16 code units, matching JavaScript string indices
+    pub start: usize,
+    /// End offset (exclusive) in UTF-16 code units
+    pub end: usize,
+    /// 1-based line number
+    pub line: usize,
+    pub suggestions: Vec<String>,
+}
+
+/// Misspellings in one file, from `check_file`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileSpellcheck {
+    pub path: String,
+    pub language: String,
+    pub misspellings: Vec<Misspelling>,
+}
+
+/// A word to check: its byte range in the text and its line
+#[derive(Debug)]
+struct WordSpan {
+    range: Range<usize>,
+    line: usize,
+}
+
+/// Words worth checking, skipping front matter, code fences, inline code,
+/// URLs, link targets, and `[!...]` field tags.
+fn checkable_words(text: &str) -> Vec<WordSpan> {
+    let mut words = Vec::new();
+    let mut offset = 0;
+    let mut fence: Option<&str> = None;
+    let mut in_front_matter = text.starts_with("---\n") || text.starts_with("---\r\n");
+
+    for (index, raw_line) in text.split_inclusive('\n').enumerate() {
+        let line_start = offset;
+        offset += raw_line.len();
+        let line = raw_line.trim_end_matches(['\r', '\n']);
+        let trimmed = line.trim_start();
+
+        if in_front_matter {
+            if index > 0 && (trimmed == "---" || trimmed == "...") {
+                in_front_matter = false;
+            }
+            continue;
+        }
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            }
+            continue;
+        }
+        if trimmed.starts_with("```") {
+            fence = Some("```");
+            continue;
+        }
+        if trimmed.starts_with("~~~") {
+            fence = Some("~~~");
+            continue;
+        }
+
+        let skipped: Vec<Range<usize>> = SKIPPED_SPAN_REGEX
+            .find_iter(line)
+            .map(|span| span.range())
+            .collect();
+        for word in WORD_REGEX.find_iter(line) {
+            let inside_skipped = skipped
+                .iter()
+                .any(|span| span.start <= word.start() && word.end() <= span.end);
+            if inside_skipped || word.as_str().chars().count() < 2 {
+                continue;
+            }
+            words.push(WordSpan {
+                range: line_start + word.start()..line_start + word.end(),
+                line: index + 1,
+            });
+        }
+    }
+    words
+}
+
+/// Misspelled words in `text`, with UTF-16 offsets.
+fn find_misspellings(text: &str, dictionary: &Dictionary) -> Vec<Misspelling> {
+    let mut misspellings = Vec::new();
+    let mut last_byte = 0;
+    let mut last_utf16 = 0;
+    for span in checkable_words(text) {
+        let word = &text[span.range.clone()];
+        let normalized = word.replace('’', "'");
+        if dictionary.check(&normalized) {
+            continue;
+        }
+
+        last_utf16 += text[last_byte..span.range.start].encode_utf16().count();
+        last_byte = span.range.start;
+        let start = last_utf16;
+        let end = start + word.encode_utf16().count();
+
+        let mut suggestions = Vec::new();
+        dictionary.suggest(&normalized, &mut suggestions);
+        suggestions.truncate(MAX_SUGGESTIONS);
+        misspellings.push(Misspelling {
+            word: word.to_string(),
+            start,
+            end,
+            line: span.line,
+            suggestions,
+        });
+    }
+    misspellings
+}
+
+fn spellcheck_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join(SPELLCHECK_DIR))
+        .map_err(|e| format!("Failed to resolve app data directory: {}", e))
+}
+
+/// Folders that may hold dictionaries: the user's first, then the bundled ones.
+fn dictionary_dirs(app: &AppHandle) -> Result<Vec<PathBuf>, String> {
+    let mut dirs = vec![spellcheck_dir(app)?.join(DICTIONARIES_DIR)];
+    if let Ok(resource_dir) = app.path().resource_dir() {
+        dirs.push(resource_dir.join(DICTIONARIES_DIR));
+    }
+    Ok(dirs)
+}
+
+/// Normalize `en_us` or `EN-us` to `en-US`, rejecting anything that isn't a
+/// plain language tag.
+fn normalize_language(language: &str) -> Result<String, String> {
+    let trimmed = language.trim().replace('_', "-");
+    let mut parts = trimmed.split('-');
+    let base = parts.next().unwrap_or_default();
+    let region = parts.next();
+    let valid = |part: &str| {
+        (2..=8).contains(&part.len()) && part.chars().all(|c| c.is_ascii_alphanumeric())
+    };
+    if !valid(base) || parts.next().is_some() || region.is_some_and(|region| !valid(region)) {
+        return Err(format!("Invalid spell check language '{}'", language));
+    }
+    Ok(match region {
+        Some(region) => format!(
+            "{}-{}",
+            base.to_ascii_lowercase(),
+            region.to_ascii_uppercase()
+        ),
+        None => base.to_ascii_lowercase(),
+    })
+}
+
+/// Words added by the user.
+fn read_custom_words(app: &AppHandle) -> Vec<String> {
+    let Ok(dir) = spellcheck_dir(app) else {
+        return Vec::new();
+    };
+    fs::read_to_string(dir.join(CUSTOM_WORDS_FILE))
+        .map(|content| {
+            content
+                .lines()
+                .map(str::trim)
+                .filter(|word| !word.is_empty())
+                .map(str::to_string)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse the Hunspell files for `language` from the first folder that has them.
+fn load_dictionary(app: &AppHandle, language: &str) -> Result<Dictionary, String> {
+    // Hunspell files are conventionally named with an underscore: en_US.dic
+    let file_stem = language.replace('-', "_");
+    let dirs = dictionary_dirs(app)?;
+    for dir in &dirs {
+        let aff_path = dir.join(format!("{}.aff", file_stem));
+        let dic_path = dir.join(format!("{}.dic", file_stem));
+        if !aff_path.is_file() || !dic_path.is_file() {
+            continue;
+        }
+        let aff = fs::read_to_string(&aff_path)
+            .map_err(|e| format!("Failed to read {}: {}", aff_path.display(), e))?;
+        let dic = fs::read_to_string(&dic_path)
+            .map_err(|e| format!("Failed to read {}: {}", dic_path.display(), e))?;
+        let mut dictionary = Dictionary::new(&aff, &dic)
+            .map_err(|e| format!("Failed to parse {} dictionary: {}", language, e))?;
+        for word in read_custom_words(app) {
+            if let Err(e) = dictionary.add(&word) {
+                log::warn!("Skipping custom word '{}': {}", word, e);
+            }
+        }
+        log::info!("Loaded {} dictionary from {}", language, dir.display());
+        return Ok(dictionary);
+    }
+
+    let install_dir = dirs
+        .first()
+        .map(|dir| dir.display().to_string())
+        .unwrap_or_default();
+    Err(SpellcheckError {
+        kind: SpellcheckErrorKind::NotAvailable,
+        language: language.to_string(),
+        searched: dirs.iter().map(|dir| dir.display().to_string()).collect(),
+        message: format!(
+            "No {} dictionary is installed. Download the Hunspell files {}.aff and {}.dic \
+             (for example from the LibreOffice dictionaries project) and place them in {}",
+            language, file_stem, file_stem, install_dir
+        ),
+    }
+    .into_command_error())
+}
+
+/// Run `check` against the dictionary for `language`, loading it on first use.
+fn with_dictionary<T>(
+    app: &AppHandle,
+    language: &str,
+    check: impl FnOnce(&Dictionary) -> T,
+) -> Result<T, String> {
+    let mut dictionaries = DICTIONARIES
+        .lock()
+        .map_err(|_| "Spell check dictionaries are unavailable".to_string())?;
+    if !dictionaries.contains_key(language) {
+        let dictionary = load_dictionary(app, language)?;
+        dictionaries.insert(language.to_string(), dictionary);
+    }
+    let dictionary = dictionaries
+        .get(language)
+        .ok_or_else(|| format!("No {} dictionary is loaded", language))?;
+    Ok(check(dictionary))
+}
+
+/// Language from the argument, else settings, else en-US.
+async fn resolve_language(app: &AppHandle, language: Option<String>) -> Result<String, String> {
+    let language = match language.filter(|language| !language.trim().is_empty()) {
+        Some(language) => language,
+        None => load_settings(app.clone())
+            .await
+            .ok()
+            .and_then(|settings| settings.spellcheck_language)
+            .filter(|language| !language.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_SPELLCHECK_LANGUAGE.to_string()),
+    };
+    normalize_language(&language)
+}
+
+/// Check text for misspellings
+///
+/// Front matter, code fences, inline code, URLs, link targets, and `[!...]`
+/// field tags are skipped. Returns a JSON-encoded `SpellcheckError` with
+/// kind `not-available` when no dictionary is installed for the language.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `text` - Markdown text to check
+/// * `language` - Language such as `en-US`; defaults to the language in settings
+///
+/// # Returns
+///
+/// Misspelled words with UTF-16 offsets and suggestions, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const misspellings = await invoke<Misspelling[]>('check_text', {
+///   text: editor.getValue(),
+///   language: null,
+/// });
+/// ```
+#[tauri::command]
+pub async fn check_text(
+    app: AppHandle,
+    text: String,
+    language: Option<String>,
+) -> Result<Vec<Misspelling>, String> {
+    let language = resolve_language(&app, language).await?;
+    with_dictionary(&app, &language, |dictionary| {
+        find_misspellings(&text, dictionary)
+    })
+}
+
+/// Check a markdown file for misspellings
+///
+/// Uses the language from settings. Meant for batch checks such as lint and
+/// space health; the editor should use `check_text` on its buffer.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `path` - Full path to the markdown file
+///
+/// # Returns
+///
+/// The file's misspellings, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke<FileSpellcheck>('check_file', {
+///   path: '/path/to/space/Projects/Website/README.md',
+/// });
+/// ```
+#[tauri::command]
+pub async fn check_file(app: AppHandle, path: String) -> Result<FileSpellcheck, String> {
+    log::info!("Spell checking file: {}", path);
+
+    let text = fs::read_to_string(&path).map_err(|e| format!("Failed to read file: {}", e))?;
+    let language = resolve_language(&app, None).await?;
+    let misspellings = with_dictionary(&app, &language, |dictionary| {
+        find_misspellings(&text, dictionary)
+    })?;
+    Ok(FileSpellcheck {
+        path,
+        language,
+        misspellings,
+    })
+}
+
+/// Add a word to the user's custom dictionary
+///
+/// The word is saved in the app data dir and accepted in every language
+/// from then on.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `word` - Word to accept
+///
+/// # Returns
+///
+/// Success or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('add_word_to_dictionary', { word: 'gtdspace' });
+/// ```
+#[tauri::command]
+pub fn add_word_to_dictionary(app: AppHandle, word: String) -> Result<(), String> {
+    let word = word.trim();
+    if word.is_empty() || word.chars().any(char::is_whitespace) {
+        return Err("Dictionary words must be a single word".to_string());
+    }
+    if read_custom_words(&app)
+        .iter()
+        .any(|existing| existing == word)
+    {
+        return Ok(());
+    }
+
+    let dir = spellcheck_dir(&app)?;
+    fs::create_dir_all(&dir).map_err(|e| format!("Failed to create spell check folder: {}", e))?;
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(dir.join(CUSTOM_WORDS_FILE))
+        .map_err(|e| format!("Failed to open custom dictionary: {}", e))?;
+    writeln!(file, "{}", word).map_err(|e| format!("Failed to save word: {}", e))?;
+
+    let mut dictionaries = DICTIONARIES
+        .lock()
+        .map_err(|_| "Spell check dictionaries are unavailable".to_string())?;
+    for dictionary in dictionaries.values_mut() {
+        dictionary
+            .add(word)
+            .map_err(|e| format!("Failed to add word: {}", e))?;
+    }
+    log::info!("Added '{}' to the custom dictionary", word);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(text: &str) -> Vec<&str> {
+        checkable_words(text)
+            .into_iter()
+            .map(|span| &text[span.range])
+            .collect()
+    }
+
+    #[test]
+    fn checker_skips_code_urls_and_field_tags() {
+        let text = "---\ntitle: Frontmattr\n---\n# Plan the launch\n\n[!singleselect:status:in-progress]\nSee https://exampel.com/docs and `inlne` code, [a lnk](../Notes/Spec.md).\n```rust\nlet recieve = 1;\n```\nDon’t forget x.\n";
+        assert_eq!(
+            words(text),
+            vec!["Plan", "the", "launch", "See", "and", "code", "lnk", "Don’t", "forget"]
+        );
+
+        let spans = checkable_words(text);
+        assert_eq!(spans[0].line, 4);
+        assert_eq!(spans.last().map(|span| span.line), Some(11));
+    }
+
+    #[test]
+    fn bundled_dictionary_flags_misspellings() -> Result<(), String> {
+        let dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/dictionaries");
+        let aff = fs::read_to_string(dir.join("en_US.aff")).map_err(|e| e.to_string())?;
+        let dic = fs::read_to_string(dir.join("en_US.dic")).map_err(|e| e.to_string())?;
+        let dictionary = Dictionary::new(&aff, &dic).map_err(|e| e.to_string())?;
+
+        let text = "# Weekly review\n\nDon’t forget to recieve the `recieve` packages.\n";
+        let misspellings = find_misspellings(text, &dictionary);
+        assert_eq!(misspellings.len(), 1);
+        let misspelling = &misspellings[0];
+        assert_eq!(misspelling.word, "recieve");
+        assert_eq!(misspelling.line, 3);
+        let utf16: Vec<u16> = text.encode_utf16().collect();
+        assert_eq!(
+            String::from_utf16_lossy(&utf16[misspelling.start..misspelling.end]),
+            "recieve"
+        );
+        assert!(misspelling.suggestions.iter().any(|word| word == "receive"));
+        Ok(())
+    }
+
+    #[test]
+    fn languages_are_normalized() {
+        assert_eq!(normalize_language("en_us"), Ok("en-US".to_string()));
+        assert_eq!(normalize_language("DE"), Ok("de".to_string()));
+        assert!(normalize_language("../en").is_err());
+        assert!(normalize_language("en-US-x").is_err());
+    }
+}
@@ -0,0 +1,243 @@
+//! Standup notes: what got done yesterday, what is on today, what is stuck.
+//!
+//! Actions come from the metadata index, so "completed yesterday" means the
+//! action is completed and its file was last modified yesterday; the status
+//! marker itself carries no timestamp.
+
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::field_vocabulary::field_vocabulary_or_default;
+use super::file_meta::{indexed_file_meta, FileMeta};
+use super::focus::collect_focus_items;
+use super::gtd_habits_domain::{is_habit_history_archive, parse_history_rows, HabitStatus};
+
+/// Result of `generate_standup_notes`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StandupNotes {
+    /// Actions completed and last modified yesterday
+    pub yesterday_completed: Vec<String>,
+    /// Actions focused for today or in progress
+    pub today_in_progress: Vec<String>,
+    /// Actions waiting on someone else
+    pub blockers: Vec<String>,
+    /// Habits with a `Complete` history row today
+    pub habits_completed_today: Vec<String>,
+    /// The lists above as a plain-text standup script
+    pub formatted_text: String,
+}
+
+fn file_stem(relative: &str) -> String {
+    let file = relative.rsplit('/').next().unwrap_or(relative);
+    file.rsplit_once('.')
+        .map_or(file, |(stem, _)| stem)
+        .to_string()
+}
+
+fn local_date(unix_secs: u64) -> Option<NaiveDate> {
+    DateTime::from_timestamp(unix_secs as i64, 0)
+        .map(|time| time.with_timezone(&Local).date_naive())
+}
+
+fn sorted_unique(mut names: Vec<String>) -> Vec<String> {
+    names.sort_by_key(|name| name.to_lowercase());
+    names.dedup();
+    names
+}
+
+fn format_standup(notes: &StandupNotes) -> String {
+    let mut text = String::new();
+    for (heading, items) in [
+        ("Yesterday", &notes.yesterday_completed),
+        ("Today", &notes.today_in_progress),
+        ("Blockers", &notes.blockers),
+        ("Habits done today", &notes.habits_completed_today),
+    ] {
+        if !text.is_empty() {
+            text.push('\n');
+        }
+        text.push_str(heading);
+        text.push_str(":\n");
+        if items.is_empty() {
+            text.push_str("- None\n");
+        }
+        for item in items {
+            text.push_str(&format!("- {}\n", item));
+        }
+    }
+    text
+}
+
+/// Build standup notes for `today` from indexed metadata and habit history.
+fn build_standup_notes(
+    space_root: &Path,
+    entries: impl IntoIterator<Item = (String, FileMeta)>,
+    today: NaiveDate,
+) -> StandupNotes {
+    let vocabulary = field_vocabulary_or_default(space_root);
+    let yesterday = today - Duration::days(1);
+
+    let mut notes = StandupNotes {
+        today_in_progress: collect_focus_items(space_root, today)
+            .into_iter()
+            .filter(|item| item.focus_date == today.format("%Y-%m-%d").to_string())
+            .map(|item| item.name)
+            .collect(),
+        ..StandupNotes::default()
+    };
+
+    for (relative, meta) in entries {
+        match meta.kind.as_str() {
+            "action" => {
+                let status = meta
+                    .status
+                    .as_deref()
+                    .map(|status| vocabulary.builtin_status(status).to_ascii_lowercase())
+                    .unwrap_or_default();
+                match status.as_str() {
+                    "completed" | "complete" | "done"
+                        if local_date(meta.modified) == Some(yesterday) =>
+                    {
+                        notes.yesterday_completed.push(file_stem(&relative));
+                    }
+                    "in-progress" => notes.today_in_progress.push(file_stem(&relative)),
+                    "waiting" => notes.blockers.push(file_stem(&relative)),
+                    _ => {}
+                }
+            }
+            "habit" => {
+                let path = space_root.join(&relative);
+                if is_habit_history_archive(&path) {
+                    continue;
+                }
+                let Ok(content) = fs::read_to_string(&path) else {
+                    continue;
+                };
+                let done_today = parse_history_rows(&content).iter().any(|row| {
+                    row.timestamp.date() == today
+                        && row.status == HabitStatus::Completed.history_label()
+                });
+                if done_today {
+                    notes.habits_completed_today.push(file_stem(&relative));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    notes.yesterday_completed = sorted_unique(notes.yesterday_completed);
+    notes.today_in_progress = sorted_unique(notes.today_in_progress);
+    notes.blockers = sorted_unique(notes.blockers);
+    notes.habits_completed_today = sorted_unique(notes.habits_completed_today);
+    notes.formatted_text = format_standup(&notes);
+    notes
+}
+
+/// Summarize yesterday and today for a team standup
+///
+/// Lists actions completed yesterday (completed and last modified
+/// yesterday), actions focused for today or in progress, waiting actions as
+/// blockers, and habits completed today. `formatted_text` holds the same
+/// lists as a ready-to-paste script.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+///
+/// # Returns
+///
+/// Standup lists and formatted text, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const notes = await invoke<StandupNotes>('generate_standup_notes', {
+///   spacePath: '/path/to/space',
+/// });
+/// await navigator.clipboard.writeText(notes.formatted_text);
+/// ```
+#[tauri::command]
+pub fn generate_standup_notes(space_path: String) -> Result<StandupNotes, String> {
+    log::info!("Generating standup notes for: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    Ok(build_standup_notes(
+        space_root,
+        indexed_file_meta(space_root)?,
+        Local::now().date_naive(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn standup_notes_group_actions_and_habits() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let action = |status: &str, focus: &str| {
+            format!(
+                "# Action\n\n[!singleselect:status:{}]\n[!datetime:focus_date:{}]\n",
+                status, focus
+            )
+        };
+        write_test_file(project.join("Shipped.md"), &action("completed", ""))?;
+        write_test_file(project.join("Draft.md"), &action("in-progress", ""))?;
+        write_test_file(
+            project.join("Call.md"),
+            &action("waiting", "2026-03-10T09:00:00"),
+        )?;
+        write_test_file(
+            project.join("Plan.md"),
+            &action("waiting", "2026-03-10T09:00:00"),
+        )?;
+        write_test_file(
+            root.join("Habits/Stretch.md"),
+            "# Stretch\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n| 2026-03-10 | 8:00 AM | Complete | Manual | Changed from To Do to Complete |\n",
+        )?;
+        write_test_file(
+            root.join("Habits/Read.md"),
+            "# Read\n\n## History\n| Date | Time | Status | Action | Details |\n|------|------|--------|--------|---------|\n| 2026-03-09 | 8:00 AM | Complete | Manual | Changed from To Do to Complete |\n",
+        )?;
+
+        let today = NaiveDate::from_ymd_opt(2026, 3, 10).ok_or("invalid date")?;
+        let yesterday_noon = today
+            .pred_opt()
+            .and_then(|date| date.and_hms_opt(12, 0, 0))
+            .and_then(|time| time.and_local_timezone(Local).single())
+            .ok_or("invalid time")?
+            .timestamp() as u64;
+
+        let mut entries = indexed_file_meta(root)?;
+        for (relative, meta) in entries.iter_mut() {
+            if relative.ends_with("Shipped.md") {
+                meta.modified = yesterday_noon;
+            }
+        }
+        let notes = build_standup_notes(root, entries, today);
+
+        assert_eq!(notes.yesterday_completed, vec!["Shipped"]);
+        assert!(notes.today_in_progress.contains(&"Draft".to_string()));
+        assert!(notes.today_in_progress.contains(&"Call".to_string()));
+        assert_eq!(notes.blockers, vec!["Call", "Plan"]);
+        assert_eq!(notes.habits_completed_today, vec!["Stretch"]);
+        assert!(notes
+            .formatted_text
+            .starts_with("Yesterday:\n- Shipped\n\nToday:\n"));
+        assert!(notes
+            .formatted_text
+            .ends_with("Blockers:\n- Call\n- Plan\n\nHabits done today:\n- Stretch\n"));
+        Ok(())
+    }
+}
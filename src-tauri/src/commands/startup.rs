@@ -0,0 +1,270 @@
+//! One-call data load for app startup.
+//!
+//! The frontend used to issue half a dozen IPC calls one after another while
+//! the app opened. `startup_prefetch` runs them side by side instead and
+//! reports each result on its own, so one failing part doesn't hide the rest.
+
+use serde::Serialize;
+use std::path::Path;
+use std::time::Instant;
+use tauri::AppHandle;
+
+use super::file_meta::warm_meta_index;
+use super::filesystem::{list_markdown_files, MarkdownFile};
+use super::google_calendar_commands::google_calendar_get_status;
+use super::gtd_habits::check_and_reset_habits;
+use super::gtd_projects::{list_gtd_projects, GTDProject};
+use super::outline::get_space_outline;
+use super::settings::{load_settings, UserSettings};
+use super::workspace::check_is_gtd_space;
+use crate::google_calendar::SyncStatus;
+
+/// Result of one prefetched call: exactly one of `value` and `error` is set
+#[derive(Debug, Clone, Serialize)]
+pub struct PrefetchPart<T> {
+    pub value: Option<T>,
+    pub error: Option<String>,
+}
+
+impl<T> From<Result<T, String>> for PrefetchPart<T> {
+    fn from(result: Result<T, String>) -> Self {
+        match result {
+            Ok(value) => Self {
+                value: Some(value),
+                error: None,
+            },
+            Err(error) => Self {
+                value: None,
+                error: Some(error),
+            },
+        }
+    }
+}
+
+/// Space-specific startup data
+#[derive(Debug, Clone, Serialize)]
+pub struct SpacePrefetch {
+    /// `check_is_gtd_space`
+    pub is_gtd_space: PrefetchPart<bool>,
+    /// `list_gtd_projects`
+    pub projects: PrefetchPart<Vec<GTDProject>>,
+    /// `list_markdown_files` for the space root
+    pub files: PrefetchPart<Vec<MarkdownFile>>,
+    /// `check_and_reset_habits`: names of habits that were reset
+    pub reset_habits: PrefetchPart<Vec<String>>,
+}
+
+/// Everything the frontend needs to open, from `startup_prefetch`
+#[derive(Debug, Clone, Serialize)]
+pub struct StartupPrefetch {
+    /// Space the data was loaded for, if one was given or found in settings
+    pub space_path: Option<String>,
+    /// `load_settings`
+    pub settings: PrefetchPart<UserSettings>,
+    /// `google_calendar_get_status`
+    pub calendar_status: PrefetchPart<SyncStatus>,
+    /// Space data; `None` when no space was given or saved in settings
+    pub space: Option<SpacePrefetch>,
+    /// Wall-clock time the prefetch took
+    pub elapsed_ms: u64,
+}
+
+/// The blocking loaders behind `SpacePrefetch`
+struct SpaceLoaders {
+    check_is_gtd_space: fn(String) -> Result<bool, String>,
+    list_gtd_projects: fn(String) -> Result<Vec<GTDProject>, String>,
+    list_markdown_files: fn(String) -> Result<Vec<MarkdownFile>, String>,
+    check_and_reset_habits: fn(String) -> Result<Vec<String>, String>,
+}
+
+const SPACE_LOADERS: SpaceLoaders = SpaceLoaders {
+    check_is_gtd_space,
+    list_gtd_projects,
+    list_markdown_files,
+    check_and_reset_habits,
+};
+
+/// Run a blocking loader on the blocking pool.
+async fn run_blocking<T: Send + 'static>(
+    loader: fn(String) -> Result<T, String>,
+    space_path: String,
+) -> PrefetchPart<T> {
+    tokio::task::spawn_blocking(move || loader(space_path))
+        .await
+        .unwrap_or_else(|e| Err(format!("Startup task failed: {}", e)))
+        .into()
+}
+
+/// Load every part of `SpacePrefetch` at the same time.
+async fn prefetch_space(space_path: &str, loaders: &SpaceLoaders) -> SpacePrefetch {
+    let (is_gtd_space, projects, files, reset_habits) = tokio::join!(
+        run_blocking(loaders.check_is_gtd_space, space_path.to_string()),
+        run_blocking(loaders.list_gtd_projects, space_path.to_string()),
+        run_blocking(loaders.list_markdown_files, space_path.to_string()),
+        run_blocking(loaders.check_and_reset_habits, space_path.to_string()),
+    );
+    SpacePrefetch {
+        is_gtd_space,
+        projects,
+        files,
+        reset_habits,
+    }
+}
+
+fn saved_space_path(settings: &Result<UserSettings, String>) -> Option<String> {
+    let settings = settings.as_ref().ok()?;
+    settings
+        .default_space_path
+        .clone()
+        .or_else(|| settings.last_folder.clone())
+        .filter(|path| !path.trim().is_empty())
+}
+
+/// Build the outline cache and metadata index so the first sidebar render
+/// and file query after startup are served warm.
+fn warm_space_caches(space_path: String) {
+    tokio::spawn(async move {
+        if let Err(error) = get_space_outline(space_path.clone(), None).await {
+            log::warn!("Failed to warm outline for {}: {}", space_path, error);
+        }
+        let root = space_path.clone();
+        match tokio::task::spawn_blocking(move || warm_meta_index(Path::new(&root))).await {
+            Ok(Ok(())) => log::debug!("Warmed metadata index for {}", space_path),
+            Ok(Err(error)) => {
+                log::warn!(
+                    "Failed to warm metadata index for {}: {}",
+                    space_path,
+                    error
+                )
+            }
+            Err(error) => log::warn!("Metadata index warm-up task failed: {}", error),
+        }
+    });
+}
+
+/// Load the data the app needs at startup in one call
+///
+/// Runs `load_settings`, `check_is_gtd_space`, `list_gtd_projects`,
+/// `list_markdown_files`, `check_and_reset_habits`, and
+/// `google_calendar_get_status` concurrently. Each result is reported
+/// separately, so a failing part leaves the others usable. Once the space is
+/// known to be a GTD space, the outline cache and metadata index are built in
+/// the background.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `space_path` - GTD space to load; defaults to the space saved in settings
+///
+/// # Returns
+///
+/// Settings, calendar status, and space data, each with a value or an error
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const startup = await invoke<StartupPrefetch>('startup_prefetch', { spacePath: null });
+/// if (startup.space?.is_gtd_space.value) {
+///   setProjects(startup.space.projects.value ?? []);
+/// }
+/// ```
+#[tauri::command]
+pub async fn startup_prefetch(
+    app: AppHandle,
+    space_path: Option<String>,
+) -> Result<StartupPrefetch, String> {
+    let started = Instant::now();
+    let requested_space = space_path.filter(|path| !path.trim().is_empty());
+
+    // Settings only have to come first when they decide which space to load
+    let settings_and_space = async {
+        match requested_space {
+            Some(path) => {
+                let (settings, space) = tokio::join!(
+                    load_settings(app.clone()),
+                    prefetch_space(&path, &SPACE_LOADERS)
+                );
+                (settings, Some(path), Some(space))
+            }
+            None => {
+                let settings = load_settings(app.clone()).await;
+                let path = saved_space_path(&settings);
+                let space = match &path {
+                    Some(path) => Some(prefetch_space(path, &SPACE_LOADERS).await),
+                    None => None,
+                };
+                (settings, path, space)
+            }
+        }
+    };
+    let ((settings, space_path, space), calendar_status) =
+        tokio::join!(settings_and_space, google_calendar_get_status(app.clone()));
+
+    let elapsed_ms = started.elapsed().as_millis() as u64;
+    log::info!(
+        "Startup prefetch for {:?} finished in {}ms",
+        space_path,
+        elapsed_ms
+    );
+
+    if let (Some(path), Some(space)) = (&space_path, &space) {
+        if space.is_gtd_space.value == Some(true) {
+            warm_space_caches(path.clone());
+        }
+    }
+
+    Ok(StartupPrefetch {
+        space_path,
+        settings: settings.into(),
+        calendar_status: calendar_status.into(),
+        space,
+        elapsed_ms,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const DELAY: Duration = Duration::from_millis(200);
+
+    fn slow_habit_check(_: String) -> Result<Vec<String>, String> {
+        std::thread::sleep(DELAY);
+        Ok(vec!["Exercise".to_string()])
+    }
+
+    fn slow_project_list(_: String) -> Result<Vec<GTDProject>, String> {
+        std::thread::sleep(DELAY);
+        Err("Projects directory does not exist".to_string())
+    }
+
+    #[tokio::test]
+    async fn habit_check_and_project_listing_run_concurrently() {
+        let loaders = SpaceLoaders {
+            check_is_gtd_space: |_| Ok(true),
+            list_gtd_projects: slow_project_list,
+            list_markdown_files: |_| Ok(Vec::new()),
+            check_and_reset_habits: slow_habit_check,
+        };
+
+        let started = Instant::now();
+        let space = prefetch_space("/path/to/space", &loaders).await;
+        let elapsed = started.elapsed();
+
+        assert!(
+            elapsed < DELAY * 2,
+            "loaders ran serially: took {:?}",
+            elapsed
+        );
+        assert_eq!(space.reset_habits.value, Some(vec!["Exercise".to_string()]));
+        assert!(space.projects.value.is_none());
+        assert_eq!(
+            space.projects.error.as_deref(),
+            Some("Projects directory does not exist")
+        );
+        assert_eq!(space.is_gtd_space.value, Some(true));
+    }
+}
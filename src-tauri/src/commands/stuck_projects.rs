@@ -0,0 +1,133 @@
+//! "Stuck project" check for the weekly review: active projects that have no
+//! in-progress action to move them forward.
+
+use std::path::Path;
+
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary};
+use super::fields::{get_field, FieldKind};
+use super::filesystem::{list_project_actions, read_file};
+use super::gtd_projects::{list_gtd_projects, GTDProject};
+
+fn is_next_action(content: &str, vocabulary: &FieldVocabulary) -> bool {
+    get_field(content, FieldKind::SingleSelect, "status").is_some_and(|status| {
+        vocabulary
+            .builtin_status(&status)
+            .eq_ignore_ascii_case("in-progress")
+    })
+}
+
+fn has_next_action(project: &GTDProject, vocabulary: &FieldVocabulary) -> Result<bool, String> {
+    for action in list_project_actions(project.path.clone())? {
+        match read_file(action.path.clone()) {
+            Ok(content) if is_next_action(&content, vocabulary) => return Ok(true),
+            Ok(_) => {}
+            Err(error) => log::warn!("Skipping unreadable action {}: {}", action.path, error),
+        }
+    }
+    Ok(false)
+}
+
+pub(crate) fn find_projects_without_next_action(
+    space_path: String,
+) -> Result<Vec<GTDProject>, String> {
+    let vocabulary = field_vocabulary_or_default(Path::new(&space_path));
+    let mut stuck = Vec::new();
+    for project in list_gtd_projects(space_path)? {
+        if vocabulary.builtin_status(&project.status) == "completed" {
+            continue;
+        }
+        if !has_next_action(&project, &vocabulary)? {
+            stuck.push(project);
+        }
+    }
+    Ok(stuck)
+}
+
+/// List projects that have no next action
+///
+/// A project is stuck when it is not completed and none of its action files
+/// has `[!singleselect:status:in-progress]` (or a custom status mapped to it).
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// Stuck projects sorted by name, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const stuck = await invoke<GTDProject[]>('get_projects_without_next_action', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub async fn get_projects_without_next_action(
+    space_path: String,
+) -> Result<Vec<GTDProject>, String> {
+    log::info!("Finding projects without a next action in: {}", space_path);
+
+    let stuck = tokio::task::spawn_blocking(move || find_projects_without_next_action(space_path))
+        .await
+        .map_err(|e| format!("Failed to check projects for next actions: {}", e))??;
+
+    log::info!("Found {} projects without a next action", stuck.len());
+    Ok(stuck)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(status: &str) -> String {
+        format!(
+            "# Action\n\n## Status\n[!singleselect:status:{}]\n\n## Effort\n[!singleselect:effort:small]\n",
+            status
+        )
+    }
+
+    fn project(name: &str, status: &str) -> String {
+        format!(
+            "# {}\n\n## Status\n[!singleselect:project-status:{}]\n",
+            name, status
+        )
+    }
+
+    #[tokio::test]
+    async fn projects_without_in_progress_actions_are_stuck() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            root.join("Projects/Alpha Project/Wait on vendor.md"),
+            &action("waiting"),
+        )?;
+        write_test_file(
+            root.join("Projects/Beta/README.md"),
+            &project("Beta", "in-progress"),
+        )?;
+        write_test_file(
+            root.join("Projects/Beta/Call Sam.md"),
+            &action("in-progress"),
+        )?;
+        write_test_file(
+            root.join("Projects/Gamma/README.md"),
+            &project("Gamma", "completed"),
+        )?;
+        write_test_file(root.join("Projects/Gamma/Wrap up.md"), &action("completed"))?;
+        write_test_file(
+            root.join("Projects/Delta/README.md"),
+            &project("Delta", "waiting"),
+        )?;
+
+        let stuck = get_projects_without_next_action(root.to_string_lossy().to_string()).await?;
+        let names: Vec<&str> = stuck.iter().map(|project| project.name.as_str()).collect();
+        assert_eq!(names, vec!["Alpha Project", "Delta"]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,203 @@
+//! Inline `#tag` discovery across a space.
+//!
+//! A tag is a `#` followed by letters, digits, `_`, `-`, or `/`, standing alone
+//! as a whitespace-separated word. Markdown headings (`# Title`) and URL
+//! fragments never qualify.
+
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+use super::filesystem::{scan_directory_recursive, MarkdownFile};
+
+/// A markdown file containing a tag, with the lines that mention it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TaggedFile {
+    #[serde(flatten)]
+    pub file: MarkdownFile,
+    /// Trimmed lines containing the tag, in file order
+    pub matching_lines: Vec<String>,
+}
+
+/// A tag and how many files use it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TagSummary {
+    /// Tag name without the leading `#`
+    pub tag: String,
+    pub file_count: u32,
+}
+
+fn is_tag_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '/')
+}
+
+/// Tag names on one line, without the leading `#`.
+fn tags_in_line(line: &str) -> impl Iterator<Item = &str> {
+    line.split_whitespace().filter_map(|word| {
+        let name = word.strip_prefix('#')?;
+        let valid = !name.is_empty()
+            && name.chars().all(is_tag_char)
+            && name.chars().any(char::is_alphabetic);
+        valid.then_some(name)
+    })
+}
+
+fn markdown_files_in(space_path: &str) -> Result<Vec<MarkdownFile>, String> {
+    let space_root = Path::new(space_path);
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+
+    let mut files = Vec::new();
+    scan_directory_recursive(space_root, &mut files)?;
+    Ok(files)
+}
+
+/// Find every markdown file tagged with `#tag`
+///
+/// Matches the tag only as a standalone word, so `#work` does not match
+/// `#workshop` or `note#work`.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `tag` - Tag to search for, with or without the leading `#`
+///
+/// # Returns
+///
+/// Tagged files sorted by number of matching lines (most first), or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const files = await invoke<TaggedFile[]>('list_files_by_tag', {
+///   spacePath: '/path/to/space',
+///   tag: 'errands',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_files_by_tag(space_path: String, tag: String) -> Result<Vec<TaggedFile>, String> {
+    let tag = tag.trim().trim_start_matches('#');
+    if tag.is_empty() || !tag.chars().all(is_tag_char) {
+        return Err(format!("Invalid tag '{}'", tag));
+    }
+    log::info!("Listing files tagged #{} in: {}", tag, space_path);
+
+    let pattern = Regex::new(&format!(r"(?:^|\s)#{}(?:\s|$)", regex::escape(tag)))
+        .map_err(|e| format!("Invalid tag pattern: {}", e))?;
+
+    let mut tagged: Vec<TaggedFile> = markdown_files_in(&space_path)?
+        .into_iter()
+        .filter_map(|file| {
+            let content = fs::read_to_string(&file.path).ok()?;
+            let matching_lines: Vec<String> = content
+                .lines()
+                .filter(|line| pattern.is_match(line))
+                .map(|line| line.trim().to_string())
+                .collect();
+            (!matching_lines.is_empty()).then_some(TaggedFile {
+                file,
+                matching_lines,
+            })
+        })
+        .collect();
+
+    tagged.sort_by(|a, b| {
+        b.matching_lines
+            .len()
+            .cmp(&a.matching_lines.len())
+            .then_with(|| a.file.path.cmp(&b.file.path))
+    });
+    Ok(tagged)
+}
+
+/// List every inline tag used in a space
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+///
+/// # Returns
+///
+/// Tags with the number of files using each, most used first, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const tags = await invoke<TagSummary[]>('list_all_tags', {
+///   spacePath: '/path/to/space',
+/// });
+/// ```
+#[tauri::command]
+pub fn list_all_tags(space_path: String) -> Result<Vec<TagSummary>, String> {
+    log::info!("Listing tags in: {}", space_path);
+
+    let mut counts: HashMap<String, u32> = HashMap::new();
+    for file in markdown_files_in(&space_path)? {
+        let Ok(content) = fs::read_to_string(&file.path) else {
+            continue;
+        };
+        let mut file_tags: Vec<&str> = content.lines().flat_map(tags_in_line).collect();
+        file_tags.sort_unstable();
+        file_tags.dedup();
+        for tag in file_tags {
+            *counts.entry(tag.to_string()).or_default() += 1;
+        }
+    }
+
+    let mut summaries: Vec<TagSummary> = counts
+        .into_iter()
+        .map(|(tag, file_count)| TagSummary { tag, file_count })
+        .collect();
+    summaries.sort_by(|a, b| {
+        b.file_count
+            .cmp(&a.file_count)
+            .then_with(|| a.tag.cmp(&b.tag))
+    });
+    Ok(summaries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn tags_match_whole_words_only() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            root.join("Cabinet/Errands.md"),
+            "# Errands\n\nPick up keys #errands\n#errands #home\nSee https://example.com/#errands\n",
+        )?;
+        write_test_file(
+            root.join("Someday Maybe/Garden.md"),
+            "# Garden\n\nBuy seeds #errands #garden\n#errandsmore\n",
+        )?;
+
+        let space = root.to_string_lossy().to_string();
+        let tagged = list_files_by_tag(space.clone(), "#errands".to_string())?;
+        assert_eq!(tagged.len(), 2);
+        assert_eq!(tagged[0].file.name, "Errands.md");
+        assert_eq!(
+            tagged[0].matching_lines,
+            vec!["Pick up keys #errands", "#errands #home"]
+        );
+        assert_eq!(tagged[1].matching_lines, vec!["Buy seeds #errands #garden"]);
+
+        let tags = list_all_tags(space)?;
+        let errands = tags.iter().find(|summary| summary.tag == "errands");
+        assert_eq!(tags[0].tag, "errands");
+        assert_eq!(errands.map(|summary| summary.file_count), Some(2));
+        assert!(tags.iter().any(|summary| summary.tag == "errandsmore"));
+        assert!(!tags.iter().any(|summary| summary.tag.is_empty()));
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use std::path::Path;
+
 fn strip_markdown_suffixes(value: &str) -> String {
     let mut stripped = value.trim().to_string();
 
@@ -71,6 +73,20 @@ pub fn sanitize_markdown_file_stem(name: &str) -> String {
     }
 }
 
+/// Whether `path` has a `.md` or `.markdown` extension, in any case.
+pub(crate) fn is_markdown_path(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown"))
+}
+
+/// Whether `path` is a README, e.g. a project's `README.md`.
+pub(crate) fn is_readme_path(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem.eq_ignore_ascii_case("README"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::sanitize_markdown_file_stem;
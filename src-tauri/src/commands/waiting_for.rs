@@ -0,0 +1,365 @@
+//! Waiting-for tracking for delegated actions.
+//!
+//! When an action's status changes to waiting through `set_file_field`, a
+//! `[!datetime:waiting_since:...]` marker records when. Actions that were
+//! set to waiting some other way fall back to the file's modified time, and
+//! are flagged as estimates.
+
+use chrono::{DateTime, Local, NaiveDate, NaiveDateTime};
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+use super::field_vocabulary::{field_vocabulary_or_default, FieldVocabulary};
+use super::fields::{get_field, set_field, FieldKind};
+use super::project_velocity::ACTION_STATUS_REGEX;
+use super::space_config::{find_configured_space_root, load_space_config};
+
+/// Field key of the marker recording when an action started waiting
+pub(crate) const WAITING_SINCE_KEY: &str = "waiting_since";
+/// Days an action may wait before a follow-up is due, unless configured
+pub(crate) const DEFAULT_WAITING_THRESHOLD_DAYS: u32 = 7;
+
+/// `@name` tokens, not part of an email address
+static PERSON_TOKEN_REGEX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"(?:^|[\s(\[,;])@([A-Za-z][\w.-]*[\w])")
+        .expect("Invalid person token regex pattern")
+});
+
+/// A waiting action and how long it has waited
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitingItem {
+    /// Action file stem
+    pub name: String,
+    /// Full path to the action file
+    pub path: String,
+    /// Folder name of the owning project
+    pub project_name: String,
+    /// Date the action started waiting (YYYY-MM-DD)
+    pub waiting_since: String,
+    pub days_waiting: i64,
+    /// True when `waiting_since` comes from the file's modified time
+    pub is_estimate: bool,
+    /// `@person` tokens from the title or notes, without the `@`
+    pub waiting_on: Vec<String>,
+    /// Waited longer than the space's follow-up threshold
+    pub needs_follow_up: bool,
+}
+
+/// Follow-up threshold for the space containing `path`.
+pub(crate) fn waiting_threshold_days(path: &Path) -> u32 {
+    find_configured_space_root(path)
+        .and_then(|root| load_space_config(&root).ok())
+        .and_then(|config| config.waiting_threshold_days)
+        .unwrap_or(DEFAULT_WAITING_THRESHOLD_DAYS)
+}
+
+/// Start or clear the `waiting_since` marker after a status change.
+///
+/// Entering waiting stamps `now`, adding the marker below the status marker
+/// when the file has none. Leaving waiting clears the stamp. Staying in or
+/// out of waiting changes nothing.
+pub(crate) fn track_waiting_since(
+    content: &str,
+    was_waiting: bool,
+    is_waiting: bool,
+    now: NaiveDateTime,
+) -> Result<String, String> {
+    let has_marker = get_field(content, FieldKind::Datetime, WAITING_SINCE_KEY).is_some();
+    if was_waiting == is_waiting {
+        return Ok(content.to_string());
+    }
+    if !is_waiting {
+        return if has_marker {
+            set_field(content, FieldKind::Datetime, WAITING_SINCE_KEY, "")
+        } else {
+            Ok(content.to_string())
+        };
+    }
+
+    let stamp = now.format("%Y-%m-%dT%H:%M:%S").to_string();
+    if has_marker {
+        return set_field(content, FieldKind::Datetime, WAITING_SINCE_KEY, &stamp);
+    }
+    let section = format!(
+        "## Waiting Since\n[!datetime:{}:{}]",
+        WAITING_SINCE_KEY, stamp
+    );
+    match ACTION_STATUS_REGEX.find(content) {
+        Some(status) => {
+            let line_end = content[status.end()..]
+                .find('\n')
+                .map_or(content.len(), |offset| status.end() + offset);
+            Ok(format!(
+                "{}\n\n{}{}",
+                &content[..line_end],
+                section,
+                &content[line_end..]
+            ))
+        }
+        None => Ok(format!("{}\n\n{}\n", content.trim_end(), section)),
+    }
+}
+
+fn parse_waiting_since(value: &str) -> Option<NaiveDate> {
+    let value = value.trim();
+    if let Ok(datetime) = DateTime::parse_from_rfc3339(value) {
+        return Some(datetime.with_timezone(&Local).date_naive());
+    }
+    NaiveDate::parse_from_str(value.get(..10)?, "%Y-%m-%d").ok()
+}
+
+fn modified_date(path: &Path) -> Option<NaiveDate> {
+    let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok()?;
+    Some(DateTime::<Local>::from(modified).date_naive())
+}
+
+/// Notes section text, or nothing when the action has no `## Notes` heading.
+fn notes_section(content: &str) -> String {
+    content
+        .lines()
+        .skip_while(|line| !line.trim().eq_ignore_ascii_case("## notes"))
+        .skip(1)
+        .take_while(|line| !line.trim_start().starts_with('#') && line.trim() != "---")
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// `@person` tokens in order of first appearance.
+fn person_tokens(texts: &[&str]) -> Vec<String> {
+    let mut people: Vec<String> = Vec::new();
+    for text in texts {
+        for captures in PERSON_TOKEN_REGEX.captures_iter(text) {
+            let person = captures[1].to_string();
+            if !people
+                .iter()
+                .any(|known| known.eq_ignore_ascii_case(&person))
+            {
+                people.push(person);
+            }
+        }
+    }
+    people
+}
+
+fn waiting_item(
+    path: &Path,
+    project_name: &str,
+    today: NaiveDate,
+    threshold_days: u32,
+    vocabulary: &FieldVocabulary,
+) -> Option<WaitingItem> {
+    let content = fs::read_to_string(path).ok()?;
+    let status = ACTION_STATUS_REGEX
+        .captures(&content)
+        .and_then(|captures| captures.get(1))
+        .map(|value| value.as_str().trim())
+        .unwrap_or_default();
+    if vocabulary.builtin_status(status) != "waiting" {
+        return None;
+    }
+
+    let recorded = get_field(&content, FieldKind::Datetime, WAITING_SINCE_KEY)
+        .as_deref()
+        .and_then(parse_waiting_since);
+    let (since, is_estimate) = match recorded {
+        Some(since) => (since, false),
+        None => (modified_date(path).unwrap_or(today), true),
+    };
+    let days_waiting = (today - since).num_days().max(0);
+
+    let name = path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy()
+        .to_string();
+    let waiting_on = person_tokens(&[&name, &notes_section(&content)]);
+    Some(WaitingItem {
+        waiting_on,
+        name,
+        path: path.to_string_lossy().to_string(),
+        project_name: project_name.to_string(),
+        waiting_since: since.format("%Y-%m-%d").to_string(),
+        days_waiting,
+        is_estimate,
+        needs_follow_up: days_waiting > i64::from(threshold_days),
+    })
+}
+
+/// Waiting actions in every project, longest-waiting first.
+pub(crate) fn collect_waiting_items(
+    space_root: &Path,
+    today: NaiveDate,
+    threshold_days: u32,
+) -> Vec<WaitingItem> {
+    let Ok(projects) = fs::read_dir(space_root.join("Projects")) else {
+        return Vec::new();
+    };
+
+    let vocabulary = field_vocabulary_or_default(space_root);
+    let mut items = Vec::new();
+    for project in projects.flatten().map(|entry| entry.path()) {
+        let Ok(entries) = fs::read_dir(&project) else {
+            continue;
+        };
+        let project_name = project
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        for path in entries.flatten().map(|entry| entry.path()) {
+            let is_markdown = path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| {
+                    ext.eq_ignore_ascii_case("md") || ext.eq_ignore_ascii_case("markdown")
+                });
+            let is_readme = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .is_some_and(|stem| stem.eq_ignore_ascii_case("README"));
+            if path.is_file() && is_markdown && !is_readme {
+                items.extend(waiting_item(
+                    &path,
+                    &project_name,
+                    today,
+                    threshold_days,
+                    &vocabulary,
+                ));
+            }
+        }
+    }
+
+    items.sort_by(|a, b| {
+        b.days_waiting
+            .cmp(&a.days_waiting)
+            .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+    });
+    items
+}
+
+/// List actions waiting on someone else
+///
+/// Days waiting count from the action's `waiting_since` marker, or from the
+/// file's modified time (flagged with `is_estimate`) when the marker is
+/// missing. Items past the space's `waiting_threshold_days` (7 unless
+/// configured) are flagged with `needs_follow_up`.
+///
+/// # Arguments
+///
+/// * `space_path` - Root directory of the GTD space
+///
+/// # Returns
+///
+/// Waiting actions, longest-waiting first, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const items = await invoke<WaitingItem[]>('list_waiting_items', {
+///   spacePath: '/path/to/space',
+/// });
+/// const overdue = items.filter((item) => item.needs_follow_up);
+/// ```
+#[tauri::command]
+pub fn list_waiting_items(space_path: String) -> Result<Vec<WaitingItem>, String> {
+    log::info!("Listing waiting items in: {}", space_path);
+
+    let space_root = Path::new(&space_path);
+    if !space_root.is_dir() {
+        return Err(format!("Space directory does not exist: {}", space_path));
+    }
+    Ok(collect_waiting_items(
+        space_root,
+        Local::now().date_naive(),
+        waiting_threshold_days(space_root),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::fields::set_file_field;
+    use crate::commands::space_config::set_waiting_threshold_days;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    fn action(status: &str, notes: &str) -> String {
+        format!(
+            "# Action\n\n## Status\n[!singleselect:status:{}]\n\n## Notes\n{}\n\n---\n## Created\n[!datetime:created_date_time:2026-03-01T09:00:00]\n",
+            status, notes
+        )
+    }
+
+    #[test]
+    fn waiting_items_use_the_recorded_date_or_an_estimate() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let project = root.join("Projects/Alpha Project");
+        let space_path = root.to_string_lossy().to_string();
+        set_waiting_threshold_days(space_path.clone(), Some(3))?;
+
+        let quote = project.join("Quote from @dana.md");
+        write_test_file(
+            &quote,
+            &action("in-progress", "Asked @sam, cc ana@example.com"),
+        )?;
+        set_file_field(
+            quote.to_string_lossy().to_string(),
+            FieldKind::SingleSelect,
+            "status".to_string(),
+            "waiting".to_string(),
+        )?;
+        let content = fs::read_to_string(&quote).map_err(|e| e.to_string())?;
+        assert!(content.contains("[!singleselect:status:waiting]\n\n## Waiting Since\n"));
+
+        // Back-date the stamp so the item is past the threshold
+        let stamped = set_field(
+            &content,
+            FieldKind::Datetime,
+            WAITING_SINCE_KEY,
+            "2026-03-20T10:00:00",
+        )?;
+        write_test_file(&quote, &stamped)?;
+        write_test_file(project.join("Invoice.md"), &action("waiting", ""))?;
+        write_test_file(project.join("Draft.md"), &action("in-progress", ""))?;
+
+        let today = NaiveDate::from_ymd_opt(2026, 4, 1).expect("valid date");
+        let items = collect_waiting_items(root, today, waiting_threshold_days(root));
+        let summary: Vec<(&str, i64, bool, bool)> = items
+            .iter()
+            .map(|item| {
+                (
+                    item.name.as_str(),
+                    item.days_waiting,
+                    item.is_estimate,
+                    item.needs_follow_up,
+                )
+            })
+            .collect();
+        assert_eq!(summary[0], ("Quote from @dana", 12, false, true));
+        assert_eq!(items[0].waiting_on, vec!["dana", "sam"]);
+        assert_eq!(items[0].project_name, "Alpha Project");
+        assert_eq!(summary[1].0, "Invoice");
+        assert!(summary[1].2);
+        assert_eq!(summary.len(), 2);
+
+        set_file_field(
+            quote.to_string_lossy().to_string(),
+            FieldKind::SingleSelect,
+            "status".to_string(),
+            "completed".to_string(),
+        )?;
+        let content = fs::read_to_string(&quote).map_err(|e| e.to_string())?;
+        assert_eq!(
+            get_field(&content, FieldKind::Datetime, WAITING_SINCE_KEY).as_deref(),
+            Some("")
+        );
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@
 use notify_debouncer_mini::DebouncedEventKind;
 use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode};
 use serde::{Deserialize, Serialize};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::{self, RecvTimeoutError};
 use std::sync::Arc;
@@ -28,6 +28,7 @@ pub struct FileChangeEvent {
 struct RunningWatcher {
     handle: tokio::task::JoinHandle<()>,
     shutdown: Arc<AtomicBool>,
+    folder: PathBuf,
 }
 
 lazy_static::lazy_static! {
@@ -52,6 +53,23 @@ async fn shutdown_running_watcher(watcher_slot: &mut Option<RunningWatcher>) ->
     true
 }
 
+/// Whether a file watcher task is currently active.
+pub(crate) async fn is_file_watcher_running() -> bool {
+    let watcher_guard = WATCHER_HANDLE.lock().await;
+    watcher_guard
+        .as_ref()
+        .is_some_and(|watcher| !watcher.handle.is_finished())
+}
+
+/// Folder watched by the active file watcher, if any.
+pub(crate) async fn watched_folder() -> Option<PathBuf> {
+    let watcher_guard = WATCHER_HANDLE.lock().await;
+    watcher_guard
+        .as_ref()
+        .filter(|watcher| !watcher.handle.is_finished())
+        .map(|watcher| watcher.folder.clone())
+}
+
 /// Start file watching service for a folder
 ///
 /// Monitors the specified folder for changes to markdown files and emits
@@ -152,7 +170,11 @@ pub async fn start_file_watcher(app: AppHandle, folder_path: String) -> Result<S
     });
 
     // Store task handle
-    *watcher_guard = Some(RunningWatcher { handle, shutdown });
+    *watcher_guard = Some(RunningWatcher {
+        handle,
+        shutdown,
+        folder: path.to_path_buf(),
+    });
     drop(watcher_guard);
 
     log::info!("File watcher started successfully for: {}", folder_path);
@@ -201,6 +223,12 @@ pub async fn stop_file_watcher() -> Result<String, String> {
 ///
 /// Processes file change events and emits appropriate events to the frontend.
 fn handle_file_event(app: &AppHandle, path: &std::path::Path, _kind: &DebouncedEventKind) {
+    // Folder renames and deletions change the outline and metadata index too,
+    // so update them before the markdown-only filter
+    super::outline::invalidate_outline(path);
+    super::file_previews::invalidate_preview(path);
+    super::file_meta::record_file_change(path);
+
     // Only process markdown files
     if let Some(extension) = path.extension() {
         let ext_str = extension.to_string_lossy().to_lowercase();
@@ -211,6 +239,12 @@ fn handle_file_event(app: &AppHandle, path: &std::path::Path, _kind: &DebouncedE
         return;
     }
 
+    emit_file_changed(app, path, "modified");
+}
+
+/// Tell the frontend a markdown file changed, as the watcher does for
+/// external edits.
+pub(crate) fn emit_file_changed(app: &AppHandle, path: &std::path::Path, event_type: &str) {
     let file_path = path.to_string_lossy().to_string();
     let file_name = path
         .file_name()
@@ -218,10 +252,8 @@ fn handle_file_event(app: &AppHandle, path: &std::path::Path, _kind: &DebouncedE
         .to_string_lossy()
         .to_string();
 
-    let event_type = "modified".to_string();
-
     let change_event = FileChangeEvent {
-        event_type,
+        event_type: event_type.to_string(),
         file_path,
         file_name,
         timestamp: std::time::SystemTime::now()
@@ -1,23 +1,36 @@
 //! GTD workspace initialization and validation commands.
 
-use chrono::{Datelike, Timelike};
+use chrono::Datelike;
 use std::fs;
 use std::path::{Path, PathBuf};
 use tauri::AppHandle;
 
-use super::gtd_projects::{create_gtd_action, create_gtd_project};
 use super::seed_data::{
     areas_of_focus_overview_template, core_values_template,
     generate_area_of_focus_template_with_refs, generate_goal_template_with_refs,
-    generate_project_readme_with_refs, generate_vision_document_template_with_refs,
-    generate_weekly_review_habit, goals_overview_template, life_mission_template,
-    purpose_principles_overview_template, vision_overview_template, ProjectReadmeParams,
+    generate_vision_document_template_with_refs, goals_overview_template, life_mission_template,
+    purpose_principles_overview_template, vision_overview_template,
     CABINET_GTD_PRINCIPLES_TEMPLATE, SOMEDAY_LEARN_LANGUAGE_TEMPLATE, WELCOME_TEMPLATE,
 };
-use super::settings::{get_default_settings, load_settings};
+use super::seed_packs::{apply_seed_pack, default_seed_pack, load_seed_pack, SeedPack};
+use super::settings::{get_default_settings, load_settings, update_settings};
+use super::space_config::ensure_space_writable;
 
 const CABINET_REFERENCE_FILE_NAME: &str = "GTD Principles Reference.md";
 
+/// Directories a GTD space must contain
+const GTD_REQUIRED_DIRS: [&str; 1] = ["Projects"];
+/// Recognized horizon directories; a space needs at least three GTD directories in total
+const GTD_OPTIONAL_DIRS: [&str; 7] = [
+    "Areas of Focus",
+    "Goals",
+    "Vision",
+    "Purpose & Principles",
+    "Habits",
+    "Someday Maybe",
+    "Cabinet",
+];
+
 fn write_file_if_missing(path: &Path, content: &str, description: &str) -> Result<(), String> {
     if path.exists() {
         return Ok(());
@@ -106,16 +119,8 @@ pub fn check_is_gtd_space(path: String) -> Result<bool, String> {
 
     // Check for key GTD directories
     // Making Projects the only truly required directory
-    let required_dirs = ["Projects"];
-    let optional_dirs = [
-        "Areas of Focus",
-        "Goals",
-        "Vision",
-        "Purpose & Principles",
-        "Habits",
-        "Someday Maybe",
-        "Cabinet",
-    ];
+    let required_dirs = GTD_REQUIRED_DIRS;
+    let optional_dirs = GTD_OPTIONAL_DIRS;
 
     let mut required_found = 0;
     let mut missing_required = Vec::new();
@@ -178,6 +183,108 @@ pub fn check_is_gtd_space(path: String) -> Result<bool, String> {
     Ok(is_gtd_space)
 }
 
+/// Explain why `root_path` is not a GTD space, naming the missing directories.
+fn describe_missing_gtd_dirs(root_path: &Path) -> String {
+    let missing = |dirs: &[&'static str]| -> Vec<&'static str> {
+        dirs.iter()
+            .filter(|dir| !root_path.join(dir).is_dir())
+            .copied()
+            .collect()
+    };
+    let missing_required = missing(&GTD_REQUIRED_DIRS);
+    let missing_optional = missing(&GTD_OPTIONAL_DIRS);
+    let found = GTD_REQUIRED_DIRS.len() + GTD_OPTIONAL_DIRS.len()
+        - missing_required.len()
+        - missing_optional.len();
+
+    let mut reasons = Vec::new();
+    if !missing_required.is_empty() {
+        reasons.push(format!("missing {}", missing_required.join(", ")));
+    }
+    if found < 3 {
+        reasons.push(format!(
+            "only {} of the 3 required GTD directories present (add any of: {})",
+            found,
+            missing_optional.join(", ")
+        ));
+    }
+    format!(
+        "'{}' is not a GTD space: {}",
+        root_path.display(),
+        reasons.join("; ")
+    )
+}
+
+/// Resolve `new_path` to an absolute path and, if asked, check it is a GTD space.
+fn resolve_default_space_path(new_path: &str, validate: bool) -> Result<PathBuf, String> {
+    let trimmed = new_path.trim();
+    if trimmed.is_empty() {
+        return Err("Space path cannot be blank".to_string());
+    }
+
+    let path = Path::new(trimmed);
+    let resolved = if path.exists() {
+        fs::canonicalize(path).map_err(|e| format!("Failed to resolve path: {}", e))?
+    } else if validate {
+        return Err(format!(
+            "'{}' does not exist; run initialize_gtd_space first",
+            trimmed
+        ));
+    } else {
+        std::path::absolute(path).map_err(|e| format!("Failed to resolve path: {}", e))?
+    };
+
+    if validate && !check_is_gtd_space(resolved.to_string_lossy().to_string())? {
+        return Err(describe_missing_gtd_dirs(&resolved));
+    }
+    Ok(resolved)
+}
+
+/// Change the default GTD space path saved in user settings
+///
+/// The directory is not created here; use `initialize_gtd_space` for a new
+/// location.
+///
+/// # Arguments
+///
+/// * `app` - Tauri application handle
+/// * `new_path` - Path to use as the default space
+/// * `validate` - Require the path to already be a GTD space
+///
+/// # Returns
+///
+/// The normalized absolute path that was saved, or error details naming
+/// the missing GTD directories
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const saved = await invoke<string>('set_default_gtd_space', {
+///   newPath: '/Users/me/GTD Space',
+///   validate: true,
+/// });
+/// ```
+#[tauri::command]
+pub async fn set_default_gtd_space(
+    app: AppHandle,
+    new_path: String,
+    validate: bool,
+) -> Result<String, String> {
+    log::info!("Setting default GTD space: {}", new_path);
+
+    let resolved = resolve_default_space_path(&new_path, validate)?
+        .to_string_lossy()
+        .to_string();
+    update_settings(app, |settings| {
+        settings.default_space_path = Some(resolved.clone())
+    })
+    .await?;
+
+    Ok(resolved)
+}
+
 fn initialize_gtd_space_blocking(space_path: String) -> Result<String, String> {
     let trimmed_space_path = space_path.trim();
     if trimmed_space_path.is_empty() {
@@ -188,6 +295,7 @@ fn initialize_gtd_space_blocking(space_path: String) -> Result<String, String> {
     log::info!("Initializing GTD space at: {}", trimmed_space_path);
 
     let root_path = Path::new(trimmed_space_path);
+    ensure_space_writable(root_path)?;
 
     // Create root directory if it doesn't exist
     if !root_path.exists() {
@@ -424,15 +532,20 @@ pub async fn initialize_gtd_space(space_path: String) -> Result<String, String>
 
 /// Seed the GTD space with example projects and actions
 ///
-/// This creates a small set of demo projects and actions that showcase
-/// statuses, focus dates, due dates, and effort levels. If the Projects
-/// directory already contains subdirectories, seeding is skipped.
-fn seed_example_gtd_content_blocking(space_path: String) -> Result<String, String> {
+/// Without a pack this applies the built-in default pack: a small set of
+/// demo projects and actions that showcase statuses, focus dates, due dates,
+/// and effort levels. If the Projects directory already contains
+/// subdirectories, seeding is skipped.
+fn seed_example_gtd_content_blocking(
+    space_path: String,
+    pack_path: Option<String>,
+) -> Result<String, String> {
     let trimmed_space_path = space_path.trim();
     if trimmed_space_path.is_empty() {
         return Err("space_path cannot be blank".to_string());
     }
     let space_path = trimmed_space_path.to_string();
+    ensure_space_writable(Path::new(&space_path))?;
 
     let projects_root = Path::new(&space_path).join("Projects");
 
@@ -446,228 +559,72 @@ fn seed_example_gtd_content_blocking(space_path: String) -> Result<String, Strin
         return Ok("Example content already seeded".to_string());
     }
 
-    // Detect if any project directories already exist
-    let sample_project_name = "Launch Side Business";
-    let sample_project_path = projects_root.join(sample_project_name);
-    let sample_seed_complete_marker = sample_project_path.join(".seed_complete");
-    let sample_project_exists = sample_project_path.is_dir();
-    let mut has_non_sample_projects = false;
-    if let Ok(entries) = fs::read_dir(&projects_root) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if path.is_dir() && path != sample_project_path {
-                has_non_sample_projects = true;
-                break;
-            }
-        }
-    }
-
-    if has_non_sample_projects {
-        return Ok("Projects already exist; skipping example seeding".to_string());
-    }
-
-    if sample_project_exists && !sample_seed_complete_marker.exists() {
-        return Ok("Projects already exist; skipping example seeding".to_string());
-    }
-
-    // Helper to safely create a project and ignore "already exists" errors
-    fn ensure_project(
-        space_path: &str,
-        name: &str,
-        description: &str,
-        due_date: Option<String>,
-        status: Option<String>,
-    ) -> Result<String, String> {
-        match create_gtd_project(
-            space_path.to_string(),
-            name.to_string(),
-            description.to_string(),
-            due_date,
-            status,
-        ) {
-            Ok(path) => Ok(path),
-            Err(e) => {
-                // If it already exists, compute the expected path and return it
-                if e.contains("already exists") {
-                    Ok(Path::new(space_path)
-                        .join("Projects")
-                        .join(name)
-                        .to_string_lossy()
-                        .to_string())
-                } else {
-                    Err(e)
-                }
-            }
-        }
-    }
-
-    fn ensure_action(
-        project_path: &str,
-        action_name: &str,
-        status: &str,
-        due_date: Option<String>,
-        focus_date: Option<String>,
-        effort: &str,
-    ) -> Result<(), String> {
-        let action_path = Path::new(project_path).join(format!("{}.md", action_name));
-        if action_path.exists() {
-            return Ok(());
-        }
-
-        match create_gtd_action(
-            project_path.to_string(),
-            action_name.to_string(),
-            status.to_string(),
-            due_date,
-            focus_date,
-            effort.to_string(),
-            None,
-            None,
-        ) {
-            Ok(_) => Ok(()),
-            Err(error) if error.contains("already exists") => Ok(()),
-            Err(error) => Err(error),
-        }
-    }
-
-    // MINIMAL Project with MAXIMUM references (Project → Area + Goal)
-    let base_next_week = chrono::Local::now() + chrono::Duration::days(7);
-    let next_week = base_next_week
-        .with_hour(17)
-        .and_then(|dt| dt.with_minute(0))
-        .and_then(|dt| dt.with_second(0))
-        .unwrap_or(base_next_week);
-
-    let project_name = "Launch Side Business";
-    let project1_path = ensure_project(
-        &space_path,
-        project_name,
-        "Create and launch consulting business for passive income generation",
-        Some(next_week.to_rfc3339()),
-        Some("in-progress".to_string()),
-    )?;
-
-    if !sample_seed_complete_marker.exists() {
-        fs::write(&sample_seed_complete_marker, "in-progress")
-            .map_err(|e| format!("Failed to claim sample seed ownership: {}", e))?;
-    }
-
-    // Update with references to BOTH Area and Goal
-    let space_root = Path::new(&space_path);
-    let areas_ref = existing_reference(
-        space_root
-            .join("Areas of Focus")
-            .join("Professional Excellence.md"),
-    );
-    let goals_ref = existing_reference(space_root.join("Goals").join("Build Financial Freedom.md"));
-    let vision_ref = existing_reference(space_root.join("Vision").join("My 3-5 Year Vision.md"));
-    let purpose_ref = existing_reference(
-        space_root
-            .join("Purpose & Principles")
-            .join("Core Values.md"),
-    );
-    let cabinet_ref =
-        existing_reference(space_root.join("Cabinet").join(CABINET_REFERENCE_FILE_NAME));
-
-    let readme_path = Path::new(&project1_path).join("README.md");
-    let readme_params = ProjectReadmeParams {
-        name: project_name,
-        description: "Create and launch consulting business for passive income generation",
-        due_date: Some(next_week.to_rfc3339()),
-        status: "in-progress",
-        areas_refs: &areas_ref,     // References Area
-        goals_refs: &goals_ref,     // References Goal
-        vision_refs: &vision_ref,   // References Vision
-        purpose_refs: &purpose_ref, // References Purpose & Principles
-        general_refs: &cabinet_ref, // References Cabinet
+    let pack = match pack_path.filter(|path| !path.trim().is_empty()) {
+        Some(pack_path) => load_seed_pack(Path::new(&pack_path))?,
+        None => default_seed_pack(),
     };
-    let readme_content = generate_project_readme_with_refs(readme_params);
-    fs::write(&readme_path, readme_content)
-        .map_err(|e| format!("Failed to update seeded project README: {}", e))?;
-
-    // Just 2 simple actions
-    ensure_action(
-        &project1_path,
-        "Define service offerings",
-        "in-progress",
-        None,
-        Some(chrono::Local::now().to_rfc3339()),
-        "medium",
-    )?;
-
-    ensure_action(
-        &project1_path,
-        "Create landing page",
-        "waiting",
-        Some(next_week.to_rfc3339()),
-        None,
-        "large",
-    )?;
-
-    // That's it - just ONE project with maximum connections!
-
-    // Create just ONE example habit
-    let habits_dir = Path::new(&space_path).join("Habits");
-    if habits_dir.exists() {
-        let weekly_review = habits_dir.join("Weekly GTD Review.md");
-        if !weekly_review.exists() {
-            let content = generate_weekly_review_habit();
-            fs::write(&weekly_review, content)
-                .map_err(|e| format!("Failed to create weekly review habit: {}", e))?;
-        }
-    }
-
-    // Create just ONE Someday Maybe example
-    let someday_dir = Path::new(&space_path).join("Someday Maybe");
-    if someday_dir.exists() {
-        let someday_example = someday_dir.join("Write a Book.md");
-        if !someday_example.exists() {
-            let content = r#"# Write a Book
-
-**Topic**: Practical guide to building sustainable business systems
-
-## When I'm ready:
-- [ ] Outline key chapters
-- [ ] Research publishers vs self-publishing
-- [ ] Build audience platform first
-- [ ] Dedicate 2 hours daily to writing
-
-*Will support my Financial Freedom goal when activated*
-"#;
-            fs::write(&someday_example, content)
-                .map_err(|e| format!("Failed to create Someday Maybe example: {}", e))?;
-        }
-    }
+    seed_from_pack(Path::new(&space_path), &projects_root, &seed_marker, &pack)
+}
 
-    // Create just ONE Cabinet reference (that the project references)
-    let cabinet_dir = Path::new(&space_path).join("Cabinet");
-    if cabinet_dir.exists() {
-        let gtd_ref = cabinet_dir.join(CABINET_REFERENCE_FILE_NAME);
-        if !gtd_ref.exists() {
-            fs::write(&gtd_ref, CABINET_GTD_PRINCIPLES_TEMPLATE)
-                .map_err(|e| format!("Failed to create GTD Principles Reference: {}", e))?;
-        }
+/// Apply a seed pack unless the space already has projects.
+fn seed_from_pack(
+    space_root: &Path,
+    projects_root: &Path,
+    seed_marker: &Path,
+    pack: &SeedPack,
+) -> Result<String, String> {
+    let has_projects = fs::read_dir(projects_root)
+        .map(|entries| entries.flatten().any(|entry| entry.path().is_dir()))
+        .unwrap_or(false);
+    if has_projects {
+        return Ok("Projects already exist; skipping example seeding".to_string());
     }
 
-    // Note: Horizons are now created as top-level folders during initialization
-    // No need to recreate them here
-
-    // Habits already created above - removed duplicates
-
-    fs::write(&sample_seed_complete_marker, "complete")
-        .map_err(|e| format!("Failed to write sample seed marker: {}", e))?;
+    let written = apply_seed_pack(pack, space_root)?;
     fs::write(
-        &seed_marker,
-        format!("seeded: {}", chrono::Local::now().to_rfc3339()),
+        seed_marker,
+        format!(
+            "seeded: {} ({})",
+            chrono::Local::now().to_rfc3339(),
+            pack.manifest.name
+        ),
     )
     .map_err(|e| format!("Failed to write seed marker: {}", e))?;
 
-    Ok("Seeded example projects, actions, horizons, habits, and reference materials".to_string())
+    Ok(format!(
+        "Seeded {} files from pack '{}'",
+        written, pack.manifest.name
+    ))
 }
 
+/// Seed example content into a GTD space
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the GTD space
+/// * `pack_path` - Optional seed pack (`seed-pack.json` or its folder); the
+///   built-in example content is used when omitted
+///
+/// # Returns
+///
+/// Summary message, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('seed_example_gtd_content', {
+///   spacePath: '/path/to/space',
+///   packPath: '/path/to/packs/team-starter',
+/// });
+/// ```
 #[tauri::command]
-pub async fn seed_example_gtd_content(space_path: String) -> Result<String, String> {
-    tokio::task::spawn_blocking(move || seed_example_gtd_content_blocking(space_path))
+pub async fn seed_example_gtd_content(
+    space_path: String,
+    pack_path: Option<String>,
+) -> Result<String, String> {
+    tokio::task::spawn_blocking(move || seed_example_gtd_content_blocking(space_path, pack_path))
         .await
         .map_err(|error| format!("Failed to seed example GTD content: {}", error))?
 }
@@ -699,7 +656,7 @@ pub async fn initialize_default_gtd_space(app: AppHandle) -> Result<String, Stri
 
     // Seed content if enabled
     if settings.seed_example_content.unwrap_or(true) {
-        seed_example_gtd_content(target_path.clone()).await?;
+        seed_example_gtd_content(target_path.clone(), None).await?;
     }
 
     Ok(target_path)
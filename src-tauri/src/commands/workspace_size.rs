@@ -0,0 +1,149 @@
+//! Workspace size statistics used to warn before a space gets slow.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use walkdir::WalkDir;
+
+use super::filesystem::{file_entry, MarkdownFile};
+
+/// File count above which the workspace is flagged as large
+const LARGE_WORKSPACE_FILE_COUNT: u32 = 1000;
+
+/// Size statistics for a workspace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceSize {
+    /// Files of every type, excluding `.git`
+    pub total_files: u32,
+    /// Directories below the workspace root, excluding `.git`
+    pub total_directories: u32,
+    pub total_bytes: u64,
+    pub largest_file: Option<MarkdownFile>,
+    /// Path components of the deepest entry relative to the root
+    pub deepest_path_depth: u32,
+    /// Set when the workspace is large enough to slow the app down
+    pub warning: Option<String>,
+}
+
+fn size_warning(total_files: u32) -> Option<String> {
+    (total_files > LARGE_WORKSPACE_FILE_COUNT).then(|| {
+        format!(
+            "Workspace exceeds {} files; consider archiving completed projects",
+            LARGE_WORKSPACE_FILE_COUNT
+        )
+    })
+}
+
+/// Measure the size of a workspace
+///
+/// Counts files of every type, not just markdown. Git metadata (`.git`) is
+/// skipped since it is not workspace content.
+///
+/// # Arguments
+///
+/// * `space_path` - Root path of the GTD space
+///
+/// # Returns
+///
+/// File, directory, and byte totals plus a warning for large workspaces
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const size = await invoke<WorkspaceSize>('get_workspace_size', {
+///   spacePath: '/path/to/space',
+/// });
+/// if (size.warning) console.warn(size.warning);
+/// ```
+#[tauri::command]
+pub fn get_workspace_size(space_path: String) -> Result<WorkspaceSize, String> {
+    log::info!("Measuring workspace size: {}", space_path);
+
+    let root = Path::new(&space_path);
+    if !root.is_dir() {
+        return Err("Directory does not exist".to_string());
+    }
+
+    let mut size = WorkspaceSize {
+        total_files: 0,
+        total_directories: 0,
+        total_bytes: 0,
+        largest_file: None,
+        deepest_path_depth: 0,
+        warning: None,
+    };
+    let entries = WalkDir::new(root)
+        .min_depth(1)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git");
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to walk workspace: {}", e))?;
+        size.deepest_path_depth = size.deepest_path_depth.max(entry.depth() as u32);
+
+        if entry.file_type().is_dir() {
+            size.total_directories += 1;
+            continue;
+        }
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let metadata = entry
+            .metadata()
+            .map_err(|e| format!("Failed to read {}: {}", entry.path().display(), e))?;
+        size.total_files += 1;
+        size.total_bytes += metadata.len();
+        if size
+            .largest_file
+            .as_ref()
+            .is_none_or(|largest| metadata.len() > largest.size)
+        {
+            size.largest_file = Some(file_entry(entry.path(), &metadata));
+        }
+    }
+    size.warning = size_warning(size.total_files);
+
+    log::info!(
+        "Workspace has {} files in {} directories ({} bytes)",
+        size.total_files,
+        size.total_directories,
+        size.total_bytes
+    );
+    Ok(size)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[test]
+    fn workspace_size_counts_all_files_outside_git() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        write_test_file(
+            root.join("Cabinet/Scans/2026/receipt.pdf"),
+            &"x".repeat(4096),
+        )?;
+        write_test_file(root.join(".git/objects/ab/cdef"), &"x".repeat(8192))?;
+
+        let size = get_workspace_size(root.to_string_lossy().to_string())?;
+        // Fitness.md, Alpha README.md, receipt.pdf
+        assert_eq!(size.total_files, 3);
+        // Projects, Habits, Cabinet, Someday Maybe, Goals, Alpha Project, Scans, 2026
+        assert_eq!(size.total_directories, 8);
+        assert_eq!(size.deepest_path_depth, 4);
+        let largest = size.largest_file.expect("largest file");
+        assert_eq!(largest.name, "receipt.pdf");
+        assert_eq!(largest.extension, ".pdf");
+        assert!(size.total_bytes > 4096);
+        assert!(size.warning.is_none());
+
+        assert!(size_warning(LARGE_WORKSPACE_FILE_COUNT).is_none());
+        assert!(size_warning(LARGE_WORKSPACE_FILE_COUNT + 1)
+            .is_some_and(|warning| warning.contains("consider archiving")));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,449 @@
+//! Point-in-time copies of a space for users who do not use git sync.
+//!
+//! Each snapshot is a plain folder named after its local creation time,
+//! `{snapshot_dir}/YYYY-MM-DDTHH-MM-SS/`, holding an unencrypted copy of the
+//! space. Rebuildable internal data in `.gtdspace` is left out, as when
+//! duplicating a space.
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::pre_op_backups::backup_before_bulk_operation;
+use super::space_config::{ensure_space_writable, SPACE_CONFIG_DIR};
+use super::space_copy::{copy_tree, is_internal_entry, SpaceDuplicateProgress};
+use super::space_walk::{walk_files, WalkOptions};
+
+/// Folder name format of a snapshot
+const SNAPSHOT_NAME_FORMAT: &str = "%Y-%m-%dT%H-%M-%S";
+
+/// Result of taking a snapshot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotResult {
+    /// Absolute path of the new snapshot folder
+    pub snapshot_path: String,
+    pub files_copied: u32,
+    pub bytes_copied: u64,
+}
+
+/// A snapshot found in a snapshot folder
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SnapshotInfo {
+    /// Folder name, e.g. `2026-03-02T09-15-00`
+    pub name: String,
+    pub snapshot_path: String,
+    /// RFC 3339 creation time
+    pub created_at: String,
+    pub file_count: u32,
+    /// Total size of the snapshot's files in bytes
+    pub size: u64,
+}
+
+/// Creation time encoded in a snapshot folder name. Names may carry a
+/// `-N` suffix when several snapshots were taken in the same second.
+fn parse_snapshot_name(name: &str) -> Option<DateTime<Local>> {
+    let stamp = name.get(..19)?;
+    let naive = NaiveDateTime::parse_from_str(stamp, SNAPSHOT_NAME_FORMAT).ok()?;
+    Local.from_local_datetime(&naive).earliest()
+}
+
+/// First unused snapshot folder for `now` inside `snapshot_dir`
+fn next_snapshot_path(snapshot_dir: &Path, now: DateTime<Local>) -> PathBuf {
+    let stamp = now.format(SNAPSHOT_NAME_FORMAT).to_string();
+    let mut candidate = snapshot_dir.join(&stamp);
+    let mut suffix = 2;
+    while candidate.exists() {
+        candidate = snapshot_dir.join(format!("{}-{}", stamp, suffix));
+        suffix += 1;
+    }
+    candidate
+}
+
+/// Copy `space_root` into a new timestamped folder under `snapshot_dir`.
+fn take_snapshot(space_root: &Path, snapshot_dir: &Path) -> Result<SnapshotResult, String> {
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+    let space_root =
+        fs::canonicalize(space_root).map_err(|e| format!("Failed to resolve space path: {}", e))?;
+    fs::create_dir_all(snapshot_dir)
+        .map_err(|e| format!("Failed to create snapshot folder: {}", e))?;
+    let snapshot_dir = fs::canonicalize(snapshot_dir)
+        .map_err(|e| format!("Failed to resolve snapshot folder: {}", e))?;
+    if snapshot_dir.starts_with(&space_root) {
+        return Err("Snapshot folder cannot be inside the space".to_string());
+    }
+
+    let target = next_snapshot_path(&snapshot_dir, Local::now());
+    fs::create_dir(&target).map_err(|e| format!("Failed to create snapshot: {}", e))?;
+
+    let mut progress = SpaceDuplicateProgress {
+        files_copied: 0,
+        bytes_copied: 0,
+        current_path: String::new(),
+    };
+    if let Err(error) = copy_tree(&space_root, &target, &[], false, &mut progress, |_| {}) {
+        if let Err(cleanup_error) = fs::remove_dir_all(&target) {
+            log::error!(
+                "Failed to remove partial snapshot {}: {}",
+                target.display(),
+                cleanup_error
+            );
+        }
+        return Err(error);
+    }
+
+    Ok(SnapshotResult {
+        snapshot_path: target.to_string_lossy().to_string(),
+        files_copied: progress.files_copied as u32,
+        bytes_copied: progress.bytes_copied,
+    })
+}
+
+/// Remove everything in the space except rebuildable internal data.
+fn clear_space_contents(space_root: &Path) -> Result<(), String> {
+    let remove = |path: &Path| {
+        let result = if path.is_dir() && !path.is_symlink() {
+            fs::remove_dir_all(path)
+        } else {
+            fs::remove_file(path)
+        };
+        result.map_err(|e| format!("Failed to remove {}: {}", path.display(), e))
+    };
+
+    let entries =
+        fs::read_dir(space_root).map_err(|e| format!("Failed to read space folder: {}", e))?;
+    for entry in entries {
+        let path = entry
+            .map_err(|e| format!("Failed to read space folder: {}", e))?
+            .path();
+        if path
+            .file_name()
+            .is_some_and(|name| name == SPACE_CONFIG_DIR)
+            && path.is_dir()
+        {
+            let config_entries = fs::read_dir(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            for config_entry in config_entries {
+                let config_path = config_entry
+                    .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+                    .path();
+                let relative = config_path
+                    .strip_prefix(space_root)
+                    .map_err(|e| e.to_string())?;
+                if !is_internal_entry(relative) {
+                    remove(&config_path)?;
+                }
+            }
+        } else {
+            remove(&path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Snapshot the current space, then replace its contents with `snapshot`.
+/// Returns the path of the snapshot of the replaced contents.
+fn restore_from_snapshot(
+    snapshot: &Path,
+    space_root: &Path,
+    skip_backup: bool,
+) -> Result<String, String> {
+    if !snapshot.is_dir() {
+        return Err(format!("Snapshot does not exist: {}", snapshot.display()));
+    }
+    let is_snapshot_folder = snapshot
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| parse_snapshot_name(name).is_some());
+    if !is_snapshot_folder {
+        return Err(format!("Not a snapshot folder: {}", snapshot.display()));
+    }
+    if !space_root.is_dir() {
+        return Err("Space directory does not exist".to_string());
+    }
+    ensure_space_writable(space_root)?;
+    let snapshot =
+        fs::canonicalize(snapshot).map_err(|e| format!("Failed to resolve snapshot: {}", e))?;
+    let space_root =
+        fs::canonicalize(space_root).map_err(|e| format!("Failed to resolve space path: {}", e))?;
+    if snapshot.starts_with(&space_root) || space_root.starts_with(&snapshot) {
+        return Err("Snapshot and space must not contain each other".to_string());
+    }
+    let snapshot_dir = snapshot
+        .parent()
+        .ok_or_else(|| "Snapshot has no parent folder".to_string())?;
+
+    let safety = take_snapshot(&space_root, snapshot_dir)?;
+    log::info!(
+        "Saved current contents to {} before restoring",
+        safety.snapshot_path
+    );
+    let (files, _) = walk_files(&space_root, WalkOptions::default())?;
+    let files: Vec<PathBuf> = files
+        .into_iter()
+        .filter(|path| {
+            path.strip_prefix(&space_root)
+                .is_ok_and(|relative| !is_internal_entry(relative))
+        })
+        .collect();
+    backup_before_bulk_operation(
+        &space_root,
+        "restore_snapshot",
+        &uuid::Uuid::new_v4().to_string(),
+        &files,
+        skip_backup,
+    )?;
+
+    clear_space_contents(&space_root)?;
+    let mut progress = SpaceDuplicateProgress {
+        files_copied: 0,
+        bytes_copied: 0,
+        current_path: String::new(),
+    };
+    copy_tree(&snapshot, &space_root, &[], false, &mut progress, |_| {}).map_err(|error| {
+        format!(
+            "Restore failed partway ({}); previous contents are in {}",
+            error, safety.snapshot_path
+        )
+    })?;
+
+    log::info!(
+        "Restored {} files from {}",
+        progress.files_copied,
+        snapshot.display()
+    );
+    Ok(safety.snapshot_path)
+}
+
+/// Take a point-in-time snapshot of a space
+///
+/// Copies the space into a new `YYYY-MM-DDTHH-MM-SS` folder inside
+/// `snapshot_dir`. Snapshots are not encrypted; use git sync for encrypted
+/// backups.
+///
+/// # Arguments
+///
+/// * `space_path` - Root of the space to snapshot
+/// * `snapshot_dir` - Folder that holds snapshots; must be outside the space
+///
+/// # Returns
+///
+/// The snapshot path and copy totals, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const snapshot = await invoke<SnapshotResult>('snapshot_workspace', {
+///   spacePath: '/Users/me/GTD Space',
+///   snapshotDir: '/Users/me/GTD Snapshots',
+/// });
+/// ```
+#[tauri::command]
+pub async fn snapshot_workspace(
+    space_path: String,
+    snapshot_dir: String,
+) -> Result<SnapshotResult, String> {
+    log::info!("Snapshotting space {} into {}", space_path, snapshot_dir);
+
+    tokio::task::spawn_blocking(move || {
+        take_snapshot(Path::new(&space_path), Path::new(snapshot_dir.trim()))
+    })
+    .await
+    .map_err(|error| format!("Snapshot task failed: {}", error))?
+}
+
+/// List the snapshots in a snapshot folder, newest first
+///
+/// Folders whose names are not snapshot timestamps are ignored.
+///
+/// # Arguments
+///
+/// * `snapshot_dir` - Folder that holds snapshots
+///
+/// # Returns
+///
+/// Snapshot details, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const snapshots = await invoke<SnapshotInfo[]>('list_snapshots', {
+///   snapshotDir: '/Users/me/GTD Snapshots',
+/// });
+/// ```
+#[tauri::command]
+pub async fn list_snapshots(snapshot_dir: String) -> Result<Vec<SnapshotInfo>, String> {
+    tokio::task::spawn_blocking(move || {
+        let dir = Path::new(&snapshot_dir);
+        if !dir.exists() {
+            return Ok(Vec::new());
+        }
+        let entries =
+            fs::read_dir(dir).map_err(|e| format!("Failed to read snapshot folder: {}", e))?;
+
+        let mut snapshots = Vec::new();
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let Some(created_at) = parse_snapshot_name(&name) else {
+                continue;
+            };
+            if !path.is_dir() {
+                continue;
+            }
+            let (files, _) = walk_files(&path, WalkOptions::default())?;
+            let size = files
+                .iter()
+                .filter_map(|file| fs::metadata(file).ok())
+                .map(|metadata| metadata.len())
+                .sum();
+            snapshots.push(SnapshotInfo {
+                name,
+                snapshot_path: path.to_string_lossy().to_string(),
+                created_at: created_at.to_rfc3339(),
+                file_count: files.len() as u32,
+                size,
+            });
+        }
+        snapshots.sort_by(|a, b| b.name.cmp(&a.name));
+        Ok(snapshots)
+    })
+    .await
+    .map_err(|error| format!("Snapshot listing task failed: {}", error))?
+}
+
+/// Replace a space's contents with a snapshot
+///
+/// `snapshot_path` must be a folder created by `snapshot_workspace`, named
+/// with its timestamp. The current contents are snapshotted next to it first,
+/// so a restore can itself be undone, and also backed up to
+/// `.gtdspace/pre-op-backups` when there are more files than the space's
+/// backup threshold. Rebuildable internal data in `.gtdspace` is kept.
+///
+/// # Arguments
+///
+/// * `snapshot_path` - Snapshot folder to restore
+/// * `workspace_path` - Root of the space to overwrite
+/// * `skip_backup` - Skip the automatic pre-operation backup
+///
+/// # Returns
+///
+/// Path of the snapshot holding the replaced contents, or error details
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const previous = await invoke<string>('restore_snapshot', {
+///   snapshotPath: '/Users/me/GTD Snapshots/2026-03-02T09-15-00',
+///   workspacePath: '/Users/me/GTD Space',
+/// });
+/// ```
+#[tauri::command]
+pub async fn restore_snapshot(
+    snapshot_path: String,
+    workspace_path: String,
+    skip_backup: Option<bool>,
+) -> Result<String, String> {
+    log::info!(
+        "Restoring snapshot {} into {}",
+        snapshot_path,
+        workspace_path
+    );
+
+    tokio::task::spawn_blocking(move || {
+        restore_from_snapshot(
+            Path::new(&snapshot_path),
+            Path::new(&workspace_path),
+            skip_backup.unwrap_or(false),
+        )
+    })
+    .await
+    .map_err(|error| format!("Snapshot restore task failed: {}", error))?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::pre_op_backups::list_pre_op_backups;
+    use crate::test_utils::{seed_test_workspace, write_test_file};
+
+    #[tokio::test]
+    async fn snapshots_round_trip_and_keep_replaced_contents() -> Result<(), String> {
+        let workspace = seed_test_workspace()?;
+        let root = workspace.path();
+        let note = root.join("Cabinet/Note.md");
+        write_test_file(&note, "original\n")?;
+        write_test_file(root.join(".gtdspace/meta.json"), "{}")?;
+        let snapshots = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let snapshot_dir = snapshots.path().to_string_lossy().to_string();
+        let space_path = root.to_string_lossy().to_string();
+
+        let snapshot = snapshot_workspace(space_path.clone(), snapshot_dir.clone()).await?;
+        let snapshot_root = Path::new(&snapshot.snapshot_path);
+        assert!(snapshot.files_copied > 0);
+        assert!(snapshot_root.join("Cabinet/Note.md").exists());
+        assert!(!snapshot_root.join(".gtdspace/meta.json").exists());
+        assert!(snapshot_workspace(
+            space_path.clone(),
+            root.join("Snaps").to_string_lossy().to_string()
+        )
+        .await
+        .is_err());
+
+        write_test_file(&note, "edited\n")?;
+        write_test_file(root.join("Cabinet/New.md"), "new\n")?;
+        for index in 0..10 {
+            write_test_file(root.join(format!("Cabinet/Draft {}.md", index)), "draft\n")?;
+        }
+        let not_a_snapshot = snapshots.path().join("Old space");
+        write_test_file(not_a_snapshot.join("Cabinet/Note.md"), "stale\n")?;
+        assert!(restore_snapshot(
+            not_a_snapshot.to_string_lossy().to_string(),
+            space_path.clone(),
+            None
+        )
+        .await
+        .is_err());
+        assert!(root.join("Cabinet/New.md").exists());
+        assert_eq!(
+            fs::read_dir(snapshots.path())
+                .map_err(|e| e.to_string())?
+                .count(),
+            2
+        );
+        let previous = restore_snapshot(snapshot.snapshot_path.clone(), space_path, None).await?;
+
+        assert_eq!(
+            fs::read_to_string(&note).map_err(|e| e.to_string())?,
+            "original\n"
+        );
+        assert!(!root.join("Cabinet/New.md").exists());
+        assert!(root.join(".gtdspace/meta.json").exists());
+        let backups = list_pre_op_backups(root.to_string_lossy().to_string())?;
+        assert_eq!(backups.len(), 1);
+        assert_eq!(backups[0].operation, "restore_snapshot");
+        assert!(backups[0].files.contains(&"Cabinet/New.md".to_string()));
+        assert_eq!(
+            fs::read_to_string(Path::new(&previous).join("Cabinet/Note.md"))
+                .map_err(|e| e.to_string())?,
+            "edited\n"
+        );
+
+        write_test_file(snapshots.path().join("notes.txt"), "not a snapshot")?;
+        let listed = list_snapshots(snapshot_dir).await?;
+        assert_eq!(listed.len(), 2);
+        assert_eq!(listed[0].snapshot_path, previous);
+        assert_eq!(listed[1].file_count, snapshot.files_copied);
+        assert!(parse_snapshot_name("2026-03-02T09-15-00-2").is_some());
+
+        Ok(())
+    }
+}
@@ -187,6 +187,23 @@ impl GoogleAuthManager {
         Ok(hub)
     }
 
+    /// Current access token, refreshed by the authenticator when expired.
+    pub async fn access_token(&self) -> Result<String, Box<dyn std::error::Error>> {
+        let auth = self.authenticator.as_ref().ok_or("Not authenticated")?;
+        let token = auth
+            .token(
+                &["https://www.googleapis.com/auth/calendar.readonly"]
+                    .iter()
+                    .map(|s| s.to_string())
+                    .collect::<Vec<_>>(),
+            )
+            .await?;
+        token
+            .token()
+            .map(str::to_string)
+            .ok_or_else(|| "Authenticator returned no access token".into())
+    }
+
     #[allow(dead_code)]
     pub async fn refresh_token_if_needed(&mut self) -> Result<(), Box<dyn std::error::Error>> {
         if let Some(auth) = &self.authenticator {
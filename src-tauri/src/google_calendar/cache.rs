@@ -57,6 +57,20 @@ pub(crate) fn load_google_calendar_cache_from_path(
     Ok(Some(cache))
 }
 
+/// Delete the persisted event cache. Returns whether a cache file existed.
+pub(crate) fn delete_google_calendar_cache() -> Result<bool, String> {
+    let path = google_calendar_cache_path()?;
+    match fs::remove_file(&path) {
+        Ok(()) => Ok(true),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(error) => Err(format!(
+            "Failed to delete Google Calendar cache '{}': {}",
+            path.display(),
+            error
+        )),
+    }
+}
+
 pub(crate) async fn save_google_calendar_cache(cache: &CachedEvents) -> Result<(), String> {
     let path = google_calendar_cache_path()?;
     let parent = path
@@ -233,6 +247,10 @@ mod tests {
                 meeting_link: Some("https://meet.example.com/weekly".to_string()),
                 status: "confirmed".to_string(),
                 color_id: Some("3".to_string()),
+                category: None,
+                is_recurring: false,
+                recurring_event_id: None,
+                recurrence: Vec::new(),
             }],
             last_updated: Utc::now(),
         }
@@ -1,7 +1,9 @@
-use reqwest::StatusCode;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
-use tokio::time::sleep;
+
+use super::retry::{
+    classify_status, google_error_reasons, parse_retry_after, retry_with_backoff, RetryDecision,
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CalendarEvent {
@@ -69,6 +71,7 @@ pub async fn fetch_calendar_events(
     let mut all_events: Vec<CalendarEvent> = Vec::new();
     let mut page_token: Option<String> = None;
     let mut page_count = 0;
+    let mut retries = 0;
     const MAX_RESULTS_PER_PAGE: u32 = 250;
 
     // Loop through all pages
@@ -95,8 +98,15 @@ pub async fn fetch_calendar_events(
             query_params.push(("pageToken".to_string(), token.clone()));
         }
 
-        let google_response: GoogleCalendarListResponse =
-            get_with_retries(&client, url, access_token, &query_params, page_count).await?;
+        let google_response: GoogleCalendarListResponse = get_with_retries(
+            &client,
+            url,
+            access_token,
+            &query_params,
+            page_count,
+            &mut retries,
+        )
+        .await?;
 
         // Convert Google events to our format
         let page_events: Vec<CalendarEvent> = google_response
@@ -149,8 +159,9 @@ pub async fn fetch_calendar_events(
     }
 
     println!(
-        "[CalendarClient] Total events fetched: {}",
-        all_events.len()
+        "[CalendarClient] Total events fetched: {} ({} retries)",
+        all_events.len(),
+        retries
     );
     Ok(all_events)
 }
@@ -172,127 +183,62 @@ pub async fn fetch_events_async(
 }
 
 /// Executes an HTTP GET with bounded retries, exponential backoff, and jitter.
-/// Retries on HTTP 429, any 5xx, and transient network errors (connect/timeouts).
+/// Retries rate limits (429, 403 `rateLimitExceeded`), any 5xx, and transient
+/// network errors; honors `Retry-After`; other 4xx responses fail immediately.
 async fn get_with_retries(
     client: &reqwest::Client,
     url: &str,
     access_token: &str,
     query_params: &[(String, String)],
     page_count: u32,
+    retries: &mut u32,
 ) -> Result<GoogleCalendarListResponse, Box<dyn std::error::Error>> {
-    let max_attempts: u32 = 5;
-    let base_delay_ms: u64 = 300;
+    fn transient(error: &reqwest::Error) -> RetryDecision {
+        if error.is_timeout() || error.is_connect() {
+            RetryDecision::Retry { retry_after: None }
+        } else {
+            RetryDecision::Fail
+        }
+    }
+    let label = format!("Fetching events page {}", page_count);
 
-    for attempt in 1..=max_attempts {
-        let req = client
+    retry_with_backoff(&label, retries, move || async move {
+        let resp = client
             .get(url)
             .bearer_auth(access_token)
-            .query(query_params);
-
-        match req.send().await {
-            Ok(resp) => {
-                let status = resp.status();
-                if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
-                    if attempt == max_attempts {
-                        println!(
-                            "[CalendarClient] Final failure (status {}) on page {} after {} attempts",
-                            status,
-                            page_count,
-                            attempt
-                        );
-                        return Err(Box::new(std::io::Error::other(format!(
-                            "Failed to fetch events on page {}: HTTP status {}",
-                            page_count, status
-                        ))));
-                    }
-
-                    let backoff_ms = base_delay_ms.saturating_mul(1u64 << (attempt - 1));
-                    // Calculate jitter before await to avoid Send issue
-                    let jitter_ms: u64 = {
-                        use rand::RngExt;
-                        let mut rng = rand::rng();
-                        rng.random_range(0..=backoff_ms / 2 + 1)
-                    };
-                    let sleep_ms = backoff_ms + jitter_ms;
-                    println!(
-                        "[CalendarClient] Retry {} due to HTTP {} on page {}. Sleeping {} ms...",
-                        attempt, status, page_count, sleep_ms
-                    );
-                    sleep(Duration::from_millis(sleep_ms)).await;
-                    continue;
-                }
-
-                // Status is OK or other non-retryable 4xx
-                match resp.json::<GoogleCalendarListResponse>().await {
-                    Ok(parsed) => return Ok(parsed),
-                    Err(e) => {
-                        // Retry on transient network read errors
-                        let is_transient = e.is_timeout() || e.is_connect();
-                        if is_transient && attempt < max_attempts {
-                            let backoff_ms = base_delay_ms.saturating_mul(1u64 << (attempt - 1));
-                            // Calculate jitter before await to avoid Send issue
-                            let jitter_ms: u64 = {
-                                use rand::RngExt;
-                                let mut rng = rand::rng();
-                                rng.random_range(0..=backoff_ms / 2 + 1)
-                            };
-                            let sleep_ms = backoff_ms + jitter_ms;
-                            println!(
-                                "[CalendarClient] Retry {} due to body/network error on page {}: {}. Sleeping {} ms...",
-                                attempt, page_count, e, sleep_ms
-                            );
-                            sleep(Duration::from_millis(sleep_ms)).await;
-                            continue;
-                        }
-                        if is_transient {
-                            println!(
-                                "[CalendarClient] Final failure parsing response on page {} after {} attempts: {}",
-                                page_count, attempt, e
-                            );
-                        }
-                        return Err(Box::new(e) as Box<dyn std::error::Error>);
-                    }
-                }
-            }
-            Err(e) => {
-                let mut retryable = e.is_timeout() || e.is_connect();
-                if let Some(status) = e.status() {
-                    retryable = retryable
-                        || status == StatusCode::TOO_MANY_REQUESTS
-                        || status.is_server_error();
-                }
-
-                if retryable && attempt < max_attempts {
-                    let backoff_ms = base_delay_ms.saturating_mul(1u64 << (attempt - 1));
-                    // Calculate jitter before await to avoid Send issue
-                    let jitter_ms: u64 = {
-                        use rand::RngExt;
-                        let mut rng = rand::rng();
-                        rng.random_range(0..=backoff_ms / 2 + 1)
-                    };
-                    let sleep_ms = backoff_ms + jitter_ms;
-                    println!(
-                        "[CalendarClient] Retry {} due to network error on page {}: {}. Sleeping {} ms...",
-                        attempt, page_count, e, sleep_ms
-                    );
-                    sleep(Duration::from_millis(sleep_ms)).await;
-                    continue;
-                }
-
-                if retryable {
-                    println!(
-                        "[CalendarClient] Final failure (network) on page {} after {} attempts: {}",
-                        page_count, attempt, e
-                    );
-                }
-                return Err(Box::new(e) as Box<dyn std::error::Error>);
-            }
+            .query(query_params)
+            .send()
+            .await
+            .map_err(|e| {
+                let decision = transient(&e);
+                (e.to_string(), decision)
+            })?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            let retry_after = resp
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| parse_retry_after(value, chrono::Utc::now()));
+            let body: serde_json::Value = resp.json().await.unwrap_or_default();
+            let decision =
+                classify_status(status.as_u16(), &google_error_reasons(&body), retry_after);
+            return Err((format!("HTTP status {}", status), decision));
         }
-    }
 
-    // Should be unreachable
-    Err(Box::new(std::io::Error::other(format!(
-        "Failed to fetch events on page {} after retries (unexpected fallthrough)",
-        page_count
-    ))))
+        resp.json::<GoogleCalendarListResponse>()
+            .await
+            .map_err(|e| {
+                let decision = transient(&e);
+                (e.to_string(), decision)
+            })
+    })
+    .await
+    .map_err(|error| {
+        Box::new(std::io::Error::other(format!(
+            "Failed to fetch events on page {}: {}",
+            page_count, error
+        ))) as Box<dyn std::error::Error>
+    })
 }
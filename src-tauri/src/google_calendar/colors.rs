@@ -0,0 +1,90 @@
+//! Google Calendar color palette and color-to-category mapping.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+use super::GoogleCalendarEvent;
+
+/// Category for events whose color has no mapping (or no color at all)
+pub const DEFAULT_EVENT_CATEGORY: &str = "event";
+
+/// One palette entry as hex colors, e.g. `#a4bdfc`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ColorDefinition {
+    pub background: String,
+    pub foreground: String,
+}
+
+/// The user's calendar and event color palettes, keyed by color id
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CalendarColors {
+    pub calendar: BTreeMap<String, ColorDefinition>,
+    pub event: BTreeMap<String, ColorDefinition>,
+}
+
+/// Trim ids and categories, rejecting blank entries.
+pub fn normalize_color_mapping(
+    mapping: HashMap<String, String>,
+) -> Result<HashMap<String, String>, String> {
+    mapping
+        .into_iter()
+        .map(|(color_id, category)| {
+            let color_id = color_id.trim().to_string();
+            let category = category.trim().to_string();
+            if color_id.is_empty() {
+                return Err("Color id cannot be empty".to_string());
+            }
+            if category.is_empty() {
+                return Err(format!("Category for color {} cannot be empty", color_id));
+            }
+            Ok((color_id, category))
+        })
+        .collect()
+}
+
+/// Category for `color_id`, falling back to [`DEFAULT_EVENT_CATEGORY`].
+pub fn resolve_event_category(color_id: Option<&str>, mapping: &HashMap<String, String>) -> String {
+    color_id
+        .and_then(|id| mapping.get(id.trim()))
+        .cloned()
+        .unwrap_or_else(|| DEFAULT_EVENT_CATEGORY.to_string())
+}
+
+/// Fill in `category` on each event from its color id.
+pub fn apply_event_categories(
+    events: &mut [GoogleCalendarEvent],
+    mapping: &HashMap<String, String>,
+) {
+    for event in events {
+        event.category = Some(resolve_event_category(event.color_id.as_deref(), mapping));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn categories_resolve_with_default_fallback() {
+        let mapping = normalize_color_mapping(HashMap::from([
+            (" 11 ".to_string(), " meeting ".to_string()),
+            ("9".to_string(), "deep-work".to_string()),
+        ]))
+        .unwrap();
+
+        assert_eq!(resolve_event_category(Some("11"), &mapping), "meeting");
+        assert_eq!(resolve_event_category(Some("9"), &mapping), "deep-work");
+        assert_eq!(
+            resolve_event_category(Some("42"), &mapping),
+            DEFAULT_EVENT_CATEGORY
+        );
+        assert_eq!(
+            resolve_event_category(None, &mapping),
+            DEFAULT_EVENT_CATEGORY
+        );
+
+        assert!(
+            normalize_color_mapping(HashMap::from([("1".to_string(), " ".to_string())])).is_err()
+        );
+    }
+}
@@ -1,26 +1,32 @@
 use chrono::{DateTime, Utc};
 use google_calendar3::api::Event;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 pub mod auth;
 pub mod cache;
 pub mod calendar_client;
+pub mod colors;
 pub mod config_manager;
 pub mod custom_flow_delegate;
 pub mod oauth_server;
+pub mod retry;
 pub mod storage;
 pub mod sync;
 pub mod token_manager;
+pub mod webhook;
 
 // Re-export the config from config_manager to avoid duplication
 pub use cache::{load_google_calendar_cache, CachedEvents};
+pub use colors::{CalendarColors, ColorDefinition};
 pub use config_manager::GoogleOAuthConfig as GoogleCalendarConfig;
 
 use auth::GoogleAuthManager;
 use storage::TokenStorage;
-use sync::CalendarSyncManager;
+use sync::{CalendarSyncManager, SyncOutcome};
+use webhook::WebhookChannel;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GoogleCalendarEvent {
@@ -34,6 +40,18 @@ pub struct GoogleCalendarEvent {
     pub meeting_link: Option<String>,
     pub status: String,
     pub color_id: Option<String>,
+    /// GTD category resolved from `color_id` via the user's color mapping
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Part of a recurring series
+    #[serde(default)]
+    pub is_recurring: bool,
+    /// Series id when this is one occurrence of a recurring event
+    #[serde(default)]
+    pub recurring_event_id: Option<String>,
+    /// RRULE, EXDATE, and RDATE lines; only series events carry them
+    #[serde(default)]
+    pub recurrence: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -42,6 +60,23 @@ pub struct SyncStatus {
     pub last_sync: Option<DateTime<Utc>>,
     pub sync_in_progress: bool,
     pub error: Option<String>,
+    /// OAuth client credentials are available (stored or environment)
+    #[serde(default)]
+    pub credentials_configured: bool,
+    /// Stored tokens exist but can no longer be used; the user must reconnect
+    #[serde(default)]
+    pub needs_reauth: bool,
+    /// Access token expiry, when known
+    #[serde(default)]
+    pub token_expires_at: Option<DateTime<Utc>>,
+}
+
+/// Result of `google_calendar_sync`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarSyncResult {
+    pub events: Vec<GoogleCalendarEvent>,
+    /// API calls retried after rate limits or transient errors
+    pub retries: u32,
 }
 
 pub struct GoogleCalendarManager {
@@ -91,11 +126,43 @@ impl GoogleCalendarManager {
         Ok(())
     }
 
+    /// Register a push notification channel, replacing any existing one.
+    pub async fn register_webhook(
+        &self,
+        notification_url: &str,
+    ) -> Result<WebhookChannel, Box<dyn std::error::Error>> {
+        if let Err(e) = self.stop_webhook().await {
+            log::warn!("[GoogleCalendar] Failed to stop previous webhook: {}", e);
+        }
+
+        let access_token = self.auth_manager.lock().await.access_token().await?;
+        let channel = webhook::register_channel(&access_token, notification_url).await?;
+        webhook::save_channel(&self.webhook_channel_path(), &channel)?;
+        Ok(channel)
+    }
+
+    /// Stop the registered push notification channel, if any, and forget it.
+    pub async fn stop_webhook(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let path = self.webhook_channel_path();
+        let Some(channel) = webhook::load_channel(&path)? else {
+            return Ok(());
+        };
+
+        let access_token = self.auth_manager.lock().await.access_token().await?;
+        webhook::stop_channel(&access_token, &channel).await?;
+        webhook::delete_channel(&path)?;
+        Ok(())
+    }
+
+    fn webhook_channel_path(&self) -> std::path::PathBuf {
+        webhook::channel_path(&self.token_storage.get_token_path())
+    }
+
     pub async fn sync_events(
         &self,
         time_min: Option<DateTime<Utc>>,
         time_max: Option<DateTime<Utc>>,
-    ) -> Result<Vec<GoogleCalendarEvent>, Box<dyn std::error::Error>> {
+    ) -> Result<SyncOutcome, Box<dyn std::error::Error>> {
         // Get the hub while holding the auth lock
         let hub = {
             let auth = self.auth_manager.lock().await;
@@ -107,6 +174,66 @@ impl GoogleCalendarManager {
         sync.sync_events(hub, time_min, time_max).await
     }
 
+    /// Fetch a single event from the primary calendar, returning `None` if it no longer exists.
+    pub async fn get_event(
+        &self,
+        event_id: &str,
+    ) -> Result<Option<GoogleCalendarEvent>, Box<dyn std::error::Error>> {
+        let hub = {
+            let auth = self.auth_manager.lock().await;
+            auth.get_calendar_hub().await?
+        };
+
+        match hub.events().get("primary", event_id).doit().await {
+            Ok((_, event)) => Ok(Some(GoogleCalendarEvent::from(event))),
+            Err(google_calendar3::Error::Failure(response))
+                if response.status() == google_calendar3::hyper::StatusCode::NOT_FOUND
+                    || response.status() == google_calendar3::hyper::StatusCode::GONE =>
+            {
+                Ok(None)
+            }
+            Err(google_calendar3::Error::BadRequest(body))
+                if matches!(
+                    body.pointer("/error/code").and_then(|code| code.as_u64()),
+                    Some(404) | Some(410)
+                ) =>
+            {
+                Ok(None)
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Fetch the user's calendar and event color palettes.
+    pub async fn get_colors(&self) -> Result<CalendarColors, Box<dyn std::error::Error>> {
+        let hub = {
+            let auth = self.auth_manager.lock().await;
+            auth.get_calendar_hub().await?
+        };
+
+        let (_, colors) = hub.colors().get().doit().await?;
+        let convert = |palette: Option<HashMap<String, google_calendar3::api::ColorDefinition>>| {
+            palette
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(id, color)| {
+                    (
+                        id,
+                        ColorDefinition {
+                            background: color.background.unwrap_or_default(),
+                            foreground: color.foreground.unwrap_or_default(),
+                        },
+                    )
+                })
+                .collect()
+        };
+
+        Ok(CalendarColors {
+            calendar: convert(colors.calendar),
+            event: convert(colors.event),
+        })
+    }
+
     pub async fn get_status(&self) -> Result<SyncStatus, Box<dyn std::error::Error>> {
         let auth = self.auth_manager.lock().await;
         let sync = self.sync_manager.lock().await;
@@ -115,10 +242,23 @@ impl GoogleCalendarManager {
             is_connected: auth.is_authenticated().await,
             last_sync: sync.get_last_sync_time(),
             sync_in_progress: sync.is_syncing(),
-            error: None,
+            error: sync.auto_sync_paused_until().map(|until| {
+                format!(
+                    "Auto-sync paused after repeated failures until {}",
+                    until.to_rfc3339()
+                )
+            }),
+            credentials_configured: true,
+            needs_reauth: false,
+            token_expires_at: None,
         })
     }
 
+    /// When auto-sync may resume after repeated failures, if it is paused
+    pub async fn auto_sync_paused_until(&self) -> Option<DateTime<Utc>> {
+        self.sync_manager.lock().await.auto_sync_paused_until()
+    }
+
     pub async fn get_cached_events(
         &self,
     ) -> Result<Vec<GoogleCalendarEvent>, Box<dyn std::error::Error>> {
@@ -155,6 +295,9 @@ impl From<Event> for GoogleCalendarEvent {
             })
         });
 
+        let recurrence = event.recurrence.unwrap_or_default();
+        let is_recurring = event.recurring_event_id.is_some() || !recurrence.is_empty();
+
         GoogleCalendarEvent {
             id: event.id.unwrap_or_default(),
             summary: event
@@ -168,6 +311,10 @@ impl From<Event> for GoogleCalendarEvent {
             meeting_link,
             status: event.status.unwrap_or_else(|| "confirmed".to_string()),
             color_id: event.color_id,
+            category: None,
+            is_recurring,
+            recurring_event_id: event.recurring_event_id,
+            recurrence,
         }
     }
 }
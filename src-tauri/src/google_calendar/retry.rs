@@ -0,0 +1,285 @@
+//! Retry, backoff, and circuit-breaker policy for Google Calendar API calls.
+//!
+//! Rate limits (429, 403 `rateLimitExceeded`) and 5xx responses are retried with
+//! exponential backoff plus jitter, honoring `Retry-After` when the server sends
+//! it. Anything else (401, unknown calendar id, malformed requests) fails on the
+//! first attempt. Repeated hard failures open a circuit breaker that pauses
+//! auto-sync for a cooldown period.
+
+use chrono::{DateTime, Utc};
+use std::fmt::Display;
+use std::future::Future;
+use std::time::Duration;
+
+/// Attempts per request, including the first
+pub(crate) const MAX_ATTEMPTS: u32 = 5;
+const BASE_DELAY_MS: u64 = 300;
+const MAX_DELAY: Duration = Duration::from_secs(30);
+
+/// Consecutive hard failures before auto-sync is paused
+const BREAKER_FAILURE_THRESHOLD: u32 = 3;
+const BREAKER_COOLDOWN_MINUTES: i64 = 15;
+
+const RATE_LIMIT_REASONS: [&str; 3] = [
+    "rateLimitExceeded",
+    "userRateLimitExceeded",
+    "quotaExceeded",
+];
+
+/// Whether a failed attempt should be tried again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RetryDecision {
+    /// Transient failure; wait `retry_after` if the server asked, else back off
+    Retry { retry_after: Option<Duration> },
+    /// Permanent failure; surface immediately
+    Fail,
+}
+
+/// Classify an HTTP error response.
+///
+/// `reasons` are the `error.errors[].reason` values from a Google API error body.
+pub(crate) fn classify_status(
+    status: u16,
+    reasons: &[&str],
+    retry_after: Option<Duration>,
+) -> RetryDecision {
+    let rate_limited = status == 429
+        || (status == 403
+            && reasons
+                .iter()
+                .any(|reason| RATE_LIMIT_REASONS.contains(reason)));
+
+    if rate_limited || (500..600).contains(&status) {
+        RetryDecision::Retry { retry_after }
+    } else {
+        RetryDecision::Fail
+    }
+}
+
+/// The `error.errors[].reason` values from a Google API error body.
+pub(crate) fn google_error_reasons(body: &serde_json::Value) -> Vec<&str> {
+    body.pointer("/error/errors")
+        .and_then(|errors| errors.as_array())
+        .map(|errors| {
+            errors
+                .iter()
+                .filter_map(|error| error.get("reason").and_then(|reason| reason.as_str()))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse a `Retry-After` header given as delta-seconds or an HTTP date.
+pub(crate) fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let at = DateTime::parse_from_rfc2822(value)
+        .ok()?
+        .with_timezone(&Utc);
+    Some((at - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+/// Exponential backoff with up to 50% jitter for the given 1-based attempt.
+fn backoff_delay(attempt: u32) -> Duration {
+    use rand::RngExt;
+
+    let backoff_ms = BASE_DELAY_MS.saturating_mul(1u64 << (attempt - 1).min(16));
+    let jitter_ms = rand::rng().random_range(0..=backoff_ms / 2);
+    Duration::from_millis(backoff_ms + jitter_ms).min(MAX_DELAY)
+}
+
+/// Run `attempt` until it succeeds, fails permanently, or runs out of attempts.
+///
+/// Each retry increments `retries`, so callers can report how many happened.
+pub(crate) async fn retry_with_backoff<T, E, F, Fut>(
+    label: &str,
+    retries: &mut u32,
+    mut attempt: F,
+) -> Result<T, E>
+where
+    E: Display,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, (E, RetryDecision)>>,
+{
+    let mut attempt_number = 1;
+    loop {
+        match attempt().await {
+            Ok(value) => return Ok(value),
+            Err((error, RetryDecision::Retry { retry_after })) if attempt_number < MAX_ATTEMPTS => {
+                let delay = retry_after
+                    .map(|delay| delay.min(MAX_DELAY))
+                    .unwrap_or_else(|| backoff_delay(attempt_number));
+                log::warn!(
+                    "[GoogleCalendar] {} failed (attempt {}/{}): {}. Retrying in {} ms",
+                    label,
+                    attempt_number,
+                    MAX_ATTEMPTS,
+                    error,
+                    delay.as_millis()
+                );
+                *retries += 1;
+                attempt_number += 1;
+                tokio::time::sleep(delay).await;
+            }
+            Err((error, _)) => {
+                log::error!(
+                    "[GoogleCalendar] {} failed after {} attempt(s): {}",
+                    label,
+                    attempt_number,
+                    error
+                );
+                return Err(error);
+            }
+        }
+    }
+}
+
+/// Pauses auto-sync after repeated hard failures
+#[derive(Debug, Default)]
+pub(crate) struct CircuitBreaker {
+    consecutive_failures: u32,
+    open_until: Option<DateTime<Utc>>,
+}
+
+impl CircuitBreaker {
+    pub(crate) fn record_success(&mut self) {
+        self.consecutive_failures = 0;
+        self.open_until = None;
+    }
+
+    pub(crate) fn record_failure(&mut self, now: DateTime<Utc>) {
+        self.consecutive_failures += 1;
+        if self.consecutive_failures >= BREAKER_FAILURE_THRESHOLD {
+            self.open_until = Some(now + chrono::Duration::minutes(BREAKER_COOLDOWN_MINUTES));
+        }
+    }
+
+    /// When auto-sync may resume, if it is currently paused
+    pub(crate) fn open_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.open_until.filter(|until| *until > now)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_status_retries_only_rate_limits_and_server_errors() {
+        let wait = Some(Duration::from_secs(2));
+        assert_eq!(
+            classify_status(429, &[], wait),
+            RetryDecision::Retry { retry_after: wait }
+        );
+        assert_eq!(
+            classify_status(403, &["rateLimitExceeded"], None),
+            RetryDecision::Retry { retry_after: None }
+        );
+        assert_eq!(
+            classify_status(503, &[], None),
+            RetryDecision::Retry { retry_after: None }
+        );
+        assert_eq!(
+            classify_status(403, &["forbidden"], None),
+            RetryDecision::Fail
+        );
+        assert_eq!(classify_status(401, &[], None), RetryDecision::Fail);
+        assert_eq!(
+            classify_status(404, &["notFound"], None),
+            RetryDecision::Fail
+        );
+    }
+
+    #[test]
+    fn google_error_reasons_reads_error_body() {
+        let body = serde_json::json!({
+            "error": {
+                "code": 403,
+                "errors": [{ "domain": "usageLimits", "reason": "rateLimitExceeded" }]
+            }
+        });
+        assert_eq!(google_error_reasons(&body), vec!["rateLimitExceeded"]);
+        assert!(google_error_reasons(&serde_json::json!({})).is_empty());
+    }
+
+    #[test]
+    fn parse_retry_after_accepts_seconds_and_http_dates() {
+        let now = DateTime::parse_from_rfc3339("2026-04-10T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(parse_retry_after("7", now), Some(Duration::from_secs(7)));
+        assert_eq!(
+            parse_retry_after("Fri, 10 Apr 2026 12:00:30 GMT", now),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_retry_after("Fri, 10 Apr 2026 11:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[tokio::test]
+    async fn retry_with_backoff_counts_retries_and_stops_on_permanent_errors() {
+        let retry_now = RetryDecision::Retry {
+            retry_after: Some(Duration::ZERO),
+        };
+
+        let mut calls = 0;
+        let mut retries = 0;
+        let result: Result<u32, String> = retry_with_backoff("list events", &mut retries, || {
+            calls += 1;
+            let outcome = if calls < 3 {
+                Err(("HTTP 503".to_string(), retry_now))
+            } else {
+                Ok(calls)
+            };
+            async move { outcome }
+        })
+        .await;
+        assert_eq!(result, Ok(3));
+        assert_eq!(retries, 2);
+
+        let mut retries = 0;
+        let result: Result<(), String> =
+            retry_with_backoff("list events", &mut retries, || async {
+                Err(("HTTP 401".to_string(), RetryDecision::Fail))
+            })
+            .await;
+        assert_eq!(result, Err("HTTP 401".to_string()));
+        assert_eq!(retries, 0);
+
+        let mut retries = 0;
+        let result: Result<(), String> =
+            retry_with_backoff("list events", &mut retries, || async {
+                Err(("HTTP 429".to_string(), retry_now))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(retries, MAX_ATTEMPTS - 1);
+    }
+
+    #[test]
+    fn circuit_breaker_opens_after_repeated_failures() {
+        let now = Utc::now();
+        let mut breaker = CircuitBreaker::default();
+
+        breaker.record_failure(now);
+        breaker.record_failure(now);
+        assert_eq!(breaker.open_until(now), None);
+
+        breaker.record_failure(now);
+        let until = breaker.open_until(now).expect("breaker should be open");
+        assert_eq!(
+            until,
+            now + chrono::Duration::minutes(BREAKER_COOLDOWN_MINUTES)
+        );
+        assert_eq!(breaker.open_until(until), None);
+
+        breaker.record_success();
+        assert_eq!(breaker.open_until(now), None);
+    }
+}
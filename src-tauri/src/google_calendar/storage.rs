@@ -220,6 +220,74 @@ impl TokenStorage {
     }
 }
 
+/// What the status check needs to know about persisted tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenSummary {
+    pub has_refresh_token: bool,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// `time::OffsetDateTime` as serialized by the OAuth library:
+/// `[year, ordinal, hour, minute, second, nanosecond, offset_h, offset_m, offset_s]`.
+fn parse_offset_datetime_tuple(value: &serde_json::Value) -> Option<chrono::DateTime<chrono::Utc>> {
+    let parts: Vec<i64> = value
+        .as_array()?
+        .iter()
+        .map(|part| part.as_i64())
+        .collect::<Option<_>>()?;
+    if parts.len() < 9 {
+        return None;
+    }
+
+    let local = chrono::NaiveDate::from_yo_opt(parts[0] as i32, parts[1] as u32)?.and_hms_opt(
+        parts[2] as u32,
+        parts[3] as u32,
+        parts[4] as u32,
+    )?;
+    let offset = chrono::Duration::seconds(parts[6] * 3600 + parts[7] * 60 + parts[8]);
+    Some((local - offset).and_utc())
+}
+
+/// Summarize a token file without initializing the OAuth client.
+///
+/// Understands both the authenticator's persisted format (a list of
+/// `{scopes, token}` entries) and the flat `StoredToken` format.
+pub(crate) fn read_token_summary(path: &Path) -> Result<Option<TokenSummary>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read token file '{}': {}", path.display(), e))?;
+    let value: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Failed to parse token file '{}': {}", path.display(), e))?;
+
+    let token =
+        match &value {
+            serde_json::Value::Array(entries) => entries
+                .first()
+                .and_then(|entry| entry.get("token"))
+                .ok_or_else(|| format!("Token file '{}' has no tokens", path.display()))?,
+            other => other,
+        };
+
+    let has_refresh_token = token
+        .get("refresh_token")
+        .and_then(|refresh| refresh.as_str())
+        .is_some_and(|refresh| !refresh.trim().is_empty());
+    let expires_at = token.get("expires_at").and_then(|expires| match expires {
+        serde_json::Value::Number(seconds) => seconds
+            .as_i64()
+            .and_then(|seconds| chrono::DateTime::from_timestamp(seconds, 0)),
+        other => parse_offset_datetime_tuple(other),
+    });
+
+    Ok(Some(TokenSummary {
+        has_refresh_token,
+        expires_at,
+    }))
+}
+
 // Store sync metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SyncMetadata {
@@ -401,3 +469,42 @@ pub async fn delete_token_info(app_handle: &tauri::AppHandle) -> Result<(), Stri
 pub async fn is_authenticated(app_handle: &tauri::AppHandle) -> bool {
     read_token_info(app_handle).await.is_ok()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_token_summary_handles_persisted_and_flat_formats() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("google_calendar_tokens.json");
+        assert_eq!(read_token_summary(&path).unwrap(), None);
+
+        std::fs::write(
+            &path,
+            r#"[{"scopes":["calendar"],"token":{"access_token":"a","refresh_token":"r","expires_at":[2026,100,12,30,0,0,-5,0,0],"id_token":null}}]"#,
+        )
+        .unwrap();
+        let summary = read_token_summary(&path).unwrap().unwrap();
+        assert!(summary.has_refresh_token);
+        assert_eq!(
+            summary.expires_at.map(|at| at.to_rfc3339()),
+            Some("2026-04-10T17:30:00+00:00".to_string())
+        );
+
+        std::fs::write(
+            &path,
+            r#"{"access_token":"a","refresh_token":null,"expires_at":1775842200}"#,
+        )
+        .unwrap();
+        let summary = read_token_summary(&path).unwrap().unwrap();
+        assert!(!summary.has_refresh_token);
+        assert_eq!(
+            summary.expires_at.map(|at| at.timestamp()),
+            Some(1775842200)
+        );
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(read_token_summary(&path).is_err());
+    }
+}
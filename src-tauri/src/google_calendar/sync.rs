@@ -8,6 +8,10 @@ use tauri::{AppHandle, Emitter};
 
 use super::{
     cache::{load_google_calendar_cache, save_google_calendar_cache, CachedEvents},
+    retry::{
+        classify_status, google_error_reasons, parse_retry_after, retry_with_backoff,
+        CircuitBreaker, RetryDecision,
+    },
     GoogleCalendarEvent,
 };
 
@@ -20,6 +24,39 @@ pub struct CalendarSyncManager {
     cached_events: Option<CachedEvents>,
     last_sync_time: Option<DateTime<Utc>>,
     is_syncing: AtomicBool,
+    breaker: CircuitBreaker,
+}
+
+/// Events from a sync and how many API retries it took
+pub struct SyncOutcome {
+    pub events: Vec<GoogleCalendarEvent>,
+    pub retries: u32,
+}
+
+/// Decide whether a failed Calendar API call is worth retrying.
+fn classify_hub_error(error: &google_calendar3::Error) -> RetryDecision {
+    match error {
+        google_calendar3::Error::Failure(response) => {
+            let retry_after = response
+                .headers()
+                .get(hyper::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| parse_retry_after(value, Utc::now()));
+            classify_status(response.status().as_u16(), &[], retry_after)
+        }
+        google_calendar3::Error::BadRequest(body) => {
+            let status = body
+                .pointer("/error/code")
+                .and_then(|code| code.as_u64())
+                .and_then(|code| u16::try_from(code).ok())
+                .unwrap_or(400);
+            classify_status(status, &google_error_reasons(body), None)
+        }
+        google_calendar3::Error::HttpError(_) | google_calendar3::Error::Io(_) => {
+            RetryDecision::Retry { retry_after: None }
+        }
+        _ => RetryDecision::Fail,
+    }
 }
 
 impl CalendarSyncManager {
@@ -29,6 +66,7 @@ impl CalendarSyncManager {
             cached_events: None,
             last_sync_time: None,
             is_syncing: AtomicBool::new(false),
+            breaker: CircuitBreaker::default(),
         }
     }
 
@@ -37,10 +75,11 @@ impl CalendarSyncManager {
         hub: CalendarHub<hyper_rustls::HttpsConnector<hyper::client::HttpConnector>>,
         time_min: Option<DateTime<Utc>>,
         time_max: Option<DateTime<Utc>>,
-    ) -> Result<Vec<GoogleCalendarEvent>, Box<dyn std::error::Error>> {
+    ) -> Result<SyncOutcome, Box<dyn std::error::Error>> {
         if self.is_syncing.swap(true, Ordering::SeqCst) {
             return Err(std::io::Error::other("Google Calendar sync already in progress").into());
         }
+        let mut retries = 0;
         let result: Result<Vec<GoogleCalendarEvent>, Box<dyn std::error::Error>> = (async {
             let mut all_events = Vec::new();
 
@@ -60,21 +99,31 @@ impl CalendarSyncManager {
             // Fetch events with pagination
             let mut page_token: Option<String> = None;
             loop {
-                // Recreate the call for each page
-                // Clone the DateTime values since time_min/time_max take ownership
-                let mut call = hub
-                    .events()
-                    .list(calendar_id)
-                    .single_events(true)
-                    .order_by("startTime")
-                    .time_min(effective_min)
-                    .time_max(effective_max);
-
-                if let Some(token) = &page_token {
-                    call = call.page_token(token);
-                }
-
-                let (_, event_list) = call.doit().await?;
+                // Recreate the call for each attempt; `doit` consumes it
+                let hub = &hub;
+                let page = page_token.as_deref();
+                let (_, event_list) =
+                    retry_with_backoff("Listing calendar events", &mut retries, move || {
+                        let mut call = hub
+                            .events()
+                            .list(calendar_id)
+                            .single_events(true)
+                            .order_by("startTime")
+                            .time_min(effective_min)
+                            .time_max(effective_max);
+
+                        if let Some(token) = page {
+                            call = call.page_token(token);
+                        }
+
+                        async move {
+                            call.doit().await.map_err(|error| {
+                                let decision = classify_hub_error(&error);
+                                (error, decision)
+                            })
+                        }
+                    })
+                    .await?;
 
                 if let Some(items) = event_list.items {
                     for event in items {
@@ -112,8 +161,17 @@ impl CalendarSyncManager {
 
         // Always clear the syncing flag
         self.is_syncing.store(false, Ordering::SeqCst);
-        // Return the inner result (success or error)
-        result
+
+        match &result {
+            Ok(_) => self.breaker.record_success(),
+            Err(_) => self.breaker.record_failure(Utc::now()),
+        }
+        result.map(|events| SyncOutcome { events, retries })
+    }
+
+    /// When auto-sync may resume after repeated failures, if it is paused
+    pub fn auto_sync_paused_until(&self) -> Option<DateTime<Utc>> {
+        self.breaker.open_until(Utc::now())
     }
 
     #[allow(dead_code)]
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tauri::Manager;
 
@@ -50,32 +50,62 @@ fn read_to_string_retry(
     Err(std::io::Error::other("exhausted attempts without success"))
 }
 
+/// Folder under the app data dir holding the token store
+const TOKEN_DIR: &str = "google-calendar";
+/// Token store file name
+const TOKEN_FILE: &str = "google_calendar_tokens.json";
+
 pub struct TokenManager {
     storage_path: PathBuf,
 }
 
+/// Create the token directory if missing, restricted to the owner on Unix.
+fn ensure_storage_dir(dir: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dir)?;
+
+    // Set restrictive permissions on the directory for Unix-like systems
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut perms = std::fs::metadata(dir)?.permissions();
+        perms.set_mode(0o700); // Read/write/execute for owner only
+        std::fs::set_permissions(dir, perms)?;
+    }
+    Ok(())
+}
+
 impl TokenManager {
     pub fn new(app_handle: tauri::AppHandle) -> Result<Self, Box<dyn std::error::Error>> {
-        let mut storage_path = app_handle
+        let mut storage_dir = app_handle
             .path()
             .app_data_dir()
             .map_err(|e| format!("Failed to get app data dir: {}", e))?;
 
-        storage_path.push("google-calendar");
-        std::fs::create_dir_all(&storage_path)?;
+        storage_dir.push(TOKEN_DIR);
+        Self::in_dir(storage_dir)
+    }
 
-        // Set restrictive permissions on the directory for Unix-like systems
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            let mut perms = std::fs::metadata(&storage_path)?.permissions();
-            perms.set_mode(0o700); // Read/write/execute for owner only
-            std::fs::set_permissions(&storage_path, perms)?;
-        }
+    fn in_dir(storage_dir: PathBuf) -> Result<Self, Box<dyn std::error::Error>> {
+        ensure_storage_dir(&storage_dir)?;
+        Ok(Self {
+            storage_path: storage_dir.join(TOKEN_FILE),
+        })
+    }
 
-        storage_path.push("google_calendar_tokens.json");
+    /// Token store file inside `app_data_dir`, without creating anything.
+    pub(crate) fn token_file_path(app_data_dir: &Path) -> PathBuf {
+        app_data_dir.join(TOKEN_DIR).join(TOKEN_FILE)
+    }
 
-        Ok(Self { storage_path })
+    /// The app data dir may be removed after startup (or never created on a
+    /// fresh install), so recreate it before touching the token file.
+    fn ensure_parent_dir(&self) -> std::io::Result<&Path> {
+        let parent_dir = self
+            .storage_path
+            .parent()
+            .ok_or_else(|| std::io::Error::other("Invalid storage path"))?;
+        ensure_storage_dir(parent_dir)?;
+        Ok(parent_dir)
     }
 
     #[allow(dead_code)]
@@ -83,10 +113,7 @@ impl TokenManager {
         let json = serde_json::to_string_pretty(tokens)?;
 
         // Create a temporary file in the same directory as the target file
-        let parent_dir = self
-            .storage_path
-            .parent()
-            .ok_or_else(|| std::io::Error::other("Invalid storage path"))?;
+        let parent_dir = self.ensure_parent_dir()?;
         let mut temp_file = tempfile::NamedTempFile::new_in(parent_dir)?;
 
         // Write to temp file
@@ -160,6 +187,7 @@ impl TokenManager {
 
     #[allow(dead_code)]
     pub fn load_tokens(&self) -> Result<Option<StoredTokens>, Box<dyn std::error::Error>> {
+        self.ensure_parent_dir()?;
         if !self.storage_path.exists() {
             return Ok(None);
         }
@@ -205,3 +233,32 @@ impl TokenManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokens_round_trip_when_app_data_dir_is_missing() -> Result<(), Box<dyn std::error::Error>> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let storage_dir = temp_dir.path().join("app-data/google-calendar");
+        let manager = TokenManager::in_dir(storage_dir.clone())?;
+
+        // Simulate the app data dir disappearing after startup
+        std::fs::remove_dir_all(temp_dir.path().join("app-data"))?;
+        assert!(manager.load_tokens()?.is_none());
+        assert!(storage_dir.is_dir());
+
+        std::fs::remove_dir_all(temp_dir.path().join("app-data"))?;
+        manager.save_tokens(&StoredTokens {
+            access_token: "access".to_string(),
+            refresh_token: Some("refresh".to_string()),
+            expires_at: Some(1_800_000_000),
+        })?;
+        let loaded = manager.load_tokens()?.expect("tokens should be saved");
+        assert_eq!(loaded.access_token, "access");
+        assert_eq!(loaded.refresh_token.as_deref(), Some("refresh"));
+
+        Ok(())
+    }
+}
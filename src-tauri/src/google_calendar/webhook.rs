@@ -0,0 +1,200 @@
+//! Push notification channel for the primary calendar.
+//!
+//! Google delivers change notifications to an HTTPS endpoint registered with
+//! `events.watch`. The active channel is persisted next to the OAuth tokens so
+//! incoming notifications can be checked against it and so it can be stopped
+//! on disconnect.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const CHANNEL_FILE_NAME: &str = "google_calendar_channel.json";
+const WATCH_URL: &str = "https://www.googleapis.com/calendar/v3/calendars/primary/events/watch";
+const STOP_URL: &str = "https://www.googleapis.com/calendar/v3/channels/stop";
+
+/// A registered push notification channel
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct WebhookChannel {
+    /// Channel id chosen when registering
+    pub id: String,
+    /// Opaque id Google assigns to the watched resource
+    pub resource_id: String,
+    /// Expiry in milliseconds since the epoch, when Google reports one
+    pub expiration: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ChannelResponse {
+    id: String,
+    resource_id: String,
+    expiration: Option<String>,
+}
+
+/// Channel file stored alongside the token file at `token_path`.
+pub fn channel_path(token_path: &Path) -> PathBuf {
+    token_path.with_file_name(CHANNEL_FILE_NAME)
+}
+
+pub fn load_channel(path: &Path) -> Result<Option<WebhookChannel>, String> {
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let contents = fs::read_to_string(path).map_err(|error| {
+        format!(
+            "Failed to read Google Calendar channel '{}': {}",
+            path.display(),
+            error
+        )
+    })?;
+    let channel = serde_json::from_str::<WebhookChannel>(&contents).map_err(|error| {
+        format!(
+            "Failed to parse Google Calendar channel '{}': {}",
+            path.display(),
+            error
+        )
+    })?;
+    Ok(Some(channel))
+}
+
+pub fn save_channel(path: &Path, channel: &WebhookChannel) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(channel)
+        .map_err(|error| format!("Failed to serialize Google Calendar channel: {}", error))?;
+    fs::write(path, json).map_err(|error| {
+        format!(
+            "Failed to write Google Calendar channel '{}': {}",
+            path.display(),
+            error
+        )
+    })
+}
+
+pub fn delete_channel(path: &Path) -> Result<(), String> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(error) if error.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(error) => Err(format!(
+            "Failed to delete Google Calendar channel '{}': {}",
+            path.display(),
+            error
+        )),
+    }
+}
+
+/// Check that a notification belongs to the stored channel.
+pub fn validate_notification(
+    stored: Option<&WebhookChannel>,
+    channel_id: &str,
+    resource_id: &str,
+) -> Result<(), String> {
+    let channel = stored.ok_or("No Google Calendar webhook is registered")?;
+    if channel.id != channel_id.trim() || channel.resource_id != resource_id.trim() {
+        return Err(format!(
+            "Notification for unknown channel '{}' ignored",
+            channel_id
+        ));
+    }
+    Ok(())
+}
+
+fn http_client() -> Result<reqwest::Client, Box<dyn std::error::Error>> {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(5))
+        .timeout(Duration::from_secs(15))
+        .build()
+        .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)
+}
+
+/// Register a new channel delivering primary calendar changes to `notification_url`.
+pub async fn register_channel(
+    access_token: &str,
+    notification_url: &str,
+) -> Result<WebhookChannel, Box<dyn std::error::Error>> {
+    let body = serde_json::json!({
+        "id": uuid::Uuid::new_v4().to_string(),
+        "type": "web_hook",
+        "address": notification_url,
+    });
+
+    let resp = http_client()?
+        .post(WATCH_URL)
+        .bearer_auth(access_token)
+        .json(&body)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Failed to register webhook (HTTP {}): {}", status, body).into());
+    }
+
+    let channel: ChannelResponse = resp.json().await?;
+    Ok(WebhookChannel {
+        id: channel.id,
+        resource_id: channel.resource_id,
+        expiration: channel
+            .expiration
+            .and_then(|value| value.parse::<i64>().ok()),
+    })
+}
+
+/// Stop a channel; one Google no longer knows about counts as stopped.
+pub async fn stop_channel(
+    access_token: &str,
+    channel: &WebhookChannel,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let body = serde_json::json!({
+        "id": channel.id,
+        "resourceId": channel.resource_id,
+    });
+
+    let resp = http_client()?
+        .post(STOP_URL)
+        .bearer_auth(access_token)
+        .json(&body)
+        .send()
+        .await?;
+
+    let status = resp.status();
+    if !status.is_success() && status != reqwest::StatusCode::NOT_FOUND {
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Failed to stop webhook (HTTP {}): {}", status, body).into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn channel_round_trips_next_to_tokens_and_validates_notifications() -> Result<(), String> {
+        let dir = tempfile::tempdir().map_err(|e| e.to_string())?;
+        let path = channel_path(&dir.path().join("google_calendar_tokens.json"));
+        assert_eq!(path, dir.path().join(CHANNEL_FILE_NAME));
+        assert_eq!(load_channel(&path)?, None);
+        assert!(validate_notification(None, "chan-1", "res-1").is_err());
+
+        let channel = WebhookChannel {
+            id: "chan-1".to_string(),
+            resource_id: "res-1".to_string(),
+            expiration: Some(1_775_000_000_000),
+        };
+        save_channel(&path, &channel)?;
+        let stored = load_channel(&path)?;
+        assert_eq!(stored.as_ref(), Some(&channel));
+
+        assert!(validate_notification(stored.as_ref(), "chan-1", "res-1").is_ok());
+        assert!(validate_notification(stored.as_ref(), "chan-2", "res-1").is_err());
+        assert!(validate_notification(stored.as_ref(), "chan-1", "res-2").is_err());
+
+        delete_channel(&path)?;
+        delete_channel(&path)?;
+        assert_eq!(load_channel(&path)?, None);
+        Ok(())
+    }
+}
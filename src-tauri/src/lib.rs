@@ -27,20 +27,29 @@ pub fn run() {
         let _ = env_logger::try_init();
     }
 
+    commands::app::mark_app_started();
+
     register_handlers(tauri::Builder::default())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_store::Builder::default().build())
-        .setup(|_app| {
+        .setup(|app| {
             #[cfg(debug_assertions)]
             {
-                let window = _app.get_webview_window("main").unwrap();
+                let window = app.get_webview_window("main").unwrap();
                 window.open_devtools();
             }
+            commands::focus_session::resume_focus_ticker(app.handle());
             Ok(())
         })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|_app, event| {
+            if let tauri::RunEvent::Exit = event {
+                commands::habit_scheduler::abort_habit_scheduler();
+                commands::focus_session::abort_focus_ticker();
+            }
+        });
 }
 
 #[cfg(debug_assertions)]
@@ -50,25 +59,51 @@ fn register_handlers(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<taur
         commands::app::test_select_folder,
         commands::app::get_app_version,
         commands::app::check_permissions,
+        commands::app::get_app_health,
+        commands::app_data::get_app_paths,
+        commands::app_data::open_app_data_dir,
+        commands::app_data::clear_app_caches,
         commands::workspace::get_default_gtd_space_path,
         commands::workspace::initialize_default_gtd_space,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_status,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_preview_push,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_push,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_pull,
+        #[cfg(feature = "git-sync")]
+        commands::git_commands::git_sync_restore_file,
         commands::dialogs::select_folder,
         commands::dialogs::open_folder_in_explorer,
         commands::dialogs::open_file_location,
+        commands::dialogs::open_file_in_external_app,
+        commands::dialogs::get_external_editors,
         commands::filesystem::list_markdown_files,
+        commands::filesystem::list_markdown_files_page,
+        commands::filesystem::list_files_modified_since,
+        commands::workspace_size::get_workspace_size,
         commands::filesystem::list_project_actions,
+        commands::filesystem::list_project_actions_page,
+        commands::activity::get_workspace_activity_feed,
+        commands::activity::get_daily_action_counts,
+        commands::activity::get_weekly_completion_trend,
         commands::filesystem::read_file,
+        commands::chunked_read::read_file_chunked,
+        commands::chunked_read::cancel_file_read,
         commands::filesystem::save_file,
         commands::filesystem::create_file,
         commands::filesystem::rename_file,
         commands::filesystem::delete_file,
         commands::filesystem::delete_folder,
+        commands::filesystem::restore_from_trash,
+        commands::delete_impact::get_delete_impact,
+        commands::rename_impact::preview_file_rename_impact,
         commands::settings::load_settings,
         commands::settings::save_settings,
+        commands::settings::update_last_active_timestamp,
+        commands::settings::reset_settings,
         commands::settings::secure_store_set,
         commands::settings::secure_store_get,
         commands::settings::secure_store_remove,
@@ -78,20 +113,118 @@ fn register_handlers(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<taur
         commands::filesystem::copy_file,
         commands::filesystem::move_file,
         commands::filesystem::replace_in_file,
+        commands::file_diff::diff_file_versions,
+        commands::fields::get_file_fields,
+        commands::fields::set_file_field,
+        commands::file_icons::set_file_icon,
+        commands::file_meta::query_files_meta,
         commands::gtd_relationships::find_reverse_relationships,
         commands::gtd_relationships::find_habits_referencing,
+        commands::habit_recommendations::get_habit_recommendations,
+        commands::horizon_alignment::compute_gtd_horizon_alignment,
+        commands::gtd_relationships::list_all_references,
+        commands::gtd_relationships::get_projects_by_area,
+        commands::gtd_relationships::get_projects_by_goal,
+        commands::gtd_relationships::get_projects_by_vision,
+        commands::gtd_relationships::get_related_files,
+        commands::project_templates::list_project_templates,
+        commands::project_templates::create_project_from_template,
+        commands::milestones::create_project_milestone,
+        commands::milestones::list_project_milestones,
+        commands::area_standards::create_area_standard,
+        commands::area_standards::list_area_standards,
+        commands::snippets::list_snippets,
+        commands::snippets::get_snippet,
+        commands::snippets::save_snippet,
+        commands::snippets::delete_snippet,
+        commands::snippets::render_snippet,
+        commands::snippets::insert_snippet_into_file,
+        commands::spellcheck::check_text,
+        commands::spellcheck::check_file,
+        commands::spellcheck::add_word_to_dictionary,
+        commands::tags::list_files_by_tag,
+        commands::tags::list_all_tags,
         commands::workspace::check_is_gtd_space,
         commands::workspace::initialize_gtd_space,
+        commands::workspace::set_default_gtd_space,
+        commands::space_copy::duplicate_space,
+        commands::space_relocate::relocate_space,
+        commands::workspace_snapshots::snapshot_workspace,
+        commands::workspace_snapshots::list_snapshots,
+        commands::workspace_snapshots::restore_snapshot,
+        commands::stuck_projects::get_projects_without_next_action,
+        commands::orphaned_actions::find_orphaned_actions,
+        commands::orphaned_actions::adopt_orphaned_action,
+        commands::orphaned_actions::create_readme_for_folder,
+        commands::review_prompts::get_gtd_review_prompt,
+        commands::standup::generate_standup_notes,
+        commands::motivation::get_motivation_quote,
+        commands::batch_move::batch_move_actions,
+        commands::startup::startup_prefetch,
+        commands::badges::get_inbox_count,
+        commands::badges::get_overdue_count,
+        commands::focus_scheduling::check_focus_conflicts,
+        commands::focus_scheduling::suggest_focus_slots,
+        commands::waiting_for::list_waiting_items,
         commands::workspace::seed_example_gtd_content,
+        commands::seed_packs::validate_seed_pack,
         commands::gtd_projects::create_gtd_project,
+        commands::gtd_projects::convert_someday_maybe_to_project,
         commands::gtd_projects::create_gtd_action,
         commands::gtd_habits::create_gtd_habit,
         commands::gtd_habits::update_habit_status,
+        commands::gtd_habits::toggle_habit,
         commands::gtd_habits::repair_habit_history,
         commands::gtd_habits::check_and_reset_habits,
+        commands::habit_scheduler::start_habit_scheduler,
+        commands::habit_scheduler::stop_habit_scheduler,
+        commands::habit_scheduler::get_habit_scheduler_status,
+        commands::gtd_habits::prune_habit_history,
+        commands::gtd_habits::prune_all_habit_histories,
+        commands::gtd_habits::merge_habit_history_files,
+        commands::gtd_habits::get_habit_stats,
+        commands::habit_export::get_habit_data,
+        commands::habit_export::export_habit_data_json,
+        commands::gtd_habits::check_all_habits_completion_rate,
+        commands::gtd_habits::set_habit_focus_date,
+        commands::gtd_habits::convert_habit_to_project_action,
         commands::gtd_projects::list_gtd_projects,
+        commands::project_rollup::reconcile_project_statuses,
+        commands::project_report::generate_project_report_pdf,
+        commands::metadata::get_project_metadata,
+        commands::metadata::get_action_metadata,
+        commands::meeting_notes::parse_meeting_notes,
+        commands::meeting_notes::create_actions_from_meeting,
+        commands::note_split::split_note_into_actions,
+        commands::action_notes::get_action_notes,
+        commands::action_notes::append_action_note,
+        commands::sections::get_file_section,
+        commands::sections::replace_file_section,
+        commands::action_timers::start_action_timer,
+        commands::action_timers::get_running_action_timer,
+        commands::action_timers::stop_action_timer,
+        commands::action_timers::get_action_time_total,
+        commands::action_timers::get_project_time_totals,
+        commands::focus_session::start_focus_session,
+        commands::focus_session::get_focus_session,
+        commands::focus_session::pause_focus_session,
+        commands::focus_session::resume_focus_session,
+        commands::focus_session::stop_focus_session,
+        commands::project_velocity::get_estimated_project_completion,
+        commands::effort_distribution::get_action_effort_distribution,
+        commands::deadlines::check_upcoming_deadlines,
+        commands::due_summary::get_due_summary,
+        commands::focus::get_focus_blocks,
+        commands::frontmatter::normalize_markdown_frontmatter,
+        commands::outline::get_space_outline,
+        commands::file_previews::get_file_previews,
+        commands::outline::get_outline_generation,
         commands::gtd_projects::rename_gtd_project,
         commands::gtd_projects::rename_gtd_action,
+        commands::gtd_projects::set_project_due_date,
+        commands::gtd_projects::set_action_due_date,
+        commands::gtd_projects::set_action_focus_date,
+        commands::action_table::sync_project_action_table,
         commands::filesystem::check_directory_exists,
         commands::filesystem::create_directory,
         commands::google_calendar_commands::google_calendar_test,
@@ -103,13 +236,42 @@ fn register_handlers(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<taur
         commands::google_calendar_commands::google_calendar_disconnect,
         commands::google_calendar_commands::google_calendar_disconnect_simple,
         commands::google_calendar_commands::google_calendar_sync,
+        commands::google_calendar_commands::google_calendar_register_webhook,
+        commands::google_calendar_commands::google_calendar_handle_webhook_notification,
         commands::google_calendar_commands::google_calendar_get_status,
         commands::google_calendar_commands::google_calendar_get_cached_events,
+        commands::google_calendar_commands::google_calendar_get_event,
+        commands::google_calendar_commands::google_calendar_get_colors,
+        commands::google_calendar_commands::set_calendar_color_mapping,
+        commands::google_calendar_commands::get_calendar_event_by_gtd_action,
+        commands::google_calendar_commands::export_calendar_events_to_gtd_actions,
+        commands::google_calendar_commands::get_calendar_events_for_project,
+        commands::calendar_actions::create_action_from_event,
+        commands::calendar_actions::list_unprocessed_events,
+        commands::recurring_events::list_recurring_calendar_events,
         commands::google_calendar_commands::google_oauth_store_config,
         commands::google_calendar_commands::google_oauth_get_config,
         commands::google_calendar_commands::google_oauth_clear_config,
         commands::google_calendar_commands::google_oauth_has_config,
-        commands::filesystem::check_file_exists
+        commands::filesystem::check_file_exists,
+        commands::filesystem::set_file_writable,
+        commands::filesystem::check_directory_writable,
+        commands::space_config::set_space_read_only,
+        commands::space_config::set_action_tables_enabled,
+        commands::space_config::set_pre_op_backup_threshold,
+        commands::space_config::set_waiting_threshold_days,
+        commands::pre_op_backups::list_pre_op_backups,
+        commands::pre_op_backups::restore_pre_op_backup,
+        commands::space_config::get_space_config,
+        commands::field_vocabulary::get_field_vocabulary,
+        commands::field_vocabulary::set_field_vocabulary,
+        commands::custom_fields::get_custom_field_definitions,
+        commands::custom_fields::set_custom_field_definitions,
+        commands::custom_fields::set_action_custom_fields,
+        commands::space_config::set_file_locked,
+        commands::folder_encryption::enable_folder_encryption,
+        commands::folder_encryption::unlock_encrypted_folder,
+        commands::folder_encryption::lock_encrypted_folder
     ])
 }
 
@@ -119,25 +281,51 @@ fn register_handlers(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<taur
         commands::app::ping,
         commands::app::get_app_version,
         commands::app::check_permissions,
+        commands::app::get_app_health,
+        commands::app_data::get_app_paths,
+        commands::app_data::open_app_data_dir,
+        commands::app_data::clear_app_caches,
         commands::workspace::get_default_gtd_space_path,
         commands::workspace::initialize_default_gtd_space,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_status,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_preview_push,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_push,
+        #[cfg(feature = "git-sync")]
         commands::git_commands::git_sync_pull,
+        #[cfg(feature = "git-sync")]
+        commands::git_commands::git_sync_restore_file,
         commands::dialogs::select_folder,
         commands::dialogs::open_folder_in_explorer,
         commands::dialogs::open_file_location,
+        commands::dialogs::open_file_in_external_app,
+        commands::dialogs::get_external_editors,
         commands::filesystem::list_markdown_files,
+        commands::filesystem::list_markdown_files_page,
+        commands::filesystem::list_files_modified_since,
+        commands::workspace_size::get_workspace_size,
         commands::filesystem::list_project_actions,
+        commands::filesystem::list_project_actions_page,
+        commands::activity::get_workspace_activity_feed,
+        commands::activity::get_daily_action_counts,
+        commands::activity::get_weekly_completion_trend,
         commands::filesystem::read_file,
+        commands::chunked_read::read_file_chunked,
+        commands::chunked_read::cancel_file_read,
         commands::filesystem::save_file,
         commands::filesystem::create_file,
         commands::filesystem::rename_file,
         commands::filesystem::delete_file,
         commands::filesystem::delete_folder,
+        commands::filesystem::restore_from_trash,
+        commands::delete_impact::get_delete_impact,
+        commands::rename_impact::preview_file_rename_impact,
         commands::settings::load_settings,
         commands::settings::save_settings,
+        commands::settings::update_last_active_timestamp,
+        commands::settings::reset_settings,
         commands::settings::secure_store_set,
         commands::settings::secure_store_get,
         commands::settings::secure_store_remove,
@@ -147,20 +335,118 @@ fn register_handlers(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<taur
         commands::filesystem::copy_file,
         commands::filesystem::move_file,
         commands::filesystem::replace_in_file,
+        commands::file_diff::diff_file_versions,
+        commands::fields::get_file_fields,
+        commands::fields::set_file_field,
+        commands::file_icons::set_file_icon,
+        commands::file_meta::query_files_meta,
         commands::gtd_relationships::find_reverse_relationships,
         commands::gtd_relationships::find_habits_referencing,
+        commands::habit_recommendations::get_habit_recommendations,
+        commands::horizon_alignment::compute_gtd_horizon_alignment,
+        commands::gtd_relationships::list_all_references,
+        commands::gtd_relationships::get_projects_by_area,
+        commands::gtd_relationships::get_projects_by_goal,
+        commands::gtd_relationships::get_projects_by_vision,
+        commands::gtd_relationships::get_related_files,
+        commands::project_templates::list_project_templates,
+        commands::project_templates::create_project_from_template,
+        commands::milestones::create_project_milestone,
+        commands::milestones::list_project_milestones,
+        commands::area_standards::create_area_standard,
+        commands::area_standards::list_area_standards,
+        commands::snippets::list_snippets,
+        commands::snippets::get_snippet,
+        commands::snippets::save_snippet,
+        commands::snippets::delete_snippet,
+        commands::snippets::render_snippet,
+        commands::snippets::insert_snippet_into_file,
+        commands::spellcheck::check_text,
+        commands::spellcheck::check_file,
+        commands::spellcheck::add_word_to_dictionary,
+        commands::tags::list_files_by_tag,
+        commands::tags::list_all_tags,
         commands::workspace::check_is_gtd_space,
         commands::workspace::initialize_gtd_space,
+        commands::workspace::set_default_gtd_space,
+        commands::space_copy::duplicate_space,
+        commands::space_relocate::relocate_space,
+        commands::workspace_snapshots::snapshot_workspace,
+        commands::workspace_snapshots::list_snapshots,
+        commands::workspace_snapshots::restore_snapshot,
+        commands::stuck_projects::get_projects_without_next_action,
+        commands::orphaned_actions::find_orphaned_actions,
+        commands::orphaned_actions::adopt_orphaned_action,
+        commands::orphaned_actions::create_readme_for_folder,
+        commands::review_prompts::get_gtd_review_prompt,
+        commands::standup::generate_standup_notes,
+        commands::motivation::get_motivation_quote,
+        commands::batch_move::batch_move_actions,
+        commands::startup::startup_prefetch,
+        commands::badges::get_inbox_count,
+        commands::badges::get_overdue_count,
+        commands::focus_scheduling::check_focus_conflicts,
+        commands::focus_scheduling::suggest_focus_slots,
+        commands::waiting_for::list_waiting_items,
         commands::workspace::seed_example_gtd_content,
+        commands::seed_packs::validate_seed_pack,
         commands::gtd_projects::create_gtd_project,
+        commands::gtd_projects::convert_someday_maybe_to_project,
         commands::gtd_projects::create_gtd_action,
         commands::gtd_habits::create_gtd_habit,
         commands::gtd_habits::update_habit_status,
+        commands::gtd_habits::toggle_habit,
         commands::gtd_habits::repair_habit_history,
         commands::gtd_habits::check_and_reset_habits,
+        commands::habit_scheduler::start_habit_scheduler,
+        commands::habit_scheduler::stop_habit_scheduler,
+        commands::habit_scheduler::get_habit_scheduler_status,
+        commands::gtd_habits::prune_habit_history,
+        commands::gtd_habits::prune_all_habit_histories,
+        commands::gtd_habits::merge_habit_history_files,
+        commands::gtd_habits::get_habit_stats,
+        commands::habit_export::get_habit_data,
+        commands::habit_export::export_habit_data_json,
+        commands::gtd_habits::check_all_habits_completion_rate,
+        commands::gtd_habits::set_habit_focus_date,
+        commands::gtd_habits::convert_habit_to_project_action,
         commands::gtd_projects::list_gtd_projects,
+        commands::project_rollup::reconcile_project_statuses,
+        commands::project_report::generate_project_report_pdf,
+        commands::metadata::get_project_metadata,
+        commands::metadata::get_action_metadata,
+        commands::meeting_notes::parse_meeting_notes,
+        commands::meeting_notes::create_actions_from_meeting,
+        commands::note_split::split_note_into_actions,
+        commands::action_notes::get_action_notes,
+        commands::action_notes::append_action_note,
+        commands::sections::get_file_section,
+        commands::sections::replace_file_section,
+        commands::action_timers::start_action_timer,
+        commands::action_timers::get_running_action_timer,
+        commands::action_timers::stop_action_timer,
+        commands::action_timers::get_action_time_total,
+        commands::action_timers::get_project_time_totals,
+        commands::focus_session::start_focus_session,
+        commands::focus_session::get_focus_session,
+        commands::focus_session::pause_focus_session,
+        commands::focus_session::resume_focus_session,
+        commands::focus_session::stop_focus_session,
+        commands::project_velocity::get_estimated_project_completion,
+        commands::effort_distribution::get_action_effort_distribution,
+        commands::deadlines::check_upcoming_deadlines,
+        commands::due_summary::get_due_summary,
+        commands::focus::get_focus_blocks,
+        commands::frontmatter::normalize_markdown_frontmatter,
+        commands::outline::get_space_outline,
+        commands::file_previews::get_file_previews,
+        commands::outline::get_outline_generation,
         commands::gtd_projects::rename_gtd_project,
         commands::gtd_projects::rename_gtd_action,
+        commands::gtd_projects::set_project_due_date,
+        commands::gtd_projects::set_action_due_date,
+        commands::gtd_projects::set_action_focus_date,
+        commands::action_table::sync_project_action_table,
         commands::filesystem::check_directory_exists,
         commands::filesystem::create_directory,
         commands::google_calendar_commands::google_calendar_start_auth,
@@ -170,12 +456,41 @@ fn register_handlers(builder: tauri::Builder<tauri::Wry>) -> tauri::Builder<taur
         commands::google_calendar_commands::google_calendar_disconnect,
         commands::google_calendar_commands::google_calendar_disconnect_simple,
         commands::google_calendar_commands::google_calendar_sync,
+        commands::google_calendar_commands::google_calendar_register_webhook,
+        commands::google_calendar_commands::google_calendar_handle_webhook_notification,
         commands::google_calendar_commands::google_calendar_get_status,
         commands::google_calendar_commands::google_calendar_get_cached_events,
+        commands::google_calendar_commands::google_calendar_get_event,
+        commands::google_calendar_commands::google_calendar_get_colors,
+        commands::google_calendar_commands::set_calendar_color_mapping,
+        commands::google_calendar_commands::get_calendar_event_by_gtd_action,
+        commands::google_calendar_commands::export_calendar_events_to_gtd_actions,
+        commands::google_calendar_commands::get_calendar_events_for_project,
+        commands::calendar_actions::create_action_from_event,
+        commands::calendar_actions::list_unprocessed_events,
+        commands::recurring_events::list_recurring_calendar_events,
         commands::google_calendar_commands::google_oauth_store_config,
         commands::google_calendar_commands::google_oauth_get_config,
         commands::google_calendar_commands::google_oauth_clear_config,
         commands::google_calendar_commands::google_oauth_has_config,
-        commands::filesystem::check_file_exists
+        commands::filesystem::check_file_exists,
+        commands::filesystem::set_file_writable,
+        commands::filesystem::check_directory_writable,
+        commands::space_config::set_space_read_only,
+        commands::space_config::set_action_tables_enabled,
+        commands::space_config::set_pre_op_backup_threshold,
+        commands::space_config::set_waiting_threshold_days,
+        commands::pre_op_backups::list_pre_op_backups,
+        commands::pre_op_backups::restore_pre_op_backup,
+        commands::space_config::get_space_config,
+        commands::field_vocabulary::get_field_vocabulary,
+        commands::field_vocabulary::set_field_vocabulary,
+        commands::custom_fields::get_custom_field_definitions,
+        commands::custom_fields::set_custom_field_definitions,
+        commands::custom_fields::set_action_custom_fields,
+        commands::space_config::set_file_locked,
+        commands::folder_encryption::enable_folder_encryption,
+        commands::folder_encryption::unlock_encrypted_folder,
+        commands::folder_encryption::lock_encrypted_folder
     ])
 }
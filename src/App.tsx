@@ -3,6 +3,7 @@ import "@/utils/resize-observer-fix";
 // Use guarded Tauri detection and dynamic invoke to avoid web/runtime crashes
 import { waitForTauriReady } from "@/utils/tauri-ready";
 import { safeInvoke } from "@/utils/safe-invoke";
+import { listen } from "@tauri-apps/api/event";
 import {
   PanelLeftClose,
   PanelLeft,
@@ -62,6 +63,7 @@ import type {
   EditorMode,
   GTDProject,
   GitSyncPreviewResponse,
+  HabitsResetEvent,
 } from "@/types";
 import "./styles/globals.css";
 import { onContentSaved } from "@/utils/content-event-bus";
@@ -1033,39 +1035,25 @@ export const App: React.FC = () => {
       return repairedHabits;
     };
 
-    // Function to check and reset habits
-    const checkHabits = async () => {
+    // Refresh after the backend scheduler resets habits
+    const handleHabitsReset = async (resetHabits: string[]) => {
       try {
-        // Guard against missing required state
-        if (!currentSpacePath || currentSpacePath.trim() === "") {
-          return; // Skip invocation if no valid space path
+        if (isCancelled || !currentSpacePath || resetHabits.length === 0) {
+          return;
         }
 
-        // Always run the check - the backend will determine if any habits need resetting
-        // This ensures we catch all frequency intervals properly
-        const resetHabits =
-          (await safeInvoke<string[]>(
-            "check_and_reset_habits",
-            {
-              spacePath: currentSpacePath,
-            },
-            []
-          )) ?? [];
+        // Refresh the workspace to show updated statuses
+        // Use refs to avoid effect re-running when these functions change
+        await checkGTDSpaceRef.current(currentSpacePath);
+        await loadProjectsRef.current(currentSpacePath);
 
-        if (resetHabits && resetHabits.length > 0) {
-          // Refresh the workspace to show updated statuses
-          // Use refs to avoid effect re-running when these functions change
-          await checkGTDSpaceRef.current(currentSpacePath);
-          await loadProjectsRef.current(currentSpacePath);
-
-          // Also refresh the current tab if it's a habit
-          const currentTab = activeTabRef.current;
-          if (isHabitPath(currentTab?.file.path) && currentTab?.id) {
-            await reloadTabFromDiskRef.current(currentTab.id);
-          }
+        // Also refresh the current tab if it's a habit
+        const currentTab = activeTabRef.current;
+        if (isHabitPath(currentTab?.file.path) && currentTab?.id) {
+          await reloadTabFromDiskRef.current(currentTab.id);
         }
       } catch (error) {
-        console.error("[App] Failed to check and reset habits:", error);
+        console.error("[App] Failed to refresh after habit reset:", error);
       }
     };
 
@@ -1099,18 +1087,39 @@ export const App: React.FC = () => {
       }
     };
 
-    // Check for missed resets immediately on startup
-    checkMissedResets();
+    let unlistenHabitsReset: (() => void) | null = null;
+
+    // Check for missed resets immediately on startup, then let the backend
+    // scheduler check at each reset boundary and the top of every hour
+    const startHabitScheduler = async () => {
+      await checkMissedResets();
+      if (isCancelled || !currentSpacePath || currentSpacePath.trim() === "") {
+        return;
+      }
+
+      try {
+        const unlisten = await listen<HabitsResetEvent>("habits-reset", (event) => {
+          if (pathsEqual(event.payload.space_path, currentSpacePath)) {
+            void handleHabitsReset(event.payload.reset_habits);
+          }
+        });
+        if (isCancelled) {
+          unlisten();
+          return;
+        }
+        unlistenHabitsReset = unlisten;
+
+        await safeInvoke("start_habit_scheduler", { spacePath: currentSpacePath }, null);
+      } catch (error) {
+        console.error("[App] Failed to start habit scheduler:", error);
+      }
+    };
 
-    // Check every minute to catch all frequency intervals
-    // The backend will determine if any habits actually need resetting
-    const interval = setInterval(() => {
-      checkHabits();
-    }, 60000); // 1 minute interval
+    void startHabitScheduler();
 
     return () => {
       isCancelled = true;
-      clearInterval(interval);
+      unlistenHabitsReset?.();
     };
   }, [gtdSpace?.root_path, toast, withErrorHandling]); // Only depend on root_path, use refs for everything else
 
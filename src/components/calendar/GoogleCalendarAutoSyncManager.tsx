@@ -37,13 +37,13 @@ export const GoogleCalendarAutoSyncManager: React.FC = () => {
           return;
         }
 
-        const result = await syncGoogleCalendarEvents();
+        const result = await syncGoogleCalendarEvents('google_calendar_sync', { auto: true });
         if (!result) {
           console.warn('[GoogleCalendarAutoSync] Sync skipped:', reason, '(invoke unavailable)');
         } else {
           console.info(
             '[GoogleCalendarAutoSync] Synced events',
-            { reason, count: result.events.length }
+            { reason, count: result.events.length, retries: result.retries }
           );
         }
       } catch (error) {
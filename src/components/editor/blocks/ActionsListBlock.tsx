@@ -12,7 +12,6 @@ import { Button } from '@/components/ui/button';
 import { ScrollArea } from '@/components/ui/scroll-area';
 import { getPersistedActiveTabFilePath } from '@/hooks/tab-runtime';
 import { safeInvoke } from '@/utils/safe-invoke';
-import { extractMetadata } from '@/utils/metadata-extractor';
 import debounce from 'lodash.debounce';
 import {
   List,
@@ -25,7 +24,13 @@ import {
   Calendar,
   Clock
 } from 'lucide-react';
-import type { GTDAction, GTDActionStatus, GTDActionEffort, MarkdownFile } from '@/types';
+import type {
+  ActionMetadata,
+  GTDAction,
+  GTDActionStatus,
+  GTDActionEffort,
+  MarkdownFile,
+} from '@/types';
 import { normalizeStatus } from '@/utils/gtd-status';
 
 /**
@@ -195,35 +200,25 @@ const ActionsListRenderer = React.memo(function ActionsListRenderer(props: Actio
         return [];
       });
 
-      // For each file, read its content and extract metadata
+      // For each file, read its header metadata
       const actionPromises = actionFiles.map(async (file) => {
         try {
-          // Read file content
-          const content = await safeInvoke<string>('read_file', {
-            path: file.path
-          }, '').catch(() => '');
+          const metadata = await safeInvoke<ActionMetadata | null>('get_action_metadata', {
+            actionPath: file.path
+          }, null).catch(() => null);
 
-          // Extract metadata from content
-          const metadata = extractMetadata(content);
-          
           // Extract action name from filename (remove .md extension)
           const name = file.name.replace(/\.md$/i, '');
           
           // Build GTDAction object with metadata
           const action: GTDAction = {
-            name: metadata.title || name,
+            name: metadata?.title || name,
             path: file.path,
-            status: normalizeStatus(metadata.status as string),
-            focusDate: metadata.focusDate || null,
-            dueDate: metadata.dueDate || null,
-            effort: (metadata.effort as GTDActionEffort) || 'medium',
-            createdDateTime: (() => {
-              const dt = metadata.createdDateTime || metadata.created_date_time;
-              if (Array.isArray(dt)) {
-                return dt[0] || new Date().toISOString();
-              }
-              return dt || new Date().toISOString();
-            })(),
+            status: normalizeStatus(metadata?.status),
+            focusDate: metadata?.focus_date ?? null,
+            dueDate: metadata?.due_date ?? null,
+            effort: (metadata?.effort as GTDActionEffort) || 'medium',
+            createdDateTime: metadata?.created_date_time || new Date().toISOString(),
             project_path: projectPath
           };
           
@@ -30,7 +30,7 @@ import { useErrorHandler } from '@/hooks/useErrorHandler';
 import { useToast } from '@/hooks/useToast';
 import { RefreshCw, Clock, Search, X } from 'lucide-react';
 import type { HabitReferenceGroups } from '@/utils/gtd-markdown-helpers';
-import type { MarkdownFile } from '@/types';
+import type { CreatedWithReferences, MarkdownFile } from '@/types';
 
 interface CreateHabitDialogProps {
   isOpen: boolean;
@@ -332,11 +332,14 @@ export const CreateHabitDialog: React.FC<CreateHabitDialogProps> = ({
 
     const result = await withErrorHandling(
       async () => {
-        const habitPath = await safeInvoke<string>('create_gtd_habit', payload, null);
-        if (!habitPath) {
+        const created = await safeInvoke<CreatedWithReferences>('create_gtd_habit', payload, null);
+        if (!created?.path) {
           throw new Error('Failed to create habit');
         }
-        return habitPath;
+        if (created.dropped_references.length > 0) {
+          console.warn('[CreateHabitDialog] Dropped invalid references', created.dropped_references);
+        }
+        return created.path;
       },
       'Failed to create habit',
       'habit'
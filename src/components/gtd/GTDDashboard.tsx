@@ -394,7 +394,7 @@ const GTDDashboardComponent: React.FC<GTDDashboardProps> = ({
       }
 
       // Delete the file and verify result
-      const delResult = await safeInvoke<FileOperationResult>('delete_file', { path: actionPath }, { success: false, message: 'Failed to delete file' });
+      const delResult = await safeInvoke<FileOperationResult>('delete_file', { path: actionPath, useTrash: true }, { success: false, message: 'Failed to delete file' });
       if (!delResult || delResult.success !== true) {
         toast({ title: 'Delete failed', description: delResult?.message || 'Could not delete action', variant: 'destructive' });
         return;
@@ -25,7 +25,6 @@ import {
   DEFAULT_PROJECT_OUTCOME,
   type ProjectHorizonReferences,
 } from "@/utils/gtd-markdown-helpers";
-import { parseActionMarkdown } from "@/utils/gtd-action-markdown";
 import { parseHabitContent } from "@/utils/gtd-habit-markdown";
 import {
   normalizeProjectHorizonReferences,
@@ -45,7 +44,8 @@ import { checkTauriContextAsync } from "@/utils/tauri-ready";
 import { safeInvoke } from "@/utils/safe-invoke";
 import { formatDisplayDate } from "@/utils/format-display-date";
 import { useErrorHandler } from "@/hooks/useErrorHandler";
-import type { GTDProjectStatus, MarkdownFile } from "@/types";
+import { normalizeActionStatus } from "@/utils/gtd-status";
+import type { ActionMetadata, GTDProjectStatus, MarkdownFile } from "@/types";
 import { Circle, CircleDot, CheckCircle2, RefreshCw, LayoutList, Activity, CircleOff } from "lucide-react";
 import { GeneralReferencesField } from "@/components/gtd/GeneralReferencesField";
 
@@ -175,27 +175,27 @@ const ProjectActionsSection: React.FC<{ projectPath: string | null }> = ({ proje
             return null;
           }
 
-          const content = await withErrorHandling(async () => {
-            const result = await safeInvoke<string>("read_file", { path: file.path }, null);
+          const metadata = await withErrorHandling(async () => {
+            const result = await safeInvoke<ActionMetadata>(
+              "get_action_metadata",
+              { actionPath: file.path },
+              null
+            );
             if (result == null) {
               throw new Error(`Failed to read action file: ${file.path}`);
             }
             return result;
           });
-          if (typeof content !== "string") {
+          if (!metadata) {
             return null;
           }
-          const parsedAction = parseActionMarkdown(content);
           const fallbackName = file.name.replace(/\.(md|markdown)$/i, "");
           return {
-            name:
-              parsedAction.title && parsedAction.title !== "Untitled"
-                ? parsedAction.title
-                : fallbackName,
+            name: metadata.title || fallbackName,
             path: file.path,
-            status: parsedAction.status,
-            dueDate: parsedAction.dueDate || null,
-            focusDate: parsedAction.focusDate || null,
+            status: normalizeActionStatus(metadata.status),
+            dueDate: metadata.due_date,
+            focusDate: metadata.focus_date,
           } as ProjectActionItem;
         })
       );
@@ -1,4 +1,6 @@
 import { useEffect } from 'react';
+import { listen } from '@tauri-apps/api/event';
+import type { PathRemap } from '@/types';
 import type { RenameMode } from './state';
 
 type RenameEventDetail = {
@@ -85,4 +87,34 @@ export function useTabManagerSubscriptions({
       window.removeEventListener('open-reference-file', handleOpenReference);
     };
   }, [onDelete, onOpenReference, onRename]);
+
+  // Backend renames, moves, and space relocations report every moved file
+  useEffect(() => {
+    let isCancelled = false;
+    let unlistenPathsRemapped: (() => void) | null = null;
+
+    const subscribe = async () => {
+      try {
+        const unlisten = await listen<PathRemap[]>('paths-remapped', (event) => {
+          event.payload.forEach((pair) => {
+            onRename({ oldPath: pair.old_path, newPath: pair.new_path }, 'exact');
+          });
+        });
+        if (isCancelled) {
+          unlisten();
+          return;
+        }
+        unlistenPathsRemapped = unlisten;
+      } catch {
+        // Not running inside Tauri; there is no backend to emit the event
+      }
+    };
+
+    void subscribe();
+
+    return () => {
+      isCancelled = true;
+      unlistenPathsRemapped?.();
+    };
+  }, [onRename]);
 }
@@ -342,6 +342,7 @@ export const useFileManager = () => {
           console.log('Deleting file:', operation.path);
           result = await safeInvoke<FileOperationResult>('delete_file', {
             path: operation.path,
+            useTrash: true,
           }, { success: false, message: 'Failed to delete file' });
           break;
           
@@ -4,6 +4,7 @@ import debounce from 'lodash.debounce';
 import { useErrorHandler } from './useErrorHandler';
 import { useToast } from './useToast';
 import {
+  CreatedWithReferences,
   GTDSpace,
   GTDProject,
   GTDProjectCreate,
@@ -149,16 +150,17 @@ export function useGTDSpace() {
       try {
         const result = await withErrorHandling(
           async () => {
-            const projectPath = await safeInvoke<string>('create_gtd_project', {
+            const created = await safeInvoke<CreatedWithReferences>('create_gtd_project', {
               spacePath: params.spacePath,
               projectName: params.projectName,
               description: params.description,
               dueDate: params.dueDate || undefined,
               status: params.status || 'in-progress',
             }, null);
-            if (!projectPath) {
+            if (!created?.path) {
               throw new Error('Failed to create project');
             }
+            const projectPath = created.path;
             
             // Create project object for state update
             const newProject: GTDProject = {
@@ -357,7 +357,7 @@ export function useGTDWorkspaceSidebar({
             message?: string | null;
           }>(
             'delete_folder',
-            { path: normalizedDeletePath },
+            { path: normalizedDeletePath, useTrash: true },
             { success: false, message: 'Failed to delete folder' }
           );
 
@@ -404,7 +404,7 @@ export function useGTDWorkspaceSidebar({
             message?: string | null;
           }>(
             'delete_file',
-            { path: normalizedDeletePath },
+            { path: normalizedDeletePath, useTrash: true },
             { success: false, message: 'Failed to delete file' }
           );
 
@@ -15,6 +15,30 @@ export interface GoogleCalendarEvent {
   meeting_link?: string;
   status: string;
   color_id?: string;
+  /** GTD category resolved from color_id; unmapped colors fall back to 'event' */
+  category?: string;
+  is_recurring?: boolean;
+  /** Series ID shared by every occurrence of a recurring event */
+  recurring_event_id?: string | null;
+  /** RRULE/EXDATE lines of the series, when known */
+  recurrence?: string[];
+}
+
+export interface RecurringEvent extends GoogleCalendarEvent {
+  next_occurrence: string | null;
+  occurrences_in_next_30_days: number;
+}
+
+export interface CalendarColorDefinition {
+  /** Hex color, e.g. #a4bdfc */
+  background: string;
+  foreground: string;
+}
+
+// Palettes keyed by color id, from google_calendar_get_colors
+export interface CalendarColors {
+  calendar: Record<string, CalendarColorDefinition>;
+  event: Record<string, CalendarColorDefinition>;
 }
 
 // Backend returns snake_case fields
@@ -23,6 +47,38 @@ export interface GoogleCalendarSyncStatus {
   last_sync?: string;
   sync_in_progress: boolean;
   error?: string;
+  credentials_configured: boolean;
+  needs_reauth: boolean;
+  token_expires_at?: string;
+}
+
+// Per-file result of removing [!gcal:event_id:...] links on disconnect
+export interface EventLinkCleanup {
+  path: string;
+  links_removed: number;
+  error?: string | null;
+}
+
+// Returned by the disconnect commands
+export interface DisconnectSummary {
+  message: string;
+  /** Cached events were deleted, so no last sync is reported */
+  cache_cleared: boolean;
+  links_cleared: EventLinkCleanup[];
+}
+
+// A calendar event export_calendar_events_to_gtd_actions did not turn into an action
+export interface SkippedCalendarEvent {
+  event_id: string;
+  summary: string;
+  reason: string;
+}
+
+// Returned by export_calendar_events_to_gtd_actions
+export interface CalendarExportResult {
+  /** Full paths of the created actions */
+  created: string[];
+  skipped: SkippedCalendarEvent[];
 }
 
 // Frontend uses camelCase fields
@@ -31,6 +87,9 @@ export interface SyncStatus {
   lastSync?: string;
   syncInProgress: boolean;
   error?: string;
+  credentialsConfigured?: boolean;
+  needsReauth?: boolean;
+  tokenExpiresAt?: string;
 }
 
 // Mapper function to convert from backend snake_case to frontend camelCase
@@ -40,6 +99,9 @@ export function mapGoogleCalendarSyncStatus(status: GoogleCalendarSyncStatus): S
     lastSync: status.last_sync,
     syncInProgress: status.sync_in_progress,
     error: status.error,
+    credentialsConfigured: status.credentials_configured,
+    needsReauth: status.needs_reauth,
+    tokenExpiresAt: status.token_expires_at,
   };
 }
 
@@ -5,6 +5,8 @@
  * @phase 0-1 - Type definitions for Phase 0 setup and Phase 1 MVP
  */
 
+import type { GoogleCalendarSyncStatus } from './google-calendar';
+
 // === BACKEND COMMUNICATION TYPES ===
 
 /**
@@ -96,6 +98,42 @@ export interface MarkdownFile {
   last_modified: number;
   /** File extension (.md, .markdown) */
   extension: string;
+  /** Whether the OS permissions allow writing to the file */
+  is_writable?: boolean;
+  /** Custom field values; only set for project actions in spaces that declare custom fields */
+  custom_fields?: Record<string, string>;
+}
+
+/**
+ * Sort key for `list_markdown_files_page` / `list_project_actions_page`
+ */
+export type FileSortKey = 'name' | 'modified' | 'size' | 'created';
+
+/**
+ * Filtering, sorting, and pagination for paged file listings
+ */
+export interface ListFilesOptions {
+  /** Unset keeps the listing's default order */
+  sort_by?: FileSortKey;
+  descending?: boolean;
+  /** RFC 3339 or YYYY-MM-DD (local time) */
+  modified_after?: string;
+  /** Case-insensitive substring of the file name */
+  name_contains?: string;
+  offset?: number;
+  /** Unset returns every remaining match */
+  limit?: number;
+}
+
+/**
+ * One page of a file listing
+ */
+export interface MarkdownFilePage {
+  files: MarkdownFile[];
+  /** Matches across all pages */
+  total_available: number;
+  offset: number;
+  has_more: boolean;
 }
 
 /**
@@ -110,6 +148,32 @@ export interface FileOperationResult {
   message?: string;
 }
 
+/**
+ * Payload of the `file-chunk` event from `read_file_chunked`
+ */
+export interface FileChunk {
+  session_id: string;
+  path: string;
+  /** Zero-based position of this chunk */
+  index: number;
+  /** Always ends on a character boundary, so chunks can be appended as-is */
+  content: string;
+}
+
+/**
+ * Payload of the `file-read-complete` event from `read_file_chunked`
+ */
+export interface FileReadComplete {
+  session_id: string;
+  path: string;
+  chunk_count: number;
+  total_bytes: number;
+  /** Hex SHA-256 of the full content; empty when the read failed */
+  checksum: string;
+  /** Why the read stopped early, if it did */
+  error: string | null;
+}
+
 /**
  * Editor mode options for Phase 1-3
  */
@@ -322,6 +386,19 @@ export interface FileChangeEvent {
   timestamp: number;
 }
 
+/**
+ * Run of lines from `diff_file_versions`; removed and context line numbers
+ * refer to the old version, added line numbers to the new one (1-based)
+ */
+export interface DiffHunk {
+  kind: 'added' | 'removed' | 'context';
+  /** First line of the run */
+  start_line: number;
+  /** Last line of the run, inclusive */
+  end_line: number;
+  lines: string[];
+}
+
 // === SETTINGS TYPES ===
 
 export type McpServerLogLevel = 'error' | 'warn' | 'info' | 'debug' | 'trace';
@@ -362,6 +439,12 @@ export interface UserSettings {
   seed_example_content?: boolean | null;
   /** Preferred default GTD space path */
   default_space_path?: string | null;
+  /** Spaces created or registered by the app, such as duplicated sandboxes */
+  known_spaces?: string[];
+  /** Google Calendar color id -> GTD category (e.g. meeting, deep-work) */
+  calendar_color_mapping?: Record<string, string>;
+  /** Unix time (seconds) the app was last active */
+  last_active_timestamp?: number | null;
   /** Whether git sync/backups are enabled */
   git_sync_enabled?: boolean | null;
   /** Path to the dedicated git sync repository */
@@ -392,6 +475,8 @@ export interface UserSettings {
   mcp_server_read_only?: boolean | null;
   /** Default log level used by the standalone MCP server */
   mcp_server_log_level?: McpServerLogLevel | null;
+  /** Spell check language such as en-US; unset means en-US */
+  spellcheck_language?: string | null;
 }
 
 /**
@@ -531,9 +616,9 @@ export interface SearchResult {
   line_number: number;
   /** Line content containing the match */
   line_content: string;
-  /** Start position of match within the line */
+  /** Start of the match within line_content, in UTF-16 code units (JS string index) */
   match_start: number;
-  /** End position of match within the line */
+  /** End of the match within line_content (exclusive, UTF-16 code units) */
   match_end: number;
   /** Context lines before the match */
   context_before?: string[];
@@ -555,6 +640,20 @@ export interface SearchFilters {
   include_file_names: boolean;
   /** Maximum number of results */
   max_results: number;
+  /** Parse AND/OR/NOT, quoted phrases, and name:/path: prefixes */
+  use_query_syntax?: boolean;
+  /** Only search actions whose custom field has this value; a blank query lists them */
+  custom_field?: CustomFieldFilter | null;
+  /** Descend into symlinked folders (default false) */
+  follow_symlinks?: boolean;
+}
+
+/**
+ * Custom field equality predicate for search, e.g. client = Acme
+ */
+export interface CustomFieldFilter {
+  key: string;
+  value: string;
 }
 
 /**
@@ -583,6 +682,8 @@ export interface SearchResponse {
   duration_ms: number;
   /** Whether search was truncated due to limits */
   truncated: boolean;
+  /** Set when some folders were too deeply nested to search */
+  warning?: string | null;
 }
 
 // === GTD TYPES ===
@@ -620,6 +721,11 @@ export interface GTDProject {
   createdDateTime: string;
   /** Number of actions in the project */
   action_count?: number;
+  /** Emoji or icon name from the README's [!icon:...] marker */
+  icon?: string | null;
+  /** Built-in status implied by the project's actions (cancelled when all were
+   * cancelled); null without actions */
+  derived_status?: string | null;
 }
 
 /**
@@ -690,6 +796,883 @@ export interface GTDProjectCreate {
   status?: GTDProjectStatus;
 }
 
+/**
+ * Markdown file under Projects that belongs to no project
+ */
+export interface OrphanedAction {
+  /** Full path to the file */
+  path: string;
+  /** File name without extension */
+  name: string;
+  /** In the Projects root, or in a folder without a README.md */
+  reason: 'projects-root' | 'missing-readme';
+  /** Folder without a README, for `missing-readme` orphans */
+  folder: string | null;
+}
+
+/**
+ * Result of creating a habit or project with references
+ */
+export interface CreatedWithReferences {
+  /** Full path to the created habit file or project folder */
+  path: string;
+  /** References, as given, that did not resolve inside the space */
+  dropped_references: string[];
+}
+
+/**
+ * One `[!*-references:...]` or `[!references:...]` field
+ */
+export interface ReferenceBlock {
+  /** Marker tag, e.g. `areas-references` */
+  tag: string;
+  paths: string[];
+}
+
+/**
+ * A file related to another through reference fields, from `get_related_files`
+ */
+export interface RelatedFile {
+  path: string;
+  name: string;
+  /** Reference targets both files list */
+  shared_reference_count: number;
+  relationship_type: 'parent' | 'child' | 'sibling';
+}
+
+/**
+ * A reference field pointing at a path about to be deleted
+ */
+export interface IncomingReference {
+  source_file: string;
+  /** Field tag, e.g. `goals-references` */
+  reference_type: string;
+}
+
+/**
+ * What deleting a file or folder would affect, from `get_delete_impact`
+ */
+export interface DeleteImpact {
+  path: string;
+  incoming_references: IncomingReference[];
+  habits: IncomingReference[];
+  /** Files inside the folder; 0 for files */
+  contained_files: number;
+}
+
+/**
+ * What renaming a file or project would affect, from `preview_file_rename_impact`
+ */
+export interface RenameImpact {
+  /** Whether the old path exists and the new path is free */
+  direct_rename: boolean;
+  files_referencing_old_path: string[];
+  /** Kinds of referencing files, e.g. 'project' or 'habit' */
+  reference_types: string[];
+  total_files_to_update: number;
+}
+
+/**
+ * Structured error (JSON-encoded) from delete_file/delete_folder when the
+ * path is still referenced and `acknowledgeImpact` was false
+ */
+export interface ImpactWarning {
+  kind: 'unacknowledged-impact';
+  message: string;
+  impact: DeleteImpact;
+}
+
+/**
+ * Action timer persisted across restarts; at most one runs at a time
+ */
+export interface RunningTimer {
+  action_path: string;
+  /** RFC 3339 start time */
+  started_at: string;
+}
+
+/**
+ * Result of `stop_action_timer`
+ */
+export interface StoppedTimer {
+  action_path: string;
+  started_at: string;
+  ended_at: string;
+  duration_minutes: number;
+  /** False when the action file was deleted and nothing was written */
+  logged: boolean;
+}
+
+export type FocusPhase = 'work' | 'break' | 'complete';
+
+/**
+ * Focus session status from the focus session commands; also the
+ * `focus-tick` event payload
+ */
+export interface FocusSessionStatus {
+  action_path: string;
+  phase: FocusPhase;
+  /** Current cycle, 1-based */
+  cycle: number;
+  cycles: number;
+  /** Seconds left in the current phase */
+  remaining_seconds: number;
+  /** Seconds of work completed so far */
+  work_seconds: number;
+  paused: boolean;
+}
+
+/**
+ * Payload of the `focus-phase-change` event
+ */
+export interface FocusPhaseChange {
+  action_path: string;
+  from: FocusPhase;
+  to: FocusPhase;
+  /** Cycle the session is now in, 1-based */
+  cycle: number;
+}
+
+/**
+ * Result of `stop_focus_session`
+ */
+export interface StoppedFocusSession {
+  action_path: string;
+  started_at: string;
+  ended_at: string;
+  /** Work time, excluding breaks and pauses */
+  work_minutes: number;
+  completed_cycles: number;
+  /** Whether a Time Log row was written */
+  logged: boolean;
+}
+
+/**
+ * Structured error (JSON-encoded) from start_focus_session when another
+ * session is running and `force` was not set
+ */
+export interface FocusSessionError {
+  kind: 'conflict';
+  /** Action of the session that is already running */
+  action_path: string;
+  message: string;
+}
+
+/**
+ * Structured error (JSON-encoded) from encrypted folder commands when the
+ * passphrase is wrong or the folder has not been unlocked
+ */
+export interface FolderEncryptionError {
+  kind: 'wrong-passphrase' | 'locked';
+  /** Encrypted folder the operation targeted */
+  folder: string;
+  message: string;
+}
+
+/**
+ * Time logged in an action's `## Time Log` table
+ */
+export interface ActionTimeTotal {
+  action_path: string;
+  action_name: string;
+  total_minutes: number;
+  entry_count: number;
+}
+
+/**
+ * Time logged across a project's actions, most time first
+ */
+export interface ProjectTimeTotals {
+  total_minutes: number;
+  actions: ActionTimeTotal[];
+}
+
+/**
+ * Snapshot written to `.gtdspace/pre-op-backups` before a bulk operation
+ */
+export interface PreOpBackup {
+  /** Archive file name, passed to `restore_pre_op_backup` */
+  name: string;
+  operation: string;
+  operation_id: string;
+  /** RFC 3339 creation time */
+  created_at: string;
+  /** Space-relative paths stored in the archive */
+  files: string[];
+  /** Archive size in bytes */
+  size: number;
+}
+
+/**
+ * Workspace size statistics from `get_workspace_size` (`.git` excluded)
+ */
+export interface WorkspaceSize {
+  total_files: number;
+  total_directories: number;
+  total_bytes: number;
+  largest_file: MarkdownFile | null;
+  deepest_path_depth: number;
+  /** Set when the workspace is large enough to slow the app down */
+  warning: string | null;
+}
+
+/**
+ * Checklist item under `## Action Items` in meeting notes
+ */
+export interface ActionItem {
+  /** Item text with the `@assignee` mention removed */
+  text: string;
+  checked: boolean;
+  assignee: string | null;
+}
+
+/**
+ * Structured meeting notes from `parse_meeting_notes`
+ */
+export interface MeetingNotes {
+  title: string;
+  date: string | null;
+  attendees: string[];
+  decisions: string[];
+  action_items: ActionItem[];
+}
+
+/**
+ * An open action item create_actions_from_meeting did not turn into an action
+ */
+export interface SkippedActionItem {
+  text: string;
+  reason: string;
+}
+
+/**
+ * Result of create_actions_from_meeting
+ */
+export interface MeetingActionsResult {
+  /** Full paths of the created actions */
+  created: string[];
+  skipped: SkippedActionItem[];
+}
+
+/**
+ * Header metadata parsed from a project README by `get_project_metadata`
+ */
+export interface ProjectMetadata {
+  title: string;
+  status: string | null;
+  description: string;
+  due_date: string | null;
+  focus_date: string | null;
+  created_date_time: string | null;
+  completed_date_time: string | null;
+  references: ReferenceBlock[];
+  action_stats: {
+    total_actions: number;
+    completed_actions: number;
+    cancelled_actions: number;
+    percentage: number;
+  };
+  /** Missing or unrecognized values the UI can prompt the user to fix */
+  parse_warnings: string[];
+}
+
+/**
+ * Header metadata parsed from an action file by `get_action_metadata`
+ */
+export interface ActionMetadata {
+  title: string;
+  status: string | null;
+  focus_date: string | null;
+  due_date: string | null;
+  effort: string | null;
+  /** Contexts without the leading `@` */
+  contexts: string[];
+  references: ReferenceBlock[];
+  calendar_event_id: string | null;
+  /** Values from the `## Custom` section, keyed by field key */
+  custom_fields: Record<string, string>;
+  created_date_time: string | null;
+  completed_date_time: string | null;
+  parse_warnings: string[];
+}
+
+/**
+ * Result of validating a seed pack manifest
+ */
+export interface SeedPackValidation {
+  valid: boolean;
+  /** Pack name from the manifest */
+  name: string | null;
+  directory_count: number;
+  file_count: number;
+  /** Every problem found; empty when valid */
+  errors: string[];
+}
+
+/**
+ * Project milestone stored under the project's milestones/ folder
+ */
+export interface Milestone {
+  name: string;
+  /** Full path to the milestone file */
+  path: string;
+  target_date: string | null;
+  /** milestone-status value, e.g. pending */
+  status: string;
+}
+
+/** Reusable text snippet stored in .gtdspace/snippets */
+export interface Snippet {
+  name: string;
+  /** Raw snippet text, placeholders unexpanded */
+  content: string;
+}
+
+/** Structured error returned (JSON-encoded) when snippet variables are missing */
+export interface SnippetRenderError {
+  kind: 'missing-variables';
+  /** Variable names to prompt for, sorted */
+  missing: string[];
+  message: string;
+}
+
+/**
+ * Payload of `space-duplicate-progress` events
+ */
+export interface SpaceDuplicateProgress {
+  files_copied: number;
+  bytes_copied: number;
+  /** File most recently copied, relative to the space root */
+  current_path: string;
+}
+
+/**
+ * Result of duplicate_space
+ */
+export interface DuplicateSpaceResult {
+  target_path: string;
+  files_copied: number;
+  bytes_copied: number;
+  /** Absolute self-references rewritten to point into the copy */
+  references_rewritten: number;
+}
+
+/**
+ * Result of relocate_space
+ */
+export interface RelocateSpaceResult {
+  /** Absolute path of the space's new root */
+  new_path: string;
+  /** The original folder was left in place */
+  copied: boolean;
+  /** Absolute references inside markdown pointed at the new root */
+  references_rewritten: number;
+  /** Settings and timer entries pointed at the new root */
+  store_entries_updated: number;
+  watcher_restarted: boolean;
+  /** An interrupted relocation was picked up from its journal */
+  resumed: boolean;
+}
+
+/**
+ * Result of `snapshot_workspace`
+ */
+export interface SnapshotResult {
+  /** Absolute path of the new snapshot folder */
+  snapshot_path: string;
+  files_copied: number;
+  bytes_copied: number;
+}
+
+/**
+ * Snapshot found by `list_snapshots`
+ */
+export interface SnapshotInfo {
+  /** Folder name, e.g. 2026-03-02T09-15-00 */
+  name: string;
+  snapshot_path: string;
+  /** RFC 3339 creation time */
+  created_at: string;
+  file_count: number;
+  /** Total size of the snapshot's files in bytes */
+  size: number;
+}
+
+/**
+ * Suggested habit for an area or goal without one, from `get_habit_recommendations`
+ */
+export interface HabitRecommendation {
+  area_or_goal_name: string;
+  area_or_goal_path: string;
+  /** Suggested habit name */
+  suggestion: string;
+  rationale: string;
+}
+
+/**
+ * One habit history row in a habit data export
+ */
+export interface HabitEntry {
+  /** RFC 3339 local time of the row */
+  date: string;
+  /** The row records a completion rather than a reset to To Do */
+  completed: boolean;
+  /** What wrote the row, e.g. Manual or Auto-Reset */
+  action: string;
+}
+
+/**
+ * A habit and its history, oldest row first
+ */
+export interface HabitData {
+  name: string;
+  path: string;
+  /** Frequency marker token, e.g. daily or weekly */
+  frequency: string;
+  /** RFC 3339 local time from the created_date_time marker */
+  created_at?: string | null;
+  entries: HabitEntry[];
+}
+
+/**
+ * Versioned habit data from `get_habit_data` and `export_habit_data_json`
+ */
+export interface HabitDataExport {
+  schema_version: number;
+  /** RFC 3339 local time the export was made */
+  exported_at: string;
+  since?: string | null;
+  habits: HabitData[];
+}
+
+/**
+ * How well projects, areas, and goals link up the horizons, from
+ * `compute_gtd_horizon_alignment`
+ */
+export interface AlignmentReport {
+  projects_with_area_links: number;
+  projects_without_area_links: number;
+  areas_with_goal_links: number;
+  areas_without_goal_links: number;
+  goals_with_vision_links: number;
+  goals_without_vision_links: number;
+  /** 0.0 to 1.0; 1.0 when every item links up the hierarchy */
+  alignment_score: number;
+}
+
+/**
+ * One old/new pair in the `paths-remapped` event emitted after backend
+ * renames, moves, and space relocations
+ */
+export interface PathRemap {
+  old_path: string;
+  new_path: string;
+}
+
+/**
+ * Payload of the `habits-reset` event emitted by the habit scheduler
+ */
+export interface HabitsResetEvent {
+  space_path: string;
+  /** Names of habits that were reset */
+  reset_habits: string[];
+}
+
+/**
+ * Habit scheduler state, from `get_habit_scheduler_status`
+ */
+export interface HabitSchedulerStatus {
+  running: boolean;
+  space_path: string | null;
+  /** Unix seconds of the last completed check */
+  last_run: number | null;
+  /** Unix seconds of the next planned check */
+  next_run: number | null;
+  last_error: string | null;
+}
+
+export type ReviewType = 'daily' | 'weekly' | 'monthly';
+
+/**
+ * One question on a review agenda
+ */
+export interface ReviewItem {
+  prompt: string;
+  /** Names or counts behind the prompt */
+  context_data: string;
+  /** Command that lists the items, when there is one */
+  action_command: string | null;
+}
+
+/**
+ * Review agenda from `get_gtd_review_prompt`
+ */
+export interface ReviewPrompt {
+  title: string;
+  items: ReviewItem[];
+}
+
+/**
+ * Outcome of `batch_move_actions`
+ */
+export interface BatchMoveResult {
+  /** [old_path, new_path] pairs */
+  moved: [string, string][];
+  /** [path, reason] pairs for paths that were not source-project actions */
+  skipped: [string, string][];
+  /** [path, error] pairs for moves that failed */
+  failed: [string, string][];
+}
+
+/**
+ * One result inside startup_prefetch: exactly one of value and error is set
+ */
+export interface PrefetchPart<T> {
+  value: T | null;
+  error: string | null;
+}
+
+/**
+ * Space data loaded by startup_prefetch
+ */
+export interface SpacePrefetch {
+  is_gtd_space: PrefetchPart<boolean>;
+  projects: PrefetchPart<GTDProject[]>;
+  files: PrefetchPart<MarkdownFile[]>;
+  /** Names of habits that were reset */
+  reset_habits: PrefetchPart<string[]>;
+}
+
+/**
+ * Aggregate startup data returned by startup_prefetch
+ */
+export interface StartupPrefetch {
+  /** Space the data was loaded for, if one was given or saved in settings */
+  space_path: string | null;
+  settings: PrefetchPart<UserSettings>;
+  calendar_status: PrefetchPart<GoogleCalendarSyncStatus>;
+  /** Null when no space was given or saved in settings */
+  space: SpacePrefetch | null;
+  elapsed_ms: number;
+}
+
+/**
+ * A meeting or action focus block occupying time
+ */
+export interface BusyBlock {
+  source: 'calendar-event' | 'action';
+  /** Event summary or action name */
+  title: string;
+  /** Local start time (YYYY-MM-DDTHH:MM:SS) */
+  start: string;
+  /** Local end time (YYYY-MM-DDTHH:MM:SS) */
+  end: string;
+  event_id?: string;
+  path?: string;
+}
+
+/**
+ * Age of the Google Calendar cache behind a scheduling result
+ */
+export interface CalendarFreshness {
+  cache_updated_at: string | null;
+  cache_age_minutes: number | null;
+  /** True when there is no cache or it is older than 15 minutes */
+  possibly_stale: boolean;
+}
+
+/**
+ * Result of check_focus_conflicts
+ */
+export interface FocusConflictReport {
+  conflicts: BusyBlock[];
+  freshness: CalendarFreshness;
+}
+
+/**
+ * A free gap within working hours
+ */
+export interface FocusSlot {
+  start: string;
+  end: string;
+  duration_minutes: number;
+}
+
+/**
+ * Result of suggest_focus_slots
+ */
+export interface FocusSlotReport {
+  slots: FocusSlot[];
+  freshness: CalendarFreshness;
+}
+
+/**
+ * Working day bounds as HH:MM
+ */
+export interface WorkHours {
+  start: string;
+  end: string;
+}
+
+/**
+ * A waiting action returned by list_waiting_items
+ */
+export interface WaitingItem {
+  name: string;
+  path: string;
+  project_name: string;
+  /** Date the action started waiting (YYYY-MM-DD) */
+  waiting_since: string;
+  days_waiting: number;
+  /** True when waiting_since comes from the file's modified time */
+  is_estimate: boolean;
+  /** @person tokens from the title or notes, without the @ */
+  waiting_on: string[];
+  /** Waited longer than the space's waiting_threshold_days */
+  needs_follow_up: boolean;
+}
+
+/**
+ * A misspelled word from check_text or check_file
+ */
+export interface Misspelling {
+  word: string;
+  /** Start offset in UTF-16 code units (JavaScript string index) */
+  start: number;
+  /** End offset (exclusive) in UTF-16 code units */
+  end: number;
+  /** 1-based line number */
+  line: number;
+  suggestions: string[];
+}
+
+/**
+ * Misspellings in one file, from check_file
+ */
+export interface FileSpellcheck {
+  path: string;
+  language: string;
+  misspellings: Misspelling[];
+}
+
+/**
+ * JSON-encoded error from the spell check commands
+ */
+export interface SpellcheckError {
+  kind: 'not-available';
+  language: string;
+  /** Folders searched for the dictionary files */
+  searched: string[];
+  /** Explanation including how to install the dictionary */
+  message: string;
+}
+
+/**
+ * Open actions per effort level, from get_action_effort_distribution
+ */
+export interface EffortDistribution {
+  small: number;
+  medium: number;
+  large: number;
+  extra_large: number;
+  /** Actions with no effort or one outside the vocabulary */
+  unknown: number;
+  /** small=1, medium=2, large=3, extra-large=5; unknown scores as medium */
+  total_effort_points: number;
+  /** Effort level closest to the mean, or 'none' without open actions */
+  average_effort_label: string;
+}
+
+/**
+ * Actions last modified on one local day, from get_daily_action_counts
+ */
+export interface DailyActionCount {
+  /** YYYY-MM-DD */
+  date: string;
+  count: number;
+}
+
+/**
+ * Open project or action listed in a due bucket
+ */
+export interface DueItem {
+  name: string;
+  path: string;
+  /** Due date as written in the file */
+  due_date?: string | null;
+  status?: string | null;
+}
+
+/**
+ * Count and earliest-due items of one due bucket
+ */
+export interface DueBucket {
+  count: number;
+  items: DueItem[];
+}
+
+/**
+ * Open items grouped by when they are due
+ */
+export interface DueBuckets {
+  overdue: DueBucket;
+  due_today: DueBucket;
+  /** Due after today and within the horizon */
+  due_this_week: DueBucket;
+  due_later: DueBucket;
+  no_due_date: DueBucket;
+}
+
+/**
+ * Result of `get_due_summary`
+ */
+export interface DueSummary {
+  /** RFC 3339 time the summary was computed */
+  computed_at: string;
+  horizon_days: number;
+  projects: DueBuckets;
+  actions: DueBuckets;
+}
+
+/**
+ * Daily quote from the space's Purpose & Principles and Vision pages,
+ * from `get_motivation_quote`
+ */
+export interface MotivationQuote {
+  /** Blockquote text with the `>` markers removed */
+  text: string;
+  /** Full path to the file the quote came from */
+  source_file: string;
+  /** Paragraph around the quote, or its heading when there is none */
+  context: string;
+}
+
+/**
+ * Result of `generate_standup_notes`
+ */
+export interface StandupNotes {
+  /** Actions completed and last modified yesterday */
+  yesterday_completed: string[];
+  /** Actions focused for today or in progress */
+  today_in_progress: string[];
+  /** Actions waiting on someone else */
+  blockers: string[];
+  /** Habits with a Complete history row today */
+  habits_completed_today: string[];
+  /** The lists above as a plain-text standup script */
+  formatted_text: string;
+}
+
+/**
+ * Completions in one week, from `get_weekly_completion_trend`
+ */
+export interface WeeklyCount {
+  /** Monday of the week, YYYY-MM-DD */
+  week_start: string;
+  actions_completed: number;
+  habits_completed: number;
+  projects_closed: number;
+}
+
+/**
+ * An action created, or planned on a dry run, by split_note_into_actions
+ */
+export interface NoteSplitAction {
+  heading: string;
+  /** Full path to the action file */
+  path: string;
+}
+
+/**
+ * A heading split_note_into_actions left in the note
+ */
+export interface SkippedHeading {
+  heading: string;
+  reason: string;
+}
+
+/**
+ * Result of split_note_into_actions
+ */
+export interface NoteSplitResult {
+  actions: NoteSplitAction[];
+  skipped: SkippedHeading[];
+  /** Whether nothing was written */
+  dry_run: boolean;
+}
+
+/**
+ * A project whose declared status disagrees with its actions, from
+ * reconcile_project_statuses
+ */
+export interface ProjectStatusMismatch {
+  project_name: string;
+  project_path: string;
+  /** Status in the README */
+  declared_status: string;
+  /** Status implied by the actions, in the space's vocabulary */
+  derived_status: string;
+  /** Whether the README was rewritten */
+  applied: boolean;
+}
+
+/**
+ * Status or effort value allowed in a space
+ */
+export interface VocabularyValue {
+  /** Value stored in the field marker */
+  value: string;
+  /** Built-in value with the same meaning, e.g. 'waiting' for 'blocked' */
+  maps_to: GTDActionStatus | GTDActionEffort;
+  /** Picker label; falls back to `value` */
+  label?: string;
+}
+
+/**
+ * Status and effort values from `get_field_vocabulary`
+ */
+export interface FieldVocabulary {
+  statuses: VocabularyValue[];
+  efforts: VocabularyValue[];
+  /** Whether any values come from the space config */
+  is_custom: boolean;
+}
+
+/**
+ * Custom action field declared in the space config
+ */
+export interface CustomFieldDefinition {
+  /** Marker key, e.g. 'client' */
+  key: string;
+  kind: 'singleselect' | 'text' | 'datetime';
+  /** Values a singleselect field accepts; empty accepts any value */
+  allowed_values?: string[];
+  /** Display label; falls back to `key` */
+  label?: string;
+}
+
+/**
+ * Structured error (JSON-encoded) for a rejected custom field value
+ */
+export interface CustomFieldError {
+  kind: 'unknown-field' | 'invalid-value';
+  key: string;
+  value: string;
+  /** Accepted values for singleselect fields */
+  allowed_values?: string[];
+  message: string;
+}
+
+/**
+ * Installed editor from `get_external_editors`
+ */
+export interface ExternalEditor {
+  /** Display name for the menu */
+  name: string;
+  /** Value to pass as `app` to `open_file_in_external_app` */
+  app: string;
+}
+
 /**
  * GTD action creation parameters
  */
@@ -748,6 +1731,15 @@ export interface GTDHabit {
   createdDateTime: string;
 }
 
+/**
+ * Habit status and streaks after `toggle_habit`
+ */
+export interface HabitToggleResult {
+  status: GTDHabitStatus;
+  current_streak: number;
+  longest_streak: number;
+}
+
 /**
  * GTD Area of Focus status options
  */
@@ -73,16 +73,24 @@ export function setAutoSyncPreference(enabled: boolean): void {
 export interface GoogleCalendarSyncResult {
   events: GoogleCalendarEvent[];
   timestamp: string;
+  retries: number;
 }
 
+// `google_calendar_sync` returns events with a retry count; `google_calendar_fetch_events` returns the bare list
+type BackendSyncResult = GoogleCalendarEvent[] | { events: GoogleCalendarEvent[]; retries: number };
+
 export async function syncGoogleCalendarEvents(
-  command: 'google_calendar_sync' | 'google_calendar_fetch_events' = 'google_calendar_sync'
+  command: 'google_calendar_sync' | 'google_calendar_fetch_events' = 'google_calendar_sync',
+  options: { auto?: boolean } = {}
 ): Promise<GoogleCalendarSyncResult | null> {
-  const events = await safeInvoke<GoogleCalendarEvent[]>(command, undefined, null);
+  const args = command === 'google_calendar_sync' ? { auto: options.auto ?? false } : undefined;
+  const result = await safeInvoke<BackendSyncResult>(command, args, null);
+  const events = Array.isArray(result) ? result : result?.events;
   if (Array.isArray(events)) {
     const timestamp =
       persistGoogleCalendarEvents(events) ?? new Date().toISOString();
-    return { events, timestamp };
+    const retries = Array.isArray(result) ? 0 : result?.retries ?? 0;
+    return { events, timestamp, retries };
   }
   return null;
 }
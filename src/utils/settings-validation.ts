@@ -421,6 +421,7 @@ export function validateAndCoerceSettings(importedData: unknown): ValidationResu
     'git_sync_last_push',
     'git_sync_last_pull',
     'mcp_server_workspace_path',
+    'spellcheck_language',
   ];
 
   for (const field of optionalStringFields) {